@@ -681,6 +681,7 @@ const FUTURE_UDF_FUNCTIONS: &[&str] = &[
     "F.INV",
     "F.INV.RT",
     "F.TEST",
+    "FIELDVALUE",
     "FILTER",
     "FLOOR.MATH",
     "FLOOR.PRECISE",
@@ -4,10 +4,12 @@
 //! stream:
 //! - `decode_rgce`: best-effort decoding of `rgce` into Excel formula text
 //! - `encode_rgce` (feature `encode`): encoding of formula text into `rgce`
+//! - `tokens_from_rgce`: token boundaries and operand classes of an `rgce` stream
 //!
 //! The encoder is intentionally scoped to the initial editing workflows:
 //! constants, A1-style refs, basic operators, and a curated set of built-in
-//! functions.
+//! functions. Sheet-qualified refs need a sheet-name -> `ixti` map and go through
+//! `encode_rgce_with_sheets` / `decode_rgce_with_sheets`.
 //!
 //! Notes on structured references (Excel Tables):
 //! - BIFF12/XLSB stores structured references using `PtgExtend(etpg=0x19)` (aka `PtgList`).
@@ -19,6 +21,7 @@
 mod ftab;
 mod function_ids;
 pub mod errors;
+pub mod ptg;
 mod rgce;
 pub mod ptg_list;
 pub mod structured_refs;
@@ -27,7 +30,12 @@ pub use ftab::{function_id_from_name, function_name_from_id, FTAB_USER_DEFINED};
 pub use function_ids::{
     function_id_to_name, function_name_to_id, function_name_to_id_uppercase, function_spec_from_id,
 };
-pub use rgce::{decode_rgce, decode_rgce_with_base, decode_rgce_with_rgcb, DecodeRgceError};
+pub use rgce::{
+    decode_rgce, decode_rgce_with_base, decode_rgce_with_rgcb, decode_rgce_with_sheets,
+    tokens_from_rgce, DecodeRgceError, RgceToken,
+};
 
 #[cfg(feature = "encode")]
-pub use rgce::{encode_rgce, encode_rgce_with_rgcb, EncodedRgce, EncodeRgceError};
+pub use rgce::{
+    encode_rgce, encode_rgce_with_rgcb, encode_rgce_with_sheets, EncodeRgceError, EncodedRgce,
+};
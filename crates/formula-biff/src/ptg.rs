@@ -0,0 +1,140 @@
+//! Named BIFF12 `Ptg` opcode constants.
+//!
+//! These are the token numbers understood by [`crate::decode_rgce`] (and, with the `encode`
+//! feature, emitted by `encode_rgce`), exported so external decoders can share the numbering.
+//!
+//! Operand tokens (`0x20..=0x7F`) carry a *class* in bits 5-6 of the opcode. Class-bearing
+//! constants below use the reference-class form (`0x2X`/`0x3X`); combine the base bits with a
+//! different class via [`with_class`], e.g. `with_class(PTG_REF, PTG_CLASS_VALUE) == 0x44`.
+
+/// Mask selecting the token number without its class bits.
+pub const PTG_BASE_MASK: u8 = 0x1F;
+/// Mask selecting the class bits of an operand token.
+pub const PTG_CLASS_MASK: u8 = 0x60;
+/// Reference class (`0x20`): the operand is passed as a reference.
+pub const PTG_CLASS_REF: u8 = 0x20;
+/// Value class (`0x40`): the operand is dereferenced to a single value.
+pub const PTG_CLASS_VALUE: u8 = 0x40;
+/// Array class (`0x60`): the operand is evaluated as an array.
+pub const PTG_CLASS_ARRAY: u8 = 0x60;
+
+// Control tokens.
+pub const PTG_EXP: u8 = 0x01;
+pub const PTG_TBL: u8 = 0x02;
+
+// Binary operators.
+pub const PTG_ADD: u8 = 0x03;
+pub const PTG_SUB: u8 = 0x04;
+pub const PTG_MUL: u8 = 0x05;
+pub const PTG_DIV: u8 = 0x06;
+pub const PTG_POWER: u8 = 0x07;
+pub const PTG_CONCAT: u8 = 0x08;
+pub const PTG_LT: u8 = 0x09;
+pub const PTG_LE: u8 = 0x0A;
+pub const PTG_EQ: u8 = 0x0B;
+pub const PTG_GT: u8 = 0x0C;
+pub const PTG_GE: u8 = 0x0D;
+pub const PTG_NE: u8 = 0x0E;
+pub const PTG_ISECT: u8 = 0x0F;
+pub const PTG_UNION: u8 = 0x10;
+pub const PTG_RANGE: u8 = 0x11;
+
+// Unary operators and punctuation.
+pub const PTG_UPLUS: u8 = 0x12;
+pub const PTG_UMINUS: u8 = 0x13;
+pub const PTG_PERCENT: u8 = 0x14;
+pub const PTG_PAREN: u8 = 0x15;
+pub const PTG_MISS_ARG: u8 = 0x16;
+/// Spill range postfix operator (`A1#`).
+pub const PTG_SPILL: u8 = 0x2F;
+
+// Constants.
+pub const PTG_STR: u8 = 0x17;
+pub const PTG_ERR: u8 = 0x1C;
+pub const PTG_BOOL: u8 = 0x1D;
+pub const PTG_INT: u8 = 0x1E;
+pub const PTG_NUM: u8 = 0x1F;
+
+/// `PtgExtend` (reference class); the next byte is an `etpg` subtype such as [`ETPG_LIST`].
+///
+/// Unlike other operands, the `PtgExtend` family starts one class lower: `0x18` is reference,
+/// `0x38` value and `0x58` array class. [`ptg_class`] accounts for this; [`with_class`] does not.
+pub const PTG_EXTEND: u8 = 0x18;
+/// `etpg` subtype of `PtgExtend` for structured references (`PtgList`).
+pub const ETPG_LIST: u8 = 0x19;
+
+/// `PtgAttr`; the next byte is a `grbit` made of the `PTG_ATTR_*` flags.
+pub const PTG_ATTR: u8 = 0x19;
+pub const PTG_ATTR_SEMI: u8 = 0x01;
+pub const PTG_ATTR_IF: u8 = 0x02;
+pub const PTG_ATTR_CHOOSE: u8 = 0x04;
+pub const PTG_ATTR_GOTO: u8 = 0x08;
+pub const PTG_ATTR_SUM: u8 = 0x10;
+pub const PTG_ATTR_BAXCEL: u8 = 0x20;
+pub const PTG_ATTR_SPACE: u8 = 0x40;
+
+// Operand tokens (reference-class form).
+pub const PTG_ARRAY: u8 = 0x20;
+pub const PTG_FUNC: u8 = 0x21;
+pub const PTG_FUNC_VAR: u8 = 0x22;
+pub const PTG_NAME: u8 = 0x23;
+pub const PTG_REF: u8 = 0x24;
+pub const PTG_AREA: u8 = 0x25;
+pub const PTG_MEM_AREA: u8 = 0x26;
+pub const PTG_MEM_ERR: u8 = 0x27;
+pub const PTG_MEM_NO_MEM: u8 = 0x28;
+pub const PTG_MEM_FUNC: u8 = 0x29;
+pub const PTG_REF_ERR: u8 = 0x2A;
+pub const PTG_AREA_ERR: u8 = 0x2B;
+pub const PTG_REF_N: u8 = 0x2C;
+pub const PTG_AREA_N: u8 = 0x2D;
+pub const PTG_MEM_AREA_N: u8 = 0x2E;
+pub const PTG_NAME_X: u8 = 0x39;
+pub const PTG_REF_3D: u8 = 0x3A;
+pub const PTG_AREA_3D: u8 = 0x3B;
+pub const PTG_REF_ERR_3D: u8 = 0x3C;
+pub const PTG_AREA_ERR_3D: u8 = 0x3D;
+
+/// Operand class of a `Ptg` token.
+///
+/// The class tells Excel how to evaluate an operand: as a reference, dereferenced to a single
+/// value (legacy implicit intersection), or as an array (e.g. inside array-entered formulas).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PtgClass {
+    Reference,
+    Value,
+    Array,
+}
+
+impl PtgClass {
+    /// The class bits (`PTG_CLASS_*`) for this class.
+    pub const fn bits(self) -> u8 {
+        match self {
+            PtgClass::Reference => PTG_CLASS_REF,
+            PtgClass::Value => PTG_CLASS_VALUE,
+            PtgClass::Array => PTG_CLASS_ARRAY,
+        }
+    }
+}
+
+/// Returns the operand class encoded in `ptg`, or `None` for operators, constants and control
+/// tokens that carry no class.
+pub const fn ptg_class(ptg: u8) -> Option<PtgClass> {
+    match ptg {
+        PTG_EXTEND => Some(PtgClass::Reference),
+        0x38 => Some(PtgClass::Value),
+        0x58 => Some(PtgClass::Array),
+        PTG_SPILL => None,
+        _ => match ptg & PTG_CLASS_MASK {
+            PTG_CLASS_REF => Some(PtgClass::Reference),
+            PTG_CLASS_VALUE => Some(PtgClass::Value),
+            PTG_CLASS_ARRAY => Some(PtgClass::Array),
+            _ => None,
+        },
+    }
+}
+
+/// Replace the class bits of an operand token with `class` (one of the `PTG_CLASS_*` values).
+pub const fn with_class(ptg: u8, class: u8) -> u8 {
+    (ptg & !PTG_CLASS_MASK) | (class & PTG_CLASS_MASK)
+}
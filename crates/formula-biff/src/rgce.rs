@@ -1,5 +1,6 @@
 use crate::errors::biff_error_literal;
 use crate::function_ids::{function_id_to_name, function_spec_from_id};
+use crate::ptg::{self, ptg_class, PtgClass};
 use crate::ptg_list::{decode_ptg_list_payload_candidates, PtgListDecoded};
 use crate::structured_refs::{
     estimated_structured_ref_len, push_structured_ref, structured_ref_is_single_cell, StructuredRefItem,
@@ -7,8 +8,9 @@ use crate::structured_refs::{
 use core::fmt::Write as _;
 use formula_model::{
     push_a1_cell_area_row1, push_a1_cell_ref_row1, push_escaped_excel_double_quote_char,
-    push_excel_single_quoted_identifier,
+    push_excel_single_quoted_identifier, push_sheet_name_a1,
 };
+use std::collections::HashMap;
 
 #[cfg(feature = "encode")]
 use crate::errors::biff_error_code_from_literal;
@@ -435,6 +437,14 @@ fn advance_pos(
     Ok(())
 }
 
+/// Byte length of the `tAttrChoose` jump table that follows a `PtgAttr` header.
+///
+/// `wAttr` is the number of CHOOSE values; the table has one extra entry pointing past the last
+/// value (used when the index is out of range), so it holds `wAttr + 1` `u16` offsets.
+fn attr_choose_jump_table_len(w_attr: usize) -> usize {
+    w_attr.saturating_add(1).saturating_mul(2)
+}
+
 /// Scan a nested BIFF12 token subexpression (e.g. the payload of `PtgMemFunc`) and advance the
 /// `rgcb` cursor for any `PtgArray` tokens encountered.
 ///
@@ -454,199 +464,167 @@ fn consume_rgcb_arrays_in_subexpression(
         let ptg = rgce[i];
         advance_pos(&mut i, 1, rgce.len(), ptg_offset, ptg)?;
 
-        match ptg {
-            // PtgExp / PtgTbl: [row: u16][col: u16]
-            //
-            // These tokens are used for shared formulas / data tables and can appear inside
-            // non-printing `PtgMem*` subexpressions. We don't decode them to text here, but we
-            // must skip their payload to keep scanning aligned so we can still find any nested
-            // `PtgArray` tokens.
-            0x01 | 0x02 => {
-                let needed = 4;
-                let _ = slice_at(rgce, i, needed, ptg_offset, ptg)?;
-                advance_pos(&mut i, needed, rgce.len(), ptg_offset, ptg)?;
-            }
-
-            // PtgArray (any class): [unused: 7 bytes] + array constant in rgcb.
-            0x20 | 0x40 | 0x60 => {
-                let needed = 7;
-                let _ = slice_at(rgce, i, needed, ptg_offset, ptg)?;
-                advance_pos(&mut i, needed, rgce.len(), ptg_offset, ptg)?;
-                let _ = decode_array_constant(rgcb, rgcb_pos, ptg_offset, ptg)?;
-            }
-
-            // Binary operators and simple operators with no payload.
-            0x03..=0x16 | 0x2F => {}
-
-            // PtgStr: [cch: u16][utf16 chars...]
-            0x17 => {
-                let hdr = slice_at(rgce, i, 2, ptg_offset, ptg)?;
-                let cch = u16::from_le_bytes([hdr[0], hdr[1]]) as usize;
-                advance_pos(&mut i, 2, rgce.len(), ptg_offset, ptg)?;
-                let byte_len = cch.saturating_mul(2);
-                let _ = slice_at(rgce, i, byte_len, ptg_offset, ptg)?;
-                advance_pos(&mut i, byte_len, rgce.len(), ptg_offset, ptg)?;
-            }
-
-            // PtgExtend* (structured refs): [etpg: u8][payload...]
-            0x18 | 0x38 | 0x58 => {
-                let hdr = slice_at(rgce, i, 1, ptg_offset, ptg)?;
-                let etpg = hdr[0];
-                advance_pos(&mut i, 1, rgce.len(), ptg_offset, ptg)?;
-                match etpg {
-                    // etpg=0x19 is the structured reference payload (PtgList): fixed 12 bytes.
-                    0x19 => {
-                        let needed = 12;
-                        let _ = slice_at(rgce, i, needed, ptg_offset, ptg)?;
-                        advance_pos(&mut i, needed, rgce.len(), ptg_offset, ptg)?;
-                    }
-                    // Unknown extend subtype: stop scanning to avoid desync/false positives.
-                    _ => break,
-                }
-            }
-
-            // PtgAttr: [grbit: u8][wAttr: u16] + optional jump table for tAttrChoose.
-            0x19 => {
-                let hdr = slice_at(rgce, i, 3, ptg_offset, ptg)?;
-                let grbit = hdr[0];
-                let w_attr = u16::from_le_bytes([hdr[1], hdr[2]]) as usize;
-                advance_pos(&mut i, 3, rgce.len(), ptg_offset, ptg)?;
-
-                const T_ATTR_CHOOSE: u8 = 0x04;
-                if grbit & T_ATTR_CHOOSE != 0 {
-                    let needed = w_attr.saturating_mul(2);
-                    let _ = slice_at(rgce, i, needed, ptg_offset, ptg)?;
-                    advance_pos(&mut i, needed, rgce.len(), ptg_offset, ptg)?;
+        // Unknown ptg (or `PtgExtend` subtype): stop scanning to avoid desync/false positives.
+        let Some(payload_len) = ptg_payload_len(rgce, i, ptg_offset, ptg)? else {
+            break;
+        };
+        let payload = slice_at(rgce, i, payload_len, ptg_offset, ptg)?;
+        if ptg_class(ptg).is_some() {
+            match ptg::with_class(ptg, ptg::PTG_CLASS_REF) {
+                // PtgArray: [unused: 7 bytes] + array constant in rgcb.
+                ptg::PTG_ARRAY => {
+                    let _ = decode_array_constant(rgcb, rgcb_pos, ptg_offset, ptg)?;
+                }
+                // PtgMem* tokens: [cce: u16][subexpression...]
+                ptg::PTG_MEM_AREA
+                | ptg::PTG_MEM_ERR
+                | ptg::PTG_MEM_NO_MEM
+                | ptg::PTG_MEM_FUNC
+                | ptg::PTG_MEM_AREA_N => {
+                    consume_rgcb_arrays_in_subexpression(
+                        &payload[2..],
+                        rgcb,
+                        rgcb_pos,
+                        rgce_base_offset.saturating_add(i + 2),
+                    )?;
                 }
+                _ => {}
             }
+        }
+        advance_pos(&mut i, payload_len, rgce.len(), ptg_offset, ptg)?;
+    }
 
-            // PtgErr: [code: u8]
-            0x1C => {
-                let _ = slice_at(rgce, i, 1, ptg_offset, ptg)?;
-                advance_pos(&mut i, 1, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgBool: [b: u8]
-            0x1D => {
-                let _ = slice_at(rgce, i, 1, ptg_offset, ptg)?;
-                advance_pos(&mut i, 1, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgInt: [n: u16]
-            0x1E => {
-                let _ = slice_at(rgce, i, 2, ptg_offset, ptg)?;
-                advance_pos(&mut i, 2, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgNum: [f64]
-            0x1F => {
-                let _ = slice_at(rgce, i, 8, ptg_offset, ptg)?;
-                advance_pos(&mut i, 8, rgce.len(), ptg_offset, ptg)?;
-            }
-
-            // PtgFunc: [iftab: u16]
-            0x21 | 0x41 | 0x61 => {
-                let _ = slice_at(rgce, i, 2, ptg_offset, ptg)?;
-                advance_pos(&mut i, 2, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgFuncVar: [argc: u8][iftab: u16]
-            0x22 | 0x42 | 0x62 => {
-                let _ = slice_at(rgce, i, 3, ptg_offset, ptg)?;
-                advance_pos(&mut i, 3, rgce.len(), ptg_offset, ptg)?;
-            }
+    Ok(())
+}
 
-            // PtgName: [nameId: u32][reserved: u16]
-            0x23 | 0x43 | 0x63 => {
-                let _ = slice_at(rgce, i, 6, ptg_offset, ptg)?;
-                advance_pos(&mut i, 6, rgce.len(), ptg_offset, ptg)?;
-            }
+/// Byte length of the payload that follows the `ptg` byte at `ptg_offset`, where `rgce[i]` is the
+/// first payload byte.
+///
+/// Variable-length payloads are sized from their headers: `PtgStr` characters, the `tAttrChoose`
+/// jump table and `PtgMem*` subexpressions (which are included in the length). Returns `Ok(None)`
+/// for tokens and `PtgExtend` subtypes whose layout is unknown.
+fn ptg_payload_len(
+    rgce: &[u8],
+    i: usize,
+    ptg_offset: usize,
+    ptg: u8,
+) -> Result<Option<usize>, DecodeRgceError> {
+    use crate::ptg::*;
 
-            // PtgRef: [row: u32][col: u16]
-            0x24 | 0x44 | 0x64 => {
-                let _ = slice_at(rgce, i, 6, ptg_offset, ptg)?;
-                advance_pos(&mut i, 6, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgArea: [rowFirst: u32][rowLast: u32][colFirst: u16][colLast: u16]
-            0x25 | 0x45 | 0x65 => {
-                let _ = slice_at(rgce, i, 12, ptg_offset, ptg)?;
-                advance_pos(&mut i, 12, rgce.len(), ptg_offset, ptg)?;
-            }
+    // The `PtgExtend` family starts one class lower than other operands (see `ptg_class`).
+    const PTG_EXTEND_VALUE: u8 = PTG_EXTEND | PTG_CLASS_REF;
+    const PTG_EXTEND_ARRAY: u8 = PTG_EXTEND | PTG_CLASS_VALUE;
 
-            // PtgMem* tokens: [cce: u16][subexpression...]
-            0x26 | 0x46 | 0x66 | 0x27 | 0x47 | 0x67 | 0x28 | 0x48 | 0x68 | 0x29 | 0x49 | 0x69
-            | 0x2E | 0x4E | 0x6E => {
-                let hdr = slice_at(rgce, i, 2, ptg_offset, ptg)?;
-                let cce = u16::from_le_bytes([hdr[0], hdr[1]]) as usize;
-                advance_pos(&mut i, 2, rgce.len(), ptg_offset, ptg)?;
-                let subexpr = slice_at(rgce, i, cce, ptg_offset, ptg)?;
-                consume_rgcb_arrays_in_subexpression(
-                    subexpr,
-                    rgcb,
-                    rgcb_pos,
-                    rgce_base_offset.saturating_add(i),
-                )?;
-                advance_pos(&mut i, cce, rgce.len(), ptg_offset, ptg)?;
-            }
+    let u16_at = |at: usize| -> Result<usize, DecodeRgceError> {
+        let hdr = slice_at(rgce, at, 2, ptg_offset, ptg)?;
+        Ok(u16::from_le_bytes([hdr[0], hdr[1]]) as usize)
+    };
 
-            // PtgRefErr: [row: u32][col: u16]
-            0x2A | 0x4A | 0x6A => {
-                let _ = slice_at(rgce, i, 6, ptg_offset, ptg)?;
-                advance_pos(&mut i, 6, rgce.len(), ptg_offset, ptg)?;
+    let len = match ptg {
+        // [row: u16][col: u16]
+        PTG_EXP | PTG_TBL => 4,
+        PTG_ADD..=PTG_MISS_ARG | PTG_SPILL => 0,
+        // [cch: u16][utf16 chars...]
+        PTG_STR => 2 + u16_at(i)?.saturating_mul(2),
+        // [etpg: u8][payload...]; only `PtgList` (12 bytes) is understood.
+        PTG_EXTEND | PTG_EXTEND_VALUE | PTG_EXTEND_ARRAY => {
+            match slice_at(rgce, i, 1, ptg_offset, ptg)?[0] {
+                ETPG_LIST => 1 + 12,
+                _ => return Ok(None),
             }
-            // PtgAreaErr: [rowFirst: u32][rowLast: u32][colFirst: u16][colLast: u16]
-            0x2B | 0x4B | 0x6B => {
-                let _ = slice_at(rgce, i, 12, ptg_offset, ptg)?;
-                advance_pos(&mut i, 12, rgce.len(), ptg_offset, ptg)?;
+        }
+        // [grbit: u8][wAttr: u16] + jump table for tAttrChoose.
+        PTG_ATTR => {
+            let grbit = slice_at(rgce, i, 1, ptg_offset, ptg)?[0];
+            let w_attr = u16_at(i + 1)?;
+            if grbit & PTG_ATTR_CHOOSE != 0 {
+                3 + attr_choose_jump_table_len(w_attr)
+            } else {
+                3
             }
+        }
+        PTG_ERR | PTG_BOOL => 1,
+        PTG_INT => 2,
+        PTG_NUM => 8,
+        // Operand tokens share one layout across classes.
+        PTG_CLASS_REF..=u8::MAX => match with_class(ptg, PTG_CLASS_REF) {
+            // [unused: 7 bytes]; the array constant itself lives in rgcb.
+            PTG_ARRAY => 7,
+            // [iftab: u16]
+            PTG_FUNC => 2,
+            // [argc: u8][iftab: u16]
+            PTG_FUNC_VAR => 3,
+            // [nameId: u32][reserved: u16] / [row: u32][col: u16]
+            PTG_NAME | PTG_REF | PTG_REF_ERR | PTG_REF_N => 6,
+            // [rowFirst: u32][rowLast: u32][colFirst: u16][colLast: u16]
+            PTG_AREA | PTG_AREA_ERR | PTG_AREA_N => 12,
+            // [cce: u16][subexpression...]
+            PTG_MEM_AREA | PTG_MEM_ERR | PTG_MEM_NO_MEM | PTG_MEM_FUNC | PTG_MEM_AREA_N => {
+                2 + u16_at(i)?
+            }
+            // [ixti: u16][nameIndex: u16]
+            PTG_NAME_X => 4,
+            // [ixti: u16][row: u32][col: u16]
+            PTG_REF_3D | PTG_REF_ERR_3D => 8,
+            // [ixti: u16][rowFirst: u32][rowLast: u32][colFirst: u16][colLast: u16]
+            PTG_AREA_3D | PTG_AREA_ERR_3D => 14,
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(len))
+}
 
-            // PtgRefN: [row_off: i32][col_off: i16]
-            0x2C | 0x4C | 0x6C => {
-                let _ = slice_at(rgce, i, 6, ptg_offset, ptg)?;
-                advance_pos(&mut i, 6, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgAreaN: [rowFirst_off: i32][rowLast_off: i32][colFirst_off: i16][colLast_off: i16]
-            0x2D | 0x4D | 0x6D => {
-                let _ = slice_at(rgce, i, 12, ptg_offset, ptg)?;
-                advance_pos(&mut i, 12, rgce.len(), ptg_offset, ptg)?;
-            }
+/// A single token in a BIFF12 `rgce` stream, as reported by [`tokens_from_rgce`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RgceToken {
+    /// Byte offset of the token's `ptg` byte within the `rgce` stream.
+    pub offset: usize,
+    /// Raw `ptg` byte, including any class bits.
+    pub ptg: u8,
+    /// Total encoded length in bytes (the `ptg` byte plus its payload).
+    pub len: usize,
+    /// Operand class for operand tokens (`PtgRef`, `PtgArea`, `PtgFunc`, `PtgName`, ...).
+    pub class: Option<PtgClass>,
+}
 
-            // PtgNameX: [ixti: u16][nameIndex: u16]
-            0x39 | 0x59 | 0x79 => {
-                let _ = slice_at(rgce, i, 4, ptg_offset, ptg)?;
-                advance_pos(&mut i, 4, rgce.len(), ptg_offset, ptg)?;
-            }
+/// Split a BIFF12 `rgce` stream into its top-level tokens, reporting each operand's class.
+///
+/// `PtgMem*` tokens are reported as a single token whose `len` covers their nested
+/// subexpression, matching how [`decode_rgce`] treats them.
+///
+/// Classes are not preserved through formula text: `encode_rgce` always emits reference-class
+/// operands (value class only for explicit `@` references), so re-encoding the decoded text of an
+/// array-entered formula loses its array-class tokens. Keep the original bytes, or patch the
+/// opcodes with [`crate::ptg::with_class`], when the class must survive a round trip.
+pub fn tokens_from_rgce(rgce: &[u8]) -> Result<Vec<RgceToken>, DecodeRgceError> {
+    let mut tokens = Vec::new();
+    let mut i = 0usize;
+    while i < rgce.len() {
+        let offset = i;
+        let ptg = rgce[i];
+        advance_pos(&mut i, 1, rgce.len(), offset, ptg)?;
 
-            // PtgRef3d: [ixti: u16][row: u32][col: u16]
-            0x3A | 0x5A | 0x7A => {
-                let _ = slice_at(rgce, i, 8, ptg_offset, ptg)?;
-                advance_pos(&mut i, 8, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgArea3d: [ixti: u16][rowFirst: u32][rowLast: u32][colFirst: u16][colLast: u16]
-            0x3B | 0x5B | 0x7B => {
-                let _ = slice_at(rgce, i, 14, ptg_offset, ptg)?;
-                advance_pos(&mut i, 14, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgRefErr3d: [ixti: u16][row: u32][col: u16]
-            0x3C | 0x5C | 0x7C => {
-                let _ = slice_at(rgce, i, 8, ptg_offset, ptg)?;
-                advance_pos(&mut i, 8, rgce.len(), ptg_offset, ptg)?;
-            }
-            // PtgAreaErr3d: [ixti: u16][rowFirst: u32][rowLast: u32][colFirst: u16][colLast: u16]
-            0x3D | 0x5D | 0x7D => {
-                let _ = slice_at(rgce, i, 14, ptg_offset, ptg)?;
-                advance_pos(&mut i, 14, rgce.len(), ptg_offset, ptg)?;
-            }
+        let payload_len = ptg_payload_len(rgce, i, offset, ptg)?
+            .ok_or(DecodeRgceError::UnsupportedToken { offset, ptg })?;
+        let _ = slice_at(rgce, i, payload_len, offset, ptg)?;
+        advance_pos(&mut i, payload_len, rgce.len(), offset, ptg)?;
 
-            // Unknown ptg: stop scanning to avoid desync/false positives.
-            _ => break,
-        }
+        tokens.push(RgceToken {
+            offset,
+            ptg,
+            len: 1 + payload_len,
+            class: ptg_class(ptg),
+        });
     }
-
-    Ok(())
+    Ok(tokens)
 }
 
 /// Best-effort decode of a BIFF12 `rgce` token stream into formula text.
 ///
 /// The returned string does **not** include a leading `=`.
 pub fn decode_rgce(rgce: &[u8]) -> Result<String, DecodeRgceError> {
-    decode_rgce_impl(rgce, None, None)
+    decode_rgce_impl(rgce, None, None, None)
 }
 
 /// Best-effort decode of a BIFF12 `rgce` token stream into formula text, using a trailing `rgcb`
@@ -654,15 +632,17 @@ pub fn decode_rgce(rgce: &[u8]) -> Result<String, DecodeRgceError> {
 ///
 /// The returned string does **not** include a leading `=`.
 pub fn decode_rgce_with_rgcb(rgce: &[u8], rgcb: &[u8]) -> Result<String, DecodeRgceError> {
-    decode_rgce_impl(rgce, Some(rgcb), None)
+    decode_rgce_impl(rgce, Some(rgcb), None, None)
 }
 
 /// Best-effort decode of a BIFF12 `rgce` token stream into formula text, using a base cell for
 /// relative-reference tokens.
 ///
-/// Excel encodes certain formulas (notably in shared formulas) using relative-reference tokens
-/// like `PtgRefN` / `PtgAreaN` that store offsets from the formula's origin cell. This helper
-/// converts those relative offsets into A1-style references using the provided base coordinates.
+/// Excel encodes certain formulas (notably shared formulas) using relative-reference tokens like
+/// `PtgRefN` / `PtgAreaN`, whose relative components are offsets from the cell the formula is
+/// evaluated in. For a shared formula that is each *member* cell, not the anchor cell that stores
+/// the shared `rgce`: decoding the same `rgce` with different bases yields each member's own
+/// references. Components flagged as absolute are not shifted and decode with `$` markers.
 ///
 /// `base_row0` and `base_col0` are **0-indexed** cell coordinates (`A1` is `(0, 0)`).
 ///
@@ -672,13 +652,29 @@ pub fn decode_rgce_with_base(
     base_row0: u32,
     base_col0: u32,
 ) -> Result<String, DecodeRgceError> {
-    decode_rgce_impl(rgce, None, Some((base_row0, base_col0)))
+    decode_rgce_impl(rgce, None, Some((base_row0, base_col0)), None)
+}
+
+/// Best-effort decode of a BIFF12 `rgce` token stream into formula text, resolving the `ixti`
+/// of 3D reference tokens (`PtgRef3d` / `PtgArea3d`) through a sheet-name -> ixti map.
+///
+/// Sheet names are quoted only when Excel requires it (e.g. `'My Sheet'!A1`, `'2024'!A1`). An
+/// `ixti` missing from `sheets` falls back to the same `'Sheet{ixti}'` placeholder as
+/// [`decode_rgce`].
+///
+/// The returned string does **not** include a leading `=`.
+pub fn decode_rgce_with_sheets(
+    rgce: &[u8],
+    sheets: &HashMap<String, u16>,
+) -> Result<String, DecodeRgceError> {
+    decode_rgce_impl(rgce, None, None, Some(sheets))
 }
 
 fn decode_rgce_impl(
     rgce: &[u8],
     rgcb: Option<&[u8]>,
     base: Option<(u32, u32)>,
+    sheets: Option<&HashMap<String, u16>>,
 ) -> Result<String, DecodeRgceError> {
     if rgce.is_empty() {
         return Ok(String::new());
@@ -839,6 +835,10 @@ fn decode_rgce_impl(
                 stack.push(ExprFragment::missing());
             }
             // String literal.
+            //
+            // BIFF12 `PtgStr` is `[cch: u16][rgch: UTF-16LE]`. Unlike BIFF8's
+            // `ShortXLUnicodeString` there is no `fHighByte` flag byte: characters are never stored
+            // in the compressed (8-bit) form, so `cch` always counts 16-bit code units.
             0x17 => {
                 if rgce.len().saturating_sub(i) < 2 {
                     return Err(DecodeRgceError::UnexpectedEof {
@@ -1007,6 +1007,11 @@ fn decode_rgce_impl(
             //
             // Most attributes are evaluation hints or formatting metadata. We treat them as
             // non-printing tokens, but must consume their payload so later ptgs stay aligned.
+            // In particular `IF(c,a,b)` is stored as
+            //   c tAttrIf a tAttrGoto b tAttrGoto PtgFuncVar(IF)
+            // and `CHOOSE(i,a,b)` as
+            //   i tAttrChoose a tAttrGoto b tAttrGoto PtgFuncVar(CHOOSE)
+            // so the jump tokens carry no structure: the trailing PtgFuncVar rebuilds the call.
             //
             // Excel also uses `tAttrSum` as an optimization where `SUM(A1:A10)` is encoded as:
             //   PtgArea(A1:A10) + PtgAttr(tAttrSum)
@@ -1025,10 +1030,7 @@ fn decode_rgce_impl(
                 let w_attr = u16::from_le_bytes([hdr[1], hdr[2]]);
                 advance_pos(&mut i, 3, rgce.len(), ptg_offset, ptg)?;
 
-                const T_ATTR_CHOOSE: u8 = 0x04;
-                const T_ATTR_SUM: u8 = 0x10;
-
-                if grbit & T_ATTR_SUM != 0 {
+                if grbit & crate::ptg::PTG_ATTR_SUM != 0 {
                     let arg = stack.pop().ok_or(DecodeRgceError::StackUnderflow {
                         offset: ptg_offset,
                         ptg,
@@ -1048,9 +1050,9 @@ fn decode_rgce_impl(
                     stack.push(ExprFragment::new(text));
                 }
 
-                if grbit & T_ATTR_CHOOSE != 0 {
-                    // `tAttrChoose` is followed by a jump table of `u16` offsets (wAttr entries).
-                    let needed = (w_attr as usize).saturating_mul(2);
+                if grbit & crate::ptg::PTG_ATTR_CHOOSE != 0 {
+                    // `tAttrChoose` is followed by a jump table of `u16` offsets.
+                    let needed = attr_choose_jump_table_len(w_attr as usize);
                     if rgce.len().saturating_sub(i) < needed {
                         return Err(DecodeRgceError::UnexpectedEof {
                             offset: ptg_offset,
@@ -1556,7 +1558,7 @@ fn decode_rgce_impl(
                 i += 12;
                 stack.push(ExprFragment::new("#REF!".to_string()));
             }
-            // PtgRefN: [row_off: i32][col_off: i16]
+            // PtgRefN: [row: u32][col: u16] (relative components are offsets)
             0x2C | 0x4C | 0x6C => {
                 let Some((base_row0, base_col0)) = base else {
                     return Err(DecodeRgceError::UnsupportedToken {
@@ -1575,23 +1577,20 @@ fn decode_rgce_impl(
                 }
 
                 let hdr = slice_at(rgce, i, 6, ptg_offset, ptg)?;
-                let row_off = i32::from_le_bytes([hdr[0], hdr[1], hdr[2], hdr[3]]) as i64;
-                let col_off = i16::from_le_bytes([hdr[4], hdr[5]]) as i64;
+                let row_raw = u32::from_le_bytes([hdr[0], hdr[1], hdr[2], hdr[3]]);
+                let col_field = u16::from_le_bytes([hdr[4], hdr[5]]);
                 advance_pos(&mut i, 6, rgce.len(), ptg_offset, ptg)?;
 
-                const MAX_ROW0: i64 = 1_048_575;
-                const MAX_COL0: i64 = 0x3FFF;
-                let abs_row0 = base_row0 as i64 + row_off;
-                let abs_col0 = base_col0 as i64 + col_off;
-                if abs_row0 < 0 || abs_row0 > MAX_ROW0 || abs_col0 < 0 || abs_col0 > MAX_COL0 {
-                    stack.push(ExprFragment::new("#REF!".to_string()));
-                } else {
-                    let mut text = String::new();
-                    push_cell_ref_a1(&mut text, abs_row0 as u32, abs_col0 as u32);
-                    stack.push(ExprFragment::new(text));
+                match resolve_ref_n(row_raw, col_field, base_row0, base_col0) {
+                    Some((row0, col_field)) => {
+                        let mut text = String::new();
+                        push_cell_ref_from_field(&mut text, row0, col_field);
+                        stack.push(ExprFragment::new(text));
+                    }
+                    None => stack.push(ExprFragment::new("#REF!".to_string())),
                 }
             }
-            // PtgAreaN: [rowFirst_off: i32][rowLast_off: i32][colFirst_off: i16][colLast_off: i16]
+            // PtgAreaN: [rowFirst: u32][rowLast: u32][colFirst: u16][colLast: u16] (relative components are offsets)
             0x2D | 0x4D | 0x6D => {
                 let Some((base_row0, base_col0)) = base else {
                     return Err(DecodeRgceError::UnsupportedToken {
@@ -1610,31 +1609,16 @@ fn decode_rgce_impl(
                 }
 
                 let hdr = slice_at(rgce, i, 12, ptg_offset, ptg)?;
-                let row1_off = i32::from_le_bytes([hdr[0], hdr[1], hdr[2], hdr[3]]) as i64;
-                let row2_off = i32::from_le_bytes([hdr[4], hdr[5], hdr[6], hdr[7]]) as i64;
-                let col1_off = i16::from_le_bytes([hdr[8], hdr[9]]) as i64;
-                let col2_off = i16::from_le_bytes([hdr[10], hdr[11]]) as i64;
+                let row1_raw = u32::from_le_bytes([hdr[0], hdr[1], hdr[2], hdr[3]]);
+                let row2_raw = u32::from_le_bytes([hdr[4], hdr[5], hdr[6], hdr[7]]);
+                let col1_field = u16::from_le_bytes([hdr[8], hdr[9]]);
+                let col2_field = u16::from_le_bytes([hdr[10], hdr[11]]);
                 advance_pos(&mut i, 12, rgce.len(), ptg_offset, ptg)?;
 
-                const MAX_ROW0: i64 = 1_048_575;
-                const MAX_COL0: i64 = 0x3FFF;
-                let abs_row1 = base_row0 as i64 + row1_off;
-                let abs_row2 = base_row0 as i64 + row2_off;
-                let abs_col1 = base_col0 as i64 + col1_off;
-                let abs_col2 = base_col0 as i64 + col2_off;
-
-                if abs_row1 < 0
-                    || abs_row1 > MAX_ROW0
-                    || abs_row2 < 0
-                    || abs_row2 > MAX_ROW0
-                    || abs_col1 < 0
-                    || abs_col1 > MAX_COL0
-                    || abs_col2 < 0
-                    || abs_col2 > MAX_COL0
-                {
-                    stack.push(ExprFragment::new("#REF!".to_string()));
-                } else {
-                    let is_single_cell = abs_row1 == abs_row2 && abs_col1 == abs_col2;
+                let first = resolve_ref_n(row1_raw, col1_field, base_row0, base_col0);
+                let last = resolve_ref_n(row2_raw, col2_field, base_row0, base_col0);
+                if let (Some((row1, col1_field)), Some((row2, col2_field))) = (first, last) {
+                    let is_single_cell = row1 == row2 && col1_field == col2_field;
                     let is_value_class = (ptg & 0x60) == 0x40;
 
                     let mut text = String::new();
@@ -1642,12 +1626,10 @@ fn decode_rgce_impl(
                         // Preserve legacy implicit intersection semantics.
                         text.push('@');
                     }
-                    let (row1, col1) = (abs_row1 as u32, abs_col1 as u32);
-                    let (row2, col2) = (abs_row2 as u32, abs_col2 as u32);
-                    push_cell_ref_a1(&mut text, row1, col1);
+                    push_cell_ref_from_field(&mut text, row1, col1_field);
                     if !is_single_cell {
                         text.push(':');
-                        push_cell_ref_a1(&mut text, row2, col2);
+                        push_cell_ref_from_field(&mut text, row2, col2_field);
                     }
 
                     let mut frag = ExprFragment::new(text);
@@ -1656,6 +1638,8 @@ fn decode_rgce_impl(
                         frag.precedence = 70;
                     }
                     stack.push(frag);
+                } else {
+                    stack.push(ExprFragment::new("#REF!".to_string()));
                 }
             }
             // PtgRef3d: [ixti: u16][row: u32][col: u16]
@@ -1674,7 +1658,7 @@ fn decode_rgce_impl(
                 let col_field = u16::from_le_bytes([hdr[6], hdr[7]]);
                 advance_pos(&mut i, 8, rgce.len(), ptg_offset, ptg)?;
 
-                let prefix = format_sheet_placeholder(ixti);
+                let prefix = format_sheet_prefix(ixti, sheets);
                 let mut text = prefix;
                 push_cell_ref_from_field(&mut text, row0, col_field);
                 stack.push(ExprFragment::new(text));
@@ -1697,7 +1681,7 @@ fn decode_rgce_impl(
                 let col_last = u16::from_le_bytes([hdr[12], hdr[13]]);
                 advance_pos(&mut i, 14, rgce.len(), ptg_offset, ptg)?;
 
-                let prefix = format_sheet_placeholder(ixti);
+                let prefix = format_sheet_prefix(ixti, sheets);
 
                 let is_single_cell =
                     row_first0 == row_last0 && (col_first & 0x3FFF) == (col_last & 0x3FFF);
@@ -1791,6 +1775,27 @@ fn decode_rgce_impl(
     }
 }
 
+fn format_sheet_prefix(ixti: u16, sheets: Option<&HashMap<String, u16>>) -> String {
+    // Pick the smallest matching name so the output is deterministic if several names share an
+    // ixti.
+    let name = sheets.and_then(|sheets| {
+        sheets
+            .iter()
+            .filter(|(_, &idx)| idx == ixti)
+            .map(|(name, _)| name)
+            .min()
+    });
+    match name {
+        Some(name) => {
+            let mut out = String::new();
+            push_sheet_name_a1(&mut out, name);
+            out.push('!');
+            out
+        }
+        None => format_sheet_placeholder(ixti),
+    }
+}
+
 fn format_sheet_placeholder(ixti: u16) -> String {
     // Best-effort placeholder: without workbook context we cannot resolve `ixti` into a real sheet
     // name, but we can still emit valid sheet-qualified formula text by quoting a stable placeholder.
@@ -1809,9 +1814,45 @@ fn push_cell_ref_from_field(out: &mut String, row0: u32, col_field: u16) {
     push_a1_cell_ref_row1(row1, col, !col_relative, !row_relative, out);
 }
 
-fn push_cell_ref_a1(out: &mut String, row0: u32, col0: u32) {
-    // Best-effort absolute coordinates: no `$` markers.
-    push_a1_cell_ref_row1(u64::from(row0) + 1, col0, false, false, out);
+/// Resolve one `PtgRefN` / `PtgAreaN` coordinate against the cell that owns the formula.
+///
+/// The relative flags live in the column field, as for `PtgRef`. A relative row is a signed
+/// offset from `base_row0`; a relative column is a signed 14-bit offset from `base_col0`.
+/// Absolute components are plain coordinates and ignore the base.
+///
+/// Returns the absolute 0-based row and a column field carrying the absolute column with the
+/// original relative flags (so `$` markers survive), or `None` when the reference falls off the
+/// grid (`#REF!`).
+fn resolve_ref_n(
+    row_raw: u32,
+    col_field: u16,
+    base_row0: u32,
+    base_col0: u32,
+) -> Option<(u32, u16)> {
+    const MAX_ROW0: i64 = 1_048_575;
+    const MAX_COL0: i64 = 0x3FFF;
+
+    let col_relative = (col_field & 0x8000) == 0x8000;
+    let row_relative = (col_field & 0x4000) == 0x4000;
+    let col_raw = col_field & 0x3FFF;
+
+    let row0 = if row_relative {
+        i64::from(base_row0) + i64::from(row_raw as i32)
+    } else {
+        i64::from(row_raw)
+    };
+    let col0 = if col_relative {
+        // Sign-extend the 14-bit column offset.
+        let col_off = ((col_raw << 2) as i16) >> 2;
+        i64::from(base_col0) + i64::from(col_off)
+    } else {
+        i64::from(col_raw)
+    };
+
+    if !(0..=MAX_ROW0).contains(&row0) || !(0..=MAX_COL0).contains(&col0) {
+        return None;
+    }
+    Some((row0 as u32, (col0 as u16) | (col_field & 0xC000)))
 }
 
 fn decode_ptg_list_payload_best_effort(payload: &[u8; 12]) -> PtgListDecoded {
@@ -1956,6 +1997,8 @@ pub enum EncodeRgceError {
     InvalidNumber(String),
     #[error("unsupported error literal: {0}")]
     InvalidErrorLiteral(String),
+    #[error("unknown sheet: {0}")]
+    UnknownSheet(String),
 }
 
 #[cfg(feature = "encode")]
@@ -1977,7 +2020,7 @@ pub fn encode_rgce_with_rgcb(formula: &str) -> Result<EncodedRgce, EncodeRgceErr
         })?;
     let mut rgce = Vec::new();
     let mut rgcb = Vec::new();
-    encode_expr(&ast.expr, &mut rgce, &mut rgcb)?;
+    encode_expr(&ast.expr, &mut rgce, &mut rgcb, None)?;
     Ok(EncodedRgce { rgce, rgcb })
 }
 
@@ -1990,6 +2033,67 @@ pub fn encode_rgce(formula: &str) -> Result<Vec<u8>, EncodeRgceError> {
     Ok(encoded.rgce)
 }
 
+/// Encode formula text into `rgce`, emitting `PtgRef3d` / `PtgArea3d` for sheet-qualified
+/// references.
+///
+/// `sheets` maps sheet names to their `ixti` (index into the workbook's extern-sheet table). Names
+/// are matched case-insensitively, so `='my sheet'!A1` resolves through a `"My Sheet"` entry.
+/// Sheet-qualified references to a sheet missing from the map fail with
+/// [`EncodeRgceError::UnknownSheet`].
+#[cfg(feature = "encode")]
+pub fn encode_rgce_with_sheets(
+    formula: &str,
+    sheets: &HashMap<String, u16>,
+) -> Result<Vec<u8>, EncodeRgceError> {
+    use formula_engine::{parse_formula, ParseOptions};
+
+    let ast =
+        parse_formula(formula, ParseOptions::default()).map_err(|e| EncodeRgceError::Parse {
+            message: e.message,
+            start: e.span.start,
+            end: e.span.end,
+        })?;
+    let mut rgce = Vec::new();
+    let mut rgcb = Vec::new();
+    encode_expr(&ast.expr, &mut rgce, &mut rgcb, Some(sheets))?;
+    if !rgcb.is_empty() {
+        return Err(EncodeRgceError::Unsupported("array literals"));
+    }
+    Ok(rgce)
+}
+
+/// Resolve the sheet prefix of a reference into an `ixti`.
+///
+/// Returns `Ok(None)` for unqualified references.
+#[cfg(feature = "encode")]
+fn resolve_sheet_ixti(
+    r: &formula_engine::CellRef,
+    sheets: Option<&HashMap<String, u16>>,
+) -> Result<Option<u16>, EncodeRgceError> {
+    if r.workbook.is_some() {
+        return Err(EncodeRgceError::Unsupported("external workbook references"));
+    }
+    let Some(sheet) = r.sheet.as_ref() else {
+        return Ok(None);
+    };
+    let Some(sheets) = sheets else {
+        return Err(EncodeRgceError::Unsupported(
+            "3D/sheet-qualified references",
+        ));
+    };
+    let Some(name) = sheet.as_single_sheet() else {
+        return Err(EncodeRgceError::Unsupported("3D sheet-span references"));
+    };
+    if let Some(&ixti) = sheets.get(name) {
+        return Ok(Some(ixti));
+    }
+    sheets
+        .iter()
+        .find(|(candidate, _)| formula_model::sheet_name_eq_case_insensitive(candidate, name))
+        .map(|(_, &ixti)| Some(ixti))
+        .ok_or_else(|| EncodeRgceError::UnknownSheet(name.to_string()))
+}
+
 #[cfg(feature = "encode")]
 fn push_utf16le_u16_len_with_rollback(
     out: &mut Vec<u8>,
@@ -2032,6 +2136,7 @@ fn encode_expr(
     expr: &formula_engine::Expr,
     rgce: &mut Vec<u8>,
     rgcb: &mut Vec<u8>,
+    sheets: Option<&HashMap<String, u16>>,
 ) -> Result<(), EncodeRgceError> {
     use formula_engine::{BinaryOp, Coord, Expr, PostfixOp, UnaryOp};
 
@@ -2065,11 +2170,7 @@ fn encode_expr(
             rgce.push(code);
         }
         Expr::CellRef(r) => {
-            if r.workbook.is_some() || r.sheet.is_some() {
-                return Err(EncodeRgceError::Unsupported(
-                    "3D/sheet-qualified references",
-                ));
-            }
+            let ixti = resolve_sheet_ixti(r, sheets)?;
             let (col, col_abs) = match &r.col {
                 Coord::A1 { index, abs } => (*index, *abs),
                 Coord::Offset(_) => return Err(EncodeRgceError::Unsupported("relative offsets")),
@@ -2078,25 +2179,36 @@ fn encode_expr(
                 Coord::A1 { index, abs } => (*index, *abs),
                 Coord::Offset(_) => return Err(EncodeRgceError::Unsupported("relative offsets")),
             };
-            rgce.push(0x24); // PtgRef
+            match ixti {
+                Some(ixti) => {
+                    rgce.push(0x3A); // PtgRef3d
+                    rgce.extend_from_slice(&ixti.to_le_bytes());
+                }
+                None => rgce.push(0x24), // PtgRef
+            }
             rgce.extend_from_slice(&row.to_le_bytes());
             rgce.extend_from_slice(&encode_col_with_flags(col, col_abs, row_abs));
         }
         Expr::Binary(b) if b.op == BinaryOp::Range => {
-            // Prefer encoding simple A1:A2 areas as PtgArea for Excel-compatible rgce.
+            // Prefer encoding simple A1:A2 / Sheet1!A1:A2 areas as PtgArea / PtgArea3d for
+            // Excel-compatible rgce.
             if let (Expr::CellRef(a), Expr::CellRef(bref)) = (&*b.left, &*b.right) {
-                if a.workbook.is_none()
-                    && a.sheet.is_none()
-                    && bref.workbook.is_none()
-                    && bref.sheet.is_none()
-                {
+                let a_ixti = resolve_sheet_ixti(a, sheets)?;
+                let b_ixti = resolve_sheet_ixti(bref, sheets)?;
+                if b_ixti.is_none() || b_ixti == a_ixti {
                     if let (Some((c1, c1_abs)), Some((r1, r1_abs))) =
                         (coord_to_a1(&a.col), coord_to_a1(&a.row))
                     {
                         if let (Some((c2, c2_abs)), Some((r2, r2_abs))) =
                             (coord_to_a1(&bref.col), coord_to_a1(&bref.row))
                         {
-                            rgce.push(0x25); // PtgArea
+                            match a_ixti {
+                                Some(ixti) => {
+                                    rgce.push(0x3B); // PtgArea3d
+                                    rgce.extend_from_slice(&ixti.to_le_bytes());
+                                }
+                                None => rgce.push(0x25), // PtgArea
+                            }
                             rgce.extend_from_slice(&r1.to_le_bytes());
                             rgce.extend_from_slice(&r2.to_le_bytes());
                             rgce.extend_from_slice(&encode_col_with_flags(c1, c1_abs, r1_abs));
@@ -2108,13 +2220,13 @@ fn encode_expr(
             }
 
             // Fallback: encode as operator.
-            encode_expr(&b.left, rgce, rgcb)?;
-            encode_expr(&b.right, rgce, rgcb)?;
+            encode_expr(&b.left, rgce, rgcb, sheets)?;
+            encode_expr(&b.right, rgce, rgcb, sheets)?;
             rgce.push(0x11); // PtgRange
         }
         Expr::Binary(b) => {
-            encode_expr(&b.left, rgce, rgcb)?;
-            encode_expr(&b.right, rgce, rgcb)?;
+            encode_expr(&b.left, rgce, rgcb, sheets)?;
+            encode_expr(&b.right, rgce, rgcb, sheets)?;
             let ptg = match b.op {
                 BinaryOp::Add => 0x03,
                 BinaryOp::Sub => 0x04,
@@ -2137,11 +2249,7 @@ fn encode_expr(
         Expr::Unary(u) if u.op == UnaryOp::ImplicitIntersection => {
             match &*u.expr {
                 Expr::CellRef(r) => {
-                    if r.workbook.is_some() || r.sheet.is_some() {
-                        return Err(EncodeRgceError::Unsupported(
-                            "3D/sheet-qualified references",
-                        ));
-                    }
+                    let ixti = resolve_sheet_ixti(r, sheets)?;
                     let (col, col_abs) = match &r.col {
                         Coord::A1 { index, abs } => (*index, *abs),
                         Coord::Offset(_) => {
@@ -2157,7 +2265,13 @@ fn encode_expr(
 
                     // Encode `@A1` by emitting a value-class reference token (PtgRefV). Excel
                     // uses this representation for legacy implicit intersection.
-                    rgce.push(0x44); // PtgRefV
+                    match ixti {
+                        Some(ixti) => {
+                            rgce.push(0x5A); // PtgRef3dV
+                            rgce.extend_from_slice(&ixti.to_le_bytes());
+                        }
+                        None => rgce.push(0x44), // PtgRefV
+                    }
                     rgce.extend_from_slice(&row.to_le_bytes());
                     rgce.extend_from_slice(&encode_col_with_flags(col, col_abs, row_abs));
                 }
@@ -2167,20 +2281,24 @@ fn encode_expr(
                     ));
                 }
                 Expr::Binary(b) if b.op == BinaryOp::Range => {
-                    // Encode `@A1:A2` as PtgAreaV.
+                    // Encode `@A1:A2` as PtgAreaV (PtgArea3dV when sheet-qualified).
                     if let (Expr::CellRef(a), Expr::CellRef(bref)) = (&*b.left, &*b.right) {
-                        if a.workbook.is_none()
-                            && a.sheet.is_none()
-                            && bref.workbook.is_none()
-                            && bref.sheet.is_none()
-                        {
+                        let a_ixti = resolve_sheet_ixti(a, sheets)?;
+                        let b_ixti = resolve_sheet_ixti(bref, sheets)?;
+                        if b_ixti.is_none() || b_ixti == a_ixti {
                             if let (Some((c1, c1_abs)), Some((r1, r1_abs))) =
                                 (coord_to_a1(&a.col), coord_to_a1(&a.row))
                             {
                                 if let (Some((c2, c2_abs)), Some((r2, r2_abs))) =
                                     (coord_to_a1(&bref.col), coord_to_a1(&bref.row))
                                 {
-                                    rgce.push(0x45); // PtgAreaV
+                                    match a_ixti {
+                                        Some(ixti) => {
+                                            rgce.push(0x5B); // PtgArea3dV
+                                            rgce.extend_from_slice(&ixti.to_le_bytes());
+                                        }
+                                        None => rgce.push(0x45), // PtgAreaV
+                                    }
                                     rgce.extend_from_slice(&r1.to_le_bytes());
                                     rgce.extend_from_slice(&r2.to_le_bytes());
                                     rgce.extend_from_slice(&encode_col_with_flags(
@@ -2207,7 +2325,7 @@ fn encode_expr(
             }
         }
         Expr::Unary(u) => {
-            encode_expr(&u.expr, rgce, rgcb)?;
+            encode_expr(&u.expr, rgce, rgcb, sheets)?;
             match u.op {
                 UnaryOp::Plus => rgce.push(0x12),
                 UnaryOp::Minus => rgce.push(0x13),
@@ -2217,7 +2335,7 @@ fn encode_expr(
             }
         }
         Expr::Postfix(p) => {
-            encode_expr(&p.expr, rgce, rgcb)?;
+            encode_expr(&p.expr, rgce, rgcb, sheets)?;
             match p.op {
                 PostfixOp::Percent => rgce.push(0x14),
                 PostfixOp::SpillRange => rgce.push(0x2F),
@@ -2244,7 +2362,7 @@ fn encode_expr(
                 if matches!(arg, Expr::Missing) {
                     rgce.push(0x16); // PtgMissArg
                 } else {
-                    encode_expr(arg, rgce, rgcb)?;
+                    encode_expr(arg, rgce, rgcb, sheets)?;
                 }
             }
 
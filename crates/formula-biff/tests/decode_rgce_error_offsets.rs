@@ -200,12 +200,12 @@ fn decode_rgce_reports_offset_for_truncated_ptgattr_header() {
 
 #[test]
 fn decode_rgce_reports_offset_for_truncated_tattrchoose_jump_table() {
-    // PtgAttr(tAttrChoose, wAttr=2) requires 4 jump-table bytes after the 3-byte attr header.
-    // Provide only 2.
+    // PtgAttr(tAttrChoose, wAttr=2) requires 6 jump-table bytes (wAttr + 1 entries) after the
+    // 3-byte attr header. Provide only 4.
     let rgce = [
         0x1E, 0x01, 0x00, // PtgInt(1) (prefix)
         0x19, 0x04, 0x02, 0x00, // PtgAttr(tAttrChoose, wAttr=2)
-        0xFF, 0xFF, // truncated jump table (needs 4 bytes)
+        0xFF, 0xFF, 0xFF, 0xFF, // truncated jump table (needs 6 bytes)
     ];
     let err = decode_rgce(&rgce).expect_err("expected truncated tAttrChoose jump table");
     assert!(
@@ -214,8 +214,8 @@ fn decode_rgce_reports_offset_for_truncated_tattrchoose_jump_table() {
             DecodeRgceError::UnexpectedEof {
                 offset: 3,
                 ptg: 0x19,
-                needed: 4,
-                remaining: 2
+                needed: 6,
+                remaining: 4
             }
         ),
         "expected UnexpectedEof at offset 3 for ptg=0x19, got {err:?}"
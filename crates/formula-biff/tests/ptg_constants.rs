@@ -0,0 +1,68 @@
+use formula_biff::decode_rgce;
+use formula_biff::ptg::{self, with_class};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn with_class_swaps_only_class_bits() {
+    assert_eq!(with_class(ptg::PTG_REF, ptg::PTG_CLASS_VALUE), 0x44);
+    assert_eq!(with_class(ptg::PTG_REF, ptg::PTG_CLASS_ARRAY), 0x64);
+    assert_eq!(with_class(0x64, ptg::PTG_CLASS_REF), ptg::PTG_REF);
+    assert_eq!(with_class(ptg::PTG_NAME_X, ptg::PTG_CLASS_VALUE), 0x59);
+    assert_eq!(
+        with_class(ptg::PTG_AREA_3D, ptg::PTG_CLASS_ARRAY) & ptg::PTG_BASE_MASK,
+        ptg::PTG_AREA_3D & ptg::PTG_BASE_MASK
+    );
+}
+
+#[test]
+fn constants_match_decoder_numbering() {
+    // A1 + 1 > 2
+    let mut rgce = vec![with_class(ptg::PTG_REF, ptg::PTG_CLASS_VALUE)];
+    rgce.extend_from_slice(&0u32.to_le_bytes());
+    rgce.extend_from_slice(&0xC000u16.to_le_bytes());
+    rgce.extend_from_slice(&[
+        ptg::PTG_INT,
+        1,
+        0,
+        ptg::PTG_ADD,
+        ptg::PTG_INT,
+        2,
+        0,
+        ptg::PTG_GT,
+    ]);
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "A1+1>2");
+
+    // SUM(1,"x") via PtgFuncVar(argc=2, iftab=4)
+    let mut rgce = vec![ptg::PTG_INT, 1, 0, ptg::PTG_STR, 1, 0, b'x', 0];
+    rgce.extend_from_slice(&[with_class(ptg::PTG_FUNC_VAR, ptg::PTG_CLASS_VALUE), 2, 4, 0]);
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "SUM(1,\"x\")");
+
+    let rgce = [
+        ptg::PTG_BOOL,
+        1,
+        ptg::PTG_PAREN,
+        ptg::PTG_PERCENT,
+        ptg::PTG_ERR,
+        0x07,
+        ptg::PTG_CONCAT,
+    ];
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "(TRUE)%&#DIV/0!");
+}
+
+#[cfg(feature = "encode")]
+#[test]
+fn encoder_emits_exported_opcodes() {
+    use formula_biff::encode_rgce;
+
+    // Percent binds tighter than negation in the encoder: `-(1%)`.
+    let rgce = encode_rgce("=-1%").expect("encode");
+    assert_eq!(
+        rgce,
+        vec![ptg::PTG_INT, 1, 0, ptg::PTG_PERCENT, ptg::PTG_UMINUS]
+    );
+    let rgce = encode_rgce("=1<>2").expect("encode");
+    assert_eq!(
+        rgce,
+        vec![ptg::PTG_INT, 1, 0, ptg::PTG_INT, 2, 0, ptg::PTG_NE]
+    );
+}
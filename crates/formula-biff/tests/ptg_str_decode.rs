@@ -26,3 +26,41 @@ fn ptg_str_decodes_well_formed_utf16_identically() {
     let decoded = decode_rgce(&rgce).expect("decode");
     assert_eq!(decoded, "\"Hello\"");
 }
+
+#[test]
+fn ptg_str_doubles_embedded_quotes() {
+    let units: Vec<u16> = r#"say "hi""#.encode_utf16().collect();
+    let rgce = ptg_str(&units);
+    let decoded = decode_rgce(&rgce).expect("decode");
+    assert_eq!(decoded, r#""say ""hi""""#);
+}
+
+#[test]
+fn ptg_str_preserves_embedded_newline() {
+    let units: Vec<u16> = "line1\nline2".encode_utf16().collect();
+    let rgce = ptg_str(&units);
+    let decoded = decode_rgce(&rgce).expect("decode");
+    assert_eq!(decoded, "\"line1\nline2\"");
+}
+
+#[test]
+fn ptg_str_decodes_non_bmp_surrogate_pairs() {
+    // U+1F600 is stored as a surrogate pair, so `cch` counts 2 code units for one char.
+    let text = "a\u{1F600}b";
+    let units: Vec<u16> = text.encode_utf16().collect();
+    assert_eq!(units.len(), 4);
+    let rgce = ptg_str(&units);
+    let decoded = decode_rgce(&rgce).expect("decode");
+    assert_eq!(decoded, format!("\"{text}\""));
+}
+
+#[test]
+fn ptg_str_uses_16_bit_character_count() {
+    // BIFF12 string lengths are 16-bit; make sure lengths above 255 are not truncated.
+    let text = "x".repeat(300);
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let rgce = ptg_str(&units);
+    assert_eq!(&rgce[1..3], &300u16.to_le_bytes());
+    let decoded = decode_rgce(&rgce).expect("decode");
+    assert_eq!(decoded, format!("\"{text}\""));
+}
@@ -92,8 +92,8 @@ fn skips_memfunc_and_attrchoose_payloads() {
     // PtgAttr(tAttrChoose): [ptg=0x19][grbit=0x04][wAttr: u16][jump_table...]
     rgce.push(0x19);
     rgce.push(0x04); // tAttrChoose
-    rgce.extend_from_slice(&2u16.to_le_bytes()); // wAttr=2 -> 4 jump-table bytes
-    rgce.extend_from_slice(&[0x30, 0x30, 0x30, 0x30]); // would desync if not skipped
+    rgce.extend_from_slice(&2u16.to_le_bytes()); // wAttr=2 -> 3 entries, 6 jump-table bytes
+    rgce.extend_from_slice(&[0x30, 0x30, 0x30, 0x30, 0x30, 0x30]); // would desync if not skipped
 
     rgce.extend_from_slice(&ptg_int(2));
     rgce.push(0x03); // PtgAdd
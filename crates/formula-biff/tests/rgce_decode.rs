@@ -37,13 +37,13 @@ fn decodes_optimized_sum_using_tattrsum() {
 
 #[test]
 fn consumes_tattrchoose_jump_table_bytes_to_keep_offsets_aligned() {
-    // `tAttrChoose` is followed by a `u16` jump table (wAttr entries).
+    // `tAttrChoose` is followed by a `u16` jump table (wAttr + 1 entries).
     // The jump offsets are evaluator metadata, but we must still consume them so subsequent tokens
     // stay aligned.
     let rgce = [
         0x1E, 0x01, 0x00, // PtgInt(1)
         0x19, 0x04, 0x01, 0x00, // PtgAttr(tAttrChoose, wAttr=1)
-        0xFF, 0xFF, // jump table entry (ignored)
+        0xFF, 0xFF, 0xFF, 0xFF, // jump table entries (ignored)
         0x1E, 0x02, 0x00, // PtgInt(2)
         0x03, // PtgAdd
     ];
@@ -53,3 +53,84 @@ fn consumes_tattrchoose_jump_table_bytes_to_keep_offsets_aligned() {
     assert_parses_and_roundtrips(&text);
 }
 
+#[test]
+fn decodes_nested_if_with_tattrif_and_tattrgoto_tokens() {
+    // Excel stores `=IF(A1,1,IF(B1,2,3))` with short-circuit jumps around each branch:
+    //   A1 tAttrIf 1 tAttrGoto [B1 tAttrIf 2 tAttrGoto 3 tAttrGoto IF] tAttrGoto IF
+    let rgce = [
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, // PtgRef A1
+        0x19, 0x02, 0x07, 0x00, // PtgAttr(tAttrIf) -> false branch
+        0x1E, 0x01, 0x00, // PtgInt(1)
+        0x19, 0x08, 0x2F, 0x00, // PtgAttr(tAttrGoto) -> past outer IF
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, // PtgRef B1
+        0x19, 0x02, 0x07, 0x00, // PtgAttr(tAttrIf) -> inner false branch
+        0x1E, 0x02, 0x00, // PtgInt(2)
+        0x19, 0x08, 0x0A, 0x00, // PtgAttr(tAttrGoto) -> past inner IF
+        0x1E, 0x03, 0x00, // PtgInt(3)
+        0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrGoto) -> past inner IF
+        0x22, 0x03, 0x01, 0x00, // PtgFuncVar(argc=3, IF)
+        0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrGoto) -> past outer IF
+        0x22, 0x03, 0x01, 0x00, // PtgFuncVar(argc=3, IF)
+    ];
+
+    let text = decode_rgce(&rgce).expect("decode");
+    assert_eq!(text, "IF(A1,1,IF(B1,2,3))");
+    assert_parses_and_roundtrips(&text);
+}
+
+#[test]
+fn decodes_choose_with_tattrchoose_jump_table_and_nested_if() {
+    // `=CHOOSE(A1,10,IF(B1,20,30))+1`:
+    //   A1 tAttrChoose[3 offsets] 10 tAttrGoto [B1 tAttrIf 20 tAttrGoto 30 tAttrGoto IF]
+    //   tAttrGoto CHOOSE 1 PtgAdd
+    // The jump table has wAttr + 1 entries; consuming only wAttr would desync every token after it.
+    let rgce = [
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, // PtgRef A1
+        0x19, 0x04, 0x02, 0x00, // PtgAttr(tAttrChoose, wAttr=2)
+        0x06, 0x00, 0x0D, 0x00, 0x34, 0x00, // jump table (3 entries)
+        0x1E, 0x0A, 0x00, // PtgInt(10)
+        0x19, 0x08, 0x2B, 0x00, // PtgAttr(tAttrGoto)
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, // PtgRef B1
+        0x19, 0x02, 0x07, 0x00, // PtgAttr(tAttrIf)
+        0x1E, 0x14, 0x00, // PtgInt(20)
+        0x19, 0x08, 0x0A, 0x00, // PtgAttr(tAttrGoto)
+        0x1E, 0x1E, 0x00, // PtgInt(30)
+        0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrGoto)
+        0x22, 0x03, 0x01, 0x00, // PtgFuncVar(argc=3, IF)
+        0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrGoto)
+        0x22, 0x03, 0x64, 0x00, // PtgFuncVar(argc=3, CHOOSE)
+        0x1E, 0x01, 0x00, // PtgInt(1)
+        0x03, // PtgAdd
+    ];
+
+    let text = decode_rgce(&rgce).expect("decode");
+    assert_eq!(text, "CHOOSE(A1,10,IF(B1,20,30))+1");
+    assert_parses_and_roundtrips(&text);
+}
+
+#[test]
+fn ptg_paren_preserves_redundant_user_parentheses() {
+    // Excel records parentheses the user typed as `PtgParen`, even when precedence does not need
+    // them. `=(A1)+(B1)` is stored as:
+    //   PtgRef(A1) PtgParen PtgRef(B1) PtgParen PtgAdd
+    let rgce = [
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, // PtgRef A1
+        0x15, // PtgParen
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, // PtgRef B1
+        0x15, // PtgParen
+        0x03, // PtgAdd
+    ];
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "(A1)+(B1)");
+
+    // Nested redundant parens around an operand that already needs them: `=((A1+B1))*2`.
+    let rgce = [
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, // PtgRef A1
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, // PtgRef B1
+        0x03, // PtgAdd
+        0x15, // PtgParen
+        0x15, // PtgParen
+        0x1E, 0x02, 0x00, // PtgInt(2)
+        0x05, // PtgMul
+    ];
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "((A1+B1))*2");
+}
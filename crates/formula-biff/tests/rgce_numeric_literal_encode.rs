@@ -0,0 +1,45 @@
+#![cfg(feature = "encode")]
+
+use formula_biff::{decode_rgce, encode_rgce};
+use pretty_assertions::assert_eq;
+
+fn ptg_num(n: f64) -> Vec<u8> {
+    let mut out = vec![0x1F]; // PtgNum
+    out.extend_from_slice(&n.to_le_bytes());
+    out
+}
+
+#[test]
+fn encode_uses_ptg_int_for_zero() {
+    assert_eq!(encode_rgce("=0").expect("encode"), vec![0x1E, 0x00, 0x00]);
+}
+
+#[test]
+fn encode_uses_ptg_int_for_u16_max() {
+    assert_eq!(
+        encode_rgce("=65535").expect("encode"),
+        vec![0x1E, 0xFF, 0xFF]
+    );
+}
+
+#[test]
+fn encode_uses_ptg_num_above_u16_range() {
+    assert_eq!(encode_rgce("=65536").expect("encode"), ptg_num(65536.0));
+}
+
+#[test]
+fn encode_negative_integer_as_ptg_int_with_unary_minus() {
+    // Excel has no signed `PtgInt`; negative literals are a positive operand followed by
+    // `PtgUminus`.
+    let rgce = encode_rgce("=-5").expect("encode");
+    assert_eq!(rgce, vec![0x1E, 0x05, 0x00, 0x13]);
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "-5");
+}
+
+#[test]
+#[allow(clippy::approx_constant)] // `3.14` is a literal from formula text, not an approximation of PI.
+fn encode_uses_ptg_num_for_fractional_values() {
+    let rgce = encode_rgce("=3.14").expect("encode");
+    assert_eq!(rgce, ptg_num(3.14));
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "3.14");
+}
@@ -16,21 +16,40 @@ fn ptg_areaerr() -> [u8; 13] {
     [0x2B, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
 }
 
+/// Column field for a fully relative `PtgRefN`/`PtgAreaN` component: a 14-bit signed column
+/// offset plus the row/col relative flags.
+fn rel_col(col_off: i16) -> u16 {
+    (col_off as u16 & 0x3FFF) | 0xC000
+}
+
 fn ptg_refn(row_off: i32, col_off: i16) -> Vec<u8> {
+    ptg_refn_raw(row_off as u32, rel_col(col_off))
+}
+
+fn ptg_refn_raw(row: u32, col_field: u16) -> Vec<u8> {
     let mut out = Vec::new();
     out.push(0x2C); // PtgRefN
-    out.extend_from_slice(&row_off.to_le_bytes());
-    out.extend_from_slice(&col_off.to_le_bytes());
+    out.extend_from_slice(&row.to_le_bytes());
+    out.extend_from_slice(&col_field.to_le_bytes());
     out
 }
 
 fn ptg_arean(row1_off: i32, row2_off: i32, col1_off: i16, col2_off: i16) -> Vec<u8> {
+    ptg_arean_raw(
+        row1_off as u32,
+        row2_off as u32,
+        rel_col(col1_off),
+        rel_col(col2_off),
+    )
+}
+
+fn ptg_arean_raw(row1: u32, row2: u32, col1_field: u16, col2_field: u16) -> Vec<u8> {
     let mut out = Vec::new();
     out.push(0x2D); // PtgAreaN
-    out.extend_from_slice(&row1_off.to_le_bytes());
-    out.extend_from_slice(&row2_off.to_le_bytes());
-    out.extend_from_slice(&col1_off.to_le_bytes());
-    out.extend_from_slice(&col2_off.to_le_bytes());
+    out.extend_from_slice(&row1.to_le_bytes());
+    out.extend_from_slice(&row2.to_le_bytes());
+    out.extend_from_slice(&col1_field.to_le_bytes());
+    out.extend_from_slice(&col2_field.to_le_bytes());
     out
 }
 
@@ -68,6 +87,61 @@ fn decodes_ptgarean_with_base() {
     assert_eq!(decode_rgce_with_base(&rgce, 2, 2).expect("decode"), "B2:D4");
 }
 
+#[test]
+fn shared_formula_rgce_decodes_relative_to_each_member_cell() {
+    // Shared formula anchored at C3 as `=A1+SUM(B2:B4)`; members decode against their own cell.
+    let mut rgce = ptg_refn(-2, -2);
+    rgce.extend_from_slice(&ptg_arean(-1, 1, -1, -1));
+    rgce.extend_from_slice(&[0x22, 0x01, 0x04, 0x00]); // PtgFuncVar(argc=1, SUM)
+    rgce.push(0x03); // PtgAdd
+
+    assert_eq!(
+        decode_rgce_with_base(&rgce, 2, 2).expect("decode at C3"),
+        "A1+SUM(B2:B4)"
+    );
+    // Member E7 (row0=6, col0=4): every relative reference shifts by (+4, +2).
+    assert_eq!(
+        decode_rgce_with_base(&rgce, 6, 4).expect("decode at E7"),
+        "C5+SUM(D6:D8)"
+    );
+}
+
+#[test]
+fn ptgrefn_positive_column_offsets_use_the_14_bit_field() {
+    // `B1` relative to A1 is (+0, +1); the flags sit above the 14-bit offset.
+    let rgce = ptg_refn(0, 1);
+    assert_eq!(decode_rgce_with_base(&rgce, 0, 0).expect("decode"), "B1");
+    assert_eq!(decode_rgce_with_base(&rgce, 9, 25).expect("decode"), "AA10");
+}
+
+#[test]
+fn ptgrefn_absolute_components_ignore_the_base() {
+    // `$A1`: absolute column 0, relative row offset 0.
+    let mixed_col = ptg_refn_raw(0, 0x4000);
+    assert_eq!(
+        decode_rgce_with_base(&mixed_col, 4, 3).expect("decode"),
+        "$A5"
+    );
+
+    // `B$1`: absolute row 0, relative column offset -2 (from D).
+    let mixed_row = ptg_refn_raw(0, (-2i16 as u16 & 0x3FFF) | 0x8000);
+    assert_eq!(
+        decode_rgce_with_base(&mixed_row, 4, 3).expect("decode"),
+        "B$1"
+    );
+
+    // `$A$1:$B$2` decodes identically from any member cell.
+    let absolute = ptg_arean_raw(0, 1, 0, 1);
+    assert_eq!(
+        decode_rgce_with_base(&absolute, 0, 0).expect("decode"),
+        "$A$1:$B$2"
+    );
+    assert_eq!(
+        decode_rgce_with_base(&absolute, 50, 7).expect("decode"),
+        "$A$1:$B$2"
+    );
+}
+
 #[test]
 fn ptgrefn_out_of_bounds_emits_ref() {
     // Base cell A1 + row_off=-1 is out-of-bounds.
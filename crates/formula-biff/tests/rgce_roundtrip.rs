@@ -1,7 +1,10 @@
 #![cfg(feature = "encode")]
 
-use formula_biff::{decode_rgce, encode_rgce, EncodeRgceError};
+use formula_biff::{
+    decode_rgce, decode_rgce_with_sheets, encode_rgce, encode_rgce_with_sheets, EncodeRgceError,
+};
 use pretty_assertions::assert_eq;
+use std::collections::HashMap;
 
 fn normalize(formula: &str) -> String {
     let ast = formula_engine::parse_formula(formula, formula_engine::ParseOptions::default())
@@ -64,6 +67,71 @@ fn rgce_roundtrip_spill_range() {
     assert_eq!(normalize("A1#"), normalize(&decoded));
 }
 
+#[test]
+fn rgce_roundtrip_sheet_qualified_refs_requote_special_names() {
+    let sheets: HashMap<String, u16> = [
+        ("Data".to_string(), 0),
+        ("My Sheet".to_string(), 1),
+        ("2024".to_string(), 2),
+        ("1Q Results".to_string(), 3),
+    ]
+    .into_iter()
+    .collect();
+
+    for formula in [
+        "Data!A1",
+        "'My Sheet'!A1",
+        "'2024'!$B$2",
+        "SUM('1Q Results'!A1:C5)",
+        "'My Sheet'!A1+Data!$A$1:B2*2",
+        "@'My Sheet'!A1:A3",
+    ] {
+        let rgce = encode_rgce_with_sheets(formula, &sheets).expect("encode");
+        let decoded = decode_rgce_with_sheets(&rgce, &sheets).expect("decode");
+        assert_eq!(normalize(formula), normalize(&decoded), "formula={formula}");
+    }
+
+    // PtgRef3d: [ptg][ixti: u16][row: u32][col: u16], with relative row/col flags set.
+    let rgce = encode_rgce_with_sheets("'My Sheet'!B3", &sheets).expect("encode");
+    assert_eq!(rgce, vec![0x3A, 1, 0, 2, 0, 0, 0, 1, 0xC0]);
+    assert_eq!(
+        decode_rgce_with_sheets(&rgce, &sheets).expect("decode"),
+        "'My Sheet'!B3"
+    );
+    // Sheet names are matched case-insensitively.
+    assert_eq!(
+        encode_rgce_with_sheets("'my sheet'!B3", &sheets).expect("encode"),
+        rgce
+    );
+
+    let area = encode_rgce_with_sheets("'2024'!A1:B2", &sheets).expect("encode");
+    assert_eq!(area[0], 0x3B); // PtgArea3d
+    assert_eq!(
+        decode_rgce_with_sheets(&area, &sheets).expect("decode"),
+        "'2024'!A1:B2"
+    );
+}
+
+#[test]
+fn rgce_sheet_qualified_refs_require_known_sheets() {
+    let sheets: HashMap<String, u16> = [("Data".to_string(), 0)].into_iter().collect();
+    match encode_rgce_with_sheets("'My Sheet'!A1", &sheets) {
+        Err(EncodeRgceError::UnknownSheet(name)) => assert_eq!(name, "My Sheet"),
+        other => panic!("expected UnknownSheet error, got: {other:?}"),
+    }
+    match encode_rgce("Data!A1") {
+        Err(EncodeRgceError::Unsupported(_)) => {}
+        other => panic!("expected Unsupported error, got: {other:?}"),
+    }
+
+    // An ixti missing from the map decodes to the quoted placeholder.
+    let rgce = [0x3A, 7, 0, 0, 0, 0, 0, 0, 0xC0];
+    assert_eq!(
+        decode_rgce_with_sheets(&rgce, &sheets).expect("decode"),
+        "'Sheet7'!A1"
+    );
+}
+
 #[test]
 fn rgce_encode_accepts_na_bang_error_literal() {
     // Excel sometimes serializes `#N/A` as `#N/A!` in user-facing formula text. The BIFF token
@@ -0,0 +1,127 @@
+use formula_biff::ptg::{self, PtgClass};
+use formula_biff::{decode_rgce, tokens_from_rgce, DecodeRgceError, RgceToken};
+use pretty_assertions::assert_eq;
+
+fn ptg_ref(ptg: u8, row: u32, col: u16) -> Vec<u8> {
+    let mut out = vec![ptg];
+    out.extend_from_slice(&row.to_le_bytes());
+    // Relative row/col flags set (A1-style relative reference).
+    out.extend_from_slice(&(col | 0xC000).to_le_bytes());
+    out
+}
+
+#[test]
+fn reports_reference_vs_value_class_for_ptg_ref() {
+    let reference = ptg_ref(0x24, 0, 0);
+    let value = ptg_ref(0x44, 0, 0);
+
+    assert_eq!(
+        tokens_from_rgce(&reference).expect("tokens"),
+        vec![RgceToken {
+            offset: 0,
+            ptg: 0x24,
+            len: 7,
+            class: Some(PtgClass::Reference),
+        }]
+    );
+    assert_eq!(
+        tokens_from_rgce(&value).expect("tokens")[0].class,
+        Some(PtgClass::Value)
+    );
+    assert_eq!(
+        tokens_from_rgce(&ptg_ref(0x64, 0, 0)).expect("tokens")[0].class,
+        Some(PtgClass::Array)
+    );
+
+    // Both decode to the same cell; the class only affects evaluation.
+    assert_eq!(decode_rgce(&reference).expect("decode"), "A1");
+    assert_eq!(decode_rgce(&value).expect("decode"), "A1");
+}
+
+#[test]
+fn reports_offsets_and_no_class_for_operators_and_constants() {
+    // ABS(A1:B2) * 2
+    let mut rgce = vec![0x25];
+    rgce.extend_from_slice(&0u32.to_le_bytes());
+    rgce.extend_from_slice(&1u32.to_le_bytes());
+    rgce.extend_from_slice(&0xC000u16.to_le_bytes());
+    rgce.extend_from_slice(&0xC001u16.to_le_bytes());
+    rgce.extend_from_slice(&[0x41, 24, 0]); // PtgFuncV(ABS)
+    rgce.extend_from_slice(&[ptg::PTG_INT, 2, 0, ptg::PTG_MUL]);
+    assert_eq!(decode_rgce(&rgce).expect("decode"), "ABS(A1:B2)*2");
+
+    let tokens = tokens_from_rgce(&rgce).expect("tokens");
+    let summary: Vec<(usize, u8, Option<PtgClass>)> =
+        tokens.iter().map(|t| (t.offset, t.ptg, t.class)).collect();
+    assert_eq!(
+        summary,
+        vec![
+            (0, 0x25, Some(PtgClass::Reference)),
+            (13, 0x41, Some(PtgClass::Value)),
+            (16, ptg::PTG_INT, None),
+            (19, ptg::PTG_MUL, None),
+        ]
+    );
+}
+
+#[test]
+fn ptg_extend_family_uses_shifted_class_numbering() {
+    assert_eq!(ptg::ptg_class(0x18), Some(PtgClass::Reference));
+    assert_eq!(ptg::ptg_class(0x38), Some(PtgClass::Value));
+    assert_eq!(ptg::ptg_class(0x58), Some(PtgClass::Array));
+    assert_eq!(ptg::ptg_class(ptg::PTG_SPILL), None);
+    assert_eq!(ptg::ptg_class(ptg::PTG_STR), None);
+    assert_eq!(PtgClass::Value.bits(), ptg::PTG_CLASS_VALUE);
+}
+
+#[test]
+fn ptg_mem_token_spans_its_nested_subexpression() {
+    // PtgMemFunc(cce=7) wrapping a PtgRef, followed by a top-level PtgRef.
+    let mut rgce = vec![0x29, 7, 0];
+    rgce.extend(ptg_ref(0x24, 0, 0));
+    rgce.extend(ptg_ref(0x44, 1, 1));
+
+    let tokens = tokens_from_rgce(&rgce).expect("tokens");
+    assert_eq!(tokens.len(), 2);
+    assert_eq!((tokens[0].offset, tokens[0].len), (0, 10));
+    assert_eq!(
+        (tokens[1].offset, tokens[1].class),
+        (10, Some(PtgClass::Value))
+    );
+}
+
+#[test]
+fn unknown_or_truncated_tokens_are_errors() {
+    assert_eq!(
+        tokens_from_rgce(&[0x1E, 1, 0, 0xFF]),
+        Err(DecodeRgceError::UnsupportedToken {
+            offset: 3,
+            ptg: 0xFF
+        })
+    );
+    assert!(matches!(
+        tokens_from_rgce(&[0x24, 0, 0]),
+        Err(DecodeRgceError::UnexpectedEof {
+            offset: 0,
+            ptg: 0x24,
+            ..
+        })
+    ));
+}
+
+#[cfg(feature = "encode")]
+#[test]
+fn encoder_emits_reference_class_unless_implicit_intersection() {
+    use formula_biff::encode_rgce;
+
+    let plain = encode_rgce("=A1").expect("encode");
+    assert_eq!(
+        tokens_from_rgce(&plain).expect("tokens")[0].class,
+        Some(PtgClass::Reference)
+    );
+    let at = encode_rgce("=@A1").expect("encode");
+    assert_eq!(
+        tokens_from_rgce(&at).expect("tokens")[0].class,
+        Some(PtgClass::Value)
+    );
+}
@@ -256,8 +256,9 @@ impl<'a> CompileCtx<'a> {
                         .instrs
                         .push(Instruction::new(OpCode::JumpIfFalseOrError, 0, 0));
 
-                    // TRUE branch.
-                    self.compile_expr_inner(&args[1], false);
+                    // TRUE branch. Like `LET`, the branches keep references so reference-aware
+                    // callers (`COUNT`, `ISREF`, ...) see a range rather than its value.
+                    self.compile_expr_inner(&args[1], allow_range);
                     let jump_end_idx = self.program.instrs.len();
                     self.program
                         .instrs
@@ -266,7 +267,7 @@ impl<'a> CompileCtx<'a> {
                     // FALSE branch.
                     let false_target = self.program.instrs.len() as u32;
                     if args.len() == 3 {
-                        self.compile_expr_inner(&args[2], false);
+                        self.compile_expr_inner(&args[2], allow_range);
                     } else {
                         // Engine behavior: missing false branch defaults to FALSE (not blank).
                         let idx = self.program.consts.len() as u32;
@@ -0,0 +1,114 @@
+use super::grid::Grid;
+use super::value::{CellCoord, ResolvedRange};
+use crate::functions::math::criteria::{Criteria, CriteriaIndexKey, CriteriaIndexKind};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Smallest criteria range (in cells) worth indexing.
+///
+/// Below this, a direct scan is cheaper than hashing every cell once.
+pub(crate) const CRITERIA_INDEX_MIN_CELLS: usize = 1024;
+
+/// Equality index for a single criteria range: maps each key to the row-major offsets
+/// (`row_off * cols + col_off`) of the cells that carry it, in ascending order.
+#[derive(Debug, Default)]
+struct CriteriaIndex {
+    offsets: HashMap<CriteriaIndexKey, Arc<[u32]>>,
+}
+
+/// Per-recalc cache of equality indexes over `*IFS` criteria ranges.
+///
+/// Repeated `SUMIFS`/`COUNTIFS`/`AVERAGEIFS`/`MINIFS`/`MAXIFS` calls over the same large criteria
+/// range otherwise rescan the whole range on every call. Grids expose a cache through
+/// [`Grid::criteria_index_cache`] only while the cells they read are guaranteed not to change
+/// (the engine creates one per calc level and drops it before applying the next level's results),
+/// so entries never need explicit invalidation.
+#[derive(Debug, Default)]
+pub struct CriteriaIndexCache {
+    indexes: Mutex<HashMap<(usize, ResolvedRange, CriteriaIndexKind), Arc<CriteriaIndex>>>,
+}
+
+impl CriteriaIndexCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the row-major offsets within `range` whose cells match `crit`, or `None` if `crit`
+    /// is not an indexable equality criteria.
+    ///
+    /// The index for `(sheet, range, kind)` is built on first use by scanning the range once.
+    pub(crate) fn matching_offsets(
+        &self,
+        grid: &dyn Grid,
+        sheet: usize,
+        range: ResolvedRange,
+        crit: &Criteria,
+    ) -> Option<Arc<[u32]>> {
+        let (kind, key) = crit.index_key()?;
+        let cache_key = (sheet, range, kind);
+
+        let existing = self
+            .indexes
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .get(&cache_key)
+            .cloned();
+        let index = match existing {
+            Some(index) => index,
+            None => {
+                // Build outside the lock so concurrent evaluations of unrelated ranges don't
+                // serialize on a single scan.
+                let built = Arc::new(build_index(grid, range, kind, crit)?);
+                self.indexes
+                    .lock()
+                    .unwrap_or_else(|poison| poison.into_inner())
+                    .entry(cache_key)
+                    .or_insert(built)
+                    .clone()
+            }
+        };
+
+        Some(
+            index
+                .offsets
+                .get(&key)
+                .cloned()
+                .unwrap_or_else(|| Arc::from(Vec::new())),
+        )
+    }
+}
+
+fn build_index(
+    grid: &dyn Grid,
+    range: ResolvedRange,
+    kind: CriteriaIndexKind,
+    crit: &Criteria,
+) -> Option<CriteriaIndex> {
+    let rows = u32::try_from(range.rows()).ok()?;
+    let cols = u32::try_from(range.cols()).ok()?;
+    rows.checked_mul(cols)?;
+
+    let mut offsets: HashMap<CriteriaIndexKey, Vec<u32>> = HashMap::new();
+    for row_off in 0..rows {
+        for col_off in 0..cols {
+            let cell = CellCoord {
+                row: range.row_start + row_off as i32,
+                col: range.col_start + col_off as i32,
+            };
+            let value = super::runtime::bytecode_value_to_engine(grid.get_value(cell));
+            if let Some(key) = crit.index_key_for_value(kind, &value) {
+                offsets
+                    .entry(key)
+                    .or_default()
+                    .push(row_off * cols + col_off);
+            }
+        }
+    }
+
+    Some(CriteriaIndex {
+        offsets: offsets
+            .into_iter()
+            .map(|(key, offsets)| (key, Arc::from(offsets)))
+            .collect(),
+    })
+}
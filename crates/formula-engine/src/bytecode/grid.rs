@@ -1,3 +1,4 @@
+use super::criteria_index::CriteriaIndexCache;
 use super::value::{CellCoord, ErrorKind, SheetId, Value};
 use ahash::AHashMap;
 use formula_model::{EXCEL_MAX_COLS, EXCEL_MAX_ROWS};
@@ -209,6 +210,15 @@ pub trait Grid: Sync {
         coord.row >= 0 && coord.col >= 0 && coord.row < rows && coord.col < cols
     }
 
+    /// Cache of equality indexes over `*IFS` criteria ranges on this grid's sheet.
+    ///
+    /// Implementations should only return a cache while the cells it indexes cannot change (e.g.
+    /// for the duration of one calc level). The default implementation disables indexing.
+    #[inline]
+    fn criteria_index_cache(&self) -> Option<&CriteriaIndexCache> {
+        None
+    }
+
     /// If `addr` is part of a spilled array, returns the spill origin cell.
     ///
     /// This mirrors the semantics of [`crate::eval::ValueResolver::spill_origin`]. Bytecode
@@ -1,6 +1,7 @@
 pub mod ast;
 mod cache;
 mod compiler;
+mod criteria_index;
 mod eval;
 pub mod grid;
 mod lower;
@@ -11,6 +12,7 @@ pub mod value;
 
 pub use cache::BytecodeCache;
 pub use compiler::Compiler;
+pub use criteria_index::CriteriaIndexCache;
 pub use eval::Vm;
 pub use lower::{lower_canonical_expr, lower_canonical_expr_with_sheet_span, LowerError};
 pub use program::{Instruction, OpCode, Program};
@@ -1,4 +1,5 @@
 use super::ast::{BinaryOp, Expr, Function, UnaryOp};
+use super::criteria_index::CRITERIA_INDEX_MIN_CELLS;
 use super::grid::Grid;
 use super::value::{
     Array as ArrayValue, CellCoord, ErrorKind, MultiRangeRef, RangeRef, Ref, ResolvedRange,
@@ -11,13 +12,14 @@ use crate::eval::MAX_MATERIALIZED_ARRAY_CELLS;
 use crate::external_refs::expand_external_sheet_span_from_order;
 use crate::external_refs::format_external_span_key;
 use crate::functions::lookup;
-use crate::functions::math::criteria::Criteria as EngineCriteria;
+use crate::functions::math::criteria::{parse_numeric_text, Criteria as EngineCriteria};
 use crate::functions::wildcard::WildcardPattern;
 use crate::locale::ValueLocaleConfig;
 use crate::simd::{self, CmpOp, NumericCriteria};
 use crate::value::{
-    cmp_case_insensitive, eq_case_insensitive, format_number_general_with_options, parse_number,
-    ErrorKind as EngineErrorKind, RecordValue, Value as EngineValue,
+    cmp_case_insensitive, cmp_numbers_excel, cmp_text_collated, eq_case_insensitive,
+    format_number_general_with_options, parse_number, ErrorKind as EngineErrorKind, RecordValue,
+    Value as EngineValue,
 };
 use chrono::{DateTime, Datelike, Timelike, Utc};
 use smallvec::SmallVec;
@@ -168,7 +170,8 @@ fn expand_external_spans_in_multirange(
                         let order = match orders.entry(workbook.clone()) {
                             Entry::Occupied(entry) => entry.into_mut(),
                             Entry::Vacant(entry) => {
-                                let Some(order) = grid.external_sheet_order(workbook.as_ref()) else {
+                                let Some(order) = grid.external_sheet_order(workbook.as_ref())
+                                else {
                                     return Err(ErrorKind::Ref);
                                 };
                                 entry.insert(order)
@@ -305,7 +308,9 @@ fn cmp_sheet_ids_in_tab_order(grid: &dyn Grid, a: &SheetId, b: &SheetId) -> Orde
             a_idx.cmp(&b_idx).then_with(|| a_id.cmp(b_id))
         }
         (SheetId::Local(_), SheetId::External(_) | SheetId::ExternalSpan { .. }) => Ordering::Less,
-        (SheetId::External(_) | SheetId::ExternalSpan { .. }, SheetId::Local(_)) => Ordering::Greater,
+        (SheetId::External(_) | SheetId::ExternalSpan { .. }, SheetId::Local(_)) => {
+            Ordering::Greater
+        }
         (SheetId::External(a_key), SheetId::External(b_key)) => {
             // Preserve external-workbook tab order when available.
             match (
@@ -401,11 +406,16 @@ fn multirange_unique_areas(
         areas.push((area, resolved));
     }
     areas.sort_by(|(a_area, a_range), (b_area, b_range)| {
-        cmp_sheet_ids_in_tab_order_cached(grid, &mut external_sheet_indices, &a_area.sheet, &b_area.sheet)
-            .then_with(|| a_range.row_start.cmp(&b_range.row_start))
-            .then_with(|| a_range.col_start.cmp(&b_range.col_start))
-            .then_with(|| a_range.row_end.cmp(&b_range.row_end))
-            .then_with(|| a_range.col_end.cmp(&b_range.col_end))
+        cmp_sheet_ids_in_tab_order_cached(
+            grid,
+            &mut external_sheet_indices,
+            &a_area.sheet,
+            &b_area.sheet,
+        )
+        .then_with(|| a_range.row_start.cmp(&b_range.row_start))
+        .then_with(|| a_range.col_start.cmp(&b_range.col_start))
+        .then_with(|| a_range.row_end.cmp(&b_range.row_end))
+        .then_with(|| a_range.col_end.cmp(&b_range.col_end))
     });
 
     for (area_idx, (area, resolved)) in areas.into_iter().enumerate() {
@@ -1005,7 +1015,11 @@ fn eval_ast_inner(
             // Evaluate arguments first (AST evaluation).
             let mut evaluated: SmallVec<[Value; 8]> = SmallVec::new();
             if evaluated.try_reserve(args.len()).is_err() {
-                debug_assert!(false, "allocation failed (eval_ast args, count={})", args.len());
+                debug_assert!(
+                    false,
+                    "allocation failed (eval_ast args, count={})",
+                    args.len()
+                );
                 return Value::Error(ErrorKind::Num);
             }
             for (arg_idx, arg) in args.iter().enumerate() {
@@ -1311,6 +1325,9 @@ fn coerce_to_bool_finite(v: &Value) -> Result<bool, ErrorKind> {
     }
 }
 
+/// Compare a candidate coerced by [`coerce_countif_value_to_number`] (or read from a strict-numeric
+/// column slice) against a numeric criteria. `NaN` stands for a value that doesn't compare as a
+/// number, so it only satisfies `<>`.
 fn matches_numeric_criteria(v: f64, criteria: NumericCriteria) -> bool {
     match criteria.op {
         CmpOp::Eq => v == criteria.rhs,
@@ -1322,14 +1339,25 @@ fn matches_numeric_criteria(v: f64, criteria: NumericCriteria) -> bool {
     }
 }
 
-fn coerce_countif_value_to_number(v: &Value) -> Option<f64> {
+/// Coerce a COUNTIF-family candidate for comparison against a numeric criteria.
+///
+/// Only numbers compare as numbers, except that numeric text also matches `=`/`<>`. Blanks,
+/// booleans and other text map to `NaN` (see [`matches_numeric_criteria`]); errors never match.
+fn coerce_countif_value_to_number(v: &Value, criteria: NumericCriteria) -> Option<f64> {
+    let text_compares = matches!(criteria.op, CmpOp::Eq | CmpOp::Ne);
+    let parse_text =
+        |s: &str| Some(parse_numeric_text(s, thread_number_locale()).unwrap_or(f64::NAN));
     match v {
         Value::Number(n) => Some(*n),
-        Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
-        Value::Empty | Value::Missing => Some(0.0),
-        Value::Text(s) => parse_number(s, thread_number_locale()).ok(),
-        Value::Entity(v) => parse_number(v.display.as_str(), thread_number_locale()).ok(),
-        Value::Record(v) => parse_number(v.display.as_str(), thread_number_locale()).ok(),
+        Value::Text(s) if text_compares => parse_text(s),
+        Value::Entity(v) if text_compares => parse_text(v.display.as_str()),
+        Value::Record(v) if text_compares => parse_text(v.display.as_str()),
+        Value::Bool(_)
+        | Value::Empty
+        | Value::Missing
+        | Value::Text(_)
+        | Value::Entity(_)
+        | Value::Record(_) => Some(f64::NAN),
         Value::Lambda(_) => None,
         Value::Error(_) | Value::Array(_) | Value::Range(_) | Value::MultiRange(_) => None,
     }
@@ -2103,16 +2131,16 @@ fn excel_order(left: &Value, right: &Value) -> Result<Ordering, ErrorKind> {
     };
 
     Ok(match (l, r) {
-        (Value::Number(a), Value::Number(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
-        (Value::Text(a), Value::Text(b)) => cmp_case_insensitive(a.as_ref(), b.as_ref()),
+        (Value::Number(a), Value::Number(b)) => cmp_numbers_excel(a, b),
+        (Value::Text(a), Value::Text(b)) => {
+            cmp_text_collated(a.as_ref(), b.as_ref(), thread_value_locale().collation)
+        }
         (Value::Bool(a), Value::Bool(b)) => a.cmp(&b),
         // Type precedence (approximate Excel): numbers < text < booleans.
         (Value::Number(_), b) if matches!(b, Value::Text(_) | Value::Bool(_)) => Ordering::Less,
         (Value::Text(_), Value::Bool(_)) => Ordering::Less,
         (Value::Text(_), Value::Number(_)) => Ordering::Greater,
-        (Value::Bool(_), b) if matches!(b, Value::Number(_) | Value::Text(_)) => {
-            Ordering::Greater
-        }
+        (Value::Bool(_), b) if matches!(b, Value::Number(_) | Value::Text(_)) => Ordering::Greater,
         // Blank should have been coerced above.
         (Value::Empty | Value::Missing, Value::Empty | Value::Missing) => Ordering::Equal,
         (Value::Empty | Value::Missing, _) => Ordering::Less,
@@ -5982,7 +6010,11 @@ fn fn_concat_op(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
     // Keep single-cell ranges scalar (deref returns the cell value directly).
     let mut deref_args: Vec<Value> = Vec::new();
     if deref_args.try_reserve_exact(args.len()).is_err() {
-        debug_assert!(false, "allocation failed (concat deref args, count={})", args.len());
+        debug_assert!(
+            false,
+            "allocation failed (concat deref args, count={})",
+            args.len()
+        );
         return Value::Error(ErrorKind::Num);
     }
     let mut saw_array = false;
@@ -6731,7 +6763,14 @@ fn fn_count(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
     let mut count = 0usize;
     for arg in args {
         match arg {
-            Value::Number(_) => count += 1,
+            // Direct scalars follow Excel's argument coercion (logicals and numeric text count);
+            // array elements and referenced cells only count when they hold numbers.
+            Value::Number(_) | Value::Bool(_) => count += 1,
+            Value::Text(s) => {
+                if parse_value_from_text(s).is_ok() {
+                    count += 1;
+                }
+            }
             Value::Array(a) => {
                 if a.len() >= SIMD_ARRAY_MIN_LEN {
                     let mut buf = [f64::NAN; SIMD_AGGREGATE_BLOCK];
@@ -6774,11 +6813,9 @@ fn fn_count(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
                     }
                 }
             }
-            Value::Bool(_)
-            | Value::Empty
+            Value::Empty
             | Value::Missing
             | Value::Error(_)
-            | Value::Text(_)
             | Value::Entity(_)
             | Value::Record(_)
             | Value::Lambda(_) => {}
@@ -7270,7 +7307,10 @@ fn fn_sumifs(
         || crits.try_reserve_exact(crit_count).is_err()
         || numeric_crits.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (sumifs criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (sumifs criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -7361,6 +7401,36 @@ fn fn_sumifs(
         }
     }
 
+    if let Some((indexed, offsets)) = ifs_index_candidates(grid, &crit_ranges, &crits) {
+        // Offsets are row-major, so the first error hit matches the row-major scan below.
+        let mut sum = 0.0;
+        for &offset in offsets.iter() {
+            let row_off = (offset / cols as u32) as i32;
+            let col_off = (offset % cols as u32) as i32;
+            if !ifs_offset_matches(grid, &crit_ranges, &crits, indexed, row_off, col_off) {
+                continue;
+            }
+            match grid.get_value(CellCoord {
+                row: sum_range.row_start + row_off,
+                col: sum_range.col_start + col_off,
+            }) {
+                Value::Number(v) => sum += v,
+                Value::Error(e) => return Value::Error(e),
+                Value::Bool(_)
+                | Value::Text(_)
+                | Value::Entity(_)
+                | Value::Record(_)
+                | Value::Empty
+                | Value::Missing
+                | Value::Array(_)
+                | Value::Range(_)
+                | Value::MultiRange(_)
+                | Value::Lambda(_) => {}
+            }
+        }
+        return Value::Number(sum);
+    }
+
     if all_numeric {
         // Like MINIFS/MAXIFS, only take the numeric slice fast path when all slices are available
         // for the full rectangular region. Otherwise fall back to a row-major scan so error
@@ -7436,10 +7506,7 @@ fn fn_sumifs(
                         let idx = i + lane;
                         let mut matches = true;
                         for (slice, crit) in crit_slices.iter().zip(numeric_crits.iter()) {
-                            let mut v = slice[idx];
-                            if v.is_nan() {
-                                v = 0.0;
-                            }
+                            let v = slice[idx];
                             if !matches_numeric_criteria(v, *crit) {
                                 matches = false;
                                 break;
@@ -7458,10 +7525,7 @@ fn fn_sumifs(
                 for idx in i..len {
                     let mut matches = true;
                     for (slice, crit) in crit_slices.iter().zip(numeric_crits.iter()) {
-                        let mut v = slice[idx];
-                        if v.is_nan() {
-                            v = 0.0;
-                        }
+                        let v = slice[idx];
                         if !matches_numeric_criteria(v, *crit) {
                             matches = false;
                             break;
@@ -7539,8 +7603,13 @@ fn sumifs_with_array_ranges(
     let crit_count = (args.len() - 1) / 2;
     let mut crit_ranges: Vec<Range2DArg<'_>> = Vec::new();
     let mut crits: Vec<EngineCriteria> = Vec::new();
-    if crit_ranges.try_reserve_exact(crit_count).is_err() || crits.try_reserve_exact(crit_count).is_err() {
-        debug_assert!(false, "allocation failed (sumifs array criteria, count={crit_count})");
+    if crit_ranges.try_reserve_exact(crit_count).is_err()
+        || crits.try_reserve_exact(crit_count).is_err()
+    {
+        debug_assert!(
+            false,
+            "allocation failed (sumifs array criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -7631,7 +7700,10 @@ fn fn_countifs(
         || criteria.try_reserve_exact(crit_count).is_err()
         || numeric.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (countifs criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (countifs criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -7737,6 +7809,18 @@ fn fn_countifs(
         }
     }
 
+    if let Some((indexed, offsets)) = ifs_index_candidates(grid, &ranges, &criteria) {
+        let count = offsets
+            .iter()
+            .filter(|&&offset| {
+                let row_off = (offset / cols as u32) as i32;
+                let col_off = (offset % cols as u32) as i32;
+                ifs_offset_matches(grid, &ranges, &criteria, indexed, row_off, col_off)
+            })
+            .count();
+        return Value::Number(count as f64);
+    }
+
     let mut count = 0usize;
     for col_off in 0..cols {
         if all_numeric {
@@ -7762,7 +7846,7 @@ fn fn_countifs(
 
             if slices.len() == ranges.len() {
                 if numeric.len() == 1 {
-                    count += simd::count_if_blank_as_zero_f64(slices[0], numeric[0]);
+                    count += simd::count_if_with_blanks_f64(slices[0], numeric[0]);
                     continue;
                 }
 
@@ -7773,10 +7857,7 @@ fn fn_countifs(
                         let idx = i + lane;
                         let mut matches = true;
                         for (slice, crit) in slices.iter().zip(numeric.iter()) {
-                            let mut v = slice[idx];
-                            if v.is_nan() {
-                                v = 0.0;
-                            }
+                            let v = slice[idx];
                             if !matches_numeric_criteria(v, *crit) {
                                 matches = false;
                                 break;
@@ -7791,10 +7872,7 @@ fn fn_countifs(
                 for idx in i..len {
                     let mut matches = true;
                     for (slice, crit) in slices.iter().zip(numeric.iter()) {
-                        let mut v = slice[idx];
-                        if v.is_nan() {
-                            v = 0.0;
-                        }
+                        let v = slice[idx];
                         if !matches_numeric_criteria(v, *crit) {
                             matches = false;
                             break;
@@ -7842,9 +7920,13 @@ fn countifs_with_array_ranges(
     let crit_count = args.len() / 2;
     let mut ranges: Vec<CriteriaRange<'_>> = Vec::new();
     let mut criteria: Vec<EngineCriteria> = Vec::new();
-    if ranges.try_reserve_exact(crit_count).is_err() || criteria.try_reserve_exact(crit_count).is_err()
+    if ranges.try_reserve_exact(crit_count).is_err()
+        || criteria.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (countifs array criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (countifs array criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
     let mut shape: Option<(usize, usize)> = None;
@@ -7995,7 +8077,10 @@ fn fn_averageif(
         .unwrap_or(criteria_range_ref);
 
         let crit_range = criteria_range_ref.resolve(base);
-        let avg_range = average_range_ref.resolve(base);
+        let mut avg_range = average_range_ref.resolve(base);
+        // Excel sizes average_range from its top-left cell to match criteria_range.
+        avg_range.row_end = avg_range.row_start.saturating_add(crit_range.rows() - 1);
+        avg_range.col_end = avg_range.col_start.saturating_add(crit_range.cols() - 1);
 
         if !range_in_bounds(grid, crit_range) || !range_in_bounds(grid, avg_range) {
             return Value::Error(ErrorKind::Ref);
@@ -8022,10 +8107,6 @@ fn fn_averageif(
                 col: avg_range.col_end,
             },
         );
-        if crit_range.rows() != avg_range.rows() || crit_range.cols() != avg_range.cols() {
-            return Value::Error(ErrorKind::Value);
-        }
-
         let rows = crit_range.rows();
         let cols = crit_range.cols();
         if rows <= 0 || cols <= 0 {
@@ -8315,7 +8396,10 @@ fn fn_averageifs(
         || crits.try_reserve_exact(crit_count).is_err()
         || numeric_crits.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (averageifs criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (averageifs criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -8415,6 +8499,43 @@ fn fn_averageifs(
         }
     }
 
+    if let Some((indexed, offsets)) = ifs_index_candidates(grid, &crit_ranges, &crits) {
+        // Offsets are row-major, so the first error hit matches the row-major scan below.
+        let mut sum = 0.0;
+        let mut count = 0usize;
+        for &offset in offsets.iter() {
+            let row_off = (offset / cols as u32) as i32;
+            let col_off = (offset % cols as u32) as i32;
+            if !ifs_offset_matches(grid, &crit_ranges, &crits, indexed, row_off, col_off) {
+                continue;
+            }
+            match grid.get_value(CellCoord {
+                row: avg_range.row_start + row_off,
+                col: avg_range.col_start + col_off,
+            }) {
+                Value::Number(v) => {
+                    sum += v;
+                    count += 1;
+                }
+                Value::Error(e) => return Value::Error(e),
+                Value::Bool(_)
+                | Value::Text(_)
+                | Value::Entity(_)
+                | Value::Record(_)
+                | Value::Empty
+                | Value::Missing
+                | Value::Array(_)
+                | Value::Range(_)
+                | Value::MultiRange(_)
+                | Value::Lambda(_) => {}
+            }
+        }
+        if count == 0 {
+            return Value::Error(ErrorKind::Div0);
+        }
+        return Value::Number(sum / count as f64);
+    }
+
     if all_numeric {
         // Like MINIFS/MAXIFS, only take the numeric slice fast path when all slices are available
         // for the full rectangular region. Otherwise fall back to a row-major scan so error
@@ -8493,10 +8614,7 @@ fn fn_averageifs(
                         let idx = i + lane;
                         let mut matches = true;
                         for (slice, crit) in crit_slices.iter().zip(numeric_crits.iter()) {
-                            let mut v = slice[idx];
-                            if v.is_nan() {
-                                v = 0.0;
-                            }
+                            let v = slice[idx];
                             if !matches_numeric_criteria(v, *crit) {
                                 matches = false;
                                 break;
@@ -8516,10 +8634,7 @@ fn fn_averageifs(
                 for idx in i..len {
                     let mut matches = true;
                     for (slice, crit) in crit_slices.iter().zip(numeric_crits.iter()) {
-                        let mut v = slice[idx];
-                        if v.is_nan() {
-                            v = 0.0;
-                        }
+                        let v = slice[idx];
                         if !matches_numeric_criteria(v, *crit) {
                             matches = false;
                             break;
@@ -8609,9 +8724,13 @@ fn averageifs_with_array_ranges(
     let crit_count = (args.len() - 1) / 2;
     let mut crit_ranges: Vec<Range2DArg<'_>> = Vec::new();
     let mut crits: Vec<EngineCriteria> = Vec::new();
-    if crit_ranges.try_reserve_exact(crit_count).is_err() || crits.try_reserve_exact(crit_count).is_err()
+    if crit_ranges.try_reserve_exact(crit_count).is_err()
+        || crits.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (averageifs array criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (averageifs array criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -8744,7 +8863,10 @@ fn fn_minifs(
         || crits.try_reserve_exact(crit_count).is_err()
         || numeric_crits.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (minifs criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (minifs criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -8837,6 +8959,36 @@ fn fn_minifs(
         }
     }
 
+    if let Some((indexed, offsets)) = ifs_index_candidates(grid, &crit_ranges, &crits) {
+        // Offsets are row-major, so the first error hit matches the row-major scan below.
+        let mut best: Option<f64> = None;
+        for &offset in offsets.iter() {
+            let row_off = (offset / cols as u32) as i32;
+            let col_off = (offset % cols as u32) as i32;
+            if !ifs_offset_matches(grid, &crit_ranges, &crits, indexed, row_off, col_off) {
+                continue;
+            }
+            match grid.get_value(CellCoord {
+                row: min_range.row_start + row_off,
+                col: min_range.col_start + col_off,
+            }) {
+                Value::Number(v) => best = Some(best.map_or(v, |b| b.min(v))),
+                Value::Error(e) => return Value::Error(e),
+                Value::Bool(_)
+                | Value::Text(_)
+                | Value::Entity(_)
+                | Value::Record(_)
+                | Value::Empty
+                | Value::Missing
+                | Value::Array(_)
+                | Value::Range(_)
+                | Value::MultiRange(_)
+                | Value::Lambda(_) => {}
+            }
+        }
+        return Value::Number(best.unwrap_or(0.0));
+    }
+
     if all_numeric {
         // Only use the numeric fast path when all required slices are available (no blocked rows).
         let mut slices_ok = true;
@@ -8886,10 +9038,7 @@ fn fn_minifs(
             for idx in 0..min_slice.len() {
                 let mut matches = true;
                 for (slice, crit) in crit_slices.iter().zip(numeric_crits.iter()) {
-                    let mut v = slice[idx];
-                    if v.is_nan() {
-                        v = 0.0;
-                    }
+                    let v = slice[idx];
                     if !matches_numeric_criteria(v, *crit) {
                         matches = false;
                         break;
@@ -8980,9 +9129,13 @@ fn minifs_with_array_ranges(
     let crit_count = (args.len() - 1) / 2;
     let mut crit_ranges: Vec<Range2DArg<'_>> = Vec::new();
     let mut crits: Vec<EngineCriteria> = Vec::new();
-    if crit_ranges.try_reserve_exact(crit_count).is_err() || crits.try_reserve_exact(crit_count).is_err()
+    if crit_ranges.try_reserve_exact(crit_count).is_err()
+        || crits.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (minifs array criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (minifs array criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -9090,7 +9243,10 @@ fn fn_maxifs(
         || crits.try_reserve_exact(crit_count).is_err()
         || numeric_crits.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (maxifs criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (maxifs criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -9183,6 +9339,36 @@ fn fn_maxifs(
         }
     }
 
+    if let Some((indexed, offsets)) = ifs_index_candidates(grid, &crit_ranges, &crits) {
+        // Offsets are row-major, so the first error hit matches the row-major scan below.
+        let mut best: Option<f64> = None;
+        for &offset in offsets.iter() {
+            let row_off = (offset / cols as u32) as i32;
+            let col_off = (offset % cols as u32) as i32;
+            if !ifs_offset_matches(grid, &crit_ranges, &crits, indexed, row_off, col_off) {
+                continue;
+            }
+            match grid.get_value(CellCoord {
+                row: max_range.row_start + row_off,
+                col: max_range.col_start + col_off,
+            }) {
+                Value::Number(v) => best = Some(best.map_or(v, |b| b.max(v))),
+                Value::Error(e) => return Value::Error(e),
+                Value::Bool(_)
+                | Value::Text(_)
+                | Value::Entity(_)
+                | Value::Record(_)
+                | Value::Empty
+                | Value::Missing
+                | Value::Array(_)
+                | Value::Range(_)
+                | Value::MultiRange(_)
+                | Value::Lambda(_) => {}
+            }
+        }
+        return Value::Number(best.unwrap_or(0.0));
+    }
+
     if all_numeric {
         // Only use the numeric fast path when all required slices are available (no blocked rows).
         let mut slices_ok = true;
@@ -9232,10 +9418,7 @@ fn fn_maxifs(
             for idx in 0..max_slice.len() {
                 let mut matches = true;
                 for (slice, crit) in crit_slices.iter().zip(numeric_crits.iter()) {
-                    let mut v = slice[idx];
-                    if v.is_nan() {
-                        v = 0.0;
-                    }
+                    let v = slice[idx];
                     if !matches_numeric_criteria(v, *crit) {
                         matches = false;
                         break;
@@ -9326,9 +9509,13 @@ fn maxifs_with_array_ranges(
     let crit_count = (args.len() - 1) / 2;
     let mut crit_ranges: Vec<Range2DArg<'_>> = Vec::new();
     let mut crits: Vec<EngineCriteria> = Vec::new();
-    if crit_ranges.try_reserve_exact(crit_count).is_err() || crits.try_reserve_exact(crit_count).is_err()
+    if crit_ranges.try_reserve_exact(crit_count).is_err()
+        || crits.try_reserve_exact(crit_count).is_err()
     {
-        debug_assert!(false, "allocation failed (maxifs array criteria, count={crit_count})");
+        debug_assert!(
+            false,
+            "allocation failed (maxifs array criteria, count={crit_count})"
+        );
         return Value::Error(ErrorKind::Num);
     }
 
@@ -9382,16 +9569,15 @@ fn maxifs_with_array_ranges(
 }
 
 fn fn_sumproduct(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
-    if args.len() != 2 {
+    if args.is_empty() {
         return Value::Error(ErrorKind::Value);
     }
 
     // Preserve Excel-like argument error precedence.
-    if let Value::Error(e) = &args[0] {
-        return Value::Error(*e);
-    }
-    if let Value::Error(e) = &args[1] {
-        return Value::Error(*e);
+    for arg in args {
+        if let Value::Error(e) = arg {
+            return Value::Error(*e);
+        }
     }
 
     struct RangeOperand<'a> {
@@ -9429,7 +9615,10 @@ fn fn_sumproduct(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
             let cols = cols_i32 as usize;
             let mut col_slices: Vec<Option<&'a [f64]>> = Vec::new();
             if col_slices.try_reserve_exact(cols).is_err() {
-                debug_assert!(false, "allocation failed (sumproduct col_slices, cols={cols})");
+                debug_assert!(
+                    false,
+                    "allocation failed (sumproduct col_slices, cols={cols})"
+                );
                 return Err(ErrorKind::Num);
             }
             for col in range.col_start..=range.col_end {
@@ -9504,23 +9693,83 @@ fn fn_sumproduct(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
         }
     }
 
-    let a = match &args[0] {
-        Value::Range(r) => match RangeOperand::new(grid, r.resolve(base)) {
-            Ok(v) => Operand::Range(v),
-            Err(e) => return Value::Error(e),
-        },
-        Value::Array(arr) => Operand::Array(arr),
-        Value::MultiRange(_) => Operand::MultiRange,
-        other => Operand::Scalar(other),
+    /// Sum the element-wise product of any number of operands in a single pass, coercing each
+    /// element straight from its source so no intermediate product array is built. 1x1 operands
+    /// broadcast and are coerced once, in argument order at the first element.
+    fn sumproduct_streaming(operands: &[Operand<'_>]) -> Result<f64, ErrorKind> {
+        let len = operands.iter().map(Operand::len).max().unwrap_or(0);
+        if len == 0 || operands.iter().any(|op| op.len() != len && op.len() != 1) {
+            return Err(ErrorKind::Value);
+        }
+
+        let mut broadcast: Vec<f64> = Vec::new();
+        if broadcast.try_reserve_exact(operands.len()).is_err() {
+            debug_assert!(
+                false,
+                "allocation failed (sumproduct operands={})",
+                operands.len()
+            );
+            return Err(ErrorKind::Num);
+        }
+        broadcast.resize(operands.len(), 0.0);
+
+        let mut sum = 0.0;
+        for idx in 0..len {
+            let mut product = 1.0;
+            for (op, cached) in operands.iter().zip(broadcast.iter_mut()) {
+                let x = if op.len() == 1 {
+                    if idx == 0 {
+                        *cached = op.coerce_number_at(0)?;
+                    }
+                    *cached
+                } else {
+                    op.coerce_number_at(idx)?
+                };
+                product *= x;
+            }
+            sum += product;
+        }
+        Ok(sum)
+    }
+
+    fn to_operand<'a>(
+        arg: &'a Value,
+        grid: &'a dyn Grid,
+        base: CellCoord,
+    ) -> Result<Operand<'a>, ErrorKind> {
+        Ok(match arg {
+            Value::Range(r) => Operand::Range(RangeOperand::new(grid, r.resolve(base))?),
+            Value::Array(arr) => Operand::Array(arr),
+            Value::MultiRange(_) => Operand::MultiRange,
+            other => Operand::Scalar(other),
+        })
+    }
+
+    if args.len() != 2 {
+        let mut operands: Vec<Operand<'_>> = Vec::new();
+        if operands.try_reserve_exact(args.len()).is_err() {
+            debug_assert!(false, "allocation failed (sumproduct args={})", args.len());
+            return Value::Error(ErrorKind::Num);
+        }
+        for arg in args {
+            match to_operand(arg, grid, base) {
+                Ok(op) => operands.push(op),
+                Err(e) => return Value::Error(e),
+            }
+        }
+        return match sumproduct_streaming(&operands) {
+            Ok(v) => Value::Number(v),
+            Err(e) => Value::Error(e),
+        };
+    }
+
+    let a = match to_operand(&args[0], grid, base) {
+        Ok(op) => op,
+        Err(e) => return Value::Error(e),
     };
-    let b = match &args[1] {
-        Value::Range(r) => match RangeOperand::new(grid, r.resolve(base)) {
-            Ok(v) => Operand::Range(v),
-            Err(e) => return Value::Error(e),
-        },
-        Value::Array(arr) => Operand::Array(arr),
-        Value::MultiRange(_) => Operand::MultiRange,
-        other => Operand::Scalar(other),
+    let b = match to_operand(&args[1], grid, base) {
+        Ok(op) => op,
+        Err(e) => return Value::Error(e),
     };
 
     let len_a = a.len();
@@ -9625,13 +9874,21 @@ fn fn_vlookup(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
     if matches!(lookup_value, Value::Lambda(_)) {
         return Value::Error(ErrorKind::Value);
     }
-    if matches!(
-        lookup_value,
-        Value::Array(_) | Value::Range(_) | Value::MultiRange(_)
-    ) {
+    if matches!(lookup_value, Value::Range(_) | Value::MultiRange(_)) {
         return Value::Error(ErrorKind::Spill);
     }
 
+    // An array lookup value spills one lookup per element (matching the AST evaluator).
+    elementwise_unary(lookup_value, |lookup_value| {
+        vlookup_one(lookup_value, args, grid, base)
+    })
+}
+
+fn vlookup_one(lookup_value: &Value, args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
+    if let Value::Error(e) = lookup_value {
+        return Value::Error(*e);
+    }
+
     enum LookupTable<'a> {
         Range(ResolvedRange),
         Array(&'a ArrayValue),
@@ -9749,13 +10006,21 @@ fn fn_hlookup(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
     if matches!(lookup_value, Value::Lambda(_)) {
         return Value::Error(ErrorKind::Value);
     }
-    if matches!(
-        lookup_value,
-        Value::Array(_) | Value::Range(_) | Value::MultiRange(_)
-    ) {
+    if matches!(lookup_value, Value::Range(_) | Value::MultiRange(_)) {
         return Value::Error(ErrorKind::Spill);
     }
 
+    // An array lookup value spills one lookup per element (matching the AST evaluator).
+    elementwise_unary(lookup_value, |lookup_value| {
+        hlookup_one(lookup_value, args, grid, base)
+    })
+}
+
+fn hlookup_one(lookup_value: &Value, args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
+    if let Value::Error(e) = lookup_value {
+        return Value::Error(*e);
+    }
+
     enum LookupTable<'a> {
         Range(ResolvedRange),
         Array(&'a ArrayValue),
@@ -10465,7 +10730,10 @@ fn fn_xlookup(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
                     }
                     let mut values: Vec<Value> = Vec::new();
                     if values.try_reserve_exact(cols).is_err() {
-                        debug_assert!(false, "allocation failed (xlookup row array values, cols={cols})");
+                        debug_assert!(
+                            false,
+                            "allocation failed (xlookup row array values, cols={cols})"
+                        );
                         return Value::Error(ErrorKind::Num);
                     }
                     let row_start = idx.saturating_mul(cols);
@@ -10516,7 +10784,10 @@ fn fn_xlookup(args: &[Value], grid: &dyn Grid, base: CellCoord) -> Value {
                     }
                     let mut values: Vec<Value> = Vec::new();
                     if values.try_reserve_exact(rows).is_err() {
-                        debug_assert!(false, "allocation failed (xlookup col array values, rows={rows})");
+                        debug_assert!(
+                            false,
+                            "allocation failed (xlookup col array values, rows={rows})"
+                        );
                         return Value::Error(ErrorKind::Num);
                     }
                     for row_offset in 0..rows {
@@ -10687,7 +10958,12 @@ fn excel_cmp(a: &Value, b: &Value) -> Option<i32> {
                 (a, b) if type_rank(a) == Some(1) => {
                     let a = text_like_str(a)?;
                     let b = text_like_str(b)?;
-                    Some(ordering_to_i32(cmp_case_insensitive(a.as_ref(), b.as_ref())))
+                    let collation = thread_value_locale().collation;
+                    Some(ordering_to_i32(cmp_text_collated(
+                        a.as_ref(),
+                        b.as_ref(),
+                        collation,
+                    )))
                 }
                 (Value::Bool(x), Value::Bool(y)) => Some(ordering_to_i32(x.cmp(y))),
                 (Value::Empty | Value::Missing, Value::Empty | Value::Missing) => Some(0),
@@ -11083,7 +11359,7 @@ fn range2d_from_value<'a>(
         _ => Err(ErrorKind::Value),
     }
 }
-fn bytecode_value_to_engine(value: Value) -> EngineValue {
+pub(super) fn bytecode_value_to_engine(value: Value) -> EngineValue {
     match value {
         Value::Number(n) => EngineValue::Number(n),
         Value::Bool(b) => EngineValue::Bool(b),
@@ -11286,7 +11562,7 @@ fn count_if_array_numeric_criteria(arr: &ArrayValue, criteria: NumericCriteria)
         return arr
             .iter()
             .filter(|v| {
-                let Some(n) = coerce_countif_value_to_number(*v) else {
+                let Some(n) = coerce_countif_value_to_number(v, criteria) else {
                     return false;
                 };
                 matches_numeric_criteria(n, criteria)
@@ -11299,7 +11575,7 @@ fn count_if_array_numeric_criteria(arr: &ArrayValue, criteria: NumericCriteria)
     let mut len = 0usize;
 
     for v in arr.iter() {
-        let Some(n) = coerce_countif_value_to_number(v) else {
+        let Some(n) = coerce_countif_value_to_number(v, criteria) else {
             continue;
         };
         if n.is_nan() {
@@ -11339,9 +11615,7 @@ fn sum_if_array_numeric_criteria(
     let mut len = 0usize;
 
     for (crit_v, sum_v) in criteria_values.iter().zip(values.iter()) {
-        let Some(n) = coerce_countif_value_to_number(crit_v) else {
-            return None;
-        };
+        let n = coerce_countif_value_to_number(crit_v, criteria)?;
         // Preserve scalar semantics for NaN numeric values by falling back. The SIMD criteria kernels
         // treat NaNs as blanks.
         if n.is_nan() {
@@ -11390,9 +11664,7 @@ fn sum_count_if_array_numeric_criteria(
     let mut len = 0usize;
 
     for (crit_v, avg_v) in criteria_values.iter().zip(values.iter()) {
-        let Some(n) = coerce_countif_value_to_number(crit_v) else {
-            return None;
-        };
+        let n = coerce_countif_value_to_number(crit_v, criteria)?;
         if n.is_nan() {
             return None;
         }
@@ -11462,6 +11734,66 @@ fn record_error_row_major(
     }
 }
 
+/// Answer the most selective indexable criteria of a range-only `*IFS` call from the grid's
+/// criteria index.
+///
+/// Returns the position of that criteria in `crits` together with the row-major offsets of the
+/// cells matching it. Callers must still check every other criteria for each candidate. Returns
+/// `None` when the grid has no index cache, the ranges are too small to be worth indexing, or no
+/// criteria is a plain equality test.
+fn ifs_index_candidates(
+    grid: &dyn Grid,
+    crit_ranges: &[ResolvedRange],
+    crits: &[EngineCriteria],
+) -> Option<(usize, Arc<[u32]>)> {
+    let cache = grid.criteria_index_cache()?;
+    let first = crit_ranges.first()?;
+    let cells = usize::try_from(first.rows())
+        .ok()?
+        .checked_mul(usize::try_from(first.cols()).ok()?)?;
+    if cells < CRITERIA_INDEX_MIN_CELLS {
+        return None;
+    }
+
+    let mut best: Option<(usize, Arc<[u32]>)> = None;
+    for (idx, (range, crit)) in crit_ranges.iter().zip(crits.iter()).enumerate() {
+        let Some(offsets) = cache.matching_offsets(grid, grid.sheet_id(), *range, crit) else {
+            continue;
+        };
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_offsets)| offsets.len() < best_offsets.len())
+        {
+            best = Some((idx, offsets));
+        }
+    }
+    best
+}
+
+/// Check every criteria except `skip` against the cells at `(row_off, col_off)`.
+fn ifs_offset_matches(
+    grid: &dyn Grid,
+    crit_ranges: &[ResolvedRange],
+    crits: &[EngineCriteria],
+    skip: usize,
+    row_off: i32,
+    col_off: i32,
+) -> bool {
+    crit_ranges
+        .iter()
+        .zip(crits.iter())
+        .enumerate()
+        .all(|(idx, (range, crit))| {
+            idx == skip || {
+                let cell = CellCoord {
+                    row: range.row_start + row_off,
+                    col: range.col_start + col_off,
+                };
+                crit.matches(&bytecode_value_to_engine(grid.get_value(cell)))
+            }
+        })
+}
+
 #[inline]
 fn record_error_sumproduct_offset(
     best: &mut Option<(i32, i32, u8, ErrorKind)>,
@@ -12588,15 +12920,15 @@ fn count_if_range(
                     continue;
                 }
                 seen += 1;
-                if let Some(n) = coerce_countif_value_to_number(&v) {
+                if let Some(n) = coerce_countif_value_to_number(&v, criteria) {
                     if matches_numeric_criteria(n, criteria) {
                         count += 1;
                     }
                 }
             }
 
-            // COUNTIF treats implicit blanks as zero for numeric criteria.
-            if matches_numeric_criteria(0.0, criteria) {
+            // Implicit blanks only satisfy `<>` numeric criteria.
+            if matches_numeric_criteria(f64::NAN, criteria) {
                 let total_cells = (range.rows() as i64) * (range.cols() as i64);
                 let implicit_blanks = total_cells.saturating_sub(seen as i64);
                 count = count.saturating_add(implicit_blanks as usize);
@@ -12609,12 +12941,13 @@ fn count_if_range(
     let mut count = 0usize;
     for col in range.col_start..=range.col_end {
         if let Some(slice) = grid.column_slice_strict_numeric(col, range.row_start, range.row_end) {
-            count += simd::count_if_blank_as_zero_f64(slice, criteria);
+            count += simd::count_if_with_blanks_f64(slice, criteria);
         } else {
             for row in range.row_start..=range.row_end {
-                if let Some(v) =
-                    coerce_countif_value_to_number(&grid.get_value(CellCoord { row, col }))
-                {
+                if let Some(v) = coerce_countif_value_to_number(
+                    &grid.get_value(CellCoord { row, col }),
+                    criteria,
+                ) {
                     if matches_numeric_criteria(v, criteria) {
                         count += 1;
                     }
@@ -12658,15 +12991,15 @@ fn count_if_range_on_sheet(
                     continue;
                 }
                 seen += 1;
-                if let Some(n) = coerce_countif_value_to_number(&v) {
+                if let Some(n) = coerce_countif_value_to_number(&v, criteria) {
                     if matches_numeric_criteria(n, criteria) {
                         count += 1;
                     }
                 }
             }
 
-            // COUNTIF treats implicit blanks as zero for numeric criteria.
-            if matches_numeric_criteria(0.0, criteria) {
+            // Implicit blanks only satisfy `<>` numeric criteria.
+            if matches_numeric_criteria(f64::NAN, criteria) {
                 let total_cells = (range.rows() as i64) * (range.cols() as i64);
                 let implicit_blanks = total_cells.saturating_sub(seen as i64);
                 count = count.saturating_add(implicit_blanks as usize);
@@ -12681,11 +13014,12 @@ fn count_if_range_on_sheet(
         if let Some(slice) =
             grid.column_slice_on_sheet_strict_numeric(sheet, col, range.row_start, range.row_end)
         {
-            count += simd::count_if_blank_as_zero_f64(slice, criteria);
+            count += simd::count_if_with_blanks_f64(slice, criteria);
         } else {
             for row in range.row_start..=range.row_end {
                 if let Some(v) = coerce_countif_value_to_number(
                     &grid.get_value_on_sheet(sheet, CellCoord { row, col }),
+                    criteria,
                 ) {
                     if matches_numeric_criteria(v, criteria) {
                         count += 1;
@@ -12945,7 +13279,8 @@ fn sumproduct_range(grid: &dyn Grid, a: ResolvedRange, b: ResolvedRange) -> Resu
     let cols_usize = cols as usize;
     let mut slice_a: Vec<Option<&[f64]>> = Vec::new();
     let mut slice_b: Vec<Option<&[f64]>> = Vec::new();
-    if slice_a.try_reserve_exact(cols_usize).is_err() || slice_b.try_reserve_exact(cols_usize).is_err()
+    if slice_a.try_reserve_exact(cols_usize).is_err()
+        || slice_b.try_reserve_exact(cols_usize).is_err()
     {
         debug_assert!(
             false,
@@ -13453,7 +13788,9 @@ mod tests {
             fn get_value_on_sheet(&self, sheet: &SheetId, coord: CellCoord) -> Value {
                 match sheet {
                     SheetId::Local(_) => self.get_value(coord),
-                    SheetId::External(_) | SheetId::ExternalSpan { .. } => Value::Error(ErrorKind::Ref),
+                    SheetId::External(_) | SheetId::ExternalSpan { .. } => {
+                        Value::Error(ErrorKind::Ref)
+                    }
                 }
             }
 
@@ -13517,7 +13854,9 @@ mod tests {
             fn get_value_on_sheet(&self, sheet: &SheetId, coord: CellCoord) -> Value {
                 match sheet {
                     SheetId::Local(_) => self.get_value(coord),
-                    SheetId::External(_) | SheetId::ExternalSpan { .. } => Value::Error(ErrorKind::Ref),
+                    SheetId::External(_) | SheetId::ExternalSpan { .. } => {
+                        Value::Error(ErrorKind::Ref)
+                    }
                 }
             }
 
@@ -13817,7 +14156,6 @@ mod tests {
         };
 
         let seen_in_range = 7usize;
-        let explicit_zero_matches = 3usize;
         let total_cells = (range.rows() as usize) * (range.cols() as usize);
 
         let grid = PanicGrid {
@@ -13838,17 +14176,26 @@ mod tests {
             )]),
         };
 
+        // Only the number 0 and the numeric text "0" equal 0; blanks (explicit or implicit) don't.
         let criteria_zero = NumericCriteria::new(CmpOp::Eq, 0.0);
-        let expected_zero = explicit_zero_matches + total_cells.saturating_sub(seen_in_range);
         assert_eq!(
             count_if_range_on_sheet(&grid, &SheetId::Local(0), range, criteria_zero),
-            Ok(expected_zero)
+            Ok(2)
         );
 
+        // Everything except the zeros and the error is `<>0`, including every implicit blank.
+        let criteria_ne_zero = NumericCriteria::new(CmpOp::Ne, 0.0);
+        let expected_ne_zero = 4 + total_cells.saturating_sub(seen_in_range);
+        assert_eq!(
+            count_if_range_on_sheet(&grid, &SheetId::Local(0), range, criteria_ne_zero),
+            Ok(expected_ne_zero)
+        );
+
+        // `TRUE` doesn't order against numbers, so only the 2 is `>0`.
         let criteria_gt = NumericCriteria::new(CmpOp::Gt, 0.0);
         assert_eq!(
             count_if_range_on_sheet(&grid, &SheetId::Local(0), range, criteria_gt),
-            Ok(2)
+            Ok(1)
         );
     }
 
@@ -13936,19 +14283,19 @@ mod tests {
         values.resize(len, Value::Text(Arc::from("x")));
         values[0] = Value::Number(f64::NAN);
         values[1] = Value::Number(1.0);
-        values[2] = Value::Empty; // coerces to 0.0
+        values[2] = Value::Empty; // never equals a number
         values[3] = Value::Text(Arc::from("2")); // numeric text coerces to 2.0
         values[4] = Value::Number(0.0);
 
         let arr = ArrayValue::new(1, values.len(), values);
 
         let ne_zero = NumericCriteria::new(CmpOp::Ne, 0.0);
-        // NaN != 0 => true, 1 != 0 => true, empty(0) != 0 => false, 2 != 0 => true.
-        assert_eq!(count_if_array_numeric_criteria(&arr, ne_zero), 3);
+        // NaN, 1, empty, 2 and every non-numeric "x" are `<>0`; only the explicit 0 is not.
+        assert_eq!(count_if_array_numeric_criteria(&arr, ne_zero), len - 1);
 
         let eq_zero = NumericCriteria::new(CmpOp::Eq, 0.0);
-        // NaN == 0 => false, explicit 0 == 0 => true, empty(0) == 0 => true.
-        assert_eq!(count_if_array_numeric_criteria(&arr, eq_zero), 2);
+        // NaN == 0 => false, explicit 0 == 0 => true, empty == 0 => false.
+        assert_eq!(count_if_array_numeric_criteria(&arr, eq_zero), 1);
     }
 
     #[test]
@@ -14083,7 +14430,9 @@ mod tests {
 
         let mut crit_values: Vec<Value> = Vec::new();
         let mut sum_values: Vec<Value> = Vec::new();
-        assert!(crit_values.try_reserve_exact(len).is_ok() && sum_values.try_reserve_exact(len).is_ok());
+        assert!(
+            crit_values.try_reserve_exact(len).is_ok() && sum_values.try_reserve_exact(len).is_ok()
+        );
         for i in 0..len {
             crit_values.push(Value::Number(i as f64));
             sum_values.push(Value::Number(1.0));
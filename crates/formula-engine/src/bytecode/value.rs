@@ -135,7 +135,7 @@ impl MultiRangeRef {
 }
 
 /// Resolved absolute range (inclusive bounds).
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ResolvedRange {
     pub row_start: i32,
     pub row_end: i32,
@@ -9,11 +9,52 @@ pub enum ExcelDateSystem {
     Excel1900 { lotus_compat: bool },
     /// 1904 date system (Mac). Serial day 0 is `1904-01-01`.
     Excel1904,
+    /// 1900 date system on the proleptic Gregorian calendar (see
+    /// [`DateCompatibility::Proleptic`]). Serial day 0 is `1899-12-30`, so serials from
+    /// `1900-03-01` onward match [`ExcelDateSystem::EXCEL_1900`].
+    Excel1900Proleptic,
 }
 
 impl ExcelDateSystem {
     /// Excel's default 1900 date system with the Lotus compatibility bug enabled.
     pub const EXCEL_1900: ExcelDateSystem = ExcelDateSystem::Excel1900 { lotus_compat: true };
+
+    /// Calendar compatibility mode of this date system.
+    pub fn compatibility(self) -> DateCompatibility {
+        match self {
+            ExcelDateSystem::Excel1900Proleptic => DateCompatibility::Proleptic,
+            ExcelDateSystem::Excel1900 { .. } | ExcelDateSystem::Excel1904 => {
+                DateCompatibility::ExcelBug
+            }
+        }
+    }
+
+    /// Switch a 1900 date system between Excel's calendar and the proleptic one. The 1904 date
+    /// system has no fictitious leap day and is returned unchanged.
+    pub fn with_compatibility(self, compatibility: DateCompatibility) -> ExcelDateSystem {
+        match (self, compatibility) {
+            (ExcelDateSystem::Excel1904, _) => self,
+            (_, DateCompatibility::Proleptic) => ExcelDateSystem::Excel1900Proleptic,
+            (ExcelDateSystem::Excel1900Proleptic, DateCompatibility::ExcelBug) => {
+                ExcelDateSystem::EXCEL_1900
+            }
+            (ExcelDateSystem::Excel1900 { .. }, DateCompatibility::ExcelBug) => self,
+        }
+    }
+}
+
+/// How the 1900 date system treats the year 1900.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateCompatibility {
+    /// Excel's calendar: 1900 is a leap year, so serial 60 is the fictitious `1900-02-29`.
+    #[default]
+    ExcelBug,
+    /// Historically correct calendar with no `1900-02-29`. Serials from `1900-03-01` (61) onward
+    /// are unchanged; earlier dates are one serial higher than in Excel (`1900-01-01` is 2).
+    ///
+    /// Use only when Excel interop isn't required: workbooks exchanged with Excel will show
+    /// pre-March-1900 dates one day off.
+    Proleptic,
 }
 
 /// Calendar date representation that can model Excel's fictitious `1900-02-29`.
@@ -59,6 +100,13 @@ pub fn ymd_to_serial(date: ExcelDate, system: ExcelDateSystem) -> ExcelResult<i3
             let serial = days - base;
             Ok(i32::try_from(serial).map_err(|_| ExcelError::Num)?)
         }
+        ExcelDateSystem::Excel1900Proleptic => {
+            validate_ymd(date)?;
+            let days = days_from_civil(date.year, date.month, date.day);
+            let base = days_from_civil(1899, 12, 30);
+            let serial = days - base;
+            Ok(i32::try_from(serial).map_err(|_| ExcelError::Num)?)
+        }
     }
 }
 
@@ -87,7 +135,26 @@ pub fn serial_to_ymd(serial: i32, system: ExcelDateSystem) -> ExcelResult<ExcelD
             let (y, m, d) = civil_from_days(days);
             Ok(ExcelDate::new(y, m, d))
         }
+        ExcelDateSystem::Excel1900Proleptic => {
+            let base = days_from_civil(1899, 12, 30);
+            let days = base + i64::from(serial);
+            let (y, m, d) = civil_from_days(days);
+            Ok(ExcelDate::new(y, m, d))
+        }
+    }
+}
+
+/// Serial of `9999-12-31`, the last date Excel can represent, in `system`.
+pub fn max_serial(system: ExcelDateSystem) -> i32 {
+    ymd_to_serial(ExcelDate::new(9999, 12, 31), system).unwrap_or(i32::MAX)
+}
+
+/// Accept `serial` if it lies in Excel's date range (`0..=max_serial`), otherwise `#NUM!`.
+pub fn check_serial(serial: i64, system: ExcelDateSystem) -> ExcelResult<i32> {
+    if serial < 0 || serial > i64::from(max_serial(system)) {
+        return Err(ExcelError::Num);
     }
+    Ok(serial as i32)
 }
 
 fn validate_ymd(date: ExcelDate) -> ExcelResult<()> {
@@ -10,4 +10,4 @@ mod trace;
 
 pub use trace::{DebugEvaluation, Span, TraceKind, TraceNode, TraceRef};
 
-pub(crate) use trace::{evaluate_with_trace, parse_spanned_formula};
+pub(crate) use trace::{evaluate_with_trace, parse_spanned_formula, SpannedExpr, SpannedExprKind};
@@ -3,6 +3,7 @@ use crate::eval::{
     parse_a1, CellAddr, CompareOp, EvalContext, FormulaParseError, SheetReference, UnaryOp,
 };
 use crate::functions::{ArgValue as FnArgValue, FunctionContext, SheetId as FnSheetId};
+use crate::locale::TextCollation;
 use crate::value::{Array, ErrorKind, Value};
 use formula_model::sheet_name_eq_case_insensitive;
 use std::cmp::Ordering;
@@ -3336,7 +3337,8 @@ impl<'a, R: crate::eval::ValueResolver> TracedEvaluator<'a, R> {
 
                 let l = self.deref_eval_value_dynamic(l_ev);
                 let r = self.deref_eval_value_dynamic(r_ev);
-                let out = elementwise_binary(&l, &r, |a, b| excel_compare(a, b, *op));
+                let collation = self.value_locale.collation;
+                let out = elementwise_binary(&l, &r, |a, b| excel_compare(a, b, *op, collation));
                 (
                     EvalValue::Scalar(out.clone()),
                     TraceNode {
@@ -3526,6 +3528,7 @@ impl<'a, R: crate::eval::ValueResolver> TracedEvaluator<'a, R> {
         match name {
             "IF" => self.fn_if(args),
             "IFERROR" => self.fn_iferror(args),
+            "IFNA" => self.fn_ifna(args),
             "ISERROR" => self.fn_iserror(args),
             "RTD" => self.fn_rtd(args),
             "SUM" => self.fn_sum(args),
@@ -4047,17 +4050,43 @@ impl<'a, R: crate::eval::ValueResolver> TracedEvaluator<'a, R> {
     }
 
     fn fn_iferror(&self, args: &[SpannedExpr<usize>]) -> (Value, Vec<TraceNode>) {
+        self.fn_if_error_kind(args, |e| matches!(e, Value::Error(_)))
+    }
+
+    fn fn_ifna(&self, args: &[SpannedExpr<usize>]) -> (Value, Vec<TraceNode>) {
+        self.fn_if_error_kind(args, |e| matches!(e, Value::Error(ErrorKind::NA)))
+    }
+
+    /// Shared IFERROR/IFNA body: array results pass through unchanged unless some element is an
+    /// error, in which case the fallback replaces those elements (broadcasting like `lift2`).
+    fn fn_if_error_kind(
+        &self,
+        args: &[SpannedExpr<usize>],
+        is_caught: impl Fn(&Value) -> bool,
+    ) -> (Value, Vec<TraceNode>) {
         if args.len() < 2 {
             return (Value::Error(ErrorKind::Value), Vec::new());
         }
-        let (first, first_trace) = self.eval_scalar(&args[0]);
-        match first {
-            Value::Error(_) => {
-                let (fallback, fallback_trace) = self.eval_scalar(&args[1]);
-                (fallback, vec![first_trace, fallback_trace])
-            }
-            other => (other, vec![first_trace]),
+        let (first_ev, first_trace) = self.eval_value(&args[0]);
+        let first = self.deref_eval_value_dynamic(first_ev);
+        let needs_fallback = match &first {
+            Value::Array(arr) => arr.iter().any(&is_caught),
+            other => is_caught(other),
+        };
+        if !needs_fallback {
+            return (first, vec![first_trace]);
         }
+
+        let (fallback_ev, fallback_trace) = self.eval_value(&args[1]);
+        let fallback = self.deref_eval_value_dynamic(fallback_ev);
+        let out = elementwise_binary(&first, &fallback, |first, fallback| {
+            if is_caught(first) {
+                fallback.clone()
+            } else {
+                first.clone()
+            }
+        });
+        (out, vec![first_trace, fallback_trace])
     }
 
     fn fn_iserror(&self, args: &[SpannedExpr<usize>]) -> (Value, Vec<TraceNode>) {
@@ -4195,6 +4224,7 @@ impl<'a, R: crate::eval::ValueResolver> TracedEvaluator<'a, R> {
             true
         };
         let exact = !range_lookup;
+        let collation = self.value_locale.collation;
 
         let width = (table_range.end.col - table_range.start.col + 1) as i64;
         if col_index > width {
@@ -4212,7 +4242,7 @@ impl<'a, R: crate::eval::ValueResolver> TracedEvaluator<'a, R> {
                 if matches!(candidate, Value::Error(_)) {
                     continue;
                 }
-                let is_match = excel_order(&candidate, &lookup_value)
+                let is_match = excel_order(&candidate, &lookup_value, collation)
                     .map(|o| o == Ordering::Equal)
                     .unwrap_or(false);
                 if is_match {
@@ -4238,7 +4268,7 @@ impl<'a, R: crate::eval::ValueResolver> TracedEvaluator<'a, R> {
                 if matches!(candidate, Value::Error(_)) {
                     continue;
                 }
-                let ord = match excel_order(&candidate, &lookup_value) {
+                let ord = match excel_order(&candidate, &lookup_value, collation) {
                     Ok(o) => o,
                     Err(_) => continue,
                 };
@@ -4332,6 +4362,9 @@ impl<'a, R: crate::eval::ValueResolver> TracedEvaluator<'a, R> {
                         crate::date::ExcelDateSystem::Excel1900 { .. } => {
                             formula_format::DateSystem::Excel1900
                         }
+                        crate::date::ExcelDateSystem::Excel1900Proleptic => {
+                            formula_format::DateSystem::Excel1900Proleptic
+                        }
                         crate::date::ExcelDateSystem::Excel1904 => {
                             formula_format::DateSystem::Excel1904
                         }
@@ -4530,8 +4563,8 @@ fn map_excel_error(error: ExcelError) -> ErrorKind {
     }
 }
 
-fn excel_compare(left: &Value, right: &Value, op: CompareOp) -> Value {
-    let ord = match excel_order(left, right) {
+fn excel_compare(left: &Value, right: &Value, op: CompareOp, collation: TextCollation) -> Value {
+    let ord = match excel_order(left, right, collation) {
         Ok(ord) => ord,
         Err(e) => return Value::Error(e),
     };
@@ -4548,7 +4581,11 @@ fn excel_compare(left: &Value, right: &Value, op: CompareOp) -> Value {
     Value::Bool(result)
 }
 
-fn excel_order(left: &Value, right: &Value) -> Result<Ordering, ErrorKind> {
+fn excel_order(
+    left: &Value,
+    right: &Value,
+    collation: TextCollation,
+) -> Result<Ordering, ErrorKind> {
     if let Value::Error(e) = left {
         return Err(*e);
     }
@@ -4596,8 +4633,8 @@ fn excel_order(left: &Value, right: &Value) -> Result<Ordering, ErrorKind> {
     };
 
     Ok(match (&l, &r) {
-        (Value::Number(a), Value::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
-        (Value::Text(a), Value::Text(b)) => crate::value::cmp_case_insensitive(a, b),
+        (Value::Number(a), Value::Number(b)) => crate::value::cmp_numbers_excel(*a, *b),
+        (Value::Text(a), Value::Text(b)) => crate::value::cmp_text_collated(a, b, collation),
         (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
         (Value::Number(_), Value::Text(_) | Value::Bool(_)) => Ordering::Less,
         (Value::Text(_), Value::Bool(_)) => Ordering::Less,
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use formula_format::{FormatOptions, Value as FmtValue};
 
+use crate::locale::{FormulaLocale, EN_US};
 use crate::value::RecordValue;
 use crate::Value;
 
@@ -15,6 +16,20 @@ pub fn format_value_for_display(
     value: &Value,
     format_code: Option<&str>,
     options: &FormatOptions,
+) -> formula_format::FormattedValue {
+    format_value_for_display_in_locale(value, format_code, options, &EN_US)
+}
+
+/// Like [`format_value_for_display`], but renders error values with `locale`'s
+/// error literals (e.g. `#BEZUG!` instead of `#REF!` in `de-DE`).
+///
+/// Only the rendered text is localized; the value itself keeps its canonical
+/// error code.
+pub fn format_value_for_display_in_locale(
+    value: &Value,
+    format_code: Option<&str>,
+    options: &FormatOptions,
+    locale: &FormulaLocale,
 ) -> formula_format::FormattedValue {
     enum DisplayValue<'a> {
         Number(f64),
@@ -98,6 +113,7 @@ pub fn format_value_for_display(
         }
         DisplayValue::Blank => formula_format::format_value(FmtValue::Blank, format_code, options),
         DisplayValue::Error(err) => {
+            let err = locale.localized_error_literal(err).unwrap_or(err);
             formula_format::format_value(FmtValue::Error(err), format_code, options)
         }
     }
@@ -3,4 +3,5 @@ pub mod rewrite;
 
 pub use ops::{
     CellChange, CellSnapshot, EditError, EditOp, EditResult, FormulaRewrite, MovedRange,
+    TextToColumnsSplit,
 };
@@ -1,3 +1,4 @@
+use crate::sort_filter::SortKey;
 use crate::value::Value;
 use formula_model::{CellRef, Range};
 
@@ -55,6 +56,47 @@ pub enum EditOp {
         src: Range,
         dst: Range,
     },
+    /// Split the text of each cell in a single-column range across adjacent columns, like Excel's
+    /// Data > Text to Columns. Pieces are re-typed (numbers, dates/times and booleans are inferred).
+    ///
+    /// With `shift_right`, cells are inserted to the right of `src` for the extra pieces;
+    /// otherwise the pieces overwrite the neighbouring cells.
+    TextToColumns {
+        sheet: String,
+        src: Range,
+        split: TextToColumnsSplit,
+        shift_right: bool,
+    },
+    /// Delete rows within `range` whose values in `key_columns` repeat an earlier row, like
+    /// Excel's Data > Remove Duplicates. Text compares case-insensitively.
+    ///
+    /// `key_columns` are sheet column indices inside `range`. Remaining rows shift up within the
+    /// range; cells outside it are untouched.
+    RemoveDuplicates {
+        sheet: String,
+        range: Range,
+        key_columns: Vec<u32>,
+    },
+    /// Stably sort the rows of `range` by `keys`, like Excel's Data > Sort.
+    ///
+    /// Key columns are offsets from `range.start.col` (as in [`crate::sort_filter::SortSpec`]).
+    /// Cells move with their rows and relative references in moved formulas are adjusted to the
+    /// new row; references from elsewhere to the sorted cells are left alone.
+    SortRange {
+        sheet: String,
+        range: Range,
+        keys: Vec<SortKey>,
+        has_header: bool,
+    },
+}
+
+/// How [`EditOp::TextToColumns`] breaks a cell's text into pieces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TextToColumnsSplit {
+    /// Split at every occurrence of the delimiter.
+    Delimiter(char),
+    /// Split into fields of the given character widths; any remaining text forms a final field.
+    FixedWidths(Vec<u32>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -100,4 +142,6 @@ pub struct EditResult {
     pub changed_cells: Vec<CellChange>,
     pub moved_ranges: Vec<MovedRange>,
     pub formula_rewrites: Vec<FormulaRewrite>,
+    /// Rows deleted by [`EditOp::RemoveDuplicates`] (zero for other operations).
+    pub removed_rows: u32,
 }
@@ -5,7 +5,8 @@ use formula_model::sheet_name_eq_case_insensitive;
 use crate::{
     parse_formula, ArrayLiteral, Ast, BinaryExpr, BinaryOp, CallExpr, CellAddr,
     CellRef as AstCellRef, ColRef as AstColRef, Coord, Expr, FieldAccessExpr, FunctionCall,
-    ParseOptions, PostfixExpr, RowRef as AstRowRef, SerializeOptions, SheetRef, UnaryExpr,
+    FunctionName, ParseOptions, PostfixExpr, RowRef as AstRowRef, SerializeOptions, SheetRef,
+    UnaryExpr,
 };
 
 const REF_ERROR: &str = "#REF!";
@@ -219,6 +220,24 @@ pub fn rewrite_formula_for_sheet_delete_with_aliases(
     })
 }
 
+/// Rewrites references to the defined name `old_name` so they spell `new_name`.
+///
+/// `targets_renamed_name` is called with each matching reference's sheet qualifier (`None` for a
+/// bare `=Rate`, which resolves against the formula's own sheet) and decides whether that
+/// reference resolves to the renamed definition. Calls to LAMBDA-valued names (`=MyFn(1)`) are
+/// rewritten too; LET/LAMBDA locals that shadow `old_name` are left untouched.
+pub fn rewrite_formula_for_name_rename(
+    formula: &str,
+    cell_origin: CellAddr,
+    old_name: &str,
+    new_name: &str,
+    mut targets_renamed_name: impl FnMut(Option<&str>) -> bool,
+) -> (String, bool) {
+    rewrite_formula_via_ast(formula, cell_origin, |expr| {
+        rewrite_expr_for_name_rename(expr, old_name, new_name, false, &mut targets_renamed_name)
+    })
+}
+
 fn rewrite_formula_via_ast<F>(formula: &str, cell_origin: CellAddr, f: F) -> (String, bool)
 where
     F: FnOnce(&Expr) -> (Expr, bool),
@@ -2476,6 +2495,128 @@ fn shift_range(area: GridRange, delta_row: i32, delta_col: i32) -> Option<GridRa
     ))
 }
 
+fn name_matches(name: &str, defined_name: &str) -> bool {
+    crate::value::eq_case_insensitive(name.trim(), defined_name.trim())
+}
+
+/// Whether a LET/LAMBDA binding named `binding` shadows the defined name `defined_name`.
+fn binding_shadows(binding: &Expr, defined_name: &str) -> bool {
+    const XLPM_PREFIX: &str = "_xlpm.";
+    let Expr::NameRef(binding) = binding else {
+        return false;
+    };
+    let name = binding.name.trim();
+    let name = match name.get(..XLPM_PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(XLPM_PREFIX) => &name[XLPM_PREFIX.len()..],
+        _ => name,
+    };
+    name_matches(name, defined_name)
+}
+
+fn rewrite_expr_for_name_rename<F>(
+    expr: &Expr,
+    old_name: &str,
+    new_name: &str,
+    shadowed: bool,
+    targets_renamed_name: &mut F,
+) -> (Expr, bool)
+where
+    F: FnMut(Option<&str>) -> bool,
+{
+    match expr {
+        Expr::NameRef(r) if r.workbook.is_none() && name_matches(&r.name, old_name) => {
+            let targets = match &r.sheet {
+                None => !shadowed && targets_renamed_name(None),
+                Some(SheetRef::Sheet(sheet)) => targets_renamed_name(Some(sheet)),
+                Some(SheetRef::SheetRange { .. }) => false,
+            };
+            if !targets {
+                return (expr.clone(), false);
+            }
+            let mut out = r.clone();
+            out.name = new_name.to_string();
+            (Expr::NameRef(out), true)
+        }
+        Expr::FunctionCall(call)
+            if matches!(call.name.name_upper.as_str(), "LET" | "LAMBDA")
+                && !call.args.is_empty() =>
+        {
+            // LET(name1, value1, ..., calculation): each binding is visible to the arguments
+            // after it. LAMBDA(param1, ..., body): parameters are visible to the body.
+            let is_let = call.name.name_upper == "LET";
+            let last = call.args.len() - 1;
+            let mut inner_shadowed = shadowed;
+            let mut changed = false;
+            let mut args = Vec::with_capacity(call.args.len());
+            for (idx, arg) in call.args.iter().enumerate() {
+                let is_binding = idx < last && (!is_let || idx % 2 == 0);
+                if is_binding && matches!(arg, Expr::NameRef(_)) {
+                    inner_shadowed |= binding_shadows(arg, old_name);
+                    args.push(arg.clone());
+                    continue;
+                }
+                let scope_shadowed = if is_let || idx == last {
+                    inner_shadowed
+                } else {
+                    shadowed
+                };
+                let (rewritten, c) = rewrite_expr_for_name_rename(
+                    arg,
+                    old_name,
+                    new_name,
+                    scope_shadowed,
+                    targets_renamed_name,
+                );
+                changed |= c;
+                args.push(rewritten);
+            }
+            if !changed {
+                return (expr.clone(), false);
+            }
+            (
+                Expr::FunctionCall(FunctionCall {
+                    name: call.name.clone(),
+                    args,
+                }),
+                true,
+            )
+        }
+        Expr::FunctionCall(call) => {
+            let (rewritten, args_changed) = rewrite_expr_children(expr, |child| {
+                rewrite_expr_for_name_rename(
+                    child,
+                    old_name,
+                    new_name,
+                    shadowed,
+                    targets_renamed_name,
+                )
+            });
+            let callee_renamed = !shadowed
+                && !call.name.has_xlfn_prefix
+                && name_matches(&call.name.original, old_name)
+                && crate::functions::lookup_function(&call.name.name_upper).is_none()
+                && targets_renamed_name(None);
+            if !callee_renamed {
+                return (rewritten, args_changed);
+            }
+            let args = match rewritten {
+                Expr::FunctionCall(FunctionCall { args, .. }) => args,
+                _ => call.args.clone(),
+            };
+            (
+                Expr::FunctionCall(FunctionCall {
+                    name: FunctionName::new(new_name.to_string()),
+                    args,
+                }),
+                true,
+            )
+        }
+        _ => rewrite_expr_children(expr, |child| {
+            rewrite_expr_for_name_rename(child, old_name, new_name, shadowed, targets_renamed_name)
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,17 +1,18 @@
 use crate::bytecode;
 use crate::calc_settings::{CalcSettings, CalculationMode};
-use crate::date::ExcelDateSystem;
+use crate::date::{DateCompatibility, ExcelDateSystem};
 use crate::editing::rewrite::{
-    rewrite_formula_for_copy_delta, rewrite_formula_for_range_map_with_resolver,
-    rewrite_formula_for_sheet_delete_with_aliases,
+    rewrite_formula_for_copy_delta, rewrite_formula_for_name_rename,
+    rewrite_formula_for_range_map_with_resolver, rewrite_formula_for_sheet_delete_with_aliases,
     rewrite_formula_for_structural_edit_with_resolver, GridRange, RangeMapEdit, StructuralEdit,
 };
 use crate::editing::{
     CellChange, CellSnapshot, EditError, EditOp, EditResult, FormulaRewrite, MovedRange,
+    TextToColumnsSplit,
 };
 use crate::eval::{
-    compile_canonical_expr, lower_ast, parse_a1, CellAddr, CompiledExpr, Expr, FormulaParseError,
-    RangeRef, SheetReference, ValueResolver,
+    compile_canonical_expr, lower_ast, parse_a1_with_max_cols, CellAddr, CompiledExpr, Expr,
+    FormulaParseError, RangeRef, SheetReference, ValueResolver,
 };
 use crate::graph::{CellDeps, DependencyGraph as CalcGraph, Precedent, SheetRange};
 use crate::iterative;
@@ -26,14 +27,12 @@ use crate::pivot::{
     PivotTableDefinition, PivotTableId,
 };
 use crate::value::{Array, ErrorKind, Value};
-use formula_format::{
-    DateSystem as FmtDateSystem, FormatOptions as FmtFormatOptions, Value as FmtValue,
-};
+use formula_format::{DateSystem as FmtDateSystem, FormatOptions as FmtFormatOptions};
 use formula_model::table::TableColumn;
 use formula_model::{
     rewrite_table_names_in_formula, validate_table_name, CellId, CellRef, ColProperties,
-    HorizontalAlignment, Range, RowProperties, Style, StyleTable, Table, TableError,
-    EXCEL_MAX_COLS, EXCEL_MAX_ROWS,
+    GridLimits, HiddenState, HorizontalAlignment, Range, RowProperties, Style, StyleTable, Table,
+    TableError, EXCEL_MAX_COLS, EXCEL_MAX_ROWS, MAX_GRID_COLS,
 };
 #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
 use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
@@ -47,10 +46,19 @@ use thiserror::Error;
 use unicode_normalization::UnicodeNormalization;
 
 mod bytecode_diagnostics;
+mod formula_analysis;
+mod formula_lint;
+mod formula_validation;
 mod pivot_refresh;
+mod print_names;
+mod snapshot;
 pub use bytecode_diagnostics::{
     BytecodeCompileReason, BytecodeCompileReportEntry, BytecodeCompileStats,
 };
+pub use formula_analysis::FormulaReferences;
+pub use formula_lint::{FormulaLint, FormulaLintKind, LintSeverity};
+pub use formula_validation::FormulaDiagnostic;
+pub use snapshot::{EngineSnapshotError, ENGINE_SNAPSHOT_VERSION};
 
 pub type SheetId = usize;
 
@@ -95,6 +103,10 @@ pub enum EngineError {
     AstSerialize(#[from] crate::SerializeError),
     #[error("cannot delete last sheet")]
     CannotDeleteLastSheet,
+    #[error("sheet not found: {0}")]
+    SheetNotFound(String),
+    #[error("{0} must be scoped to a sheet")]
+    BuiltinNameRequiresSheetScope(&'static str),
     #[error(
         "range values dimensions mismatch: expected {expected_rows}x{expected_cols}, got {actual_rows}x{actual_cols}"
     )]
@@ -106,6 +118,12 @@ pub enum EngineError {
     },
     #[error("allocation failed: {0}")]
     AllocationFailure(&'static str),
+    #[error(transparent)]
+    AutoFilter(#[from] crate::sort_filter::ModelAutoFilterError),
+    #[error(transparent)]
+    Filter(#[from] crate::sort_filter::FilterError),
+    #[error(transparent)]
+    RangeData(#[from] crate::sort_filter::RangeDataError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
@@ -122,6 +140,18 @@ pub enum SheetLifecycleError {
     Internal(String),
 }
 
+#[derive(Debug, Error)]
+pub enum NameRenameError {
+    #[error("defined name not found")]
+    NameNotFound,
+    #[error(transparent)]
+    InvalidName(#[from] formula_model::DefinedNameValidationError),
+    #[error("a defined name with this name already exists in the same scope")]
+    DuplicateName,
+    #[error(transparent)]
+    Engine(#[from] EngineError),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RecalcMode {
     SingleThreaded,
@@ -181,6 +211,42 @@ pub struct RecalcValueChange {
     pub value: Value,
 }
 
+/// Number of evaluated cells between two progress callbacks during a recalc.
+pub const RECALC_PROGRESS_INTERVAL: usize = 256;
+
+/// Progress of an in-flight recalculation, see
+/// [`Engine::recalculate_with_value_changes_and_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecalcProgress {
+    /// Formula cells evaluated so far.
+    pub done: usize,
+    /// Formula cells scheduled so far; grows if spills dirty additional cells mid-recalc.
+    pub total: usize,
+}
+
+/// A formatting layer in the style precedence chain `sheet < col < row < range-run < cell`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleLayer {
+    Sheet,
+    Col,
+    Row,
+    RangeRun,
+    Cell,
+}
+
+/// One layer's contribution to a cell's effective style, see
+/// [`Engine::describe_style_resolution`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleLayerContribution {
+    pub layer: StyleLayer,
+    pub style_id: u32,
+    /// Style properties this layer's style sets (`"font"`, `"fill"`, `"border"`, `"alignment"`,
+    /// `"protection"`, `"numberFormat"`).
+    pub properties: Vec<&'static str>,
+    /// The subset of `properties` not overridden by a higher-precedence layer.
+    pub supplied: Vec<&'static str>,
+}
+
 /// Scope for a defined name / named range.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NameScope<'a> {
@@ -328,10 +394,15 @@ struct Sheet {
     row_count: u32,
     /// Logical column count for the worksheet grid.
     ///
-    /// The engine currently enforces Excel's 16,384-column maximum.
+    /// Bounded by the workbook's [`GridLimits::max_cols`] (Excel's 16,384 columns by default).
     col_count: u32,
     /// Per-row formatting/visibility overrides.
     row_properties: BTreeMap<u32, RowProperties>,
+    /// Rows currently hidden by an AutoFilter (0-based).
+    ///
+    /// Kept apart from the user-hidden flag in `row_properties` so re-applying or clearing a
+    /// filter never unhides rows the user hid explicitly. See [`Engine::apply_autofilter`].
+    filter_hidden_rows: BTreeSet<u32>,
     /// Per-column formatting/visibility overrides.
     col_properties: BTreeMap<u32, ColProperties>,
     /// Range-based formatting layer stored as per-column row interval runs.
@@ -367,6 +438,7 @@ impl Default for Sheet {
             row_count: EXCEL_MAX_ROWS,
             col_count: EXCEL_MAX_COLS,
             row_properties: BTreeMap::new(),
+            filter_hidden_rows: BTreeSet::new(),
             col_properties: BTreeMap::new(),
             format_runs_by_col: BTreeMap::new(),
             dc_default_style_id: 0,
@@ -414,6 +486,8 @@ struct Workbook {
     next_pivot_id: PivotTableId,
     /// Legacy text code page used for DBCS (`*B`) text functions.
     text_codepage: u16,
+    /// Logical grid bounds: default dimensions for new sheets and the column limit for addresses.
+    grid_limits: GridLimits,
 }
 
 #[cfg(test)]
@@ -529,7 +603,11 @@ impl Workbook {
         };
 
         let id = self.sheets.len();
-        self.sheets.push(Sheet::default());
+        self.sheets.push(Sheet {
+            row_count: self.grid_limits.max_rows,
+            col_count: self.grid_limits.max_cols,
+            ..Sheet::default()
+        });
         self.sheet_keys.push(Some(sheet_key.to_string()));
         self.sheet_display_names.push(Some(display_name.clone()));
 
@@ -967,6 +1045,8 @@ pub struct Engine {
     date_system: ExcelDateSystem,
     value_locale: ValueLocaleConfig,
     locale_config: crate::LocaleConfig,
+    /// Locale used to render error values in display text (see [`Engine::cell_display_text`]).
+    display_locale: &'static FormulaLocale,
     text_codepage: u16,
     circular_references: HashSet<CellKey>,
     spills: SpillState,
@@ -975,6 +1055,48 @@ pub struct Engine {
     info: EngineInfo,
 }
 
+struct RecalcProgressReporter<'a> {
+    on_progress: &'a mut dyn FnMut(RecalcProgress),
+    done: usize,
+    total: usize,
+    last_reported: usize,
+}
+
+impl<'a> RecalcProgressReporter<'a> {
+    fn new(on_progress: &'a mut dyn FnMut(RecalcProgress)) -> Self {
+        Self {
+            on_progress,
+            done: 0,
+            total: 0,
+            last_reported: 0,
+        }
+    }
+
+    fn schedule(&mut self, cells: usize) {
+        self.total = self.total.saturating_add(cells);
+    }
+
+    fn advance(&mut self, cells: usize) {
+        self.done = self.done.saturating_add(cells).min(self.total);
+        if self.done - self.last_reported >= RECALC_PROGRESS_INTERVAL {
+            self.report();
+        }
+    }
+
+    fn finish(&mut self) {
+        self.done = self.total;
+        self.report();
+    }
+
+    fn report(&mut self) {
+        self.last_reported = self.done;
+        (self.on_progress)(RecalcProgress {
+            done: self.done,
+            total: self.total,
+        });
+    }
+}
+
 #[derive(Default)]
 struct RecalcValueChangeCollector {
     before: HashMap<CellKey, Value>,
@@ -1087,6 +1209,7 @@ impl Engine {
             date_system: ExcelDateSystem::EXCEL_1900,
             value_locale: ValueLocaleConfig::default(),
             locale_config: crate::LocaleConfig::en_us(),
+            display_locale: &crate::locale::EN_US,
             text_codepage: 1252,
             circular_references: HashSet::new(),
             spills: SpillState::default(),
@@ -1107,7 +1230,7 @@ impl Engine {
         col: u32,
         mut runs: Vec<FormatRun>,
     ) -> Result<(), EngineError> {
-        if col >= EXCEL_MAX_COLS {
+        if col >= self.workbook.grid_limits.max_cols {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::ColumnOutOfRange,
             ));
@@ -1724,7 +1847,7 @@ impl Engine {
         style_id: u32,
     ) -> Result<(), EngineError> {
         let sheet_id = self.workbook.ensure_sheet(sheet);
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         if addr.row >= i32::MAX as u32 {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::RowOutOfRange,
@@ -1820,7 +1943,7 @@ impl Engine {
                     crate::eval::AddressParseError::RowOutOfRange,
                 ));
             }
-            if cell.col >= EXCEL_MAX_COLS {
+            if cell.col >= self.workbook.grid_limits.max_cols {
                 return Err(EngineError::Address(
                     crate::eval::AddressParseError::ColumnOutOfRange,
                 ));
@@ -2003,6 +2126,241 @@ impl Engine {
         }
     }
 
+    /// Set (or clear) the explicit height override for a row, in points (OOXML `row/@ht`).
+    ///
+    /// Like Excel, a height of `0` hides the row instead of storing a zero height, so the
+    /// previous height is restored when the row is unhidden via [`Engine::set_row_hidden`].
+    pub fn set_row_height(&mut self, sheet: &str, row_0based: u32, height: Option<f32>) {
+        if height == Some(0.0) {
+            self.set_row_hidden(sheet, row_0based, true);
+            return;
+        }
+
+        let sheet_id = self.workbook.ensure_sheet(sheet);
+        let sheet_dims_changed = self.workbook.grow_sheet_dimensions(
+            sheet_id,
+            CellAddr {
+                row: row_0based,
+                col: 0,
+            },
+        );
+        if sheet_dims_changed {
+            self.sheet_dims_generation = self.sheet_dims_generation.wrapping_add(1);
+            // Sheet dimension growth can affect out-of-bounds semantics; see `set_col_width`.
+            self.mark_all_compiled_cells_dirty();
+        }
+
+        let Some(sheet) = self.workbook.sheets.get_mut(sheet_id) else {
+            return;
+        };
+        sheet.row_properties.entry(row_0based).or_default().height = height;
+
+        // Prune default entries to keep the map sparse.
+        if let Some(props) = sheet.row_properties.get(&row_0based) {
+            if *props == RowProperties::default() {
+                sheet.row_properties.remove(&row_0based);
+            }
+        }
+
+        // Row heights are layout metadata only; no formula observes them today, so only sheet
+        // growth requires a recalculation tick.
+        if sheet_dims_changed && self.calc_settings.calculation_mode != CalculationMode::Manual {
+            self.recalculate();
+        }
+    }
+
+    /// Set whether a row is user-hidden.
+    pub fn set_row_hidden(&mut self, sheet: &str, row_0based: u32, hidden: bool) {
+        let sheet_id = self.workbook.ensure_sheet(sheet);
+        let sheet_dims_changed = self.workbook.grow_sheet_dimensions(
+            sheet_id,
+            CellAddr {
+                row: row_0based,
+                col: 0,
+            },
+        );
+        if sheet_dims_changed {
+            self.sheet_dims_generation = self.sheet_dims_generation.wrapping_add(1);
+            // Sheet dimension growth can affect out-of-bounds semantics; see `set_col_width`.
+            self.mark_all_compiled_cells_dirty();
+        }
+
+        let Some(sheet) = self.workbook.sheets.get_mut(sheet_id) else {
+            return;
+        };
+        let before = sheet
+            .row_properties
+            .get(&row_0based)
+            .map(|p| p.hidden)
+            .unwrap_or(false);
+        sheet.row_properties.entry(row_0based).or_default().hidden = hidden;
+
+        // Prune default entries to keep the map sparse.
+        if let Some(props) = sheet.row_properties.get(&row_0based) {
+            if *props == RowProperties::default() {
+                sheet.row_properties.remove(&row_0based);
+            }
+        }
+
+        if before != hidden {
+            self.mark_row_visibility_dependents_dirty();
+        }
+
+        if (sheet_dims_changed || before != hidden)
+            && self.calc_settings.calculation_mode != CalculationMode::Manual
+        {
+            self.recalculate();
+        }
+    }
+
+    /// Returns the effective height of a row in points.
+    ///
+    /// Hidden rows report a height of `0` (matching Excel's row height dialog). Returns `None`
+    /// when the row uses the sheet's default height.
+    pub fn row_height(&self, sheet: &str, row_0based: u32) -> Option<f32> {
+        let sheet_id = self.workbook.sheet_id(sheet)?;
+        let props = self
+            .workbook
+            .sheets
+            .get(sheet_id)?
+            .row_properties
+            .get(&row_0based)?;
+        if props.hidden {
+            return Some(0.0);
+        }
+        props.height
+    }
+
+    /// Returns whether a row is user-hidden.
+    pub fn is_row_hidden(&self, sheet: &str, row_0based: u32) -> bool {
+        self.workbook
+            .sheet_id(sheet)
+            .and_then(|sheet_id| self.workbook.sheets.get(sheet_id))
+            .and_then(|sheet| sheet.row_properties.get(&row_0based))
+            .is_some_and(|props| props.hidden)
+    }
+
+    /// Returns whether a row is hidden by an AutoFilter (see [`Engine::apply_autofilter`]).
+    pub fn is_row_filtered(&self, sheet: &str, row_0based: u32) -> bool {
+        self.workbook
+            .sheet_id(sheet)
+            .and_then(|sheet_id| self.workbook.sheets.get(sheet_id))
+            .is_some_and(|sheet| sheet.filter_hidden_rows.contains(&row_0based))
+    }
+
+    /// Evaluate an AutoFilter over `range` on `sheet` and hide the data rows it filters out.
+    ///
+    /// The first row of `range` is the header row and is never hidden. Filter-hidden flags for
+    /// the data rows are recomputed from the current cell values on every call; passing `None`
+    /// clears them (removing the filter). User-hidden rows are left untouched.
+    ///
+    /// `SUBTOTAL` and `AGGREGATE` skip filtered rows, so they are recalculated when the set of
+    /// filtered rows changes.
+    pub fn apply_autofilter(
+        &mut self,
+        sheet: &str,
+        range: Range,
+        filter: Option<&formula_model::autofilter::SheetAutoFilter>,
+    ) -> Result<crate::sort_filter::FilterResult, EngineError> {
+        let sheet_id = self.workbook.ensure_sheet(sheet);
+        let result = match filter {
+            Some(filter) => {
+                let rows = self
+                    .get_range_values(sheet, range)?
+                    .iter()
+                    .map(|row| row.iter().map(engine_value_to_sort_value).collect())
+                    .collect();
+                let range_ref = crate::sort_filter::RangeRef {
+                    start_row: range.start.row as usize,
+                    start_col: range.start.col as usize,
+                    end_row: range.end.row as usize,
+                    end_col: range.end.col as usize,
+                };
+                let data = crate::sort_filter::RangeData::new(range_ref, rows)?;
+                let filter = crate::sort_filter::AutoFilter::try_from_model_with_value_locale(
+                    filter,
+                    self.value_locale,
+                )?;
+                crate::sort_filter::apply_autofilter_with_value_locale(
+                    &data,
+                    &filter,
+                    self.value_locale,
+                )?
+            }
+            None => crate::sort_filter::FilterResult {
+                visible_rows: vec![true; range.height() as usize],
+                hidden_sheet_rows: Vec::new(),
+            },
+        };
+
+        let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) else {
+            return Ok(result);
+        };
+        let data_rows = range.start.row.saturating_add(1)..=range.end.row;
+        let before: Vec<u32> = sheet_state
+            .filter_hidden_rows
+            .range(data_rows.clone())
+            .copied()
+            .collect();
+        for row in &before {
+            sheet_state.filter_hidden_rows.remove(row);
+        }
+        for &row in &result.hidden_sheet_rows {
+            if let Ok(row) = u32::try_from(row) {
+                if data_rows.contains(&row) {
+                    sheet_state.filter_hidden_rows.insert(row);
+                }
+            }
+        }
+        let changed = !sheet_state
+            .filter_hidden_rows
+            .range(data_rows)
+            .copied()
+            .eq(before);
+
+        if changed {
+            self.mark_row_visibility_dependents_dirty();
+            if self.calc_settings.calculation_mode != CalculationMode::Manual {
+                self.recalculate();
+            }
+        }
+        Ok(result)
+    }
+
+    /// Mark formulas that observe row visibility (`SUBTOTAL`, `AGGREGATE`) dirty.
+    ///
+    /// Hiding rows changes their results without changing any cell value, so the dependency
+    /// graph cannot see it. Formulas are matched by name, which is conservative but cheap compared
+    /// to dirtying the whole workbook.
+    fn mark_row_visibility_dependents_dirty(&mut self) {
+        for (sheet_id, sheet) in self.workbook.sheets.iter().enumerate() {
+            if !self.workbook.sheet_exists(sheet_id) {
+                continue;
+            }
+            for (addr, cell) in &sheet.cells {
+                if cell.compiled.is_none() {
+                    continue;
+                }
+                let Some(formula) = cell.formula.as_deref() else {
+                    continue;
+                };
+                let formula = formula.to_ascii_uppercase();
+                if !formula.contains("SUBTOTAL") && !formula.contains("AGGREGATE") {
+                    continue;
+                }
+                let key = CellKey {
+                    sheet: sheet_id,
+                    addr: *addr,
+                };
+                self.dirty.insert(key);
+                self.dirty_reasons.remove(&key);
+                self.calc_graph.mark_dirty(cell_id_from_key(key));
+            }
+        }
+
+        self.sync_dirty_from_calc_graph();
+    }
+
     /// Replace the set of formatting runs for a column.
     ///
     /// Runs are interpreted as row ranges `[start_row, end_row_exclusive)`.
@@ -2013,7 +2371,7 @@ impl Engine {
         col_0based: u32,
         mut runs: Vec<FormatRun>,
     ) -> Result<(), EngineError> {
-        if col_0based >= EXCEL_MAX_COLS {
+        if col_0based >= self.workbook.grid_limits.max_cols {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::ColumnOutOfRange,
             ));
@@ -2303,6 +2661,7 @@ impl Engine {
             sheet_state.names.clear();
             sheet_state.default_style_id = None;
             sheet_state.row_properties.clear();
+            sheet_state.filter_hidden_rows.clear();
             sheet_state.col_properties.clear();
             sheet_state.format_runs_by_col.clear();
             sheet_state.dc_default_style_id = 0;
@@ -2557,10 +2916,60 @@ impl Engine {
         result
     }
 
+    /// Returns the workbook's logical grid limits (Excel's grid unless changed via
+    /// [`Engine::set_grid_limits`]).
+    pub fn grid_limits(&self) -> GridLimits {
+        self.workbook.grid_limits
+    }
+
+    /// Opt into a logical grid larger (or smaller) than Excel's 1,048,576 x 16,384.
+    ///
+    /// The limits control:
+    /// - the default dimensions of sheets created afterwards (existing sheets keep theirs; use
+    ///   [`Engine::set_sheet_dimensions`] to resize them),
+    /// - the widest column accepted by A1 address parsing (`set_cell_value("XFE1", ..)`) and by
+    ///   column-bounded APIs such as [`Engine::set_sheet_dimensions`].
+    ///
+    /// `max_cols` may not exceed [`MAX_GRID_COLS`] (`ZZZ`), and `max_rows` may not exceed
+    /// `i32::MAX`. Lowering `max_cols` below an existing sheet's column count is rejected.
+    ///
+    /// Interop caveat: workbooks using cells beyond `XFD1048576` cannot be round-tripped through
+    /// Excel file formats, and bytecode evaluation falls back to the AST evaluator for references
+    /// beyond Excel's bounds.
+    pub fn set_grid_limits(&mut self, limits: GridLimits) -> Result<(), EngineError> {
+        if limits.max_rows == 0 || limits.max_rows > i32::MAX as u32 {
+            return Err(EngineError::Address(
+                crate::eval::AddressParseError::RowOutOfRange,
+            ));
+        }
+        if limits.max_cols == 0 || limits.max_cols > MAX_GRID_COLS {
+            return Err(EngineError::Address(
+                crate::eval::AddressParseError::ColumnOutOfRange,
+            ));
+        }
+        if self
+            .workbook
+            .sheets
+            .iter()
+            .any(|sheet| sheet.col_count > limits.max_cols)
+        {
+            return Err(EngineError::Address(
+                crate::eval::AddressParseError::ColumnOutOfRange,
+            ));
+        }
+        self.workbook.grid_limits = limits;
+        Ok(())
+    }
+
+    /// Parse an A1 address honoring the workbook's configured column limit.
+    fn parse_address(&self, addr: &str) -> Result<CellAddr, crate::eval::AddressParseError> {
+        parse_a1_with_max_cols(addr, self.workbook.grid_limits.max_cols)
+    }
+
     /// Returns the configured worksheet dimensions for `sheet` (row/column count).
     ///
-    /// When unset, sheets default to Excel-compatible dimensions
-    /// (`EXCEL_MAX_ROWS` x `EXCEL_MAX_COLS`).
+    /// When unset, sheets default to the workbook's [`GridLimits`] (Excel-compatible
+    /// `EXCEL_MAX_ROWS` x `EXCEL_MAX_COLS` unless configured otherwise).
     pub fn sheet_dimensions(&self, sheet: &str) -> Option<(u32, u32)> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
         let sheet = self.workbook.sheets.get(sheet_id)?;
@@ -2623,7 +3032,8 @@ impl Engine {
     /// against the sheet's configured dimensions.
     ///
     /// Notes:
-    /// - `col_count` is limited to Excel's 16,384-column maximum.
+    /// - `col_count` is limited to the workbook's [`GridLimits::max_cols`] (Excel's 16,384 columns
+    ///   unless raised via [`Engine::set_grid_limits`]).
     /// - `row_count` is limited to `i32::MAX` for now because several internal evaluation paths
     ///   (notably the bytecode engine) use 32-bit coordinates.
     pub fn set_sheet_dimensions(
@@ -2644,7 +3054,7 @@ impl Engine {
                 crate::eval::AddressParseError::ColumnOutOfRange,
             ));
         }
-        if col_count > EXCEL_MAX_COLS {
+        if col_count > self.workbook.grid_limits.max_cols {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::ColumnOutOfRange,
             ));
@@ -2734,7 +3144,7 @@ impl Engine {
 
         let origin = origin.map(str::trim).filter(|s| !s.is_empty());
         let origin = match origin {
-            Some(addr) => Some(parse_a1(addr)?),
+            Some(addr) => Some(self.parse_address(addr)?),
             None => None,
         };
 
@@ -2938,8 +3348,8 @@ impl Engine {
     ///
     /// `None` (or `Some(0)`) clears the column style.
     pub fn set_col_style_id(&mut self, sheet: &str, col0: u32, style_id: Option<u32>) {
-        // The engine enforces Excel's fixed 16,384-column grid.
-        if col0 >= EXCEL_MAX_COLS {
+        // Column styles beyond the workbook's grid are ignored.
+        if col0 >= self.workbook.grid_limits.max_cols {
             return;
         }
 
@@ -2997,50 +3407,250 @@ impl Engine {
         }
     }
 
-    /// Set the default style id for a sheet (DocumentController sheet formatting layer).
-    pub fn set_sheet_default_patch_style_id(&mut self, sheet: &str, style_id: u32) {
-        let sheet_id = self.workbook.ensure_sheet(sheet);
-        if let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) {
-            sheet_state.dc_default_style_id = style_id;
+    /// Restyle every cell in `range` by mapping the style it currently has through `update`.
+    ///
+    /// Small blocks are written cell by cell. Whole rows, whole columns and larger blocks go
+    /// through the style layers instead (sheet default, row/col styles, or per-column format runs)
+    /// so formatting a full sheet does not materialize a cell per grid position. Higher-precedence
+    /// layers and explicit cell styles inside `range` are mapped as well, so they keep their own
+    /// properties without shadowing the update.
+    ///
+    /// `update` runs once per distinct style id; id `0` stands for "no style".
+    pub fn update_range_style(
+        &mut self,
+        sheet: &str,
+        range: Range,
+        mut update: impl FnMut(Style) -> Style,
+    ) -> Result<(), EngineError> {
+        let limits = self.workbook.grid_limits;
+        if range.end.row >= i32::MAX as u32 {
+            return Err(EngineError::Address(
+                crate::eval::AddressParseError::RowOutOfRange,
+            ));
         }
-        self.mark_all_compiled_cells_dirty();
-        if self.calc_settings.calculation_mode != CalculationMode::Manual {
-            self.recalculate();
+        if range.end.col >= limits.max_cols {
+            return Err(EngineError::Address(
+                crate::eval::AddressParseError::ColumnOutOfRange,
+            ));
         }
-    }
 
-    /// Set the style id for a row (DocumentController row formatting layer).
-    pub fn set_row_patch_style_id(&mut self, sheet: &str, row: u32, style_id: u32) {
         let sheet_id = self.workbook.ensure_sheet(sheet);
-        // Ensure the row is in-bounds (growing dimensions if needed).
-        let _ = self
-            .workbook
-            .grow_sheet_dimensions(sheet_id, CellAddr { row, col: 0 });
-        if let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) {
-            if style_id == 0 {
-                sheet_state.dc_row_style_ids.remove(&row);
-            } else {
-                sheet_state.dc_row_style_ids.insert(row, style_id);
+        let mut mapped: HashMap<u32, u32> = HashMap::new();
+        let mut map_style = |styles: &mut StyleTable, style_id: u32| -> u32 {
+            *mapped.entry(style_id).or_insert_with(|| {
+                let style = styles.get(style_id).cloned().unwrap_or_default();
+                styles.intern(update(style))
+            })
+        };
+
+        let whole_rows = range.start.col == 0 && range.end.col + 1 >= limits.max_cols;
+        let whole_cols = range.start.row == 0 && range.end.row + 1 >= limits.max_rows;
+        if !whole_rows && !whole_cols && range.cell_count() <= MAX_PER_CELL_RESTYLE_CELLS {
+            let mut writes = Vec::with_capacity(range.cell_count() as usize);
+            for cell in range.iter() {
+                let key = CellKey {
+                    sheet: sheet_id,
+                    addr: CellAddr {
+                        row: cell.row,
+                        col: cell.col,
+                    },
+                };
+                let style_id = self.workbook.get_cell(key).map_or(0, |cell| cell.style_id);
+                writes.push((cell, map_style(&mut self.workbook.styles, style_id)));
             }
+            return self.set_cell_style_ids(sheet, &writes);
         }
-        self.mark_all_compiled_cells_dirty();
-        if self.calc_settings.calculation_mode != CalculationMode::Manual {
-            self.recalculate();
+
+        let grow_to = CellAddr {
+            row: if whole_cols { 0 } else { range.end.row },
+            col: if whole_rows { 0 } else { range.end.col },
+        };
+        if self.workbook.grow_sheet_dimensions(sheet_id, grow_to) {
+            self.sheet_dims_generation = self.sheet_dims_generation.wrapping_add(1);
+            self.mark_all_compiled_cells_dirty();
         }
-    }
 
-    /// Set the style id for a column (DocumentController column formatting layer).
-    pub fn set_col_patch_style_id(&mut self, sheet: &str, col: u32, style_id: u32) {
-        let sheet_id = self.workbook.ensure_sheet(sheet);
-        // Ensure the column is in-bounds (growing dimensions if needed).
-        let _ = self
-            .workbook
-            .grow_sheet_dimensions(sheet_id, CellAddr { row: 0, col });
-        if let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) {
-            if style_id == 0 {
-                sheet_state.dc_col_style_ids.remove(&col);
-            } else {
-                sheet_state.dc_col_style_ids.insert(col, style_id);
+        let mut cell_writes: Vec<(CellRef, u32)> = Vec::new();
+        {
+            let styles = &mut self.workbook.styles;
+            let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) else {
+                return Ok(());
+            };
+            let mut remap = |style_id: Option<u32>| {
+                Some(map_style(styles, style_id.unwrap_or(0))).filter(|id| *id != 0)
+            };
+
+            if whole_rows && whole_cols {
+                sheet_state.default_style_id = remap(sheet_state.default_style_id);
+                for props in sheet_state.col_properties.values_mut() {
+                    if props.style_id.is_some_and(|id| id != 0) {
+                        props.style_id = remap(props.style_id);
+                    }
+                }
+                for props in sheet_state.row_properties.values_mut() {
+                    if props.style_id.is_some_and(|id| id != 0) {
+                        props.style_id = remap(props.style_id);
+                    }
+                }
+            } else if whole_rows {
+                for row in range.start.row..=range.end.row {
+                    let props = sheet_state.row_properties.entry(row).or_default();
+                    props.style_id = remap(props.style_id);
+                }
+            } else if whole_cols {
+                for col in range.start.col..=range.end.col {
+                    let props = sheet_state.col_properties.entry(col).or_default();
+                    props.style_id = remap(props.style_id);
+                }
+                // Row styles outrank column styles, so styled rows take the update per cell.
+                for (&row, props) in &sheet_state.row_properties {
+                    if props.style_id.is_none_or(|id| id == 0) {
+                        continue;
+                    }
+                    for col in range.start.col..=range.end.col {
+                        let style_id = sheet_state
+                            .cells
+                            .get(&CellAddr { row, col })
+                            .map_or(0, |cell| cell.style_id);
+                        cell_writes
+                            .push((CellRef::new(row, col), remap(Some(style_id)).unwrap_or(0)));
+                    }
+                }
+            }
+            // Keep the maps sparse when a remapped style falls back to the default.
+            sheet_state
+                .row_properties
+                .retain(|_, props| *props != RowProperties::default());
+            sheet_state
+                .col_properties
+                .retain(|_, props| *props != ColProperties::default());
+
+            // Format runs outrank row/col styles; for plain blocks they are also the base layer.
+            let fill_gaps = !whole_rows && !whole_cols;
+            let cols: Vec<u32> = if fill_gaps {
+                (range.start.col..=range.end.col).collect()
+            } else {
+                sheet_state
+                    .format_runs_by_col
+                    .range(range.start.col..=range.end.col)
+                    .map(|(col, _)| *col)
+                    .collect()
+            };
+            for col in cols {
+                let runs = sheet_state
+                    .format_runs_by_col
+                    .remove(&col)
+                    .unwrap_or_default();
+                let runs = crate::metadata::restyle_runs_in_rows(
+                    &runs,
+                    range.start.row,
+                    range.end.row + 1,
+                    fill_gaps,
+                    |style_id| remap(Some(style_id)).unwrap_or(0),
+                );
+                if !runs.is_empty() {
+                    sheet_state.format_runs_by_col.insert(col, runs);
+                }
+            }
+
+            for (addr, cell) in &sheet_state.cells {
+                let cell_ref = CellRef::new(addr.row, addr.col);
+                if cell.style_id != 0 && range.contains(cell_ref) {
+                    cell_writes.push((cell_ref, remap(Some(cell.style_id)).unwrap_or(0)));
+                }
+            }
+        }
+
+        if !self.calc_settings.full_precision {
+            self.mark_all_compiled_cells_dirty();
+        }
+        // Apply the cell layer without an intermediate recalc, then recalc once for all layers.
+        let mode = self.calc_settings.calculation_mode;
+        self.calc_settings.calculation_mode = CalculationMode::Manual;
+        let result = self.set_cell_style_ids(sheet, &cell_writes);
+        self.calc_settings.calculation_mode = mode;
+        result?;
+        if mode != CalculationMode::Manual {
+            self.recalculate();
+        }
+        Ok(())
+    }
+
+    /// Clear the explicit per-cell number format (see [`Engine::set_cell_number_format`]) of every
+    /// cell in `range`.
+    pub fn clear_range_cell_number_formats(
+        &mut self,
+        sheet: &str,
+        range: Range,
+    ) -> Result<(), EngineError> {
+        let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
+            return Ok(());
+        };
+        let Some(sheet_state) = self.workbook.sheets.get(sheet_id) else {
+            return Ok(());
+        };
+        let addrs: Vec<CellAddr> = sheet_state
+            .cells
+            .iter()
+            .filter(|(addr, cell)| {
+                cell.number_format.is_some() && range.contains(CellRef::new(addr.row, addr.col))
+            })
+            .map(|(addr, _)| *addr)
+            .collect();
+        for addr in addrs {
+            self.set_cell_number_format(
+                sheet,
+                &formula_model::cell_to_a1(addr.row, addr.col),
+                None,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Set the default style id for a sheet (DocumentController sheet formatting layer).
+    pub fn set_sheet_default_patch_style_id(&mut self, sheet: &str, style_id: u32) {
+        let sheet_id = self.workbook.ensure_sheet(sheet);
+        if let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) {
+            sheet_state.dc_default_style_id = style_id;
+        }
+        self.mark_all_compiled_cells_dirty();
+        if self.calc_settings.calculation_mode != CalculationMode::Manual {
+            self.recalculate();
+        }
+    }
+
+    /// Set the style id for a row (DocumentController row formatting layer).
+    pub fn set_row_patch_style_id(&mut self, sheet: &str, row: u32, style_id: u32) {
+        let sheet_id = self.workbook.ensure_sheet(sheet);
+        // Ensure the row is in-bounds (growing dimensions if needed).
+        let _ = self
+            .workbook
+            .grow_sheet_dimensions(sheet_id, CellAddr { row, col: 0 });
+        if let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) {
+            if style_id == 0 {
+                sheet_state.dc_row_style_ids.remove(&row);
+            } else {
+                sheet_state.dc_row_style_ids.insert(row, style_id);
+            }
+        }
+        self.mark_all_compiled_cells_dirty();
+        if self.calc_settings.calculation_mode != CalculationMode::Manual {
+            self.recalculate();
+        }
+    }
+
+    /// Set the style id for a column (DocumentController column formatting layer).
+    pub fn set_col_patch_style_id(&mut self, sheet: &str, col: u32, style_id: u32) {
+        let sheet_id = self.workbook.ensure_sheet(sheet);
+        // Ensure the column is in-bounds (growing dimensions if needed).
+        let _ = self
+            .workbook
+            .grow_sheet_dimensions(sheet_id, CellAddr { row: 0, col });
+        if let Some(sheet_state) = self.workbook.sheets.get_mut(sheet_id) {
+            if style_id == 0 {
+                sheet_state.dc_col_style_ids.remove(&col);
+            } else {
+                sheet_state.dc_col_style_ids.insert(col, style_id);
             }
         }
         self.mark_all_compiled_cells_dirty();
@@ -3081,7 +3691,7 @@ impl Engine {
         style_id: u32,
     ) -> Result<(), EngineError> {
         let sheet_id = self.workbook.ensure_sheet(sheet);
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         if addr.row >= i32::MAX as u32 {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::RowOutOfRange,
@@ -3107,18 +3717,158 @@ impl Engine {
 
     /// Convenience: compute the effective style values for a cell using the engine's current
     /// style table + formatting layers.
+    ///
+    /// Patch formatting layers take precedence; cells without any fall back to the workbook
+    /// style layers (matching `CELL("format")` / `CELL("protect")`).
     pub fn effective_cell_style(
         &self,
         sheet: &str,
         addr: &str,
     ) -> Option<crate::style_patch::EffectiveStyle> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         let sheet_state = self.workbook.sheets.get(sheet_id)?;
         if addr.row >= sheet_state.row_count || addr.col >= sheet_state.col_count {
             return None;
         }
+        Some(self.resolve_effective_style(sheet_id, addr, true))
+    }
+
+    /// First cell in `range` whose effective style is locked (`Protection.locked`, the Excel
+    /// default), scanning row-major, or `None` if every cell is unlocked. Cells outside the sheet
+    /// (or on a missing sheet) count as locked.
+    ///
+    /// Rather than resolving every cell, this resolves one cell per run of rows that share the
+    /// same sheet/col/row/range-run layers, plus each stored cell in the range, so whole-column or
+    /// whole-row ranges stay cheap.
+    pub fn first_locked_cell(&self, sheet: &str, range: Range) -> Option<CellRef> {
+        let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
+            return Some(range.start);
+        };
+        let sheet_state = self.workbook.sheets.get(sheet_id)?;
+        let mut locked: Option<CellAddr> = None;
+        let mut note = |addr: CellAddr| {
+            if locked.is_none_or(|first| (addr.row, addr.col) < (first.row, first.col)) {
+                locked = Some(addr);
+            }
+        };
+
+        // Cells past the sheet's dimensions: the first overflowing column on the first row, or
+        // the first overflowing row.
+        if range.end.col >= sheet_state.col_count {
+            note(CellAddr {
+                row: range.start.row,
+                col: range.start.col.max(sheet_state.col_count),
+            });
+        }
+        if range.end.row >= sheet_state.row_count {
+            note(CellAddr {
+                row: range.start.row.max(sheet_state.row_count),
+                col: range.start.col,
+            });
+        }
+        if range.start.row >= sheet_state.row_count || range.start.col >= sheet_state.col_count {
+            return locked.map(|addr| CellRef::new(addr.row, addr.col));
+        }
+        let range = Range::new(
+            range.start,
+            CellRef::new(
+                range.end.row.min(sheet_state.row_count - 1),
+                range.end.col.min(sheet_state.col_count - 1),
+            ),
+        );
+        let in_range = |addr: &CellAddr| {
+            (range.start.row..=range.end.row).contains(&addr.row)
+                && (range.start.col..=range.end.col).contains(&addr.col)
+        };
+        let stored = |addr: &CellAddr| {
+            sheet_state.cells.contains_key(addr) || sheet_state.dc_cell_style_ids.contains_key(addr)
+        };
+
+        // Rows whose own style can differ from their neighbours'.
+        let mut styled_rows: Vec<u32> = sheet_state
+            .row_properties
+            .range(range.start.row..=range.end.row)
+            .filter(|(_, props)| props.style_id.is_some_and(|id| id != 0))
+            .map(|(&row, _)| row)
+            .chain(
+                sheet_state
+                    .dc_row_style_ids
+                    .iter()
+                    .filter(|(row, &id)| id != 0 && (range.start.row..=range.end.row).contains(row))
+                    .map(|(&row, _)| row),
+            )
+            .collect();
+        styled_rows.sort_unstable();
+        styled_rows.dedup();
+
+        for col in range.start.col..=range.end.col {
+            // Segment starts: the range start, each styled row and the row after it, and every
+            // format-run boundary in this column.
+            let mut starts: Vec<u32> = vec![range.start.row];
+            for &row in &styled_rows {
+                starts.push(row);
+                starts.push(row.saturating_add(1));
+            }
+            let run_bounds = sheet_state
+                .format_runs_by_col
+                .get(&col)
+                .into_iter()
+                .flatten()
+                .flat_map(|run| [run.start_row, run.end_row_exclusive])
+                .chain(
+                    sheet_state
+                        .dc_format_runs_by_col
+                        .get(&col)
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|run| [run.start_row, run.end_row_exclusive]),
+                );
+            starts.extend(run_bounds);
+            starts.retain(|row| (range.start.row..=range.end.row).contains(row));
+            starts.sort_unstable();
+            starts.dedup();
+
+            for (idx, &start) in starts.iter().enumerate() {
+                let end = starts.get(idx + 1).map_or(range.end.row, |next| next - 1);
+                if !self
+                    .resolve_effective_style(sheet_id, CellAddr { row: start, col }, false)
+                    .locked
+                {
+                    continue;
+                }
+                // Stored cells are resolved below; report the first unstored cell here.
+                if let Some(row) = (start..=end).find(|&row| !stored(&CellAddr { row, col })) {
+                    note(CellAddr { row, col });
+                    break;
+                }
+            }
+        }
 
+        // Stored cells carry their own style layer.
+        for addr in sheet_state
+            .cells
+            .keys()
+            .chain(sheet_state.dc_cell_style_ids.keys())
+            .filter(|addr| in_range(addr))
+        {
+            if self.resolve_effective_style(sheet_id, *addr, true).locked {
+                note(*addr);
+            }
+        }
+
+        locked.map(|addr| CellRef::new(addr.row, addr.col))
+    }
+
+    /// Effective style at `addr` on an existing sheet. With `include_cell` false, the cell's own
+    /// style layer is ignored, giving the style of an unstyled cell at that position.
+    fn resolve_effective_style(
+        &self,
+        sheet_id: SheetId,
+        addr: CellAddr,
+        include_cell: bool,
+    ) -> crate::style_patch::EffectiveStyle {
+        let sheet_state = &self.workbook.sheets[sheet_id];
         let col_style = sheet_state
             .dc_col_style_ids
             .get(&addr.col)
@@ -3133,6 +3883,7 @@ impl Engine {
             .dc_cell_style_ids
             .get(&addr)
             .copied()
+            .filter(|_| include_cell)
             .unwrap_or(0);
         let range_run_style = sheet_state
             .dc_format_runs_by_col
@@ -3152,19 +3903,74 @@ impl Engine {
             cell: cell_style,
         };
 
-        Some(crate::style_patch::resolve_effective_style(
-            &self.style_table,
-            layers,
-        ))
+        if layers.in_precedence_order().into_iter().any(|id| id != 0) {
+            return crate::style_patch::resolve_effective_style(&self.style_table, layers);
+        }
+
+        // Without patch formatting, resolve the workbook style layers:
+        // sheet < col < row < range-run < cell.
+        let key = CellKey {
+            sheet: sheet_id,
+            addr,
+        };
+        let model_layers = [
+            sheet_state.default_style_id.unwrap_or(0),
+            sheet_state
+                .col_properties
+                .get(&addr.col)
+                .and_then(|props| props.style_id)
+                .unwrap_or(0),
+            sheet_state
+                .row_properties
+                .get(&addr.row)
+                .and_then(|props| props.style_id)
+                .unwrap_or(0),
+            style_id_for_row_in_runs(
+                sheet_state
+                    .format_runs_by_col
+                    .get(&addr.col)
+                    .map(|runs| runs.as_slice()),
+                addr.row,
+            ),
+            self.workbook
+                .get_cell(key)
+                .filter(|_| include_cell)
+                .map_or(0, |cell| cell.style_id),
+        ];
+        let mut effective = crate::style_patch::EffectiveStyle::default();
+        for style in model_layers
+            .into_iter()
+            .filter(|&id| id != 0)
+            .filter_map(|id| self.workbook.styles.get(id))
+        {
+            if let Some(fmt) = style.number_format.as_deref() {
+                effective.number_format = Some(fmt.to_string());
+            }
+            if let Some(horizontal) = style.alignment.as_ref().and_then(|a| a.horizontal) {
+                effective.alignment_horizontal = Some(horizontal);
+            }
+            if let Some(protection) = style.protection.as_ref() {
+                effective.locked = protection.locked;
+            }
+        }
+        effective
     }
 
     pub fn set_calc_settings(&mut self, settings: CalcSettings) {
+        let precision_changed = self.calc_settings.full_precision != settings.full_precision;
         self.calc_settings = settings;
+        if precision_changed {
+            // Formula results are rounded to their displayed precision when `full_precision` is
+            // off, so cached results are stale once the mode flips. Constants are only rounded
+            // when entered, matching Excel's "data will permanently lose accuracy" behavior.
+            self.mark_all_compiled_cells_dirty();
+        }
     }
 
     fn fmt_date_system(&self) -> FmtDateSystem {
         match self.date_system {
             ExcelDateSystem::Excel1900 { .. } => FmtDateSystem::Excel1900,
+            ExcelDateSystem::Excel1900Proleptic => FmtDateSystem::Excel1900Proleptic,
             ExcelDateSystem::Excel1904 => FmtDateSystem::Excel1904,
         }
     }
@@ -3309,6 +4115,91 @@ impl Engine {
         None
     }
 
+    /// Describe how a cell's style is assembled from the formatting layers.
+    ///
+    /// Returns the layers with a non-default style id, lowest precedence first
+    /// (`sheet < col < row < range-run < cell`), along with the properties each one sets and the
+    /// ones it still supplies after higher layers are applied. Spilled outputs report their spill
+    /// origin's layers. An explicit per-cell number format ([`Engine::set_cell_number_format`])
+    /// is not a style layer and is not listed; it wins over every layer.
+    pub fn describe_style_resolution(
+        &self,
+        sheet: &str,
+        addr: &str,
+    ) -> Result<Vec<StyleLayerContribution>, EngineError> {
+        let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
+            return Ok(Vec::new());
+        };
+        let key = CellKey {
+            sheet: sheet_id,
+            addr: self.parse_address(addr)?,
+        };
+        let key = self.spill_origin_key(key).unwrap_or(key);
+        let Some(sheet_state) = self.workbook.sheets.get(key.sheet) else {
+            return Ok(Vec::new());
+        };
+
+        let layers = [
+            (StyleLayer::Sheet, sheet_state.default_style_id.unwrap_or(0)),
+            (
+                StyleLayer::Col,
+                sheet_state
+                    .col_properties
+                    .get(&key.addr.col)
+                    .and_then(|props| props.style_id)
+                    .unwrap_or(0),
+            ),
+            (
+                StyleLayer::Row,
+                sheet_state
+                    .row_properties
+                    .get(&key.addr.row)
+                    .and_then(|props| props.style_id)
+                    .unwrap_or(0),
+            ),
+            (
+                StyleLayer::RangeRun,
+                style_id_for_row_in_runs(
+                    sheet_state
+                        .format_runs_by_col
+                        .get(&key.addr.col)
+                        .map(|runs| runs.as_slice()),
+                    key.addr.row,
+                ),
+            ),
+            (
+                StyleLayer::Cell,
+                self.workbook
+                    .get_cell(key)
+                    .map(|cell| cell.style_id)
+                    .unwrap_or(0),
+            ),
+        ];
+
+        let mut out: Vec<StyleLayerContribution> = Vec::new();
+        for (layer, style_id) in layers {
+            if style_id == 0 {
+                continue;
+            }
+            let properties = self
+                .workbook
+                .styles
+                .get(style_id)
+                .map(style_properties)
+                .unwrap_or_default();
+            for lower in &mut out {
+                lower.supplied.retain(|prop| !properties.contains(prop));
+            }
+            out.push(StyleLayerContribution {
+                layer,
+                style_id,
+                supplied: properties.clone(),
+                properties,
+            });
+        }
+        Ok(out)
+    }
+
     fn round_number_as_displayed(&self, number: f64, format_pattern: Option<&str>) -> f64 {
         if self.calc_settings.full_precision {
             return number;
@@ -3324,9 +4215,15 @@ impl Engine {
         //
         // If the formatted string cannot be parsed back into a number (e.g. date/time formats or
         // patterns with non-numeric literal text), we fall back to storing the full-precision value.
+        //
+        // Formatting goes through the same helper as [`Engine::cell_display_text`], so stored
+        // values always agree with the text a user sees.
         let options = self.fmt_options();
-        let fmt_value = FmtValue::Number(number);
-        let formatted = formula_format::format_value(fmt_value, format_pattern, &options);
+        let formatted = crate::display::format_value_for_display(
+            &Value::Number(number),
+            format_pattern,
+            &options,
+        );
         match crate::coercion::number::parse_number_strict(
             &formatted.text,
             options.locale.decimal_sep,
@@ -3352,6 +4249,20 @@ impl Engine {
         }
     }
 
+    /// Locale used to render error values in [`Engine::cell_display_text`].
+    pub fn display_locale(&self) -> &'static FormulaLocale {
+        self.display_locale
+    }
+
+    /// Render error values in display text using `locale`'s error literals (e.g. `#BEZUG!` for
+    /// `#REF!` in `de-DE`).
+    ///
+    /// This only affects display paths; cell values, formulas and function results keep the
+    /// canonical (en-US) error codes, so no recalculation is needed.
+    pub fn set_display_locale(&mut self, locale: &'static FormulaLocale) {
+        self.display_locale = locale;
+    }
+
     /// Configure an [`ExternalValueProvider`].
     ///
     /// This provider is used both for:
@@ -3783,6 +4694,17 @@ impl Engine {
         self.date_system
     }
 
+    /// Switch the 1900 date system between Excel's calendar (with the fictitious `1900-02-29`)
+    /// and the proleptic Gregorian one. Defaults to [`DateCompatibility::ExcelBug`]; has no effect
+    /// on the 1904 date system.
+    pub fn set_date_compatibility(&mut self, compatibility: DateCompatibility) {
+        self.set_date_system(self.date_system.with_compatibility(compatibility));
+    }
+
+    pub fn date_compatibility(&self) -> DateCompatibility {
+        self.date_system.compatibility()
+    }
+
     pub fn set_value_locale(&mut self, value_locale: ValueLocaleConfig) {
         if self.value_locale == value_locale {
             return;
@@ -3865,7 +4787,7 @@ impl Engine {
         format_pattern: Option<String>,
     ) -> Result<(), EngineError> {
         let sheet_id = self.workbook.ensure_sheet(sheet);
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
 
         let format_pattern =
             format_pattern.and_then(|s| if s.trim().is_empty() { None } else { Some(s) });
@@ -3956,7 +4878,7 @@ impl Engine {
         let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
             return Ok(None);
         };
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         if let Some(sheet_state) = self.workbook.sheets.get(sheet_id) {
             if addr.row >= sheet_state.row_count || addr.col >= sheet_state.col_count {
                 return Ok(None);
@@ -3972,6 +4894,53 @@ impl Engine {
             .and_then(|cell| cell.number_format.clone()))
     }
 
+    /// Get the number format pattern that applies to a cell after resolving its explicit override,
+    /// spill origin, and layered style ids (sheet < col < row < range-run < cell).
+    ///
+    /// Returns `Ok(None)` when no layer specifies a number format (Excel `"General"`).
+    pub fn effective_cell_number_format(
+        &self,
+        sheet: &str,
+        addr: &str,
+    ) -> Result<Option<String>, EngineError> {
+        let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
+            return Ok(None);
+        };
+        let addr = self.parse_address(addr)?;
+        Ok(self
+            .number_format_pattern_for_rounding(CellKey {
+                sheet: sheet_id,
+                addr,
+            })
+            .map(|fmt| fmt.to_string()))
+    }
+
+    /// Render a cell's value as displayed, using its effective number format (see
+    /// [`Engine::effective_cell_number_format`]). Error values use the
+    /// [display locale](Engine::set_display_locale)'s spelling.
+    ///
+    /// In "precision as displayed" mode (`full_precision = false`), numeric values are stored
+    /// rounded to exactly this text.
+    pub fn cell_display_text(&self, sheet: &str, addr: &str) -> Result<String, EngineError> {
+        let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
+            return Ok(String::new());
+        };
+        let value = self.get_cell_value(sheet, addr);
+        let key = CellKey {
+            sheet: sheet_id,
+            addr: self.parse_address(addr)?,
+        };
+        let format_pattern = self.number_format_pattern_for_rounding(key);
+        let options = self.fmt_options();
+        Ok(crate::display::format_value_for_display_in_locale(
+            &value,
+            format_pattern,
+            &options,
+            self.display_locale,
+        )
+        .text)
+    }
+
     pub fn set_cell_value(
         &mut self,
         sheet: &str,
@@ -3979,7 +4948,7 @@ impl Engine {
         value: impl Into<Value>,
     ) -> Result<(), EngineError> {
         let sheet_id = self.workbook.ensure_sheet(sheet);
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         // The engine supports rows beyond Excel's default 1,048,576 limit, but some internal
         // evaluation paths (notably the bytecode engine and reference rewriting) use 32-bit
         // coordinates. Keep sheet growth bounded to `i32::MAX` rows so all row/offset conversions
@@ -4065,7 +5034,7 @@ impl Engine {
         let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
             return Ok(None);
         };
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         Ok(self
             .workbook
             .sheets
@@ -4084,7 +5053,7 @@ impl Engine {
         phonetic: Option<String>,
     ) -> Result<(), EngineError> {
         let sheet_id = self.workbook.ensure_sheet(sheet);
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         // Keep coordinates bounded so internal 32-bit conversions remain sound.
         if addr.row >= i32::MAX as u32 {
             return Err(EngineError::Address(
@@ -4185,13 +5154,13 @@ impl Engine {
 
         let sheet_id = self.workbook.ensure_sheet(sheet);
 
-        // Enforce Excel's fixed 16,384-column limit and the engine's i32 row bound.
+        // Enforce the workbook's column limit and the engine's i32 row bound.
         if range.end.row >= i32::MAX as u32 {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::RowOutOfRange,
             ));
         }
-        if range.end.col >= EXCEL_MAX_COLS {
+        if range.end.col >= self.workbook.grid_limits.max_cols {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::ColumnOutOfRange,
             ));
@@ -4430,7 +5399,7 @@ impl Engine {
     /// This is distinct from setting a cell to [`Value::Blank`], which behaves like Excel "clear
     /// contents" and preserves a cell's formatting (style id) when present.
     pub fn clear_cell(&mut self, sheet: &str, addr: &str) -> Result<(), EngineError> {
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
             return Ok(());
         };
@@ -4523,6 +5492,10 @@ impl Engine {
     /// Tables are needed to resolve structured references like `Table1[Col]` and `[@Col]`.
     pub fn set_sheet_tables(&mut self, sheet: &str, tables: Vec<Table>) {
         let sheet_id = self.workbook.ensure_sheet(sheet);
+        self.set_sheet_tables_by_id(sheet_id, tables);
+    }
+
+    fn set_sheet_tables_by_id(&mut self, sheet_id: SheetId, tables: Vec<Table>) {
         self.workbook.set_tables(sheet_id, tables);
 
         let mut tables_by_sheet: Vec<Vec<Table>> = Vec::new();
@@ -4800,14 +5773,132 @@ impl Engine {
         Ok(rewrites)
     }
 
-    /// Returns the current set of tables for `sheet`.
+    /// Add an Excel table (ListObject) to `sheet`.
     ///
-    /// This is primarily intended for inspection/testing (e.g. verifying structured reference
-    /// metadata). Callers should treat table definitions as immutable workbook metadata and use
-    /// [`Engine::set_sheet_tables`] to replace them.
-    pub fn get_sheet_tables(&self, sheet: &str) -> Option<&[Table]> {
-        let sheet_id = self.workbook.sheet_id(sheet)?;
-        let sheet = self.workbook.sheets.get(sheet_id)?;
+    /// The table name is validated like [`Engine::rename_table`] and must be unique
+    /// (case-insensitive) across the workbook. The table range may not overlap any other table on
+    /// the sheet. `table.id` is replaced with the next free workbook-wide table id.
+    pub fn add_table(&mut self, sheet: &str, mut table: Table) -> Result<(), TableError> {
+        let sheet_id = self
+            .workbook
+            .sheet_id(sheet)
+            .ok_or(TableError::SheetNotFound)?;
+
+        table.name = table.name.trim().to_string();
+        table.display_name = table.display_name.trim().to_string();
+        if table.display_name.is_empty() {
+            table.display_name = table.name.clone();
+        }
+        validate_table_name(&table.name)?;
+        validate_table_name(&table.display_name)?;
+        if table.range.height() < table.header_row_count + table.totals_row_count
+            || table.columns.len() != table.range.width() as usize
+        {
+            return Err(TableError::InvalidRange);
+        }
+
+        let mut next_id = 1u32;
+        for existing in self.workbook.sheets.iter().flat_map(|s| s.tables.iter()) {
+            for name in [&table.name, &table.display_name] {
+                if existing.name.eq_ignore_ascii_case(name)
+                    || existing.display_name.eq_ignore_ascii_case(name)
+                {
+                    return Err(TableError::DuplicateName);
+                }
+            }
+            next_id = next_id.max(existing.id.saturating_add(1));
+        }
+        // Tables and defined names share one namespace in formulas, whatever the name's scope.
+        let name_keys = [
+            normalize_defined_name(&table.name),
+            normalize_defined_name(&table.display_name),
+        ];
+        if self
+            .workbook
+            .names
+            .keys()
+            .chain(self.workbook.sheets.iter().flat_map(|s| s.names.keys()))
+            .any(|key| name_keys.contains(key))
+        {
+            return Err(TableError::ConflictsWithDefinedName);
+        }
+
+        let mut tables = self.workbook.sheets[sheet_id].tables.clone();
+        if tables.iter().any(|t| t.range.intersects(&table.range)) {
+            return Err(TableError::OverlapsExistingTable);
+        }
+        table.id = next_id;
+        tables.push(table);
+        self.set_sheet_tables_by_id(sheet_id, tables);
+        Ok(())
+    }
+
+    /// Resize an existing table to `new_range` (Excel's "Resize Table").
+    ///
+    /// The header row must stay on the same row and the new range may not overlap another table
+    /// on the same sheet. Columns are added (with default `ColumnN` names) or truncated to match
+    /// the new width.
+    pub fn resize_table(&mut self, name: &str, new_range: Range) -> Result<(), TableError> {
+        let (sheet_id, table_idx) = self.find_table_index(name)?;
+        let mut tables = self.workbook.sheets[sheet_id].tables.clone();
+
+        let table = &mut tables[table_idx];
+        if table.header_row_count > 0 && new_range.start.row != table.range.start.row {
+            return Err(TableError::InvalidRange);
+        }
+        table.set_range(new_range)?;
+
+        if tables
+            .iter()
+            .enumerate()
+            .any(|(idx, t)| idx != table_idx && t.range.intersects(&new_range))
+        {
+            return Err(TableError::OverlapsExistingTable);
+        }
+        self.set_sheet_tables_by_id(sheet_id, tables);
+        Ok(())
+    }
+
+    /// Delete a table, returning its definition.
+    ///
+    /// Cell contents are left in place; structured references to the deleted table no longer
+    /// resolve and evaluate to `#NAME?`.
+    pub fn delete_table(&mut self, name: &str) -> Result<Table, TableError> {
+        let (sheet_id, table_idx) = self.find_table_index(name)?;
+        let mut tables = self.workbook.sheets[sheet_id].tables.clone();
+        let removed = tables.remove(table_idx);
+        self.set_sheet_tables_by_id(sheet_id, tables);
+        Ok(removed)
+    }
+
+    fn find_table_index(&self, name: &str) -> Result<(SheetId, usize), TableError> {
+        let name = name.trim();
+        self.workbook
+            .sheets
+            .iter()
+            .enumerate()
+            .filter(|(sheet_id, _)| self.workbook.sheet_exists(*sheet_id))
+            .find_map(|(sheet_id, sheet)| {
+                sheet
+                    .tables
+                    .iter()
+                    .position(|t| {
+                        t.name.eq_ignore_ascii_case(name)
+                            || t.display_name.eq_ignore_ascii_case(name)
+                    })
+                    .map(|idx| (sheet_id, idx))
+            })
+            .ok_or(TableError::TableNotFound)
+    }
+
+    /// Returns the current set of tables for `sheet`.
+    ///
+    /// This is primarily intended for inspection/testing (e.g. verifying structured reference
+    /// metadata). Callers should treat table definitions as immutable workbook metadata and use
+    /// [`Engine::set_sheet_tables`] to replace them.
+    pub fn get_sheet_tables(&self, sheet: &str) -> Option<&[Table]> {
+        let sheet_id = self.workbook.sheet_id(sheet)?;
+        let sheet = self.workbook.sheets.get(sheet_id)?;
         Some(sheet.tables.as_slice())
     }
 
@@ -5033,6 +6124,14 @@ impl Engine {
         if name.is_empty() {
             return Ok(());
         }
+        // Excel's built-in print names only have meaning per sheet (`localSheetId` in XLSX).
+        if let (Some(builtin), NameScope::Workbook) =
+            (print_names::BuiltinPrintName::from_name(name), scope)
+        {
+            return Err(EngineError::BuiltinNameRequiresSheetScope(
+                builtin.canonical_name(),
+            ));
+        }
         let name_key = match crate::value::try_casefold(name) {
             Ok(key) => key,
             Err(_) => {
@@ -5115,6 +6214,150 @@ impl Engine {
         })
     }
 
+    /// Rename a defined name and rewrite every formula that refers to it (Excel-like).
+    ///
+    /// Cell formulas, table column formulas and other name definitions are updated. References
+    /// that resolve to a different definition are left alone: a sheet-scoped name shadowing a
+    /// renamed workbook name, or a LET/LAMBDA local with the same spelling.
+    pub fn rename_name(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+        scope: NameScope<'_>,
+    ) -> Result<(), NameRenameError> {
+        let old_name = old_name.trim();
+        let new_name = new_name.trim();
+        formula_model::validate_defined_name(new_name)?;
+
+        let scope_sheet = match scope {
+            NameScope::Workbook => None,
+            NameScope::Sheet(sheet) => Some(
+                self.workbook
+                    .sheet_id(sheet)
+                    .ok_or(NameRenameError::NameNotFound)?,
+            ),
+        };
+        let old_key = normalize_defined_name(old_name);
+        let new_key = normalize_defined_name(new_name);
+        let scope_names = match scope_sheet {
+            None => &self.workbook.names,
+            Some(sheet_id) => &self.workbook.sheets[sheet_id].names,
+        };
+        if !scope_names.contains_key(&old_key) {
+            return Err(NameRenameError::NameNotFound);
+        }
+        if new_key != old_key && scope_names.contains_key(&new_key) {
+            return Err(NameRenameError::DuplicateName);
+        }
+
+        // A reference resolves against its qualifying sheet (or the formula's own sheet): that
+        // sheet's local names first, then workbook names.
+        let sheet_ids = self.workbook.sheet_ids_in_order().to_vec();
+        let shadowing_sheets: HashSet<SheetId> = sheet_ids
+            .iter()
+            .copied()
+            .filter(|&id| self.workbook.sheets[id].names.contains_key(&old_key))
+            .collect();
+        let targets = |ctx_sheet: Option<SheetId>| match scope_sheet {
+            None => ctx_sheet.is_none_or(|id| !shadowing_sheets.contains(&id)),
+            Some(scope_sheet) => ctx_sheet == Some(scope_sheet),
+        };
+        let sheet_aliases: Vec<(SheetId, String, Option<String>)> = sheet_ids
+            .iter()
+            .map(|&id| {
+                (
+                    id,
+                    self.workbook.sheet_name(id).unwrap_or_default().to_string(),
+                    self.workbook.sheet_key_name(id).map(str::to_string),
+                )
+            })
+            .collect();
+        let resolve_sheet = |name: &str| {
+            sheet_aliases
+                .iter()
+                .find(|(_, display, key)| {
+                    formula_model::sheet_name_eq_case_insensitive(display, name)
+                        || key.as_deref().is_some_and(|key| {
+                            formula_model::sheet_name_eq_case_insensitive(key, name)
+                        })
+                })
+                .map(|(id, _, _)| *id)
+        };
+        let rewrite = |formula: &str, origin: crate::CellAddr, own_sheet: Option<SheetId>| {
+            let (rewritten, changed) =
+                rewrite_formula_for_name_rename(formula, origin, old_name, new_name, |qualifier| {
+                    match qualifier {
+                        None => targets(own_sheet),
+                        Some(sheet) => resolve_sheet(sheet).is_some_and(|id| targets(Some(id))),
+                    }
+                });
+            changed.then_some(rewritten)
+        };
+
+        for &sheet_id in &sheet_ids {
+            let sheet = &mut self.workbook.sheets[sheet_id];
+            for (addr, cell) in sheet.cells.iter_mut() {
+                let Some(formula) = cell.formula.as_deref() else {
+                    continue;
+                };
+                let origin = crate::CellAddr::new(addr.row, addr.col);
+                if let Some(rewritten) = rewrite(formula, origin, Some(sheet_id)) {
+                    cell.formula = Some(rewritten.into());
+                }
+            }
+            for table in &mut sheet.tables {
+                for column in &mut table.columns {
+                    for formula in [column.formula.as_mut(), column.totals_formula.as_mut()]
+                        .into_iter()
+                        .flatten()
+                    {
+                        if let Some(rewritten) =
+                            rewrite(formula, crate::CellAddr::new(0, 0), Some(sheet_id))
+                        {
+                            *formula = rewritten;
+                        }
+                    }
+                }
+            }
+            for def in sheet.names.values_mut() {
+                if let NameDefinition::Reference(formula) | NameDefinition::Formula(formula) =
+                    &mut def.definition
+                {
+                    if let Some(rewritten) =
+                        rewrite(formula, crate::CellAddr::new(0, 0), Some(sheet_id))
+                    {
+                        *formula = rewritten;
+                    }
+                }
+            }
+        }
+        for def in self.workbook.names.values_mut() {
+            if let NameDefinition::Reference(formula) | NameDefinition::Formula(formula) =
+                &mut def.definition
+            {
+                if let Some(rewritten) = rewrite(formula, crate::CellAddr::new(0, 0), None) {
+                    *formula = rewritten;
+                }
+            }
+        }
+
+        let scope_names = match scope_sheet {
+            None => &mut self.workbook.names,
+            Some(sheet_id) => &mut self.workbook.sheets[sheet_id].names,
+        };
+        if let Some(def) = scope_names.remove(&old_key) {
+            scope_names.insert(new_key, def);
+        }
+
+        // Stored formula text changed, so recompile names and cells from it.
+        self.recompile_all_defined_names()?;
+        self.rebuild_graph()?;
+        if self.calc_settings.calculation_mode != CalculationMode::Manual {
+            self.recalculate();
+        }
+        Ok(())
+    }
+
     pub fn get_name(&self, name: &str, scope: NameScope<'_>) -> Option<&NameDefinition> {
         let name = name.trim();
         if name.is_empty() {
@@ -5142,7 +6385,7 @@ impl Engine {
         formula: &str,
     ) -> Result<(), EngineError> {
         let sheet_id = self.workbook.ensure_sheet(sheet);
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         if addr.row >= i32::MAX as u32 {
             return Err(EngineError::Address(
                 crate::eval::AddressParseError::RowOutOfRange,
@@ -5170,6 +6413,28 @@ impl Engine {
                 normalize_relative_to: Some(origin),
             },
         )?;
+        self.install_cell_formula(key, formula, &parsed.expr);
+
+        // Recalculate this cell and anything depending on it.
+        self.mark_dirty_including_self_with_reasons(key);
+        self.calc_graph.mark_dirty(cell_id);
+        self.mark_dirty_blocked_spill_origins_for_cell(key);
+        self.sync_dirty_from_calc_graph();
+        if self.calc_settings.calculation_mode != CalculationMode::Manual {
+            self.recalculate();
+        }
+        Ok(())
+    }
+
+    /// Compile an already-parsed canonical formula into `key`: lowers the AST, updates dependency
+    /// tracking and the per-cell analysis flags, and stores the formula text.
+    ///
+    /// Does not mark anything dirty or trigger recalculation.
+    fn install_cell_formula(&mut self, key: CellKey, formula: &str, expr: &crate::Expr) {
+        let sheet_id = key.sheet;
+        let addr = key.addr;
+        let cell_id = cell_id_from_key(key);
+
         let mut resolve_sheet = |name: &str| self.workbook.sheet_id(name);
         let mut sheet_dims = |sheet_id: usize| {
             self.workbook
@@ -5178,13 +6443,8 @@ impl Engine {
                 .map(|s| (s.row_count, s.col_count))
                 .unwrap_or((EXCEL_MAX_ROWS, EXCEL_MAX_COLS))
         };
-        let compiled = compile_canonical_expr(
-            &parsed.expr,
-            sheet_id,
-            addr,
-            &mut resolve_sheet,
-            &mut sheet_dims,
-        );
+        let compiled =
+            compile_canonical_expr(expr, sheet_id, addr, &mut resolve_sheet, &mut sheet_dims);
         let mut tables_by_sheet: Vec<Vec<Table>> = Vec::new();
         let _ = tables_by_sheet.try_reserve_exact(self.workbook.sheets.len());
         for s in self.workbook.sheets.iter() {
@@ -5232,7 +6492,7 @@ impl Engine {
         self.calc_graph.update_cell_dependencies(cell_id, deps);
 
         let (compiled_formula, bytecode_compile_reason) =
-            match self.try_compile_bytecode(&parsed.expr, key, thread_safe, dynamic_deps) {
+            match self.try_compile_bytecode(expr, key, thread_safe, dynamic_deps) {
                 Ok((program, depends_on_sheet_dims)) => (
                     CompiledFormula::Bytecode(BytecodeFormula {
                         ast: compiled.clone(),
@@ -5261,16 +6521,6 @@ impl Engine {
                 sheet_state.origin_dependents.remove(&addr);
             }
         }
-
-        // Recalculate this cell and anything depending on it.
-        self.mark_dirty_including_self_with_reasons(key);
-        self.calc_graph.mark_dirty(cell_id);
-        self.mark_dirty_blocked_spill_origins_for_cell(key);
-        self.sync_dirty_from_calc_graph();
-        if self.calc_settings.calculation_mode != CalculationMode::Manual {
-            self.recalculate();
-        }
-        Ok(())
     }
 
     /// Set a cell formula that was entered using a different reference style (e.g. R1C1).
@@ -5285,7 +6535,7 @@ impl Engine {
         formula: &str,
         mut opts: crate::ParseOptions,
     ) -> Result<(), EngineError> {
-        let origin_eval = parse_a1(addr)?;
+        let origin_eval = self.parse_address(addr)?;
         let origin = crate::CellAddr::new(origin_eval.row, origin_eval.col);
 
         // Normalize any relative A1 coordinates against the destination cell so the AST is
@@ -5366,9 +6616,133 @@ impl Engine {
         let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
             return Value::Blank;
         };
-        let Ok(addr) = parse_a1(addr) else {
+        let Ok(addr) = self.parse_address(addr) else {
             return Value::Error(ErrorKind::Ref);
         };
+        self.cell_value_at(sheet_id, addr)
+    }
+
+    /// Bulk-read an arbitrary list of cells (e.g. a non-contiguous multi-selection).
+    ///
+    /// Values are returned in the order of `cells`, with the same semantics as
+    /// [`Engine::get_cell_value`] for each entry. Unlike per-cell calls, the sheet is resolved once
+    /// and no A1 parsing is needed.
+    pub fn get_cells_values(&self, sheet: &str, cells: &[CellRef]) -> Vec<Value> {
+        let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
+            return vec![Value::Blank; cells.len()];
+        };
+        cells
+            .iter()
+            .map(|cell| {
+                self.cell_value_at(
+                    sheet_id,
+                    CellAddr {
+                        row: cell.row,
+                        col: cell.col,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Evaluate `targets` as if each override cell held the given value, then restore the
+    /// workbook.
+    ///
+    /// This is the primitive behind Goal Seek / Data Table style what-if analysis: the overrides
+    /// are written as constants (replacing any formula), the workbook is recalculated, the
+    /// targets are read, and the original inputs (formula or value plus phonetic metadata) are put
+    /// back before a final recalculation; cells that had no record (including spill outputs) are
+    /// cleared again rather than left as explicit blanks. Values are returned in the order of
+    /// `targets`, with the same semantics as [`Engine::get_cells_values`]. An unknown `sheet` is
+    /// rejected before anything is written; if an override cannot be applied, the workbook is
+    /// still restored before the error is returned.
+    ///
+    /// Both recalculations run regardless of the workbook's calculation mode, so volatile
+    /// functions and any edits still pending in manual mode are recomputed as well.
+    pub fn evaluate_with_overrides(
+        &mut self,
+        sheet: &str,
+        targets: &[CellRef],
+        overrides: &[(CellRef, Value)],
+    ) -> Result<Vec<Value>, EngineError> {
+        // Validate upfront so a bad override can't leave the workbook partially modified (or
+        // create the sheet as a side effect of writing to it).
+        let sheet_id = self
+            .workbook
+            .sheet_id(sheet)
+            .ok_or_else(|| EngineError::SheetNotFound(sheet.to_string()))?;
+        let limits = self.workbook.grid_limits;
+        for (cell, _) in overrides {
+            if cell.row >= i32::MAX as u32 || cell.row >= limits.max_rows {
+                return Err(EngineError::Address(
+                    crate::eval::AddressParseError::RowOutOfRange,
+                ));
+            }
+            if cell.col >= limits.max_cols {
+                return Err(EngineError::Address(
+                    crate::eval::AddressParseError::ColumnOutOfRange,
+                ));
+            }
+        }
+
+        // Snapshot the stored inputs, not the displayed values: spill outputs and empty cells
+        // have no record (`None`) and are cleared again so spills can re-form.
+        let saved: Vec<(String, Option<String>, Option<Value>, Option<String>)> = overrides
+            .iter()
+            .map(|(cell, _)| {
+                let addr = cell.to_a1();
+                let formula = self.get_cell_formula(sheet, &addr).map(str::to_string);
+                let value = self
+                    .workbook
+                    .get_cell(CellKey {
+                        sheet: sheet_id,
+                        addr: CellAddr {
+                            row: cell.row,
+                            col: cell.col,
+                        },
+                    })
+                    .map(|record| record.value.clone());
+                let phonetic = self.get_cell_phonetic(sheet, &addr).map(str::to_string);
+                (addr, formula, value, phonetic)
+            })
+            .collect();
+
+        // Suspend automatic recalculation so each write doesn't trigger a full recalc.
+        let mode = self.calc_settings.calculation_mode;
+        self.calc_settings.calculation_mode = CalculationMode::Manual;
+
+        let values = overrides
+            .iter()
+            .try_for_each(|(cell, value)| self.set_cell_value(sheet, &cell.to_a1(), value.clone()))
+            .map(|()| {
+                self.recalculate_single_threaded();
+                self.get_cells_values(sheet, targets)
+            });
+
+        // Restore even when an override failed, in reverse so the earliest snapshot wins when a
+        // cell is overridden twice. The first error is reported once everything is put back.
+        let mut restored = Ok(());
+        for (addr, formula, value, phonetic) in saved.into_iter().rev() {
+            let result = match (formula, value) {
+                (Some(formula), _) => self.set_cell_formula(sheet, &addr, &formula),
+                (None, Some(value)) => self.set_cell_value(sheet, &addr, value),
+                (None, None) => self.clear_cell(sheet, &addr),
+            }
+            .and_then(|()| match phonetic {
+                Some(_) => self.set_cell_phonetic(sheet, &addr, phonetic),
+                None => Ok(()),
+            });
+            restored = restored.and(result);
+        }
+
+        self.calc_settings.calculation_mode = mode;
+        self.recalculate_single_threaded();
+        let values = values?;
+        restored?;
+        Ok(values)
+    }
+
+    fn cell_value_at(&self, sheet_id: SheetId, addr: CellAddr) -> Value {
         if let Some(sheet) = self.workbook.sheets.get(sheet_id) {
             if addr.row >= sheet.row_count || addr.col >= sheet.col_count {
                 return Value::Error(ErrorKind::Ref);
@@ -5610,11 +6984,47 @@ impl Engine {
         Ok(out)
     }
 
+    /// Returns the bounding box `(top_left, bottom_right)` of every non-empty cell on `sheet`,
+    /// or `None` when the sheet has no content.
+    ///
+    /// A cell counts when it holds a formula or a non-blank value; spilled array results extend
+    /// the range even though their cells are not stored individually. Style-only cells are
+    /// ignored.
+    pub fn used_range(&self, sheet: &str) -> Option<(CellAddr, CellAddr)> {
+        let sheet_id = self.workbook.sheet_id(sheet)?;
+        let sheet_state = self.workbook.sheets.get(sheet_id)?;
+
+        let stored = sheet_state
+            .cells
+            .iter()
+            .filter(|(_, cell)| cell.formula.is_some() || !matches!(cell.value, Value::Blank))
+            .map(|(addr, _)| (*addr, *addr));
+        let spilled = self
+            .spills
+            .by_origin
+            .iter()
+            .filter(|(origin, _)| origin.sheet == sheet_id)
+            .map(|(origin, spill)| (origin.addr, spill.end));
+
+        stored.chain(spilled).reduce(|(start, end), (lo, hi)| {
+            (
+                CellAddr {
+                    row: start.row.min(lo.row),
+                    col: start.col.min(lo.col),
+                },
+                CellAddr {
+                    row: end.row.max(hi.row),
+                    col: end.col.max(hi.col),
+                },
+            )
+        })
+    }
+
     /// Returns the spill range (origin inclusive) for a cell if it is an array-spill
     /// origin or belongs to a spilled range.
     pub fn spill_range(&self, sheet: &str, addr: &str) -> Option<(CellAddr, CellAddr)> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         if let Some(sheet_state) = self.workbook.sheets.get(sheet_id) {
             if addr.row >= sheet_state.row_count || addr.col >= sheet_state.col_count {
                 return None;
@@ -5633,7 +7043,7 @@ impl Engine {
     /// to a spilled range.
     pub fn spill_origin(&self, sheet: &str, addr: &str) -> Option<(SheetId, CellAddr)> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         if let Some(sheet_state) = self.workbook.sheets.get(sheet_id) {
             if addr.row >= sheet_state.row_count || addr.col >= sheet_state.col_count {
                 return None;
@@ -5647,9 +7057,48 @@ impl Engine {
         Some((origin.sheet, origin.addr))
     }
 
+    /// Show `#SPILL!` on a formula cell whose spill was just cleared because content was written
+    /// into one of its output cells.
+    ///
+    /// Editing a spill output already marks the origin dirty, but in manual calculation mode the
+    /// origin keeps its old top-left value until the next recalculation. Hosts that batch edits
+    /// can call this after the write so the origin reflects the blockage immediately; the next
+    /// recalculation re-evaluates it and re-spills if the blocker is gone by then. Because the
+    /// value changes outside recalculation, it is not reported as a recalc value change.
+    ///
+    /// Returns `false` (and does nothing) if `origin` is not a formula cell or is still spilling.
+    pub fn mark_spill_origin_blocked(&mut self, sheet: &str, origin: &str) -> bool {
+        let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
+            return false;
+        };
+        let Ok(addr) = self.parse_address(origin) else {
+            return false;
+        };
+        let key = CellKey {
+            sheet: sheet_id,
+            addr,
+        };
+        if self.spills.by_origin.contains_key(&key) {
+            return false;
+        }
+        let Some(cell) = self
+            .workbook
+            .sheets
+            .get_mut(sheet_id)
+            .and_then(|sheet| sheet.cells.get_mut(&addr))
+            .filter(|cell| cell.formula.is_some())
+        else {
+            return false;
+        };
+        cell.value = Value::Error(ErrorKind::Spill);
+        self.calc_graph.mark_dirty(cell_id_from_key(key));
+        self.sync_dirty_from_calc_graph();
+        true
+    }
+
     pub fn get_cell_formula(&self, sheet: &str, addr: &str) -> Option<&str> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         let key = CellKey {
             sheet: sheet_id,
             addr,
@@ -5659,7 +7108,7 @@ impl Engine {
 
     pub fn get_cell_phonetic(&self, sheet: &str, addr: &str) -> Option<&str> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         let key = CellKey {
             sheet: sheet_id,
             addr,
@@ -5687,32 +7136,13 @@ impl Engine {
     /// syntax-only parser/serializer.
     pub fn get_cell_formula_r1c1(&self, sheet: &str, addr: &str) -> Option<String> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         let key = CellKey {
             sheet: sheet_id,
             addr,
         };
         let formula = self.workbook.get_cell(key)?.formula.as_deref()?;
-
-        let origin = crate::CellAddr::new(addr.row, addr.col);
-        let ast = crate::parse_formula(
-            formula,
-            crate::ParseOptions {
-                locale: crate::LocaleConfig::en_us(),
-                reference_style: crate::ReferenceStyle::A1,
-                normalize_relative_to: Some(origin),
-            },
-        )
-        .ok()?;
-
-        ast.to_string(crate::SerializeOptions {
-            locale: crate::LocaleConfig::en_us(),
-            reference_style: crate::ReferenceStyle::R1C1,
-            include_xlfn_prefix: true,
-            origin: Some(origin),
-            omit_equals: false,
-        })
-        .ok()
+        canonical_formula_to_r1c1(formula, addr)
     }
 
     /// Returns the formula for `addr` rendered in localized R1C1 reference style.
@@ -5735,9 +7165,12 @@ impl Engine {
         // We only need to snapshot the pivot registry if the edit can shift worksheet coordinates.
         // Avoid cloning on operations that don't touch pivot destinations (e.g. CopyRange/Fill).
         let mut pivot_registry_before: Option<crate::pivot_registry::PivotRegistry> = None;
-        let op_clone = op.clone();
+        // Pivot definitions replay the edit; data-dependent edits (text to columns) may add the
+        // structural shift they performed.
+        let mut pivot_ops = vec![op.clone()];
         let mut formula_rewrites = Vec::new();
         let mut moved_ranges = Vec::new();
+        let mut removed_rows = 0;
 
         let sheet_names = sheet_names_by_id(&self.workbook);
         // For pivot-definition structural edits, treat stable sheet keys and user-visible display
@@ -6100,40 +7533,163 @@ impl Engine {
                     to: dst,
                 });
             }
-            EditOp::CopyRange {
+            EditOp::CopyRange {
+                sheet,
+                src,
+                dst_top_left,
+            } => {
+                let sheet_id = self
+                    .workbook
+                    .sheet_id(&sheet)
+                    .ok_or_else(|| EditError::SheetNotFound(sheet.clone()))?;
+                edited_sheet_id = sheet_id;
+                if src.width() == 0 || src.height() == 0 {
+                    return Err(EditError::InvalidRange);
+                }
+                copy_range(
+                    &mut self.workbook.sheets[sheet_id],
+                    &sheet,
+                    src,
+                    dst_top_left,
+                    &mut formula_rewrites,
+                );
+            }
+            EditOp::Fill { sheet, src, dst } => {
+                let sheet_id = self
+                    .workbook
+                    .sheet_id(&sheet)
+                    .ok_or_else(|| EditError::SheetNotFound(sheet.clone()))?;
+                edited_sheet_id = sheet_id;
+                fill_range(
+                    &mut self.workbook.sheets[sheet_id],
+                    &sheet,
+                    src,
+                    dst,
+                    &mut formula_rewrites,
+                );
+            }
+            EditOp::TextToColumns {
                 sheet,
                 src,
-                dst_top_left,
+                split,
+                shift_right,
             } => {
+                if src.width() != 1 || src.height() == 0 {
+                    return Err(EditError::InvalidRange);
+                }
+                if let TextToColumnsSplit::FixedWidths(widths) = &split {
+                    if widths.is_empty() || widths.contains(&0) {
+                        return Err(EditError::InvalidCount);
+                    }
+                }
                 let sheet_id = self
                     .workbook
                     .sheet_id(&sheet)
                     .ok_or_else(|| EditError::SheetNotFound(sheet.clone()))?;
                 edited_sheet_id = sheet_id;
-                if src.width() == 0 || src.height() == 0 {
+                let rows = text_to_columns_pieces(&self.workbook.sheets[sheet_id], src, &split);
+                let extra = rows
+                    .iter()
+                    .map(|(_, pieces)| pieces.len().saturating_sub(1))
+                    .max()
+                    .unwrap_or(0) as u32;
+                if shift_right && extra > 0 {
+                    let range = Range::new(
+                        CellRef::new(src.start.row, src.start.col + 1),
+                        CellRef::new(src.end.row, src.start.col + extra),
+                    );
+                    insert_cells_shift_right(&mut self.workbook.sheets[sheet_id], range, extra);
+                    let edit = RangeMapEdit {
+                        sheet: sheet.clone(),
+                        moved_region: GridRange::new(
+                            range.start.row,
+                            range.start.col,
+                            range.end.row,
+                            u32::MAX,
+                        ),
+                        delta_row: 0,
+                        delta_col: extra as i32,
+                        deleted_region: None,
+                    };
+                    self.rewrite_defined_names_range_map(&sheet_names, &edit)
+                        .map_err(|e| EditError::Engine(e.to_string()))?;
+                    if pivot_registry_before.is_none() {
+                        pivot_registry_before = Some(self.pivot_registry.clone());
+                    }
+                    self.pivot_registry.apply_range_map_edit_with_sheet_id(
+                        sheet_id,
+                        &edit,
+                        &sheet_names,
+                    );
+                    formula_rewrites.extend(rewrite_all_formulas_range_map(
+                        &mut self.workbook,
+                        &sheet_names,
+                        &edit,
+                    ));
+                    pivot_ops.insert(0, EditOp::InsertCellsShiftRight { sheet, range });
+                }
+                write_text_to_columns(
+                    &mut self.workbook.sheets[sheet_id],
+                    src.start.col,
+                    rows,
+                    self.value_locale,
+                    self.date_system,
+                );
+            }
+            EditOp::RemoveDuplicates {
+                sheet,
+                range,
+                key_columns,
+            } => {
+                if key_columns.is_empty() {
+                    return Err(EditError::InvalidCount);
+                }
+                if key_columns
+                    .iter()
+                    .any(|col| *col < range.start.col || *col > range.end.col)
+                {
                     return Err(EditError::InvalidRange);
                 }
-                copy_range(
+                let sheet_id = self
+                    .workbook
+                    .sheet_id(&sheet)
+                    .ok_or_else(|| EditError::SheetNotFound(sheet.clone()))?;
+                edited_sheet_id = sheet_id;
+                removed_rows = remove_duplicate_rows(
                     &mut self.workbook.sheets[sheet_id],
                     &sheet,
-                    src,
-                    dst_top_left,
+                    range,
+                    &key_columns,
                     &mut formula_rewrites,
                 );
             }
-            EditOp::Fill { sheet, src, dst } => {
+            EditOp::SortRange {
+                sheet,
+                range,
+                keys,
+                has_header,
+            } => {
+                if keys.is_empty() {
+                    return Err(EditError::InvalidCount);
+                }
+                if keys.iter().any(|key| key.column >= range.width() as usize) {
+                    return Err(EditError::InvalidRange);
+                }
                 let sheet_id = self
                     .workbook
                     .sheet_id(&sheet)
                     .ok_or_else(|| EditError::SheetNotFound(sheet.clone()))?;
                 edited_sheet_id = sheet_id;
-                fill_range(
+                sort_range_rows(
                     &mut self.workbook.sheets[sheet_id],
                     &sheet,
-                    src,
-                    dst,
+                    range,
+                    &keys,
+                    usize::from(has_header),
+                    self.value_locale,
                     &mut formula_rewrites,
-                );
+                )
+                .map_err(|e| EditError::Engine(e.to_string()))?;
             }
         }
 
@@ -6142,7 +7698,9 @@ impl Engine {
             Workbook::with_sheet_key_lookup(name, |key| sheet_name_to_id.get(key).copied())
         };
         for pivot in self.workbook.pivots.values_mut() {
-            pivot.apply_edit_op_with_sheet_resolver(&op_clone, &mut resolve_sheet_id);
+            for op in &pivot_ops {
+                pivot.apply_edit_op_with_sheet_resolver(op, &mut resolve_sheet_id);
+            }
         }
 
         if let Err(err) = self.grow_sheet_dimensions_to_fit_cells(edited_sheet_id) {
@@ -6166,6 +7724,7 @@ impl Engine {
             changed_cells,
             moved_ranges,
             formula_rewrites,
+            removed_rows,
         })
     }
 
@@ -6186,9 +7745,9 @@ impl Engine {
             }
         };
 
-        // The engine enforces Excel's fixed 16,384-column grid. Editing operations should not be
-        // able to create cells beyond that bound.
-        if max_col >= EXCEL_MAX_COLS {
+        // Editing operations should not be able to create cells beyond the workbook's column
+        // limit (Excel's fixed 16,384-column grid by default).
+        if max_col >= self.workbook.grid_limits.max_cols {
             return Err(EditError::Engine(
                 crate::eval::AddressParseError::ColumnOutOfRange.to_string(),
             ));
@@ -6253,6 +7812,27 @@ impl Engine {
         self.recalculate_with_value_changes(RecalcMode::MultiThreaded)
     }
 
+    /// Single-threaded recalculation that reports progress to `on_progress` as it goes.
+    ///
+    /// The callback runs synchronously on the recalculating thread, roughly every
+    /// [`RECALC_PROGRESS_INTERVAL`] evaluated cells, and once more at the end with
+    /// `done == total`. `total` may grow during the pass when spills or dynamic references dirty
+    /// additional cells.
+    pub fn recalculate_with_value_changes_and_progress(
+        &mut self,
+        on_progress: &mut dyn FnMut(RecalcProgress),
+    ) -> Vec<RecalcValueChange> {
+        let mut changes = RecalcValueChangeCollector::default();
+        let mut progress = RecalcProgressReporter::new(on_progress);
+        self.recalculate_with_mode_and_value_changes_inner(
+            RecalcMode::SingleThreaded,
+            Some(&mut changes),
+            Some(&mut progress),
+        );
+        progress.finish();
+        changes.into_sorted_changes(&self.workbook)
+    }
+
     fn recalculate_with_mode_and_value_changes(
         &mut self,
         mode: RecalcMode,
@@ -6263,7 +7843,11 @@ impl Engine {
             if mode == RecalcMode::MultiThreaded {
                 if let Some(pool) = recalc_thread_pool() {
                     pool.install(|| {
-                        self.recalculate_with_mode_and_value_changes_inner(mode, value_changes)
+                        self.recalculate_with_mode_and_value_changes_inner(
+                            mode,
+                            value_changes,
+                            None,
+                        )
                     });
                     return;
                 }
@@ -6273,18 +7857,20 @@ impl Engine {
                 self.recalculate_with_mode_and_value_changes_inner(
                     RecalcMode::SingleThreaded,
                     value_changes,
+                    None,
                 );
                 return;
             }
         }
 
-        self.recalculate_with_mode_and_value_changes_inner(mode, value_changes);
+        self.recalculate_with_mode_and_value_changes_inner(mode, value_changes, None);
     }
 
     fn recalculate_with_mode_and_value_changes_inner(
         &mut self,
         mode: RecalcMode,
         mut value_changes: Option<&mut RecalcValueChangeCollector>,
+        mut progress: Option<&mut RecalcProgressReporter<'_>>,
     ) {
         let date_system = self.date_system;
         // Spill recalculation can introduce new dirty cells (spill outputs becoming
@@ -6342,11 +7928,15 @@ impl Engine {
 
                 if !needs_levels {
                     let recalc_ctx = recalc_ctx.get_or_insert_with(|| self.begin_recalc_context());
+                    if let Some(progress) = progress.as_deref_mut() {
+                        progress.schedule(order.len());
+                    }
                     let (spill_dirty_roots, dynamic_dirty_roots) = self.recalculate_order(
                         order,
                         recalc_ctx,
                         date_system,
                         value_changes.as_deref_mut(),
+                        progress.as_deref_mut(),
                     );
                     if spill_dirty_roots.is_empty() && dynamic_dirty_roots.is_empty() {
                         return;
@@ -6370,15 +7960,19 @@ impl Engine {
             if levels.is_empty() {
                 return;
             }
+            if let Some(progress) = progress.as_deref_mut() {
+                progress.schedule(levels.iter().map(Vec::len).sum());
+            }
 
             let recalc_ctx = recalc_ctx.get_or_insert_with(|| self.begin_recalc_context());
 
-            let (spill_dirty_roots, dynamic_dirty_roots) = self.recalculate_levels(
+            let (spill_dirty_roots, dynamic_dirty_roots) = self.recalculate_levels_with_progress(
                 levels,
                 mode,
                 recalc_ctx,
                 date_system,
                 value_changes.as_deref_mut(),
+                progress.as_deref_mut(),
             );
             if spill_dirty_roots.is_empty() && dynamic_dirty_roots.is_empty() {
                 return;
@@ -6399,6 +7993,7 @@ impl Engine {
         recalc_ctx: &crate::eval::RecalcContext,
         date_system: ExcelDateSystem,
         mut value_changes: Option<&mut RecalcValueChangeCollector>,
+        mut progress: Option<&mut RecalcProgressReporter<'_>>,
     ) -> (Vec<CellId>, Vec<CellId>) {
         self.circular_references.clear();
         let value_locale = self.value_locale;
@@ -6443,6 +8038,9 @@ impl Engine {
 
         for cell_id in order {
             let key = cell_key_from_id(cell_id);
+            if let Some(progress) = progress.as_deref_mut() {
+                progress.advance(1);
+            }
 
             let value = {
                 let Some(cell) = self.workbook.get_cell(key) else {
@@ -6495,6 +8093,7 @@ impl Engine {
                                 cols_by_sheet,
                                 slice_mode,
                                 trace: None,
+                                criteria_index: None,
                             };
                             let base = bytecode::CellCoord {
                                 row: key.addr.row as i32,
@@ -6523,13 +8122,14 @@ impl Engine {
         (spill_dirty_roots, dynamic_dirty_roots)
     }
 
-    fn recalculate_levels(
+    fn recalculate_levels_with_progress(
         &mut self,
         levels: Vec<Vec<CellId>>,
         mode: RecalcMode,
         recalc_ctx: &crate::eval::RecalcContext,
         date_system: ExcelDateSystem,
         mut value_changes: Option<&mut RecalcValueChangeCollector>,
+        mut progress: Option<&mut RecalcProgressReporter<'_>>,
     ) -> (Vec<CellId>, Vec<CellId>) {
         self.circular_references.clear();
         let value_locale = self.value_locale;
@@ -6641,6 +8241,12 @@ impl Engine {
                 buffer_results = false;
             }
 
+            // Cells in one level don't depend on each other, so the ranges they read stay fixed
+            // until the level's results are applied. Unbuffered evaluation applies results as it
+            // goes, so it can't share indexes across tasks.
+            let criteria_index = bytecode::CriteriaIndexCache::new();
+            let criteria_index = buffer_results.then_some(&criteria_index);
+
             let eval_non_dynamic_task = |snapshot: &Snapshot,
                                         vm: &mut bytecode::Vm,
                                         k: &CellKey,
@@ -6687,6 +8293,7 @@ impl Engine {
                                 cols_by_sheet,
                                 slice_mode,
                                 trace: None,
+                                criteria_index,
                             };
                             let base = bytecode::CellCoord {
                                 row: k.addr.row as i32,
@@ -6890,6 +8497,7 @@ impl Engine {
                                 cols_by_sheet,
                                 slice_mode,
                                 trace: Some(&bytecode_trace),
+                                criteria_index: None,
                             };
                             let base = bytecode::CellCoord {
                                 row: k.addr.row as i32,
@@ -7069,6 +8677,10 @@ impl Engine {
                     dynamic_dirty_roots.push(cell_id);
                 }
             }
+
+            if let Some(progress) = progress.as_deref_mut() {
+                progress.advance(tasks_len);
+            }
         }
 
         self.calc_graph.clear_dirty();
@@ -9261,7 +10873,7 @@ impl Engine {
         let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
             return false;
         };
-        let Ok(addr) = parse_a1(addr) else {
+        let Ok(addr) = self.parse_address(addr) else {
             return false;
         };
         self.dirty.contains(&CellKey {
@@ -9337,7 +10949,7 @@ impl Engine {
     /// provided `cell`.
     pub fn dirty_dependency_path(&self, sheet: &str, addr: &str) -> Option<Vec<PrecedentNode>> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         let key = CellKey {
             sheet: sheet_id,
             addr,
@@ -9392,7 +11004,7 @@ impl Engine {
                 format!("unknown sheet '{sheet}'"),
             )));
         };
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         let key = CellKey {
             sheet: sheet_id,
             addr,
@@ -9503,7 +11115,7 @@ impl Engine {
         let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
             return Ok(Vec::new());
         };
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         let key = CellKey {
             sheet: sheet_id,
             addr,
@@ -9545,7 +11157,7 @@ impl Engine {
         let Some(sheet_id) = self.workbook.sheet_id(sheet) else {
             return Ok(Vec::new());
         };
-        let addr = parse_a1(addr)?;
+        let addr = self.parse_address(addr)?;
         let key = CellKey {
             sheet: sheet_id,
             addr,
@@ -9752,7 +11364,7 @@ impl PivotRefreshContext for Engine {
 
     fn read_cell_number_format(&self, sheet: &str, addr: &str) -> Option<String> {
         let sheet_id = self.workbook.sheet_id(sheet)?;
-        let addr = parse_a1(addr).ok()?;
+        let addr = self.parse_address(addr).ok()?;
         self.number_format_pattern_for_rounding(CellKey {
             sheet: sheet_id,
             addr,
@@ -9991,6 +11603,38 @@ fn ranges_overlap(a: Range, b: Range) -> bool {
         || a.start.col > b.end.col)
 }
 
+/// Convert a stored engine value into the sort/filter subsystem's cell representation.
+///
+/// Rich values filter on their display text; arrays/references (which never live in a cell
+/// directly) are treated as `#VALUE!`.
+/// Names of the properties a style sets, in a stable order.
+fn style_properties(style: &formula_model::Style) -> Vec<&'static str> {
+    [
+        ("font", style.font.is_some()),
+        ("fill", style.fill.is_some()),
+        ("border", style.border.is_some()),
+        ("alignment", style.alignment.is_some()),
+        ("protection", style.protection.is_some()),
+        ("numberFormat", style.number_format.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(name, set)| set.then_some(name))
+    .collect()
+}
+
+fn engine_value_to_sort_value(value: &Value) -> crate::sort_filter::CellValue {
+    use crate::sort_filter::CellValue;
+    match value {
+        Value::Blank => CellValue::Blank,
+        Value::Number(n) => CellValue::Number(*n),
+        Value::Text(s) => CellValue::Text(s.clone()),
+        Value::Bool(b) => CellValue::Bool(*b),
+        Value::Error(e) => CellValue::Error((*e).into()),
+        Value::Entity(_) | Value::Record(_) => CellValue::Text(value.to_string()),
+        _ => CellValue::Error(ErrorKind::Value.into()),
+    }
+}
+
 fn shift_rows(sheet: &mut Sheet, row: u32, count: u32, insert: bool) {
     let del_end = row.saturating_add(count.saturating_sub(1));
     let old_cells = std::mem::take(&mut sheet.cells);
@@ -10049,6 +11693,22 @@ fn shift_rows(sheet: &mut Sheet, row: u32, count: u32, insert: bool) {
         }
     }
     sheet.row_properties = new_props;
+
+    let old_filtered = std::mem::take(&mut sheet.filter_hidden_rows);
+    sheet.filter_hidden_rows = old_filtered
+        .into_iter()
+        .filter_map(|r| {
+            if insert {
+                Some(if r >= row { r.saturating_add(count) } else { r })
+            } else if r < row {
+                Some(r)
+            } else if r > del_end {
+                Some(r.saturating_sub(count))
+            } else {
+                None
+            }
+        })
+        .collect();
 }
 
 fn shift_cols(sheet: &mut Sheet, col: u32, count: u32, insert: bool) {
@@ -10697,6 +12357,294 @@ fn copy_range(
     }
 }
 
+/// Split the constant text cells of the single-column `src` range for
+/// [`EditOp::TextToColumns`], returning each split row with its pieces.
+///
+/// Fixed-width pieces are trimmed since the padding belongs to the layout, and trailing empty
+/// fields are dropped so short lines do not claim extra columns.
+fn text_to_columns_pieces(
+    sheet: &Sheet,
+    src: Range,
+    split: &TextToColumnsSplit,
+) -> Vec<(u32, Vec<String>)> {
+    let mut out = Vec::new();
+    for row in src.start.row..=src.end.row {
+        let addr = CellAddr {
+            row,
+            col: src.start.col,
+        };
+        let Some(cell) = sheet.cells.get(&addr) else {
+            continue;
+        };
+        if cell.formula.is_some() {
+            continue;
+        }
+        let Value::Text(text) = &cell.value else {
+            continue;
+        };
+        let pieces = match split {
+            TextToColumnsSplit::Delimiter(delimiter) => {
+                text.split(*delimiter).map(str::to_string).collect()
+            }
+            TextToColumnsSplit::FixedWidths(widths) => {
+                let mut chars = text.chars();
+                let mut pieces: Vec<String> = widths
+                    .iter()
+                    .map(|width| {
+                        let piece: String = chars.by_ref().take(*width as usize).collect();
+                        piece.trim().to_string()
+                    })
+                    .collect();
+                pieces.push(chars.as_str().trim().to_string());
+                while pieces.len() > 1 && pieces.last().is_some_and(String::is_empty) {
+                    pieces.pop();
+                }
+                pieces
+            }
+        };
+        out.push((row, pieces));
+    }
+    out
+}
+
+/// Write text-to-columns pieces starting at `col`, re-typing each piece like Excel's "General"
+/// column format. Existing cell styles are kept; inferred dates/times get a date number format.
+fn write_text_to_columns(
+    sheet: &mut Sheet,
+    col: u32,
+    rows: Vec<(u32, Vec<String>)>,
+    value_locale: ValueLocaleConfig,
+    date_system: ExcelDateSystem,
+) {
+    let now_utc = chrono::Utc::now();
+    for (row, pieces) in rows {
+        for (offset, piece) in pieces.iter().enumerate() {
+            let addr = CellAddr {
+                row,
+                col: col + offset as u32,
+            };
+            let (value, inferred_format) =
+                text_to_columns_value(piece, value_locale, now_utc, date_system);
+            let (style_id, number_format) = match sheet.cells.get(&addr) {
+                Some(cell) => (cell.style_id, cell.number_format.clone()),
+                None => (0, None),
+            };
+            if matches!(value, Value::Blank) && style_id == 0 && number_format.is_none() {
+                sheet.cells.remove(&addr);
+                continue;
+            }
+            sheet.cells.insert(
+                addr,
+                Cell {
+                    value,
+                    style_id,
+                    number_format: inferred_format.or(number_format),
+                    ..Cell::default()
+                },
+            );
+        }
+    }
+}
+
+fn text_to_columns_value(
+    piece: &str,
+    value_locale: ValueLocaleConfig,
+    now_utc: chrono::DateTime<chrono::Utc>,
+    date_system: ExcelDateSystem,
+) -> (Value, Option<String>) {
+    if piece.is_empty() {
+        return (Value::Blank, None);
+    }
+    if piece.eq_ignore_ascii_case("TRUE") {
+        return (Value::Bool(true), None);
+    }
+    if piece.eq_ignore_ascii_case("FALSE") {
+        return (Value::Bool(false), None);
+    }
+    let separators = value_locale.separators;
+    if let Ok(n) = crate::coercion::number::parse_number_strict(
+        piece,
+        separators.decimal_sep,
+        Some(separators.thousands_sep),
+    ) {
+        return (Value::Number(n), None);
+    }
+    if let Ok(serial) = crate::coercion::datetime::parse_value_text(
+        piece.trim(),
+        value_locale,
+        now_utc,
+        date_system,
+    ) {
+        let format = if serial.fract() == 0.0 {
+            "m/d/yyyy"
+        } else if serial < 1.0 {
+            "h:mm:ss"
+        } else {
+            "m/d/yyyy h:mm"
+        };
+        return (Value::Number(serial), Some(format.to_string()));
+    }
+    (Value::Text(piece.to_string()), None)
+}
+
+/// Key cell value for [`EditOp::RemoveDuplicates`]: text compares case-insensitively and `-0`
+/// equals `0`.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum DuplicateKey {
+    Blank,
+    Number(u64),
+    Text(String),
+    Bool(bool),
+    Error(ErrorKind),
+    Other(String),
+}
+
+impl DuplicateKey {
+    fn from_cell(cell: Option<&Cell>) -> Self {
+        let Some(cell) = cell else {
+            return DuplicateKey::Blank;
+        };
+        match &cell.value {
+            Value::Blank => DuplicateKey::Blank,
+            Value::Number(n) => DuplicateKey::Number((n + 0.0).to_bits()),
+            Value::Text(s) => DuplicateKey::Text(crate::value::casefold_owned(s.clone())),
+            Value::Bool(b) => DuplicateKey::Bool(*b),
+            Value::Error(e) => DuplicateKey::Error(*e),
+            other => DuplicateKey::Other(format!("{other:?}")),
+        }
+    }
+}
+
+/// Delete rows of `range` whose `key_columns` repeat an earlier row, compacting the kept rows to
+/// the top of the range. Returns the number of rows removed.
+fn remove_duplicate_rows(
+    sheet: &mut Sheet,
+    sheet_name: &str,
+    range: Range,
+    key_columns: &[u32],
+    formula_rewrites: &mut Vec<FormulaRewrite>,
+) -> u32 {
+    let mut seen = HashSet::new();
+    let mut kept_rows = Vec::new();
+    for row in range.start.row..=range.end.row {
+        let key: Vec<DuplicateKey> = key_columns
+            .iter()
+            .map(|&col| DuplicateKey::from_cell(sheet.cells.get(&CellAddr { row, col })))
+            .collect();
+        if seen.insert(key) {
+            kept_rows.push(row);
+        }
+    }
+    let removed = range.height() - kept_rows.len() as u32;
+    if removed == 0 {
+        return 0;
+    }
+
+    let mut extracted: HashMap<CellAddr, Cell> = HashMap::new();
+    for cell in range.iter() {
+        let addr = cell_addr_from_cell_ref(cell);
+        if let Some(value) = sheet.cells.remove(&addr) {
+            extracted.insert(addr, value);
+        }
+    }
+
+    for (dst_row, src_row) in (range.start.row..).zip(kept_rows) {
+        for col in range.start.col..=range.end.col {
+            let Some(mut value) = extracted.remove(&CellAddr { row: src_row, col }) else {
+                continue;
+            };
+            if dst_row != src_row {
+                if let Some(formula) = &value.formula {
+                    let origin = crate::CellAddr::new(dst_row, col);
+                    let delta_row = dst_row as i32 - src_row as i32;
+                    let (new_formula, _) =
+                        rewrite_formula_for_copy_delta(formula, sheet_name, origin, delta_row, 0);
+                    if new_formula != formula.as_ref() {
+                        formula_rewrites.push(FormulaRewrite {
+                            sheet: sheet_name.to_string(),
+                            cell: CellRef::new(dst_row, col),
+                            before: formula.to_string(),
+                            after: new_formula.clone(),
+                        });
+                    }
+                    value.formula = Some(new_formula.into());
+                }
+            }
+            sheet.cells.insert(CellAddr { row: dst_row, col }, value);
+        }
+    }
+    removed
+}
+
+/// Reorder the rows of `range` (below `header_rows`) by `keys`, moving whole cells and
+/// adjusting relative references in moved formulas like a copy to the new row.
+fn sort_range_rows(
+    sheet: &mut Sheet,
+    sheet_name: &str,
+    range: Range,
+    keys: &[crate::sort_filter::SortKey],
+    header_rows: usize,
+    value_locale: ValueLocaleConfig,
+    formula_rewrites: &mut Vec<FormulaRewrite>,
+) -> Result<(), crate::sort_filter::SortError> {
+    let perm = crate::sort_filter::compute_row_permutation_with_value_locale(
+        range.height() as usize,
+        header_rows,
+        keys,
+        value_locale,
+        |row, col| {
+            let addr = CellAddr {
+                row: range.start.row + row as u32,
+                col: range.start.col + col as u32,
+            };
+            sheet
+                .cells
+                .get(&addr)
+                .map(|cell| engine_value_to_sort_value(&cell.value))
+                .unwrap_or(crate::sort_filter::CellValue::Blank)
+        },
+    )?;
+
+    let new_row_of =
+        |row: u32| range.start.row + perm.old_to_new[(row - range.start.row) as usize] as u32;
+    let mut extracted: Vec<(CellAddr, Cell)> = Vec::new();
+    for cell in range.iter() {
+        let addr = cell_addr_from_cell_ref(cell);
+        if new_row_of(addr.row) == addr.row {
+            continue;
+        }
+        if let Some(value) = sheet.cells.remove(&addr) {
+            extracted.push((addr, value));
+        }
+    }
+    for (addr, mut value) in extracted {
+        let new_row = new_row_of(addr.row);
+        if let Some(formula) = &value.formula {
+            let origin = crate::CellAddr::new(new_row, addr.col);
+            let delta_row = new_row as i32 - addr.row as i32;
+            let (new_formula, _) =
+                rewrite_formula_for_copy_delta(formula, sheet_name, origin, delta_row, 0);
+            if new_formula != formula.as_ref() {
+                formula_rewrites.push(FormulaRewrite {
+                    sheet: sheet_name.to_string(),
+                    cell: CellRef::new(new_row, addr.col),
+                    before: formula.to_string(),
+                    after: new_formula.clone(),
+                });
+            }
+            value.formula = Some(new_formula.into());
+        }
+        sheet.cells.insert(
+            CellAddr {
+                row: new_row,
+                col: addr.col,
+            },
+            value,
+        );
+    }
+    Ok(())
+}
+
 fn fill_range(
     sheet: &mut Sheet,
     sheet_name: &str,
@@ -12142,6 +14090,7 @@ struct Snapshot {
     sheet_names: Vec<HashMap<String, crate::eval::ResolvedName>>,
     styles: StyleTable,
     row_properties: Vec<BTreeMap<u32, RowProperties>>,
+    filter_hidden_rows: Vec<BTreeSet<u32>>,
     col_properties: Vec<BTreeMap<u32, ColProperties>>,
     workbook_directory: Option<String>,
     workbook_filename: Option<String>,
@@ -12307,18 +14256,22 @@ impl Snapshot {
         let spill_origin_by_cell = spills.origin_by_cell.clone();
         let mut tables: Vec<Vec<Table>> = Vec::new();
         let mut row_properties: Vec<BTreeMap<u32, RowProperties>> = Vec::new();
+        let mut filter_hidden_rows: Vec<BTreeSet<u32>> = Vec::new();
         let mut col_properties: Vec<BTreeMap<u32, ColProperties>> = Vec::new();
         let _ = tables.try_reserve_exact(workbook.sheets.len());
         let _ = row_properties.try_reserve_exact(workbook.sheets.len());
+        let _ = filter_hidden_rows.try_reserve_exact(workbook.sheets.len());
         let _ = col_properties.try_reserve_exact(workbook.sheets.len());
         for (sheet_id, s) in workbook.sheets.iter().enumerate() {
             if workbook.sheet_exists(sheet_id) {
                 tables.push(s.tables.clone());
                 row_properties.push(s.row_properties.clone());
+                filter_hidden_rows.push(s.filter_hidden_rows.clone());
                 col_properties.push(s.col_properties.clone());
             } else {
                 tables.push(Vec::new());
                 row_properties.push(BTreeMap::new());
+                filter_hidden_rows.push(BTreeSet::new());
                 col_properties.push(BTreeMap::new());
             }
         }
@@ -12397,6 +14350,7 @@ impl Snapshot {
             workbook_names,
             sheet_names,
             row_properties,
+            filter_hidden_rows,
             col_properties,
             styles: workbook.styles.clone(),
             external_value_provider,
@@ -12529,7 +14483,7 @@ impl crate::eval::ValueResolver for Snapshot {
                     .map(|s| s.as_str())
                     .or(self.info.origin.as_deref())?;
 
-                let addr = parse_a1(origin).ok()?;
+                let addr = parse_a1_with_max_cols(origin, MAX_GRID_COLS).ok()?;
 
                 // Reject out-of-bounds origin coordinates to keep `INFO("origin")` deterministic
                 // and consistent with `Engine::set_sheet_origin` validation.
@@ -12603,6 +14557,21 @@ impl crate::eval::ValueResolver for Snapshot {
             .and_then(|props| props.style_id)
     }
 
+    fn row_hidden_state(&self, sheet_id: usize, row: u32) -> HiddenState {
+        HiddenState {
+            user: self
+                .row_properties
+                .get(sheet_id)
+                .and_then(|map| map.get(&row))
+                .is_some_and(|props| props.hidden),
+            outline: false,
+            filter: self
+                .filter_hidden_rows
+                .get(sheet_id)
+                .is_some_and(|rows| rows.contains(&row)),
+        }
+    }
+
     fn col_properties(&self, sheet_id: usize, col: u32) -> Option<ColProperties> {
         self.col_properties
             .get(sheet_id)
@@ -12855,6 +14824,15 @@ impl crate::eval::ValueResolver for Snapshot {
     }
 
     fn iter_sheet_cells(&self, sheet_id: usize) -> Option<Box<dyn Iterator<Item = CellAddr> + '_>> {
+        self.iter_sheet_cells_in_rows(sheet_id, 0, u32::MAX)
+    }
+
+    fn iter_sheet_cells_in_rows(
+        &self,
+        sheet_id: usize,
+        row_start: u32,
+        row_end: u32,
+    ) -> Option<Box<dyn Iterator<Item = CellAddr> + '_>> {
         // When values are provided out-of-band, we cannot safely enumerate only the snapshot's
         // stored cells: provider-backed values may exist for addresses that are not present in
         // `ordered_cells`, and skipping them would produce incorrect results for range functions
@@ -12867,14 +14845,22 @@ impl crate::eval::ValueResolver for Snapshot {
         if !self.sheet_exists(sheet_id) {
             return None;
         }
+        if row_start > row_end {
+            return Some(Box::new(std::iter::empty()));
+        }
+        // `ordered_cells` is sorted row-major within a sheet, so a row window is a contiguous
+        // slice of the set.
         let start = CellKey {
             sheet: sheet_id,
-            addr: CellAddr { row: 0, col: 0 },
+            addr: CellAddr {
+                row: row_start,
+                col: 0,
+            },
         };
         let end = CellKey {
             sheet: sheet_id,
             addr: CellAddr {
-                row: u32::MAX,
+                row: row_end,
                 col: u32::MAX,
             },
         };
@@ -13718,6 +15704,8 @@ pub trait ExternalDataProvider: Send + Sync {
 
 const EXCEL_MAX_COLS_I32: i32 = EXCEL_MAX_COLS as i32;
 const EXCEL_MAX_ROWS_I32: i32 = EXCEL_MAX_ROWS as i32;
+/// Largest block [`Engine::update_range_style`] writes cell by cell before using style layers.
+const MAX_PER_CELL_RESTYLE_CELLS: u64 = 65_536;
 const BYTECODE_MAX_RANGE_CELLS: i64 = crate::eval::MAX_MATERIALIZED_ARRAY_CELLS as i64;
 
 fn engine_error_to_bytecode(err: ErrorKind) -> bytecode::ErrorKind {
@@ -14400,6 +16388,9 @@ struct EngineBytecodeGrid<'a> {
     cols_by_sheet: &'a [HashMap<i32, BytecodeColumn>],
     slice_mode: ColumnSliceMode,
     trace: Option<&'a Mutex<crate::eval::DependencyTrace>>,
+    /// Criteria index cache for the current calc level (`None` when cell values may change while
+    /// the grid is in use).
+    criteria_index: Option<&'a bytecode::CriteriaIndexCache>,
 }
 
 impl<'a> EngineBytecodeGrid<'a> {
@@ -14841,6 +16832,10 @@ impl bytecode::grid::Grid for EngineBytecodeGrid<'_> {
         None
     }
 
+    fn criteria_index_cache(&self) -> Option<&bytecode::CriteriaIndexCache> {
+        self.criteria_index
+    }
+
     fn spill_origin(&self, sheet_id: &bytecode::SheetId, addr: CellAddr) -> Option<CellAddr> {
         match sheet_id {
             bytecode::SheetId::Local(sheet_id) => self.snapshot.spill_origin(*sheet_id, addr),
@@ -16142,7 +18137,7 @@ fn bytecode_expr_is_eligible_inner(
                 true
             }
             bytecode::ast::Function::SumProduct => {
-                if args.len() != 2 {
+                if args.is_empty() || args.len() > crate::EXCEL_MAX_ARGS {
                     return false;
                 }
                 args.iter()
@@ -16467,6 +18462,18 @@ fn analyze_expr_flags(
     (names, volatile, thread_safe, dynamic_deps, origin_deps)
 }
 
+/// Row/column span of a static cell or range reference resolved at `origin`.
+fn static_ref_span(expr: &CompiledExpr, origin: CellAddr) -> Option<(u32, u32)> {
+    let (start, end) = match expr {
+        Expr::CellRef(r) => (r.addr, r.addr),
+        Expr::RangeRef(r) => (r.start, r.end),
+        _ => return None,
+    };
+    let start = start.resolve(origin)?;
+    let end = end.resolve(origin)?;
+    Some((start.row.abs_diff(end.row), start.col.abs_diff(end.col)))
+}
+
 fn walk_expr_flags(
     expr: &CompiledExpr,
     current_cell: CellKey,
@@ -16642,6 +18649,20 @@ fn walk_expr_flags(
                 if matches!(spec.name, "OFFSET" | "INDIRECT" | "GETPIVOTDATA") {
                     *dynamic_deps = true;
                 }
+                // AVERAGEIF resizes a reference average_range to the criteria range's shape, so when
+                // the shapes differ it reads cells its arguments don't name.
+                if spec.name == "AVERAGEIF" && args.len() == 3 {
+                    let resized = match (
+                        static_ref_span(&args[0], current_cell.addr),
+                        static_ref_span(&args[2], current_cell.addr),
+                    ) {
+                        (Some(criteria), Some(average)) => criteria != average,
+                        _ => !matches!(args[2], Expr::Blank | Expr::ArrayLiteral { .. }),
+                    };
+                    if resized {
+                        *dynamic_deps = true;
+                    }
+                }
 
                 if spec.name == "INFO" {
                     // `INFO("origin")` depends on host-provided worksheet view state (scroll position +
@@ -17987,6 +20008,29 @@ fn spill_range_target_cell(expr: &CompiledExpr, current_cell: CellKey) -> Option
     }
 }
 
+/// Render a canonical A1 formula entered at `origin` in canonical R1C1 style.
+fn canonical_formula_to_r1c1(formula: &str, origin: CellAddr) -> Option<String> {
+    let origin = crate::CellAddr::new(origin.row, origin.col);
+    let ast = crate::parse_formula(
+        formula,
+        crate::ParseOptions {
+            locale: crate::LocaleConfig::en_us(),
+            reference_style: crate::ReferenceStyle::A1,
+            normalize_relative_to: Some(origin),
+        },
+    )
+    .ok()?;
+
+    ast.to_string(crate::SerializeOptions {
+        locale: crate::LocaleConfig::en_us(),
+        reference_style: crate::ReferenceStyle::R1C1,
+        include_xlfn_prefix: true,
+        origin: Some(origin),
+        omit_equals: false,
+    })
+    .ok()
+}
+
 fn spill_range_bounds(cell: CellKey, spills: &SpillState) -> (CellKey, CellAddr) {
     let origin = if spills.by_origin.contains_key(&cell) {
         cell
@@ -19837,6 +21881,7 @@ fn rewrite_defined_name_range_map(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::eval::parse_a1;
     use chrono::TimeZone;
 
     #[test]
@@ -19948,6 +21993,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn row_height_zero_maps_to_hidden_and_preserves_previous_height() {
+        let mut engine = Engine::new();
+        assert_eq!(engine.row_height("Sheet1", 1), None);
+
+        engine.set_row_height("Sheet1", 1, Some(20.0));
+        assert_eq!(engine.row_height("Sheet1", 1), Some(20.0));
+        assert!(!engine.is_row_hidden("Sheet1", 1));
+
+        engine.set_row_height("Sheet1", 1, Some(0.0));
+        assert!(engine.is_row_hidden("Sheet1", 1));
+        assert_eq!(engine.row_height("Sheet1", 1), Some(0.0));
+
+        engine.set_row_hidden("Sheet1", 1, false);
+        assert_eq!(engine.row_height("Sheet1", 1), Some(20.0));
+
+        engine.set_row_height("Sheet1", 1, None);
+        assert_eq!(engine.row_height("Sheet1", 1), None);
+        let sheet_id = engine.workbook.sheet_id("Sheet1").expect("sheet exists");
+        assert!(
+            engine.workbook.sheets[sheet_id].row_properties.is_empty(),
+            "clearing the last row property should prune the entry"
+        );
+    }
+
     #[test]
     fn sheet_name_lookup_is_nfkc_and_unicode_case_insensitive() {
         let mut engine = Engine::new();
@@ -21090,24 +23160,26 @@ mod tests {
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = single.recalculate_levels(
+        let _ = single.recalculate_levels_with_progress(
             levels_single,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             single.date_system,
             None,
+            None,
         );
 
         let levels_multi = multi
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = multi.recalculate_levels(
+        let _ = multi.recalculate_levels_with_progress(
             levels_multi,
             RecalcMode::MultiThreaded,
             &recalc_ctx,
             multi.date_system,
             None,
+            None,
         );
 
         for addr in ["A1", "A2", "A3", "B1"] {
@@ -21172,24 +23244,26 @@ mod tests {
         };
 
         let levels_ast = ast.calc_graph.calc_levels_for_dirty().expect("calc levels");
-        let _ = ast.recalculate_levels(
+        let _ = ast.recalculate_levels_with_progress(
             levels_ast,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             ast.date_system,
             None,
+            None,
         );
 
         let levels_bytecode = bytecode
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = bytecode.recalculate_levels(
+        let _ = bytecode.recalculate_levels_with_progress(
             levels_bytecode,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             bytecode.date_system,
             None,
+            None,
         );
 
         for addr in ["A1", "A2"] {
@@ -21229,12 +23303,13 @@ mod tests {
                 .calc_graph
                 .calc_levels_for_dirty()
                 .expect("calc levels");
-            let _ = engine.recalculate_levels(
+            let _ = engine.recalculate_levels_with_progress(
                 levels,
                 RecalcMode::SingleThreaded,
                 ctx,
                 engine.date_system,
                 None,
+                None,
             );
         };
 
@@ -21335,24 +23410,26 @@ mod tests {
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = bytecode_engine.recalculate_levels(
+        let _ = bytecode_engine.recalculate_levels_with_progress(
             levels_bc,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             bytecode_engine.date_system,
             None,
+            None,
         );
 
         let levels_ast = ast_engine
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = ast_engine.recalculate_levels(
+        let _ = ast_engine.recalculate_levels_with_progress(
             levels_ast,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             ast_engine.date_system,
             None,
+            None,
         );
 
         for addr in ["A1", "A2", "A3", "B1"] {
@@ -21482,7 +23559,7 @@ mod tests {
                 .set_cell_formula("Sheet1", "B1", "=SUM(A:A)")
                 .unwrap();
             engine
-                .set_cell_formula("Sheet1", "B2", "=COUNTIF(A:A, 0)")
+                .set_cell_formula("Sheet1", "B2", r#"=COUNTIF(A:A, "<>2")"#)
                 .unwrap();
 
             // Criteria aggregates over full-column ranges should also take the sparse iteration path.
@@ -21655,7 +23732,7 @@ mod tests {
 
         // Sanity check expected values.
         assert_eq!(bc_sum, Value::Number(6.0));
-        assert_eq!(bc_countif, Value::Number(1_048_573.0));
+        assert_eq!(bc_countif, Value::Number(1_048_575.0));
         assert_eq!(bc_sumif, Value::Number(50.0));
         assert_eq!(bc_sumifs, Value::Number(30.0));
         assert_eq!(bc_countifs, Value::Number(1.0));
@@ -21956,7 +24033,7 @@ mod tests {
                 .set_cell_formula("Sheet1", "B1", "=SUM(Sheet1:Sheet3!A:A)")
                 .unwrap();
             engine
-                .set_cell_formula("Sheet1", "B2", "=COUNTIF(Sheet1:Sheet3!A:A, 0)")
+                .set_cell_formula("Sheet1", "B2", r#"=COUNTIF(Sheet1:Sheet3!A:A, "<>5")"#)
                 .unwrap();
             engine
                 .set_cell_formula("Sheet1", "B3", "=MIN(Sheet1:Sheet3!A:A)")
@@ -22074,7 +24151,7 @@ mod tests {
 
         // Sanity check expected values.
         assert_eq!(bc_sum, Value::Number(45.0));
-        assert_eq!(bc_countif, Value::Number(3_145_719.0));
+        assert_eq!(bc_countif, Value::Number(3_145_727.0));
         assert_eq!(bc_min, Value::Number(1.0));
         assert_eq!(bc_max, Value::Number(9.0));
         assert_eq!(bc_count, Value::Number(9.0));
@@ -22221,7 +24298,8 @@ mod tests {
             ("C3", Value::Number(60.0)),
             ("C4", Value::Number(20.0)),
             ("C5", Value::Number(3.0)),
-            ("C6", Value::Number(1_048_573.0)),
+            // Blanks are not `0`; only the explicit zero in A500000 matches.
+            ("C6", Value::Number(1.0)),
             ("C7", Value::Number(10.0)),
             ("C8", Value::Number(30.0)),
             ("C9", Value::Number(50.0)),
@@ -23620,12 +25698,13 @@ mod tests {
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = engine_bc.recalculate_levels(
+        let _ = engine_bc.recalculate_levels_with_progress(
             levels,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             engine_bc.date_system,
             None,
+            None,
         );
         let value_bc = engine_bc.get_cell_value("Sheet1", addr);
         assert_eq!(value_bc, expected);
@@ -23645,12 +25724,13 @@ mod tests {
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = engine_ast.recalculate_levels(
+        let _ = engine_ast.recalculate_levels_with_progress(
             levels,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             engine_ast.date_system,
             None,
+            None,
         );
         let value_ast = engine_ast.get_cell_value("Sheet1", addr);
         assert_eq!(value_ast, expected);
@@ -23697,12 +25777,13 @@ mod tests {
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = engine_bc.recalculate_levels(
+        let _ = engine_bc.recalculate_levels_with_progress(
             levels,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             engine_bc.date_system,
             None,
+            None,
         );
         let value_bc = engine_bc.get_cell_value("Sheet1", addr);
 
@@ -23721,12 +25802,13 @@ mod tests {
             .calc_graph
             .calc_levels_for_dirty()
             .expect("calc levels");
-        let _ = engine_ast.recalculate_levels(
+        let _ = engine_ast.recalculate_levels_with_progress(
             levels,
             RecalcMode::SingleThreaded,
             &recalc_ctx,
             engine_ast.date_system,
             None,
+            None,
         );
         let value_ast = engine_ast.get_cell_value("Sheet1", addr);
 
@@ -24361,6 +26443,7 @@ mod tests {
             cols_by_sheet: std::slice::from_ref(&cols),
             slice_mode: ColumnSliceMode::IgnoreNonNumeric,
             trace: None,
+            criteria_index: None,
         };
 
         assert!(
@@ -0,0 +1,291 @@
+//! Read-only reference analysis of formula text.
+//!
+//! [`Engine::analyze_formula`] parses a formula the way the engine would on commit and lists the
+//! cells, ranges, defined names and tables it refers to, without storing the formula or touching
+//! the dependency graph. Formula editors use it for live precedent highlighting.
+
+use formula_model::CellRef as ModelCellRef;
+
+use super::formula_validation::{local_key, strip_prefix_ignore_case};
+use super::{Engine, FormulaDiagnostic, SheetId};
+use crate::eval::CellAddr;
+use crate::locale::{canonicalize_formula, FormulaLocale};
+use crate::{
+    BinaryOp, Coord, Expr, FunctionCall, LocaleConfig, ParseOptions, PostfixOp, ReferenceStyle,
+    SerializeOptions, Span,
+};
+
+/// References found by [`Engine::analyze_formula`], each in source order without duplicates.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormulaReferences {
+    /// Cell and range references in A1 notation without `$` markers (e.g. `A1`, `Sheet2!B1:C3`,
+    /// `A:A`, `A1#`). References without a sheet prefix refer to the formula's sheet.
+    pub ranges: Vec<String>,
+    /// Defined names (including LAMBDA-valued names called as functions), as written.
+    pub names: Vec<String>,
+    /// Tables referenced by structured references or by bare table name.
+    pub tables: Vec<String>,
+}
+
+impl Engine {
+    /// List the references a canonical (en-US) formula would read if entered on `sheet`, without
+    /// mutating the workbook.
+    ///
+    /// `origin` is the cell the formula is being edited in; it resolves relative offsets and
+    /// `[@Column]`-style references to the table containing that cell. LET/LAMBDA locals are not
+    /// reported as names.
+    pub fn analyze_formula(
+        &self,
+        sheet: &str,
+        formula: &str,
+        origin: Option<CellAddr>,
+    ) -> Result<FormulaReferences, FormulaDiagnostic> {
+        self.analyze_formula_with_locale(sheet, formula, origin, None)
+    }
+
+    /// Like [`Engine::analyze_formula`], but for a formula entered in a locale-specific display
+    /// format. Reported references are rendered in canonical (en-US) syntax.
+    pub fn analyze_formula_localized(
+        &self,
+        sheet: &str,
+        localized_formula: &str,
+        origin: Option<CellAddr>,
+        locale: &FormulaLocale,
+    ) -> Result<FormulaReferences, FormulaDiagnostic> {
+        self.analyze_formula_with_locale(sheet, localized_formula, origin, Some(locale))
+    }
+
+    fn analyze_formula_with_locale(
+        &self,
+        sheet: &str,
+        formula: &str,
+        origin: Option<CellAddr>,
+        locale: Option<&FormulaLocale>,
+    ) -> Result<FormulaReferences, FormulaDiagnostic> {
+        let whole = Span::new(0, formula.len());
+        let canonical;
+        let formula = match locale {
+            Some(locale) => {
+                canonical =
+                    canonicalize_formula(formula, locale).map_err(|err| FormulaDiagnostic {
+                        message: err.to_string(),
+                        span: whole,
+                    })?;
+                canonical.as_str()
+            }
+            None => formula,
+        };
+        let opts = ParseOptions {
+            locale: LocaleConfig::en_us(),
+            reference_style: ReferenceStyle::A1,
+            normalize_relative_to: None,
+        };
+        let ast = crate::parse_formula(formula, opts).map_err(|err| FormulaDiagnostic {
+            message: err.message,
+            // Spans into the canonical text don't line up with localized input.
+            span: if locale.is_some() { whole } else { err.span },
+        })?;
+
+        let mut collector = ReferenceCollector {
+            engine: self,
+            sheet_id: self.workbook.sheet_id(sheet),
+            origin,
+            locals: Vec::new(),
+            out: FormulaReferences::default(),
+        };
+        collector.visit(&ast.expr);
+        Ok(collector.out)
+    }
+}
+
+struct ReferenceCollector<'a> {
+    engine: &'a Engine,
+    sheet_id: Option<SheetId>,
+    origin: Option<CellAddr>,
+    /// LET/LAMBDA locals currently in scope (casefolded, without any `_xlpm.` prefix).
+    locals: Vec<String>,
+    out: FormulaReferences,
+}
+
+impl ReferenceCollector<'_> {
+    fn visit(&mut self, expr: &Expr) {
+        match expr {
+            Expr::CellRef(_) | Expr::ColRef(_) | Expr::RowRef(_) => self.push_range(expr),
+            Expr::Binary(binary) if binary.op == BinaryOp::Range && is_area(expr) => {
+                self.push_range(expr)
+            }
+            Expr::Postfix(postfix)
+                if postfix.op == PostfixOp::SpillRange
+                    && matches!(postfix.expr.as_ref(), Expr::CellRef(_)) =>
+            {
+                self.push_range(expr)
+            }
+            Expr::NameRef(name) => {
+                if name.sheet.is_none() && name.workbook.is_none() {
+                    if self.locals.contains(&local_key(&name.name)) {
+                        return;
+                    }
+                    if let Some(table) = self.engine.find_table(&name.name) {
+                        push_unique(&mut self.out.tables, table.name.clone());
+                        return;
+                    }
+                }
+                let rendered = self.render(expr);
+                push_unique(&mut self.out.names, rendered);
+            }
+            Expr::StructuredRef(sref) => {
+                if sref.workbook.is_some() {
+                    return;
+                }
+                let table = match sref.table.as_deref() {
+                    Some(name) => Some(
+                        self.engine
+                            .find_table(name)
+                            .map_or_else(|| name.to_string(), |t| t.name.clone()),
+                    ),
+                    None => self.table_at_origin(),
+                };
+                if let Some(table) = table {
+                    push_unique(&mut self.out.tables, table);
+                }
+            }
+            Expr::FunctionCall(call) => self.visit_call(call),
+            Expr::Call(call) => {
+                self.visit(&call.callee);
+                call.args.iter().for_each(|arg| self.visit(arg));
+            }
+            Expr::FieldAccess(access) => self.visit(&access.base),
+            Expr::Array(array) => array.rows.iter().flatten().for_each(|e| self.visit(e)),
+            Expr::Unary(unary) => self.visit(&unary.expr),
+            Expr::Postfix(postfix) => self.visit(&postfix.expr),
+            Expr::Binary(binary) => {
+                self.visit(&binary.left);
+                self.visit(&binary.right);
+            }
+            Expr::Number(_)
+            | Expr::String(_)
+            | Expr::Boolean(_)
+            | Expr::Error(_)
+            | Expr::Missing => {}
+        }
+    }
+
+    fn visit_call(&mut self, call: &FunctionCall) {
+        match call.name.name_upper.as_str() {
+            // LET(name1, value1, [name2, value2, ...], calculation): each value sees the earlier
+            // bindings, and the calculation sees all of them.
+            "LET" => {
+                let scope_len = self.locals.len();
+                for (idx, arg) in call.args.iter().enumerate() {
+                    let is_binding_name = idx % 2 == 0 && idx + 1 < call.args.len();
+                    if let (true, Expr::NameRef(binding)) = (is_binding_name, arg) {
+                        self.locals.push(local_key(&binding.name));
+                        continue;
+                    }
+                    self.visit(arg);
+                }
+                self.locals.truncate(scope_len);
+            }
+            // LAMBDA([param1, ...], calculation)
+            "LAMBDA" => {
+                let scope_len = self.locals.len();
+                if let Some((body, params)) = call.args.split_last() {
+                    for param in params {
+                        match param {
+                            Expr::NameRef(param) => self.locals.push(local_key(&param.name)),
+                            other => self.visit(other),
+                        }
+                    }
+                    self.visit(body);
+                }
+                self.locals.truncate(scope_len);
+            }
+            name => {
+                let original = strip_prefix_ignore_case(&call.name.original, "_xlfn.");
+                let is_name_call = crate::functions::lookup_function(name).is_none()
+                    && !self.locals.contains(&local_key(original))
+                    && self.engine.defined_name_exists(self.sheet_id, original);
+                if is_name_call {
+                    push_unique(&mut self.out.names, original.to_string());
+                }
+                call.args.iter().for_each(|arg| self.visit(arg));
+            }
+        }
+    }
+
+    fn push_range(&mut self, expr: &Expr) {
+        let rendered = self.render(&strip_absolute_markers(expr));
+        push_unique(&mut self.out.ranges, rendered);
+    }
+
+    fn render(&self, expr: &Expr) -> String {
+        crate::Ast::new(false, expr.clone())
+            .to_string(SerializeOptions {
+                origin: Some(self.origin.map_or(crate::CellAddr::new(0, 0), |o| {
+                    crate::CellAddr::new(o.row, o.col)
+                })),
+                omit_equals: true,
+                ..SerializeOptions::default()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Table on the formula's sheet whose range contains the origin cell (the table `[@Col]`
+    /// refers to).
+    fn table_at_origin(&self) -> Option<String> {
+        let origin = self.origin?;
+        let sheet = self.engine.workbook.sheets.get(self.sheet_id?)?;
+        sheet
+            .tables
+            .iter()
+            .find(|t| t.range.contains(ModelCellRef::new(origin.row, origin.col)))
+            .map(|t| t.name.clone())
+    }
+}
+
+/// Whether `expr` is a contiguous area built from plain references with `:` (e.g. `A1:B2`,
+/// `A:C`, `Sheet1!A1:B2`), as opposed to a range with computed endpoints like `A1:INDEX(...)`.
+fn is_area(expr: &Expr) -> bool {
+    match expr {
+        Expr::CellRef(_) | Expr::ColRef(_) | Expr::RowRef(_) => true,
+        Expr::Binary(binary) => {
+            binary.op == BinaryOp::Range && is_area(&binary.left) && is_area(&binary.right)
+        }
+        _ => false,
+    }
+}
+
+fn strip_absolute_markers(expr: &Expr) -> Expr {
+    fn relative(coord: Coord) -> Coord {
+        match coord {
+            Coord::A1 { index, .. } => Coord::A1 { index, abs: false },
+            Coord::Offset(_) => coord,
+        }
+    }
+
+    fn strip(expr: &mut Expr) {
+        match expr {
+            Expr::CellRef(r) => {
+                r.col = relative(r.col);
+                r.row = relative(r.row);
+            }
+            Expr::ColRef(r) => r.col = relative(r.col),
+            Expr::RowRef(r) => r.row = relative(r.row),
+            Expr::Binary(binary) => {
+                strip(&mut binary.left);
+                strip(&mut binary.right);
+            }
+            Expr::Postfix(postfix) => strip(&mut postfix.expr),
+            _ => {}
+        }
+    }
+    let mut expr = expr.clone();
+    strip(&mut expr);
+    expr
+}
+
+fn push_unique(list: &mut Vec<String>, item: String) {
+    if !list.contains(&item) {
+        list.push(item);
+    }
+}
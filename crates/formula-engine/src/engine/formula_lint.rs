@@ -0,0 +1,334 @@
+//! Advisory lint checks for formula text.
+//!
+//! [`Engine::lint_formula`] flags formulas that parse and evaluate fine but are likely mistakes:
+//! ranges over empty areas, volatile functions, blank cells used in arithmetic, and formulas that
+//! break the pattern of their neighbors (Excel's "inconsistent formula" error check). Lints never
+//! block committing a formula.
+
+use super::{canonical_formula_to_r1c1, CellKey, Engine, FormulaDiagnostic, SheetId};
+use crate::debug::{parse_spanned_formula, SpannedExpr, SpannedExprKind};
+use crate::eval::{BinaryOp, CellAddr, RangeRef, SheetReference};
+use crate::functions::Volatility;
+use crate::locale::{canonicalize_formula, FormulaLocale};
+use crate::{LocaleConfig, ParseOptions, ReferenceStyle, Span, Value};
+
+/// How strongly a [`FormulaLint`] suggests a mistake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintSeverity {
+    /// Often intentional, but worth knowing about (e.g. volatile functions).
+    Info,
+    /// Likely a mistake.
+    Warning,
+}
+
+/// The check that produced a [`FormulaLint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormulaLintKind {
+    /// A multi-cell range in which every cell is empty.
+    EmptyRange,
+    /// A call to a volatile function (`NOW`, `RAND`, `OFFSET`, ...).
+    VolatileFunction,
+    /// The cells on both sides of the origin hold the same formula (in R1C1 terms), but this
+    /// formula differs from it.
+    InconsistentFormula,
+    /// An arithmetic operand that refers to an empty cell, which evaluates as `0`.
+    BlankCellInArithmetic,
+}
+
+impl FormulaLintKind {
+    pub fn severity(self) -> LintSeverity {
+        match self {
+            FormulaLintKind::VolatileFunction => LintSeverity::Info,
+            FormulaLintKind::EmptyRange
+            | FormulaLintKind::InconsistentFormula
+            | FormulaLintKind::BlankCellInArithmetic => LintSeverity::Warning,
+        }
+    }
+}
+
+/// An advisory finding reported by [`Engine::lint_formula`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormulaLint {
+    pub kind: FormulaLintKind,
+    pub severity: LintSeverity,
+    pub message: String,
+    /// Byte offsets into the linted formula text (including any leading `=`).
+    pub span: Span,
+}
+
+impl Engine {
+    /// Lint a canonical (en-US) formula as if it were entered on `sheet`, without mutating the
+    /// workbook.
+    ///
+    /// `origin` is the cell being edited; the inconsistent-formula check compares against its
+    /// neighbors and is skipped without it. Findings are reported in source order, followed by
+    /// the neighbor check. Parse errors are returned as a [`FormulaDiagnostic`].
+    pub fn lint_formula(
+        &self,
+        sheet: &str,
+        formula: &str,
+        origin: Option<CellAddr>,
+    ) -> Result<Vec<FormulaLint>, FormulaDiagnostic> {
+        let opts = ParseOptions {
+            locale: LocaleConfig::en_us(),
+            reference_style: ReferenceStyle::A1,
+            normalize_relative_to: None,
+        };
+        crate::parse_formula(formula, opts).map_err(|err| FormulaDiagnostic {
+            message: err.message,
+            span: err.span,
+        })?;
+        // The checks need per-node spans, which only the debug parser keeps. It covers the
+        // grammar the checks look at; anything it rejects simply gets no lints.
+        let Ok(expr) = parse_spanned_formula(formula) else {
+            return Ok(Vec::new());
+        };
+
+        let sheet_id = self.workbook.sheet_id(sheet);
+        let mut linter = Linter {
+            engine: self,
+            formula,
+            sheet_id,
+            out: Vec::new(),
+        };
+        linter.visit(&expr);
+        if let (Some(sheet_id), Some(origin)) = (sheet_id, origin) {
+            if self.is_inconsistent_with_neighbors(sheet_id, formula, origin) {
+                linter.push(
+                    FormulaLintKind::InconsistentFormula,
+                    Span::new(0, formula.len()),
+                    "Formula differs from the formulas in adjacent cells".to_string(),
+                );
+            }
+        }
+        Ok(linter.out)
+    }
+
+    /// Like [`Engine::lint_formula`], but for a formula entered in a locale-specific display
+    /// format. The checks run on the canonical form, so every span covers the whole formula.
+    pub fn lint_formula_localized(
+        &self,
+        sheet: &str,
+        localized_formula: &str,
+        origin: Option<CellAddr>,
+        locale: &FormulaLocale,
+    ) -> Result<Vec<FormulaLint>, FormulaDiagnostic> {
+        let whole = Span::new(0, localized_formula.len());
+        let canonical =
+            canonicalize_formula(localized_formula, locale).map_err(|err| FormulaDiagnostic {
+                message: err.to_string(),
+                span: whole,
+            })?;
+        let mut lints = self
+            .lint_formula(sheet, &canonical, origin)
+            .map_err(|diagnostic| FormulaDiagnostic {
+                message: diagnostic.message,
+                span: whole,
+            })?;
+        for lint in &mut lints {
+            lint.span = whole;
+        }
+        Ok(lints)
+    }
+
+    /// Whether the cell holds no formula and reads as blank (no constant, spill output or
+    /// provider value).
+    fn cell_is_empty(&self, sheet_id: SheetId, addr: CellAddr) -> bool {
+        let key = CellKey {
+            sheet: sheet_id,
+            addr,
+        };
+        self.workbook
+            .get_cell(key)
+            .is_none_or(|cell| cell.formula.is_none())
+            && self.cell_value_at(sheet_id, addr) == Value::Blank
+    }
+
+    /// Whether no cell in `start..=end` holds a formula, a non-blank constant or a spilled value.
+    ///
+    /// Provider-backed sheets cannot be enumerated, so they never count as empty.
+    fn area_is_empty(&self, sheet_id: SheetId, start: CellAddr, end: CellAddr) -> bool {
+        if self.external_value_provider.is_some() {
+            return false;
+        }
+        let Some(sheet) = self.workbook.sheets.get(sheet_id) else {
+            return false;
+        };
+        let contains = |addr: &CellAddr| {
+            (start.row..=end.row).contains(&addr.row) && (start.col..=end.col).contains(&addr.col)
+        };
+        let has_content = sheet.cells.iter().any(|(addr, cell)| {
+            contains(addr) && (cell.formula.is_some() || cell.value != Value::Blank)
+        });
+        let has_spill = self
+            .spills
+            .origin_by_cell
+            .keys()
+            .any(|key| key.sheet == sheet_id && contains(&key.addr));
+        !has_content && !has_spill
+    }
+
+    /// Excel's "inconsistent formula" check: the cells on both sides of `origin` (left/right or
+    /// above/below) share one R1C1 formula that `formula` does not match.
+    fn is_inconsistent_with_neighbors(
+        &self,
+        sheet_id: SheetId,
+        formula: &str,
+        origin: CellAddr,
+    ) -> bool {
+        let Some(own) = canonical_formula_to_r1c1(formula, origin) else {
+            return false;
+        };
+        let neighbor = |row_off: i32, col_off: i32| {
+            let addr = CellAddr {
+                row: origin.row.checked_add_signed(row_off)?,
+                col: origin.col.checked_add_signed(col_off)?,
+            };
+            let key = CellKey {
+                sheet: sheet_id,
+                addr,
+            };
+            let formula = self.workbook.get_cell(key)?.formula.as_deref()?;
+            canonical_formula_to_r1c1(formula, addr)
+        };
+        [((0, -1), (0, 1)), ((-1, 0), (1, 0))]
+            .into_iter()
+            .any(|(a, b)| match (neighbor(a.0, a.1), neighbor(b.0, b.1)) {
+                (Some(a), Some(b)) => a == b && a != own,
+                _ => false,
+            })
+    }
+}
+
+struct Linter<'a> {
+    engine: &'a Engine,
+    formula: &'a str,
+    sheet_id: Option<SheetId>,
+    out: Vec<FormulaLint>,
+}
+
+impl Linter<'_> {
+    fn push(&mut self, kind: FormulaLintKind, span: Span, message: String) {
+        self.out.push(FormulaLint {
+            kind,
+            severity: kind.severity(),
+            message,
+            span,
+        });
+    }
+
+    fn visit(&mut self, expr: &SpannedExpr<String>) {
+        match &expr.kind {
+            SpannedExprKind::RangeRef(range) => self.check_range(range, expr),
+            SpannedExprKind::FunctionCall { name, args } => {
+                let volatile = crate::functions::lookup_function(name)
+                    .is_some_and(|spec| spec.volatility == Volatility::Volatile);
+                if volatile {
+                    // Point at the function name (as written, including any `_xlfn.` prefix).
+                    let name_len = self.source(expr).find('(').unwrap_or(0);
+                    self.push(
+                        FormulaLintKind::VolatileFunction,
+                        Span::new(expr.span.start, expr.span.start + name_len),
+                        format!("{name} is volatile and recalculates on every change"),
+                    );
+                }
+                args.iter().for_each(|arg| self.visit(arg));
+            }
+            SpannedExprKind::Binary { op, left, right } => {
+                let arithmetic = matches!(
+                    op,
+                    BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Pow
+                );
+                for operand in [left, right] {
+                    if arithmetic {
+                        self.check_operand(operand);
+                    }
+                    self.visit(operand);
+                }
+            }
+            SpannedExprKind::Unary { expr: inner, .. } => {
+                self.check_operand(inner);
+                self.visit(inner);
+            }
+            SpannedExprKind::Compare { left, right, .. } => {
+                self.visit(left);
+                self.visit(right);
+            }
+            SpannedExprKind::ArrayLiteral { rows } => {
+                rows.iter().flatten().for_each(|e| self.visit(e));
+            }
+            SpannedExprKind::FieldAccess { base: inner, .. }
+            | SpannedExprKind::Group(inner)
+            | SpannedExprKind::ImplicitIntersection(inner)
+            | SpannedExprKind::SpillRange(inner) => self.visit(inner),
+            SpannedExprKind::Number(_)
+            | SpannedExprKind::Text(_)
+            | SpannedExprKind::Bool(_)
+            | SpannedExprKind::Blank
+            | SpannedExprKind::Error(_)
+            | SpannedExprKind::CellRef(_)
+            | SpannedExprKind::StructuredRef(_)
+            | SpannedExprKind::NameRef(_) => {}
+        }
+    }
+
+    /// Flag a direct arithmetic operand (ignoring parentheses) that refers to an empty cell.
+    fn check_operand(&mut self, expr: &SpannedExpr<String>) {
+        match &expr.kind {
+            SpannedExprKind::Group(inner) => self.check_operand(inner),
+            SpannedExprKind::CellRef(cell) => {
+                let Some(sheet_id) = self.resolve_sheet(&cell.sheet) else {
+                    return;
+                };
+                let Some(addr) = cell.addr.as_abs_cell_addr() else {
+                    return;
+                };
+                if self.engine.cell_is_empty(sheet_id, addr) {
+                    let message = format!("{} is blank and is treated as 0", self.source(expr));
+                    self.push(FormulaLintKind::BlankCellInArithmetic, span(expr), message);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_range(&mut self, range: &RangeRef<String>, expr: &SpannedExpr<String>) {
+        let Some(sheet_id) = self.resolve_sheet(&range.sheet) else {
+            return;
+        };
+        let (Some(a), Some(b)) = (range.start.as_abs_cell_addr(), range.end.as_abs_cell_addr())
+        else {
+            return;
+        };
+        let start = CellAddr {
+            row: a.row.min(b.row),
+            col: a.col.min(b.col),
+        };
+        let end = CellAddr {
+            row: a.row.max(b.row),
+            col: a.col.max(b.col),
+        };
+        if start != end && self.engine.area_is_empty(sheet_id, start, end) {
+            let message = format!("{} refers to an empty range", self.source(expr));
+            self.push(FormulaLintKind::EmptyRange, span(expr), message);
+        }
+    }
+
+    fn resolve_sheet(&self, sheet: &SheetReference<String>) -> Option<SheetId> {
+        match sheet {
+            SheetReference::Current => self.sheet_id,
+            SheetReference::Sheet(name) => self.engine.workbook.sheet_id(name),
+            SheetReference::SheetRange(..) | SheetReference::External(_) => None,
+        }
+    }
+
+    fn source(&self, expr: &SpannedExpr<String>) -> &str {
+        self.formula
+            .get(expr.span.start..expr.span.end)
+            .unwrap_or_default()
+    }
+}
+
+fn span(expr: &SpannedExpr<String>) -> Span {
+    Span::new(expr.span.start, expr.span.end)
+}
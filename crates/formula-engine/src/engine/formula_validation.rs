@@ -0,0 +1,359 @@
+//! Pre-commit validation of formula text.
+//!
+//! [`Engine::validate_formula`] runs the same parse the engine applies on commit, then resolves
+//! function names, defined names and table references against the current workbook without
+//! touching any cell. It reports the first problem that would make the committed formula fail to
+//! parse or evaluate to `#NAME?`, so editors can flag typos while the user is still typing.
+
+use formula_model::Table;
+
+use super::{with_defined_name_key, Engine, SheetId};
+use crate::locale::{canonicalize_formula, FormulaLocale};
+use crate::structured_refs::{parse_structured_ref, StructuredColumn, StructuredColumns};
+use crate::{
+    Expr, FunctionCall, LocaleConfig, ParseOptions, ReferenceStyle, SheetRef, Span, StructuredRef,
+    Token, TokenKind,
+};
+
+/// A problem found by [`Engine::validate_formula`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormulaDiagnostic {
+    pub message: String,
+    /// Byte offsets into the validated formula text (including any leading `=`).
+    pub span: Span,
+}
+
+/// The first identifier that does not resolve, in source order.
+enum Unresolved {
+    Function(String),
+    Name(String),
+    Table(String),
+    Column { table: String, column: String },
+}
+
+impl Unresolved {
+    fn identifier(&self) -> &str {
+        match self {
+            Unresolved::Function(name) | Unresolved::Name(name) | Unresolved::Table(name) => name,
+            Unresolved::Column { table, .. } => table,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Unresolved::Function(name) => format!("Unknown function: {name}"),
+            Unresolved::Name(name) => format!("Unknown name: {name}"),
+            Unresolved::Table(name) => format!("Unknown table: {name}"),
+            Unresolved::Column { table, column } => {
+                format!("Unknown column in table {table}: {column}")
+            }
+        }
+    }
+}
+
+impl Engine {
+    /// Check a canonical (en-US) formula as if it were entered on `sheet`, without mutating the
+    /// workbook.
+    ///
+    /// Returns the parse error, or the first function/name/table reference that would evaluate to
+    /// `#NAME?` (or `#REF!` for unknown table columns) once committed. Names are resolved in
+    /// `sheet`'s scope first, then at workbook scope; LET/LAMBDA locals are honored.
+    pub fn validate_formula(&self, sheet: &str, formula: &str) -> Result<(), FormulaDiagnostic> {
+        self.validate_formula_with_locale(sheet, formula, None)
+    }
+
+    /// Like [`Engine::validate_formula`], but for a formula entered in a locale-specific display
+    /// format. Spans refer to the localized text.
+    pub fn validate_formula_localized(
+        &self,
+        sheet: &str,
+        localized_formula: &str,
+        locale: &FormulaLocale,
+    ) -> Result<(), FormulaDiagnostic> {
+        self.validate_formula_with_locale(sheet, localized_formula, Some(locale))
+    }
+
+    fn validate_formula_with_locale(
+        &self,
+        sheet: &str,
+        formula: &str,
+        locale: Option<&FormulaLocale>,
+    ) -> Result<(), FormulaDiagnostic> {
+        let opts = ParseOptions {
+            locale: locale.map_or_else(LocaleConfig::en_us, |l| l.config),
+            reference_style: ReferenceStyle::A1,
+            normalize_relative_to: None,
+        };
+        let whole = Span::new(0, formula.len());
+
+        let parsed =
+            crate::parse_formula(formula, opts.clone()).map_err(|err| FormulaDiagnostic {
+                message: err.message,
+                span: err.span,
+            })?;
+        let ast = match locale {
+            Some(locale) => {
+                // Resolution runs on the canonical form (English function names), matching the
+                // localized commit path.
+                let canonical =
+                    canonicalize_formula(formula, locale).map_err(|err| FormulaDiagnostic {
+                        message: err.to_string(),
+                        span: whole,
+                    })?;
+                crate::parse_formula(&canonical, ParseOptions::default()).map_err(|err| {
+                    FormulaDiagnostic {
+                        message: err.message,
+                        span: whole,
+                    }
+                })?
+            }
+            None => parsed,
+        };
+
+        let mut resolver = NameResolver {
+            engine: self,
+            sheet_id: self.workbook.sheet_id(sheet),
+            locals: Vec::new(),
+            visited: Vec::new(),
+        };
+        let Some(unresolved) = resolver.check(&ast.expr) else {
+            return Ok(());
+        };
+
+        // The same identifier can appear several times (e.g. a LET local used after its scope
+        // ends), so point at the occurrence the resolver actually rejected.
+        let occurrence = resolver
+            .visited
+            .iter()
+            .filter(|name| crate::value::eq_case_insensitive(name, unresolved.identifier()))
+            .count()
+            .saturating_sub(1);
+        let span = crate::lex(formula, &opts)
+            .ok()
+            .and_then(|tokens| identifier_span(&tokens, unresolved.identifier(), occurrence))
+            .unwrap_or(whole);
+        Err(FormulaDiagnostic {
+            message: unresolved.message(),
+            span,
+        })
+    }
+
+    pub(super) fn defined_name_exists(&self, sheet_id: Option<SheetId>, name: &str) -> bool {
+        with_defined_name_key(name, |key| {
+            sheet_id
+                .and_then(|id| self.workbook.sheets.get(id))
+                .is_some_and(|sheet| sheet.names.contains_key(key))
+                || self.workbook.names.contains_key(key)
+        })
+    }
+
+    pub(super) fn find_table(&self, name: &str) -> Option<&Table> {
+        self.workbook
+            .sheets
+            .iter()
+            .enumerate()
+            .filter(|(sheet_id, _)| self.workbook.sheet_exists(*sheet_id))
+            .flat_map(|(_, sheet)| sheet.tables.iter())
+            .find(|t| {
+                t.name.eq_ignore_ascii_case(name) || t.display_name.eq_ignore_ascii_case(name)
+            })
+    }
+}
+
+struct NameResolver<'a> {
+    engine: &'a Engine,
+    sheet_id: Option<SheetId>,
+    /// LET/LAMBDA locals currently in scope (casefolded, without any `_xlpm.` prefix).
+    locals: Vec<String>,
+    /// Every identifier checked so far, in source order (without `_xlfn.`/`_xlpm.` prefixes).
+    visited: Vec<String>,
+}
+
+impl NameResolver<'_> {
+    fn is_local(&self, name: &str) -> bool {
+        let name = local_key(name);
+        self.locals.contains(&name)
+    }
+
+    fn visit(&mut self, name: &str) {
+        self.visited
+            .push(strip_prefix_ignore_case(name.trim(), "_xlpm.").to_string());
+    }
+
+    fn check(&mut self, expr: &Expr) -> Option<Unresolved> {
+        match expr {
+            Expr::NameRef(name) => {
+                self.visit(&name.name);
+                if name.workbook.is_some() {
+                    return None;
+                }
+                let resolved = match &name.sheet {
+                    Some(SheetRef::Sheet(sheet)) => self
+                        .engine
+                        .defined_name_exists(self.engine.workbook.sheet_id(sheet), &name.name),
+                    Some(SheetRef::SheetRange { .. }) => true,
+                    None => {
+                        self.is_local(&name.name)
+                            || self.engine.defined_name_exists(self.sheet_id, &name.name)
+                            || self.engine.find_table(&name.name).is_some()
+                    }
+                };
+                (!resolved).then(|| Unresolved::Name(name.name.clone()))
+            }
+            Expr::StructuredRef(sref) => self.check_structured_ref(sref),
+            Expr::FunctionCall(call) => self.check_call(call),
+            Expr::Call(call) => std::iter::once(call.callee.as_ref())
+                .chain(call.args.iter())
+                .find_map(|e| self.check(e)),
+            Expr::FieldAccess(access) => self.check(&access.base),
+            Expr::Array(array) => array.rows.iter().flatten().find_map(|e| self.check(e)),
+            Expr::Unary(unary) => self.check(&unary.expr),
+            Expr::Postfix(postfix) => self.check(&postfix.expr),
+            Expr::Binary(binary) => self
+                .check(&binary.left)
+                .or_else(|| self.check(&binary.right)),
+            Expr::Number(_)
+            | Expr::String(_)
+            | Expr::Boolean(_)
+            | Expr::Error(_)
+            | Expr::CellRef(_)
+            | Expr::ColRef(_)
+            | Expr::RowRef(_)
+            | Expr::Missing => None,
+        }
+    }
+
+    fn check_call(&mut self, call: &FunctionCall) -> Option<Unresolved> {
+        let name = call.name.name_upper.as_str();
+        match name {
+            // LET(name1, value1, [name2, value2, ...], calculation): each value sees the earlier
+            // bindings, and the calculation sees all of them.
+            "LET" => {
+                let scope_len = self.locals.len();
+                let mut result = None;
+                for (idx, arg) in call.args.iter().enumerate() {
+                    let is_binding_name = idx % 2 == 0 && idx + 1 < call.args.len();
+                    if is_binding_name {
+                        if let Expr::NameRef(binding) = arg {
+                            self.visit(&binding.name);
+                            self.locals.push(local_key(&binding.name));
+                            continue;
+                        }
+                    }
+                    result = self.check(arg);
+                    if result.is_some() {
+                        break;
+                    }
+                }
+                self.locals.truncate(scope_len);
+                result
+            }
+            // LAMBDA([param1, ...], calculation)
+            "LAMBDA" => {
+                let scope_len = self.locals.len();
+                let (body, params) = call.args.split_last()?;
+                let mut result = None;
+                for param in params {
+                    match param {
+                        Expr::NameRef(param) => {
+                            self.visit(&param.name);
+                            self.locals.push(local_key(&param.name));
+                        }
+                        other => {
+                            result = self.check(other);
+                            if result.is_some() {
+                                break;
+                            }
+                        }
+                    }
+                }
+                let result = result.or_else(|| self.check(body));
+                self.locals.truncate(scope_len);
+                result
+            }
+            _ => {
+                let original = if call.name.has_xlfn_prefix {
+                    &call.name.original["_xlfn.".len()..]
+                } else {
+                    call.name.original.as_str()
+                };
+                self.visit(original);
+                let resolved = crate::functions::lookup_function(name).is_some()
+                    || self.is_local(original)
+                    || self.engine.defined_name_exists(self.sheet_id, original);
+                if !resolved {
+                    return Some(Unresolved::Function(original.to_string()));
+                }
+                call.args.iter().find_map(|arg| self.check(arg))
+            }
+        }
+    }
+
+    fn check_structured_ref(&mut self, sref: &StructuredRef) -> Option<Unresolved> {
+        // `[@Col]`-style refs resolve against the table containing the formula cell, which is not
+        // known before commit; external workbook tables cannot be checked either.
+        if sref.workbook.is_some() {
+            return None;
+        }
+        let table_name = sref.table.as_deref()?;
+        self.visit(table_name);
+        let Some(table) = self.engine.find_table(table_name) else {
+            return Some(Unresolved::Table(table_name.to_string()));
+        };
+        let (parsed, _) = parse_structured_ref(&format!("{table_name}[{}]", sref.spec), 0)?;
+        let mut columns: Vec<&str> = Vec::new();
+        match &parsed.columns {
+            StructuredColumns::All => {}
+            StructuredColumns::Single(col) => columns.push(col),
+            StructuredColumns::Range { start, end } => columns.extend([start.as_str(), end]),
+            StructuredColumns::Multi(parts) => {
+                for part in parts {
+                    match part {
+                        StructuredColumn::Single(col) => columns.push(col),
+                        StructuredColumn::Range { start, end } => {
+                            columns.extend([start.as_str(), end])
+                        }
+                    }
+                }
+            }
+        }
+        columns
+            .into_iter()
+            .find(|col| {
+                !table
+                    .columns
+                    .iter()
+                    .any(|c| crate::value::eq_case_insensitive(&c.name, col))
+            })
+            .map(|col| Unresolved::Column {
+                table: table_name.to_string(),
+                column: col.to_string(),
+            })
+    }
+}
+
+pub(super) fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> &'a str {
+    text.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map_or(text, |_| &text[prefix.len()..])
+}
+
+pub(super) fn local_key(name: &str) -> String {
+    with_defined_name_key(
+        strip_prefix_ignore_case(name.trim(), "_xlpm."),
+        str::to_string,
+    )
+}
+
+/// Span of the `occurrence`-th (zero-based) identifier token spelling `name`, ignoring case and
+/// any `_xlfn.` prefix.
+fn identifier_span(tokens: &[Token], name: &str, occurrence: usize) -> Option<Span> {
+    let mut matches = tokens.iter().filter_map(|token| {
+        let (TokenKind::Ident(text) | TokenKind::QuotedIdent(text)) = &token.kind else {
+            return None;
+        };
+        let text = strip_prefix_ignore_case(text, "_xlfn.");
+        crate::value::eq_case_insensitive(text, name).then_some(token.span)
+    });
+    matches.nth(occurrence)
+}
@@ -0,0 +1,255 @@
+//! Excel's built-in print names.
+//!
+//! Excel keeps a sheet's print area and print titles in sheet-scoped defined names
+//! (`_xlnm.Print_Area` / `_xlnm.Print_Titles`, see [`formula_model::XLNM_PRINT_AREA`]) whose
+//! definitions are unions of absolute references on that sheet. The engine stores them like any
+//! other defined name, but [`Engine::print_area`] / [`Engine::print_titles`] read them back as
+//! typed ranges and the matching setters write the canonical name and definition Excel expects.
+
+use formula_model::{
+    push_column_label, push_sheet_name_a1, CellRef as ModelCellRef, ColRange, PrintTitles,
+    Range as ModelRange, RowRange,
+};
+
+use super::{Engine, EngineError, NameDefinition, NameScope, SheetId};
+use crate::{BinaryOp, Coord, Expr, LocaleConfig, ParseOptions, ReferenceStyle, SheetRef};
+
+/// Reserved `_xlnm.*` names that encode print settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BuiltinPrintName {
+    PrintArea,
+    PrintTitles,
+}
+
+impl BuiltinPrintName {
+    /// Recognize a reserved print name (ASCII case-insensitive, `_xlnm.` prefix required).
+    pub(super) fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        if name.eq_ignore_ascii_case(formula_model::XLNM_PRINT_AREA) {
+            Some(Self::PrintArea)
+        } else if name.eq_ignore_ascii_case(formula_model::XLNM_PRINT_TITLES) {
+            Some(Self::PrintTitles)
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn canonical_name(self) -> &'static str {
+        match self {
+            Self::PrintArea => formula_model::XLNM_PRINT_AREA,
+            Self::PrintTitles => formula_model::XLNM_PRINT_TITLES,
+        }
+    }
+}
+
+/// One area of a print name definition, in 0-based sheet coordinates.
+enum PrintArea {
+    Cells(ModelRange),
+    Rows(RowRange),
+    Cols(ColRange),
+}
+
+impl Engine {
+    /// Print area of `sheet` (the sheet-scoped `_xlnm.Print_Area` name), as 0-based ranges.
+    ///
+    /// Returns `None` when the sheet has no print area or its definition is not a union of cell
+    /// ranges on that sheet.
+    pub fn print_area(&self, sheet: &str) -> Option<Vec<ModelRange>> {
+        let areas = self.builtin_print_areas(sheet, BuiltinPrintName::PrintArea)?;
+        areas
+            .into_iter()
+            .map(|area| match area {
+                PrintArea::Cells(range) => Some(range),
+                PrintArea::Rows(_) | PrintArea::Cols(_) => None,
+            })
+            .collect()
+    }
+
+    /// Set (or clear, with `None` or an empty slice) the print area of `sheet`.
+    pub fn set_print_area(
+        &mut self,
+        sheet: &str,
+        area: Option<&[ModelRange]>,
+    ) -> Result<(), EngineError> {
+        let Some(sheet_name) = self.print_name_sheet(sheet) else {
+            return Ok(());
+        };
+        let Some(area) = area.filter(|area| !area.is_empty()) else {
+            self.remove_name(formula_model::XLNM_PRINT_AREA, NameScope::Sheet(sheet));
+            return Ok(());
+        };
+
+        let mut definition = String::new();
+        for (idx, range) in area.iter().enumerate() {
+            if idx > 0 {
+                definition.push(',');
+            }
+            push_sheet_name_a1(&mut definition, &sheet_name);
+            definition.push('!');
+            push_abs_cell(&mut definition, range.start);
+            if !range.is_single_cell() {
+                definition.push(':');
+                push_abs_cell(&mut definition, range.end);
+            }
+        }
+        self.define_name(
+            formula_model::XLNM_PRINT_AREA,
+            NameScope::Sheet(sheet),
+            NameDefinition::Reference(definition),
+        )
+    }
+
+    /// Print titles of `sheet` (the sheet-scoped `_xlnm.Print_Titles` name): the rows and/or
+    /// columns repeated on every printed page.
+    pub fn print_titles(&self, sheet: &str) -> Option<PrintTitles> {
+        let areas = self.builtin_print_areas(sheet, BuiltinPrintName::PrintTitles)?;
+        let mut titles = PrintTitles::default();
+        for area in areas {
+            match area {
+                PrintArea::Rows(rows) if titles.repeat_rows.is_none() => {
+                    titles.repeat_rows = Some(rows)
+                }
+                PrintArea::Cols(cols) if titles.repeat_cols.is_none() => {
+                    titles.repeat_cols = Some(cols)
+                }
+                _ => return None,
+            }
+        }
+        Some(titles)
+    }
+
+    /// Set (or clear, with `None` or empty titles) the print titles of `sheet`.
+    pub fn set_print_titles(
+        &mut self,
+        sheet: &str,
+        titles: Option<PrintTitles>,
+    ) -> Result<(), EngineError> {
+        let Some(sheet_name) = self.print_name_sheet(sheet) else {
+            return Ok(());
+        };
+        let Some(titles) = titles.filter(|t| t.repeat_rows.is_some() || t.repeat_cols.is_some())
+        else {
+            self.remove_name(formula_model::XLNM_PRINT_TITLES, NameScope::Sheet(sheet));
+            return Ok(());
+        };
+
+        // Excel writes repeated rows before repeated columns.
+        let mut definition = String::new();
+        if let Some(rows) = titles.repeat_rows.map(RowRange::normalized) {
+            push_sheet_name_a1(&mut definition, &sheet_name);
+            definition.push_str(&format!("!${}:${}", rows.start + 1, rows.end + 1));
+        }
+        if let Some(cols) = titles.repeat_cols.map(ColRange::normalized) {
+            if !definition.is_empty() {
+                definition.push(',');
+            }
+            push_sheet_name_a1(&mut definition, &sheet_name);
+            definition.push_str("!$");
+            push_column_label(cols.start, &mut definition);
+            definition.push_str(":$");
+            push_column_label(cols.end, &mut definition);
+        }
+        self.define_name(
+            formula_model::XLNM_PRINT_TITLES,
+            NameScope::Sheet(sheet),
+            NameDefinition::Reference(definition),
+        )
+    }
+
+    /// Current name of `sheet` for use in print name definitions, if the sheet exists.
+    fn print_name_sheet(&self, sheet: &str) -> Option<String> {
+        let id = self.workbook.sheet_id(sheet)?;
+        self.workbook.sheet_name(id).map(str::to_string)
+    }
+
+    fn builtin_print_areas(&self, sheet: &str, name: BuiltinPrintName) -> Option<Vec<PrintArea>> {
+        let sheet_id = self.workbook.sheet_id(sheet)?;
+        let formula = match self.get_name(name.canonical_name(), NameScope::Sheet(sheet))? {
+            NameDefinition::Reference(formula) | NameDefinition::Formula(formula) => formula,
+            NameDefinition::Constant(_) => return None,
+        };
+        let ast = crate::parse_formula(
+            formula,
+            ParseOptions {
+                locale: LocaleConfig::en_us(),
+                reference_style: ReferenceStyle::A1,
+                normalize_relative_to: None,
+            },
+        )
+        .ok()?;
+
+        let mut areas = Vec::new();
+        self.collect_print_areas(&ast.expr, sheet_id, &mut areas)?;
+        Some(areas)
+    }
+
+    fn collect_print_areas(
+        &self,
+        expr: &Expr,
+        sheet_id: SheetId,
+        out: &mut Vec<PrintArea>,
+    ) -> Option<()> {
+        let on_sheet = |sheet: &Option<SheetRef>| match sheet {
+            None => true,
+            Some(sheet) => {
+                sheet
+                    .as_single_sheet()
+                    .and_then(|name| self.workbook.sheet_id(name))
+                    == Some(sheet_id)
+            }
+        };
+
+        match expr {
+            Expr::Binary(binary) if binary.op == BinaryOp::Union => {
+                self.collect_print_areas(&binary.left, sheet_id, out)?;
+                self.collect_print_areas(&binary.right, sheet_id, out)
+            }
+            Expr::CellRef(cell) if on_sheet(&cell.sheet) => {
+                let cell = ModelCellRef::new(coord_index(&cell.row)?, coord_index(&cell.col)?);
+                out.push(PrintArea::Cells(ModelRange::new(cell, cell)));
+                Some(())
+            }
+            Expr::Binary(binary) if binary.op == BinaryOp::Range => {
+                match (binary.left.as_ref(), binary.right.as_ref()) {
+                    (Expr::CellRef(start), Expr::CellRef(end)) if on_sheet(&start.sheet) => {
+                        let start =
+                            ModelCellRef::new(coord_index(&start.row)?, coord_index(&start.col)?);
+                        let end = ModelCellRef::new(coord_index(&end.row)?, coord_index(&end.col)?);
+                        out.push(PrintArea::Cells(ModelRange::new(start, end)));
+                    }
+                    (Expr::RowRef(start), Expr::RowRef(end)) if on_sheet(&start.sheet) => {
+                        let rows = RowRange {
+                            start: coord_index(&start.row)?,
+                            end: coord_index(&end.row)?,
+                        };
+                        out.push(PrintArea::Rows(rows.normalized()));
+                    }
+                    (Expr::ColRef(start), Expr::ColRef(end)) if on_sheet(&start.sheet) => {
+                        let cols = ColRange {
+                            start: coord_index(&start.col)?,
+                            end: coord_index(&end.col)?,
+                        };
+                        out.push(PrintArea::Cols(cols.normalized()));
+                    }
+                    _ => return None,
+                }
+                Some(())
+            }
+            _ => None,
+        }
+    }
+}
+
+fn coord_index(coord: &Coord) -> Option<u32> {
+    match coord {
+        Coord::A1 { index, .. } => Some(*index),
+        Coord::Offset(_) => None,
+    }
+}
+
+fn push_abs_cell(out: &mut String, cell: ModelCellRef) {
+    out.push('$');
+    push_column_label(cell.col, out);
+    out.push('$');
+    out.push_str(&(cell.row + 1).to_string());
+}
@@ -0,0 +1,786 @@
+//! Versioned binary snapshots of full engine state, used for fast workbook reopen.
+//!
+//! Unlike the JSON workbook export (which only keeps cell inputs), a snapshot also keeps each
+//! formula's parsed AST and every cached value. Restoring skips lexing/parsing, and no cell is
+//! re-evaluated until an input changes. The dependency graph and bytecode programs are rebuilt
+//! from the stored ASTs, since they hold process-local state (interned programs, `Arc`s).
+//!
+//! Layout: an 8-byte magic, a little-endian `u32` format version, then a CBOR payload.
+//!
+//! Not captured: external value/data providers, pivot tables, and [`EngineInfo`] metadata, which
+//! are host-provided at runtime. Cached values that are not plain scalars (rich values, or spills
+//! containing them) are dropped and their formulas are left dirty for the next recalc.
+//!
+//! [`EngineInfo`]: super::EngineInfo
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use formula_model::{ColProperties, GridLimits, RowProperties, StyleTable, Table};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::calc_settings::{CalcSettings, CalculationMode};
+use crate::date::ExcelDateSystem;
+use crate::eval::CellAddr;
+use crate::graph::{CellDeps, Precedent};
+use crate::locale::{DateOrder, TextCollation, ValueLocaleConfig};
+use crate::metadata::FormatRun;
+use crate::style_patch::{FormatRun as StyleFormatRun, StylePatch, StylePatchTable};
+use crate::value::{Array, ErrorKind, Value};
+
+use super::{
+    cell_id_from_key, CellKey, Engine, EngineError, NameDefinition, NameScope, Sheet, SheetId,
+    Spill, Workbook,
+};
+
+const SNAPSHOT_MAGIC: &[u8; 8] = b"FMLENGSN";
+const HEADER_LEN: usize = 8 /* magic */ + 4 /* version */;
+
+/// Current [`Engine::to_binary`] format version.
+///
+/// Bump this whenever the payload layout changes; [`Engine::from_binary`] rejects other versions
+/// so callers can fall back to a full load.
+pub const ENGINE_SNAPSHOT_VERSION: u32 = 4;
+
+#[derive(Debug, Error)]
+pub enum EngineSnapshotError {
+    #[error("not an engine snapshot (missing magic header)")]
+    InvalidMagic,
+    #[error("unsupported engine snapshot version {0} (expected {ENGINE_SNAPSHOT_VERSION})")]
+    UnsupportedVersion(u32),
+    #[error("failed to encode engine snapshot: {0}")]
+    Encode(String),
+    #[error("failed to decode engine snapshot: {0}")]
+    Decode(String),
+    #[error("engine snapshot is inconsistent: {0}")]
+    Corrupt(&'static str),
+    #[error(transparent)]
+    Engine(#[from] EngineError),
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotV4 {
+    calc_settings: CalcSettings,
+    date_system: SnapshotDateSystem,
+    value_locale: SnapshotValueLocale,
+    /// [`FormulaLocale`](crate::locale::FormulaLocale) id used to render errors in display text.
+    display_locale: String,
+    text_codepage: u16,
+    bytecode_enabled: bool,
+    external_refs_volatile: bool,
+    grid_limits: GridLimits,
+    workbook_directory: Option<String>,
+    workbook_filename: Option<String>,
+    styles: StyleTable,
+    style_patches: Vec<(u32, StylePatch)>,
+    /// Indexed by sheet id; deleted sheets are `None` so ids stay stable.
+    sheets: Vec<Option<SnapshotSheet>>,
+    sheet_order: Vec<SheetId>,
+    names: Vec<SnapshotName>,
+    spills: Vec<SnapshotSpill>,
+    /// `(origin, blocker)` pairs for spills currently evaluating to `#SPILL!`.
+    blocked_spills: Vec<(SnapshotCellKey, SnapshotCellKey)>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum SnapshotDateSystem {
+    Excel1900 { lotus_compat: bool },
+    Excel1904,
+    Excel1900Proleptic,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotValueLocale {
+    decimal_sep: char,
+    thousands_sep: char,
+    date_sep: char,
+    time_sep: char,
+    date_order: SnapshotDateOrder,
+    collation: SnapshotTextCollation,
+}
+
+#[derive(Serialize, Deserialize)]
+enum SnapshotDateOrder {
+    Mdy,
+    Dmy,
+    Ymd,
+}
+
+#[derive(Serialize, Deserialize)]
+enum SnapshotTextCollation {
+    Ordinal,
+    Linguistic,
+    Spanish,
+}
+
+impl SnapshotValueLocale {
+    fn from_config(config: ValueLocaleConfig) -> Self {
+        Self {
+            decimal_sep: config.separators.decimal_sep,
+            thousands_sep: config.separators.thousands_sep,
+            date_sep: config.separators.date_sep,
+            time_sep: config.separators.time_sep,
+            date_order: match config.date_order {
+                DateOrder::MDY => SnapshotDateOrder::Mdy,
+                DateOrder::DMY => SnapshotDateOrder::Dmy,
+                DateOrder::YMD => SnapshotDateOrder::Ymd,
+            },
+            collation: match config.collation {
+                TextCollation::Ordinal => SnapshotTextCollation::Ordinal,
+                TextCollation::Linguistic => SnapshotTextCollation::Linguistic,
+                TextCollation::Spanish => SnapshotTextCollation::Spanish,
+            },
+        }
+    }
+
+    fn into_config(self) -> ValueLocaleConfig {
+        let separators = formula_format::Locale {
+            decimal_sep: self.decimal_sep,
+            thousands_sep: self.thousands_sep,
+            date_sep: self.date_sep,
+            time_sep: self.time_sep,
+        };
+        let date_order = match self.date_order {
+            SnapshotDateOrder::Mdy => DateOrder::MDY,
+            SnapshotDateOrder::Dmy => DateOrder::DMY,
+            SnapshotDateOrder::Ymd => DateOrder::YMD,
+        };
+        let collation = match self.collation {
+            SnapshotTextCollation::Ordinal => TextCollation::Ordinal,
+            SnapshotTextCollation::Linguistic => TextCollation::Linguistic,
+            SnapshotTextCollation::Spanish => TextCollation::Spanish,
+        };
+        ValueLocaleConfig::new(separators, date_order).with_collation(collation)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotSheet {
+    key: String,
+    display_name: String,
+    row_count: u32,
+    col_count: u32,
+    default_style_id: Option<u32>,
+    default_col_width: Option<f32>,
+    sheet_protection_enabled: bool,
+    origin: Option<(u32, u32)>,
+    tables: Vec<Table>,
+    row_properties: Vec<(u32, RowProperties)>,
+    filter_hidden_rows: Vec<u32>,
+    col_properties: Vec<(u32, ColProperties)>,
+    format_runs_by_col: Vec<(u32, Vec<SnapshotFormatRun>)>,
+    dc_default_style_id: u32,
+    dc_row_style_ids: Vec<(u32, u32)>,
+    dc_col_style_ids: Vec<(u32, u32)>,
+    dc_format_runs_by_col: Vec<(u32, Vec<StyleFormatRun>)>,
+    /// `(row, col, style_id)`.
+    dc_cell_style_ids: Vec<(u32, u32, u32)>,
+    cells: Vec<SnapshotCell>,
+}
+
+/// `(start_row, end_row_exclusive, style_id)` of a [`FormatRun`].
+type SnapshotFormatRun = (u32, u32, u32);
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotCell {
+    row: u32,
+    col: u32,
+    /// `None` when the cached value is not a plain scalar; the formula is recalculated instead.
+    value: Option<SnapshotValue>,
+    formula: Option<SnapshotFormula>,
+    style_id: u32,
+    number_format: Option<String>,
+    phonetic: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotFormula {
+    text: String,
+    expr: crate::Expr,
+}
+
+#[derive(Serialize, Deserialize)]
+enum SnapshotValue {
+    Blank,
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(String),
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotName {
+    /// Scope sheet id, or `None` for workbook scope.
+    sheet: Option<SheetId>,
+    name: String,
+    definition: SnapshotNameDefinition,
+}
+
+#[derive(Serialize, Deserialize)]
+enum SnapshotNameDefinition {
+    Constant(SnapshotValue),
+    Reference(String),
+    Formula(String),
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct SnapshotCellKey {
+    sheet: SheetId,
+    row: u32,
+    col: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotSpill {
+    origin: SnapshotCellKey,
+    end: (u32, u32),
+    rows: usize,
+    cols: usize,
+    values: Vec<SnapshotValue>,
+}
+
+impl SnapshotValue {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Blank => Some(Self::Blank),
+            Value::Number(n) => Some(Self::Number(*n)),
+            Value::Text(s) => Some(Self::Text(s.clone())),
+            Value::Bool(b) => Some(Self::Bool(*b)),
+            Value::Error(e) => Some(Self::Error(e.as_code().to_string())),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            Self::Blank => Value::Blank,
+            Self::Number(n) => Value::Number(n),
+            Self::Text(s) => Value::Text(s),
+            Self::Bool(b) => Value::Bool(b),
+            Self::Error(code) => {
+                Value::Error(ErrorKind::from_code(&code).unwrap_or(ErrorKind::Unknown))
+            }
+        }
+    }
+}
+
+impl SnapshotCellKey {
+    fn from_key(key: CellKey) -> Self {
+        Self {
+            sheet: key.sheet,
+            row: key.addr.row,
+            col: key.addr.col,
+        }
+    }
+
+    fn key(self) -> CellKey {
+        CellKey {
+            sheet: self.sheet,
+            addr: CellAddr {
+                row: self.row,
+                col: self.col,
+            },
+        }
+    }
+}
+
+fn sorted_pairs<K: Copy + Ord, V: Clone>(entries: impl Iterator<Item = (K, V)>) -> Vec<(K, V)> {
+    let mut out: Vec<(K, V)> = entries.collect();
+    out.sort_by_key(|(k, _)| *k);
+    out
+}
+
+impl Engine {
+    /// Serialize the full engine state into a versioned binary snapshot.
+    ///
+    /// Reopening with [`Engine::from_binary`] restores formulas from their parsed ASTs and keeps
+    /// cached values, so large workbooks load without re-parsing or recalculating. External
+    /// providers, pivot tables and [`EngineInfo`](super::EngineInfo) are not captured; hosts
+    /// re-attach them after restoring.
+    pub fn to_binary(&self) -> Result<Vec<u8>, EngineSnapshotError> {
+        let snapshot = self.build_snapshot()?;
+        let mut out = Vec::new();
+        out.extend_from_slice(SNAPSHOT_MAGIC);
+        out.extend_from_slice(&ENGINE_SNAPSHOT_VERSION.to_le_bytes());
+        ciborium::into_writer(&snapshot, &mut out)
+            .map_err(|err| EngineSnapshotError::Encode(err.to_string()))?;
+        Ok(out)
+    }
+
+    /// Restore an engine from bytes produced by [`Engine::to_binary`].
+    ///
+    /// Fails with [`EngineSnapshotError::UnsupportedVersion`] for snapshots written by a different
+    /// format version; callers should then fall back to a full workbook load.
+    pub fn from_binary(bytes: &[u8]) -> Result<Engine, EngineSnapshotError> {
+        if bytes.len() < HEADER_LEN || &bytes[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC {
+            return Err(EngineSnapshotError::InvalidMagic);
+        }
+        let mut version = [0u8; 4];
+        version.copy_from_slice(&bytes[SNAPSHOT_MAGIC.len()..HEADER_LEN]);
+        let version = u32::from_le_bytes(version);
+        if version != ENGINE_SNAPSHOT_VERSION {
+            return Err(EngineSnapshotError::UnsupportedVersion(version));
+        }
+        let snapshot: SnapshotV4 = ciborium::from_reader(&bytes[HEADER_LEN..])
+            .map_err(|err| EngineSnapshotError::Decode(err.to_string()))?;
+
+        let mut engine = Engine::new();
+        engine.restore_snapshot(snapshot)?;
+        Ok(engine)
+    }
+
+    fn build_snapshot(&self) -> Result<SnapshotV4, EngineSnapshotError> {
+        let workbook = &self.workbook;
+
+        let mut uncaptured_spills: HashSet<CellKey> = HashSet::new();
+        let mut spills = Vec::with_capacity(self.spills.by_origin.len());
+        for (origin, spill) in &self.spills.by_origin {
+            let values: Option<Vec<SnapshotValue>> = spill
+                .array
+                .values
+                .iter()
+                .map(SnapshotValue::from_value)
+                .collect();
+            // Spills holding rich values are recomputed on load instead: the origin is stored
+            // without a cached value, which leaves it dirty after restore.
+            let Some(values) = values else {
+                uncaptured_spills.insert(*origin);
+                continue;
+            };
+            spills.push(SnapshotSpill {
+                origin: SnapshotCellKey::from_key(*origin),
+                end: (spill.end.row, spill.end.col),
+                rows: spill.array.rows,
+                cols: spill.array.cols,
+                values,
+            });
+        }
+        spills.sort_by_key(|spill| (spill.origin.sheet, spill.origin.row, spill.origin.col));
+
+        let mut sheets = Vec::with_capacity(workbook.sheets.len());
+        for (sheet_id, sheet) in workbook.sheets.iter().enumerate() {
+            let key = workbook.sheet_keys.get(sheet_id).cloned().flatten();
+            let display_name = workbook
+                .sheet_display_names
+                .get(sheet_id)
+                .cloned()
+                .flatten();
+            let (Some(key), Some(display_name)) = (key, display_name) else {
+                sheets.push(None);
+                continue;
+            };
+
+            let mut cells = Vec::with_capacity(sheet.cells.len());
+            for (addr, cell) in &sheet.cells {
+                let key = CellKey {
+                    sheet: sheet_id,
+                    addr: *addr,
+                };
+                let value = if uncaptured_spills.contains(&key) {
+                    None
+                } else {
+                    SnapshotValue::from_value(&cell.value)
+                };
+                let formula = match &cell.formula {
+                    Some(text) => Some(self.snapshot_formula(key, text)?),
+                    None => None,
+                };
+                cells.push(SnapshotCell {
+                    row: addr.row,
+                    col: addr.col,
+                    value,
+                    formula,
+                    style_id: cell.style_id,
+                    number_format: cell.number_format.clone(),
+                    phonetic: cell.phonetic.clone(),
+                });
+            }
+            cells.sort_by_key(|cell| (cell.row, cell.col));
+
+            let mut dc_cell_style_ids: Vec<(u32, u32, u32)> = sheet
+                .dc_cell_style_ids
+                .iter()
+                .map(|(addr, style_id)| (addr.row, addr.col, *style_id))
+                .collect();
+            dc_cell_style_ids.sort_unstable();
+
+            sheets.push(Some(SnapshotSheet {
+                key,
+                display_name,
+                row_count: sheet.row_count,
+                col_count: sheet.col_count,
+                default_style_id: sheet.default_style_id,
+                default_col_width: sheet.default_col_width,
+                sheet_protection_enabled: sheet.sheet_protection_enabled,
+                origin: sheet.origin.map(|addr| (addr.row, addr.col)),
+                tables: sheet.tables.clone(),
+                row_properties: sorted_pairs(
+                    sheet.row_properties.iter().map(|(k, v)| (*k, v.clone())),
+                ),
+                filter_hidden_rows: sheet.filter_hidden_rows.iter().copied().collect(),
+                col_properties: sorted_pairs(
+                    sheet.col_properties.iter().map(|(k, v)| (*k, v.clone())),
+                ),
+                format_runs_by_col: sorted_pairs(sheet.format_runs_by_col.iter().map(
+                    |(col, runs)| {
+                        let runs = runs
+                            .iter()
+                            .map(|run| (run.start_row, run.end_row_exclusive, run.style_id))
+                            .collect();
+                        (*col, runs)
+                    },
+                )),
+                dc_default_style_id: sheet.dc_default_style_id,
+                dc_row_style_ids: sorted_pairs(
+                    sheet.dc_row_style_ids.iter().map(|(k, v)| (*k, *v)),
+                ),
+                dc_col_style_ids: sorted_pairs(
+                    sheet.dc_col_style_ids.iter().map(|(k, v)| (*k, *v)),
+                ),
+                dc_format_runs_by_col: sorted_pairs(
+                    sheet
+                        .dc_format_runs_by_col
+                        .iter()
+                        .map(|(k, v)| (*k, v.clone())),
+                ),
+                dc_cell_style_ids,
+                cells,
+            }));
+        }
+
+        let mut names = Vec::new();
+        let scoped_names = workbook
+            .names
+            .iter()
+            .map(|(name, def)| (None, name, def))
+            .chain(
+                workbook
+                    .sheets
+                    .iter()
+                    .enumerate()
+                    .filter(|(sheet_id, _)| workbook.sheet_exists(*sheet_id))
+                    .flat_map(|(sheet_id, sheet)| {
+                        sheet
+                            .names
+                            .iter()
+                            .map(move |(name, def)| (Some(sheet_id), name, def))
+                    }),
+            );
+        for (sheet, name, defined) in scoped_names {
+            let definition = match &defined.definition {
+                NameDefinition::Constant(value) => SnapshotNameDefinition::Constant(
+                    SnapshotValue::from_value(value).ok_or_else(|| {
+                        EngineSnapshotError::Encode(format!(
+                            "defined name {name:?} holds a non-scalar constant"
+                        ))
+                    })?,
+                ),
+                NameDefinition::Reference(formula) => {
+                    SnapshotNameDefinition::Reference(formula.clone())
+                }
+                NameDefinition::Formula(formula) => {
+                    SnapshotNameDefinition::Formula(formula.clone())
+                }
+            };
+            names.push(SnapshotName {
+                sheet,
+                name: name.clone(),
+                definition,
+            });
+        }
+        names.sort_by(|a, b| (a.sheet, &a.name).cmp(&(b.sheet, &b.name)));
+
+        let mut blocked_spills: Vec<(SnapshotCellKey, SnapshotCellKey)> = self
+            .spills
+            .blocked_by_origin
+            .iter()
+            .map(|(origin, blocked)| {
+                (
+                    SnapshotCellKey::from_key(*origin),
+                    SnapshotCellKey::from_key(blocked.blocker),
+                )
+            })
+            .collect();
+        blocked_spills.sort_by_key(|(origin, _)| (origin.sheet, origin.row, origin.col));
+
+        let mut style_patches: Vec<(u32, StylePatch)> = self
+            .style_table
+            .iter()
+            .map(|(style_id, patch)| (style_id, patch.clone()))
+            .collect();
+        style_patches.sort_by_key(|(style_id, _)| *style_id);
+
+        Ok(SnapshotV4 {
+            calc_settings: self.calc_settings.clone(),
+            date_system: match self.date_system {
+                ExcelDateSystem::Excel1900 { lotus_compat } => {
+                    SnapshotDateSystem::Excel1900 { lotus_compat }
+                }
+                ExcelDateSystem::Excel1904 => SnapshotDateSystem::Excel1904,
+                ExcelDateSystem::Excel1900Proleptic => SnapshotDateSystem::Excel1900Proleptic,
+            },
+            value_locale: SnapshotValueLocale::from_config(self.value_locale),
+            display_locale: self.display_locale.id.to_string(),
+            text_codepage: self.text_codepage,
+            bytecode_enabled: self.bytecode_enabled,
+            external_refs_volatile: self.external_refs_volatile,
+            grid_limits: workbook.grid_limits,
+            workbook_directory: workbook.workbook_directory.clone(),
+            workbook_filename: workbook.workbook_filename.clone(),
+            styles: workbook.styles.clone(),
+            style_patches,
+            sheets,
+            sheet_order: workbook.sheet_order.clone(),
+            names,
+            spills,
+            blocked_spills,
+        })
+    }
+
+    fn snapshot_formula(
+        &self,
+        key: CellKey,
+        text: &str,
+    ) -> Result<SnapshotFormula, EngineSnapshotError> {
+        // Stored formulas are canonical A1 text; parse once here so every reopen can skip it.
+        let origin = crate::CellAddr::new(key.addr.row, key.addr.col);
+        let parsed = crate::parse_formula(
+            text,
+            crate::ParseOptions {
+                locale: crate::LocaleConfig::en_us(),
+                reference_style: crate::ReferenceStyle::A1,
+                normalize_relative_to: Some(origin),
+            },
+        )
+        .map_err(EngineError::from)?;
+        Ok(SnapshotFormula {
+            text: text.to_string(),
+            expr: parsed.expr,
+        })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: SnapshotV4) -> Result<(), EngineSnapshotError> {
+        // Stay in manual mode while rebuilding so `define_name` & co. do not trigger recalcs.
+        self.calc_settings.calculation_mode = CalculationMode::Manual;
+        self.date_system = match snapshot.date_system {
+            SnapshotDateSystem::Excel1900 { lotus_compat } => {
+                ExcelDateSystem::Excel1900 { lotus_compat }
+            }
+            SnapshotDateSystem::Excel1904 => ExcelDateSystem::Excel1904,
+            SnapshotDateSystem::Excel1900Proleptic => ExcelDateSystem::Excel1900Proleptic,
+        };
+        self.value_locale = snapshot.value_locale.into_config();
+        self.display_locale = crate::locale::get_locale(&snapshot.display_locale)
+            .ok_or(EngineSnapshotError::Corrupt("unknown display locale"))?;
+        self.text_codepage = snapshot.text_codepage;
+        self.bytecode_enabled = snapshot.bytecode_enabled;
+        self.external_refs_volatile = snapshot.external_refs_volatile;
+
+        let mut style_table = StylePatchTable::new();
+        for (style_id, patch) in snapshot.style_patches {
+            style_table.insert(style_id, patch);
+        }
+        self.style_table = Arc::new(style_table);
+
+        let mut workbook = Workbook {
+            text_codepage: snapshot.text_codepage,
+            grid_limits: snapshot.grid_limits,
+            workbook_directory: snapshot.workbook_directory,
+            workbook_filename: snapshot.workbook_filename,
+            styles: snapshot.styles,
+            ..Workbook::default()
+        };
+        let mut cells_by_sheet = Vec::with_capacity(snapshot.sheets.len());
+        for (sheet_id, sheet) in snapshot.sheets.into_iter().enumerate() {
+            let Some(sheet) = sheet else {
+                workbook.sheets.push(Sheet::default());
+                workbook.sheet_keys.push(None);
+                workbook.sheet_display_names.push(None);
+                cells_by_sheet.push(Vec::new());
+                continue;
+            };
+            workbook
+                .sheet_key_to_id
+                .insert(Workbook::sheet_key(&sheet.key), sheet_id);
+            workbook
+                .sheet_display_name_to_id
+                .insert(Workbook::sheet_key(&sheet.display_name), sheet_id);
+            workbook.sheet_keys.push(Some(sheet.key));
+            workbook.sheet_display_names.push(Some(sheet.display_name));
+            workbook.sheets.push(Sheet {
+                default_style_id: sheet.default_style_id,
+                default_col_width: sheet.default_col_width,
+                sheet_protection_enabled: sheet.sheet_protection_enabled,
+                origin: sheet.origin.map(|(row, col)| CellAddr { row, col }),
+                row_count: sheet.row_count,
+                col_count: sheet.col_count,
+                tables: sheet.tables,
+                row_properties: sheet.row_properties.into_iter().collect(),
+                filter_hidden_rows: sheet.filter_hidden_rows.into_iter().collect(),
+                col_properties: sheet.col_properties.into_iter().collect(),
+                format_runs_by_col: sheet
+                    .format_runs_by_col
+                    .into_iter()
+                    .map(|(col, runs)| {
+                        let runs = runs
+                            .into_iter()
+                            .map(|(start_row, end_row_exclusive, style_id)| FormatRun {
+                                start_row,
+                                end_row_exclusive,
+                                style_id,
+                            })
+                            .collect();
+                        (col, runs)
+                    })
+                    .collect(),
+                dc_default_style_id: sheet.dc_default_style_id,
+                dc_row_style_ids: sheet.dc_row_style_ids.into_iter().collect(),
+                dc_col_style_ids: sheet.dc_col_style_ids.into_iter().collect(),
+                dc_format_runs_by_col: sheet.dc_format_runs_by_col.into_iter().collect(),
+                dc_cell_style_ids: sheet
+                    .dc_cell_style_ids
+                    .into_iter()
+                    .map(|(row, col, style_id)| (CellAddr { row, col }, style_id))
+                    .collect(),
+                ..Sheet::default()
+            });
+            cells_by_sheet.push(sheet.cells);
+        }
+        if snapshot
+            .sheet_order
+            .iter()
+            .any(|&id| !workbook.sheet_exists(id))
+        {
+            return Err(EngineSnapshotError::Corrupt(
+                "sheet order references a missing sheet",
+            ));
+        }
+        workbook.sheet_order = snapshot.sheet_order;
+        workbook.rebuild_sheet_tab_index_by_id();
+        self.workbook = workbook;
+
+        for name in snapshot.names {
+            // `define_name` takes `&mut self`, so copy the scope sheet key out first.
+            let scope_sheet = match name.sheet {
+                None => None,
+                Some(sheet_id) => Some(
+                    self.workbook
+                        .sheet_key_name(sheet_id)
+                        .ok_or(EngineSnapshotError::Corrupt(
+                            "defined name scoped to a missing sheet",
+                        ))?
+                        .to_string(),
+                ),
+            };
+            let definition = match name.definition {
+                SnapshotNameDefinition::Constant(value) => {
+                    NameDefinition::Constant(value.into_value())
+                }
+                SnapshotNameDefinition::Reference(formula) => NameDefinition::Reference(formula),
+                SnapshotNameDefinition::Formula(formula) => NameDefinition::Formula(formula),
+            };
+            let scope = match scope_sheet.as_deref() {
+                Some(sheet) => NameScope::Sheet(sheet),
+                None => NameScope::Workbook,
+            };
+            self.define_name(&name.name, scope, definition)?;
+        }
+
+        // Spill footprints must exist before formulas are compiled so spill-range references
+        // (`A1#`) resolve to the restored extent.
+        for spill in snapshot.spills {
+            if spill.values.len() != spill.rows.saturating_mul(spill.cols) {
+                return Err(EngineSnapshotError::Corrupt("spill array size mismatch"));
+            }
+            let origin = spill.origin.key();
+            let array = Array::new(
+                spill.rows,
+                spill.cols,
+                spill
+                    .values
+                    .into_iter()
+                    .map(SnapshotValue::into_value)
+                    .collect(),
+            );
+            self.restore_spill(
+                origin,
+                CellAddr {
+                    row: spill.end.0,
+                    col: spill.end.1,
+                },
+                array,
+            );
+        }
+        for (origin, blocker) in snapshot.blocked_spills {
+            self.record_blocked_spill(origin.key(), blocker.key());
+        }
+
+        let mut needs_recalc: Vec<CellKey> = Vec::new();
+        for (sheet_id, cells) in cells_by_sheet.into_iter().enumerate() {
+            for snapshot_cell in cells {
+                let key = CellKey {
+                    sheet: sheet_id,
+                    addr: CellAddr {
+                        row: snapshot_cell.row,
+                        col: snapshot_cell.col,
+                    },
+                };
+                let is_formula = snapshot_cell.formula.is_some();
+                if let Some(formula) = snapshot_cell.formula {
+                    self.install_cell_formula(key, &formula.text, &formula.expr);
+                }
+                let value = match snapshot_cell.value {
+                    Some(value) => value.into_value(),
+                    None => {
+                        if is_formula {
+                            needs_recalc.push(key);
+                        }
+                        Value::Blank
+                    }
+                };
+                let cell = self.workbook.get_or_create_cell_mut(key);
+                cell.value = value;
+                cell.style_id = snapshot_cell.style_id;
+                cell.number_format = snapshot_cell.number_format;
+                cell.phonetic = snapshot_cell.phonetic;
+            }
+        }
+
+        self.calc_graph.clear_dirty();
+        self.dirty.clear();
+        self.dirty_reasons.clear();
+        for key in needs_recalc {
+            self.mark_dirty_including_self_with_reasons(key);
+            self.calc_graph.mark_dirty(cell_id_from_key(key));
+        }
+        self.sync_dirty_from_calc_graph();
+
+        self.calc_settings = snapshot.calc_settings;
+        Ok(())
+    }
+
+    fn restore_spill(&mut self, origin: CellKey, end: CellAddr, array: Array) {
+        let origin_id = cell_id_from_key(origin);
+        for r in 0..array.rows {
+            for c in 0..array.cols {
+                if r == 0 && c == 0 {
+                    continue;
+                }
+                let key = CellKey {
+                    sheet: origin.sheet,
+                    addr: CellAddr {
+                        row: origin.addr.row + r as u32,
+                        col: origin.addr.col + c as u32,
+                    },
+                };
+                self.spills.origin_by_cell.insert(key, origin);
+                self.calc_graph.update_cell_dependencies(
+                    cell_id_from_key(key),
+                    CellDeps::new(vec![Precedent::Cell(origin_id)]),
+                );
+            }
+        }
+        self.spills.by_origin.insert(origin, Spill { end, array });
+    }
+}
@@ -1,4 +1,4 @@
-use formula_model::{parse_a1_endpoint, A1Endpoint, A1ParseError};
+use formula_model::{parse_a1_endpoint_with_max_cols, A1Endpoint, A1ParseError, EXCEL_MAX_COLS};
 use thiserror::Error;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -37,11 +37,16 @@ pub enum AddressParseError {
 
 /// Parse an A1-style address like `A1` or `$B$12` into a 0-indexed [`CellAddr`].
 pub fn parse_a1(input: &str) -> Result<CellAddr, AddressParseError> {
+    parse_a1_with_max_cols(input, EXCEL_MAX_COLS)
+}
+
+/// Like [`parse_a1`], but accepts columns up to `max_cols` (see [`formula_model::GridLimits`]).
+pub fn parse_a1_with_max_cols(input: &str, max_cols: u32) -> Result<CellAddr, AddressParseError> {
     let input = input.trim();
     if input.is_empty() {
         return Err(AddressParseError::InvalidA1(input.to_string()));
     }
-    let endpoint = parse_a1_endpoint(input).map_err(|e| match e {
+    let endpoint = parse_a1_endpoint_with_max_cols(input, max_cols).map_err(|e| match e {
         A1ParseError::InvalidColumn => AddressParseError::ColumnOutOfRange,
         A1ParseError::InvalidRow => AddressParseError::RowOutOfRange,
         _ => AddressParseError::InvalidA1(input.to_string()),
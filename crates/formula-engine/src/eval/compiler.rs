@@ -7,12 +7,13 @@ use crate::eval::sheet_reference::lower_sheet_reference;
 use crate::value::ErrorKind;
 use crate::SheetRef;
 use formula_model::sheet_name_eq_case_insensitive;
-use formula_model::EXCEL_MAX_COLS;
+use formula_model::MAX_GRID_COLS;
 
-/// Excel column limit (0-indexed).
+/// Maximum column index supported by the engine (0-indexed).
 ///
-/// The engine data model assumes a fixed 16,384-column grid for now.
-const MAX_COL: u32 = EXCEL_MAX_COLS - 1;
+/// This is the widest configurable grid ([`formula_model::GridLimits`]); references beyond a
+/// sheet's actual column count still evaluate to `#REF!` at runtime, like rows.
+const MAX_COL: u32 = MAX_GRID_COLS - 1;
 
 /// Maximum row index supported by the engine (0-indexed).
 ///
@@ -8,10 +8,10 @@ use crate::eval::ast::{
 use crate::functions::{
     ArgValue as FnArgValue, FunctionContext, Reference as FnReference, SheetId as FnSheetId,
 };
-use crate::locale::ValueLocaleConfig;
+use crate::locale::{TextCollation, ValueLocaleConfig};
 use crate::value::{
-    casefold_owned, casefolded_key_arc_if, cmp_case_insensitive, try_casefold, with_casefolded_key,
-    Array, ErrorKind, Lambda, NumberLocale, Value,
+    casefold_owned, casefolded_key_arc_if, cmp_numbers_excel, cmp_text_collated, try_casefold,
+    with_casefolded_key, Array, ErrorKind, Lambda, NumberLocale, Value,
 };
 use crate::LocaleConfig;
 use formula_model::HorizontalAlignment;
@@ -325,6 +325,11 @@ pub trait ValueResolver {
         None
     }
 
+    /// Return why a row is hidden (user, outline collapse and/or AutoFilter).
+    fn row_hidden_state(&self, _sheet_id: usize, _row: u32) -> formula_model::HiddenState {
+        formula_model::HiddenState::default()
+    }
+
     /// Return per-column properties (width/hidden/default style), if present.
     fn col_properties(&self, _sheet_id: usize, _col: u32) -> Option<formula_model::ColProperties> {
         None
@@ -439,6 +444,22 @@ pub trait ValueResolver {
     ) -> Option<Box<dyn Iterator<Item = CellAddr> + '_>> {
         None
     }
+    /// Iterates stored cells in `sheet_id` whose row lies in `row_start..=row_end`.
+    ///
+    /// Backends that keep cells in row-major order should override this so row-bounded
+    /// references (e.g. `1:1` or `A1:A10` on a large sheet) skip populated cells outside their
+    /// rows. The default filters [`ValueResolver::iter_sheet_cells`].
+    fn iter_sheet_cells_in_rows(
+        &self,
+        sheet_id: usize,
+        row_start: u32,
+        row_end: u32,
+    ) -> Option<Box<dyn Iterator<Item = CellAddr> + '_>> {
+        let iter = self.iter_sheet_cells(sheet_id)?;
+        Some(Box::new(iter.filter(move |addr| {
+            addr.row >= row_start && addr.row <= row_end
+        })))
+    }
     fn resolve_structured_ref(
         &self,
         ctx: EvalContext,
@@ -581,7 +602,11 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
     }
 
     fn external_sheet_tab_index(&self, workbook: &str, sheet: &str) -> Option<usize> {
-        if !self.external_workbook_sheet_index.borrow().contains_key(workbook) {
+        if !self
+            .external_workbook_sheet_index
+            .borrow()
+            .contains_key(workbook)
+        {
             let order = self.resolver.workbook_sheet_names(workbook)?;
             let mut indices: HashMap<String, usize> = HashMap::new();
             if indices.try_reserve(order.len()).is_err() {
@@ -670,27 +695,25 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
                 end: only.end,
             });
         }
-        FnArgValue::ReferenceUnion(
-            {
-                let mut out: Vec<FnReference> = Vec::new();
-                if out.try_reserve_exact(ranges.len()).is_err() {
-                    debug_assert!(
-                        false,
-                        "allocation failed (reference union, len={})",
-                        ranges.len()
-                    );
-                    return FnArgValue::Scalar(Value::Error(ErrorKind::Num));
-                }
-                for r in ranges {
-                    out.push(FnReference {
-                        sheet_id: r.sheet_id,
-                        start: r.start,
-                        end: r.end,
-                    });
-                }
-                out
-            },
-        )
+        FnArgValue::ReferenceUnion({
+            let mut out: Vec<FnReference> = Vec::new();
+            if out.try_reserve_exact(ranges.len()).is_err() {
+                debug_assert!(
+                    false,
+                    "allocation failed (reference union, len={})",
+                    ranges.len()
+                );
+                return FnArgValue::Scalar(Value::Error(ErrorKind::Num));
+            }
+            for r in ranges {
+                out.push(FnReference {
+                    sheet_id: r.sheet_id,
+                    start: r.start,
+                    end: r.end,
+                });
+            }
+            out
+        })
     }
 
     fn resolved_ranges_to_value_reference(&self, mut ranges: Vec<ResolvedRange>) -> Value {
@@ -707,27 +730,25 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
                 end: only.end,
             });
         }
-        Value::ReferenceUnion(
-            {
-                let mut out: Vec<FnReference> = Vec::new();
-                if out.try_reserve_exact(ranges.len()).is_err() {
-                    debug_assert!(
-                        false,
-                        "allocation failed (value reference union, len={})",
-                        ranges.len()
-                    );
-                    return Value::Error(ErrorKind::Num);
-                }
-                for r in ranges {
-                    out.push(FnReference {
-                        sheet_id: r.sheet_id,
-                        start: r.start,
-                        end: r.end,
-                    });
-                }
-                out
-            },
-        )
+        Value::ReferenceUnion({
+            let mut out: Vec<FnReference> = Vec::new();
+            if out.try_reserve_exact(ranges.len()).is_err() {
+                debug_assert!(
+                    false,
+                    "allocation failed (value reference union, len={})",
+                    ranges.len()
+                );
+                return Value::Error(ErrorKind::Num);
+            }
+            for r in ranges {
+                out.push(FnReference {
+                    sheet_id: r.sheet_id,
+                    start: r.start,
+                    end: r.end,
+                });
+            }
+            out
+        })
     }
 
     fn eval_lambda_body_with_scopes(
@@ -1347,7 +1368,8 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
             Expr::Compare { op, left, right } => {
                 let l = self.deref_eval_value_dynamic(self.eval_value(left));
                 let r = self.deref_eval_value_dynamic(self.eval_value(right));
-                let out = elementwise_binary(&l, &r, |a, b| excel_compare(a, b, *op));
+                let collation = self.value_locale.collation;
+                let out = elementwise_binary(&l, &r, |a, b| excel_compare(a, b, *op, collation));
                 EvalValue::Scalar(out)
             }
             Expr::FunctionCall { name, args, .. } => {
@@ -1387,13 +1409,9 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
                             };
                             self.function_result_to_eval_value(out)
                         }
-                        EvalValue::Scalar(v) => {
-                            self.function_result_to_eval_value(self.call_value_as_function(
-                                nref.name.as_str(),
-                                v,
-                                args,
-                            ))
-                        }
+                        EvalValue::Scalar(v) => self.function_result_to_eval_value(
+                            self.call_value_as_function(nref.name.as_str(), v, args),
+                        ),
                         EvalValue::Reference(ranges) => {
                             let v = self.deref_reference_scalar(&ranges);
                             self.function_result_to_eval_value(self.call_value_as_function(
@@ -1559,7 +1577,9 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
                 let mut omitted_key = String::new();
                 if omitted_key
                     .try_reserve_exact(
-                        crate::eval::LAMBDA_OMITTED_PREFIX.len().saturating_add(param_key.len()),
+                        crate::eval::LAMBDA_OMITTED_PREFIX
+                            .len()
+                            .saturating_add(param_key.len()),
                     )
                     .is_err()
                 {
@@ -1594,7 +1614,10 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
         }
     }
 
-    fn eval_name_ref_with_key_name(&self, nref: &crate::eval::NameRef<usize>) -> (EvalValue, Option<Arc<str>>) {
+    fn eval_name_ref_with_key_name(
+        &self,
+        nref: &crate::eval::NameRef<usize>,
+    ) -> (EvalValue, Option<Arc<str>>) {
         self.eval_name_ref_with_key_name_inner(nref, false)
     }
 
@@ -1666,7 +1689,9 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
         let name = name.trim();
         let Some(key_name) = casefolded_key_arc_if(name, |folded| {
             let stack = self.name_stack.borrow();
-            !stack.iter().any(|(sid, k)| *sid == sheet_id && k.as_ref() == folded)
+            !stack
+                .iter()
+                .any(|(sid, k)| *sid == sheet_id && k.as_ref() == folded)
         }) else {
             return (EvalValue::Scalar(Value::Error(ErrorKind::Name)), None);
         };
@@ -1798,10 +1823,8 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
         match sheet {
             SheetReference::Current => Some(vec![FnSheetId::Local(self.ctx.current_sheet)]),
             SheetReference::Sheet(id) => Some(vec![FnSheetId::Local(*id)]),
-            SheetReference::SheetRange(a, b) => self
-                .resolver
-                .expand_sheet_span(*a, *b)
-                .and_then(|ids| {
+            SheetReference::SheetRange(a, b) => {
+                self.resolver.expand_sheet_span(*a, *b).and_then(|ids| {
                     let mut out: Vec<FnSheetId> = Vec::new();
                     if out.try_reserve_exact(ids.len()).is_err() {
                         debug_assert!(
@@ -1815,7 +1838,8 @@ impl<'a, R: ValueResolver> Evaluator<'a, R> {
                         out.push(FnSheetId::Local(id));
                     }
                     Some(out)
-                }),
+                })
+            }
             SheetReference::External(key) => {
                 if is_valid_external_single_sheet_key(key) {
                     return Some(vec![FnSheetId::External(key.clone())]);
@@ -2180,6 +2204,91 @@ mod tests {
         }
     }
 
+    /// Sparse resolver that counts per-cell reads, used to check that whole-column/row
+    /// references only visit populated cells.
+    struct SparseCountingResolver {
+        cells: std::collections::BTreeMap<(u32, u32), f64>,
+        reads: std::cell::Cell<usize>,
+    }
+
+    impl SparseCountingResolver {
+        fn eval(&self, formula: &str) -> Value {
+            let ast = crate::parse_formula(formula, crate::ParseOptions::default()).unwrap();
+            let ctx = EvalContext {
+                current_sheet: 0,
+                current_cell: CellAddr { row: 0, col: 5 },
+            };
+            let compiled = crate::eval::compile_canonical_expr(
+                &ast.expr,
+                ctx.current_sheet,
+                ctx.current_cell,
+                &mut |name: &str| (name == "Sheet1").then_some(0),
+                &mut |_sheet_id: usize| {
+                    (formula_model::EXCEL_MAX_ROWS, formula_model::EXCEL_MAX_COLS)
+                },
+            );
+            let recalc_ctx = RecalcContext::new(0);
+            Evaluator::new(self, ctx, &recalc_ctx).eval_formula(&compiled)
+        }
+    }
+
+    impl ValueResolver for SparseCountingResolver {
+        fn sheet_exists(&self, sheet_id: usize) -> bool {
+            sheet_id == 0
+        }
+
+        fn get_cell_value(&self, _sheet_id: usize, addr: CellAddr) -> Value {
+            self.reads.set(self.reads.get() + 1);
+            self.cells
+                .get(&(addr.row, addr.col))
+                .map_or(Value::Blank, |n| Value::Number(*n))
+        }
+
+        fn iter_sheet_cells(
+            &self,
+            sheet_id: usize,
+        ) -> Option<Box<dyn Iterator<Item = CellAddr> + '_>> {
+            (sheet_id == 0).then(|| {
+                Box::new(self.cells.keys().map(|&(row, col)| CellAddr { row, col }))
+                    as Box<dyn Iterator<Item = CellAddr> + '_>
+            })
+        }
+
+        fn resolve_structured_ref(
+            &self,
+            _ctx: EvalContext,
+            _sref: &crate::structured_refs::StructuredRef,
+        ) -> Result<Vec<(usize, CellAddr, CellAddr)>, ErrorKind> {
+            Err(ErrorKind::Name)
+        }
+    }
+
+    #[test]
+    fn whole_column_and_row_refs_only_read_populated_cells() {
+        // Ten values in column A spread over the full sheet height, plus a few in other columns.
+        let mut cells = std::collections::BTreeMap::new();
+        for i in 0..10u32 {
+            cells.insert((i * 100_000, 0), f64::from(i + 1));
+        }
+        cells.insert((0, 2), 100.0);
+        cells.insert((500_000, 3), 1000.0);
+        let resolver = SparseCountingResolver {
+            cells,
+            reads: std::cell::Cell::new(0),
+        };
+
+        assert_eq!(resolver.eval("=SUM(A:A)"), Value::Number(55.0));
+        assert_eq!(resolver.reads.get(), 10);
+
+        resolver.reads.set(0);
+        assert_eq!(resolver.eval("=SUM(1:1)"), Value::Number(101.0));
+        assert_eq!(resolver.reads.get(), 2);
+
+        resolver.reads.set(0);
+        assert_eq!(resolver.eval("=COUNT(A:D)"), Value::Number(12.0));
+        assert_eq!(resolver.reads.get(), 12);
+    }
+
     #[test]
     fn split_external_sheet_key_parts_parses_workbook_and_sheet() {
         let key = "[Book.xlsx]Sheet1";
@@ -2271,7 +2380,10 @@ mod tests {
         };
         let recalc_ctx = RecalcContext::new(1);
         let evaluator = Evaluator::new(&resolver, ctx, &recalc_ctx);
-        assert_eq!(evaluator.eval_formula(&expr), Value::Error(ErrorKind::Spill));
+        assert_eq!(
+            evaluator.eval_formula(&expr),
+            Value::Error(ErrorKind::Spill)
+        );
     }
 
     #[test]
@@ -2362,7 +2474,11 @@ impl<'a, R: ValueResolver> FunctionContext for Evaluator<'a, R> {
 
         let mut scope: HashMap<String, Value> = HashMap::new();
         if scope.try_reserve(bindings.len()).is_err() {
-            debug_assert!(false, "binding scope allocation failed (len={})", bindings.len());
+            debug_assert!(
+                false,
+                "binding scope allocation failed (len={})",
+                bindings.len()
+            );
             return Value::Error(ErrorKind::Num);
         }
         for (k, v) in bindings {
@@ -2454,7 +2570,11 @@ impl<'a, R: ValueResolver> FunctionContext for Evaluator<'a, R> {
         self.trace_reference(reference);
         match &reference.sheet_id {
             FnSheetId::Local(sheet_id) => {
-                if let Some(iter) = self.resolver.iter_sheet_cells(*sheet_id) {
+                let rows = reference.normalized();
+                if let Some(iter) =
+                    self.resolver
+                        .iter_sheet_cells_in_rows(*sheet_id, rows.start.row, rows.end.row)
+                {
                     Box::new(iter.filter(move |addr| reference.contains(*addr)))
                 } else {
                     Box::new(reference.iter_cells())
@@ -2618,7 +2738,9 @@ impl<'a, R: ValueResolver> FunctionContext for Evaluator<'a, R> {
                 let mut omitted_key = String::new();
                 if omitted_key
                     .try_reserve_exact(
-                        crate::eval::LAMBDA_OMITTED_PREFIX.len().saturating_add(param_key.len()),
+                        crate::eval::LAMBDA_OMITTED_PREFIX
+                            .len()
+                            .saturating_add(param_key.len()),
                     )
                     .is_err()
                 {
@@ -2753,6 +2875,13 @@ impl<'a, R: ValueResolver> FunctionContext for Evaluator<'a, R> {
         }
     }
 
+    fn row_hidden_state(&self, sheet_id: &FnSheetId, row: u32) -> formula_model::HiddenState {
+        match sheet_id {
+            FnSheetId::Local(id) => self.resolver.row_hidden_state(*id, row),
+            FnSheetId::External(_) => formula_model::HiddenState::default(),
+        }
+    }
+
     fn get_cell_number_format(&self, sheet_id: &FnSheetId, addr: CellAddr) -> Option<&str> {
         match sheet_id {
             FnSheetId::Local(id) => self.resolver.get_cell_number_format(*id, addr),
@@ -2828,8 +2957,8 @@ impl<'a, R: ValueResolver> FunctionContext for Evaluator<'a, R> {
     }
 }
 
-fn excel_compare(left: &Value, right: &Value, op: CompareOp) -> Value {
-    let ord = match excel_order(left, right) {
+fn excel_compare(left: &Value, right: &Value, op: CompareOp, collation: TextCollation) -> Value {
+    let ord = match excel_order(left, right, collation) {
         Ok(ord) => ord,
         Err(e) => return Value::Error(e),
     };
@@ -2846,7 +2975,11 @@ fn excel_compare(left: &Value, right: &Value, op: CompareOp) -> Value {
     Value::Bool(result)
 }
 
-fn excel_order(left: &Value, right: &Value) -> Result<Ordering, ErrorKind> {
+fn excel_order(
+    left: &Value,
+    right: &Value,
+    collation: TextCollation,
+) -> Result<Ordering, ErrorKind> {
     if let Value::Error(e) = left {
         return Err(*e);
     }
@@ -2899,8 +3032,8 @@ fn excel_order(left: &Value, right: &Value) -> Result<Ordering, ErrorKind> {
     };
 
     Ok(match (&l, &r) {
-        (Value::Number(a), Value::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
-        (Value::Text(a), Value::Text(b)) => cmp_case_insensitive(a, b),
+        (Value::Number(a), Value::Number(b)) => cmp_numbers_excel(*a, *b),
+        (Value::Text(a), Value::Text(b)) => cmp_text_collated(a, b, collation),
         (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
         // Type precedence (approximate Excel): numbers < text < booleans.
         (
@@ -3034,7 +3167,10 @@ fn elementwise_unary(value: &Value, f: impl Fn(&Value) -> Value) -> Value {
         Value::Array(arr) => {
             let total = arr.values.len();
             if total > MAX_MATERIALIZED_ARRAY_CELLS {
-                debug_assert!(false, "elementwise unary exceeds materialization limit (cells={total})");
+                debug_assert!(
+                    false,
+                    "elementwise unary exceeds materialization limit (cells={total})"
+                );
                 return Value::Error(ErrorKind::Spill);
             }
 
@@ -3116,10 +3252,8 @@ fn elementwise_binary(left: &Value, right: &Value, f: impl Fn(&Value, &Value) ->
 
             Value::Array(Array::new(out_rows, out_cols, out))
         }
-        (Value::Array(left_arr), right_scalar) => Value::Array(Array::new(
-            left_arr.rows,
-            left_arr.cols,
-            {
+        (Value::Array(left_arr), right_scalar) => {
+            Value::Array(Array::new(left_arr.rows, left_arr.cols, {
                 let total = left_arr.values.len();
                 if total > MAX_MATERIALIZED_ARRAY_CELLS {
                     debug_assert!(
@@ -3136,12 +3270,10 @@ fn elementwise_binary(left: &Value, right: &Value, f: impl Fn(&Value, &Value) ->
                     out.push(f(a, right_scalar));
                 }
                 out
-            },
-        )),
-        (left_scalar, Value::Array(right_arr)) => Value::Array(Array::new(
-            right_arr.rows,
-            right_arr.cols,
-            {
+            }))
+        }
+        (left_scalar, Value::Array(right_arr)) => {
+            Value::Array(Array::new(right_arr.rows, right_arr.cols, {
                 let total = right_arr.values.len();
                 if total > MAX_MATERIALIZED_ARRAY_CELLS {
                     debug_assert!(
@@ -3158,8 +3290,8 @@ fn elementwise_binary(left: &Value, right: &Value, f: impl Fn(&Value, &Value) ->
                     out.push(f(left_scalar, b));
                 }
                 out
-            },
-        )),
+            }))
+        }
         (left_scalar, right_scalar) => f(left_scalar, right_scalar),
     }
 }
@@ -3178,9 +3310,6 @@ fn try_reserve_exact<T>(vec: &mut Vec<T>, additional: usize, context: &'static s
     if vec.try_reserve_exact(additional).is_ok() {
         return true;
     }
-    debug_assert!(
-        false,
-        "{context} allocation failed (count={additional})"
-    );
+    debug_assert!(false, "{context} allocation failed (count={additional})");
     false
 }
@@ -10,7 +10,7 @@ mod sheet_reference;
 /// The leading NUL character ensures the key cannot be referenced by user formulas.
 pub(crate) const LAMBDA_OMITTED_PREFIX: &str = "\u{0}LAMBDA_OMITTED:";
 
-pub use address::{parse_a1, AddressParseError, CellAddr};
+pub use address::{parse_a1, parse_a1_with_max_cols, AddressParseError, CellAddr};
 pub use ast::{
     BinaryOp, CellRef, CompareOp, CompiledExpr, Expr, NameRef, ParsedExpr, PostfixOp, RangeRef,
     Ref, SheetReference, StructuredRefExpr, UnaryOp,
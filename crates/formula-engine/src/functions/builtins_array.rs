@@ -1,4 +1,4 @@
-use crate::eval::{CellAddr, CompiledExpr};
+use crate::eval::{CellAddr, CompiledExpr, Expr};
 use crate::functions::{eval_scalar_arg, ArgValue, ArraySupport, FunctionContext, FunctionSpec};
 use crate::functions::{ThreadSafety, ValueType, Volatility};
 use crate::value::{Array, ErrorKind, Value};
@@ -88,29 +88,28 @@ fn sequence_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         Ok(v) => v,
         Err(e) => return Value::Error(e),
     };
-    let cols = if args.len() >= 2 {
-        match eval_scalar_arg(ctx, &args[1]).coerce_to_i64_with_ctx(ctx) {
+    // Omitted optional arguments (`SEQUENCE(3,,0,0.5)`) take their defaults, matching Excel.
+    let provided = |idx: usize| args.get(idx).filter(|expr| !matches!(expr, Expr::Blank));
+    let cols = match provided(1) {
+        Some(expr) => match eval_scalar_arg(ctx, expr).coerce_to_i64_with_ctx(ctx) {
             Ok(v) => v,
             Err(e) => return Value::Error(e),
-        }
-    } else {
-        1
+        },
+        None => 1,
     };
-    let start = if args.len() >= 3 {
-        match eval_scalar_arg(ctx, &args[2]).coerce_to_number_with_ctx(ctx) {
+    let start = match provided(2) {
+        Some(expr) => match eval_scalar_arg(ctx, expr).coerce_to_number_with_ctx(ctx) {
             Ok(v) => v,
             Err(e) => return Value::Error(e),
-        }
-    } else {
-        1.0
+        },
+        None => 1.0,
     };
-    let step = if args.len() >= 4 {
-        match eval_scalar_arg(ctx, &args[3]).coerce_to_number_with_ctx(ctx) {
+    let step = match provided(3) {
+        Some(expr) => match eval_scalar_arg(ctx, expr).coerce_to_number_with_ctx(ctx) {
             Ok(v) => v,
             Err(e) => return Value::Error(e),
-        }
-    } else {
-        1.0
+        },
+        None => 1.0,
     };
 
     if rows <= 0 || cols <= 0 {
@@ -54,6 +54,12 @@ fn date_from_parts(
         Err(e) => return Value::Error(e),
     };
 
+    // Excel maps years 0..=1899 onto 1900..=3799; anything outside 0..=9999 is #NUM!.
+    let year = match year {
+        0..=1899 => year + 1900,
+        1900..=9999 => year,
+        _ => return Value::Error(ErrorKind::Num),
+    };
     let (year, month) = normalize_year_month(year, month);
     let year_i32 = match i32::try_from(year) {
         Ok(y) => y,
@@ -76,10 +82,12 @@ fn date_from_parts(
         Some(v) => v,
         None => return Value::Error(ErrorKind::Num),
     };
-    if serial < i64::from(i32::MIN) || serial > i64::from(i32::MAX) {
-        return Value::Error(ErrorKind::Num);
+    // Month/day overflow rolls the date forward or back, but the result must still be a valid
+    // serial (1900-01-00 through 9999-12-31).
+    match crate::date::check_serial(serial, system) {
+        Ok(serial) => Value::Number(serial as f64),
+        Err(_) => Value::Error(ErrorKind::Num),
     }
-    Value::Number(serial as f64)
 }
 
 inventory::submit! {
@@ -1225,9 +1233,6 @@ fn time_from_parts(
     let hour_num = coerce_to_finite_number(ctx, hour)?;
     let minute_num = coerce_to_finite_number(ctx, minute)?;
     let second_num = coerce_to_finite_number(ctx, second)?;
-    if hour_num < 0.0 || minute_num < 0.0 || second_num < 0.0 {
-        return Err(ErrorKind::Num);
-    }
 
     let hour_i32 = coerce_number_to_i32_trunc(hour_num)?;
     let minute_i32 = coerce_number_to_i32_trunc(minute_num)?;
@@ -6,9 +6,10 @@ use crate::functions::{
     eval_scalar_arg, volatile_rand_u64_below, ArgValue, ArraySupport, FunctionContext, FunctionSpec,
 };
 use crate::functions::{ThreadSafety, ValueType, Volatility};
+use crate::locale::TextCollation;
 use crate::value::{
-    casefold_owned, try_casefold, try_vec_with_capacity, Array, ErrorKind, Lambda, RecordValue,
-    Value,
+    casefold_owned, cmp_text_collated, try_casefold, try_vec_with_capacity, Array, ErrorKind,
+    Lambda, RecordValue, Value,
 };
 
 fn checked_array_cells(rows: usize, cols: usize) -> Result<usize, ErrorKind> {
@@ -156,6 +157,11 @@ inventory::submit! {
     }
 }
 
+/// `SORT(array, [sort_index], [sort_order], [by_col])`.
+///
+/// The sort is stable like Excel's: rows (or columns) with equal keys keep their input order in
+/// both directions. `order.sort_by` is already stable, and every comparator below also falls back
+/// to the original index so ties never depend on the sort algorithm.
 fn sort_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
     let array = match eval_array_arg(ctx, &args[0]) {
         Ok(v) => v,
@@ -206,9 +212,10 @@ fn sort_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
             Ok(v) => v,
             Err(e) => return Value::Error(e),
         };
+        let collation = ctx.value_locale().collation;
         order.sort_by(|&a, &b| {
             for (key_idx, desc) in descending_flags.iter().copied().enumerate() {
-                let ord = compare_sort_keys(&keys[key_idx][a], &keys[key_idx][b], desc);
+                let ord = compare_sort_keys(&keys[key_idx][a], &keys[key_idx][b], desc, collation);
                 if ord != Ordering::Equal {
                     return ord;
                 }
@@ -256,9 +263,10 @@ fn sort_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         Ok(v) => v,
         Err(e) => return Value::Error(e),
     };
+    let collation = ctx.value_locale().collation;
     order.sort_by(|&a, &b| {
         for (key_idx, desc) in descending_flags.iter().copied().enumerate() {
-            let ord = compare_sort_keys(&keys[key_idx][a], &keys[key_idx][b], desc);
+            let ord = compare_sort_keys(&keys[key_idx][a], &keys[key_idx][b], desc, collation);
             if ord != Ordering::Equal {
                 return ord;
             }
@@ -297,6 +305,9 @@ inventory::submit! {
     }
 }
 
+/// `SORTBY(array, by_array1, [sort_order1], ...)`.
+///
+/// Stable in the same way as [`sort_fn`]: ties across every key keep their input order.
 fn sortby_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
     let array = match eval_array_arg(ctx, &args[0]) {
         Ok(v) => v,
@@ -420,9 +431,10 @@ fn sortby_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         Ok(v) => v,
         Err(e) => return Value::Error(e),
     };
+    let collation = ctx.value_locale().collation;
     order.sort_by(|&a, &b| {
         for (key_idx, desc) in descending_flags.iter().copied().enumerate() {
-            let ord = compare_sort_keys(&keys[key_idx][a], &keys[key_idx][b], desc);
+            let ord = compare_sort_keys(&keys[key_idx][a], &keys[key_idx][b], desc, collation);
             if ord != Ordering::Equal {
                 return ord;
             }
@@ -530,7 +542,12 @@ fn error_rank(error: ErrorKind) -> u8 {
     error.code()
 }
 
-pub(super) fn compare_sort_keys(a: &SortKeyValue, b: &SortKeyValue, descending: bool) -> Ordering {
+pub(super) fn compare_sort_keys(
+    a: &SortKeyValue,
+    b: &SortKeyValue,
+    descending: bool,
+    collation: TextCollation,
+) -> Ordering {
     let rank_cmp = a.kind_rank().cmp(&b.kind_rank());
     if rank_cmp != Ordering::Equal {
         // Excel keeps a fixed cross-type ordering (numbers, then text, then booleans, then errors,
@@ -541,7 +558,11 @@ pub(super) fn compare_sort_keys(a: &SortKeyValue, b: &SortKeyValue, descending:
     let ord = match (a, b) {
         (SortKeyValue::Blank, SortKeyValue::Blank) => Ordering::Equal,
         (SortKeyValue::Number(a), SortKeyValue::Number(b)) => a.total_cmp(b),
-        (SortKeyValue::Text(a), SortKeyValue::Text(b)) => a.cmp(b),
+        // Keys are already case-folded, so ordinal order is plain code-point order.
+        (SortKeyValue::Text(a), SortKeyValue::Text(b)) => match collation {
+            TextCollation::Ordinal => a.cmp(b),
+            _ => cmp_text_collated(a, b, collation),
+        },
         (SortKeyValue::Bool(a), SortKeyValue::Bool(b)) => a.cmp(b),
         (SortKeyValue::Error(a), SortKeyValue::Error(b)) => error_rank(*a).cmp(&error_rank(*b)),
         _ => Ordering::Equal,
@@ -2552,7 +2573,7 @@ mod tests {
         let mut order: Vec<usize> = super::try_index_vec(values.len())
             .unwrap_or_else(|_| panic!("test allocation failed (order)"));
         order.sort_by(|&a, &b| {
-            let ord = compare_sort_keys(&keys[a], &keys[b], false);
+            let ord = compare_sort_keys(&keys[a], &keys[b], false, TextCollation::Linguistic);
             if ord == Ordering::Equal {
                 a.cmp(&b)
             } else {
@@ -51,7 +51,8 @@ fn let_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         ctx.set_local_key(name_key, value);
     }
 
-    ctx.eval_formula(&args[last])
+    // Keep a reference result as a reference (e.g. `ISREF(LET(x,A1,x))`).
+    ctx.eval_arg(&args[last]).into_value()
 }
 
 inventory::submit! {
@@ -116,10 +116,12 @@ fn if_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                 Err(e) => return Value::Error(e),
             };
 
+            // A scalar condition selects a branch as-is, so a reference branch stays a reference
+            // (e.g. `ISREF(IF(TRUE,A1))`, `SUM(IF(x,A1:A3,B1:B3))`).
             if cond {
-                array_lift::eval_arg(ctx, &args[1])
+                ctx.eval_arg(&args[1]).into_value()
             } else if args.len() >= 3 {
-                array_lift::eval_arg(ctx, &args[2])
+                ctx.eval_arg(&args[2]).into_value()
             } else {
                 Value::Bool(false)
             }
@@ -1,5 +1,6 @@
 use crate::coercion::datetime::parse_value_text;
 use crate::eval::CompiledExpr;
+use crate::functions::array_lift;
 use crate::functions::lookup;
 use crate::functions::wildcard::WildcardPattern;
 use crate::functions::{
@@ -29,6 +30,8 @@ inventory::submit! {
 }
 
 fn vlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
+    // A range lookup value is implicitly intersected; an array value (e.g. `{1;2;3}`) spills one
+    // lookup per element.
     let lookup_value = eval_scalar_arg(ctx, &args[0]);
     if let Value::Error(e) = lookup_value {
         return Value::Error(e);
@@ -53,7 +56,24 @@ fn vlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         true
     };
 
-    match ctx.eval_arg(&args[1]) {
+    let table = ctx.eval_arg(&args[1]);
+    array_lift::lift1(lookup_value, |lookup_value| {
+        Ok(vlookup_one(ctx, lookup_value, &table, col_index, approx))
+    })
+}
+
+fn vlookup_one(
+    ctx: &dyn FunctionContext,
+    lookup_value: &Value,
+    table: &ArgValue,
+    col_index: i64,
+    approx: bool,
+) -> Value {
+    if let Value::Error(e) = lookup_value {
+        return Value::Error(*e);
+    }
+
+    match table {
         ArgValue::Reference(table_ref) => {
             let table = table_ref.normalized();
             // Record dereference for dynamic dependency tracing (e.g. VLOOKUP(…, OFFSET(...), …)).
@@ -64,12 +84,12 @@ fn vlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
             }
 
             let row_offset = if approx {
-                match approximate_match_in_first_col(ctx, &lookup_value, &table) {
+                match approximate_match_in_first_col(ctx, lookup_value, &table) {
                     Some(r) => r,
                     None => return Value::Error(ErrorKind::NA),
                 }
             } else {
-                match exact_match_in_first_col(ctx, &lookup_value, &table) {
+                match exact_match_in_first_col(ctx, lookup_value, &table) {
                     Some(r) => r,
                     None => return Value::Error(ErrorKind::NA),
                 }
@@ -88,12 +108,12 @@ fn vlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
             }
 
             let row_offset = if approx {
-                match approximate_match_in_first_col_array(ctx, &lookup_value, &table) {
+                match approximate_match_in_first_col_array(ctx, lookup_value, table) {
                     Some(r) => r,
                     None => return Value::Error(ErrorKind::NA),
                 }
             } else {
-                match exact_match_in_first_col_array(ctx, &lookup_value, &table) {
+                match exact_match_in_first_col_array(ctx, lookup_value, table) {
                     Some(r) => r,
                     None => return Value::Error(ErrorKind::NA),
                 }
@@ -104,7 +124,7 @@ fn vlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                 .cloned()
                 .unwrap_or(Value::Blank)
         }
-        ArgValue::Scalar(Value::Error(e)) => Value::Error(e),
+        ArgValue::Scalar(Value::Error(e)) => Value::Error(*e),
         ArgValue::ReferenceUnion(_) | ArgValue::Scalar(_) => Value::Error(ErrorKind::Value),
     }
 }
@@ -124,6 +144,8 @@ inventory::submit! {
 }
 
 fn hlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
+    // A range lookup value is implicitly intersected; an array value (e.g. `{1;2;3}`) spills one
+    // lookup per element.
     let lookup_value = eval_scalar_arg(ctx, &args[0]);
     if let Value::Error(e) = lookup_value {
         return Value::Error(e);
@@ -148,7 +170,24 @@ fn hlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         true
     };
 
-    match ctx.eval_arg(&args[1]) {
+    let table = ctx.eval_arg(&args[1]);
+    array_lift::lift1(lookup_value, |lookup_value| {
+        Ok(hlookup_one(ctx, lookup_value, &table, row_index, approx))
+    })
+}
+
+fn hlookup_one(
+    ctx: &dyn FunctionContext,
+    lookup_value: &Value,
+    table: &ArgValue,
+    row_index: i64,
+    approx: bool,
+) -> Value {
+    if let Value::Error(e) = lookup_value {
+        return Value::Error(*e);
+    }
+
+    match table {
         ArgValue::Reference(table_ref) => {
             let table = table_ref.normalized();
             // Record dereference for dynamic dependency tracing (e.g. HLOOKUP(…, OFFSET(...), …)).
@@ -159,12 +198,12 @@ fn hlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
             }
 
             let col_offset = if approx {
-                match approximate_match_in_first_row(ctx, &lookup_value, &table) {
+                match approximate_match_in_first_row(ctx, lookup_value, &table) {
                     Some(c) => c,
                     None => return Value::Error(ErrorKind::NA),
                 }
             } else {
-                match exact_match_in_first_row(ctx, &lookup_value, &table) {
+                match exact_match_in_first_row(ctx, lookup_value, &table) {
                     Some(c) => c,
                     None => return Value::Error(ErrorKind::NA),
                 }
@@ -183,12 +222,12 @@ fn hlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
             }
 
             let col_offset = if approx {
-                match approximate_match_in_first_row_array(ctx, &lookup_value, &table) {
+                match approximate_match_in_first_row_array(ctx, lookup_value, table) {
                     Some(c) => c,
                     None => return Value::Error(ErrorKind::NA),
                 }
             } else {
-                match exact_match_in_first_row_array(ctx, &lookup_value, &table) {
+                match exact_match_in_first_row_array(ctx, lookup_value, table) {
                     Some(c) => c,
                     None => return Value::Error(ErrorKind::NA),
                 }
@@ -199,7 +238,7 @@ fn hlookup_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                 .cloned()
                 .unwrap_or(Value::Blank)
         }
-        ArgValue::Scalar(Value::Error(e)) => Value::Error(e),
+        ArgValue::Scalar(Value::Error(e)) => Value::Error(*e),
         ArgValue::ReferenceUnion(_) | ArgValue::Scalar(_) => Value::Error(ErrorKind::Value),
     }
 }
@@ -2669,9 +2708,10 @@ fn excel_cmp(ctx: &dyn FunctionContext, a: &Value, b: &Value) -> Option<i32> {
                 (a, b) if text_like_str(ctx, a).is_some() && text_like_str(ctx, b).is_some() => {
                     let a = text_like_str(ctx, a)?;
                     let b = text_like_str(ctx, b)?;
-                    Some(ordering_to_i32(crate::value::cmp_case_insensitive(
+                    Some(ordering_to_i32(crate::value::cmp_text_collated(
                         a.as_ref(),
                         b.as_ref(),
+                        ctx.value_locale().collation,
                     )))
                 }
                 (Value::Bool(x), Value::Bool(y)) => Some(ordering_to_i32(x.cmp(y))),
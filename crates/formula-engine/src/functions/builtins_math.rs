@@ -1,12 +1,12 @@
 use crate::eval::{CellAddr, CompiledExpr};
 use crate::functions::array_lift;
-use crate::functions::math::criteria::Criteria;
+use crate::functions::math::criteria::{parse_numeric_text, Criteria};
 use crate::functions::{
     eval_scalar_arg, volatile_rand_u64_below, ArgValue, ArraySupport, FunctionContext, FunctionSpec,
 };
 use crate::functions::{ThreadSafety, ValueType, Volatility};
 use crate::simd;
-use crate::value::{parse_number, Array, ErrorKind, Value};
+use crate::value::{Array, ErrorKind, Value};
 
 const VAR_ARGS: usize = 255;
 const SIMD_AGGREGATE_BLOCK: usize = 1024;
@@ -28,17 +28,29 @@ fn count_value_to_f64(v: &Value) -> f64 {
     }
 }
 
+/// Coerce a COUNTIF-family candidate for a numeric criteria fast path.
+///
+/// Mirrors `Criteria::matches`: values that don't compare as numbers (blanks, booleans, other
+/// text, and numeric text under `<`/`>` comparisons) map to NaN, which only satisfies `<>`.
+/// Returns `None` for values that never match a numeric criteria (errors).
 #[inline]
-fn coerce_countif_value_to_number(v: &Value, locale: crate::value::NumberLocale) -> Option<f64> {
+fn coerce_countif_value_to_number(
+    v: &Value,
+    criteria: simd::NumericCriteria,
+    locale: crate::value::NumberLocale,
+) -> Option<f64> {
+    let text_compares = matches!(criteria.op, simd::CmpOp::Eq | simd::CmpOp::Ne);
+    let parse_text = |s: &str| Some(parse_numeric_text(s, locale).unwrap_or(f64::NAN));
     match v {
         Value::Number(n) => Some(*n),
-        Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
-        // COUNTIF numeric criteria treat blank as 0 for comparison.
-        Value::Blank => Some(0.0),
-        Value::Text(s) => parse_number(s, locale).ok(),
-        Value::Entity(_) | Value::Record(_) => None,
+        Value::Text(s) if text_compares => parse_text(s),
+        Value::Entity(entity) if text_compares => parse_text(entity.display.as_str()),
+        Value::Record(record) if text_compares => parse_text(record.display.as_str()),
+        Value::Bool(_) | Value::Blank | Value::Text(_) | Value::Entity(_) | Value::Record(_) => {
+            Some(f64::NAN)
+        }
         // Criteria matching uses implicit intersection for array candidates.
-        Value::Array(arr) => coerce_countif_value_to_number(&arr.top_left(), locale),
+        Value::Array(arr) => coerce_countif_value_to_number(&arr.top_left(), criteria, locale),
         Value::Error(_)
         | Value::Reference(_)
         | Value::ReferenceUnion(_)
@@ -914,11 +926,16 @@ fn count_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
 
                     total += count as u64;
                 }
-                other => {
-                    if matches!(other, Value::Number(_)) {
+                // Direct scalar arguments count when they are numbers, logicals, or text that
+                // coerces to a number (e.g. `"1"`, a date string); cells and array elements only
+                // count when they hold numbers.
+                Value::Number(_) | Value::Bool(_) => total += 1,
+                Value::Text(_) => {
+                    if v.coerce_to_number_with_ctx(ctx).is_ok() {
                         total += 1;
                     }
                 }
+                _ => {}
             },
             ArgValue::Reference(r) => {
                 let mut buf = [0.0_f64; SIMD_AGGREGATE_BLOCK];
@@ -988,25 +1005,6 @@ inventory::submit! {
 }
 
 fn countif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
-    fn coerce_candidate_to_number(
-        value: &Value,
-        locale: crate::value::NumberLocale,
-    ) -> Option<f64> {
-        match value {
-            Value::Number(n) => Some(*n),
-            Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
-            Value::Blank => Some(0.0),
-            Value::Text(s) => parse_number(s, locale).ok(),
-            Value::Record(_) | Value::Entity(_) => None,
-            Value::Array(arr) => coerce_candidate_to_number(&arr.top_left(), locale),
-            Value::Error(_)
-            | Value::Reference(_)
-            | Value::ReferenceUnion(_)
-            | Value::Lambda(_)
-            | Value::Spill { .. } => None,
-        }
-    }
-
     let criteria_value = eval_scalar_arg(ctx, &args[1]);
     if let Value::Error(e) = criteria_value {
         return Value::Error(e);
@@ -1039,7 +1037,7 @@ fn countif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                 for addr in ctx.iter_reference_cells(&r) {
                     seen_count += 1;
                     let v = ctx.get_cell_value(&r.sheet_id, addr);
-                    let Some(n) = coerce_candidate_to_number(&v, number_locale) else {
+                    let Some(n) = coerce_countif_value_to_number(&v, numeric, number_locale) else {
                         continue;
                     };
                     if n.is_nan() {
@@ -1104,7 +1102,8 @@ fn countif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                         }
                         seen_count += 1;
                         let v = ctx.get_cell_value(&r.sheet_id, addr);
-                        let Some(n) = coerce_candidate_to_number(&v, number_locale) else {
+                        let Some(n) = coerce_countif_value_to_number(&v, numeric, number_locale)
+                        else {
                             continue;
                         };
                         if n.is_nan() {
@@ -1167,7 +1166,7 @@ fn countif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                 let mut len = 0usize;
 
                 for v in arr.iter() {
-                    let Some(n) = coerce_candidate_to_number(v, number_locale) else {
+                    let Some(n) = coerce_countif_value_to_number(v, numeric, number_locale) else {
                         continue;
                     };
                     if n.is_nan() {
@@ -1521,7 +1520,7 @@ fn sumif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                     }
 
                     for v in criteria_arr.iter() {
-                        let Some(n) = coerce_countif_value_to_number(v, locale) else {
+                        let Some(n) = coerce_countif_value_to_number(v, numeric, locale) else {
                             can_simd = false;
                             break;
                         };
@@ -1573,7 +1572,8 @@ fn sumif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                     }
 
                     for (crit_v, sum_v) in criteria_arr.iter().zip(sum_arr.iter()) {
-                        let Some(n) = coerce_countif_value_to_number(crit_v, locale) else {
+                        let Some(n) = coerce_countif_value_to_number(crit_v, numeric, locale)
+                        else {
                             can_simd = false;
                             break;
                         };
@@ -1835,11 +1835,14 @@ fn averageif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
             Err(e) => return Value::Error(e),
         }),
     };
+    let (rows, cols) = criteria_range.shape();
+    // Excel sizes a reference average_range from its top-left cell to match criteria_range, so
+    // `AVERAGEIF(A1:A4,">0",B1)` averages B1:B4.
+    let average_range = average_range.map(|r| r.resize_reference(rows, cols));
     if let Some(ref average_range) = average_range {
         average_range.record_reference(ctx);
     }
 
-    let (rows, cols) = criteria_range.shape();
     if let Some(ref average_range) = average_range {
         let (avg_rows, avg_cols) = average_range.shape();
         if rows != avg_rows || cols != avg_cols {
@@ -1872,7 +1875,7 @@ fn averageif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                     }
 
                     for v in criteria_arr.iter() {
-                        let Some(n) = coerce_countif_value_to_number(v, locale) else {
+                        let Some(n) = coerce_countif_value_to_number(v, numeric, locale) else {
                             can_simd = false;
                             break;
                         };
@@ -1932,7 +1935,8 @@ fn averageif_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
                     }
 
                     for (crit_v, avg_v) in criteria_arr.iter().zip(avg_arr.iter()) {
-                        let Some(n) = coerce_countif_value_to_number(crit_v, locale) else {
+                        let Some(n) = coerce_countif_value_to_number(crit_v, numeric, locale)
+                        else {
                             can_simd = false;
                             break;
                         };
@@ -2563,6 +2567,22 @@ impl Range2D {
         }
     }
 
+    /// Resize a reference to `rows` x `cols`, anchored at its top-left cell.
+    ///
+    /// Arrays are returned unchanged so shape mismatches still surface as `#VALUE!`.
+    fn resize_reference(self, rows: usize, cols: usize) -> Self {
+        match self {
+            Range2D::Reference(mut r) if rows > 0 && cols > 0 => {
+                r.end = CellAddr {
+                    row: r.start.row.saturating_add((rows - 1) as u32),
+                    col: r.start.col.saturating_add((cols - 1) as u32),
+                };
+                Range2D::Reference(r)
+            }
+            other => other,
+        }
+    }
+
     fn shape(&self) -> (usize, usize) {
         match self {
             Range2D::Reference(r) => {
@@ -2591,13 +2611,13 @@ impl Range2D {
 inventory::submit! {
     FunctionSpec {
         name: "SUMPRODUCT",
-        min_args: 2,
-        max_args: 2,
+        min_args: 1,
+        max_args: VAR_ARGS,
         volatility: Volatility::NonVolatile,
         thread_safety: ThreadSafety::ThreadSafe,
         array_support: ArraySupport::SupportsArrays,
         return_type: ValueType::Number,
-        arg_types: &[ValueType::Any, ValueType::Any],
+        arg_types: &[ValueType::Any],
         implementation: sumproduct_fn,
     }
 }
@@ -2640,15 +2660,105 @@ fn sumproduct_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         }
     }
 
+    /// Sum the element-wise product of any number of operands in a single pass.
+    ///
+    /// Every element is coerced straight from its cell or array slot and folded into the running
+    /// sum, so no per-factor value array or intermediate product array is ever built. 1x1
+    /// operands broadcast and are coerced once, in argument order at the first element.
+    fn sumproduct_streaming(ctx: &dyn FunctionContext, operands: &[SumproductOperand]) -> Value {
+        let len = operands
+            .iter()
+            .map(SumproductOperand::len)
+            .max()
+            .unwrap_or(0);
+        if len == 0 {
+            return Value::Error(ErrorKind::Value);
+        }
+        if operands.iter().any(|op| op.len() != len && op.len() != 1) {
+            return Value::Error(ErrorKind::Value);
+        }
+
+        let locale = ctx.number_locale();
+        let coerce_at = |op: &SumproductOperand, idx: usize| -> Result<f64, ErrorKind> {
+            match op {
+                SumproductOperand::Scalar(v) => {
+                    crate::functions::math::coerce_sumproduct_number(v, locale)
+                }
+                SumproductOperand::Array(values) => {
+                    let v = values.get(idx).ok_or(ErrorKind::Value)?;
+                    crate::functions::math::coerce_sumproduct_number(v, locale)
+                }
+                SumproductOperand::Reference(r) => {
+                    let cols = (r.end.col - r.start.col + 1) as usize;
+                    let addr = crate::eval::CellAddr {
+                        row: r.start.row + (idx / cols) as u32,
+                        col: r.start.col + (idx % cols) as u32,
+                    };
+                    let v = ctx.get_cell_value(&r.sheet_id, addr);
+                    crate::functions::math::coerce_sumproduct_number(&v, locale)
+                }
+            }
+        };
+
+        let result = (|| -> Result<f64, ErrorKind> {
+            let mut broadcast: Vec<f64> = Vec::new();
+            if broadcast.try_reserve_exact(operands.len()).is_err() {
+                debug_assert!(
+                    false,
+                    "allocation failed (sumproduct operands={})",
+                    operands.len()
+                );
+                return Err(ErrorKind::Num);
+            }
+            broadcast.resize(operands.len(), 0.0);
+
+            let mut sum = 0.0;
+            for idx in 0..len {
+                let mut product = 1.0;
+                for (op, cached) in operands.iter().zip(broadcast.iter_mut()) {
+                    let x = if op.len() == 1 {
+                        if idx == 0 {
+                            *cached = coerce_at(op, 0)?;
+                        }
+                        *cached
+                    } else {
+                        coerce_at(op, idx)?
+                    };
+                    product *= x;
+                }
+                sum += product;
+            }
+            Ok(sum)
+        })();
+
+        match result {
+            Ok(v) => Value::Number(v),
+            Err(e) => Value::Error(e),
+        }
+    }
+
     // Match Excel-style argument evaluation: process arguments in order so we still record
     // precedents for earlier reference arguments even if later args are errors.
-    let a = match arg_to_operand(ctx, ctx.eval_arg(&args[0])) {
-        Ok(v) => v,
-        Err(e) => return e,
-    };
-    let b = match arg_to_operand(ctx, ctx.eval_arg(&args[1])) {
-        Ok(v) => v,
-        Err(e) => return e,
+    let mut operands: Vec<SumproductOperand> = Vec::new();
+    if operands.try_reserve_exact(args.len()).is_err() {
+        debug_assert!(false, "allocation failed (sumproduct args={})", args.len());
+        return Value::Error(ErrorKind::Num);
+    }
+    for arg in args {
+        match arg_to_operand(ctx, ctx.eval_arg(arg)) {
+            Ok(v) => operands.push(v),
+            Err(e) => return e,
+        }
+    }
+
+    // The two-factor form is by far the most common and has dedicated SIMD paths below; every
+    // other arity streams through the generic N-factor loop.
+    if operands.len() != 2 {
+        return sumproduct_streaming(ctx, &operands);
+    }
+    let (Some(b), Some(a)) = (operands.pop(), operands.pop()) else {
+        debug_assert!(false, "operand count checked above");
+        return Value::Error(ErrorKind::Value);
     };
 
     let len_a = a.len();
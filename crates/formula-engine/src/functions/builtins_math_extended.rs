@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use crate::error::ExcelError;
 use crate::eval::{CellAddr, CompiledExpr, MAX_MATERIALIZED_ARRAY_CELLS};
 use crate::functions::{
-    array_lift, ArgValue, ArraySupport, FunctionContext, FunctionSpec, Reference,
+    array_lift, ArgValue, ArraySupport, FunctionContext, FunctionSpec, Reference, SheetId,
 };
 use crate::functions::{ThreadSafety, ValueType, Volatility};
 use crate::value::{Array, ErrorKind, Value};
@@ -385,12 +385,40 @@ mod tests {
     }
 }
 
-fn append_values_for_aggregate(ctx: &dyn FunctionContext, arg: ArgValue, out: &mut Vec<Value>) {
+/// Which hidden rows `SUBTOTAL`/`AGGREGATE` leave out of referenced ranges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HiddenRows {
+    Include,
+    /// Skip rows hidden by an AutoFilter (`SUBTOTAL` 1-11).
+    SkipFiltered,
+    /// Skip rows hidden for any reason (`SUBTOTAL` 101-111, `AGGREGATE` options 1/3/5/7).
+    SkipAll,
+}
+
+impl HiddenRows {
+    fn skips(self, ctx: &dyn FunctionContext, sheet_id: &SheetId, row: u32) -> bool {
+        match self {
+            HiddenRows::Include => false,
+            HiddenRows::SkipFiltered => ctx.row_hidden_state(sheet_id, row).filter,
+            HiddenRows::SkipAll => ctx.row_hidden_state(sheet_id, row).is_hidden(),
+        }
+    }
+}
+
+fn append_values_for_aggregate(
+    ctx: &dyn FunctionContext,
+    arg: ArgValue,
+    hidden_rows: HiddenRows,
+    out: &mut Vec<Value>,
+) {
     match arg {
         ArgValue::Scalar(Value::Array(arr)) => out.extend(arr.values),
         ArgValue::Scalar(v) => out.push(v),
         ArgValue::Reference(r) => {
             for addr in ctx.iter_reference_cells(&r) {
+                if hidden_rows.skips(ctx, &r.sheet_id, addr.row) {
+                    continue;
+                }
                 out.push(ctx.get_cell_value(&r.sheet_id, addr));
             }
         }
@@ -401,6 +429,9 @@ fn append_values_for_aggregate(ctx: &dyn FunctionContext, arg: ArgValue, out: &m
                     if !seen.insert((r.sheet_id.clone(), addr)) {
                         continue;
                     }
+                    if hidden_rows.skips(ctx, &r.sheet_id, addr.row) {
+                        continue;
+                    }
                     out.push(ctx.get_cell_value(&r.sheet_id, addr));
                 }
             }
@@ -1220,9 +1251,15 @@ fn subtotal_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
             Err(e) => return Value::Error(e),
         };
 
+    // Excel always ignores AutoFilter-hidden rows; 101-111 also ignore manually hidden rows.
+    let hidden_rows = if function_num >= 100 {
+        HiddenRows::SkipAll
+    } else {
+        HiddenRows::SkipFiltered
+    };
     let mut values = Vec::new();
     for arg in &args[1..] {
-        append_values_for_aggregate(ctx, ctx.eval_arg(arg), &mut values);
+        append_values_for_aggregate(ctx, ctx.eval_arg(arg), hidden_rows, &mut values);
     }
 
     match crate::functions::math::subtotal(function_num, &values) {
@@ -1262,9 +1299,14 @@ fn aggregate_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
         Err(e) => return Value::Error(e),
     };
 
+    let hidden_rows = if matches!(options, 1 | 3 | 5 | 7) {
+        HiddenRows::SkipAll
+    } else {
+        HiddenRows::Include
+    };
     let mut values = Vec::new();
     for arg in &args[2..] {
-        append_values_for_aggregate(ctx, ctx.eval_arg(arg), &mut values);
+        append_values_for_aggregate(ctx, ctx.eval_arg(arg), hidden_rows, &mut values);
     }
 
     match crate::functions::math::aggregate(function_num, options, &values) {
@@ -48,13 +48,18 @@ fn lift2_number(
     })
 }
 
-fn lift1_bool(
+/// ISEVEN/ISODD argument handling: logical values are `#VALUE!` rather than 1/0 (Excel rejects
+/// `ISEVEN(TRUE)`, including via a cell reference).
+fn lift1_parity(
     ctx: &dyn FunctionContext,
     expr: &CompiledExpr,
     f: impl Fn(f64) -> Result<bool, ExcelError>,
 ) -> Value {
     let value = array_lift::eval_arg(ctx, expr);
     array_lift::lift1(value, |v| {
+        if matches!(v, Value::Bool(_)) {
+            return Err(ErrorKind::Value);
+        }
         let n = v.coerce_to_number_with_ctx(ctx)?;
         match f(n) {
             Ok(out) => Ok(Value::Bool(out)),
@@ -545,7 +550,7 @@ inventory::submit! {
 }
 
 fn iseven_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
-    lift1_bool(ctx, &args[0], crate::functions::math::iseven)
+    lift1_parity(ctx, &args[0], crate::functions::math::iseven)
 }
 
 inventory::submit! {
@@ -563,7 +568,7 @@ inventory::submit! {
 }
 
 fn isodd_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
-    lift1_bool(ctx, &args[0], crate::functions::math::isodd)
+    lift1_parity(ctx, &args[0], crate::functions::math::isodd)
 }
 
 inventory::submit! {
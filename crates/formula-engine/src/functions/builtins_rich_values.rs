@@ -55,6 +55,39 @@ fn fieldaccess_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
     }
 }
 
+inventory::submit! {
+    FunctionSpec {
+        name: "FIELDVALUE",
+        min_args: 2,
+        max_args: 2,
+        volatility: Volatility::NonVolatile,
+        thread_safety: ThreadSafety::ThreadSafe,
+        array_support: ArraySupport::SupportsArrays,
+        return_type: ValueType::Any,
+        arg_types: &[ValueType::Any, ValueType::Text],
+        implementation: fieldvalue_fn,
+    }
+}
+
+/// `FIELDVALUE(value, field_name)`: the function form of the `.` field access operator.
+///
+/// Both arguments lift elementwise, so an array of field names spills one field per element.
+fn fieldvalue_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
+    let base = array_lift::eval_arg(ctx, &args[0]);
+    let field = array_lift::eval_arg(ctx, &args[1]);
+    array_lift::lift2(base, field, |base, field| {
+        let field = match field {
+            Value::Error(e) => return Err(*e),
+            Value::Text(s) => s.clone(),
+            other => other.coerce_to_string_with_ctx(ctx)?,
+        };
+        if field.trim().is_empty() {
+            return Err(ErrorKind::Value);
+        }
+        Ok(fieldaccess_scalar(base, &field))
+    })
+}
+
 fn fieldaccess_scalar(base: &Value, field: &str) -> Value {
     match base {
         Value::Error(e) => Value::Error(*e),
@@ -68,3 +101,9 @@ fn fieldaccess_scalar(base: &Value, field: &str) -> Value {
         _ => Value::Error(ErrorKind::Value),
     }
 }
+
+// On wasm targets, `inventory` registrations can be dropped by the linker if the object file
+// contains no otherwise-referenced symbols. Referencing this function from a `#[used]` table in
+// `functions/mod.rs` ensures the module (and its `inventory::submit!` entries) are retained.
+#[cfg(target_arch = "wasm32")]
+pub(super) fn __force_link() {}
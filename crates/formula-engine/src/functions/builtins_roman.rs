@@ -37,7 +37,12 @@ fn roman_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
 
     array_lift::lift2(number, form, |number, form| {
         let number = number.coerce_to_number_with_ctx(ctx)?;
-        let form = form.coerce_to_number_with_ctx(ctx)?;
+        // Excel treats a logical form as TRUE = classic (0), FALSE = simplified (4).
+        let form = match form {
+            Value::Bool(true) => 0.0,
+            Value::Bool(false) => 4.0,
+            other => other.coerce_to_number_with_ctx(ctx)?,
+        };
 
         if !number.is_finite() || !form.is_finite() {
             return Err(ErrorKind::Num);
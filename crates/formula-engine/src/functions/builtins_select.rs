@@ -61,7 +61,7 @@ fn choose_scalar(ctx: &dyn FunctionContext, index_value: Value, choices: &[Compi
         return Value::Error(ErrorKind::Value);
     };
 
-    arg_value_to_value(ctx.eval_arg(expr))
+    ctx.eval_arg(expr).into_value()
 }
 
 fn choose_array(ctx: &dyn FunctionContext, indices: &Array, choices: &[CompiledExpr]) -> Value {
@@ -240,14 +240,6 @@ fn choose_value_from_arg(ctx: &dyn FunctionContext, arg: ArgValue) -> Value {
     }
 }
 
-fn arg_value_to_value(arg: ArgValue) -> Value {
-    match arg {
-        ArgValue::Scalar(v) => v,
-        ArgValue::Reference(r) => Value::Reference(r),
-        ArgValue::ReferenceUnion(ranges) => Value::ReferenceUnion(ranges),
-    }
-}
-
 inventory::submit! {
     FunctionSpec {
         name: "IFS",
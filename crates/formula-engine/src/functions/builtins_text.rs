@@ -756,6 +756,60 @@ fn clean_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
     })
 }
 
+inventory::submit! {
+    FunctionSpec {
+        name: "UNICHAR",
+        min_args: 1,
+        max_args: 1,
+        volatility: Volatility::NonVolatile,
+        thread_safety: ThreadSafety::ThreadSafe,
+        array_support: ArraySupport::SupportsArrays,
+        return_type: ValueType::Text,
+        arg_types: &[ValueType::Number],
+        implementation: unichar_fn,
+    }
+}
+
+fn unichar_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
+    let number = array_lift::eval_arg(ctx, &args[0]);
+    array_lift::lift1(number, |number| {
+        let code = number.coerce_to_i64_with_ctx(ctx)?;
+        match code {
+            // Lone surrogate halves are not characters on their own.
+            0xD800..=0xDFFF => Err(ErrorKind::NA),
+            1..=0x10FFFF => char::from_u32(code as u32)
+                .map(|ch| Value::Text(ch.to_string()))
+                .ok_or(ErrorKind::Value),
+            _ => Err(ErrorKind::Value),
+        }
+    })
+}
+
+inventory::submit! {
+    FunctionSpec {
+        name: "UNICODE",
+        min_args: 1,
+        max_args: 1,
+        volatility: Volatility::NonVolatile,
+        thread_safety: ThreadSafety::ThreadSafe,
+        array_support: ArraySupport::SupportsArrays,
+        return_type: ValueType::Number,
+        arg_types: &[ValueType::Text],
+        implementation: unicode_fn,
+    }
+}
+
+fn unicode_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
+    let text = array_lift::eval_arg(ctx, &args[0]);
+    array_lift::lift1(text, |text| {
+        let text = text.coerce_to_string_with_ctx(ctx)?;
+        match text.chars().next() {
+            Some(ch) => Ok(Value::Number(f64::from(u32::from(ch)))),
+            None => Err(ErrorKind::Value),
+        }
+    })
+}
+
 inventory::submit! {
     FunctionSpec {
         name: "EXACT",
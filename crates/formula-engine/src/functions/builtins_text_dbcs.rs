@@ -142,6 +142,34 @@ inventory::submit! {
     }
 }
 
+inventory::submit! {
+    FunctionSpec {
+        name: "CHAR",
+        min_args: 1,
+        max_args: 1,
+        volatility: Volatility::NonVolatile,
+        thread_safety: ThreadSafety::ThreadSafe,
+        array_support: ArraySupport::SupportsArrays,
+        return_type: ValueType::Text,
+        arg_types: &[ValueType::Number],
+        implementation: dbcs::char_fn,
+    }
+}
+
+inventory::submit! {
+    FunctionSpec {
+        name: "CODE",
+        min_args: 1,
+        max_args: 1,
+        volatility: Volatility::NonVolatile,
+        thread_safety: ThreadSafety::ThreadSafe,
+        array_support: ArraySupport::SupportsArrays,
+        return_type: ValueType::Number,
+        arg_types: &[ValueType::Text],
+        implementation: dbcs::code_fn,
+    }
+}
+
 // On wasm targets, `inventory` registrations can be dropped by the linker if the object file
 // contains no otherwise-referenced symbols. Referencing this function from a `#[used]` table in
 // `functions/mod.rs` ensures the module (and its `inventory::submit!` entries) are retained.
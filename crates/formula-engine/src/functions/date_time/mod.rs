@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 
 use crate::coercion::datetime::{parse_datevalue_text, parse_timevalue_text};
 use crate::coercion::ValueLocaleConfig;
-use crate::date::{ymd_to_serial, ExcelDate, ExcelDateSystem};
+use crate::date::{check_serial, ymd_to_serial, ExcelDate, ExcelDateSystem};
 use crate::error::{ExcelError, ExcelResult};
 
 pub(crate) mod thai;
@@ -12,12 +12,22 @@ pub(crate) mod thai;
 const DEFAULT_WEEKEND_MASK: u8 = (1 << 5) | (1 << 6); // Saturday + Sunday (Mon=0..Sun=6)
 
 /// TIME(hour, minute, second)
+///
+/// Components may over- or underflow into each other (`TIME(0,90,0)` is 1:30, `TIME(1,-30,0)` is
+/// 0:30) and the result wraps past midnight (`TIME(27,0,0)` is 3:00). A component outside
+/// `-32767..=32767` or a negative total is `#NUM!`.
 pub fn time(hour: i32, minute: i32, second: i32) -> ExcelResult<f64> {
-    if hour < 0 || minute < 0 || second < 0 {
+    if [hour, minute, second]
+        .iter()
+        .any(|part| part.unsigned_abs() > 32767)
+    {
         return Err(ExcelError::Num);
     }
     let total_seconds = i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
-    Ok(total_seconds as f64 / 86400.0)
+    if total_seconds < 0 {
+        return Err(ExcelError::Num);
+    }
+    Ok((total_seconds % 86400) as f64 / 86400.0)
 }
 
 /// TIMEVALUE(time_text)
@@ -36,13 +46,16 @@ pub fn datevalue(
 }
 
 /// EOMONTH(start_date, months)
+///
+/// Both `start_date` and the result must be valid serials (see [`check_serial`]).
 pub fn eomonth(start_date: i32, months: i32, system: ExcelDateSystem) -> ExcelResult<i32> {
+    check_serial(i64::from(start_date), system)?;
     let start = crate::date::serial_to_ymd(start_date, system)?;
     let (year, month) = add_months(start.year, start.month, months);
 
     let (next_year, next_month) = add_months(year, month, 1);
     let first_next = ymd_to_serial(ExcelDate::new(next_year, next_month, 1), system)?;
-    Ok(first_next - 1)
+    check_serial(i64::from(first_next) - 1, system)
 }
 
 fn add_months(year: i32, month: u8, offset: i32) -> (i32, u8) {
@@ -54,7 +67,10 @@ fn add_months(year: i32, month: u8, offset: i32) -> (i32, u8) {
 }
 
 /// EDATE(start_date, months)
+///
+/// Both `start_date` and the result must be valid serials (see [`check_serial`]).
 pub fn edate(start_date: i32, months: i32, system: ExcelDateSystem) -> ExcelResult<i32> {
+    check_serial(i64::from(start_date), system)?;
     let start = crate::date::serial_to_ymd(start_date, system)?;
     let (year, month) = add_months(start.year, start.month, months);
 
@@ -62,7 +78,7 @@ pub fn edate(start_date: i32, months: i32, system: ExcelDateSystem) -> ExcelResu
     let mut day = start.day;
     while day > 0 {
         match ymd_to_serial(ExcelDate::new(year, month, day), system) {
-            Ok(serial) => return Ok(serial),
+            Ok(serial) => return check_serial(i64::from(serial), system),
             Err(ExcelError::Num) => day = day.saturating_sub(1),
             Err(e) => return Err(e),
         }
@@ -241,6 +257,18 @@ fn full_months_between(
     i32::try_from(months).map_err(|_| ExcelError::Num)
 }
 
+/// Serial for `DATE(year, month, day)` where `day` may run past the end of the month (like
+/// Excel's `DATE`, which rolls the excess into the following month).
+fn date_with_day_overflow(
+    year: i32,
+    month: u8,
+    day: u8,
+    system: ExcelDateSystem,
+) -> ExcelResult<i32> {
+    let first = ymd_to_serial(ExcelDate::new(year, month, 1), system)?;
+    first.checked_add(i32::from(day) - 1).ok_or(ExcelError::Num)
+}
+
 /// DATEDIF(start_date, end_date, unit)
 pub fn datedif(
     start_date: i32,
@@ -279,12 +307,25 @@ pub fn datedif(
         if unit.eq_ignore_ascii_case("YM") {
             return Ok(i64::from(full_months.rem_euclid(12)));
         }
+        let start = crate::date::serial_to_ymd(start_date, system)?;
+        let end = crate::date::serial_to_ymd(end_date, system)?;
         if unit.eq_ignore_ascii_case("MD") {
-            let anchor = edate(start_date, full_months, system)?;
+            // Excel measures from `DATE(end.year, end.month - 1, start.day)` when the end day is
+            // earlier in its month. That day can overflow a short month, so e.g. 2015-01-31 ->
+            // 2015-03-01 yields -2 (the well-known `"MD"` bug).
+            if end.day >= start.day {
+                return Ok(i64::from(end.day) - i64::from(start.day));
+            }
+            let (year, month) = add_months(end.year, end.month, -1);
+            let anchor = date_with_day_overflow(year, month, start.day, system)?;
             return Ok(i64::from(end_date) - i64::from(anchor));
         }
-        // `YD`
-        let anchor = edate(start_date, years.saturating_mul(12), system)?;
+        // `YD`: measure from `DATE(end.year, start.month, start.day)`, or the year before when that
+        // is after the end date. A Feb 29 start rolls over to Mar 1 in common years.
+        let mut anchor = date_with_day_overflow(end.year, start.month, start.day, system)?;
+        if anchor > end_date {
+            anchor = date_with_day_overflow(end.year - 1, start.month, start.day, system)?;
+        }
         return Ok(i64::from(end_date) - i64::from(anchor));
     }
 
@@ -324,6 +365,8 @@ fn weekday_monday0(serial_number: i32, system: ExcelDateSystem) -> i32 {
             (adjusted - 1).rem_euclid(7)
         }
         ExcelDateSystem::Excel1904 => (serial_number + 4).rem_euclid(7),
+        // Serial 0 is Saturday 1899-12-30.
+        ExcelDateSystem::Excel1900Proleptic => (serial_number + 5).rem_euclid(7),
     }
 }
 
@@ -441,11 +484,17 @@ pub fn networkdays_intl(
 }
 
 /// WEEKNUM(serial_number, [return_type])
+///
+/// Return types 1/17 and 2/11..16 are "system 1": week 1 is the week containing January 1,
+/// with weeks starting on the given weekday. Return type 21 is "system 2" (ISO 8601): week 1
+/// is the Monday-based week containing the year's first Thursday, so early January can fall in
+/// week 52/53 of the previous year and late December in week 1 of the next.
 pub fn weeknum(
     serial_number: i32,
     return_type: Option<i32>,
     system: ExcelDateSystem,
 ) -> ExcelResult<i32> {
+    check_serial(i64::from(serial_number), system)?;
     let date = crate::date::serial_to_ymd(serial_number, system)?;
     let return_type = return_type.unwrap_or(1);
 
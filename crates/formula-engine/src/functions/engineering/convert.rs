@@ -24,7 +24,8 @@ enum Dimension {
 enum PrefixSupport {
     None,
     Metric,
-    Binary,
+    /// Metric prefixes (`kbyte` = 1000 bytes) plus IEC binary prefixes (`kibyte` = 1024 bytes).
+    MetricAndBinary,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -78,14 +79,14 @@ fn parse_unit(unit: &str) -> Option<UnitDef> {
         return Some(def);
     }
 
-    // Information units use binary prefixes in Excel (k/M/G/...).
+    // Information units also accept IEC binary prefixes (ki/Mi/Gi/...).
     for (prefix, factor) in BINARY_PREFIXES {
         if let Some(rest) = unit.strip_prefix(prefix) {
             if rest.is_empty() {
                 continue;
             }
             if let Some(base) = unit_table().get(rest).copied() {
-                if base.prefix != PrefixSupport::Binary {
+                if base.prefix != PrefixSupport::MetricAndBinary {
                     continue;
                 }
                 return Some(UnitDef {
@@ -105,7 +106,7 @@ fn parse_unit(unit: &str) -> Option<UnitDef> {
                 continue;
             }
             if let Some(base) = unit_table().get(rest).copied() {
-                if base.prefix != PrefixSupport::Metric {
+                if base.prefix == PrefixSupport::None {
                     continue;
                 }
                 let exp = metric_prefix_exponent(rest);
@@ -158,560 +159,252 @@ const METRIC_PREFIXES: &[(&str, f64)] = &[
 
 const BINARY_PREFIXES: &[(&str, f64)] = &[
     // 2^(10*n) prefixes for information units.
-    ("Y", 1_208_925_819_614_629_174_706_176.0), // 2^80
-    ("Z", 1_180_591_620_717_411_303_424.0),     // 2^70
-    ("E", 1_152_921_504_606_846_976.0),         // 2^60
-    ("P", 1_125_899_906_842_624.0),             // 2^50
-    ("T", 1_099_511_627_776.0),                 // 2^40
-    ("G", 1_073_741_824.0),                     // 2^30
-    ("M", 1_048_576.0),                         // 2^20
-    ("k", 1_024.0),                             // 2^10
+    ("Yi", 1_208_925_819_614_629_174_706_176.0), // 2^80
+    ("Zi", 1_180_591_620_717_411_303_424.0),     // 2^70
+    ("Ei", 1_152_921_504_606_846_976.0),         // 2^60
+    ("Pi", 1_125_899_906_842_624.0),             // 2^50
+    ("Ti", 1_099_511_627_776.0),                 // 2^40
+    ("Gi", 1_073_741_824.0),                     // 2^30
+    ("Mi", 1_048_576.0),                         // 2^20
+    ("ki", 1_024.0),                             // 2^10
 ];
 
+const INCH: f64 = 0.0254;
+const FOOT: f64 = 0.3048;
+const YARD: f64 = 0.9144;
+const MILE: f64 = 1609.344;
+const NAUTICAL_MILE: f64 = 1852.0;
+const ANGSTROM: f64 = 1e-10;
+/// Julian year (365.25 days) at the speed of light.
+const LIGHT_YEAR: f64 = 9_460_730_472_580_800.0;
+const PICA_POINT: f64 = INCH / 72.0;
+const PICA: f64 = INCH / 6.0;
+const POUND_MASS: f64 = 453.592_37;
+const US_GALLON: f64 = 0.003_785_411_784;
+const UK_GALLON: f64 = 0.004_546_09;
+const HORSEPOWER: f64 = 745.699_871_582_270_2;
+
+const fn linear(dim: Dimension, scale: f64, prefix: PrefixSupport) -> UnitDef {
+    UnitDef {
+        dim,
+        scale,
+        offset: 0.0,
+        prefix,
+    }
+}
+
 fn unit_table() -> &'static HashMap<&'static str, UnitDef> {
     static TABLE: OnceLock<HashMap<&'static str, UnitDef>> = OnceLock::new();
     TABLE.get_or_init(|| {
+        use Dimension::*;
+        use PrefixSupport::{Metric, MetricAndBinary, None as NoPrefix};
+
         let mut units: HashMap<&'static str, UnitDef> = HashMap::new();
+        // Each entry lists Excel's spellings for one unit.
+        let mut add = |names: &[&'static str], def: UnitDef| {
+            for name in names {
+                units.insert(*name, def);
+            }
+        };
 
         // Length (base: meter).
-        units.insert(
-            "m",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "in",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 0.0254,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "ft",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 0.3048,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "yd",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 0.9144,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "mi",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 1609.344,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "Nmi",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 1852.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "ang",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 1e-10,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        // Typographic pica: 1 pica = 1/6 inch.
-        units.insert(
-            "Pica",
-            UnitDef {
-                dim: Dimension::Length,
-                scale: 0.0254 / 6.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
+        add(&["m"], linear(Length, 1.0, Metric));
+        add(&["mi"], linear(Length, MILE, NoPrefix));
+        add(&["Nmi"], linear(Length, NAUTICAL_MILE, NoPrefix));
+        add(&["in"], linear(Length, INCH, NoPrefix));
+        add(&["ft"], linear(Length, FOOT, NoPrefix));
+        add(&["yd"], linear(Length, YARD, NoPrefix));
+        add(&["ang"], linear(Length, ANGSTROM, Metric));
+        add(&["ell"], linear(Length, 45.0 * INCH, NoPrefix));
+        add(&["ly"], linear(Length, LIGHT_YEAR, Metric));
+        add(
+            &["parsec", "pc"],
+            linear(Length, 3.085_677_581_491_367e16, Metric),
+        );
+        // Excel spells the typographic point `Picapt` or `Pica`; lowercase `pica` is 1/6 inch.
+        add(&["Picapt", "Pica"], linear(Length, PICA_POINT, NoPrefix));
+        add(&["pica"], linear(Length, PICA, NoPrefix));
+        add(
+            &["survey_mi"],
+            linear(Length, 5280.0 * 1200.0 / 3937.0, NoPrefix),
         );
 
         // Area (base: square meter).
-        units.insert(
-            "m2",
-            UnitDef {
-                dim: Dimension::Area,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "in2",
-            UnitDef {
-                dim: Dimension::Area,
-                scale: 0.0254_f64.powi(2),
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "ft2",
-            UnitDef {
-                dim: Dimension::Area,
-                scale: 0.3048_f64.powi(2),
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "yd2",
-            UnitDef {
-                dim: Dimension::Area,
-                scale: 0.9144_f64.powi(2),
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "acre",
-            UnitDef {
-                dim: Dimension::Area,
-                scale: 4046.8564224,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "ha",
-            UnitDef {
-                dim: Dimension::Area,
-                scale: 10000.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
+        add(&["m2"], linear(Area, 1.0, Metric));
+        add(&["mi2"], linear(Area, MILE * MILE, NoPrefix));
+        add(
+            &["Nmi2"],
+            linear(Area, NAUTICAL_MILE * NAUTICAL_MILE, NoPrefix),
+        );
+        add(&["in2"], linear(Area, INCH * INCH, NoPrefix));
+        add(&["ft2"], linear(Area, FOOT * FOOT, NoPrefix));
+        add(&["yd2"], linear(Area, YARD * YARD, NoPrefix));
+        add(&["ang2"], linear(Area, ANGSTROM * ANGSTROM, Metric));
+        add(&["ly2"], linear(Area, LIGHT_YEAR * LIGHT_YEAR, NoPrefix));
+        add(
+            &["Picapt2", "Pica2"],
+            linear(Area, PICA_POINT * PICA_POINT, NoPrefix),
+        );
+        add(&["pica2"], linear(Area, PICA * PICA, NoPrefix));
+        add(&["Morgen"], linear(Area, 2500.0, NoPrefix));
+        add(&["ar"], linear(Area, 100.0, Metric));
+        add(
+            &["acre", "uk_acre"],
+            linear(Area, 4_046.856_422_4, NoPrefix),
+        );
+        add(&["us_acre"], linear(Area, 4_046.872_609_874_252, NoPrefix));
+        add(&["ha"], linear(Area, 10_000.0, NoPrefix));
 
         // Volume (base: cubic meter).
-        units.insert(
-            "m3",
-            UnitDef {
-                dim: Dimension::Volume,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "in3",
-            UnitDef {
-                dim: Dimension::Volume,
-                scale: 0.0254_f64.powi(3),
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "ft3",
-            UnitDef {
-                dim: Dimension::Volume,
-                scale: 0.3048_f64.powi(3),
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "yd3",
-            UnitDef {
-                dim: Dimension::Volume,
-                scale: 0.9144_f64.powi(3),
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        // Liter.
-        let liter = UnitDef {
-            dim: Dimension::Volume,
-            scale: 0.001,
-            offset: 0.0,
-            prefix: PrefixSupport::Metric,
-        };
-        units.insert("L", liter);
-        units.insert("l", liter);
-
-        units.insert(
-            "gal",
-            UnitDef {
-                dim: Dimension::Volume,
-                // US liquid gallon.
-                scale: 0.003_785_411_784,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
+        add(&["m3"], linear(Volume, 1.0, Metric));
+        add(&["mi3"], linear(Volume, MILE.powi(3), NoPrefix));
+        add(&["Nmi3"], linear(Volume, NAUTICAL_MILE.powi(3), NoPrefix));
+        add(&["in3"], linear(Volume, INCH.powi(3), NoPrefix));
+        add(&["ft3"], linear(Volume, FOOT.powi(3), NoPrefix));
+        add(&["yd3"], linear(Volume, YARD.powi(3), NoPrefix));
+        add(&["ang3"], linear(Volume, ANGSTROM.powi(3), Metric));
+        add(&["ly3"], linear(Volume, LIGHT_YEAR.powi(3), NoPrefix));
+        add(
+            &["Picapt3", "Pica3"],
+            linear(Volume, PICA_POINT.powi(3), NoPrefix),
+        );
+        add(&["pica3"], linear(Volume, PICA.powi(3), NoPrefix));
+        add(&["l", "L", "lt"], linear(Volume, 0.001, Metric));
+        add(&["tsp"], linear(Volume, US_GALLON / 768.0, NoPrefix));
+        add(&["tspm"], linear(Volume, 5e-6, NoPrefix));
+        add(&["tbs"], linear(Volume, US_GALLON / 256.0, NoPrefix));
+        add(&["oz"], linear(Volume, US_GALLON / 128.0, NoPrefix));
+        add(&["cup"], linear(Volume, US_GALLON / 16.0, NoPrefix));
+        add(&["pt", "us_pt"], linear(Volume, US_GALLON / 8.0, NoPrefix));
+        add(&["uk_pt"], linear(Volume, UK_GALLON / 8.0, NoPrefix));
+        add(&["qt"], linear(Volume, US_GALLON / 4.0, NoPrefix));
+        add(&["uk_qt"], linear(Volume, UK_GALLON / 4.0, NoPrefix));
+        add(&["gal"], linear(Volume, US_GALLON, NoPrefix));
+        add(&["uk_gal"], linear(Volume, UK_GALLON, NoPrefix));
+        add(&["barrel"], linear(Volume, 42.0 * US_GALLON, NoPrefix));
+        add(&["bushel"], linear(Volume, 0.035_239_070_166_88, NoPrefix));
+        add(
+            &["regton", "GRT"],
+            linear(Volume, 100.0 * FOOT.powi(3), NoPrefix),
+        );
+        add(&["MTON"], linear(Volume, 40.0 * FOOT.powi(3), NoPrefix));
 
         // Mass (base: gram).
-        units.insert(
-            "g",
-            UnitDef {
-                dim: Dimension::Mass,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "lbm",
-            UnitDef {
-                dim: Dimension::Mass,
-                scale: 453.59237,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "ozm",
-            UnitDef {
-                dim: Dimension::Mass,
-                scale: 453.59237 / 16.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        // Slug.
-        units.insert(
-            "sg",
-            UnitDef {
-                dim: Dimension::Mass,
-                // 1 slug = 32.17404855643044 lbm.
-                scale: 32.174_048_556_430_44 * 453.59237,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
+        add(&["g"], linear(Mass, 1.0, Metric));
+        // 1 slug = 32.17404855643044 lbm.
+        add(
+            &["sg"],
+            linear(Mass, 32.174_048_556_430_44 * POUND_MASS, NoPrefix),
+        );
+        add(&["lbm"], linear(Mass, POUND_MASS, NoPrefix));
+        add(&["u"], linear(Mass, 1.660_538_782e-24, Metric));
+        add(&["ozm"], linear(Mass, POUND_MASS / 16.0, NoPrefix));
+        add(&["grain"], linear(Mass, 0.064_798_91, NoPrefix));
+        add(
+            &["cwt", "shweight"],
+            linear(Mass, 100.0 * POUND_MASS, NoPrefix),
+        );
+        add(
+            &["uk_cwt", "lcwt", "hweight"],
+            linear(Mass, 112.0 * POUND_MASS, NoPrefix),
+        );
+        add(&["stone"], linear(Mass, 14.0 * POUND_MASS, NoPrefix));
+        add(&["ton"], linear(Mass, 2000.0 * POUND_MASS, NoPrefix));
+        add(
+            &["uk_ton", "LTON", "brton"],
+            linear(Mass, 2240.0 * POUND_MASS, NoPrefix),
         );
 
         // Time (base: second).
-        let second = UnitDef {
-            dim: Dimension::Time,
-            scale: 1.0,
-            offset: 0.0,
-            prefix: PrefixSupport::Metric,
-        };
-        units.insert("sec", second);
-        units.insert("s", second);
-        units.insert(
-            "mn",
-            UnitDef {
-                dim: Dimension::Time,
-                scale: 60.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "hr",
-            UnitDef {
-                dim: Dimension::Time,
-                scale: 3600.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "day",
-            UnitDef {
-                dim: Dimension::Time,
-                scale: 86400.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "yr",
-            UnitDef {
-                dim: Dimension::Time,
-                // Julian year.
-                scale: 31_557_600.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
+        add(&["sec", "s"], linear(Time, 1.0, Metric));
+        add(&["mn", "min"], linear(Time, 60.0, NoPrefix));
+        add(&["hr"], linear(Time, 3600.0, NoPrefix));
+        add(&["day", "d"], linear(Time, 86_400.0, NoPrefix));
+        // Julian year.
+        add(&["yr"], linear(Time, 31_557_600.0, NoPrefix));
 
         // Pressure (base: pascal).
-        units.insert(
-            "Pa",
-            UnitDef {
-                dim: Dimension::Pressure,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "atm",
-            UnitDef {
-                dim: Dimension::Pressure,
-                scale: 101_325.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "psi",
-            UnitDef {
-                dim: Dimension::Pressure,
-                scale: 6_894.757_293_168_361,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "Torr",
-            UnitDef {
-                dim: Dimension::Pressure,
-                scale: 101_325.0 / 760.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "mmHg",
-            UnitDef {
-                dim: Dimension::Pressure,
-                scale: 101_325.0 / 760.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "bar",
-            UnitDef {
-                dim: Dimension::Pressure,
-                scale: 100_000.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
+        add(&["Pa", "p"], linear(Pressure, 1.0, Metric));
+        add(&["atm", "at"], linear(Pressure, 101_325.0, Metric));
+        add(&["mmHg"], linear(Pressure, 101_325.0 / 760.0, Metric));
+        add(&["Torr"], linear(Pressure, 101_325.0 / 760.0, NoPrefix));
+        add(&["psi"], linear(Pressure, 6_894.757_293_168_361, NoPrefix));
+        add(&["bar"], linear(Pressure, 100_000.0, Metric));
 
         // Force (base: newton).
-        units.insert(
-            "N",
-            UnitDef {
-                dim: Dimension::Force,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "dyn",
-            UnitDef {
-                dim: Dimension::Force,
-                scale: 1e-5,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "lbf",
-            UnitDef {
-                dim: Dimension::Force,
-                scale: 4.448_221_615_260_5,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
+        add(&["N"], linear(Force, 1.0, Metric));
+        add(&["dyn", "dy"], linear(Force, 1e-5, Metric));
+        add(&["lbf"], linear(Force, 4.448_221_615_260_5, NoPrefix));
+        add(&["pond"], linear(Force, 9.806_65e-3, Metric));
 
         // Energy (base: joule).
-        units.insert(
-            "J",
-            UnitDef {
-                dim: Dimension::Energy,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "e",
-            UnitDef {
-                dim: Dimension::Energy,
-                // erg
-                scale: 1e-7,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "cal",
-            UnitDef {
-                dim: Dimension::Energy,
-                // International table calorie.
-                scale: 4.1868,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "Wh",
-            UnitDef {
-                dim: Dimension::Energy,
-                scale: 3600.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "BTU",
-            UnitDef {
-                dim: Dimension::Energy,
-                // British thermal unit (IT).
-                scale: 1055.055_852_62,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
+        add(&["J"], linear(Energy, 1.0, Metric));
+        // erg
+        add(&["e"], linear(Energy, 1e-7, Metric));
+        // Thermochemical and International Table calories.
+        add(&["c"], linear(Energy, 4.184, Metric));
+        add(&["cal"], linear(Energy, 4.1868, Metric));
+        add(&["eV", "ev"], linear(Energy, 1.602_176_487e-19, Metric));
+        add(
+            &["HPh", "hh"],
+            linear(Energy, HORSEPOWER * 3600.0, NoPrefix),
+        );
+        add(&["Wh", "wh"], linear(Energy, 3600.0, Metric));
+        // Excel's "foot-pound" is the foot-poundal (ft * lbm * ft/s^2).
+        add(
+            &["flb"],
+            linear(Energy, FOOT * FOOT * POUND_MASS / 1000.0, NoPrefix),
+        );
+        // British thermal unit (IT).
+        add(&["BTU", "btu"], linear(Energy, 1_055.055_852_62, NoPrefix));
 
         // Power (base: watt).
-        units.insert(
-            "W",
-            UnitDef {
-                dim: Dimension::Power,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "hp",
-            UnitDef {
-                dim: Dimension::Power,
-                // Mechanical horsepower.
-                scale: 745.699_871_582_270_2,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
+        add(&["W", "w"], linear(Power, 1.0, Metric));
+        // Mechanical horsepower.
+        add(&["HP", "h", "hp"], linear(Power, HORSEPOWER, NoPrefix));
+        add(&["PS"], linear(Power, 735.498_75, NoPrefix));
 
-        // Temperature (base: kelvin).
-        units.insert(
-            "K",
+        // Temperature (base: kelvin). `base = value * scale + offset`.
+        add(&["K", "kel"], linear(Temperature, 1.0, Metric));
+        add(
+            &["C", "cel"],
             UnitDef {
-                dim: Dimension::Temperature,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "C",
-            UnitDef {
-                dim: Dimension::Temperature,
-                scale: 1.0,
                 offset: 273.15,
-                prefix: PrefixSupport::None,
+                ..linear(Temperature, 1.0, NoPrefix)
             },
         );
-        units.insert(
-            "F",
+        add(
+            &["F", "fah"],
             UnitDef {
-                dim: Dimension::Temperature,
-                scale: 5.0 / 9.0,
                 offset: 273.15 - 32.0 * (5.0 / 9.0),
-                prefix: PrefixSupport::None,
-            },
-        );
-
-        // Speed (base: meter/second).
-        units.insert(
-            "m/s",
-            UnitDef {
-                dim: Dimension::Speed,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
+                ..linear(Temperature, 5.0 / 9.0, NoPrefix)
             },
         );
-        units.insert(
-            "m/hr",
+        add(&["Rank"], linear(Temperature, 5.0 / 9.0, NoPrefix));
+        add(
+            &["Reau"],
             UnitDef {
-                dim: Dimension::Speed,
-                scale: 1.0 / 3600.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "mph",
-            UnitDef {
-                dim: Dimension::Speed,
-                scale: 1609.344 / 3600.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
-        units.insert(
-            "kn",
-            UnitDef {
-                dim: Dimension::Speed,
-                scale: 1852.0 / 3600.0,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
+                offset: 273.15,
+                ..linear(Temperature, 1.25, NoPrefix)
             },
         );
 
+        // Speed (base: meter/second).
+        add(&["m/s", "m/sec"], linear(Speed, 1.0, NoPrefix));
+        add(&["m/h", "m/hr"], linear(Speed, 1.0 / 3600.0, NoPrefix));
+        add(&["mph"], linear(Speed, MILE / 3600.0, NoPrefix));
+        add(&["kn"], linear(Speed, NAUTICAL_MILE / 3600.0, NoPrefix));
+        // Admiralty knot: 6080 ft/hr.
+        add(&["admkn"], linear(Speed, 6080.0 * FOOT / 3600.0, NoPrefix));
+
         // Information (base: bit).
-        units.insert(
-            "bit",
-            UnitDef {
-                dim: Dimension::Information,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Binary,
-            },
-        );
-        units.insert(
-            "byte",
-            UnitDef {
-                dim: Dimension::Information,
-                scale: 8.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Binary,
-            },
-        );
+        add(&["bit"], linear(Information, 1.0, MetricAndBinary));
+        add(&["byte"], linear(Information, 8.0, MetricAndBinary));
 
         // Magnetism (base: tesla).
-        units.insert(
-            "T",
-            UnitDef {
-                dim: Dimension::MagneticFluxDensity,
-                scale: 1.0,
-                offset: 0.0,
-                prefix: PrefixSupport::Metric,
-            },
-        );
-        units.insert(
-            "ga",
-            UnitDef {
-                dim: Dimension::MagneticFluxDensity,
-                // 1 gauss = 1e-4 tesla.
-                scale: 1e-4,
-                offset: 0.0,
-                prefix: PrefixSupport::None,
-            },
-        );
+        add(&["T"], linear(MagneticFluxDensity, 1.0, Metric));
+        // 1 gauss = 1e-4 tesla.
+        add(&["ga"], linear(MagneticFluxDensity, 1e-4, Metric));
 
         units
     })
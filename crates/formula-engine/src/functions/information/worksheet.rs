@@ -267,6 +267,7 @@ fn format_options_for_cell(ctx: &dyn FunctionContext) -> formula_format::FormatO
         locale: ctx.value_locale().separators,
         date_system: match ctx.date_system() {
             ExcelDateSystem::Excel1900 { .. } => formula_format::DateSystem::Excel1900,
+            ExcelDateSystem::Excel1900Proleptic => formula_format::DateSystem::Excel1900Proleptic,
             ExcelDateSystem::Excel1904 => formula_format::DateSystem::Excel1904,
         },
     }
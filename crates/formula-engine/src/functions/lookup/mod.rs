@@ -252,7 +252,7 @@ fn lookup_cmp(ctx: &LookupContext, a: &Value, b: &Value) -> Ordering {
     }
 
     if let (Some(a), Some(b)) = (text_like_str(ctx, a), text_like_str(ctx, b)) {
-        return crate::value::cmp_case_insensitive(a.as_ref(), b.as_ref());
+        return crate::value::cmp_text_collated(a.as_ref(), b.as_ref(), ctx.value_locale.collation);
     }
 
     match (a, b) {
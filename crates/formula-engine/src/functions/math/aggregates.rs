@@ -508,7 +508,8 @@ pub(crate) fn coerce_sumproduct_number(
 /// SUBTOTAL(function_num, ref1, [ref2], ...)
 ///
 /// This implements the common `function_num` set (1-11 / 101-111). Hidden rows
-/// / filtered ranges are handled by the caller (range iterator).
+/// / filtered ranges are handled by the caller (range iterator): 1-11 skip
+/// AutoFilter-hidden rows, 101-111 skip every hidden row.
 pub fn subtotal(function_num: i32, values: &[Value]) -> Result<f64, ErrorKind> {
     let base = if function_num >= 100 {
         function_num - 100
@@ -535,7 +536,8 @@ pub fn subtotal(function_num: i32, values: &[Value]) -> Result<f64, ErrorKind> {
 /// AGGREGATE(function_num, options, ref1, [ref2])
 ///
 /// This intentionally implements the most common aggregation subtypes (1-11).
-/// `options` only controls whether errors are ignored.
+/// Here `options` only controls whether errors are ignored; the caller drops
+/// hidden rows for options 1/3/5/7.
 pub fn aggregate(function_num: i32, options: i32, values: &[Value]) -> Result<f64, ErrorKind> {
     let ignore_errors = matches!(options, 2 | 3 | 6 | 7);
     match function_num {
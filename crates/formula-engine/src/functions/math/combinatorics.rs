@@ -51,9 +51,20 @@ fn combin_u64(n: u64, k: u64) -> ExcelResult<f64> {
         return Ok(1.0);
     }
 
-    let mut acc = 1.0;
-    // Multiplicative formula: C(n,k) = Π_{i=1..k} (n-k+i)/i
-    for i in 1..=k {
+    // Multiplicative formula: C(n,k) = Π_{i=1..k} (n-k+i)/i. After step `i` the running value is
+    // C(n-k+i, i), an integer, so integer arithmetic stays exact while the product fits in u128.
+    let mut exact: u128 = 1;
+    let mut i = 1;
+    while i <= k {
+        let Some(product) = exact.checked_mul(u128::from(n - k + i)) else {
+            break;
+        };
+        exact = product / u128::from(i);
+        i += 1;
+    }
+
+    let mut acc = exact as f64;
+    for i in i..=k {
         let num = (n - k + i) as f64;
         let den = i as f64;
         acc *= num / den;
@@ -61,7 +72,7 @@ fn combin_u64(n: u64, k: u64) -> ExcelResult<f64> {
             return Err(ExcelError::Num);
         }
     }
-    Ok(acc)
+    checked_out(acc)
 }
 
 /// COMBIN(number, number_chosen)
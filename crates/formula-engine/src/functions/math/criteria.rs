@@ -51,6 +51,39 @@ impl TextCriteria {
     }
 }
 
+/// Which coercion an equality index over a criteria range is built with.
+///
+/// Numeric and text equality coerce candidate cells differently (e.g. `"1"` and `1` share a
+/// numeric key but `TRUE` and `"TRUE"` only share a text key), so a range needs one index per
+/// kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum CriteriaIndexKind {
+    Number,
+    Text,
+}
+
+/// Exact-match key for an equality index over a criteria range.
+///
+/// A cell matches an indexable criteria iff the cell's key (see
+/// [`Criteria::index_key_for_value`]) equals the criteria's own key (see [`Criteria::index_key`]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum CriteriaIndexKey {
+    /// Bit pattern of the coerced number, with `-0.0` normalized to `0.0`.
+    Number(u64),
+    /// Case-folded coerced text.
+    Text(String),
+}
+
+impl CriteriaIndexKey {
+    fn number(n: f64) -> Option<Self> {
+        if n.is_nan() {
+            return None;
+        }
+        let n = if n == 0.0 { 0.0 } else { n };
+        Some(Self::Number(n.to_bits()))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Criteria {
     op: CriteriaOp,
@@ -204,11 +237,64 @@ impl Criteria {
 
         match &self.rhs {
             CriteriaRhs::Number(n) => Some(NumericCriteria::new(op, *n)),
-            CriteriaRhs::Bool(b) => Some(NumericCriteria::new(op, if *b { 1.0 } else { 0.0 })),
             _ => None,
         }
     }
 
+    /// If this criteria is a plain equality test that can be answered from an equality index,
+    /// return the index kind and the key matching cells must have.
+    ///
+    /// Wildcard text patterns, boolean/blank/error criteria and ordering comparisons are not
+    /// indexable.
+    pub(crate) fn index_key(&self) -> Option<(CriteriaIndexKind, CriteriaIndexKey)> {
+        if self.op != CriteriaOp::Eq {
+            return None;
+        }
+        match &self.rhs {
+            CriteriaRhs::Number(n) => {
+                Some((CriteriaIndexKind::Number, CriteriaIndexKey::number(*n)?))
+            }
+            CriteriaRhs::Text(pattern) if !pattern.wildcard.has_wildcards() => Some((
+                CriteriaIndexKind::Text,
+                CriteriaIndexKey::Text(pattern.literal_folded.clone()),
+            )),
+            CriteriaRhs::Text(_)
+            | CriteriaRhs::Bool(_)
+            | CriteriaRhs::Blank
+            | CriteriaRhs::Error(_) => None,
+        }
+    }
+
+    /// Compute the equality index key of a candidate cell value under this criteria's locales.
+    ///
+    /// Returns `None` for values that can never satisfy an indexable criteria of `kind` (errors,
+    /// blanks, booleans and non-numeric text for [`CriteriaIndexKind::Number`], blanks for
+    /// [`CriteriaIndexKind::Text`]).
+    pub(crate) fn index_key_for_value(
+        &self,
+        kind: CriteriaIndexKind,
+        value: &Value,
+    ) -> Option<CriteriaIndexKey> {
+        if matches!(
+            value,
+            Value::Error(_) | Value::Lambda(_) | Value::Spill { .. }
+        ) {
+            return None;
+        }
+        match kind {
+            CriteriaIndexKind::Number => {
+                CriteriaIndexKey::number(coerce_to_number(value, self.op, self.number_locale)?)
+            }
+            CriteriaIndexKind::Text => {
+                let text = coerce_to_text(value, self.value_locale)?;
+                Some(CriteriaIndexKey::Text(crate::value::with_casefolded_key(
+                    &text,
+                    |folded| folded.to_string(),
+                )))
+            }
+        }
+    }
+
     pub fn matches(&self, value: &Value) -> bool {
         // Criteria functions never propagate errors from candidate cells. Errors only match
         // error criteria.
@@ -226,12 +312,7 @@ impl Criteria {
                 _ => false,
             },
             CriteriaRhs::Error(_) => matches_error_criteria(&self.op, &self.rhs, value),
-            CriteriaRhs::Bool(b) => matches_numeric_criteria(
-                self.op,
-                if *b { 1.0 } else { 0.0 },
-                value,
-                self.number_locale,
-            ),
+            CriteriaRhs::Bool(b) => matches_bool_criteria(self.op, *b, value),
             CriteriaRhs::Number(n) => {
                 matches_numeric_criteria(self.op, *n, value, self.number_locale)
             }
@@ -262,8 +343,10 @@ fn matches_error_criteria(op: &CriteriaOp, rhs: &CriteriaRhs, value: &Value) ->
 }
 
 fn matches_numeric_criteria(op: CriteriaOp, rhs: f64, value: &Value, locale: NumberLocale) -> bool {
-    let Some(value_num) = coerce_to_number(value, locale) else {
-        return false;
+    // Values that don't compare as numbers (blanks, booleans, other text) are never equal to or
+    // ordered against a number, so they only satisfy `<>`.
+    let Some(value_num) = coerce_to_number(value, op, locale) else {
+        return op == CriteriaOp::Ne;
     };
 
     match op {
@@ -276,6 +359,26 @@ fn matches_numeric_criteria(op: CriteriaOp, rhs: f64, value: &Value, locale: Num
     }
 }
 
+fn matches_bool_criteria(op: CriteriaOp, rhs: bool, value: &Value) -> bool {
+    // Booleans only compare against booleans; `TRUE` does not match `1` (or the text "TRUE").
+    let b = match value {
+        Value::Bool(b) => *b,
+        Value::Array(arr) => match arr.top_left() {
+            Value::Bool(b) => b,
+            _ => return op == CriteriaOp::Ne,
+        },
+        _ => return op == CriteriaOp::Ne,
+    };
+    match op {
+        CriteriaOp::Eq => b == rhs,
+        CriteriaOp::Ne => b != rhs,
+        CriteriaOp::Lt => !b & rhs,
+        CriteriaOp::Lte => b <= rhs,
+        CriteriaOp::Gt => b & !rhs,
+        CriteriaOp::Gte => b >= rhs,
+    }
+}
+
 fn matches_text_criteria(
     op: CriteriaOp,
     pattern: &TextCriteria,
@@ -288,6 +391,13 @@ fn matches_text_criteria(
         // values still satisfy the predicate because they are not equal to the text pattern.
         return matches!(op, CriteriaOp::Ne);
     };
+    // Text ordering (`>b`, `<=m`, ...) only applies to text; numbers and booleans never sort
+    // against a text criteria.
+    if !matches!(op, CriteriaOp::Eq | CriteriaOp::Ne)
+        && matches!(value, Value::Number(_) | Value::Bool(_))
+    {
+        return false;
+    }
 
     crate::value::with_casefolded_key(&value_text, |value_folded| match op {
         CriteriaOp::Eq => {
@@ -476,15 +586,22 @@ fn is_blank_value(value: &Value) -> bool {
     }
 }
 
-fn coerce_to_number(value: &Value, locale: NumberLocale) -> Option<f64> {
+/// Coerce a candidate cell for comparison against a numeric criteria.
+///
+/// Only numbers compare as numbers, except that numeric text also matches `=`/`<>` (Excel counts
+/// the text "5" for `COUNTIF(range, 5)` but not for `COUNTIF(range, ">4")`). Blanks and booleans
+/// never compare as numbers.
+fn coerce_to_number(value: &Value, op: CriteriaOp, locale: NumberLocale) -> Option<f64> {
+    let text_compares = matches!(op, CriteriaOp::Eq | CriteriaOp::Ne);
     match value {
         Value::Number(n) => Some(*n),
-        Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
-        Value::Blank => Some(0.0),
-        Value::Text(s) => parse_number(s, locale).ok(),
-        Value::Entity(entity) => parse_number(entity.display.as_str(), locale).ok(),
-        Value::Record(record) => parse_number(record.display.as_str(), locale).ok(),
-        Value::Array(arr) => coerce_to_number(&arr.top_left(), locale),
+        Value::Text(s) if text_compares => parse_numeric_text(s, locale),
+        Value::Entity(entity) if text_compares => parse_numeric_text(&entity.display, locale),
+        Value::Record(record) if text_compares => parse_numeric_text(&record.display, locale),
+        Value::Array(arr) => coerce_to_number(&arr.top_left(), op, locale),
+        Value::Bool(_) | Value::Blank | Value::Text(_) | Value::Entity(_) | Value::Record(_) => {
+            None
+        }
         Value::Error(_)
         | Value::Reference(_)
         | Value::ReferenceUnion(_)
@@ -493,6 +610,14 @@ fn coerce_to_number(value: &Value, locale: NumberLocale) -> Option<f64> {
     }
 }
 
+/// Parse numeric text for criteria equality. Unlike arithmetic coercion, empty text is not `0`.
+pub(crate) fn parse_numeric_text(text: &str, locale: NumberLocale) -> Option<f64> {
+    if text.trim().is_empty() {
+        return None;
+    }
+    parse_number(text, locale).ok()
+}
+
 fn coerce_to_text(value: &Value, value_locale: ValueLocaleConfig) -> Option<String> {
     match value {
         Value::Blank => None,
@@ -117,6 +117,18 @@ pub fn quotient(numerator: f64, denominator: f64) -> ExcelResult<f64> {
     checked_out((numerator / denominator).trunc())
 }
 
+/// GCD/LCM only accept arguments (and produce LCM results) below 2^53, the largest range where
+/// every integer is exactly representable as an f64.
+const GCD_LCM_LIMIT: u64 = 1 << 53;
+
+fn gcd_lcm_arg(number: f64) -> ExcelResult<u64> {
+    let v = trunc_to_u64_nonnegative(number)?;
+    if v >= GCD_LCM_LIMIT {
+        return Err(ExcelError::Num);
+    }
+    Ok(v)
+}
+
 fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
     while b != 0 {
         let r = a % b;
@@ -130,11 +142,11 @@ fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
 pub fn gcd(numbers: &[f64]) -> ExcelResult<f64> {
     let mut g: u64 = 0;
     for &n in numbers {
-        let v = trunc_to_u64_nonnegative(n)?;
-        g = gcd_u64(g, v);
-        if g == 1 {
-            // Early-out: 1 is the smallest possible GCD.
-            break;
+        // Keep validating after the GCD reaches 1: a later negative or out-of-range argument
+        // still makes the whole call `#NUM!`.
+        let v = gcd_lcm_arg(n)?;
+        if g != 1 {
+            g = gcd_u64(g, v);
         }
     }
     Ok(g as f64)
@@ -147,20 +159,23 @@ pub fn lcm(numbers: &[f64]) -> ExcelResult<f64> {
     }
 
     let mut acc: u64 = 1;
+    let mut has_zero = false;
     for &n in numbers {
-        let v = trunc_to_u64_nonnegative(n)?;
-        if v == 0 {
-            return Ok(0.0);
+        let v = gcd_lcm_arg(n)?;
+        // A zero makes the LCM 0, but later arguments are still validated.
+        has_zero |= v == 0;
+        if has_zero {
+            continue;
         }
         let g = gcd_u64(acc, v);
         let res = (acc as u128 / g as u128) * (v as u128);
-        if res > (u64::MAX as u128) {
+        if res >= u128::from(GCD_LCM_LIMIT) {
             return Err(ExcelError::Num);
         }
         acc = res as u64;
     }
 
-    Ok(acc as f64)
+    Ok(if has_zero { 0.0 } else { acc as f64 })
 }
 
 /// SQRTPI(number)
@@ -38,16 +38,22 @@ pub fn roman(number: i64, form: Option<i64>) -> ExcelResult<String> {
 
 /// ARABIC(text)
 ///
-/// Parse an Excel-compatible Roman numeral and return its value in `[0, 3999]`.
+/// Parse an Excel-compatible Roman numeral and return its value in `[-3999, 3999]`.
 ///
 /// The parser is case-insensitive and accepts any Roman numeral string that can be
-/// produced by [`roman`] for `form` 0..=4.
+/// produced by [`roman`] for `form` 0..=4. A leading `-` negates the result, as in Excel.
 pub fn arabic(text: &str) -> ExcelResult<i64> {
     let trimmed = text.trim();
     if trimmed.is_empty() {
         // Excel's ROMAN(0) yields an empty string; treat empty as 0 for round-tripping.
         return Ok(0);
     }
+    if let Some(rest) = trimmed.strip_prefix('-') {
+        if rest.is_empty() || rest.starts_with('-') {
+            return Err(ExcelError::Value);
+        }
+        return arabic(rest).map(|n| -n);
+    }
 
     let mut total: i64 = 0;
     let bytes = trimmed.as_bytes();
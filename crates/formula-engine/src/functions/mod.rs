@@ -111,6 +111,7 @@ fn force_link_inventory_modules() {
         builtins_roman::__force_link,
         builtins_select::__force_link,
         builtins_reference::__force_link,
+        builtins_rich_values::__force_link,
         builtins_statistical::__force_link,
         builtins_statistical_distributions::__force_link,
         builtins_statistical_ets::__force_link,
@@ -216,6 +217,18 @@ pub enum ArgValue {
     ReferenceUnion(Vec<Reference>),
 }
 
+impl ArgValue {
+    /// Convert to a [`Value`] without dereferencing, so functions that pass an argument through
+    /// (CHOOSE, IF, LET, ...) still return a reference to callers such as `ISREF`.
+    pub(crate) fn into_value(self) -> Value {
+        match self {
+            ArgValue::Scalar(v) => v,
+            ArgValue::Reference(r) => Value::Reference(r),
+            ArgValue::ReferenceUnion(ranges) => Value::ReferenceUnion(ranges),
+        }
+    }
+}
+
 pub trait FunctionContext {
     fn eval_arg(&self, expr: &CompiledExpr) -> ArgValue;
     fn eval_scalar(&self, expr: &CompiledExpr) -> Value;
@@ -401,6 +414,13 @@ pub trait FunctionContext {
         None
     }
 
+    /// Return why a row is hidden (user, outline collapse and/or AutoFilter).
+    ///
+    /// Used by `SUBTOTAL` and `AGGREGATE` to skip hidden rows.
+    fn row_hidden_state(&self, _sheet_id: &SheetId, _row: u32) -> formula_model::HiddenState {
+        formula_model::HiddenState::default()
+    }
+
     /// Return per-column properties (width/hidden/default style), if present.
     ///
     /// `ColProperties.width` is expressed in Excel "character" units (OOXML `col/@width`), not pixels.
@@ -314,93 +314,51 @@ pub fn median(values: &[f64]) -> Result<f64, ErrorKind> {
     }
 }
 
-pub fn mode_sngl(values: &[f64]) -> Result<f64, ErrorKind> {
-    if values.is_empty() {
-        return Err(ErrorKind::NA);
-    }
-    let mut sorted = values.to_vec();
-    sort_numbers(&mut sorted);
-
-    let mut best_count = 1usize;
-    let mut best_value: Option<f64> = None;
-
-    let mut current_value = sorted[0];
-    let mut current_count = 1usize;
-
-    for &x in sorted.iter().skip(1) {
-        if x == current_value {
-            current_count += 1;
-            continue;
-        }
-
-        if current_count > best_count {
-            best_count = current_count;
-            best_value = Some(current_value);
+/// Groups equal values as `(value, count, first_index)`, ordered by first appearance.
+///
+/// Excel reports ties in the order the values first occur, not in sorted order. `-0.0` and `0.0`
+/// compare equal, so they are folded into the same group.
+fn frequency_groups(values: &[f64]) -> Vec<(f64, usize, usize)> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    // Stable sort: within a group, the first index visited is the earliest occurrence.
+    order.sort_by(|&a, &b| (values[a] + 0.0).total_cmp(&(values[b] + 0.0)));
+
+    let mut groups: Vec<(f64, usize, usize)> = Vec::new();
+    for idx in order {
+        let value = values[idx] + 0.0;
+        match groups.last_mut() {
+            Some((current, count, _)) if *current == value => *count += 1,
+            _ => groups.push((value, 1, idx)),
         }
-
-        current_value = x;
-        current_count = 1;
-    }
-
-    if current_count > best_count {
-        best_count = current_count;
-        best_value = Some(current_value);
-    }
-
-    match (best_count, best_value) {
-        (count, Some(v)) if count >= 2 => Ok(v),
-        _ => Err(ErrorKind::NA),
     }
+    groups.sort_by_key(|&(_, _, first)| first);
+    groups
 }
 
-pub fn mode_mult(values: &[f64]) -> Result<Vec<f64>, ErrorKind> {
-    if values.is_empty() {
+pub fn mode_sngl(values: &[f64]) -> Result<f64, ErrorKind> {
+    let groups = frequency_groups(values);
+    let best_count = groups.iter().map(|&(_, count, _)| count).max().unwrap_or(0);
+    if best_count < 2 {
         return Err(ErrorKind::NA);
     }
-    let mut sorted = values.to_vec();
-    sort_numbers(&mut sorted);
-
-    let mut best_count = 1usize;
-    let mut modes: Vec<f64> = Vec::new();
-
-    let mut current_value = sorted[0];
-    let mut current_count = 1usize;
-
-    for &x in sorted.iter().skip(1) {
-        if x == current_value {
-            current_count += 1;
-            continue;
-        }
-
-        match current_count.cmp(&best_count) {
-            Ordering::Greater => {
-                best_count = current_count;
-                modes.clear();
-                modes.push(current_value);
-            }
-            Ordering::Equal if current_count == best_count => modes.push(current_value),
-            _ => {}
-        }
-
-        current_value = x;
-        current_count = 1;
-    }
-
-    match current_count.cmp(&best_count) {
-        Ordering::Greater => {
-            best_count = current_count;
-            modes.clear();
-            modes.push(current_value);
-        }
-        Ordering::Equal if current_count == best_count => modes.push(current_value),
-        _ => {}
-    }
+    groups
+        .into_iter()
+        .find(|&(_, count, _)| count == best_count)
+        .map(|(value, _, _)| value)
+        .ok_or(ErrorKind::NA)
+}
 
+pub fn mode_mult(values: &[f64]) -> Result<Vec<f64>, ErrorKind> {
+    let groups = frequency_groups(values);
+    let best_count = groups.iter().map(|&(_, count, _)| count).max().unwrap_or(0);
     if best_count < 2 {
         return Err(ErrorKind::NA);
     }
-
-    Ok(modes)
+    Ok(groups
+        .into_iter()
+        .filter(|&(_, count, _)| count == best_count)
+        .map(|(value, _, _)| value)
+        .collect())
 }
 
 pub fn large(values: &[f64], k: usize) -> Result<f64, ErrorKind> {
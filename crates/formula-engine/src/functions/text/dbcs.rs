@@ -19,25 +19,30 @@
 //!
 //! In non-DBCS codepages, they behave as identity transforms.
 //!
+//! `CHAR` / `CODE` map between characters and their legacy codepage codes (e.g. `CHAR(128)` is
+//! `€` under 1252). Under DBCS codepages, double-byte characters use the lead/trail byte pair as
+//! a single code (e.g. `CODE("あ")` is `33440` under 932). The Unicode counterparts `UNICHAR` /
+//! `UNICODE` do not depend on the codepage.
+//!
 //! `PHONETIC` depends on per-cell phonetic guide metadata (furigana).
 //! When phonetic metadata is present for a referenced cell, `PHONETIC(reference)`
 //! returns that stored string. When phonetic metadata is absent (the common
 //! case), Excel falls back to the referenced cell’s displayed text, so the
 //! engine returns the referenced value coerced to text using the current
-//! locale-aware formatting rules.
+//! locale-aware formatting rules. Multi-cell references return the
+//! concatenated furigana/text of the range rather than an array.
 //!
 //! Note: Excel's DBCS semantics contain many locale-specific edge cases. This module implements
 //! the core behaviors needed by typical Japanese/Chinese/Korean workbooks, but may need to be
 //! extended as additional Excel oracle cases are added.
 
-use crate::eval::CompiledExpr;
-use crate::eval::MAX_MATERIALIZED_ARRAY_CELLS;
+use crate::eval::{CellAddr, CompiledExpr};
 use crate::functions::array_lift;
 use crate::functions::text::search_pattern::{
     matches_pattern_with_memo, min_required_hay_len, parse_search_pattern_folded, PatternToken,
 };
 use crate::functions::{call_function, ArgValue, FunctionContext, Reference};
-use crate::value::{Array, ErrorKind, Value};
+use crate::value::{ErrorKind, Value};
 use encoding_rs::{
     Encoding, BIG5, EUC_KR, GBK, SHIFT_JIS, UTF_8, WINDOWS_1250, WINDOWS_1251, WINDOWS_1252,
     WINDOWS_1253, WINDOWS_1254, WINDOWS_1255, WINDOWS_1256, WINDOWS_1257, WINDOWS_1258,
@@ -257,6 +262,56 @@ pub(crate) fn dbcs_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value
     })
 }
 
+pub(crate) fn char_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
+    let number = array_lift::eval_arg(ctx, &args[0]);
+    let codepage = ctx.text_codepage();
+    array_lift::lift1(number, |number| {
+        let code = number.coerce_to_i64_with_ctx(ctx)?;
+        let bytes: Vec<u8> = match code {
+            1..=0xFF => vec![code as u8],
+            0x100..=0xFFFF if is_dbcs_codepage(codepage) => vec![(code >> 8) as u8, code as u8],
+            _ => return Err(ErrorKind::Value),
+        };
+        let (decoded, had_errors) =
+            legacy_encoding_for_codepage(codepage).decode_without_bom_handling(&bytes);
+        let mut chars = decoded.chars();
+        match (had_errors, chars.next(), chars.next()) {
+            (false, Some(ch), None) => Ok(Value::Text(ch.to_string())),
+            _ => Err(ErrorKind::Value),
+        }
+    })
+}
+
+pub(crate) fn code_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
+    let text = array_lift::eval_arg(ctx, &args[0]);
+    let codepage = ctx.text_codepage();
+    array_lift::lift1(text, |text| {
+        let text = text.coerce_to_string_with_ctx(ctx)?;
+        let Some(ch) = text.chars().next() else {
+            return Err(ErrorKind::Value);
+        };
+        let mut buf = [0u8; 4];
+        let (bytes, _, had_errors) =
+            legacy_encoding_for_codepage(codepage).encode(ch.encode_utf8(&mut buf));
+        // Characters outside the codepage are reported as `?`, like Windows' best-fit fallback.
+        let code = match (had_errors, bytes.as_ref()) {
+            (false, [b]) => u32::from(*b),
+            (false, [lead, trail]) => u32::from(*lead) << 8 | u32::from(*trail),
+            _ => u32::from(b'?'),
+        };
+        Ok(Value::Number(code as f64))
+    })
+}
+
+/// The legacy (ANSI) encoding `CHAR`/`CODE` use. Unknown codepages and UTF-8 fall back to 1252,
+/// since codes are single- or double-byte units rather than UTF-8 sequences.
+fn legacy_encoding_for_codepage(codepage: u16) -> &'static Encoding {
+    match encoding_for_codepage(codepage) {
+        Some(encoding) if encoding != UTF_8 => encoding,
+        _ => WINDOWS_1252,
+    }
+}
+
 pub(crate) fn phonetic_fn(ctx: &dyn FunctionContext, args: &[CompiledExpr]) -> Value {
     match ctx.eval_arg(&args[0]) {
         ArgValue::Reference(reference) => phonetic_from_reference(ctx, reference),
@@ -297,37 +352,24 @@ fn phonetic_from_reference(ctx: &dyn FunctionContext, reference: Reference) -> V
         };
     }
 
-    // Preserve the existing array/broadcast behavior for multi-cell references.
-    let rows = (reference.end.row - reference.start.row + 1) as usize;
-    let cols = (reference.end.col - reference.start.col + 1) as usize;
-    let total = match rows.checked_mul(cols) {
-        Some(v) => v,
-        None => return Value::Error(ErrorKind::Spill),
-    };
-    if total > MAX_MATERIALIZED_ARRAY_CELLS {
-        return Value::Error(ErrorKind::Spill);
-    }
-    let mut out = Vec::new();
-    if out.try_reserve_exact(total).is_err() {
-        return Value::Error(ErrorKind::Num);
-    }
-    for addr in reference.iter_cells() {
+    // Multi-cell references concatenate the range's furigana in row-major order (the classic
+    // Excel behavior). Cells without phonetic metadata contribute their text; numbers, logicals
+    // and blanks are skipped, so a range with no text yields an empty string.
+    let mut cells: Vec<CellAddr> = ctx.iter_reference_cells(&reference).collect();
+    cells.sort_by_key(|addr| (addr.row, addr.col));
+    let mut out = String::new();
+    for addr in cells {
         let cell_value = ctx.get_cell_value(&reference.sheet_id, addr);
-        // Error values are preserved per element (matching `array_lift` behavior).
         if let Value::Error(e) = cell_value {
-            out.push(Value::Error(e));
-            continue;
+            return Value::Error(e);
         }
         if let Some(phonetic) = ctx.get_cell_phonetic(&reference.sheet_id, addr) {
-            out.push(Value::Text(phonetic.to_string()));
-            continue;
+            out.push_str(phonetic);
+        } else if let Value::Text(text) = &cell_value {
+            out.push_str(text);
         }
-        out.push(match cell_value.coerce_to_string_with_ctx(ctx) {
-            Ok(s) => Value::Text(s),
-            Err(e) => Value::Error(e),
-        });
     }
-    Value::Array(Array::new(rows, cols, out))
+    Value::Text(out)
 }
 
 const FULLWIDTH_SPACE: char = '\u{3000}';
@@ -111,9 +111,10 @@ pub fn text(
     let options = FormatOptions {
         locale: value_locale.separators,
         date_system: match date_system {
-            // `formula-format` always uses the Lotus 1-2-3 leap-year bug behavior
-            // for the 1900 date system (Excel compatibility).
+            // `formula-format` uses the Lotus 1-2-3 leap-year bug behavior for the Excel 1900 date
+            // system (Excel compatibility).
             ExcelDateSystem::Excel1900 { .. } => DateSystem::Excel1900,
+            ExcelDateSystem::Excel1900Proleptic => DateSystem::Excel1900Proleptic,
             ExcelDateSystem::Excel1904 => DateSystem::Excel1904,
         },
     };
@@ -5,7 +5,8 @@ pub fn exact(text1: &str, text2: &str) -> bool {
 
 /// CLEAN(text)
 ///
-/// Removes non-printable characters (ASCII control codes 0-31 and DEL).
+/// Removes the 7-bit ASCII control codes 0-31. Like Excel, DEL (127) and the Unicode C1 controls
+/// are left in place.
 pub fn clean(text: &str) -> Result<String, crate::value::ErrorKind> {
     let mut out = String::new();
     if out.try_reserve_exact(text.len()).is_err() {
@@ -13,8 +14,7 @@ pub fn clean(text: &str) -> Result<String, crate::value::ErrorKind> {
         return Err(crate::value::ErrorKind::Num);
     }
     for c in text.chars() {
-        let code = c as u32;
-        if !(code <= 31 || code == 127) {
+        if c as u32 > 31 {
             out.push(c);
         }
     }
@@ -82,11 +82,15 @@ pub use crate::error::{ExcelError, ExcelResult};
 pub use ast::*;
 pub use editing::{
     CellChange, CellSnapshot, EditError, EditOp, EditResult, FormulaRewrite, MovedRange,
+    TextToColumnsSplit,
 };
 pub use engine::{
     BytecodeCompileReason, BytecodeCompileReportEntry, BytecodeCompileStats, Engine, EngineError,
-    EngineInfo, ExternalDataProvider, ExternalValueProvider, NameDefinition, NameScope,
-    PrecedentNode, RecalcMode, RecalcValueChange, SheetId, SheetLifecycleError,
+    EngineInfo, EngineSnapshotError, ExternalDataProvider, ExternalValueProvider,
+    FormulaDiagnostic, FormulaLint, FormulaLintKind, FormulaReferences, LintSeverity,
+    NameDefinition, NameRenameError, NameScope, PrecedentNode, RecalcMode, RecalcProgress,
+    RecalcValueChange, SheetId, SheetLifecycleError, StyleLayer, StyleLayerContribution,
+    ENGINE_SNAPSHOT_VERSION, RECALC_PROGRESS_INTERVAL,
 };
 pub use parser::{
     lex, lex_partial, parse_formula_partial, FunctionContext, ParseContext, PartialLex,
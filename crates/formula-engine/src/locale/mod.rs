@@ -10,7 +10,7 @@ pub use translate::{
     canonicalize_formula, canonicalize_formula_with_style, localize_formula,
     localize_formula_with_style,
 };
-pub use value_locale::{DateOrder, ValueLocaleConfig};
+pub use value_locale::{DateOrder, TextCollation, ValueLocaleConfig};
 
 /// Resolve the workbook's legacy Windows text codepage for a locale id.
 ///
@@ -11,6 +11,22 @@ pub enum DateOrder {
     YMD,
 }
 
+/// Collation used when ordering text in comparisons (`<`, `>`, ...), approximate lookups and
+/// `SORT`.
+///
+/// All collations are case-insensitive and agree on equality; they only differ in how strings
+/// that are not equal are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextCollation {
+    /// Compare case-folded code points (`"é" > "f"`).
+    Ordinal,
+    /// Compare base letters first and only fall back to accents when the base letters tie
+    /// (`"e" < "é" < "f"`), like Excel does for most locales.
+    Linguistic,
+    /// [`TextCollation::Linguistic`] with `Ñ` sorted as its own letter between `N` and `O`.
+    Spanish,
+}
+
 /// Locale configuration used when parsing *values* (text -> number/date/time).
 ///
 /// This is distinct from the formula parsing locale ([`crate::LocaleConfig`]), which controls
@@ -20,6 +36,7 @@ pub enum DateOrder {
 pub struct ValueLocaleConfig {
     pub separators: Locale,
     pub date_order: DateOrder,
+    pub collation: TextCollation,
 }
 
 impl Default for ValueLocaleConfig {
@@ -34,9 +51,17 @@ impl ValueLocaleConfig {
         Self {
             separators,
             date_order,
+            collation: TextCollation::Linguistic,
         }
     }
 
+    /// Override the text collation (e.g. [`TextCollation::Ordinal`] for code-point ordering).
+    #[must_use]
+    pub const fn with_collation(mut self, collation: TextCollation) -> Self {
+        self.collation = collation;
+        self
+    }
+
     #[must_use]
     pub const fn en_us() -> Self {
         Self::new(Locale::en_us(), DateOrder::MDY)
@@ -105,7 +130,7 @@ impl ValueLocaleConfig {
 
     #[must_use]
     pub const fn es_es() -> Self {
-        Self::new(Locale::es_es(), DateOrder::DMY)
+        Self::new(Locale::es_es(), DateOrder::DMY).with_collation(TextCollation::Spanish)
     }
 
     #[must_use]
@@ -50,3 +50,56 @@ pub fn style_id_for_row_in_runs(runs: Option<&[FormatRun]>, row: u32) -> u32 {
     }
     style_id
 }
+
+/// Re-map the style of every row in `[start_row, end_row_exclusive)` of a sorted run list.
+///
+/// Runs crossing the boundaries are split so rows outside the span keep their style. With
+/// `fill_gaps`, rows in the span that no run covers get a run styled `map(0)`. Runs whose style
+/// maps to `0` are dropped.
+pub(crate) fn restyle_runs_in_rows(
+    runs: &[FormatRun],
+    start_row: u32,
+    end_row_exclusive: u32,
+    fill_gaps: bool,
+    mut map: impl FnMut(u32) -> u32,
+) -> Vec<FormatRun> {
+    let mut out = Vec::with_capacity(runs.len() + 2);
+    let mut cursor = start_row;
+    let mut push = |start_row: u32, end_row_exclusive: u32, style_id: u32| {
+        if start_row < end_row_exclusive && style_id != 0 {
+            out.push(FormatRun {
+                start_row,
+                end_row_exclusive,
+                style_id,
+            });
+        }
+    };
+    for run in runs {
+        push(
+            run.start_row,
+            run.end_row_exclusive.min(start_row),
+            run.style_id,
+        );
+
+        let inner_start = run.start_row.max(start_row);
+        let inner_end = run.end_row_exclusive.min(end_row_exclusive);
+        if inner_start < inner_end {
+            if fill_gaps && cursor < inner_start {
+                push(cursor, inner_start, map(0));
+            }
+            push(inner_start, inner_end, map(run.style_id));
+            cursor = cursor.max(inner_end);
+        }
+
+        push(
+            run.start_row.max(end_row_exclusive),
+            run.end_row_exclusive,
+            run.style_id,
+        );
+    }
+    if fill_gaps && cursor < end_row_exclusive {
+        push(cursor, end_row_exclusive, map(0));
+    }
+    out.sort_by_key(|run| (run.start_row, run.end_row_exclusive, run.style_id));
+    out
+}
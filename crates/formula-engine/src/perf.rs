@@ -65,6 +65,18 @@ fn failed_benchmark_set(include_sparse_huge_ranges: bool) -> Vec<BenchmarkResult
         2,
         1000.0,
     ));
+    out.push(failed_benchmark(
+        "calc.recalc_sumifs_100k_rows_200_cells.p95",
+        8,
+        2,
+        500.0,
+    ));
+    out.push(failed_benchmark(
+        "calc.recalc_sumproduct_3_factors_100k_rows.p95",
+        8,
+        2,
+        500.0,
+    ));
     if include_sparse_huge_ranges {
         out.push(failed_benchmark(
             "calc.recalc_sparse_sum_countif_full_column.p95",
@@ -446,6 +458,87 @@ fn setup_filled_formula_recalc_engine(size: usize) -> Option<(Engine, String)> {
     Some((engine, format!("B{size}")))
 }
 
+fn setup_repeated_sumifs_engine(size: usize, formula_cells: usize) -> Option<(Engine, String)> {
+    let mut engine = Engine::new();
+
+    // A text category column and an amount column; each SUMIFS cell asks for a different category
+    // over the same criteria range, which is the access pattern the criteria index targets.
+    for row in 1..=size {
+        if engine
+            .set_cell_value(
+                "Sheet1",
+                &format!("A{row}"),
+                Value::Text(format!("cat{}", row % 100)),
+            )
+            .is_err()
+            || engine
+                .set_cell_value("Sheet1", &format!("B{row}"), (row % 1000) as f64)
+                .is_err()
+        {
+            debug_assert!(false, "seed value");
+            return None;
+        }
+    }
+
+    for row in 1..=formula_cells {
+        let formula = format!(
+            "=SUMIFS($B$1:$B${size},$A$1:$A${size},\"cat{}\")",
+            row % 100
+        );
+        if engine
+            .set_cell_formula("Sheet1", &format!("C{row}"), &formula)
+            .is_err()
+        {
+            debug_assert!(false, "set SUMIFS formula");
+            return None;
+        }
+    }
+
+    if engine.bytecode_program_count() != formula_cells.min(100) {
+        debug_assert!(false, "expected SUMIFS formulas to compile to bytecode");
+        return None;
+    }
+
+    engine.recalculate_single_threaded();
+    Some((engine, format!("C{formula_cells}")))
+}
+
+fn setup_sumproduct_engine(size: usize) -> Option<(Engine, String)> {
+    let mut engine = Engine::new();
+
+    for row in 1..=size {
+        if engine
+            .set_cell_value("Sheet1", &format!("A{row}"), (row % 100) as f64)
+            .is_err()
+            || engine
+                .set_cell_value("Sheet1", &format!("B{row}"), (row % 7) as f64)
+                .is_err()
+            || engine
+                .set_cell_value("Sheet1", &format!("C{row}"), (row % 3) as f64)
+                .is_err()
+        {
+            debug_assert!(false, "seed value");
+            return None;
+        }
+    }
+
+    // Three factors take the generic N-factor path, which must stream element-wise products
+    // rather than materializing a value or product array per factor.
+    let formula = format!("=SUMPRODUCT(A1:A{size},B1:B{size},C1:C{size})");
+    if engine.set_cell_formula("Sheet1", "D1", &formula).is_err() {
+        debug_assert!(false, "set SUMPRODUCT formula");
+        return None;
+    }
+
+    if engine.bytecode_program_count() != 1 {
+        debug_assert!(false, "expected SUMPRODUCT formula to compile to bytecode");
+        return None;
+    }
+
+    engine.recalculate_single_threaded();
+    Some((engine, "D1".to_string()))
+}
+
 pub fn run_benchmarks() -> Vec<BenchmarkResult> {
     let include_sparse_huge_ranges =
         std::env::var("FORMULA_ENGINE_BENCH_SPARSE_HUGE_RANGES").is_ok();
@@ -479,7 +572,7 @@ pub fn run_benchmarks() -> Vec<BenchmarkResult> {
     );
 
   let mut results = Vec::new();
-  let expected_results = if include_sparse_huge_ranges { 10 } else { 9 };
+  let expected_results = if include_sparse_huge_ranges { 12 } else { 11 };
   if results.try_reserve_exact(expected_results).is_err() {
     debug_assert!(false, "benchmark results allocation failed (count={expected_results})");
     return failed_benchmark_set(include_sparse_huge_ranges);
@@ -663,6 +756,69 @@ pub fn run_benchmarks() -> Vec<BenchmarkResult> {
         ));
     }
 
+    // Many SUMIFS over the same large criteria range: guards the per-recalc criteria index that
+    // lets repeated `*IFS` calls skip rescanning the range.
+    if let Some((mut engine_sumifs, sumifs_out_cell)) = setup_repeated_sumifs_engine(100_000, 200) {
+        let mut counter_sumifs = 0_i64;
+        results.push(run_benchmark(
+            "calc.recalc_sumifs_100k_rows_200_cells.p95",
+            8,
+            2,
+            500.0,
+            || {
+                counter_sumifs += 1;
+                if engine_sumifs
+                    .set_cell_value("Sheet1", "B1", (counter_sumifs % 1000) as f64)
+                    .is_err()
+                {
+                    debug_assert!(false, "update");
+                    return;
+                }
+                engine_sumifs.recalculate_single_threaded();
+                let v = engine_sumifs.get_cell_value("Sheet1", &sumifs_out_cell);
+                std::hint::black_box(v);
+            },
+        ));
+    } else {
+        results.push(failed_benchmark(
+            "calc.recalc_sumifs_100k_rows_200_cells.p95",
+            8,
+            2,
+            500.0,
+        ));
+    }
+
+    // Three-factor SUMPRODUCT over 100k rows: guards the streaming N-factor evaluation.
+    if let Some((mut engine_sumproduct, sumproduct_out_cell)) = setup_sumproduct_engine(100_000) {
+        let mut counter_sumproduct = 0_i64;
+        results.push(run_benchmark(
+            "calc.recalc_sumproduct_3_factors_100k_rows.p95",
+            8,
+            2,
+            500.0,
+            || {
+                counter_sumproduct += 1;
+                if engine_sumproduct
+                    .set_cell_value("Sheet1", "A1", (counter_sumproduct % 100) as f64)
+                    .is_err()
+                {
+                    debug_assert!(false, "update");
+                    return;
+                }
+                engine_sumproduct.recalculate_single_threaded();
+                let v = engine_sumproduct.get_cell_value("Sheet1", &sumproduct_out_cell);
+                std::hint::black_box(v);
+            },
+        ));
+    } else {
+        results.push(failed_benchmark(
+            "calc.recalc_sumproduct_3_factors_100k_rows.p95",
+            8,
+            2,
+            500.0,
+        ));
+    }
+
     // Opt-in benchmark for huge sparse ranges (`A:A`-style). Enable via:
     //   FORMULA_ENGINE_BENCH_SPARSE_HUGE_RANGES=1 cargo run -p formula-engine --bin perf_bench
     if include_sparse_huge_ranges {
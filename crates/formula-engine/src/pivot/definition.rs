@@ -231,6 +231,18 @@ impl PivotTableDefinition {
             EditOp::Fill { sheet, src: _, dst } => {
                 self.invalidate_if_overlaps(sheet, dst, resolve_sheet_id);
             }
+            // How far the split reaches depends on the data, so conservatively treat every cell to
+            // the right of the source column as rewritten. Any cells the split inserted are replayed
+            // separately as `InsertCellsShiftRight`.
+            EditOp::TextToColumns { sheet, src, .. } => {
+                let rewritten =
+                    Range::new(src.start, CellRef::new(src.end.row, EXCEL_MAX_COLS - 1));
+                self.invalidate_if_overlaps(sheet, &rewritten, resolve_sheet_id);
+            }
+            EditOp::RemoveDuplicates { sheet, range, .. }
+            | EditOp::SortRange { sheet, range, .. } => {
+                self.invalidate_if_overlaps(sheet, range, resolve_sheet_id);
+            }
         }
     }
 
@@ -622,10 +622,28 @@ impl PivotRecordSource for ColumnarTable {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PivotResult {
     pub data: Vec<Vec<PivotValue>>,
+    /// Where each row/column key landed in `data`, backing [`PivotResult::value_for`].
+    ///
+    /// Only populated by [`PivotEngine`]; results built by hand (or deserialized) have no index.
+    #[serde(skip)]
+    pub index: PivotResultIndex,
+}
+
+/// Positions of the rendered pivot items within [`PivotResult::data`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PivotResultIndex {
+    /// Row key -> data row. Leaf rows use the full key, subtotal rows the prefix they total, and
+    /// the grand total row the empty key.
+    pub rows: HashMap<PivotKey, usize>,
+    /// Column key -> first data column of that key's block (one column per value field). The
+    /// grand total columns use the empty key.
+    pub columns: HashMap<PivotKey, usize>,
+    /// Value-field captions, in the order their columns repeat within each block.
+    pub value_fields: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -647,6 +665,34 @@ pub struct CellWrite {
 }
 
 impl PivotResult {
+    /// Look up the computed value for a row item, column item and value field, as displayed
+    /// (after any "show values as" transform).
+    ///
+    /// `row_key`/`col_key` hold one part per row/column field (use [`PivotValue::to_key_part`]);
+    /// a shorter prefix selects the subtotal row for that group and an empty key selects the
+    /// grand total. `value_field` matches the value field caption case-insensitively.
+    ///
+    /// Returns `None` when the pivot has no such row, column or value field, or when no source
+    /// records fall into that combination.
+    pub fn value_for(
+        &self,
+        row_key: &PivotKey,
+        col_key: &PivotKey,
+        value_field: &str,
+    ) -> Option<&PivotValue> {
+        let vf_idx = self
+            .index
+            .value_fields
+            .iter()
+            .position(|name| crate::value::eq_case_insensitive(name, value_field))?;
+        let row = *self.index.rows.get(row_key)?;
+        let col = *self.index.columns.get(col_key)? + vf_idx;
+        match self.data.get(row)?.get(col)? {
+            PivotValue::Blank => None,
+            value => Some(value),
+        }
+    }
+
     /// Converts the computed pivot into a list of worksheet cell writes.
     pub fn to_cell_writes(&self, destination: CellRef) -> Vec<CellWrite> {
         let mut out = Vec::new();
@@ -1683,7 +1729,53 @@ impl PivotEngine {
             Self::apply_show_as(&mut data, &row_kinds, &cube, &row_keys, &col_keys, cfg)?;
         }
 
-        Ok(PivotResult { data })
+        let index = Self::build_result_index(&row_kinds, &row_keys, &col_keys, cfg);
+        Ok(PivotResult { data, index })
+    }
+
+    fn build_result_index(
+        row_kinds: &[PivotRowKind],
+        row_keys: &[PivotKey],
+        col_keys: &[PivotKey],
+        cfg: &PivotConfig,
+    ) -> PivotResultIndex {
+        let mut rows = HashMap::new();
+        for (row, kind) in row_kinds.iter().enumerate() {
+            match kind {
+                PivotRowKind::Header => {}
+                PivotRowKind::Leaf { row_key_idx } => {
+                    rows.insert(row_keys[*row_key_idx].clone(), row);
+                }
+                PivotRowKind::Subtotal { prefix_key, .. } => {
+                    rows.entry(prefix_key.clone()).or_insert(row);
+                }
+                // Without row fields the single leaf row already uses the empty key.
+                PivotRowKind::GrandTotal => {
+                    rows.entry(PivotKey(Vec::new())).or_insert(row);
+                }
+            }
+        }
+
+        let label_cols = match cfg.layout {
+            Layout::Compact => 1,
+            Layout::Outline | Layout::Tabular => cfg.row_fields.len(),
+        };
+        let block = cfg.value_fields.len();
+        let mut columns = HashMap::new();
+        for (idx, col_key) in col_keys.iter().enumerate() {
+            columns.insert(col_key.clone(), label_cols + idx * block);
+        }
+        if cfg.grand_totals.columns {
+            columns
+                .entry(PivotKey(Vec::new()))
+                .or_insert(label_cols + col_keys.len() * block);
+        }
+
+        PivotResultIndex {
+            rows,
+            columns,
+            value_fields: cfg.value_fields.iter().map(value_field_caption).collect(),
+        }
     }
 
     fn calculated_item_placement(
@@ -2035,15 +2127,7 @@ impl PivotEngine {
                 Self::join_non_empty_display_strings(col_key.0.iter().map(|p| p.display_string()))
             };
             for vf in &cfg.value_fields {
-                let base = if vf.name.is_empty() {
-                    format!(
-                        "{:?} of {}",
-                        vf.aggregation,
-                        pivot_field_ref_caption(&vf.source_field)
-                    )
-                } else {
-                    vf.name.clone()
-                };
+                let base = value_field_caption(vf);
                 let header = if col_label.is_empty() {
                     base
                 } else {
@@ -2055,15 +2139,7 @@ impl PivotEngine {
 
         if cfg.grand_totals.columns {
             for vf in &cfg.value_fields {
-                let base = if vf.name.is_empty() {
-                    format!(
-                        "{:?} of {}",
-                        vf.aggregation,
-                        pivot_field_ref_caption(&vf.source_field)
-                    )
-                } else {
-                    vf.name.clone()
-                };
+                let base = value_field_caption(vf);
                 row.push(PivotValue::Text(format!("Grand Total - {base}")));
             }
         }
@@ -3883,6 +3959,19 @@ impl KeySortSpec {
     }
 }
 
+/// Caption of a value field in pivot headers (e.g. `Sum of Sales` when unnamed).
+fn value_field_caption(vf: &ValueField) -> String {
+    if vf.name.is_empty() {
+        format!(
+            "{:?} of {}",
+            vf.aggregation,
+            pivot_field_ref_caption(&vf.source_field)
+        )
+    } else {
+        vf.name.clone()
+    }
+}
+
 fn compare_key_parts_ascending(left: &PivotKeyPart, right: &PivotKeyPart) -> Ordering {
     left.cmp(right)
 }
@@ -4631,6 +4720,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn value_for_looks_up_leaf_subtotal_and_grand_total_cells() {
+        let data = vec![
+            pv_row(&[
+                "Region".into(),
+                "Rep".into(),
+                "Product".into(),
+                "Sales".into(),
+            ]),
+            pv_row(&["East".into(), "Ann".into(), "A".into(), 100.into()]),
+            pv_row(&["East".into(), "Bob".into(), "B".into(), 150.into()]),
+            pv_row(&["West".into(), "Cy".into(), "A".into(), 200.into()]),
+            pv_row(&["West".into(), "Cy".into(), "B".into(), 250.into()]),
+        ];
+
+        let cache = PivotCache::from_range(&data).unwrap();
+
+        let value_field = |name: &str, aggregation| ValueField {
+            source_field: cache_field("Sales"),
+            name: name.to_string(),
+            aggregation,
+            number_format: None,
+            show_as: None,
+            base_field: None,
+            base_item: None,
+        };
+        let cfg = PivotConfig {
+            row_fields: vec![PivotField::new("Region"), PivotField::new("Rep")],
+            column_fields: vec![PivotField::new("Product")],
+            value_fields: vec![
+                value_field("Sum of Sales", AggregationType::Sum),
+                value_field("Count of Sales", AggregationType::Count),
+            ],
+            filter_fields: vec![],
+            calculated_fields: vec![],
+            calculated_items: vec![],
+            layout: Layout::Tabular,
+            subtotals: SubtotalPosition::Bottom,
+            grand_totals: GrandTotals {
+                rows: true,
+                columns: true,
+            },
+        };
+
+        let result = PivotEngine::calculate(&cache, &cfg).unwrap();
+        let key = |parts: &[&str]| {
+            PivotKey(
+                parts
+                    .iter()
+                    .map(|p| PivotValue::from(*p).to_key_part())
+                    .collect(),
+            )
+        };
+
+        let east_bob = key(&["East", "Bob"]);
+        assert_eq!(
+            result.value_for(&east_bob, &key(&["B"]), "Sum of Sales"),
+            Some(&150.into())
+        );
+        assert_eq!(
+            result.value_for(&key(&["West", "Cy"]), &key(&["A"]), "count of sales"),
+            Some(&1.into())
+        );
+        // Subtotal row for the `West` group and the grand total column.
+        assert_eq!(
+            result.value_for(&key(&["West"]), &key(&[]), "Sum of Sales"),
+            Some(&450.into())
+        );
+        // Grand total row.
+        assert_eq!(
+            result.value_for(&key(&[]), &key(&["A"]), "Sum of Sales"),
+            Some(&300.into())
+        );
+        assert_eq!(
+            result.value_for(&key(&[]), &key(&[]), "Sum of Sales"),
+            Some(&700.into())
+        );
+
+        // Bob never sold product A.
+        assert_eq!(
+            result.value_for(&east_bob, &key(&["A"]), "Sum of Sales"),
+            None
+        );
+        assert_eq!(
+            result.value_for(&key(&["North", "Ann"]), &key(&["A"]), "Sum of Sales"),
+            None
+        );
+        assert_eq!(
+            result.value_for(&east_bob, &key(&["C"]), "Sum of Sales"),
+            None
+        );
+        assert_eq!(
+            result.value_for(&east_bob, &key(&["B"]), "Average of Sales"),
+            None
+        );
+    }
+
     #[test]
     fn sorts_row_keys_descending_for_numeric_field() {
         let data = vec![
@@ -170,9 +170,9 @@ pub fn count_if_f64(values: &[f64], criteria: NumericCriteria) -> usize {
 
 /// COUNTIF-style numeric criteria evaluation for column slices.
 ///
-/// In Excel, blank cells are coerced to `0` for numeric criteria. Column slices represent blanks as
-/// `NaN`, so this kernel normalizes NaNs to `0` before comparison.
-pub fn count_if_blank_as_zero_f64(values: &[f64], criteria: NumericCriteria) -> usize {
+/// Column slices represent blanks as `NaN`. Blanks never equal or order against a number, so they
+/// only satisfy `<>` criteria, which is exactly how `NaN` compares.
+pub fn count_if_with_blanks_f64(values: &[f64], criteria: NumericCriteria) -> usize {
     let mut count = 0usize;
 
     let len4 = values.len() & !3;
@@ -180,7 +180,6 @@ pub fn count_if_blank_as_zero_f64(values: &[f64], criteria: NumericCriteria) ->
     while i < len4 {
         let lanes = [values[i], values[i + 1], values[i + 2], values[i + 3]];
         for &v in &lanes {
-            let v = if v.is_nan() { 0.0 } else { v };
             if matches_criteria(v, criteria) {
                 count += 1;
             }
@@ -189,7 +188,6 @@ pub fn count_if_blank_as_zero_f64(values: &[f64], criteria: NumericCriteria) ->
     }
 
     for &v in &values[i..] {
-        let v = if v.is_nan() { 0.0 } else { v };
         if matches_criteria(v, criteria) {
             count += 1;
         }
@@ -200,8 +198,8 @@ pub fn count_if_blank_as_zero_f64(values: &[f64], criteria: NumericCriteria) ->
 
 /// SUMIF-style numeric criteria evaluation for column slices.
 ///
-/// - The criteria range is interpreted with COUNTIF-style coercion where blanks are treated as
-///   `0`.
+/// - The criteria range is interpreted with COUNTIF-style coercion where blanks (`NaN`) only
+///   satisfy `<>` criteria.
 /// - The summed values treat NaNs as `0` (Excel's reference semantics ignore non-numeric cells).
 pub fn sum_if_f64(values: &[f64], criteria_values: &[f64], criteria: NumericCriteria) -> f64 {
     debug_assert_eq!(values.len(), criteria_values.len());
@@ -213,10 +211,7 @@ pub fn sum_if_f64(values: &[f64], criteria_values: &[f64], criteria: NumericCrit
     while i < len4 {
         let mut lanes = [0.0f64; 4];
         for lane in 0..4 {
-            let mut crit_v = criteria_values[i + lane];
-            if crit_v.is_nan() {
-                crit_v = 0.0;
-            }
+            let crit_v = criteria_values[i + lane];
             if matches_criteria(crit_v, criteria) {
                 let v = values[i + lane];
                 lanes[lane] = if v.is_nan() { 0.0 } else { v };
@@ -231,10 +226,7 @@ pub fn sum_if_f64(values: &[f64], criteria_values: &[f64], criteria: NumericCrit
     let mut sum = arr[0] + arr[1] + arr[2] + arr[3];
 
     for idx in i..values.len() {
-        let mut crit_v = criteria_values[idx];
-        if crit_v.is_nan() {
-            crit_v = 0.0;
-        }
+        let crit_v = criteria_values[idx];
         if !matches_criteria(crit_v, criteria) {
             continue;
         }
@@ -266,10 +258,7 @@ pub fn sum_count_if_f64(
     while i + 4 <= values.len() {
         let mut lanes = [0.0f64; 4];
         for lane in 0..4 {
-            let mut crit_v = criteria_values[i + lane];
-            if crit_v.is_nan() {
-                crit_v = 0.0;
-            }
+            let crit_v = criteria_values[i + lane];
             if !matches_criteria(crit_v, criteria) {
                 continue;
             }
@@ -290,10 +279,7 @@ pub fn sum_count_if_f64(
     let mut sum = arr[0] + arr[1] + arr[2] + arr[3];
 
     for idx in i..values.len() {
-        let mut crit_v = criteria_values[idx];
-        if crit_v.is_nan() {
-            crit_v = 0.0;
-        }
+        let crit_v = criteria_values[idx];
         if !matches_criteria(crit_v, criteria) {
             continue;
         }
@@ -313,7 +299,7 @@ pub fn sum_count_if_f64(
 /// Returns `Some(min)` when at least one numeric (non-NaN) value satisfied the criteria; otherwise
 /// `None`.
 ///
-/// Criteria evaluation follows COUNTIF-style coercion, where blanks are treated as `0`.
+/// Criteria evaluation follows COUNTIF-style coercion, where blanks only satisfy `<>` criteria.
 pub fn min_if_f64(
     values: &[f64],
     criteria_values: &[f64],
@@ -328,10 +314,7 @@ pub fn min_if_f64(
     while i + 4 <= values.len() {
         let mut lanes = [f64::INFINITY; 4];
         for lane in 0..4 {
-            let mut crit_v = criteria_values[i + lane];
-            if crit_v.is_nan() {
-                crit_v = 0.0;
-            }
+            let crit_v = criteria_values[i + lane];
             if !matches_criteria(crit_v, criteria) {
                 continue;
             }
@@ -352,10 +335,7 @@ pub fn min_if_f64(
     let mut best = arr[0].min(arr[1]).min(arr[2]).min(arr[3]);
 
     for idx in i..values.len() {
-        let mut crit_v = criteria_values[idx];
-        if crit_v.is_nan() {
-            crit_v = 0.0;
-        }
+        let crit_v = criteria_values[idx];
         if !matches_criteria(crit_v, criteria) {
             continue;
         }
@@ -375,7 +355,7 @@ pub fn min_if_f64(
 /// Returns `Some(max)` when at least one numeric (non-NaN) value satisfied the criteria; otherwise
 /// `None`.
 ///
-/// Criteria evaluation follows COUNTIF-style coercion, where blanks are treated as `0`.
+/// Criteria evaluation follows COUNTIF-style coercion, where blanks only satisfy `<>` criteria.
 pub fn max_if_f64(
     values: &[f64],
     criteria_values: &[f64],
@@ -390,10 +370,7 @@ pub fn max_if_f64(
     while i + 4 <= values.len() {
         let mut lanes = [f64::NEG_INFINITY; 4];
         for lane in 0..4 {
-            let mut crit_v = criteria_values[i + lane];
-            if crit_v.is_nan() {
-                crit_v = 0.0;
-            }
+            let crit_v = criteria_values[i + lane];
             if !matches_criteria(crit_v, criteria) {
                 continue;
             }
@@ -414,10 +391,7 @@ pub fn max_if_f64(
     let mut best = arr[0].max(arr[1]).max(arr[2]).max(arr[3]);
 
     for idx in i..values.len() {
-        let mut crit_v = criteria_values[idx];
-        if crit_v.is_nan() {
-            crit_v = 0.0;
-        }
+        let crit_v = criteria_values[idx];
         if !matches_criteria(crit_v, criteria) {
             continue;
         }
@@ -559,13 +533,17 @@ mod tests {
     use super::*;
 
     #[test]
-    fn sum_if_and_count_if_blank_as_zero_match_scalar_logic() {
+    fn sum_if_and_count_if_with_blanks_match_scalar_logic() {
         let values = [1.0, f64::NAN, 3.0, 4.0];
         let crit = [0.0, f64::NAN, 2.0, 0.0];
         let criteria = NumericCriteria::new(CmpOp::Eq, 0.0);
 
-        // Criteria matches indices 0, 1 (blank treated as 0), and 3.
-        assert_eq!(count_if_blank_as_zero_f64(&crit, criteria), 3);
+        // Criteria matches indices 0 and 3; the blank at index 1 is not `0`.
+        assert_eq!(count_if_with_blanks_f64(&crit, criteria), 2);
+        assert_eq!(
+            count_if_with_blanks_f64(&crit, NumericCriteria::new(CmpOp::Ne, 0.0)),
+            2
+        );
 
         // Summed values ignore NaN (treated as 0).
         assert_eq!(sum_if_f64(&values, &crit, criteria), 1.0 + 0.0 + 4.0);
@@ -13,6 +13,7 @@ pub use filter::{
     ModelAutoFilterError,
     NumberComparison, TextMatch, TextMatchKind,
 };
+pub(crate) use sort::compute_row_permutation_with_value_locale;
 pub use sort::{
     sort_range, sort_range_with_value_locale, RowPermutation, SortError, SortKey, SortOrder,
     SortSpec, SortValueType,
@@ -1,12 +1,13 @@
-use crate::locale::ValueLocaleConfig;
+use crate::locale::{TextCollation, ValueLocaleConfig};
 use crate::sort_filter::parse::{parse_text_datetime, parse_text_number};
 use crate::sort_filter::types::{CellValue, HeaderOption, RangeData};
-use crate::value::cmp_case_insensitive;
+use crate::value::{casefold_owned, cmp_text_collated};
 use chrono::{NaiveDate, NaiveDateTime, Timelike};
 use formula_format::{DateSystem, FormatOptions, Value as FormatValue};
 use formula_model::ErrorValue;
 use std::cmp::Ordering;
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,6 +30,9 @@ pub struct SortKey {
     pub order: SortOrder,
     pub value_type: SortValueType,
     pub case_sensitive: bool,
+    /// Optional custom order (e.g. month names). Text matching an entry case-insensitively sorts
+    /// by its position in the list, ahead of all other values.
+    pub custom_list: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -62,6 +66,8 @@ impl std::error::Error for SortError {}
 
 #[derive(Debug, Clone)]
 enum SortKeyValue {
+    /// Position of the value in the key's custom list.
+    Custom(usize),
     Blank,
     Number(f64),
     DateTime(NaiveDateTime),
@@ -73,11 +79,12 @@ enum SortKeyValue {
 impl SortKeyValue {
     fn kind_rank(&self) -> u8 {
         match self {
-            SortKeyValue::Number(_) | SortKeyValue::DateTime(_) => 0,
-            SortKeyValue::Text(_) => 1,
-            SortKeyValue::Bool(_) => 2,
-            SortKeyValue::Error(_) => 3,
-            SortKeyValue::Blank => 4,
+            SortKeyValue::Custom(_) => 0,
+            SortKeyValue::Number(_) | SortKeyValue::DateTime(_) => 1,
+            SortKeyValue::Text(_) => 2,
+            SortKeyValue::Bool(_) => 3,
+            SortKeyValue::Error(_) => 4,
+            SortKeyValue::Blank => 5,
         }
     }
 
@@ -144,9 +151,14 @@ pub fn sort_range_with_value_locale(
     Ok(perm)
 }
 
-fn compare_rows(a: &SortRow, b: &SortRow, keys: &[SortKey]) -> Ordering {
+fn compare_rows(a: &SortRow, b: &SortRow, keys: &[SortKey], collation: TextCollation) -> Ordering {
     for (key_index, key) in keys.iter().enumerate() {
-        let ord = compare_key_value(&a.key_values[key_index], &b.key_values[key_index], key);
+        let ord = compare_key_value(
+            &a.key_values[key_index],
+            &b.key_values[key_index],
+            key,
+            collation,
+        );
         if ord != Ordering::Equal {
             return ord;
         }
@@ -154,7 +166,12 @@ fn compare_rows(a: &SortRow, b: &SortRow, keys: &[SortKey]) -> Ordering {
     a.original_index.cmp(&b.original_index)
 }
 
-fn compare_key_value(a: &SortKeyValue, b: &SortKeyValue, key: &SortKey) -> Ordering {
+fn compare_key_value(
+    a: &SortKeyValue,
+    b: &SortKeyValue,
+    key: &SortKey,
+    collation: TextCollation,
+) -> Ordering {
     let rank_cmp = a.kind_rank().cmp(&b.kind_rank());
     if rank_cmp != Ordering::Equal {
         // Excel keeps a fixed cross-type ordering (numbers/dates, then text, then booleans, blanks
@@ -164,13 +181,14 @@ fn compare_key_value(a: &SortKeyValue, b: &SortKeyValue, key: &SortKey) -> Order
     }
 
     let ord = match (a, b) {
+        (SortKeyValue::Custom(a), SortKeyValue::Custom(b)) => a.cmp(b),
         (SortKeyValue::Blank, SortKeyValue::Blank) => Ordering::Equal,
         (SortKeyValue::Error(a), SortKeyValue::Error(b)) => a.code().cmp(&b.code()),
         (SortKeyValue::Text(a), SortKeyValue::Text(b)) => {
             if key.case_sensitive {
                 a.cmp(b)
             } else {
-                cmp_case_insensitive(a, b)
+                cmp_text_collated(a, b, collation)
             }
         }
         (SortKeyValue::Bool(a), SortKeyValue::Bool(b)) => a.cmp(b),
@@ -228,6 +246,21 @@ pub(crate) fn compute_row_permutation_with_value_locale(
         return Err(SortError::AllocationFailure("sort rows"));
     }
 
+    let custom_positions: Vec<Option<HashMap<String, usize>>> = keys
+        .iter()
+        .map(|key| {
+            key.custom_list.as_ref().map(|list| {
+                let mut positions = HashMap::new();
+                for (index, entry) in list.iter().enumerate() {
+                    positions
+                        .entry(casefold_owned(entry.clone()))
+                        .or_insert(index);
+                }
+                positions
+            })
+        })
+        .collect();
+
     for row_index in header_rows..row_count {
         let mut key_values: Vec<SortKeyValue> = Vec::new();
         if key_values.try_reserve_exact(keys.len()).is_err() {
@@ -235,9 +268,18 @@ pub(crate) fn compute_row_permutation_with_value_locale(
             return Err(SortError::AllocationFailure("sort keys"));
         }
 
-        for key in keys {
+        for (key, positions) in keys.iter().zip(&custom_positions) {
             let cell = cell_at(row_index, key.column);
-            key_values.push(detect_key_value(&cell, key, value_locale));
+            let custom = match (&cell, positions) {
+                (CellValue::Text(text), Some(positions)) => positions
+                    .get(&casefold_owned(text.trim().to_string()))
+                    .copied(),
+                _ => None,
+            };
+            key_values.push(match custom {
+                Some(position) => SortKeyValue::Custom(position),
+                None => detect_key_value(&cell, key, value_locale),
+            });
         }
 
         sortable.push(SortRow {
@@ -246,7 +288,7 @@ pub(crate) fn compute_row_permutation_with_value_locale(
         });
     }
 
-    sortable.sort_by(|a, b| compare_rows(a, b, keys));
+    sortable.sort_by(|a, b| compare_rows(a, b, keys, value_locale.collation));
 
     let mut new_to_old: Vec<usize> = Vec::new();
     if new_to_old.try_reserve_exact(row_count).is_err() {
@@ -547,6 +589,7 @@ mod tests {
                 order: SortOrder::Ascending,
                 value_type: SortValueType::Text,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
         let perm = sort_range(&mut data, &spec).expect("sort should succeed");
@@ -583,12 +626,14 @@ mod tests {
                     order: SortOrder::Descending,
                     value_type: SortValueType::Auto,
                     case_sensitive: false,
+                    custom_list: None,
                 },
                 SortKey {
                     column: 0,
                     order: SortOrder::Ascending,
                     value_type: SortValueType::Auto,
                     case_sensitive: false,
+                    custom_list: None,
                 },
             ],
         };
@@ -627,6 +672,7 @@ mod tests {
                 order: SortOrder::Ascending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -661,6 +707,7 @@ mod tests {
                 order: SortOrder::Ascending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -696,6 +743,7 @@ mod tests {
                 order: SortOrder::Ascending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -731,6 +779,7 @@ mod tests {
                 order: SortOrder::Descending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -755,6 +804,7 @@ mod tests {
                 order: SortOrder::Descending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -778,6 +828,7 @@ mod tests {
                 order: SortOrder::Ascending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -806,6 +857,7 @@ mod tests {
                 order: SortOrder::Ascending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -845,6 +897,7 @@ mod tests {
                 order: SortOrder::Ascending,
                 value_type: SortValueType::Auto,
                 case_sensitive: false,
+                custom_list: None,
             }],
         };
 
@@ -121,6 +121,12 @@ impl StylePatchTable {
         }
         self.patches.get(&style_id)
     }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (u32, &StylePatch)> {
+        self.patches
+            .iter()
+            .map(|(style_id, patch)| (*style_id, patch))
+    }
 }
 
 /// Tuple of contributing style IDs across formatting layers.
@@ -6,9 +6,11 @@ use std::sync::Arc;
 use crate::error::ExcelError;
 use crate::eval::CompiledExpr;
 use crate::functions::{FunctionContext, Reference};
-use crate::locale::ValueLocaleConfig;
+use crate::locale::{TextCollation, ValueLocaleConfig};
 use formula_format::{FormatOptions, Value as FmtValue};
 use formula_model::{CellRef, ErrorValue as ModelErrorValue};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
 mod formatting;
 mod number_parse;
@@ -107,6 +109,74 @@ impl Iterator for FoldedUppercaseChars<'_> {
     }
 }
 
+/// Order two strings case-insensitively under `collation`.
+///
+/// Equality is the same for every collation (it matches [`cmp_case_insensitive`]); linguistic
+/// collations first compare the strings with accents stripped so `"é"` sorts between `"e"` and
+/// `"f"` instead of after `"z"`.
+pub(crate) fn cmp_text_collated(a: &str, b: &str, collation: TextCollation) -> Ordering {
+    let spanish = match collation {
+        TextCollation::Ordinal => return cmp_case_insensitive(a, b),
+        TextCollation::Linguistic => false,
+        TextCollation::Spanish => true,
+    };
+    if a.is_ascii() && b.is_ascii() {
+        return cmp_ascii_case_insensitive(a, b);
+    }
+    match primary_collation_weights(a, spanish).cmp(primary_collation_weights(b, spanish)) {
+        Ordering::Equal => cmp_case_insensitive(a, b),
+        ord => ord,
+    }
+}
+
+/// Accent-insensitive weights for the uppercased, decomposed characters of `s`.
+///
+/// Combining marks are dropped; with `spanish`, `N` + combining tilde keeps its own weight right
+/// after `N`.
+fn primary_collation_weights(s: &str, spanish: bool) -> impl Iterator<Item = u32> + '_ {
+    let mut chars = FoldedUppercaseChars::new(s).nfd().peekable();
+    std::iter::from_fn(move || loop {
+        let ch = chars.next()?;
+        if is_combining_mark(ch) {
+            continue;
+        }
+        if spanish && ch == 'N' && chars.peek() == Some(&'\u{303}') {
+            chars.next();
+            return Some(((ch as u32) << 1) | 1);
+        }
+        return Some((ch as u32) << 1);
+    })
+}
+
+/// Order two numbers the way Excel's comparison operators do.
+///
+/// Excel keeps full `f64` precision in stored values but only trusts 15 significant digits when
+/// comparing, so binary residues like `0.1+0.2` vs `0.3` compare equal. Values that are not equal
+/// at 15 digits keep their exact ordering (`0.1+0.2-0.3` is still greater than `0`).
+pub(crate) fn cmp_numbers_excel(a: f64, b: f64) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    // Only values within a few ULPs of each other can round to the same 15 digits; skip the
+    // decimal rounding for everything else.
+    let scale = a.abs().max(b.abs());
+    if (a - b).abs() <= scale * 1e-14
+        && round_to_15_significant_digits(a) == round_to_15_significant_digits(b)
+    {
+        return Ordering::Equal;
+    }
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+fn round_to_15_significant_digits(n: f64) -> f64 {
+    if !n.is_finite() {
+        return n;
+    }
+    // Round through the decimal representation so the result is the nearest f64 to the 15-digit
+    // decimal value (scaling by powers of ten would add its own rounding error).
+    format!("{n:.14e}").parse().unwrap_or(n)
+}
+
 #[inline]
 pub(crate) fn eq_case_insensitive(a: &str, b: &str) -> bool {
     if a.is_ascii() && b.is_ascii() {
@@ -1138,6 +1208,26 @@ mod tests {
         assert_eq!(cmp_case_insensitive("straße", "STRASSE"), Ordering::Equal);
     }
 
+    #[test]
+    fn cmp_text_collated_orders_accents_by_collation() {
+        use TextCollation::{Linguistic, Ordinal, Spanish};
+
+        assert_eq!(cmp_text_collated("é", "f", Ordinal), Ordering::Greater);
+        assert_eq!(cmp_text_collated("é", "f", Linguistic), Ordering::Less);
+        assert_eq!(cmp_text_collated("e", "é", Linguistic), Ordering::Less);
+        assert_eq!(
+            cmp_text_collated("ÉCOLE", "école", Linguistic),
+            Ordering::Equal
+        );
+        assert_eq!(
+            cmp_text_collated("straße", "STRASSE", Linguistic),
+            Ordering::Equal
+        );
+        assert_eq!(cmp_text_collated("ñu", "nz", Linguistic), Ordering::Less);
+        assert_eq!(cmp_text_collated("ñu", "nz", Spanish), Ordering::Greater);
+        assert_eq!(cmp_text_collated("ñu", "oa", Spanish), Ordering::Less);
+    }
+
     #[test]
     fn casefolded_key_arc_if_matches_casefold_when_predicate_passes() {
         for s in ["foo", "FOO", "Straße", "ß", "_xlfn.xlookup"] {
@@ -61,6 +61,66 @@ fn mismatched_array_shapes_return_value_error() {
     assert!(engine.spill_range("Sheet1", "A1").is_none());
 }
 
+#[test]
+fn mismatched_vector_lengths_return_value_error() {
+    let mut engine = Engine::new();
+    for (row, value) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+        engine
+            .set_cell_value("Sheet1", &format!("A{}", row + 1), value)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{}", row + 1), value * 10.0)
+            .unwrap();
+    }
+    engine
+        .set_cell_formula("Sheet1", "D1", "={1,2,3}+{10,20}")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "D3", "=A1:A3+B1:B2")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "D5", "={1,2;3,4}*{1;2;3}")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    // Neither dimension can broadcast (lengths differ and neither is 1), so the whole result is
+    // `#VALUE!` rather than a partially padded array.
+    for cell in ["D1", "D3", "D5"] {
+        assert_eq!(
+            engine.get_cell_value("Sheet1", cell),
+            Value::Error(ErrorKind::Value),
+            "{cell}"
+        );
+        assert!(engine.spill_range("Sheet1", cell).is_none(), "{cell}");
+    }
+}
+
+#[test]
+fn scalars_broadcast_over_arrays_on_either_side() {
+    let mut engine = Engine::new();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=10-{1,2,3}")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A3", "={1;2;3}^2")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    let (start, end) = engine.spill_range("Sheet1", "A1").expect("spill range");
+    assert_eq!(start, parse_a1("A1").unwrap());
+    assert_eq!(end, parse_a1("C1").unwrap());
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(9.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(8.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(7.0));
+
+    let (start, end) = engine.spill_range("Sheet1", "A3").expect("spill range");
+    assert_eq!(start, parse_a1("A3").unwrap());
+    assert_eq!(end, parse_a1("A5").unwrap());
+    assert_eq!(engine.get_cell_value("Sheet1", "A3"), Value::Number(1.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "A4"), Value::Number(4.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "A5"), Value::Number(9.0));
+}
+
 #[test]
 fn outer_broadcasting_spills_2d_arrays() {
     let mut engine = Engine::new();
@@ -1,6 +1,6 @@
 use formula_engine::coercion::ValueLocaleConfig;
 use formula_engine::date::{ymd_to_serial, ExcelDate, ExcelDateSystem};
-use formula_engine::{Engine, Value};
+use formula_engine::{Engine, ErrorKind, Value};
 
 fn eval_single_cell_with_date_system(
     formula: &str,
@@ -299,3 +299,50 @@ fn bytecode_coercion_number_to_text_respects_value_locale() {
     assert!(bc_programs > 0, "expected formula to compile to bytecode");
     assert_eq!(bc_val, ast_val);
 }
+
+#[test]
+fn bytecode_operator_and_aggregate_text_coercion_matches_ast() {
+    // Operators coerce numeric text wherever it comes from; SUM/COUNT coerce text passed
+    // directly as an argument but skip text read from references, ranges and arrays.
+    let cases = [
+        ("=\"5\"+1", Value::Number(6.0)),
+        ("=B1+1", Value::Number(6.0)),
+        ("=B3+1", Value::Error(ErrorKind::Value)),
+        ("=SUM(\"5\",1)", Value::Number(6.0)),
+        ("=SUM(\"abc\",1)", Value::Error(ErrorKind::Value)),
+        ("=SUM(B1,1)", Value::Number(1.0)),
+        ("=SUM(B1:B3)", Value::Number(1.0)),
+        ("=COUNT(\"5\",1)", Value::Number(2.0)),
+        ("=COUNT(B1,1)", Value::Number(1.0)),
+        ("=COUNT(B1:B3)", Value::Number(1.0)),
+    ];
+
+    for bytecode_enabled in [false, true] {
+        let mut engine = Engine::new();
+        engine.set_bytecode_enabled(bytecode_enabled);
+        engine.set_cell_value("Sheet1", "B1", "5").unwrap();
+        engine.set_cell_value("Sheet1", "B2", 1.0).unwrap();
+        engine.set_cell_value("Sheet1", "B3", "abc").unwrap();
+        for (row, (formula, _)) in cases.iter().enumerate() {
+            let addr = format!("A{}", row + 1);
+            engine.set_cell_formula("Sheet1", &addr, formula).unwrap();
+        }
+        engine.recalculate_single_threaded();
+
+        if bytecode_enabled {
+            assert_eq!(
+                engine.bytecode_program_count(),
+                cases.len(),
+                "expected every formula to compile to bytecode"
+            );
+        }
+        for (row, (formula, expected)) in cases.iter().enumerate() {
+            let addr = format!("A{}", row + 1);
+            assert_eq!(
+                &engine.get_cell_value("Sheet1", &addr),
+                expected,
+                "{formula} (bytecode={bytecode_enabled})"
+            );
+        }
+    }
+}
@@ -44,3 +44,37 @@ fn bytecode_large_sparse_range() {
     engine.recalculate_single_threaded();
     assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(3.0));
 }
+
+#[test]
+fn whole_column_and_row_refs_sum_populated_cells_on_both_backends() {
+    for bytecode_enabled in [true, false] {
+        let mut engine = Engine::new();
+        engine.set_bytecode_enabled(bytecode_enabled);
+        for i in 0..10u32 {
+            engine
+                .set_cell_value("Sheet1", &format!("A{}", i * 100_000 + 1), f64::from(i + 1))
+                .expect("set value");
+        }
+        engine
+            .set_cell_value("Sheet1", "C1", 100.0)
+            .expect("set C1");
+        engine
+            .set_cell_value("Sheet1", "D500001", 1000.0)
+            .expect("set D500001");
+
+        engine
+            .set_cell_formula("Sheet1", "F2", "=SUM(A:A)")
+            .expect("set formula");
+        engine
+            .set_cell_formula("Sheet1", "F3", "=SUM(1:1)")
+            .expect("set formula");
+        engine
+            .set_cell_formula("Sheet1", "F4", "=COUNT(A:D)")
+            .expect("set formula");
+        engine.recalculate_single_threaded();
+
+        assert_eq!(engine.get_cell_value("Sheet1", "F2"), Value::Number(55.0));
+        assert_eq!(engine.get_cell_value("Sheet1", "F3"), Value::Number(101.0));
+        assert_eq!(engine.get_cell_value("Sheet1", "F4"), Value::Number(12.0));
+    }
+}
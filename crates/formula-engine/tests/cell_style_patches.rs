@@ -1,6 +1,6 @@
 use formula_engine::style_patch::{AlignmentPatch, ProtectionPatch, StylePatch};
 use formula_engine::{Engine, Value};
-use formula_model::HorizontalAlignment;
+use formula_model::{CellRef, HorizontalAlignment, Range, EXCEL_MAX_ROWS};
 
 #[test]
 fn cell_prefix_respects_explicit_alignment_null_clear() {
@@ -137,3 +137,54 @@ fn spilled_outputs_use_origin_style_patch() {
         Value::Text("\"".to_string())
     );
 }
+
+#[test]
+fn first_locked_cell_resolves_whole_columns_per_layer() {
+    let mut engine = Engine::new();
+    let protection = |locked: bool| StylePatch {
+        protection: Some(ProtectionPatch {
+            locked: Some(Some(locked)),
+        }),
+        ..StylePatch::default()
+    };
+    engine.set_style_patch(1, protection(false));
+    engine.set_style_patch(2, protection(true));
+
+    // Column B: unlocked, so the whole column is editable.
+    engine.set_col_patch_style_id("Sheet1", 1, 1);
+    let column_b = Range::new(CellRef::new(0, 1), CellRef::new(EXCEL_MAX_ROWS - 1, 1));
+    assert_eq!(engine.first_locked_cell("Sheet1", column_b), None);
+
+    // Column A keeps the default locked state.
+    let a_to_b = Range::new(CellRef::new(0, 0), CellRef::new(EXCEL_MAX_ROWS - 1, 1));
+    assert_eq!(
+        engine.first_locked_cell("Sheet1", a_to_b),
+        Some(CellRef::new(0, 0))
+    );
+
+    // A stored cell locked by its own style.
+    engine.set_cell_value("Sheet1", "B10", 1.0).unwrap();
+    engine.set_cell_patch_style_id("Sheet1", "B10", 2).unwrap();
+    assert_eq!(
+        engine.first_locked_cell("Sheet1", column_b),
+        Some(CellRef::new(9, 1))
+    );
+
+    // A locked row wins over the unlocked column, and is reported first.
+    engine.set_row_patch_style_id("Sheet1", 4, 2);
+    assert_eq!(
+        engine.first_locked_cell("Sheet1", column_b),
+        Some(CellRef::new(4, 1))
+    );
+
+    // Unlocking the stored cell and the row leaves the column editable again.
+    engine.set_cell_patch_style_id("Sheet1", "B10", 1).unwrap();
+    engine.set_row_patch_style_id("Sheet1", 4, 1);
+    assert_eq!(engine.first_locked_cell("Sheet1", column_b), None);
+
+    // Missing sheets are treated as locked.
+    assert_eq!(
+        engine.first_locked_cell("Missing", column_b),
+        Some(CellRef::new(0, 1))
+    );
+}
@@ -230,13 +230,18 @@ fn countif_boolean_criteria() {
     engine.set_cell_value("Sheet1", "A5", "TRUE").unwrap();
     // A6 left unset (blank).
 
+    // Booleans only match booleans: not 1/0, the text "TRUE", or blanks.
     assert_eq!(
         eval(&mut engine, "=COUNTIF(A1:A6, TRUE)"),
-        Value::Number(2.0)
+        Value::Number(1.0)
     );
     assert_eq!(
         eval(&mut engine, "=COUNTIF(A1:A6, FALSE)"),
-        Value::Number(3.0)
+        Value::Number(1.0)
+    );
+    assert_eq!(
+        eval(&mut engine, r#"=COUNTIF(A1:A6, "<>TRUE")"#),
+        Value::Number(5.0)
     );
 }
 
@@ -245,7 +250,7 @@ fn countif_numeric_criteria_does_not_treat_text_as_zero() {
     let mut engine = Engine::new();
     engine.set_cell_value("Sheet1", "A1", "x").unwrap();
     engine.set_cell_value("Sheet1", "A2", 0.0).unwrap();
-    // A3 left unset (blank) -> treated as 0 for numeric COUNTIF criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     engine
         .set_cell_formula("Sheet1", "Z1", "=COUNTIF(A1:A3, 0)")
@@ -255,7 +260,7 @@ fn countif_numeric_criteria_does_not_treat_text_as_zero() {
         "expected COUNTIF formula to compile to bytecode for this test"
     );
     engine.recalculate_single_threaded();
-    assert_eq!(engine.get_cell_value("Sheet1", "Z1"), Value::Number(2.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "Z1"), Value::Number(1.0));
 }
 
 #[test]
@@ -263,7 +268,7 @@ fn countifs_numeric_criteria_does_not_treat_text_as_zero() {
     let mut engine = Engine::new();
     engine.set_cell_value("Sheet1", "A1", "x").unwrap();
     engine.set_cell_value("Sheet1", "A2", 0.0).unwrap();
-    // A3 left unset (blank) -> treated as 0 for numeric COUNTIFS criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     // Ensure the second criteria always matches so the result depends only on the numeric criteria.
     engine.set_cell_value("Sheet1", "B1", 1.0).unwrap();
@@ -278,7 +283,7 @@ fn countifs_numeric_criteria_does_not_treat_text_as_zero() {
         "expected COUNTIFS formula to compile to bytecode for this test"
     );
     engine.recalculate_single_threaded();
-    assert_eq!(engine.get_cell_value("Sheet1", "Z1"), Value::Number(2.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "Z1"), Value::Number(1.0));
 }
 
 #[test]
@@ -549,3 +554,63 @@ fn countifs_odd_arg_count_returns_value() {
         Value::Error(ErrorKind::Value)
     );
 }
+
+#[test]
+fn countif_comparison_edge_case_matrix() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 0.0).unwrap();
+    engine.set_cell_value("Sheet1", "A2", 5.0).unwrap();
+    engine.set_cell_value("Sheet1", "A3", "5").unwrap();
+    engine.set_cell_value("Sheet1", "A4", "").unwrap();
+    engine.set_cell_value("Sheet1", "A5", true).unwrap();
+    engine.set_cell_value("Sheet1", "A6", false).unwrap();
+    engine.set_cell_value("Sheet1", "A7", "abc").unwrap();
+    // A8 left unset (blank).
+    engine.set_cell_value("Sheet1", "A9", -1.0).unwrap();
+    // A10 left unset (blank).
+
+    let cases: &[(&str, f64)] = &[
+        // Blanks, empty strings and FALSE are not 0; only `<>` criteria match them.
+        ("0", 1.0),
+        (r#""0""#, 1.0),
+        (r#""=0""#, 1.0),
+        (r#""<>0""#, 9.0),
+        (r#""<1""#, 2.0),
+        (r#""<=0""#, 2.0),
+        (r#"">=0""#, 2.0),
+        // `""` / `"="` match empty strings and blanks; `"<>"` matches everything else.
+        (r#""""#, 3.0),
+        (r#""=""#, 3.0),
+        (r#""<>""#, 7.0),
+        // Numeric text equals the number, but never orders against it.
+        ("5", 2.0),
+        (r#""5""#, 2.0),
+        (r#""<>5""#, 8.0),
+        (r#"">4""#, 1.0),
+        (r#""<=5""#, 3.0),
+        // Booleans only match booleans.
+        ("TRUE", 1.0),
+        ("FALSE", 1.0),
+        (r#""<>TRUE""#, 9.0),
+        ("1", 0.0),
+        // Text ordering only applies to text.
+        (r#""abc""#, 1.0),
+        (r#"">a""#, 1.0),
+    ];
+
+    for bytecode_enabled in [true, false] {
+        engine.set_bytecode_enabled(bytecode_enabled);
+        for (criteria, expected) in cases {
+            for formula in [
+                format!("=COUNTIF(A1:A10, {criteria})"),
+                format!("=COUNTIFS(A1:A10, {criteria})"),
+            ] {
+                assert_eq!(
+                    eval(&mut engine, &formula),
+                    Value::Number(*expected),
+                    "{formula} (bytecode_enabled={bytecode_enabled})"
+                );
+            }
+        }
+    }
+}
@@ -70,7 +70,7 @@ fn sumif_numeric_criteria_does_not_treat_text_as_zero() {
     let mut sheet = TestSheet::new();
     sheet.set("A1", "x");
     sheet.set("A2", 0);
-    // A3 left unset (blank) -> treated as 0 for numeric SUMIF criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     sheet.set("B1", 5);
     sheet.set("B2", 10);
@@ -84,7 +84,7 @@ fn sumif_numeric_criteria_does_not_treat_text_as_zero() {
     sheet.engine.recalculate();
     assert_number(
         &sheet.engine.get_cell_value(sheet.sheet, sheet.scratch_cell),
-        30.0,
+        10.0,
     );
 }
 
@@ -93,7 +93,7 @@ fn sumifs_numeric_criteria_does_not_treat_text_as_zero() {
     let mut sheet = TestSheet::new();
     sheet.set("A1", "x");
     sheet.set("A2", 0);
-    // A3 left unset (blank) -> treated as 0 for numeric SUMIFS criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     // Second criteria always matches so the result depends only on the numeric criteria.
     sheet.set("B1", 1);
@@ -112,7 +112,7 @@ fn sumifs_numeric_criteria_does_not_treat_text_as_zero() {
     sheet.engine.recalculate();
     assert_number(
         &sheet.engine.get_cell_value(sheet.sheet, sheet.scratch_cell),
-        30.0,
+        10.0,
     );
 }
 
@@ -121,7 +121,7 @@ fn averageif_numeric_criteria_does_not_treat_text_as_zero() {
     let mut sheet = TestSheet::new();
     sheet.set("A1", "x");
     sheet.set("A2", 0);
-    // A3 left unset (blank) -> treated as 0 for numeric AVERAGEIF criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     sheet.set("B1", 5);
     sheet.set("B2", 10);
@@ -135,7 +135,7 @@ fn averageif_numeric_criteria_does_not_treat_text_as_zero() {
     sheet.engine.recalculate();
     assert_number(
         &sheet.engine.get_cell_value(sheet.sheet, sheet.scratch_cell),
-        15.0,
+        10.0,
     );
 }
 
@@ -144,7 +144,7 @@ fn averageifs_numeric_criteria_does_not_treat_text_as_zero() {
     let mut sheet = TestSheet::new();
     sheet.set("A1", "x");
     sheet.set("A2", 0);
-    // A3 left unset (blank) -> treated as 0 for numeric AVERAGEIFS criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     // Second criteria always matches so the result depends only on the numeric criteria.
     sheet.set("B1", 1);
@@ -166,7 +166,7 @@ fn averageifs_numeric_criteria_does_not_treat_text_as_zero() {
     sheet.engine.recalculate();
     assert_number(
         &sheet.engine.get_cell_value(sheet.sheet, sheet.scratch_cell),
-        15.0,
+        10.0,
     );
 }
 
@@ -182,6 +182,49 @@ fn averageif_treats_blank_average_range_as_omitted() {
     assert_number(&sheet.eval(r#"=AVERAGEIF(A1:A4,">2",)"#), 3.5);
 }
 
+#[test]
+fn averageif_resizes_smaller_average_range_to_criteria_shape() {
+    for bytecode_enabled in [true, false] {
+        let mut sheet = TestSheet::new();
+        sheet.engine.set_bytecode_enabled(bytecode_enabled);
+        for (row, (key, amount)) in [("a", 10), ("b", 20), ("a", 30), ("a", 50)]
+            .into_iter()
+            .enumerate()
+        {
+            sheet.set(&format!("A{}", row + 1), key);
+            sheet.set(&format!("B{}", row + 1), amount);
+        }
+        // D1:E2 holds values for the 2-D case below.
+        sheet.set("D1", 1);
+        sheet.set("E1", 2);
+        sheet.set("D2", 3);
+        sheet.set("E2", 4);
+
+        // A single-cell average_range expands to B1:B4.
+        assert_number(&sheet.eval(r#"=AVERAGEIF(A1:A4,"a",B1)"#), 30.0);
+        // A shorter range is also anchored at its top-left cell and extended.
+        assert_number(&sheet.eval(r#"=AVERAGEIF(A1:A4,"a",B1:B2)"#), 30.0);
+        // The resize applies in both dimensions: D1 becomes D1:E2.
+        assert_number(&sheet.eval(r#"=AVERAGEIF(D1:E2,">1",D1)"#), 3.0);
+        // An offset anchor shifts the averaged cells: B2 becomes B2:B5 (B5 is blank).
+        assert_number(&sheet.eval(r#"=AVERAGEIF(A1:A4,"a",B2)"#), 35.0);
+        // Cells pulled in by the resize are dependencies of the formula.
+        sheet.set_formula("C1", r#"=AVERAGEIF(A1:A4,"a",B1)"#);
+        sheet.engine.recalculate();
+        sheet.set("B4", 80);
+        sheet.engine.recalculate();
+        assert_number(&sheet.engine.get_cell_value(sheet.sheet, "C1"), 40.0);
+        sheet.set("B4", 50);
+
+        // No matches still divides by zero after the resize.
+        assert_eq!(
+            sheet.eval(r#"=AVERAGEIF(A1:A4,"z",B1)"#),
+            Value::Error(ErrorKind::Div0),
+            "bytecode_enabled={bytecode_enabled}"
+        );
+    }
+}
+
 #[test]
 fn sumif_supports_wildcards_and_blank_criteria() {
     let mut sheet = TestSheet::new();
@@ -429,6 +472,37 @@ fn sumif_parses_date_criteria_strings() {
     assert_number(&sheet.eval(r#"=SUMIF(A1:A3,">1/1/2020",B1:B3)"#), 3.0);
 }
 
+#[test]
+fn criteria_concatenated_with_date_compare_on_serials() {
+    let mut sheet = TestSheet::new();
+
+    sheet.set_formula("A1", "=DATE(2023,12,31)");
+    sheet.set_formula("A2", "=DATE(2024,1,1)");
+    sheet.set_formula("A3", "=DATE(2024,3,15)");
+    sheet.set("A4", "2024-06-01");
+    sheet.set("A5", "Total");
+
+    sheet.set("B1", 100);
+    sheet.set("B2", 200);
+    sheet.set("B3", 300);
+    sheet.set("B4", 400);
+    sheet.set("B5", 1000);
+
+    assert_number(
+        &sheet.eval(r#"=SUMIF(A1:A5,">="&DATE(2024,1,1),B1:B5)"#),
+        500.0,
+    );
+    assert_number(
+        &sheet.eval(r#"=SUMIFS(B1:B5,A1:A5,">="&DATE(2024,1,1),A1:A5,"<"&DATE(2024,3,15))"#),
+        200.0,
+    );
+    assert_number(&sheet.eval(r#"=COUNTIF(A1:A5,"<"&DATE(2024,1,1))"#), 1.0);
+    assert_number(&sheet.eval("=COUNTIF(A1:A5,DATE(2024,3,15))"), 1.0);
+
+    sheet.set_formula("C1", "=DATE(2024,1,1)");
+    assert_number(&sheet.eval(r#"=SUMIF(A1:A5,">="&C1,B1:B5)"#), 500.0);
+}
+
 #[test]
 fn sumif_indirect_records_dynamic_dependencies() {
     let mut engine = Engine::new();
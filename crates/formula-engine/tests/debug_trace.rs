@@ -2,7 +2,8 @@ use formula_engine::date::ExcelDateSystem;
 use formula_engine::debug::{Span, TraceKind, TraceRef};
 use formula_engine::eval::CellAddr;
 use formula_engine::{
-    Engine, ExternalDataProvider, ExternalValueProvider, NameDefinition, NameScope, Value,
+    Engine, ErrorKind, ExternalDataProvider, ExternalValueProvider, NameDefinition, NameScope,
+    Value,
 };
 use formula_model::table::TableColumn;
 use formula_model::{Range, Table};
@@ -598,6 +599,43 @@ fn trace_respects_if_short_circuiting() {
     assert_eq!(dbg.trace.children[1].value, Value::Number(1.0));
 }
 
+#[test]
+fn debug_trace_iferror_replaces_array_errors_elementwise() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+    engine
+        .set_cell_value("Sheet1", "A2", Value::Error(ErrorKind::NA))
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C1", "=IFERROR(A1:A2,0)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "D1", "=IFNA({1;2},0)")
+        .unwrap();
+    engine.recalculate();
+
+    let dbg = engine.debug_evaluate("Sheet1", "C1").unwrap();
+    let Value::Array(arr) = dbg.value else {
+        panic!(
+            "expected Value::Array from debug evaluation, got {:?}",
+            dbg.value
+        );
+    };
+    assert_eq!(arr.values, vec![Value::Number(1.0), Value::Number(0.0)]);
+    assert_eq!(dbg.trace.children.len(), 2);
+
+    // Without errors the fallback is not evaluated (or traced).
+    let dbg = engine.debug_evaluate("Sheet1", "D1").unwrap();
+    let Value::Array(arr) = dbg.value else {
+        panic!(
+            "expected Value::Array from debug evaluation, got {:?}",
+            dbg.value
+        );
+    };
+    assert_eq!(arr.values, vec![Value::Number(1.0), Value::Number(2.0)]);
+    assert_eq!(dbg.trace.children.len(), 1);
+}
+
 #[test]
 fn trace_preserves_reference_context_for_named_ranges() {
     let mut engine = Engine::new();
@@ -1,5 +1,6 @@
 use formula_engine::{
-    EditError, EditOp, Engine, ErrorKind, NameDefinition, NameScope, PrecedentNode, Value,
+    EditError, EditOp, Engine, ErrorKind, NameDefinition, NameRenameError, NameScope,
+    PrecedentNode, Value,
 };
 
 #[test]
@@ -482,3 +483,223 @@ fn named_lambda_calls_surface_external_precedents() {
         "expected external precedent for [Book.xlsx]Sheet1!A1, got {precedents:?}"
     );
 }
+
+#[test]
+fn offset_defined_name_grows_with_appended_data() {
+    let mut engine = Engine::new();
+    for (row, v) in [(1, 10.0), (2, 20.0), (3, 30.0)] {
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), v)
+            .unwrap();
+    }
+    engine
+        .define_name(
+            "ChartData",
+            NameScope::Workbook,
+            NameDefinition::Reference("OFFSET(Sheet1!$A$1,0,0,COUNTA(Sheet1!$A:$A),1)".to_string()),
+        )
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C1", "=SUM(ChartData)")
+        .unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(60.0));
+
+    // Appending a point extends the dynamic range on the next recalc.
+    engine.set_cell_value("Sheet1", "A4", 40.0).unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(100.0));
+
+    engine.clear_cell("Sheet1", "A4").unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(60.0));
+}
+
+#[test]
+fn name_referencing_spill_tracks_spill_extent() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "E1", 3.0).unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=SEQUENCE(E1)")
+        .unwrap();
+    engine
+        .define_name(
+            "Series",
+            NameScope::Workbook,
+            NameDefinition::Reference("Sheet1!$A$1#".to_string()),
+        )
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C1", "=SUM(Series)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C2", "=ROWS(Series)")
+        .unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(6.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C2"), Value::Number(3.0));
+
+    engine.set_cell_value("Sheet1", "E1", 5.0).unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(15.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C2"), Value::Number(5.0));
+
+    engine.set_cell_value("Sheet1", "E1", 2.0).unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(3.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C2"), Value::Number(2.0));
+}
+
+#[test]
+fn rename_workbook_name_rewrites_formulas_on_every_sheet() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 10.0).unwrap();
+    engine
+        .define_name(
+            "Rate",
+            NameScope::Workbook,
+            NameDefinition::Reference("Sheet1!$A$1".to_string()),
+        )
+        .unwrap();
+    engine
+        .define_name(
+            "Doubled",
+            NameScope::Workbook,
+            NameDefinition::Formula("=Rate*2".to_string()),
+        )
+        .unwrap();
+    // Sheet3 has its own `Rate`, which shadows the workbook name there.
+    engine
+        .define_name(
+            "Rate",
+            NameScope::Sheet("Sheet3"),
+            NameDefinition::Constant(Value::Number(1.0)),
+        )
+        .unwrap();
+    engine.set_cell_formula("Sheet1", "B1", "=rate+1").unwrap();
+    engine
+        .set_cell_formula("Sheet2", "B1", "=SUM(Rate,Doubled)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet2", "B2", "=LET(Rate,5,Rate+Sheet1!Rate)")
+        .unwrap();
+    engine.set_cell_formula("Sheet3", "B1", "=Rate").unwrap();
+    engine
+        .set_cell_formula("Sheet3", "B2", "=Sheet2!Rate")
+        .unwrap();
+    engine.recalculate();
+
+    engine
+        .rename_name("Rate", "TaxRate", NameScope::Workbook)
+        .unwrap();
+    engine.recalculate();
+
+    assert_eq!(engine.get_cell_formula("Sheet1", "B1"), Some("=TaxRate+1"));
+    assert_eq!(
+        engine.get_cell_formula("Sheet2", "B1"),
+        Some("=SUM(TaxRate,Doubled)")
+    );
+    assert_eq!(
+        engine.get_cell_formula("Sheet2", "B2"),
+        Some("=LET(Rate,5,Rate+Sheet1!TaxRate)")
+    );
+    assert_eq!(engine.get_cell_formula("Sheet3", "B1"), Some("=Rate"));
+    assert_eq!(
+        engine.get_cell_formula("Sheet3", "B2"),
+        Some("=Sheet2!TaxRate")
+    );
+    assert_eq!(
+        engine.get_name("Doubled", NameScope::Workbook),
+        Some(&NameDefinition::Formula("=TaxRate*2".to_string()))
+    );
+    assert_eq!(engine.get_name("Rate", NameScope::Workbook), None);
+    assert!(engine
+        .get_name("Rate", NameScope::Sheet("Sheet3"))
+        .is_some());
+
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(11.0));
+    assert_eq!(engine.get_cell_value("Sheet2", "B1"), Value::Number(30.0));
+    assert_eq!(engine.get_cell_value("Sheet2", "B2"), Value::Number(15.0));
+    assert_eq!(engine.get_cell_value("Sheet3", "B1"), Value::Number(1.0));
+    assert_eq!(engine.get_cell_value("Sheet3", "B2"), Value::Number(10.0));
+
+    // The new name stays live for later edits.
+    engine.set_cell_value("Sheet1", "A1", 20.0).unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet2", "B1"), Value::Number(60.0));
+}
+
+#[test]
+fn rename_sheet_scoped_name_and_lambda_calls() {
+    let mut engine = Engine::new();
+    engine
+        .define_name(
+            "Twice",
+            NameScope::Sheet("Sheet1"),
+            NameDefinition::Formula("=LAMBDA(x,x*2)".to_string()),
+        )
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=Twice(4)")
+        .unwrap();
+    // Unqualified on another sheet: not the sheet-scoped name.
+    engine
+        .set_cell_formula("Sheet2", "A2", "=Twice(4)")
+        .unwrap();
+    engine.recalculate();
+
+    engine
+        .rename_name("twice", "Double", NameScope::Sheet("Sheet1"))
+        .unwrap();
+    engine.recalculate();
+
+    // Function-call syntax is re-serialized with an upper-case name, like built-in calls.
+    assert_eq!(engine.get_cell_formula("Sheet1", "A1"), Some("=DOUBLE(4)"));
+    assert_eq!(engine.get_cell_formula("Sheet2", "A2"), Some("=Twice(4)"));
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(8.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet2", "A2"),
+        Value::Error(ErrorKind::Name)
+    );
+}
+
+#[test]
+fn rename_name_rejects_collisions_and_unknown_names() {
+    let mut engine = Engine::new();
+    for name in ["Rate", "Discount"] {
+        engine
+            .define_name(
+                name,
+                NameScope::Workbook,
+                NameDefinition::Constant(Value::Number(1.0)),
+            )
+            .unwrap();
+    }
+    engine.set_cell_formula("Sheet1", "A1", "=Rate").unwrap();
+
+    assert!(matches!(
+        engine.rename_name("Rate", "DISCOUNT", NameScope::Workbook),
+        Err(NameRenameError::DuplicateName)
+    ));
+    assert!(matches!(
+        engine.rename_name("Missing", "Other", NameScope::Workbook),
+        Err(NameRenameError::NameNotFound)
+    ));
+    assert!(matches!(
+        engine.rename_name("Rate", "Other", NameScope::Sheet("Sheet1")),
+        Err(NameRenameError::NameNotFound)
+    ));
+    assert!(matches!(
+        engine.rename_name("Rate", "A1", NameScope::Workbook),
+        Err(NameRenameError::InvalidName(_))
+    ));
+    // Failed renames leave everything untouched.
+    assert_eq!(engine.get_cell_formula("Sheet1", "A1"), Some("=Rate"));
+    assert!(engine.get_name("Rate", NameScope::Workbook).is_some());
+
+    // Changing only the case is allowed and respells references.
+    engine
+        .rename_name("Rate", "RATE", NameScope::Workbook)
+        .unwrap();
+    assert_eq!(engine.get_cell_formula("Sheet1", "A1"), Some("=RATE"));
+}
@@ -1698,3 +1698,56 @@ fn indirect_rejects_non_degenerate_3d_sheet_spans() {
     assert!(engine.precedents("Summary", "A1").unwrap().is_empty());
     assert!(engine.dependents("Sheet2", "A1").unwrap().is_empty());
 }
+
+#[test]
+fn editing_indirect_target_dirties_dependents_for_incremental_recalc() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C1", "=INDIRECT(\"A1\")")
+        .unwrap();
+    engine.set_cell_formula("Sheet1", "D1", "=C1*2").unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "D1"), Value::Number(2.0));
+
+    // The edge discovered while evaluating INDIRECT is in the graph, so editing the target marks
+    // the dependents dirty right away (not only because INDIRECT is volatile).
+    engine.set_cell_value("Sheet1", "A1", 5.0).unwrap();
+    assert!(engine.is_dirty("Sheet1", "C1"));
+    assert!(engine.is_dirty("Sheet1", "D1"));
+    assert_eq!(
+        engine.dirty_dependency_path("Sheet1", "C1"),
+        Some(vec![
+            PrecedentNode::Cell {
+                sheet: 0,
+                addr: CellAddr { row: 0, col: 0 } // A1
+            },
+            PrecedentNode::Cell {
+                sheet: 0,
+                addr: CellAddr { row: 0, col: 2 } // C1
+            },
+        ])
+    );
+
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(5.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "D1"), Value::Number(10.0));
+}
+
+#[test]
+fn editing_offset_target_dirties_dependents_for_incremental_recalc() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A2", 1.0).unwrap();
+    engine.set_cell_value("Sheet1", "A3", 2.0).unwrap();
+    engine
+        .set_cell_formula("Sheet1", "B1", "=SUM(OFFSET(A1,1,0,2,1))")
+        .unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(3.0));
+
+    engine.set_cell_value("Sheet1", "A3", 10.0).unwrap();
+    assert!(engine.is_dirty("Sheet1", "B1"));
+
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(11.0));
+}
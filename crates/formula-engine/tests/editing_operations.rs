@@ -1,4 +1,5 @@
-use formula_engine::{EditOp, Engine, NameDefinition, NameScope, Value};
+use formula_engine::sort_filter::{SortKey, SortOrder, SortValueType};
+use formula_engine::{EditOp, Engine, NameDefinition, NameScope, TextToColumnsSplit, Value};
 use formula_model::{CellRef, Range, EXCEL_MAX_COLS};
 use pretty_assertions::assert_eq;
 
@@ -727,3 +728,288 @@ fn move_range_updates_named_range_definitions() {
     engine.recalculate();
     assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(42.0));
 }
+
+#[test]
+fn text_to_columns_splits_delimited_text_and_retypes_pieces() {
+    let mut engine = Engine::new();
+    engine
+        .set_cell_value("Sheet1", "A1", "Alice,42,2024-01-15,TRUE")
+        .unwrap();
+    engine.set_cell_value("Sheet1", "A2", "Bob,,7").unwrap();
+    engine.set_cell_value("Sheet1", "A3", 5.0).unwrap();
+    engine.set_cell_value("Sheet1", "B2", "old").unwrap();
+    engine.set_cell_value("Sheet1", "E1", "kept").unwrap();
+
+    engine
+        .apply_operation(EditOp::TextToColumns {
+            sheet: "Sheet1".to_string(),
+            src: range("A1:A3"),
+            split: TextToColumnsSplit::Delimiter(','),
+            shift_right: false,
+        })
+        .unwrap();
+
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Text("Alice".to_string())
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(42.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "C1"),
+        Value::Number(45306.0)
+    );
+    assert_eq!(
+        engine
+            .cell_number_format("Sheet1", "C1")
+            .unwrap()
+            .as_deref(),
+        Some("m/d/yyyy")
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "D1"), Value::Bool(true));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "E1"),
+        Value::Text("kept".to_string())
+    );
+    // Empty pieces overwrite (clear) the neighbouring cell; non-text cells are left alone.
+    assert_eq!(engine.get_cell_value("Sheet1", "B2"), Value::Blank);
+    assert_eq!(engine.get_cell_value("Sheet1", "C2"), Value::Number(7.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "A3"), Value::Number(5.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "B3"), Value::Blank);
+}
+
+#[test]
+fn text_to_columns_fixed_widths_can_shift_existing_cells_right() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", "NY  0012.5").unwrap();
+    engine.set_cell_value("Sheet1", "A2", "CA  0003").unwrap();
+    engine.set_cell_value("Sheet1", "B1", "note").unwrap();
+    engine.set_cell_formula("Sheet1", "C1", "=B1").unwrap();
+
+    engine
+        .apply_operation(EditOp::TextToColumns {
+            sheet: "Sheet1".to_string(),
+            src: range("A1:A2"),
+            split: TextToColumnsSplit::FixedWidths(vec![4, 4]),
+            shift_right: true,
+        })
+        .unwrap();
+
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Text("NY".to_string())
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(12.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(0.5));
+    assert_eq!(engine.get_cell_value("Sheet1", "B2"), Value::Number(3.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C2"), Value::Blank);
+
+    // Two extra columns were inserted in the split rows, moving B1/C1 to D1/E1.
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "D1"),
+        Value::Text("note".to_string())
+    );
+    assert_eq!(engine.get_cell_formula("Sheet1", "E1"), Some("=D1"));
+
+    assert_eq!(
+        engine.apply_operation(EditOp::TextToColumns {
+            sheet: "Sheet1".to_string(),
+            src: range("A1:B2"),
+            split: TextToColumnsSplit::Delimiter(','),
+            shift_right: false,
+        }),
+        Err(formula_engine::EditError::InvalidRange)
+    );
+}
+
+#[test]
+fn remove_duplicates_keys_on_multiple_columns_case_insensitively() {
+    let mut engine = Engine::new();
+    let rows: [(&str, f64, &str); 5] = [
+        ("Apple", 1.0, "first"),
+        ("apple", 1.0, "dup of row 1"),
+        ("Apple", 2.0, "different qty"),
+        ("Pear", 1.0, "pear"),
+        ("APPLE", 2.0, "dup of row 3"),
+    ];
+    for (i, (name, qty, note)) in rows.iter().enumerate() {
+        let row = i + 1;
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), *name)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), *qty)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("C{row}"), *note)
+            .unwrap();
+    }
+    engine.set_cell_formula("Sheet1", "D4", "=B4*10").unwrap();
+    engine.set_cell_value("Sheet1", "E2", "outside").unwrap();
+
+    let result = engine
+        .apply_operation(EditOp::RemoveDuplicates {
+            sheet: "Sheet1".to_string(),
+            range: range("A1:D5"),
+            key_columns: vec![0, 1],
+        })
+        .unwrap();
+    assert_eq!(result.removed_rows, 2);
+
+    let text = |s: &str| Value::Text(s.to_string());
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), text("first"));
+    assert_eq!(engine.get_cell_value("Sheet1", "C2"), text("different qty"));
+    assert_eq!(engine.get_cell_value("Sheet1", "A3"), text("Pear"));
+    assert_eq!(engine.get_cell_value("Sheet1", "C3"), text("pear"));
+    // The formula moved up with its row and follows it.
+    assert_eq!(engine.get_cell_formula("Sheet1", "D3"), Some("=B3*10"));
+    for addr in ["A4", "B4", "C4", "D4", "A5", "C5"] {
+        assert_eq!(
+            engine.get_cell_value("Sheet1", addr),
+            Value::Blank,
+            "{addr}"
+        );
+    }
+    assert_eq!(engine.get_cell_value("Sheet1", "E2"), text("outside"));
+}
+
+#[test]
+fn remove_duplicates_treats_blank_keys_as_equal() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", "x").unwrap();
+    engine.set_cell_value("Sheet1", "B2", 1.0).unwrap();
+    engine.set_cell_value("Sheet1", "B3", 2.0).unwrap();
+    engine.set_cell_value("Sheet1", "A4", "x").unwrap();
+    engine.set_cell_value("Sheet1", "B4", 3.0).unwrap();
+
+    let result = engine
+        .apply_operation(EditOp::RemoveDuplicates {
+            sheet: "Sheet1".to_string(),
+            range: range("A1:B4"),
+            key_columns: vec![0],
+        })
+        .unwrap();
+
+    // Rows 2 and 3 both have a blank key (row 3 is the duplicate), as does row 4 of "x".
+    assert_eq!(result.removed_rows, 2);
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Text("x".to_string())
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "B2"), Value::Number(1.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "B3"), Value::Blank);
+    assert_eq!(engine.get_cell_value("Sheet1", "B4"), Value::Blank);
+
+    assert_eq!(
+        engine.apply_operation(EditOp::RemoveDuplicates {
+            sheet: "Sheet1".to_string(),
+            range: range("A1:B4"),
+            key_columns: vec![2],
+        }),
+        Err(formula_engine::EditError::InvalidRange)
+    );
+}
+
+fn sort_key(column: usize, order: SortOrder, custom_list: Option<&[&str]>) -> SortKey {
+    SortKey {
+        column,
+        order,
+        value_type: SortValueType::Auto,
+        case_sensitive: false,
+        custom_list: custom_list.map(|list| list.iter().map(|s| s.to_string()).collect()),
+    }
+}
+
+#[test]
+fn sort_range_orders_rows_by_two_keys_and_keeps_formulas_with_their_rows() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", "Region").unwrap();
+    engine.set_cell_value("Sheet1", "B1", "Sales").unwrap();
+    engine.set_cell_value("Sheet1", "C1", "Double").unwrap();
+    for (row, region, sales) in [
+        (2, "West", 10.0),
+        (3, "East", 5.0),
+        (4, "West", 30.0),
+        (5, "east", 20.0),
+    ] {
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), region)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), sales)
+            .unwrap();
+        engine
+            .set_cell_formula("Sheet1", &format!("C{row}"), &format!("=B{row}*2"))
+            .unwrap();
+    }
+    engine.set_cell_formula("Sheet1", "E1", "=B2").unwrap();
+
+    let result = engine
+        .apply_operation(EditOp::SortRange {
+            sheet: "Sheet1".to_string(),
+            range: range("A1:C5"),
+            keys: vec![
+                sort_key(0, SortOrder::Ascending, None),
+                sort_key(1, SortOrder::Descending, None),
+            ],
+            has_header: true,
+        })
+        .unwrap();
+    assert!(result.moved_ranges.is_empty());
+    engine.recalculate();
+
+    let text = |s: &str| Value::Text(s.to_string());
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), text("Region"));
+    let sorted: Vec<(Value, Value, Value)> = (2..=5)
+        .map(|row| {
+            (
+                engine.get_cell_value("Sheet1", &format!("A{row}")),
+                engine.get_cell_value("Sheet1", &format!("B{row}")),
+                engine.get_cell_value("Sheet1", &format!("C{row}")),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sorted,
+        vec![
+            (text("east"), Value::Number(20.0), Value::Number(40.0)),
+            (text("East"), Value::Number(5.0), Value::Number(10.0)),
+            (text("West"), Value::Number(30.0), Value::Number(60.0)),
+            (text("West"), Value::Number(10.0), Value::Number(20.0)),
+        ]
+    );
+    assert_eq!(engine.get_cell_formula("Sheet1", "C2"), Some("=B2*2"));
+    // References into the sorted range from outside it are not rewritten.
+    assert_eq!(engine.get_cell_formula("Sheet1", "E1"), Some("=B2"));
+    assert_eq!(engine.get_cell_value("Sheet1", "E1"), Value::Number(20.0));
+}
+
+#[test]
+fn sort_range_honors_custom_lists() {
+    let mut engine = Engine::new();
+    for (row, month) in ["Mar", "other", "jan", "Feb", "Apple"].iter().enumerate() {
+        engine
+            .set_cell_value("Sheet1", &format!("A{}", row + 1), *month)
+            .unwrap();
+    }
+
+    let months: &[&str] = &["Jan", "Feb", "Mar"];
+    engine
+        .apply_operation(EditOp::SortRange {
+            sheet: "Sheet1".to_string(),
+            range: range("A1:A5"),
+            keys: vec![sort_key(0, SortOrder::Ascending, Some(months))],
+            has_header: false,
+        })
+        .unwrap();
+
+    // List entries come first in list order; everything else follows in normal order.
+    let values: Vec<Value> = (1..=5)
+        .map(|row| engine.get_cell_value("Sheet1", &format!("A{row}")))
+        .collect();
+    assert_eq!(
+        values,
+        ["jan", "Feb", "Mar", "Apple", "other"]
+            .map(|s| Value::Text(s.to_string()))
+            .to_vec()
+    );
+}
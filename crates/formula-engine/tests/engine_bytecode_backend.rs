@@ -1184,14 +1184,15 @@ fn array_literals_enable_bytecode_for_logical_functions() {
 fn bytecode_backend_supports_countif_array_literal_range_arg() {
     let mut engine = Engine::new();
     engine
-        .set_cell_formula("Sheet1", "A1", "=COUNTIF({1,,3}, \"<1\")")
+        .set_cell_formula("Sheet1", "A1", "=COUNTIF({1,,3}, \"<>1\")")
         .unwrap();
     assert_eq!(engine.bytecode_program_count(), 1);
 
     engine.recalculate_single_threaded();
 
-    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(1.0));
-    assert_engine_matches_ast(&engine, "=COUNTIF({1,,3}, \"<1\")", "A1");
+    // The blank element is `<>1` (blanks never equal a number).
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(2.0));
+    assert_engine_matches_ast(&engine, "=COUNTIF({1,,3}, \"<>1\")", "A1");
 }
 
 #[test]
@@ -2802,6 +2803,65 @@ fn bytecode_backend_sumproduct_flattens_mismatched_range_shapes_by_length() {
     assert_engine_matches_ast(&engine, "=SUMPRODUCT(A1:C1,A1:A3)", "D1");
 }
 
+#[test]
+fn bytecode_backend_sumproduct_accepts_one_and_three_or_more_factors() {
+    let mut engine = Engine::new();
+    for row in 1..=3 {
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), row as f64)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), (row * 10) as f64)
+            .unwrap();
+    }
+    engine.set_cell_value("Sheet1", "C1", 2.0).unwrap();
+    engine.set_cell_value("Sheet1", "C2", "x").unwrap();
+    engine.set_cell_value("Sheet1", "C3", true).unwrap();
+    engine
+        .set_cell_value("Sheet1", "D2", Value::Error(ErrorKind::Div0))
+        .unwrap();
+
+    let cases = [
+        ("E1", "=SUMPRODUCT(A1:A3)", Value::Number(6.0)),
+        // Text coerces to 0 and TRUE to 1: 1*10*2 + 2*20*0 + 3*30*1.
+        ("E2", "=SUMPRODUCT(A1:A3,B1:B3,C1:C3)", Value::Number(110.0)),
+        (
+            "E3",
+            "=SUMPRODUCT(A1:A3,2,{1;1;1},B1:B3)",
+            Value::Number(280.0),
+        ),
+        (
+            "E4",
+            "=SUMPRODUCT(A1:A3,B1:B2,C1:C3)",
+            Value::Error(ErrorKind::Value),
+        ),
+        (
+            "E5",
+            "=SUMPRODUCT(A1:A3,B1:B3,D1:D3)",
+            Value::Error(ErrorKind::Div0),
+        ),
+    ];
+    for (cell, formula, _) in &cases {
+        engine.set_cell_formula("Sheet1", cell, formula).unwrap();
+    }
+
+    assert_eq!(
+        engine.bytecode_program_count(),
+        cases.len(),
+        "expected every SUMPRODUCT arity to compile to bytecode"
+    );
+
+    engine.recalculate_single_threaded();
+    for (cell, formula, expected) in &cases {
+        assert_eq!(
+            &engine.get_cell_value("Sheet1", cell),
+            expected,
+            "{formula}"
+        );
+        assert_engine_matches_ast(&engine, formula, cell);
+    }
+}
+
 #[test]
 fn bytecode_backend_sumproduct_accepts_scalars_and_array_literals() {
     let mut engine = Engine::new();
@@ -9355,6 +9415,77 @@ fn bytecode_backend_matches_ast_for_conditional_aggregates_numeric_criteria() {
     }
 }
 
+#[test]
+fn bytecode_backend_matches_ast_for_indexed_ifs_over_large_ranges() {
+    // Large enough for the per-level criteria index to kick in; every formula in column E shares
+    // the same criteria ranges so later calls are answered from the cached index.
+    const ROWS: usize = 2_000;
+    let mut engine = Engine::new();
+    for i in 0..ROWS {
+        let row = i + 1;
+        let key = match i % 7 {
+            0 => Value::Number((i % 5) as f64),
+            1 => Value::Text(((i % 5) as f64).to_string()),
+            2 => Value::Bool(i % 2 == 0),
+            3 => Value::Text(if i % 3 == 0 { "apple" } else { "APPLE" }.to_string()),
+            4 => Value::Text("pear".to_string()),
+            5 => Value::Blank,
+            _ => Value::Error(ErrorKind::NA),
+        };
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), key)
+            .unwrap();
+        let amount = if i % 97 == 96 {
+            Value::Error(ErrorKind::Div0)
+        } else {
+            Value::Number(i as f64)
+        };
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), amount)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("C{row}"), (i % 3) as f64)
+            .unwrap();
+    }
+
+    let formulas = [
+        r#"=SUMIFS(B1:B2000,A1:A2000,3)"#,
+        r#"=SUMIFS(B1:B2000,A1:A2000,"3",C1:C2000,1)"#,
+        r#"=SUMIFS(B1:B2000,A1:A2000,TRUE)"#,
+        r#"=SUMIFS(B1:B2000,A1:A2000,"Apple",C1:C2000,">0")"#,
+        r#"=SUMIFS(B1:B2000,A1:A2000,"missing")"#,
+        r#"=COUNTIFS(A1:A2000,"pear",C1:C2000,2)"#,
+        r#"=COUNTIFS(A1:A2000,0)"#,
+        r#"=COUNTIFS(A1:A2000,"TRUE")"#,
+        r#"=AVERAGEIFS(B1:B2000,A1:A2000,"apple")"#,
+        r#"=AVERAGEIFS(B1:B2000,C1:C2000,2,A1:A2000,4)"#,
+        r#"=AVERAGEIFS(B1:B2000,A1:A2000,"missing")"#,
+        r#"=MINIFS(B1:B2000,A1:A2000,"apple",C1:C2000,">0")"#,
+        r#"=MINIFS(B1:B2000,A1:A2000,"missing")"#,
+        r#"=MAXIFS(B1:B2000,A1:A2000,"pear",C1:C2000,2)"#,
+        r#"=MAXIFS(B1:B2000,A1:A2000,3)"#,
+    ];
+    for (i, formula) in formulas.iter().enumerate() {
+        engine
+            .set_cell_formula("Sheet1", &format!("E{}", i + 1), formula)
+            .unwrap();
+    }
+    assert_eq!(engine.bytecode_program_count(), formulas.len());
+
+    engine.recalculate_single_threaded();
+    for (i, formula) in formulas.iter().enumerate() {
+        assert_engine_matches_ast(&engine, formula, &format!("E{}", i + 1));
+    }
+
+    // Indexes must not outlive the recalc that built them.
+    engine.set_cell_value("Sheet1", "A4", 3.0).unwrap();
+    engine.set_cell_value("Sheet1", "A5", "apple").unwrap();
+    engine.recalculate();
+    for (i, formula) in formulas.iter().enumerate() {
+        assert_engine_matches_ast(&engine, formula, &format!("E{}", i + 1));
+    }
+}
+
 #[test]
 fn bytecode_backend_matches_ast_for_conditional_aggregates_over_array_literals() {
     let mut engine = Engine::new();
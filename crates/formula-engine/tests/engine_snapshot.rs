@@ -0,0 +1,182 @@
+use formula_engine::date::DateCompatibility;
+use formula_engine::locale::{TextCollation, ValueLocaleConfig, DE_DE};
+use formula_engine::{
+    Engine, EngineSnapshotError, NameDefinition, NameScope, Value, ENGINE_SNAPSHOT_VERSION,
+};
+
+fn build_workbook() -> Engine {
+    let mut engine = Engine::new();
+    engine.ensure_sheet("Inputs");
+    engine.ensure_sheet("Calc");
+    for row in 1..=20 {
+        engine
+            .set_cell_value("Inputs", &format!("A{row}"), row as f64)
+            .unwrap();
+        engine
+            .set_cell_value(
+                "Inputs",
+                &format!("B{row}"),
+                Value::Text(format!("item {row}")),
+            )
+            .unwrap();
+    }
+    engine
+        .define_name(
+            "Rate",
+            NameScope::Workbook,
+            NameDefinition::Constant(Value::Number(0.25)),
+        )
+        .unwrap();
+    engine
+        .define_name(
+            "Amounts",
+            NameScope::Workbook,
+            NameDefinition::Reference("Inputs!$A$1:$A$20".to_string()),
+        )
+        .unwrap();
+
+    engine
+        .set_cell_formula("Calc", "A1", "=SUM(Amounts)")
+        .unwrap();
+    engine.set_cell_formula("Calc", "A2", "=A1*Rate").unwrap();
+    engine.set_cell_formula("Calc", "A3", "=Inputs!B8").unwrap();
+    engine
+        .set_cell_formula("Calc", "A4", "=IF(A2>50,\"big\",\"small\")")
+        .unwrap();
+    engine.set_cell_formula("Calc", "A5", "=1/0").unwrap();
+    engine
+        .set_cell_formula("Calc", "C1", "=SEQUENCE(3,2)")
+        .unwrap();
+    engine.set_cell_formula("Calc", "E1", "=SUM(C1#)").unwrap();
+    for row in 1..=20 {
+        engine
+            .set_cell_formula("Calc", &format!("F{row}"), &format!("=Inputs!A{row}*2+A2"))
+            .unwrap();
+    }
+    engine.recalculate_single_threaded();
+    engine
+}
+
+fn assert_same_values(a: &Engine, b: &Engine) {
+    for sheet in ["Inputs", "Calc"] {
+        for row in 1..=22 {
+            for col in ["A", "B", "C", "D", "E", "F"] {
+                let addr = format!("{col}{row}");
+                assert_eq!(
+                    a.get_cell_value(sheet, &addr),
+                    b.get_cell_value(sheet, &addr),
+                    "{sheet}!{addr}"
+                );
+                assert_eq!(
+                    a.get_cell_formula(sheet, &addr),
+                    b.get_cell_formula(sheet, &addr),
+                    "{sheet}!{addr}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn binary_snapshot_round_trip_restores_values_without_recalc() {
+    let original = build_workbook();
+    let bytes = original.to_binary().unwrap();
+    let mut restored = Engine::from_binary(&bytes).unwrap();
+
+    assert_same_values(&original, &restored);
+    assert_eq!(restored.get_cell_value("Calc", "D3"), Value::Number(6.0));
+    assert_eq!(restored.get_cell_value("Calc", "E1"), Value::Number(21.0));
+    // Cached values are restored as-is, so nothing is dirty after reopening.
+    assert!(restored
+        .recalculate_with_value_changes_single_threaded()
+        .is_empty());
+}
+
+#[test]
+fn binary_snapshot_round_trip_recalcs_identically_after_edits() {
+    let mut original = build_workbook();
+    let mut restored = Engine::from_binary(&original.to_binary().unwrap()).unwrap();
+
+    for engine in [&mut original, &mut restored] {
+        engine.set_cell_value("Inputs", "A7", 100.0).unwrap();
+        engine
+            .set_cell_value("Inputs", "B8", Value::Text("changed".into()))
+            .unwrap();
+        engine
+            .set_cell_formula("Calc", "C1", "=SEQUENCE(4,2)")
+            .unwrap();
+    }
+    let expected = original.recalculate_with_value_changes_single_threaded();
+    let actual = restored.recalculate_with_value_changes_single_threaded();
+    assert!(!expected.is_empty());
+    assert_eq!(actual, expected);
+    assert_same_values(&original, &restored);
+    assert_eq!(
+        restored.get_cell_value("Calc", "A3"),
+        Value::Text("changed".into())
+    );
+}
+
+#[test]
+fn binary_snapshot_rejects_foreign_and_future_data() {
+    let bytes = build_workbook().to_binary().unwrap();
+
+    assert!(matches!(
+        Engine::from_binary(b"not a snapshot"),
+        Err(EngineSnapshotError::InvalidMagic)
+    ));
+
+    let mut future = bytes.clone();
+    future[8..12].copy_from_slice(&(ENGINE_SNAPSHOT_VERSION + 1).to_le_bytes());
+    assert!(matches!(
+        Engine::from_binary(&future),
+        Err(EngineSnapshotError::UnsupportedVersion(v)) if v == ENGINE_SNAPSHOT_VERSION + 1
+    ));
+
+    assert!(matches!(
+        Engine::from_binary(&bytes[..bytes.len() / 2]),
+        Err(EngineSnapshotError::Decode(_))
+    ));
+}
+
+#[test]
+fn binary_snapshot_round_trip_keeps_date_compatibility() {
+    let mut original = build_workbook();
+    original.set_date_compatibility(DateCompatibility::Proleptic);
+    original
+        .set_cell_formula("Calc", "F1", "=DATE(1900,1,1)")
+        .unwrap();
+    original.recalculate_single_threaded();
+
+    let bytes = original.to_binary().unwrap();
+    assert_eq!(bytes[8..12], ENGINE_SNAPSHOT_VERSION.to_le_bytes());
+    let restored = Engine::from_binary(&bytes).unwrap();
+    assert_eq!(restored.date_compatibility(), DateCompatibility::Proleptic);
+    assert_eq!(restored.get_cell_value("Calc", "F1"), Value::Number(2.0));
+}
+
+#[test]
+fn binary_snapshot_rejects_versions_without_the_proleptic_date_system() {
+    let mut original = build_workbook();
+    original.set_date_compatibility(DateCompatibility::Proleptic);
+    let mut bytes = original.to_binary().unwrap();
+
+    // Version 3 payloads predate the proleptic date-system tag; they are rejected by version
+    // rather than failing halfway through decoding.
+    bytes[8..12].copy_from_slice(&3u32.to_le_bytes());
+    assert!(matches!(
+        Engine::from_binary(&bytes),
+        Err(EngineSnapshotError::UnsupportedVersion(3))
+    ));
+}
+
+#[test]
+fn binary_snapshot_round_trip_keeps_value_and_display_locales() {
+    let mut original = build_workbook();
+    original.set_value_locale(ValueLocaleConfig::de_de().with_collation(TextCollation::Ordinal));
+    original.set_display_locale(&DE_DE);
+
+    let restored = Engine::from_binary(&original.to_binary().unwrap()).unwrap();
+    assert_eq!(restored.value_locale(), original.value_locale());
+    assert_eq!(restored.display_locale().id, "de-DE");
+}
@@ -0,0 +1,84 @@
+use formula_engine::locale::{DE_DE, EN_US, FR_FR};
+use formula_engine::{Engine, ErrorKind, Value};
+
+fn build_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_cell_formula("Sheet1", "A1", "=#REF!").unwrap();
+    engine.set_cell_formula("Sheet1", "A2", "=NA()").unwrap();
+    engine.set_cell_formula("Sheet1", "A3", "=1/0").unwrap();
+    engine.set_cell_formula("Sheet1", "A4", "=\"x\"+1").unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A5", "=SQRT(-1)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "B1", "=TEXT(NA(),\"0.00\")")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "B2", "=TEXT(A1,\"@\")")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "B3", "=TEXT(1.5,\"0.00\")")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C1", "=ISERROR(A1)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+    engine
+}
+
+fn display(engine: &Engine, addr: &str) -> String {
+    engine.cell_display_text("Sheet1", addr).unwrap()
+}
+
+#[test]
+fn display_text_uses_canonical_error_codes_by_default() {
+    let engine = build_engine();
+    assert_eq!(engine.display_locale().id, EN_US.id);
+    assert_eq!(display(&engine, "A1"), "#REF!");
+    assert_eq!(display(&engine, "A2"), "#N/A");
+    assert_eq!(display(&engine, "B1"), "#N/A");
+}
+
+#[test]
+fn display_text_renders_german_error_names() {
+    let mut engine = build_engine();
+    engine.set_display_locale(&DE_DE);
+
+    assert_eq!(display(&engine, "A1"), "#BEZUG!");
+    assert_eq!(display(&engine, "A2"), "#NV");
+    assert_eq!(display(&engine, "A3"), "#DIV/0!");
+    assert_eq!(display(&engine, "A4"), "#WERT!");
+    assert_eq!(display(&engine, "A5"), "#ZAHL!");
+    // TEXT propagates the canonical error value; only its rendering is localized.
+    assert_eq!(display(&engine, "B1"), "#NV");
+    assert_eq!(display(&engine, "B2"), "#BEZUG!");
+    assert_eq!(display(&engine, "B3"), "1.50");
+
+    // Values and formulas keep the canonical codes.
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Error(ErrorKind::Ref)
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "B1"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Bool(true));
+    assert_eq!(engine.get_cell_formula("Sheet1", "A1"), Some("=#REF!"));
+}
+
+#[test]
+fn display_text_renders_french_error_names() {
+    let mut engine = build_engine();
+    engine.set_display_locale(&FR_FR);
+
+    assert_eq!(display(&engine, "A1"), "#REF!");
+    assert_eq!(display(&engine, "A2"), "#N/A");
+    assert_eq!(display(&engine, "A4"), "#VALEUR!");
+    assert_eq!(display(&engine, "A5"), "#NOMBRE!");
+    assert_eq!(display(&engine, "B2"), "#REF!");
+
+    // Switching back restores the canonical spellings.
+    engine.set_display_locale(&EN_US);
+    assert_eq!(display(&engine, "A4"), "#VALUE!");
+}
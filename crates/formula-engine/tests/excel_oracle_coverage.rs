@@ -186,7 +186,10 @@ fn excel_oracle_corpus_covers_nonvolatile_function_catalog() {
     // If a deterministic function cannot yet be represented in the oracle harness (e.g. it
     // depends on workbook-level state not modeled in `cases.json`), add it to this allow-list
     // with a justification comment. Keep this list small.
-    const EXCEPTIONS: &[&str] = &[];
+    const EXCEPTIONS: &[&str] = &[
+        // Needs linked data type (entity/record) inputs, which `cases.json` cannot express.
+        "FIELDVALUE",
+    ];
 
     for &exception in EXCEPTIONS {
         assert!(
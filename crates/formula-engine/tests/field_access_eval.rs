@@ -98,3 +98,59 @@ fn field_access_applies_elementwise_over_arrays() {
     assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(1.0));
     assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(2.0));
 }
+
+#[test]
+fn fieldvalue_returns_named_field_or_field_error() {
+    let mut sheet = TestSheet::new();
+
+    sheet.set(
+        "A1",
+        Value::Entity(EntityValue::with_properties("Product", [("Price", 12.5)])),
+    );
+    assert_eq!(
+        sheet.eval("=FIELDVALUE(A1, \"price\")"),
+        Value::Number(12.5)
+    );
+    assert_eq!(
+        sheet.eval("=FIELDVALUE(A1, \"Missing\")"),
+        Value::Error(ErrorKind::Field)
+    );
+    assert_eq!(
+        sheet.eval("=FIELDVALUE(1, \"Price\")"),
+        Value::Error(ErrorKind::Value)
+    );
+}
+
+#[test]
+fn fieldvalue_spills_over_an_array_of_field_names() {
+    let mut engine = Engine::new();
+
+    engine
+        .set_cell_value(
+            "Sheet1",
+            "A1",
+            Value::Record(RecordValue::with_fields_iter(
+                "City",
+                [("Population", 8_000_000.0), ("Area", 783.8)],
+            )),
+        )
+        .unwrap();
+    engine
+        .set_cell_formula(
+            "Sheet1",
+            "B1",
+            "=FIELDVALUE(A1, {\"Population\",\"Area\",\"Mayor\"})",
+        )
+        .unwrap();
+    engine.recalculate();
+
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "B1"),
+        Value::Number(8_000_000.0)
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(783.8));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "D1"),
+        Value::Error(ErrorKind::Field)
+    );
+}
@@ -0,0 +1,67 @@
+use formula_engine::{Engine, Value};
+
+fn eval(engine: &mut Engine, formula: &str) -> Value {
+    engine.set_cell_formula("Sheet1", "Z1", formula).unwrap();
+    engine.recalculate_single_threaded();
+    engine.get_cell_value("Sheet1", "Z1")
+}
+
+#[test]
+fn comparisons_treat_values_equal_to_15_significant_digits_as_equal() {
+    let mut engine = Engine::new();
+    engine.set_cell_formula("Sheet1", "A1", "=0.1+0.2").unwrap();
+    engine.recalculate_single_threaded();
+
+    // The stored value keeps the binary residue...
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Number(0.1 + 0.2)
+    );
+    assert_ne!(0.1 + 0.2, 0.3);
+
+    // ...but comparison operators only look at 15 significant digits.
+    for (formula, expected) in [
+        ("=0.1+0.2=0.3", true),
+        ("=A1=0.3", true),
+        ("=A1<>0.3", false),
+        ("=A1>0.3", false),
+        ("=A1<=0.3", true),
+        ("=1.1*3=3.3", true),
+        ("=0.3=0.1+0.2", true),
+        ("=1-0.9=0.1", true),
+        // Differences that are significant at 15 digits still compare unequal.
+        ("=1.00000000000001=1", false),
+        ("=1.00000000000001>1", true),
+        // A residue compared against zero is not rounded away.
+        ("=0.1+0.2-0.3=0", false),
+        ("=0.1+0.2-0.3>0", true),
+    ] {
+        assert_eq!(
+            eval(&mut engine, formula),
+            Value::Bool(expected),
+            "{formula}"
+        );
+    }
+}
+
+#[test]
+fn displayed_text_rounds_to_15_significant_digits() {
+    let mut engine = Engine::new();
+    for (formula, expected) in [
+        ("=(0.1+0.2)&\"\"", "0.3"),
+        ("=(1.1*3)&\"\"", "3.3"),
+        ("=TEXT(0.1+0.2,\"General\")", "0.3"),
+        (
+            "=TEXT(1/3,\"0.00000000000000000000\")",
+            "0.33333333333333300000",
+        ),
+        ("=(2/3)&\"\"", "0.666666666666667"),
+        ("=(0.1+0.2-0.3)&\"\"", "5.55111512312578E-17"),
+    ] {
+        assert_eq!(
+            eval(&mut engine, formula),
+            Value::Text(expected.to_string()),
+            "{formula}"
+        );
+    }
+}
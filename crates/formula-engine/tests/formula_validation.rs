@@ -0,0 +1,341 @@
+use formula_engine::locale;
+use formula_engine::{
+    Engine, FormulaDiagnostic, FormulaLintKind, LintSeverity, NameDefinition, NameScope, Span,
+    Value,
+};
+use formula_model::{Range, Table, TableColumn};
+
+fn table_column(id: u32, name: &str) -> TableColumn {
+    TableColumn {
+        id,
+        name: name.to_string(),
+        formula: None,
+        totals_formula: None,
+    }
+}
+
+fn build_workbook() -> Engine {
+    let mut engine = Engine::new();
+    engine.ensure_sheet("Sheet1");
+    engine.ensure_sheet("Inputs");
+    engine
+        .define_name(
+            "Rate",
+            NameScope::Workbook,
+            NameDefinition::Constant(Value::Number(0.25)),
+        )
+        .unwrap();
+    engine
+        .define_name(
+            "LocalTotal",
+            NameScope::Sheet("Inputs"),
+            NameDefinition::Reference("Inputs!$A$1:$A$5".to_string()),
+        )
+        .unwrap();
+    engine.set_sheet_tables(
+        "Inputs",
+        vec![Table {
+            id: 1,
+            name: "Sales".into(),
+            display_name: "Sales".into(),
+            range: Range::from_a1("C1:D5").unwrap(),
+            header_row_count: 1,
+            totals_row_count: 0,
+            columns: vec![table_column(1, "Region"), table_column(2, "Amount")],
+            style: None,
+            auto_filter: None,
+            relationship_id: None,
+            part_path: None,
+        }],
+    );
+    engine
+}
+
+fn diagnostic(message: &str, start: usize, end: usize) -> FormulaDiagnostic {
+    FormulaDiagnostic {
+        message: message.to_string(),
+        span: Span::new(start, end),
+    }
+}
+
+#[test]
+fn validate_formula_accepts_resolvable_formulas() {
+    let engine = build_workbook();
+    for formula in [
+        "=SUM(A1:A3)*Rate",
+        "=LET(x, 2, y, x*Rate, x+y)",
+        "=LAMBDA(n, n*2)(Rate)",
+        "=_xlfn.XLOOKUP(1, A1:A3, B1:B3)",
+        "=SUM(Sales[Amount])",
+        "=COUNTA(Sales[[#Headers],[Region]:[Amount]])",
+        "=Inputs!A1+Sheet1!B2",
+    ] {
+        assert_eq!(
+            engine.validate_formula("Sheet1", formula),
+            Ok(()),
+            "{formula}"
+        );
+    }
+
+    // Sheet-scoped names only resolve on their own sheet (or when qualified).
+    assert_eq!(
+        engine.validate_formula("Inputs", "=SUM(LocalTotal)"),
+        Ok(())
+    );
+    assert_eq!(
+        engine.validate_formula("Sheet1", "=SUM(Inputs!LocalTotal)"),
+        Ok(())
+    );
+    assert_eq!(
+        engine.validate_formula("Sheet1", "=SUM(LocalTotal)"),
+        Err(diagnostic("Unknown name: LocalTotal", 5, 15))
+    );
+}
+
+#[test]
+fn validate_formula_reports_first_unresolved_identifier_with_span() {
+    let engine = build_workbook();
+
+    assert_eq!(
+        engine.validate_formula("Sheet1", "=SUMM(A1)"),
+        Err(diagnostic("Unknown function: SUMM", 1, 5))
+    );
+    assert_eq!(
+        engine.validate_formula("Sheet1", "=A1*Rte+NoSuchName"),
+        Err(diagnostic("Unknown name: Rte", 4, 7))
+    );
+    // LET locals go out of scope after the LET call.
+    assert_eq!(
+        engine.validate_formula("Sheet1", "=LET(x, 1, x)+x"),
+        Err(diagnostic("Unknown name: x", 14, 15))
+    );
+    assert_eq!(
+        engine.validate_formula("Sheet1", "=SUM(Sale[Amount])"),
+        Err(diagnostic("Unknown table: Sale", 5, 9))
+    );
+    assert_eq!(
+        engine
+            .validate_formula("Sheet1", "=SUM(Sales[Amt])")
+            .unwrap_err()
+            .message,
+        "Unknown column in table Sales: Amt"
+    );
+
+    let err = engine.validate_formula("Sheet1", "=SUM(1,").unwrap_err();
+    assert!(!err.message.is_empty());
+    assert!(err.span.end <= "=SUM(1,".len());
+}
+
+#[test]
+fn validate_formula_does_not_mutate_the_workbook() {
+    let engine = build_workbook();
+    let sheets = engine.sheet_names_in_order();
+
+    assert_eq!(engine.validate_formula("Missing", "=SUM(1,2)"), Ok(()));
+    assert_eq!(
+        engine
+            .validate_formula("Missing", "=SUMM(1)")
+            .unwrap_err()
+            .message,
+        "Unknown function: SUMM"
+    );
+    assert_eq!(engine.sheet_names_in_order(), sheets);
+    assert_eq!(engine.get_cell_formula("Sheet1", "A1"), None);
+}
+
+#[test]
+fn validate_formula_localized_reports_spans_in_localized_text() {
+    let engine = build_workbook();
+
+    assert_eq!(
+        engine.validate_formula_localized("Sheet1", "=SUMME(A1;B1)*Rate", &locale::DE_DE),
+        Ok(())
+    );
+    assert_eq!(
+        engine.validate_formula_localized("Sheet1", "=SUMME(1,5;Rat)", &locale::DE_DE),
+        Err(diagnostic("Unknown name: Rat", 11, 14))
+    );
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn analyze_formula_lists_ranges_names_and_tables() {
+    let engine = build_workbook();
+    let refs = engine
+        .analyze_formula(
+            "Sheet1",
+            "=SUM($A$1:B2, Inputs!C3, A:A, 2:2, A1#, $A$1:B2) + Rate + SUM(Sales[Amount]) + COUNTA(Sales)",
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        refs.ranges,
+        strings(&["A1:B2", "Inputs!C3", "A:A", "2:2", "A1#"])
+    );
+    assert_eq!(refs.names, strings(&["Rate"]));
+    assert_eq!(refs.tables, strings(&["Sales"]));
+
+    // Sheet-scoped names resolve on their own sheet; unknown names are still reported verbatim.
+    let refs = engine
+        .analyze_formula("Inputs", "=LocalTotal + Missing", None)
+        .unwrap();
+    assert_eq!(refs.names, strings(&["LocalTotal", "Missing"]));
+}
+
+#[test]
+fn analyze_formula_skips_let_and_lambda_locals() {
+    let mut engine = build_workbook();
+    engine
+        .define_name(
+            "Double",
+            NameScope::Workbook,
+            NameDefinition::Formula("=LAMBDA(v, v * 2)".to_string()),
+        )
+        .unwrap();
+    let refs = engine
+        .analyze_formula(
+            "Sheet1",
+            "=LET(x, A1, y, x + Rate, Double(y) + LAMBDA(z, z + B1)(x))",
+            None,
+        )
+        .unwrap();
+    assert_eq!(refs.ranges, strings(&["A1", "B1"]));
+    assert_eq!(refs.names, strings(&["Rate", "Double"]));
+    assert!(refs.tables.is_empty());
+}
+
+#[test]
+fn analyze_formula_resolves_this_row_refs_against_the_origin() {
+    let engine = build_workbook();
+    let origin = formula_engine::eval::CellAddr { row: 2, col: 3 };
+    let refs = engine
+        .analyze_formula("Inputs", "=[@Amount] * 2", Some(origin))
+        .unwrap();
+    assert_eq!(refs.tables, strings(&["Sales"]));
+
+    let refs = engine
+        .analyze_formula("Inputs", "=[@Amount] * 2", None)
+        .unwrap();
+    assert!(refs.tables.is_empty());
+}
+
+#[test]
+fn analyze_formula_reports_parse_errors_without_mutating_the_workbook() {
+    let engine = build_workbook();
+    let err = engine
+        .analyze_formula("Sheet1", "=SUM(A1", None)
+        .unwrap_err();
+    assert!(!err.message.is_empty());
+    assert_eq!(engine.get_cell_formula("Sheet1", "A1"), None);
+}
+
+#[test]
+fn analyze_formula_localized_renders_canonical_references() {
+    let engine = build_workbook();
+    let refs = engine
+        .analyze_formula_localized("Sheet1", "=SUMME(A1;B2:C3)*Rate", None, &locale::DE_DE)
+        .unwrap();
+    assert_eq!(refs.ranges, strings(&["A1", "B2:C3"]));
+    assert_eq!(refs.names, strings(&["Rate"]));
+}
+
+fn lint_summary(
+    lints: &[formula_engine::FormulaLint],
+    formula: &str,
+) -> Vec<(FormulaLintKind, String)> {
+    lints
+        .iter()
+        .map(|lint| {
+            (
+                lint.kind,
+                formula[lint.span.start..lint.span.end].to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn lint_formula_flags_empty_ranges_and_blank_arithmetic_operands() {
+    let mut engine = build_workbook();
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+
+    let formula = "=SUM(Z1:Z9) + (B1) * 2 + SUM(A1:A3) + A1 - Inputs!B7";
+    let lints = engine.lint_formula("Sheet1", formula, None).unwrap();
+    assert_eq!(
+        lint_summary(&lints, formula),
+        vec![
+            (FormulaLintKind::EmptyRange, "Z1:Z9".to_string()),
+            (FormulaLintKind::BlankCellInArithmetic, "B1".to_string()),
+            (
+                FormulaLintKind::BlankCellInArithmetic,
+                "Inputs!B7".to_string()
+            ),
+        ]
+    );
+    assert!(lints
+        .iter()
+        .all(|lint| lint.severity == LintSeverity::Warning));
+    assert_eq!(lints[1].message, "B1 is blank and is treated as 0");
+
+    // Blank cells are fine outside arithmetic.
+    let lints = engine.lint_formula("Sheet1", "=B1&\"x\"", None).unwrap();
+    assert!(lints.is_empty(), "{lints:?}");
+}
+
+#[test]
+fn lint_formula_reports_volatile_functions_as_info() {
+    let mut engine = build_workbook();
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+
+    let formula = "=NOW() + _xlfn.RANDARRAY(2) * A1 + SUM(A1)";
+    let lints = engine.lint_formula("Sheet1", formula, None).unwrap();
+    assert_eq!(
+        lint_summary(&lints, formula),
+        vec![
+            (FormulaLintKind::VolatileFunction, "NOW".to_string()),
+            (
+                FormulaLintKind::VolatileFunction,
+                "_xlfn.RANDARRAY".to_string()
+            ),
+        ]
+    );
+    assert!(lints.iter().all(|lint| lint.severity == LintSeverity::Info));
+}
+
+#[test]
+fn lint_formula_flags_formulas_inconsistent_with_their_neighbors() {
+    let mut engine = build_workbook();
+    for (addr, value) in [("A1", 1.0), ("B1", 2.0), ("C1", 3.0)] {
+        engine.set_cell_value("Sheet1", addr, value).unwrap();
+    }
+    engine.set_cell_formula("Sheet1", "A2", "=A1*2").unwrap();
+    engine.set_cell_formula("Sheet1", "C2", "=C1*2").unwrap();
+    let origin = Some(formula_engine::eval::CellAddr { row: 1, col: 1 });
+
+    let formula = "=B1*3";
+    let lints = engine.lint_formula("Sheet1", formula, origin).unwrap();
+    assert_eq!(
+        lint_summary(&lints, formula),
+        vec![(FormulaLintKind::InconsistentFormula, "=B1*3".to_string())]
+    );
+
+    assert!(engine
+        .lint_formula("Sheet1", "=B1*2", origin)
+        .unwrap()
+        .is_empty());
+    // Without an origin there are no neighbors to compare against.
+    assert!(engine
+        .lint_formula("Sheet1", formula, None)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn lint_formula_reports_parse_errors() {
+    let engine = build_workbook();
+    let err = engine.lint_formula("Sheet1", "=SUM(A1", None).unwrap_err();
+    assert!(!err.message.is_empty());
+}
@@ -1,4 +1,4 @@
-use formula_engine::Value;
+use formula_engine::{ErrorKind, Value};
 
 use super::harness::{assert_number, TestSheet};
 
@@ -28,3 +28,46 @@ fn text_dates_coerce_to_serial_numbers() {
     let mut sheet = TestSheet::new();
     assert_number(&sheet.eval("=YEAR(\"2020-01-01\")"), 2020.0);
 }
+
+#[test]
+fn operators_coerce_numeric_text_from_literals_and_references() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", "5");
+    sheet.set("A2", "abc");
+    assert_number(&sheet.eval("=\"5\"+1"), 6.0);
+    assert_number(&sheet.eval("=A1+1"), 6.0);
+    assert_number(&sheet.eval("=A1*2"), 10.0);
+    assert_number(&sheet.eval("=-A1"), -5.0);
+    assert_eq!(sheet.eval("=A2+1"), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=\"abc\"+1"), Value::Error(ErrorKind::Value));
+}
+
+#[test]
+fn sum_coerces_direct_text_arguments_but_ignores_text_in_references() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", "5");
+    sheet.set("A2", 1.0);
+    sheet.set("A3", "abc");
+    assert_number(&sheet.eval("=SUM(\"5\",1)"), 6.0);
+    assert_eq!(
+        sheet.eval("=SUM(\"abc\",1)"),
+        Value::Error(ErrorKind::Value)
+    );
+    assert_number(&sheet.eval("=SUM(A1,1)"), 1.0);
+    assert_number(&sheet.eval("=SUM(A1:A3)"), 1.0);
+    assert_number(&sheet.eval("=SUM({\"5\",1})"), 1.0);
+}
+
+#[test]
+fn count_counts_direct_numeric_text_but_not_text_in_references() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", "5");
+    sheet.set("A2", 1.0);
+    sheet.set("A3", "abc");
+    assert_number(&sheet.eval("=COUNT(\"5\",1)"), 2.0);
+    assert_number(&sheet.eval("=COUNT(\"abc\",1)"), 1.0);
+    assert_number(&sheet.eval("=COUNT(A1,1)"), 1.0);
+    assert_number(&sheet.eval("=COUNT(A1:A3)"), 1.0);
+    assert_number(&sheet.eval("=COUNT({\"5\",1})"), 1.0);
+    assert_number(&sheet.eval("=COUNTA(A1:A3)"), 3.0);
+}
@@ -94,7 +94,7 @@ fn criteria_is_case_insensitive_unicode() {
 fn criteria_parses_bool_keywords() {
     let c = Criteria::parse(&Value::from("TRUE")).unwrap();
     assert!(c.matches(&Value::Bool(true)));
-    assert!(c.matches(&Value::Number(1.0)));
+    assert!(!c.matches(&Value::Number(1.0)));
     assert!(!c.matches(&Value::Number(2.0)));
     assert!(!c.matches(&Value::Blank));
     // Literal text "TRUE" does not match the boolean criteria.
@@ -137,17 +137,23 @@ fn criteria_numeric_coercion_matches_text_and_blanks() {
     assert!(c.matches(&Value::from("1")));
     assert!(c.matches(&Value::from("  1  ")));
 
-    // Criteria functions treat blank / empty string as zero for numeric comparisons.
+    // Blanks and empty strings are not zero for numeric comparisons; they only satisfy `<>`.
     let c = Criteria::parse(&Value::from("0")).unwrap();
+    assert!(!c.matches(&Value::Blank));
+    assert!(!c.matches(&Value::from("")));
+    assert!(c.matches(&Value::Number(0.0)));
+
+    let c = Criteria::parse(&Value::from("<>0")).unwrap();
     assert!(c.matches(&Value::Blank));
     assert!(c.matches(&Value::from("")));
-    assert!(c.matches(&Value::Number(0.0)));
+    assert!(!c.matches(&Value::Number(0.0)));
 
+    // Numeric text only compares equal; it is never ordered against a number.
     let c = Criteria::parse(&Value::from(">0")).unwrap();
     assert!(!c.matches(&Value::Blank));
     assert!(!c.matches(&Value::from("")));
     assert!(c.matches(&Value::Number(0.1)));
-    assert!(c.matches(&Value::from("2")));
+    assert!(!c.matches(&Value::from("2")));
 }
 
 #[test]
@@ -8,8 +8,11 @@ use formula_engine::ExcelError;
 #[test]
 fn time_builds_fractional_days() {
     assert!((date_time::time(1, 30, 0).unwrap() - 0.0625).abs() < 1.0e-12);
-    assert_eq!(date_time::time(24, 0, 0).unwrap(), 1.0);
+    assert_eq!(date_time::time(24, 0, 0).unwrap(), 0.0);
+    assert_eq!(date_time::time(27, 0, 0).unwrap(), 0.125);
+    assert_eq!(date_time::time(1, -30, 0).unwrap(), 1.0 / 48.0);
     assert_eq!(date_time::time(-1, 0, 0).unwrap_err(), ExcelError::Num);
+    assert_eq!(date_time::time(32768, 0, 0).unwrap_err(), ExcelError::Num);
 }
 
 #[test]
@@ -510,6 +513,48 @@ fn datedif_matches_excel_units() {
     );
 }
 
+#[test]
+fn datedif_matches_excel_at_month_ends_and_leap_days() {
+    let system = ExcelDateSystem::EXCEL_1900;
+    let date = |y, m, d| ymd_to_serial(ExcelDate::new(y, m, d), system).unwrap();
+
+    // (start, end, [Y, M, D, YM, MD, YD])
+    let cases = [
+        // Jan 31 -> Mar 1 crosses a 28-day February: "MD" goes negative like Excel.
+        (date(2015, 1, 31), date(2015, 3, 1), [0, 1, 29, 1, -2, 29]),
+        // Same across a 29-day February.
+        (
+            date(2019, 3, 31),
+            date(2020, 3, 1),
+            [0, 11, 336, 11, -1, 336],
+        ),
+        // A leap-day start is not a full year until Mar 1 of the next common year.
+        (
+            date(2016, 2, 29),
+            date(2017, 2, 28),
+            [0, 11, 365, 11, 30, 365],
+        ),
+        (date(2016, 2, 29), date(2017, 3, 1), [1, 12, 366, 0, 0, 0]),
+        (date(2016, 2, 29), date(2020, 2, 29), [4, 48, 1461, 0, 0, 0]),
+        // Ends on a leap day.
+        (
+            date(2019, 1, 30),
+            date(2020, 2, 29),
+            [1, 12, 395, 0, 30, 30],
+        ),
+    ];
+
+    for (start, end, expected) in cases {
+        for (unit, expected) in ["Y", "M", "D", "YM", "MD", "YD"].into_iter().zip(expected) {
+            assert_eq!(
+                date_time::datedif(start, end, unit, system).unwrap(),
+                expected,
+                "DATEDIF({start}, {end}, {unit:?})"
+            );
+        }
+    }
+}
+
 #[test]
 fn weekday_matches_excel_return_types() {
     let system = ExcelDateSystem::EXCEL_1900;
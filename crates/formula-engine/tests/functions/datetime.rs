@@ -1,4 +1,4 @@
-use formula_engine::date::ExcelDateSystem;
+use formula_engine::date::{DateCompatibility, ExcelDateSystem};
 use formula_engine::locale::ValueLocaleConfig;
 use formula_engine::{ErrorKind, Value};
 
@@ -28,6 +28,92 @@ fn date_day_overflow_and_1900_leap_bug() {
     assert_number(&sheet.eval("=DAY(DATE(1900,3,0))"), 29.0);
 }
 
+#[test]
+fn date_rolls_over_in_both_directions_until_the_serial_leaves_range() {
+    let mut sheet = TestSheet::new();
+    // Month overflow and underflow carry into the year.
+    assert_eq!(
+        sheet.eval("=DATE(2024,13,1)"),
+        sheet.eval("=DATE(2025,1,1)")
+    );
+    assert_eq!(
+        sheet.eval("=DATE(2024,-1,1)"),
+        sheet.eval("=DATE(2023,11,1)")
+    );
+    // Day overflow carries into the month; zero and negative days count back.
+    assert_eq!(
+        sheet.eval("=DATE(2024,2,30)"),
+        sheet.eval("=DATE(2024,3,1)")
+    );
+    assert_eq!(
+        sheet.eval("=DATE(2024,1,0)"),
+        sheet.eval("=DATE(2023,12,31)")
+    );
+    assert_eq!(
+        sheet.eval("=DATE(2024,3,-1)"),
+        sheet.eval("=DATE(2024,2,28)")
+    );
+
+    // Years 0..=1899 are offset from 1900.
+    assert_eq!(sheet.eval("=DATE(24,1,1)"), sheet.eval("=DATE(1924,1,1)"));
+    assert_number(&sheet.eval("=DATE(0,1,1)"), 1.0);
+
+    assert_number(&sheet.eval("=DATE(1900,1,0)"), 0.0);
+    assert_number(&sheet.eval("=DATE(9999,12,31)"), 2_958_465.0);
+    for formula in [
+        "=DATE(1900,1,-1)",
+        "=DATE(9999,12,32)",
+        "=DATE(-1,1,1)",
+        "=DATE(10000,1,1)",
+    ] {
+        assert_eq!(
+            sheet.eval(formula),
+            Value::Error(ErrorKind::Num),
+            "{formula}"
+        );
+    }
+}
+
+#[test]
+fn time_wraps_past_midnight_and_normalizes_components() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval("=TIME(27,0,0)"), 0.125);
+    assert_number(&sheet.eval("=TIME(0,90,0)"), 0.0625);
+    assert_number(&sheet.eval("=TIME(1,-30,0)"), 1.0 / 48.0);
+    assert_number(&sheet.eval("=TIME(0,0,3661)"), 3661.0 / 86_400.0);
+    assert_eq!(sheet.eval("=TIME(0,0,-1)"), Value::Error(ErrorKind::Num));
+    assert_eq!(sheet.eval("=TIME(32768,0,0)"), Value::Error(ErrorKind::Num));
+}
+
+#[test]
+fn edate_and_eomonth_error_only_outside_the_serial_range() {
+    let mut sheet = TestSheet::new();
+    assert_eq!(
+        sheet.eval("=EDATE(DATE(2024,1,31),-1)"),
+        sheet.eval("=DATE(2023,12,31)")
+    );
+    assert_eq!(
+        sheet.eval("=EOMONTH(DATE(2024,1,15),-13)"),
+        sheet.eval("=DATE(2022,12,31)")
+    );
+    assert_eq!(
+        sheet.eval("=EOMONTH(DATE(9999,12,1),0)"),
+        Value::Number(2_958_465.0)
+    );
+    for formula in [
+        "=EDATE(DATE(9999,12,1),1)",
+        "=EOMONTH(DATE(9999,12,1),1)",
+        "=EDATE(1,-1)",
+        "=EDATE(-1,1)",
+    ] {
+        assert_eq!(
+            sheet.eval(formula),
+            Value::Error(ErrorKind::Num),
+            "{formula}"
+        );
+    }
+}
+
 #[test]
 fn today_and_now_are_volatile_and_consistent() {
     let mut sheet = TestSheet::new();
@@ -77,11 +163,50 @@ fn lotus_bug_can_be_disabled() {
     assert_number(&sheet.eval("=DAY(60)"), 1.0);
 }
 
+#[test]
+fn proleptic_date_compatibility_shifts_dates_before_march_1900() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval("=DATE(1900,1,1)"), 1.0);
+    assert_number(&sheet.eval("=DATE(1900,3,1)"), 61.0);
+
+    sheet.set_date_compatibility(DateCompatibility::Proleptic);
+    assert_number(&sheet.eval("=DATE(1900,1,1)"), 2.0);
+    assert_number(&sheet.eval("=DATE(1900,2,28)"), 60.0);
+    assert_number(&sheet.eval("=DATE(1900,3,1)"), 61.0);
+    assert_number(&sheet.eval("=DATE(2024,1,15)"), 45306.0);
+    assert_number(&sheet.eval("=DATE(1900,3,1)-DATE(1900,2,28)"), 1.0);
+
+    // Serial 60 is a real day (1900-02-28), and weekdays before March 1900 are correct.
+    assert_number(&sheet.eval("=MONTH(60)"), 2.0);
+    assert_number(&sheet.eval("=DAY(60)"), 28.0);
+    assert_number(&sheet.eval("=YEAR(1)"), 1899.0);
+    assert_number(&sheet.eval("=WEEKDAY(DATE(1900,1,1))"), 2.0);
+    assert_number(&sheet.eval("=WEEKDAY(DATE(1900,3,1))"), 5.0);
+
+    // TEXT renders serials on the same calendar as the date functions.
+    assert_eq!(
+        sheet.eval("=TEXT(DATE(1900,1,1),\"yyyy-mm-dd\")"),
+        Value::Text("1900-01-01".to_string())
+    );
+    assert_eq!(
+        sheet.eval("=TEXT(60,\"yyyy-mm-dd ddd\")"),
+        Value::Text("1900-02-28 Wed".to_string())
+    );
+    assert_eq!(
+        sheet.eval("=TEXT(DATE(1900,3,1),\"yyyy-mm-dd\")"),
+        Value::Text("1900-03-01".to_string())
+    );
+
+    sheet.set_date_compatibility(DateCompatibility::ExcelBug);
+    assert_number(&sheet.eval("=DAY(60)"), 29.0);
+    assert_number(&sheet.eval("=DATE(1900,1,1)"), 1.0);
+}
+
 #[test]
 fn time_and_timevalue_conversions() {
     let mut sheet = TestSheet::new();
     assert_number(&sheet.eval("=TIME(1,30,0)"), 0.0625);
-    assert_number(&sheet.eval("=TIME(24,0,0)"), 1.0);
+    assert_number(&sheet.eval("=TIME(24,0,0)"), 0.0);
     assert_eq!(sheet.eval("=TIME(-1,0,0)"), Value::Error(ErrorKind::Num));
     sheet.set("A1", Value::Number(f64::INFINITY));
     assert_eq!(sheet.eval("=TIME(A1,0,0)"), Value::Error(ErrorKind::Num));
@@ -794,6 +919,20 @@ fn datedif_matches_excel_units() {
     );
 }
 
+#[test]
+fn datedif_md_follows_excel_month_overflow() {
+    let mut sheet = TestSheet::new();
+    assert_number(
+        &sheet.eval("=DATEDIF(DATE(2015,1,31),DATE(2015,3,1),\"MD\")"),
+        -2.0,
+    );
+    // The 1900 system counts the fictitious 1900-02-29, so February 1900 has 29 days.
+    assert_number(
+        &sheet.eval("=DATEDIF(DATE(1900,1,31),DATE(1900,3,1),\"MD\")"),
+        -1.0,
+    );
+}
+
 #[test]
 fn datedif_spills_over_array_inputs() {
     let mut sheet = TestSheet::new();
@@ -866,6 +1005,43 @@ fn weekday_and_weeknum_return_types() {
     assert_eq!(sheet.eval("=WEEKNUM(1,9)"), Value::Error(ErrorKind::Num));
 }
 
+#[test]
+fn weeknum_and_isoweeknum_across_year_boundaries() {
+    let mut sheet = TestSheet::new();
+
+    // System 1: week 1 always contains January 1, so a Sunday-start year ending on a Sunday
+    // reaches week 54.
+    assert_number(&sheet.eval("=WEEKNUM(DATE(2022,1,1))"), 1.0);
+    assert_number(&sheet.eval("=WEEKNUM(DATE(2022,1,2),1)"), 2.0);
+    assert_number(&sheet.eval("=WEEKNUM(DATE(2000,12,31),1)"), 54.0);
+    assert_number(&sheet.eval("=WEEKNUM(DATE(2000,12,31),2)"), 53.0);
+    // 2024-01-01 is a Monday; a Tuesday-start week rolls over on January 2.
+    assert_number(&sheet.eval("=WEEKNUM(DATE(2024,1,1),12)"), 1.0);
+    assert_number(&sheet.eval("=WEEKNUM(DATE(2024,1,2),12)"), 2.0);
+    assert_number(&sheet.eval("=WEEKNUM(DATE(2024,1,6),16)"), 2.0);
+
+    // System 2 (ISO): January dates before the first Thursday's week belong to the prior year.
+    for (formula, week) in [
+        ("=ISOWEEKNUM(DATE(2016,1,1))", 53.0),
+        ("=ISOWEEKNUM(DATE(2017,1,1))", 52.0),
+        ("=ISOWEEKNUM(DATE(2021,1,3))", 53.0),
+        ("=ISOWEEKNUM(DATE(2021,1,4))", 1.0),
+        ("=ISOWEEKNUM(DATE(2020,12,31))", 53.0),
+        // Late December can already be week 1 of the next ISO year.
+        ("=ISOWEEKNUM(DATE(2018,12,31))", 1.0),
+        ("=ISOWEEKNUM(DATE(2019,12,30))", 1.0),
+        ("=WEEKNUM(DATE(2019,12,30),21)", 1.0),
+    ] {
+        assert_number(&sheet.eval(formula), week);
+    }
+
+    assert_eq!(sheet.eval("=ISOWEEKNUM(-1)"), Value::Error(ErrorKind::Num));
+    assert_eq!(
+        sheet.eval("=WEEKNUM(2958466)"),
+        Value::Error(ErrorKind::Num)
+    );
+}
+
 #[test]
 fn workday_and_networkdays_skip_weekends_and_holidays() {
     let mut sheet = TestSheet::new();
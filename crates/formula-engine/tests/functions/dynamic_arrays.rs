@@ -310,6 +310,80 @@ fn sort_supports_multi_key_sort_index_and_order_vectors() {
     assert_eq!(engine.get_cell_value("Sheet1", "E4"), Value::Number(3.0));
 }
 
+#[test]
+fn sort_is_stable_for_tied_keys_in_both_directions() {
+    // Enough rows that an unstable sort would be free to shuffle the tied groups.
+    let mut engine = Engine::new();
+    for row in 1..=60u32 {
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), f64::from(row % 3))
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), f64::from(row))
+            .unwrap();
+    }
+    engine
+        .set_cell_formula("Sheet1", "D1", "=SORT(A1:B60,1,1)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "G1", "=SORT(A1:B60,1,-1)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "J1", "=SORTBY(B1:B60,A1:A60,-1)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    let expected = |keys: [u32; 3]| -> Vec<f64> {
+        keys.iter()
+            .flat_map(|&key| (1..=60u32).filter(move |row| row % 3 == key))
+            .map(f64::from)
+            .collect()
+    };
+    let column = |col: &str| -> Vec<f64> {
+        (1..=60)
+            .map(
+                |row| match engine.get_cell_value("Sheet1", &format!("{col}{row}")) {
+                    Value::Number(n) => n,
+                    other => panic!("expected number at {col}{row}, got {other:?}"),
+                },
+            )
+            .collect()
+    };
+
+    assert_eq!(column("E"), expected([0, 1, 2]));
+    assert_eq!(column("H"), expected([2, 1, 0]));
+    assert_eq!(column("J"), expected([2, 1, 0]));
+}
+
+#[test]
+fn sort_descending_keeps_cross_type_order_with_blanks_last() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", "a").unwrap();
+    engine.set_cell_value("Sheet1", "A2", 1.0).unwrap();
+    engine.set_cell_value("Sheet1", "A3", true).unwrap();
+    // A4 left blank.
+    engine.set_cell_value("Sheet1", "A5", 3.0).unwrap();
+    engine.set_cell_value("Sheet1", "A6", "B").unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C1", "=SORT(A1:A6,1,-1)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    // Direction only applies within a type: numbers, then text, then booleans, blanks last.
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(3.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C2"), Value::Number(1.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "C3"),
+        Value::Text("B".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "C4"),
+        Value::Text("a".to_string())
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "C5"), Value::Bool(true));
+    assert_eq!(engine.get_cell_value("Sheet1", "C6"), Value::Blank);
+}
+
 #[test]
 fn unique_by_row_and_column_and_exactly_once() {
     let mut engine = Engine::new();
@@ -1079,3 +1153,83 @@ fn multithreaded_and_singlethreaded_recalc_match_for_dynamic_arrays() {
         );
     }
 }
+
+#[test]
+fn sequence_fills_row_major_with_start_and_step() {
+    let mut engine = Engine::new();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=SEQUENCE(2,3,10,5)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    let (start, end) = engine.spill_range("Sheet1", "A1").expect("spill range");
+    assert_eq!(start, parse_a1("A1").unwrap());
+    assert_eq!(end, parse_a1("C2").unwrap());
+
+    // Row-major: the first row is filled before moving to the second.
+    for (addr, expected) in [
+        ("A1", 10.0),
+        ("B1", 15.0),
+        ("C1", 20.0),
+        ("A2", 25.0),
+        ("B2", 30.0),
+        ("C2", 35.0),
+    ] {
+        assert_eq!(
+            engine.get_cell_value("Sheet1", addr),
+            Value::Number(expected),
+            "{addr}"
+        );
+    }
+}
+
+#[test]
+fn sequence_supports_negative_and_fractional_steps() {
+    let mut engine = Engine::new();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=SEQUENCE(1,4,3,-2)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A3", "=SEQUENCE(3,,0,0.5)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(3.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(1.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Number(-1.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "D1"), Value::Number(-3.0));
+
+    // Omitted `cols` defaults to a single column.
+    let (_, end) = engine.spill_range("Sheet1", "A3").expect("spill range");
+    assert_eq!(end, parse_a1("A5").unwrap());
+    assert_eq!(engine.get_cell_value("Sheet1", "A5"), Value::Number(1.0));
+}
+
+#[test]
+fn sequence_rejects_non_positive_dimensions() {
+    let mut engine = Engine::new();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=SEQUENCE(0)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A2", "=SEQUENCE(2,-1)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A3", "=SEQUENCE(\"x\")")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Error(ErrorKind::Value)
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A2"),
+        Value::Error(ErrorKind::Value)
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A3"),
+        Value::Error(ErrorKind::Value)
+    );
+    assert!(engine.spill_range("Sheet1", "A1").is_none());
+}
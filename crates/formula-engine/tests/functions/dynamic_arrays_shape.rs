@@ -50,6 +50,42 @@ fn take_selects_rows_and_cols_and_handles_missing_args() {
     assert_eq!(engine.get_cell_value("Sheet1", "F6"), Value::Number(8.0));
 }
 
+#[test]
+fn take_negative_counts_select_from_the_end() {
+    let mut engine = Engine::new();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=SEQUENCE(3,3)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "E1", "=TAKE(A1#,-2,-2)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "E4", "=TAKE(A1#,1,-1)")
+        .unwrap();
+    // Counts larger than the input clamp to the full dimension.
+    engine
+        .set_cell_formula("Sheet1", "E6", "=TAKE(A1#,-10,-1)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    let (start, end) = engine.spill_range("Sheet1", "E1").expect("spill range");
+    assert_eq!(start, parse_a1("E1").unwrap());
+    assert_eq!(end, parse_a1("F2").unwrap());
+    assert_eq!(engine.get_cell_value("Sheet1", "E1"), Value::Number(5.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "F1"), Value::Number(6.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "E2"), Value::Number(8.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "F2"), Value::Number(9.0));
+
+    assert_eq!(engine.get_cell_value("Sheet1", "E4"), Value::Number(3.0));
+
+    let (start, end) = engine.spill_range("Sheet1", "E6").expect("spill range");
+    assert_eq!(start, parse_a1("E6").unwrap());
+    assert_eq!(end, parse_a1("E8").unwrap());
+    assert_eq!(engine.get_cell_value("Sheet1", "E6"), Value::Number(3.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "E7"), Value::Number(6.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "E8"), Value::Number(9.0));
+}
+
 #[test]
 fn take_accepts_spilled_input() {
     let mut engine = Engine::new();
@@ -28,6 +28,26 @@ fn vstack_pads_missing_cols_with_na() {
     assert_eq!(sheet.get("B2"), Value::Error(ErrorKind::NA));
 }
 
+#[test]
+fn vstack_uneven_widths_spill_to_widest_input() {
+    let mut sheet = TestSheet::new();
+    sheet.set_formula("A1", "=VSTACK({1;2},{3,4,5},{6,7})");
+    sheet.recalc();
+
+    assert_eq!(sheet.get("A1"), Value::Number(1.0));
+    assert_eq!(sheet.get("B1"), Value::Error(ErrorKind::NA));
+    assert_eq!(sheet.get("C1"), Value::Error(ErrorKind::NA));
+    assert_eq!(sheet.get("A2"), Value::Number(2.0));
+    assert_eq!(sheet.get("C2"), Value::Error(ErrorKind::NA));
+    assert_eq!(sheet.get("A3"), Value::Number(3.0));
+    assert_eq!(sheet.get("B3"), Value::Number(4.0));
+    assert_eq!(sheet.get("C3"), Value::Number(5.0));
+    assert_eq!(sheet.get("A4"), Value::Number(6.0));
+    assert_eq!(sheet.get("B4"), Value::Number(7.0));
+    assert_eq!(sheet.get("C4"), Value::Error(ErrorKind::NA));
+    assert_eq!(sheet.get("A5"), Value::Blank);
+}
+
 #[test]
 fn tocol_torow_ordering_and_ignore_blanks() {
     let mut sheet = TestSheet::new();
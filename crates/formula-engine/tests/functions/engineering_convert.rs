@@ -44,6 +44,69 @@ fn convert_temperature_celsius_to_fahrenheit() {
     assert_number(&sheet.eval(r#"=CONVERT(0,"C","F")"#), 32.0);
 }
 
+#[test]
+fn convert_temperature_scales_apply_offsets() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval(r#"=CONVERT(212,"F","C")"#), 100.0);
+    assert_number(&sheet.eval(r#"=CONVERT(0,"K","C")"#), -273.15);
+    assert_number(&sheet.eval(r#"=CONVERT(100,"cel","kel")"#), 373.15);
+    assert_number(&sheet.eval(r#"=CONVERT(0,"C","Rank")"#), 491.67);
+    assert_number(&sheet.eval(r#"=CONVERT(80,"Reau","C")"#), 100.0);
+}
+
+#[test]
+fn convert_time_pressure_and_energy_units() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval(r#"=CONVERT(1,"day","mn")"#), 1440.0);
+    assert_number(&sheet.eval(r#"=CONVERT(2.5,"hr","sec")"#), 9000.0);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"atm","mmHg")"#), 760.0);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"atm","kPa")"#), 101.325);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"cal","J")"#), 4.1868);
+    assert_number(
+        &sheet.eval(r#"=CONVERT(1,"kWh","BTU")"#),
+        3_412.141_633_127_942,
+    );
+    assert_number(
+        &sheet.eval(r#"=CONVERT(1,"HP","W")"#),
+        745.699_871_582_270_2,
+    );
+}
+
+#[test]
+fn convert_mass_and_volume_catalog_units() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval(r#"=CONVERT(1,"stone","lbm")"#), 14.0);
+    assert_number(&sheet.eval(r#"=CONVERT(500,"mg","g")"#), 0.5);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"gal","cup")"#), 16.0);
+    assert_number(&sheet.eval(r#"=CONVERT(3,"tsp","tbs")"#), 1.0);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"uk_gal","L")"#), 4.546_09);
+}
+
+#[test]
+fn convert_prefixed_length_units() {
+    let mut sheet = TestSheet::new();
+    assert_number(
+        &sheet.eval(r#"=CONVERT(1,"km","mi")"#),
+        0.621_371_192_237_334,
+    );
+    assert_number(&sheet.eval(r#"=CONVERT(1,"mi","km")"#), 1.609_344);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"Pica","pica")"#), 1.0 / 12.0);
+}
+
+#[test]
+fn convert_information_units_use_metric_and_binary_prefixes() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval(r#"=CONVERT(1,"byte","bit")"#), 8.0);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"kbyte","byte")"#), 1000.0);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"kibyte","byte")"#), 1024.0);
+    assert_number(&sheet.eval(r#"=CONVERT(1,"Mibit","kibit")"#), 1024.0);
+    // Binary prefixes only apply to information units.
+    assert_eq!(
+        sheet.eval(r#"=CONVERT(1,"kim","m")"#),
+        Value::Error(ErrorKind::NA)
+    );
+}
+
 #[test]
 fn convert_invalid_units_return_na() {
     let mut sheet = TestSheet::new();
@@ -55,4 +118,13 @@ fn convert_invalid_units_return_na() {
         sheet.eval(r#"=CONVERT(1,"m","kg")"#),
         Value::Error(ErrorKind::NA)
     );
+    // Prefixes are rejected on units that don't take them.
+    assert_eq!(
+        sheet.eval(r#"=CONVERT(1,"kft","m")"#),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        sheet.eval(r#"=CONVERT(1,"C","sec")"#),
+        Value::Error(ErrorKind::NA)
+    );
 }
@@ -1,4 +1,6 @@
-use formula_engine::date::{serial_to_ymd, ymd_to_serial, ExcelDate, ExcelDateSystem};
+use formula_engine::date::{
+    serial_to_ymd, ymd_to_serial, DateCompatibility, ExcelDate, ExcelDateSystem,
+};
 
 #[test]
 fn excel_1900_date_system_emulates_lotus_bug() {
@@ -67,3 +69,43 @@ fn lotus_bug_can_be_disabled() {
     // 1900-02-29 is not a real Gregorian date and should be rejected.
     assert!(ymd_to_serial(ExcelDate::new(1900, 2, 29), system).is_err());
 }
+
+#[test]
+fn proleptic_1900_calendar_diverges_from_excel_only_before_march_1900() {
+    let excel = ExcelDateSystem::EXCEL_1900;
+    let proleptic = excel.with_compatibility(DateCompatibility::Proleptic);
+    assert_eq!(proleptic, ExcelDateSystem::Excel1900Proleptic);
+    assert_eq!(proleptic.compatibility(), DateCompatibility::Proleptic);
+    assert_eq!(
+        proleptic.with_compatibility(DateCompatibility::ExcelBug),
+        excel
+    );
+
+    // One day apart before the fictitious 1900-02-29...
+    for (date, excel_serial) in [
+        (ExcelDate::new(1900, 1, 1), 1),
+        (ExcelDate::new(1900, 2, 28), 59),
+    ] {
+        assert_eq!(ymd_to_serial(date, excel).unwrap(), excel_serial);
+        assert_eq!(ymd_to_serial(date, proleptic).unwrap(), excel_serial + 1);
+        assert_eq!(serial_to_ymd(excel_serial + 1, proleptic).unwrap(), date);
+    }
+    assert_eq!(
+        serial_to_ymd(1, proleptic).unwrap(),
+        ExcelDate::new(1899, 12, 31)
+    );
+    assert!(ymd_to_serial(ExcelDate::new(1900, 2, 29), proleptic).is_err());
+
+    // ...and identical from 1900-03-01 on.
+    for date in [ExcelDate::new(1900, 3, 1), ExcelDate::new(2024, 2, 29)] {
+        let serial = ymd_to_serial(date, excel).unwrap();
+        assert_eq!(ymd_to_serial(date, proleptic).unwrap(), serial);
+        assert_eq!(serial_to_ymd(serial, proleptic).unwrap(), date);
+    }
+
+    // The 1904 date system has no fictitious leap day to bypass.
+    assert_eq!(
+        ExcelDateSystem::Excel1904.with_compatibility(DateCompatibility::Proleptic),
+        ExcelDateSystem::Excel1904
+    );
+}
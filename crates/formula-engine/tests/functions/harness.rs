@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use formula_engine::date::ExcelDateSystem;
+use formula_engine::date::{DateCompatibility, ExcelDateSystem};
 use formula_engine::locale::ValueLocaleConfig;
 use formula_engine::pivot::PivotTable;
 use formula_engine::{EditOp, EditResult, Engine, Value};
@@ -25,6 +25,10 @@ impl TestSheet {
         self.engine.set_date_system(system);
     }
 
+    pub fn set_date_compatibility(&mut self, compatibility: DateCompatibility) {
+        self.engine.set_date_compatibility(compatibility);
+    }
+
     pub fn set_value_locale(&mut self, locale: ValueLocaleConfig) {
         self.engine.set_value_locale(locale);
     }
@@ -225,3 +225,79 @@ fn index_array_slices_spill_when_used_as_formula_result() {
     assert_eq!(engine.get_cell_value("Sheet1", "G1"), Value::Number(1.0));
     assert_eq!(engine.get_cell_value("Sheet1", "G2"), Value::Number(4.0));
 }
+
+#[test]
+fn index_with_row_and_column_match_performs_2d_lookup() {
+    let mut engine = Engine::new();
+    // Row headers in A2:A4, column headers in B1:D1, data in B2:D4.
+    engine.set_cell_value("Sheet1", "B1", "Jan").unwrap();
+    engine.set_cell_value("Sheet1", "C1", "Feb").unwrap();
+    engine.set_cell_value("Sheet1", "D1", "Mar").unwrap();
+    for (row, (region, values)) in [
+        ("North", [11.0, 12.0, 13.0]),
+        ("South", [21.0, 22.0, 23.0]),
+        ("West", [31.0, 32.0, 33.0]),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let r = row + 2;
+        engine
+            .set_cell_value("Sheet1", &format!("A{r}"), region)
+            .unwrap();
+        for (col, value) in ["B", "C", "D"].into_iter().zip(values) {
+            engine
+                .set_cell_value("Sheet1", &format!("{col}{r}"), value)
+                .unwrap();
+        }
+    }
+
+    engine
+        .set_cell_formula(
+            "Sheet1",
+            "F1",
+            r#"=INDEX(B2:D4,MATCH("West",A2:A4,0),MATCH("Feb",B1:D1,0))"#,
+        )
+        .unwrap();
+    engine
+        .set_cell_formula(
+            "Sheet1",
+            "F2",
+            r#"=INDEX(B2:D4,MATCH("East",A2:A4,0),MATCH("Feb",B1:D1,0))"#,
+        )
+        .unwrap();
+    // A matched row with column_num 0 spills the whole row.
+    engine
+        .set_cell_formula("Sheet1", "F3", r#"=INDEX(B2:D4,MATCH("South",A2:A4,0),0)"#)
+        .unwrap();
+    // A matched column with row_num 0 spills the whole column.
+    engine
+        .set_cell_formula("Sheet1", "J1", r#"=INDEX(B2:D4,0,MATCH("Mar",B1:D1,0))"#)
+        .unwrap();
+    engine.recalculate();
+
+    assert_eq!(engine.get_cell_value("Sheet1", "F1"), Value::Number(32.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "F2"),
+        Value::Error(ErrorKind::NA)
+    );
+
+    let (start, end) = engine.spill_range("Sheet1", "F3").expect("F3 spill range");
+    assert_eq!(start, parse_a1("F3").unwrap());
+    assert_eq!(end, parse_a1("H3").unwrap());
+    assert_eq!(engine.get_cell_value("Sheet1", "F3"), Value::Number(21.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "G3"), Value::Number(22.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "H3"), Value::Number(23.0));
+
+    let (start, end) = engine.spill_range("Sheet1", "J1").expect("J1 spill range");
+    assert_eq!(start, parse_a1("J1").unwrap());
+    assert_eq!(end, parse_a1("J3").unwrap());
+    assert_eq!(engine.get_cell_value("Sheet1", "J1"), Value::Number(13.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "J2"), Value::Number(23.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "J3"), Value::Number(33.0));
+
+    // Editing the lookup table updates the 2D lookup.
+    engine.set_cell_value("Sheet1", "C4", 320.0).unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "F1"), Value::Number(320.0));
+}
@@ -14,6 +14,35 @@ fn isblank_distinguishes_blank_cell_from_empty_string() {
     assert_eq!(sheet.eval("=ISBLANK(\"\")"), Value::Bool(false));
 }
 
+#[test]
+fn countblank_counts_formula_empty_strings_but_isblank_does_not() {
+    let mut sheet = TestSheet::new();
+    // A1: never set. A2: formula returning "". A3: value that was later cleared.
+    // A4: formula that was later cleared. A5: zero constant.
+    sheet.set_formula("A2", "=\"\"");
+    sheet.set("A3", 5.0);
+    sheet.clear_cell("A3");
+    sheet.set_formula("A4", "=1+1");
+    sheet.clear_cell("A4");
+    sheet.set("A5", 0.0);
+    sheet.recalc();
+
+    assert_eq!(sheet.get("A2"), Value::Text(String::new()));
+
+    assert_eq!(sheet.eval("=ISBLANK(A1)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISBLANK(A2)"), Value::Bool(false));
+    assert_eq!(sheet.eval("=ISBLANK(A3)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISBLANK(A4)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISBLANK(A5)"), Value::Bool(false));
+
+    // Empty, "" and cleared cells are blank for COUNTBLANK; zeros are not.
+    assert_number(&sheet.eval("=COUNTBLANK(A1:A5)"), 4.0);
+    assert_number(&sheet.eval("=COUNTBLANK(A2)"), 1.0);
+    assert_number(&sheet.eval("=COUNTBLANK(A5)"), 0.0);
+    // COUNTA still counts the "" formula result as a value.
+    assert_number(&sheet.eval("=COUNTA(A1:A5)"), 2.0);
+}
+
 #[test]
 fn isnumber_istext_islogical_work_on_scalars_and_references() {
     let mut sheet = TestSheet::new();
@@ -175,3 +204,96 @@ fn isnontext_matches_excel_semantics_and_spills_for_array_literals() {
     assert_eq!(engine.get_cell_value("Sheet1", "D1"), Value::Bool(true));
     assert_eq!(engine.get_cell_value("Sheet1", "D2"), Value::Bool(false));
 }
+
+#[test]
+fn isref_is_true_only_for_reference_arguments() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 3.0);
+    sheet.set("A2", 4.0);
+    sheet.set_formula("C1", "=SEQUENCE(2)");
+    sheet.recalc();
+
+    for formula in [
+        "=ISREF(A1)",
+        "=ISREF(H9)",
+        "=ISREF(A1:B2)",
+        "=ISREF(Sheet1!A1)",
+        "=ISREF((A1,B1))",
+        "=ISREF(A1:B2 B1:B3)",
+        "=ISREF(C1#)",
+        "=ISREF(INDIRECT(\"A1\"))",
+        "=ISREF(OFFSET(A1,1,0))",
+        // Functions that pass an argument through keep it a reference.
+        "=ISREF(IF(TRUE,A1,1))",
+        "=ISREF(CHOOSE(2,1,A1))",
+        "=ISREF(LET(x,A1,x))",
+    ] {
+        assert_eq!(sheet.eval(formula), Value::Bool(true), "{formula}");
+    }
+
+    for formula in [
+        "=ISREF(\"A1\")",
+        "=ISREF(1)",
+        "=ISREF(A1+0)",
+        "=ISREF(IF(FALSE,A1,1))",
+        "=ISREF(LET(x,A1,x*1))",
+        "=ISREF(INDIRECT(\"not a ref\"))",
+    ] {
+        assert_eq!(sheet.eval(formula), Value::Bool(false), "{formula}");
+    }
+
+    // Returning a reference from IF/LET does not change what their callers see.
+    assert_number(&sheet.eval("=SUM(IF(TRUE,A1:A2,0))"), 7.0);
+    assert_number(&sheet.eval("=ROWS(LET(r,A1:A2,r))"), 2.0);
+    assert_number(&sheet.eval("=IF(A1>0,A2,A1)+1"), 5.0);
+}
+
+#[test]
+fn islogical_isodd_iseven_match_excel_semantics() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", true);
+    sheet.set("A2", "3");
+
+    assert_eq!(sheet.eval("=ISLOGICAL(TRUE)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISLOGICAL(1=1)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISLOGICAL(1)"), Value::Bool(false));
+    assert_eq!(sheet.eval("=ISLOGICAL(\"TRUE\")"), Value::Bool(false));
+    assert_eq!(sheet.eval("=ISLOGICAL(H9)"), Value::Bool(false));
+
+    assert_eq!(sheet.eval("=ISNONTEXT(H9)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISNONTEXT(\"\")"), Value::Bool(false));
+    assert_eq!(sheet.eval("=ISNONTEXT(TRUE)"), Value::Bool(true));
+
+    // Numbers are truncated toward zero before the parity check.
+    assert_eq!(sheet.eval("=ISODD(3)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISODD(-3.7)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISEVEN(-2.5)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISEVEN(0)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISODD(A2)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISEVEN(H9)"), Value::Bool(true));
+    assert_eq!(sheet.eval("=ISODD(H9)"), Value::Bool(false));
+
+    // Logical values are rejected rather than coerced to 1/0.
+    assert_eq!(sheet.eval("=ISODD(TRUE)"), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=ISEVEN(A1)"), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=ISEVEN(\"x\")"), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=ISODD(#N/A)"), Value::Error(ErrorKind::NA));
+}
+
+#[test]
+fn type_and_n_classify_formula_results() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 2.0);
+    sheet.set("A2", "7");
+
+    assert_number(&sheet.eval("=TYPE(A1*2)"), 1.0);
+    assert_number(&sheet.eval("=TYPE(A2)"), 2.0);
+    assert_number(&sheet.eval("=TYPE(A1>1)"), 4.0);
+    assert_number(&sheet.eval("=TYPE(1/0)"), 16.0);
+    assert_number(&sheet.eval("=TYPE(SEQUENCE(2))"), 64.0);
+
+    assert_number(&sheet.eval("=N(A1)"), 2.0);
+    assert_number(&sheet.eval("=N(A2)"), 0.0);
+    assert_number(&sheet.eval("=N(H9)"), 0.0);
+    assert_number(&sheet.eval("=N(DATE(2020,1,1))"), 43831.0);
+}
@@ -129,6 +129,65 @@ fn iferror_and_ifna() {
     );
 }
 
+#[test]
+fn iferror_and_ifna_spill_arrays_and_replace_errors_elementwise() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 1.0);
+    sheet.set("A2", Value::Error(ErrorKind::NA));
+    sheet.set("A3", Value::Error(ErrorKind::Div0));
+    sheet.set("A4", "x");
+
+    // Error-free arrays pass through unchanged.
+    sheet.set_formula("C1", "=IFERROR(SEQUENCE(3), 0)");
+    // Errors are replaced per element; scalar and array fallbacks broadcast.
+    sheet.set_formula("D1", "=IFERROR(A1:A4, 0)");
+    sheet.set_formula("E1", "=IFNA(A1:A4, \"na\")");
+    sheet.set_formula("F1", "=IFERROR(A1:A4, {10;20;30;40})");
+    sheet.recalculate();
+
+    let column = |sheet: &TestSheet, col: &str, rows: usize| -> Vec<Value> {
+        (1..=rows)
+            .map(|row| sheet.get(&format!("{col}{row}")))
+            .collect()
+    };
+    assert_eq!(
+        column(&sheet, "C", 4),
+        vec![
+            Value::Number(1.0),
+            Value::Number(2.0),
+            Value::Number(3.0),
+            Value::Blank
+        ]
+    );
+    assert_eq!(
+        column(&sheet, "D", 4),
+        vec![
+            Value::Number(1.0),
+            Value::Number(0.0),
+            Value::Number(0.0),
+            Value::Text("x".to_string())
+        ]
+    );
+    assert_eq!(
+        column(&sheet, "E", 4),
+        vec![
+            Value::Number(1.0),
+            Value::Text("na".to_string()),
+            Value::Error(ErrorKind::Div0),
+            Value::Text("x".to_string())
+        ]
+    );
+    assert_eq!(
+        column(&sheet, "F", 4),
+        vec![
+            Value::Number(1.0),
+            Value::Number(20.0),
+            Value::Number(30.0),
+            Value::Text("x".to_string())
+        ]
+    );
+}
+
 #[test]
 fn ifs_selects_first_true_condition_and_is_lazy() {
     let mut sheet = TestSheet::new();
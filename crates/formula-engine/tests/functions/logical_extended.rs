@@ -1,7 +1,36 @@
-use formula_engine::{ErrorKind, Value};
+use std::sync::{Arc, Mutex};
+
+use formula_engine::eval::CellAddr;
+use formula_engine::{Engine, ErrorKind, ExternalValueProvider, Value};
 
 use super::harness::{assert_number, TestSheet};
 
+/// Records every provider-backed cell read so tests can assert which branches were evaluated.
+#[derive(Default)]
+struct RecordingProvider {
+    reads: Mutex<Vec<(String, CellAddr)>>,
+}
+
+impl RecordingProvider {
+    fn was_read(&self, addr: CellAddr) -> bool {
+        self.reads
+            .lock()
+            .expect("lock poisoned")
+            .iter()
+            .any(|(_, read)| *read == addr)
+    }
+}
+
+impl ExternalValueProvider for RecordingProvider {
+    fn get(&self, sheet: &str, addr: CellAddr) -> Option<Value> {
+        self.reads
+            .lock()
+            .expect("lock poisoned")
+            .push((sheet.to_string(), addr));
+        Some(Value::Error(ErrorKind::Div0))
+    }
+}
+
 #[test]
 fn choose_ifs_and_switch_are_lazy_in_scalar_mode() {
     let mut sheet = TestSheet::new();
@@ -43,6 +72,59 @@ fn ifs_short_circuits_in_scalar_mode_and_returns_na() {
     );
 }
 
+#[test]
+fn ifs_and_switch_do_not_read_cells_in_skipped_branches() {
+    let provider = Arc::new(RecordingProvider::default());
+    let mut engine = Engine::new();
+    engine.set_external_value_provider(Some(provider.clone()));
+
+    // Z1/Z2 are only reachable from branches after the first match.
+    engine
+        .set_cell_formula("Sheet1", "A1", "=IFS(TRUE, \"first\", TRUE, Z1)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A2", "=SWITCH(1, 1, \"hit\", Z2, \"never\")")
+        .unwrap();
+    // Misses without a default return #N/A; the miss for IFS must not read the result branches.
+    engine
+        .set_cell_formula("Sheet1", "A3", "=IFS(FALSE, Z3, FALSE, Z3)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A4", "=SWITCH(3, 1, Z4, 2, Z4)")
+        .unwrap();
+    // Control: a taken branch does read through the provider.
+    engine
+        .set_cell_formula("Sheet1", "A5", "=IFS(FALSE, 0, TRUE, Z5)")
+        .unwrap();
+    engine.recalculate();
+
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Text("first".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A2"),
+        Value::Text("hit".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A3"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A4"),
+        Value::Error(ErrorKind::NA)
+    );
+
+    for row in 0..4 {
+        let addr = CellAddr { row, col: 25 };
+        assert!(
+            !provider.was_read(addr),
+            "expected skipped branch cell {addr:?} not to be evaluated"
+        );
+    }
+    assert!(provider.was_read(CellAddr { row: 4, col: 25 }));
+}
+
 #[test]
 fn ifs_spills_and_ignores_unselected_branch_errors_per_element() {
     let mut sheet = TestSheet::new();
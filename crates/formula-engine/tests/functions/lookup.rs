@@ -1,3 +1,4 @@
+use formula_engine::eval::parse_a1;
 use formula_engine::functions::lookup;
 use formula_engine::locale::ValueLocaleConfig;
 use formula_engine::value::EntityValue;
@@ -689,6 +690,160 @@ fn vlookup_approximate_match() {
     );
 }
 
+#[test]
+fn vlookup_approximate_match_binary_searches_sorted_tables() {
+    let mut sheet = TestSheet::new();
+    for i in 1..=1000 {
+        sheet.set(&format!("A{i}"), (i * 2) as f64);
+        sheet.set(&format!("B{i}"), i as f64);
+    }
+
+    // Omitted range_lookup defaults to TRUE: the largest key <= the lookup value.
+    assert_eq!(
+        sheet.eval("=VLOOKUP(777, A1:B1000, 2)"),
+        Value::Number(388.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(777, A1:B1000, 2, TRUE)"),
+        Value::Number(388.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(2000, A1:B1000, 2)"),
+        Value::Number(1000.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(1E9, A1:B1000, 2)"),
+        Value::Number(1000.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(1, A1:B1000, 2)"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(777, A1:B1000, 2, FALSE)"),
+        Value::Error(ErrorKind::NA)
+    );
+
+    sheet.set("D1", "apple");
+    sheet.set("D2", "banana");
+    sheet.set("D3", "cherry");
+    sheet.set("E1", 1.0);
+    sheet.set("E2", 2.0);
+    sheet.set("E3", 3.0);
+    assert_eq!(
+        sheet.eval("=VLOOKUP(\"Blueberry\", D1:E3, 2)"),
+        Value::Number(2.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(\"zucchini\", D1:E3, 2)"),
+        Value::Number(3.0)
+    );
+}
+
+#[test]
+fn vlookup_exact_match_supports_wildcards_and_reports_not_found() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", "north-east");
+    sheet.set("A2", "south");
+    sheet.set("A3", "what?");
+    sheet.set("B1", 1.0);
+    sheet.set("B2", 2.0);
+    sheet.set("B3", 3.0);
+
+    assert_eq!(
+        sheet.eval("=VLOOKUP(\"*east\", A1:B3, 2, FALSE)"),
+        Value::Number(1.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(\"s?uth\", A1:B3, 2, FALSE)"),
+        Value::Number(2.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(\"what~?\", A1:B3, 2, FALSE)"),
+        Value::Number(3.0)
+    );
+    assert_eq!(
+        sheet.eval("=VLOOKUP(\"west*\", A1:B3, 2, FALSE)"),
+        Value::Error(ErrorKind::NA)
+    );
+}
+
+#[test]
+fn vlookup_and_hlookup_spill_over_array_lookup_values() {
+    let mut engine = Engine::new();
+    for (row, (key, value)) in [(1.0, "one"), (3.0, "three"), (5.0, "five")]
+        .into_iter()
+        .enumerate()
+    {
+        let row = row + 1;
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), key)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), value)
+            .unwrap();
+    }
+    engine.set_cell_value("Sheet1", "D10", 1.0).unwrap();
+    engine.set_cell_value("Sheet1", "E10", 3.0).unwrap();
+    engine.set_cell_value("Sheet1", "F10", 5.0).unwrap();
+    engine.set_cell_value("Sheet1", "D11", 10.0).unwrap();
+    engine.set_cell_value("Sheet1", "E11", 30.0).unwrap();
+    engine.set_cell_value("Sheet1", "F11", 50.0).unwrap();
+
+    engine
+        .set_cell_formula("Sheet1", "D1", "=VLOOKUP({5;2;1}, A1:B3, 2, FALSE)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "E1", "=VLOOKUP(A1:A3*1+2, A1:B3, 2, FALSE)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "D13", "=HLOOKUP({3,4,1}, D10:F11, 2, FALSE)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    assert_eq!(
+        engine.spill_range("Sheet1", "D1"),
+        Some((parse_a1("D1").unwrap(), parse_a1("D3").unwrap()))
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "D1"),
+        Value::Text("five".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "D2"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "D3"),
+        Value::Text("one".to_string())
+    );
+
+    // Computed arrays spill too: keys 3, 5, 7.
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "E1"),
+        Value::Text("three".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "E2"),
+        Value::Text("five".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "E3"),
+        Value::Error(ErrorKind::NA)
+    );
+
+    assert_eq!(
+        engine.spill_range("Sheet1", "D13"),
+        Some((parse_a1("D13").unwrap(), parse_a1("F13").unwrap()))
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "D13"), Value::Number(30.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "E13"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "F13"), Value::Number(10.0));
+}
+
 #[test]
 fn vlookup_and_hlookup_compile_to_bytecode_backend_for_simple_range_tables() {
     let mut sheet = TestSheet::new();
@@ -994,6 +1149,41 @@ fn hlookup_supports_wildcard_exact_matching() {
     );
 }
 
+#[test]
+fn hlookup_approximate_match_is_the_default_and_validates_row_index() {
+    let mut sheet = TestSheet::new();
+    for (col, key) in ["A", "B", "C", "D", "E"]
+        .iter()
+        .zip([10.0, 20.0, 30.0, 40.0, 50.0])
+    {
+        sheet.set(&format!("{col}1"), key);
+        sheet.set(&format!("{col}2"), key / 10.0);
+    }
+
+    assert_eq!(sheet.eval("=HLOOKUP(35, A1:E2, 2)"), Value::Number(3.0));
+    assert_eq!(
+        sheet.eval("=HLOOKUP(35, A1:E2, 2, TRUE)"),
+        Value::Number(3.0)
+    );
+    assert_eq!(sheet.eval("=HLOOKUP(99, A1:E2, 2)"), Value::Number(5.0));
+    assert_eq!(
+        sheet.eval("=HLOOKUP(5, A1:E2, 2)"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        sheet.eval("=HLOOKUP(35, A1:E2, 2, FALSE)"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        sheet.eval("=HLOOKUP(30, A1:E2, 3)"),
+        Value::Error(ErrorKind::Ref)
+    );
+    assert_eq!(
+        sheet.eval("=HLOOKUP(30, A1:E2, 0)"),
+        Value::Error(ErrorKind::Value)
+    );
+}
+
 #[test]
 fn lookup_vector_form_is_exact_or_next_smaller_and_returns_last_duplicate() {
     let mut sheet = TestSheet::new();
@@ -1079,6 +1269,62 @@ fn lookup_array_form_searches_first_row_for_wide_arrays() {
     assert_eq!(sheet.eval("=LOOKUP(3.5, A1:D2)"), Value::Number(30.0));
 }
 
+#[test]
+fn lookup_array_form_square_arrays_search_first_column() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 1.0);
+    sheet.set("A2", 2.0);
+    sheet.set("B1", 5.0);
+    sheet.set("B2", 6.0);
+
+    // A square array is treated like a tall one: search A1:A2 and return from B1:B2.
+    assert_eq!(sheet.eval("=LOOKUP(2, A1:B2)"), Value::Number(6.0));
+}
+
+#[test]
+fn lookup_array_form_array_literals_pick_orientation_from_shape() {
+    let mut sheet = TestSheet::new();
+
+    // 3x2 (vertical): search the first column, return from the last column.
+    assert_eq!(
+        sheet.eval("=LOOKUP(2.5, {1,\"a\";2,\"b\";3,\"c\"})"),
+        Value::Text("b".to_string())
+    );
+
+    // 2x3 (horizontal): search the first row, return from the last row.
+    assert_eq!(
+        sheet.eval("=LOOKUP(2.5, {1,2,3;\"a\",\"b\",\"c\"})"),
+        Value::Text("b".to_string())
+    );
+
+    // Lookup values smaller than the first key are #N/A in both orientations.
+    assert_eq!(
+        sheet.eval("=LOOKUP(0, {1,\"a\";2,\"b\";3,\"c\"})"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        sheet.eval("=LOOKUP(0, {1,2,3;\"a\",\"b\",\"c\"})"),
+        Value::Error(ErrorKind::NA)
+    );
+}
+
+#[test]
+fn lookup_vector_form_allows_mixed_vector_orientations() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 10.0);
+    sheet.set("B1", 20.0);
+    sheet.set("C1", 30.0);
+    sheet.set("E1", "low");
+    sheet.set("E2", "mid");
+    sheet.set("E3", "high");
+
+    // Horizontal lookup_vector with a vertical result_vector of the same length.
+    assert_eq!(
+        sheet.eval("=LOOKUP(25, A1:C1, E1:E3)"),
+        Value::Text("mid".to_string())
+    );
+}
+
 #[test]
 fn index_and_match() {
     let mut sheet = TestSheet::new();
@@ -1180,6 +1426,35 @@ fn choose_selects_index_supports_arrays_and_range_unions() {
     );
 }
 
+#[test]
+fn choose_returns_references_usable_by_reference_consumers() {
+    let mut sheet = TestSheet::new();
+    for row in 1..=5 {
+        sheet.set(&format!("A{row}"), row as f64);
+        sheet.set(&format!("B{row}"), (row * 10) as f64);
+    }
+
+    // The chosen range is summed as a reference (not collapsed to its top-left value).
+    assert_eq!(
+        sheet.eval("=SUM(CHOOSE(2, A1:A5, B1:B5))"),
+        Value::Number(150.0)
+    );
+    assert_eq!(
+        sheet.eval("=ROWS(CHOOSE(1, A1:A5, B1:B2))"),
+        Value::Number(5.0)
+    );
+
+    // A single-cell choice can be used as a range endpoint.
+    assert_eq!(
+        sheet.eval("=SUM(A1:CHOOSE(2, A2, A4))"),
+        Value::Number(10.0)
+    );
+    assert_eq!(
+        sheet.eval("=SUM(CHOOSE(1, A2, A4):B3)"),
+        Value::Number(55.0)
+    );
+}
+
 #[test]
 fn getpivotdata_returns_values_from_tabular_pivot_output() {
     let mut sheet = TestSheet::new();
@@ -83,10 +83,14 @@ fn simd_aggregate_fast_paths_match_scalar_semantics_for_large_arrays() {
     );
 
     // COUNTIF with numeric criteria coerces bool/blank/text exactly like the scalar criteria
-    // matcher. Here, TRUE counts as 1 (matches >0) while "x" does not.
+    // matcher. Only the numbers are >0; TRUE, "x" and blanks all satisfy <>0.
     assert_number(
         &sheet.eval(&format!("=LET(x,{array_literal},COUNTIF(x,\">0\"))")),
-        256.0,
+        128.0,
+    );
+    assert_number(
+        &sheet.eval(&format!("=LET(x,{array_literal},COUNTIF(x,\"<>0\"))")),
+        512.0,
     );
 }
 
@@ -192,6 +196,48 @@ fn count_counta_countblank() {
     assert_number(&sheet.eval("=COUNTBLANK(A1:A6)"), 2.0);
 }
 
+#[test]
+fn count_treats_direct_logicals_differently_from_referenced_ones() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", true);
+    sheet.set("A2", 1.0);
+
+    // Direct arguments are coerced: logicals and numeric text count.
+    assert_number(&sheet.eval("=COUNT(TRUE, 1)"), 2.0);
+    assert_number(&sheet.eval(r#"=COUNT("1", "1/1/2024", "x")"#), 2.0);
+    // Cells and array elements only count when they hold numbers.
+    assert_number(&sheet.eval("=COUNT(A1:A2)"), 1.0);
+    assert_number(&sheet.eval("=COUNT(A1)"), 0.0);
+    assert_number(&sheet.eval(r#"=COUNT({TRUE,1,"1"})"#), 1.0);
+    // A reference returned by IF is still a reference.
+    assert_number(&sheet.eval("=COUNT(IF(TRUE, A1))"), 0.0);
+    assert_number(&sheet.eval("=COUNT(SEQUENCE(3), TRUE)"), 4.0);
+}
+
+#[test]
+fn count_and_counta_flatten_ranges_arrays_and_unions() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 1.0);
+    sheet.set_formula("A2", "=DATE(2024,1,1)");
+    sheet.set_formula("A3", "=1/0");
+    sheet.set_formula("A4", "=\"\"");
+    sheet.set("A5", Value::Text("text".to_string()));
+    sheet.recalc();
+
+    // Dates are numbers; errors and empty-string results are not.
+    assert_number(&sheet.eval("=COUNT(A1:A6)"), 2.0);
+    // COUNTA counts every non-empty cell, including errors and `""` formula results.
+    assert_number(&sheet.eval("=COUNTA(A1:A6)"), 5.0);
+    assert_number(&sheet.eval("=COUNT(A1:A2, {1,2;3,4}, A1:A3)"), 8.0);
+    assert_number(&sheet.eval(r#"=COUNTA({1,"",TRUE}, A3:A4, H1:H3)"#), 5.0);
+    // Overlapping union areas are counted once per cell.
+    assert_number(&sheet.eval("=COUNT((A1:A2,A2:A3))"), 2.0);
+    assert_number(&sheet.eval("=COUNTA((A1:A3,A3:A5))"), 5.0);
+    // Errors passed directly are ignored by COUNT and counted by COUNTA.
+    assert_number(&sheet.eval(r#"=COUNT(1/0, 1)"#), 1.0);
+    assert_number(&sheet.eval(r#"=COUNTA(1/0, "", FALSE)"#), 3.0);
+}
+
 #[test]
 fn countif_treats_lambda_cells_like_errors() {
     let mut sheet = TestSheet::new();
@@ -109,6 +109,94 @@ fn integer_helpers_match_excel_semantics() {
     assert_number(&sheet.eval("=GESTEP(5,4)"), 1.0);
 }
 
+#[test]
+fn combinatorics_boundaries_match_excel_semantics() {
+    let mut sheet = TestSheet::new();
+
+    // Results stay exact integers while they are representable.
+    assert_eq!(
+        sheet.eval("=COMBIN(40,20)"),
+        Value::Number(137_846_528_820.0)
+    );
+    assert_eq!(
+        sheet.eval("=COMBIN(50,25)"),
+        Value::Number(126_410_606_437_752.0)
+    );
+    assert_eq!(
+        sheet.eval("=MULTINOMIAL(10,10,10)"),
+        Value::Number(5_550_996_791_340.0)
+    );
+    assert_eq!(sheet.eval("=COMBINA(10,3)"), Value::Number(220.0));
+    assert_eq!(sheet.eval("=COMBIN(0,0)"), Value::Number(1.0));
+    assert_eq!(sheet.eval("=COMBINA(0,0)"), Value::Number(1.0));
+    assert_eq!(sheet.eval("=PERMUT(5,0)"), Value::Number(1.0));
+
+    // Non-integer arguments are truncated, like Excel.
+    assert_eq!(sheet.eval("=COMBIN(8.7,2.9)"), Value::Number(28.0));
+    assert_eq!(sheet.eval("=PERMUT(5.9,2.1)"), Value::Number(20.0));
+    assert_eq!(sheet.eval("=FACTDOUBLE(7.9)"), Value::Number(105.0));
+
+    // Overflow past the largest finite double.
+    assert!(matches!(sheet.eval("=FACTDOUBLE(300)"), Value::Number(n) if n > 8.15e307));
+    assert_eq!(sheet.eval("=FACTDOUBLE(301)"), Value::Error(ErrorKind::Num));
+    assert!(matches!(sheet.eval("=COMBIN(1029,514)"), Value::Number(n) if n.is_finite()));
+    assert_eq!(
+        sheet.eval("=COMBIN(1030,515)"),
+        Value::Error(ErrorKind::Num)
+    );
+    assert!(matches!(sheet.eval("=PERMUT(170,170)"), Value::Number(n) if n.is_finite()));
+    assert_eq!(sheet.eval("=PERMUT(171,171)"), Value::Error(ErrorKind::Num));
+    assert_eq!(
+        sheet.eval("=PERMUTATIONA(2,1023)"),
+        Value::Number(2f64.powi(1023))
+    );
+    assert_eq!(
+        sheet.eval("=PERMUTATIONA(2,1024)"),
+        Value::Error(ErrorKind::Num)
+    );
+
+    // Negative arguments and chosen > number.
+    for formula in [
+        "=COMBIN(-1,0)",
+        "=COMBIN(5,-1)",
+        "=COMBINA(-1,2)",
+        "=PERMUT(-5,2)",
+        "=PERMUT(2,3)",
+        "=PERMUTATIONA(3,-1)",
+        "=MULTINOMIAL(2,-1)",
+    ] {
+        assert_eq!(
+            sheet.eval(formula),
+            Value::Error(ErrorKind::Num),
+            "{formula}"
+        );
+    }
+}
+
+#[test]
+fn gcd_and_lcm_reject_arguments_beyond_exact_integers() {
+    let mut sheet = TestSheet::new();
+
+    assert_eq!(sheet.eval("=GCD(2^53-2,6)"), Value::Number(6.0));
+    assert_eq!(sheet.eval("=GCD(2^53,2)"), Value::Error(ErrorKind::Num));
+    assert_eq!(sheet.eval("=LCM(2^53,1)"), Value::Error(ErrorKind::Num));
+    assert_eq!(sheet.eval("=GCD(12.9,8.2)"), Value::Number(4.0));
+    assert_eq!(sheet.eval("=LCM(1.5,2)"), Value::Number(2.0));
+
+    // LCM results must also stay below 2^53.
+    assert_eq!(
+        sheet.eval("=LCM(2^26,2^26+1)"),
+        Value::Number(4_503_599_694_479_360.0)
+    );
+    assert_eq!(sheet.eval("=LCM(2^52,3)"), Value::Error(ErrorKind::Num));
+
+    // Every argument is validated, even once the result is settled.
+    assert_eq!(sheet.eval("=GCD(2,3,-1)"), Value::Error(ErrorKind::Num));
+    assert_eq!(sheet.eval("=LCM(0,-1)"), Value::Error(ErrorKind::Num));
+    assert_eq!(sheet.eval("=LCM(0,2^53)"), Value::Error(ErrorKind::Num));
+    assert_eq!(sheet.eval("=LCM(3,0,5)"), Value::Number(0.0));
+}
+
 #[test]
 fn series_and_sumx_helpers_match_excel_semantics() {
     let mut sheet = TestSheet::new();
@@ -129,6 +217,36 @@ fn series_and_sumx_helpers_match_excel_semantics() {
     assert_number(&sheet.eval("=SUMXMY2({1,\"x\",3},{1,2,3})"), 0.0);
 }
 
+#[test]
+fn sumx_helpers_skip_pairs_with_non_numeric_cells() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 2.0);
+    sheet.set("A2", "x");
+    sheet.set("A3", 5.0);
+    sheet.set("A4", true);
+    sheet.set("A5", 1.0);
+    sheet.set("B1", 1.0);
+    sheet.set("B2", 7.0);
+    sheet.set("B3", 3.0);
+    sheet.set("B4", 4.0);
+    sheet.set("B5", 0.0);
+
+    // Only (2,1), (5,3) and (1,0) contribute; the text, logical and blank rows are dropped.
+    assert_number(&sheet.eval("=SUMX2MY2(A1:A6,B1:B6)"), 20.0);
+    assert_number(&sheet.eval("=SUMX2PY2(A1:A6,B1:B6)"), 40.0);
+    assert_number(&sheet.eval("=SUMXMY2(A1:A6,B1:B6)"), 6.0);
+    assert_eq!(
+        sheet.eval("=SUMXMY2(A1:A5,B1:B4)"),
+        Value::Error(ErrorKind::NA)
+    );
+
+    sheet.set_formula("B5", "=1/0");
+    assert_eq!(
+        sheet.eval("=SUMX2PY2(A1:A5,B1:B5)"),
+        Value::Error(ErrorKind::Div0)
+    );
+}
+
 #[test]
 fn math_more_functions_support_elementwise_spilling() {
     let mut sheet = TestSheet::new();
@@ -88,6 +88,76 @@ fn areas_counts_reference_unions() {
     assert_eq!(sheet.eval("=AREAS(#REF!)"), Value::Error(ErrorKind::Ref));
 }
 
+#[test]
+fn offset_returns_a_reference_sized_by_height_and_width() {
+    let mut sheet = TestSheet::new();
+    for row in 1..=5 {
+        sheet.set(&format!("A{row}"), row as f64);
+        sheet.set(&format!("B{row}"), (row * 10) as f64);
+    }
+
+    assert_number(&sheet.eval("=SUM(OFFSET(A1,0,0,5,1))"), 15.0);
+    assert_number(&sheet.eval("=SUM(OFFSET(A1,1,0,2,2))"), 55.0);
+    assert_number(&sheet.eval("=OFFSET(C3,-2,-1)"), 10.0);
+
+    // The result is a reference (not a materialized array), so reference-only consumers accept
+    // it and see its shape.
+    assert_eq!(sheet.eval("=ISREF(OFFSET(A1,1,1,2,2))"), Value::Bool(true));
+    assert_number(&sheet.eval("=AREAS(OFFSET(A1,1,1,2,2))"), 1.0);
+    assert_number(&sheet.eval("=ROWS(OFFSET(A1,1,1,3,2))"), 3.0);
+    assert_number(&sheet.eval("=COLUMNS(OFFSET(A1,1,1,3,2))"), 2.0);
+    assert_number(&sheet.eval("=ROW(OFFSET(A1,3,0))"), 4.0);
+
+    // Height/width default to the size of the base reference.
+    assert_number(&sheet.eval("=SUM(OFFSET(A1:B2,3,0))"), 99.0);
+}
+
+#[test]
+fn offset_multi_cell_result_spills() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 1.0);
+    sheet.set("A2", 2.0);
+    sheet.set("B1", 3.0);
+    sheet.set("B2", 4.0);
+
+    sheet.set_formula("D1", "=OFFSET(A1,0,0,2,2)");
+    sheet.recalculate();
+
+    assert_number(&sheet.get("D1"), 1.0);
+    assert_number(&sheet.get("E1"), 3.0);
+    assert_number(&sheet.get("D2"), 2.0);
+    assert_number(&sheet.get("E2"), 4.0);
+}
+
+#[test]
+fn offset_off_grid_or_empty_results_are_ref_errors() {
+    let mut sheet = TestSheet::new();
+    sheet.set("A1", 1.0);
+
+    assert_eq!(sheet.eval("=OFFSET(A1,-1,0)"), Value::Error(ErrorKind::Ref));
+    assert_eq!(sheet.eval("=OFFSET(B2,0,-2)"), Value::Error(ErrorKind::Ref));
+    assert_eq!(
+        sheet.eval("=SUM(OFFSET(A3,-3,0,2,1))"),
+        Value::Error(ErrorKind::Ref)
+    );
+    assert_eq!(
+        sheet.eval("=SUM(OFFSET(A1,0,0,0,1))"),
+        Value::Error(ErrorKind::Ref)
+    );
+    assert_eq!(
+        sheet.eval("=SUM(OFFSET(A1,0,0,1,-1))"),
+        Value::Error(ErrorKind::Ref)
+    );
+    assert_eq!(
+        sheet.eval("=OFFSET(A1,1048576,0)"),
+        Value::Error(ErrorKind::Ref)
+    );
+    assert_eq!(
+        sheet.eval("=SUM(OFFSET(A1,0,16383,1,2))"),
+        Value::Error(ErrorKind::Ref)
+    );
+}
+
 #[test]
 fn address_formats_a1_and_r1c1_styles() {
     let mut sheet = TestSheet::new();
@@ -67,3 +67,47 @@ fn arabic_rejects_invalid_numerals() {
         Value::Error(ErrorKind::Value)
     );
 }
+
+#[test]
+fn roman_each_form_of_the_same_number() {
+    let mut sheet = TestSheet::new();
+    for (form, expected) in [
+        ("0", "MCMXCIX"),
+        ("1", "MLMVLIV"),
+        ("2", "MXMIX"),
+        ("3", "MVMIV"),
+        ("4", "MIM"),
+        // Logical forms: TRUE is classic, FALSE is the most simplified.
+        ("TRUE", "MCMXCIX"),
+        ("FALSE", "MIM"),
+    ] {
+        assert_eq!(
+            sheet.eval(&format!("=ROMAN(1999,{form})")),
+            Value::Text(expected.to_string()),
+            "form {form}"
+        );
+    }
+}
+
+#[test]
+fn arabic_round_trips_every_roman_form() {
+    let mut sheet = TestSheet::new();
+    for form in 0..=4 {
+        let formula = format!("=SUM(--(ARABIC(ROMAN(SEQUENCE(3999),{form}))=SEQUENCE(3999)))");
+        assert_number(&sheet.eval(&formula), 3999.0);
+    }
+}
+
+#[test]
+fn arabic_accepts_a_leading_minus_sign() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval("=ARABIC(\"-MMXI\")"), -2011.0);
+    assert_number(&sheet.eval("=ARABIC(\" -mcmxcix \")"), -1999.0);
+    for text in ["-", "--X", "X-", "MMMM", "IM I", "ABC"] {
+        assert_eq!(
+            sheet.eval(&format!("=ARABIC(\"{text}\")")),
+            Value::Error(ErrorKind::Value),
+            "{text}"
+        );
+    }
+}
@@ -82,6 +82,45 @@ fn mode_mult_spills_multiple_modes() {
     assert_eq!(sheet.get("Z3"), Value::Blank);
 }
 
+#[test]
+fn mode_family_reports_ties_in_first_occurrence_order() {
+    let mut sheet = TestSheet::new();
+    assert_number(&sheet.eval("=MODE.SNGL({3,3,1,1})"), 3.0);
+    assert_number(&sheet.eval("=MODE({3,1,1,3})"), 3.0);
+    assert_number(&sheet.eval("=MODE(5,2,2,5,5)"), 5.0);
+    assert_eq!(sheet.eval("=MODE(7)"), Value::Error(ErrorKind::NA));
+
+    // Multi-modal data in a range: 4, 9 and 2 all occur twice; 6 once.
+    for (addr, value) in [
+        ("A1", 4.0),
+        ("A2", 9.0),
+        ("A3", 2.0),
+        ("A4", 9.0),
+        ("A5", 6.0),
+        ("A6", 2.0),
+        ("A7", 4.0),
+    ] {
+        sheet.set(addr, value);
+    }
+    sheet.set("A8", "text");
+    sheet.set_formula("C1", "=MODE.MULT(A1:A8)");
+    sheet.recalc();
+
+    assert_eq!(sheet.get("C1"), Value::Number(4.0));
+    assert_eq!(sheet.get("C2"), Value::Number(9.0));
+    assert_eq!(sheet.get("C3"), Value::Number(2.0));
+    assert_eq!(sheet.get("C4"), Value::Blank);
+    assert_number(&sheet.eval("=MODE.SNGL(A1:A8)"), 4.0);
+    assert_number(&sheet.eval("=ROWS(MODE.MULT(A1:A8))"), 3.0);
+
+    // Fractional values group by exact equality.
+    assert_number(&sheet.eval("=MODE.SNGL({0.5,0.25,0.25,0.5,0.5})"), 0.5);
+    assert_eq!(
+        sheet.eval("=MODE.MULT({1,2,3})"),
+        Value::Error(ErrorKind::NA)
+    );
+}
+
 #[test]
 fn large_small_return_expected_order_stats() {
     let mut sheet = TestSheet::new();
@@ -134,6 +173,77 @@ fn percentile_exc_errors_outside_open_interval() {
     );
 }
 
+#[test]
+fn percentile_and_quartile_inc_vs_exc_on_same_dataset() {
+    let mut sheet = TestSheet::new();
+    let data = "{6,7,15,36,39,40,41,42,43,47,49}";
+
+    // Inclusive: rank = k*(n-1)+1; exclusive: rank = k*(n+1).
+    assert_number(&sheet.eval(&format!("=QUARTILE.INC({data},1)")), 25.5);
+    assert_number(&sheet.eval(&format!("=QUARTILE.EXC({data},1)")), 15.0);
+    assert_number(&sheet.eval(&format!("=QUARTILE.INC({data},3)")), 42.5);
+    assert_number(&sheet.eval(&format!("=QUARTILE.EXC({data},3)")), 43.0);
+    assert_number(&sheet.eval(&format!("=QUARTILE.INC({data},2)")), 40.0);
+    assert_number(&sheet.eval(&format!("=QUARTILE.EXC({data},2)")), 40.0);
+    assert_number(&sheet.eval(&format!("=MEDIAN({data})")), 40.0);
+
+    assert_number(&sheet.eval("=PERCENTILE.INC({1,3,2,4},0.3)"), 1.9);
+    assert_number(&sheet.eval("=PERCENTILE.EXC({1,3,2,4},0.3)"), 1.5);
+    assert_number(
+        &sheet.eval("=PERCENTILE.EXC({1,2,3,6,6,6,7,8,9},0.25)"),
+        2.5,
+    );
+    assert_number(
+        &sheet.eval("=PERCENTILE.INC({1,2,3,6,6,6,7,8,9},0.25)"),
+        3.0,
+    );
+    assert_number(&sheet.eval("=MEDIAN({1,2,3,4})"), 2.5);
+}
+
+#[test]
+fn percentile_and_quartile_exc_boundaries_depend_on_sample_size() {
+    let mut sheet = TestSheet::new();
+    let data = "{1,2,3,4,5,6,7,8,9}";
+
+    // With n=9, PERCENTILE.EXC accepts k in [1/(n+1), n/(n+1)] = [0.1, 0.9].
+    assert_number(&sheet.eval(&format!("=PERCENTILE.EXC({data},0.1)")), 1.0);
+    assert_number(&sheet.eval(&format!("=PERCENTILE.EXC({data},0.9)")), 9.0);
+    assert_eq!(
+        sheet.eval(&format!("=PERCENTILE.EXC({data},0.05)")),
+        Value::Error(ErrorKind::Num)
+    );
+    assert_eq!(
+        sheet.eval(&format!("=PERCENTILE.EXC({data},0.95)")),
+        Value::Error(ErrorKind::Num)
+    );
+    // The inclusive variant accepts the full closed interval.
+    assert_number(&sheet.eval(&format!("=PERCENTILE.INC({data},0)")), 1.0);
+    assert_number(&sheet.eval(&format!("=PERCENTILE.INC({data},1)")), 9.0);
+    assert_eq!(
+        sheet.eval(&format!("=PERCENTILE.INC({data},1.5)")),
+        Value::Error(ErrorKind::Num)
+    );
+
+    // QUARTILE.EXC needs enough points for the quartile's rank to land inside the data.
+    assert_eq!(
+        sheet.eval("=QUARTILE.EXC({1,2},1)"),
+        Value::Error(ErrorKind::Num)
+    );
+    assert_number(&sheet.eval("=QUARTILE.EXC({1,2,3},1)"), 1.0);
+    for quart in [0, 4] {
+        assert_eq!(
+            sheet.eval(&format!("=QUARTILE.EXC({data},{quart})")),
+            Value::Error(ErrorKind::Num)
+        );
+    }
+    assert_number(&sheet.eval(&format!("=QUARTILE.INC({data},0)")), 1.0);
+    assert_number(&sheet.eval(&format!("=QUARTILE.INC({data},4)")), 9.0);
+    assert_eq!(
+        sheet.eval(&format!("=QUARTILE.INC({data},5)")),
+        Value::Error(ErrorKind::Num)
+    );
+}
+
 #[test]
 fn percentrank_inc_and_exc_match_expected_ranks() {
     let mut sheet = TestSheet::new();
@@ -312,3 +312,43 @@ fn confidence_functions_reject_invalid_domains() {
         Value::Error(ErrorKind::Num)
     );
 }
+
+fn assert_close(value: &Value, expected: f64, tol: f64) {
+    match value {
+        Value::Number(n) => assert!(
+            (*n - expected).abs() <= tol,
+            "expected {expected} (±{tol}), got {n}"
+        ),
+        other => panic!("expected number {expected}, got {other:?}"),
+    }
+}
+
+#[test]
+fn distributions_match_excel_documented_values() {
+    let mut sheet = TestSheet::new();
+
+    // Reference values are the (rounded) results published in Excel's function documentation.
+    assert_close(&sheet.eval("=NORM.DIST(42,40,1.5,TRUE)"), 0.9087888, 1e-6);
+    assert_close(&sheet.eval("=NORM.DIST(42,40,1.5,FALSE)"), 0.10934005, 1e-6);
+    assert_close(&sheet.eval("=NORM.INV(0.908789,40,1.5)"), 42.000002, 1e-5);
+    assert_close(
+        &sheet.eval("=NORM.S.DIST(1.333333,TRUE)"),
+        0.908788726,
+        1e-6,
+    );
+    assert_close(&sheet.eval("=NORM.S.DIST(1.333333,FALSE)"), 0.1640101, 1e-6);
+
+    assert_close(&sheet.eval("=T.DIST(60,1,TRUE)"), 0.99469533, 1e-6);
+    assert_close(&sheet.eval("=T.DIST(8,3,FALSE)"), 0.00073691, 1e-6);
+    assert_close(&sheet.eval("=T.INV(0.75,2)"), 0.8164966, 1e-6);
+
+    assert_close(&sheet.eval("=CHISQ.DIST(0.5,1,TRUE)"), 0.52049988, 1e-6);
+    assert_close(&sheet.eval("=CHISQ.DIST(2,3,FALSE)"), 0.20755375, 1e-6);
+
+    assert_close(&sheet.eval("=F.DIST(15.2069,6,4,TRUE)"), 0.99, 1e-6);
+    assert_close(&sheet.eval("=F.DIST(15.2069,6,4,FALSE)"), 0.0012238, 1e-6);
+
+    assert_close(&sheet.eval("=BINOM.DIST(6,10,0.5,FALSE)"), 0.2050781, 1e-6);
+    assert_close(&sheet.eval("=POISSON.DIST(2,5,TRUE)"), 0.124652, 1e-6);
+    assert_close(&sheet.eval("=POISSON.DIST(2,5,FALSE)"), 0.084224, 1e-6);
+}
@@ -202,6 +202,94 @@ fn linest_stats_true_spills_5_rows() {
     assert_number_close(engine.get_cell_value("Sheet1", "E5"), 0.0);
 }
 
+#[test]
+fn linest_full_stats_block_matches_regression_helpers_on_noisy_data() {
+    let mut engine = Engine::new();
+
+    for (i, (x, y)) in [
+        (1.0, 2.0),
+        (2.0, 4.0),
+        (3.0, 5.0),
+        (4.0, 4.0),
+        (5.0, 5.0),
+        (6.0, 7.0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let row = i + 1;
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), y)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), x)
+            .unwrap();
+    }
+
+    engine
+        .set_cell_formula("Sheet1", "D1", "=LINEST(A1:A6,B1:B6,TRUE,TRUE)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "G1", "=SLOPE(A1:A6,B1:B6)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "G2", "=INTERCEPT(A1:A6,B1:B6)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "G3", "=RSQ(A1:A6,B1:B6)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "G4", "=STEYX(A1:A6,B1:B6)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "G5", "=FORECAST.LINEAR(7,A1:A6,B1:B6)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "H1", "=TREND(A1:A6,B1:B6,{7;8})")
+        .unwrap();
+
+    engine.recalculate_single_threaded();
+
+    let (start, end) = engine.spill_range("Sheet1", "D1").expect("spill range");
+    assert_eq!(start, parse_a1("D1").unwrap());
+    assert_eq!(end, parse_a1("E5").unwrap());
+
+    let slope = 13.5 / 17.5;
+    let intercept = 1.8;
+    let ssresid = 3.0857142857142863;
+    let ssreg = 10.414285714285715;
+    let steyx = (ssresid / 4.0_f64).sqrt();
+    let expected = [
+        ("D1", slope),
+        ("E1", intercept),
+        // Standard errors of the slope and intercept.
+        ("D2", steyx / 17.5_f64.sqrt()),
+        ("E2", steyx * (1.0 / 6.0 + 3.5 * 3.5 / 17.5_f64).sqrt()),
+        // R^2 and the standard error of the y estimate.
+        ("D3", ssreg / (ssreg + ssresid)),
+        ("E3", steyx),
+        // F statistic and degrees of freedom.
+        ("D4", ssreg / (ssresid / 4.0)),
+        ("E4", 4.0),
+        ("D5", ssreg),
+        ("E5", ssresid),
+    ];
+    for (addr, value) in expected {
+        assert_number_close(engine.get_cell_value("Sheet1", addr), value);
+    }
+
+    assert_number_close(engine.get_cell_value("Sheet1", "G1"), slope);
+    assert_number_close(engine.get_cell_value("Sheet1", "G2"), intercept);
+    assert_number_close(
+        engine.get_cell_value("Sheet1", "G3"),
+        ssreg / (ssreg + ssresid),
+    );
+    assert_number_close(engine.get_cell_value("Sheet1", "G4"), steyx);
+    assert_number_close(engine.get_cell_value("Sheet1", "G5"), 7.2);
+    assert_number_close(engine.get_cell_value("Sheet1", "H1"), 7.2);
+    assert_number_close(engine.get_cell_value("Sheet1", "H2"), 7.9714285714285715);
+}
+
 #[test]
 fn logest_and_growth_simple_exponential() {
     let mut engine = Engine::new();
@@ -439,6 +527,51 @@ fn logest_errors_on_nonpositive_y() {
     assert_error(engine.get_cell_value("Sheet1", "D2"), ErrorKind::Num);
 }
 
+#[test]
+fn logest_and_growth_multi_x_two_predictors() {
+    let mut engine = Engine::new();
+
+    // y = 2 * 3^x1 * 0.5^x2
+    // rows: (x1, x2)
+    let data = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0), (2.0, 3.0)];
+    for (i, (x1, x2)) in data.into_iter().enumerate() {
+        let row = i + 1;
+        let y = 2.0 * 3.0_f64.powf(x1) * 0.5_f64.powf(x2);
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), y)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("B{row}"), x1)
+            .unwrap();
+        engine
+            .set_cell_value("Sheet1", &format!("C{row}"), x2)
+            .unwrap();
+    }
+
+    engine
+        .set_cell_formula("Sheet1", "E1", "=LOGEST(A1:A5,B1:C5)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "E3", "=GROWTH(A1:A5,B1:C5,{2,2;3,0})")
+        .unwrap();
+
+    engine.recalculate_single_threaded();
+
+    // Like LINEST, bases come back in reverse predictor order: m2, m1, b.
+    let (start, end) = engine.spill_range("Sheet1", "E1").expect("spill range");
+    assert_eq!(start, parse_a1("E1").unwrap());
+    assert_eq!(end, parse_a1("G1").unwrap());
+    assert_number_close(engine.get_cell_value("Sheet1", "E1"), 0.5);
+    assert_number_close(engine.get_cell_value("Sheet1", "F1"), 3.0);
+    assert_number_close(engine.get_cell_value("Sheet1", "G1"), 2.0);
+
+    let (start, end) = engine.spill_range("Sheet1", "E3").expect("spill range");
+    assert_eq!(start, parse_a1("E3").unwrap());
+    assert_eq!(end, parse_a1("E4").unwrap());
+    assert_number_close(engine.get_cell_value("Sheet1", "E3"), 4.5);
+    assert_number_close(engine.get_cell_value("Sheet1", "E4"), 54.0);
+}
+
 #[test]
 fn linest_multi_x_two_predictors() {
     let mut engine = Engine::new();
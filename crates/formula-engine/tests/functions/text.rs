@@ -16,7 +16,14 @@ fn exact_is_case_sensitive() {
 #[test]
 fn clean_strips_control_codes() {
     let input = "a\u{0000}\u{0009}b\u{001F}c\u{007F}d";
-    assert_eq!(text::clean(input).unwrap(), "abcd");
+    assert_eq!(text::clean(input).unwrap(), "abc\u{007F}d");
+}
+
+#[test]
+fn clean_keeps_del_c1_controls_and_non_breaking_spaces() {
+    // Excel's CLEAN only removes codes 0-31; DEL, C1 controls and NBSP survive.
+    let input = "\r\na\tb\u{007F}c\u{0081}d\u{00A0}e\u{0007}";
+    assert_eq!(text::clean(input).unwrap(), "ab\u{007F}c\u{0081}d\u{00A0}e");
 }
 
 #[test]
@@ -161,6 +168,35 @@ fn text_formats_numbers_with_simple_patterns() {
     );
 }
 
+#[test]
+fn text_locale_tags_select_month_and_day_names() {
+    let mut sheet = TestSheet::new();
+    sheet.set_formula("A1", "=DATE(2024,3,5)");
+    sheet.recalc();
+
+    assert_eq!(
+        sheet.eval(r#"=TEXT(A1,"[$-409]mmmm")"#),
+        Value::Text("March".to_string())
+    );
+    assert_eq!(
+        sheet.eval(r#"=TEXT(A1,"[$-407]mmmm")"#),
+        Value::Text("März".to_string())
+    );
+    assert_eq!(
+        sheet.eval(r#"=TEXT(A1,"[$-407]mmm")"#),
+        Value::Text("Mrz".to_string())
+    );
+    assert_eq!(
+        sheet.eval(r#"=TEXT(A1,"[$-407]dddd, d. mmmm yyyy")"#),
+        Value::Text("Dienstag, 5. März 2024".to_string())
+    );
+    // Without a locale tag, names stay English.
+    assert_eq!(
+        sheet.eval(r#"=TEXT(A1,"dddd mmmm")"#),
+        Value::Text("Tuesday March".to_string())
+    );
+}
+
 #[test]
 fn text_formats_entity_display_string() {
     let mut sheet = TestSheet::new();
@@ -389,6 +425,20 @@ fn trim_upper_lower() {
         sheet.eval("=TRIM(\"\ta  b\")"),
         Value::Text("\ta b".to_string())
     );
+    // Only U+0020 is trimmed: tabs, line breaks and non-breaking spaces are kept as-is.
+    sheet.set(
+        "A1",
+        Value::Text(" \u{00A0} a \t\t b\n  c \u{00A0}".to_string()),
+    );
+    assert_eq!(
+        sheet.eval("=TRIM(A1)"),
+        Value::Text("\u{00A0} a \t\t b\n c \u{00A0}".to_string())
+    );
+    assert_eq!(
+        sheet.eval("=TRIM(CLEAN(A1))"),
+        Value::Text("\u{00A0} a b c \u{00A0}".to_string())
+    );
+    assert_eq!(sheet.eval("=TRIM(\"     \")"), Value::Text(String::new()));
     assert_eq!(
         sheet.eval("=UPPER(\"Abc\")"),
         Value::Text("ABC".to_string())
@@ -760,3 +810,60 @@ fn value_spills_singleton_array_literal() {
         Some((parse_a1("A1").unwrap(), parse_a1("A1").unwrap()))
     );
 }
+
+#[test]
+fn char_and_code_use_the_default_1252_codepage() {
+    let mut sheet = TestSheet::new();
+    assert_eq!(sheet.eval("=CHAR(65)"), Value::Text("A".to_string()));
+    assert_eq!(sheet.eval("=CHAR(65.9)"), Value::Text("A".to_string()));
+    assert_eq!(sheet.eval("=CHAR(128)"), Value::Text("€".to_string()));
+    assert_eq!(sheet.eval("=CHAR(233)"), Value::Text("é".to_string()));
+    assert_eq!(sheet.eval("=CHAR(0)"), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=CHAR(256)"), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=CHAR(-1)"), Value::Error(ErrorKind::Value));
+
+    assert_eq!(sheet.eval(r#"=CODE("Apple")"#), Value::Number(65.0));
+    assert_eq!(sheet.eval(r#"=CODE("€")"#), Value::Number(128.0));
+    assert_eq!(sheet.eval("=CODE(CHAR(233))"), Value::Number(233.0));
+    // Characters outside the codepage report `?`.
+    assert_eq!(sheet.eval(r#"=CODE("漢")"#), Value::Number(63.0));
+    assert_eq!(sheet.eval(r#"=CODE("")"#), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=CODE(1)"), Value::Number(49.0));
+}
+
+#[test]
+fn unichar_and_unicode_use_code_points() {
+    let mut sheet = TestSheet::new();
+    assert_eq!(sheet.eval("=UNICHAR(66)"), Value::Text("B".to_string()));
+    assert_eq!(sheet.eval("=UNICHAR(8364)"), Value::Text("€".to_string()));
+    assert_eq!(sheet.eval(r#"=UNICODE("€")"#), Value::Number(8364.0));
+    assert_eq!(sheet.eval(r#"=UNICODE("漢字")"#), Value::Number(28450.0));
+
+    // Non-BMP code points become a single character (a surrogate pair in UTF-16).
+    let Value::Text(emoji) = sheet.eval("=UNICHAR(128512)") else {
+        panic!("expected text from UNICHAR");
+    };
+    assert_eq!(emoji, "😀");
+    assert_eq!(emoji.encode_utf16().collect::<Vec<_>>(), [0xD83D, 0xDE00]);
+    assert_eq!(
+        sheet.eval("=UNICODE(UNICHAR(128512))"),
+        Value::Number(128512.0)
+    );
+    assert_eq!(sheet.eval(r#"=UNICODE("😀x")"#), Value::Number(128512.0));
+
+    assert_eq!(sheet.eval("=UNICHAR(0)"), Value::Error(ErrorKind::Value));
+    assert_eq!(
+        sheet.eval("=UNICHAR(1114112)"),
+        Value::Error(ErrorKind::Value)
+    );
+    assert_eq!(sheet.eval("=UNICHAR(55357)"), Value::Error(ErrorKind::NA));
+    assert_eq!(
+        sheet.eval(r#"=UNICODE("")"#),
+        Value::Error(ErrorKind::Value)
+    );
+
+    // Unlike CHAR/CODE, these ignore the legacy codepage.
+    sheet.set_text_codepage(932);
+    assert_eq!(sheet.eval("=UNICHAR(233)"), Value::Text("é".to_string()));
+    assert_eq!(sheet.eval(r#"=UNICODE("あ")"#), Value::Number(12354.0));
+}
@@ -1,4 +1,4 @@
-use formula_engine::locale::ValueLocaleConfig;
+use formula_engine::locale::{self, ValueLocaleConfig};
 use formula_engine::{EditOp, ErrorKind, Value};
 use formula_model::{CellRef, Range};
 
@@ -257,6 +257,58 @@ fn lenb_counts_bytes_for_chinese_korean_and_big5_dbcs_codepages() {
     assert_eq!(sheet.eval(r#"=LENB("A國")"#), Value::Number(3.0));
 }
 
+#[test]
+fn asc_and_jis_convert_cell_and_array_inputs_under_cp932() {
+    let mut sheet = TestSheet::new();
+    sheet.set_text_codepage(932);
+    sheet.set("A1", "２０２４年１２月");
+    sheet.set("A2", "ﾃﾞｰﾀ ﾍﾞｰｽ");
+
+    assert_eq!(
+        sheet.eval("=ASC(A1)"),
+        Value::Text("2024年12月".to_string())
+    );
+    assert_eq!(
+        sheet.eval("=DBCS(A2)"),
+        Value::Text("データ　ベース".to_string())
+    );
+    assert_eq!(sheet.eval(r#"=ASC(DBCS(A2))=A2"#), Value::Bool(true));
+    assert_eq!(
+        sheet.eval(r#"=INDEX(ASC({"１","ｶﾞ";"Ａ","パ"}),2,2)"#),
+        Value::Text("ﾊﾟ".to_string())
+    );
+
+    // `JIS` is the localized spelling of `DBCS` (e.g. in de-DE), not a separate function.
+    let canonical = locale::canonicalize_formula("=JIS(A2)", &locale::DE_DE).unwrap();
+    assert_eq!(canonical, "=DBCS(A2)");
+    assert_eq!(
+        sheet.eval(&canonical),
+        Value::Text("データ　ベース".to_string())
+    );
+}
+
+#[test]
+fn char_and_code_use_shift_jis_codes_under_cp932() {
+    let mut sheet = TestSheet::new();
+    sheet.set_text_codepage(932);
+
+    assert_eq!(sheet.eval("=CHAR(65)"), Value::Text("A".to_string()));
+    // Single-byte halfwidth katakana.
+    assert_eq!(sheet.eval("=CHAR(177)"), Value::Text("ｱ".to_string()));
+    assert_eq!(sheet.eval(r#"=CODE("ｱ")"#), Value::Number(177.0));
+    // Double-byte characters use the lead/trail byte pair.
+    assert_eq!(sheet.eval("=CHAR(33440)"), Value::Text("あ".to_string()));
+    assert_eq!(sheet.eval(r#"=CODE("あいう")"#), Value::Number(33440.0));
+    assert_eq!(sheet.eval(r#"=CODE("Ａ")"#), Value::Number(33376.0));
+    // A lone lead byte is not a character.
+    assert_eq!(sheet.eval("=CHAR(130)"), Value::Error(ErrorKind::Value));
+    assert_eq!(sheet.eval("=CHAR(65536)"), Value::Error(ErrorKind::Value));
+
+    // Single-byte codepages reject double-byte codes.
+    sheet.set_text_codepage(1252);
+    assert_eq!(sheet.eval("=CHAR(33440)"), Value::Error(ErrorKind::Value));
+}
+
 #[test]
 fn asc_and_dbcs_convert_ascii_under_other_dbcs_codepages() {
     let mut sheet = TestSheet::new();
@@ -402,17 +454,26 @@ fn phonetic_reads_cell_metadata_or_falls_back_to_text() {
 }
 
 #[test]
-fn phonetic_spills_over_range_references() {
+fn phonetic_concatenates_range_references() {
     let mut sheet = TestSheet::new();
-    sheet.set("A1", "abc");
-    sheet.set("A2", "def");
-    sheet.set_phonetic("A1", Some("あびし"));
+    sheet.set("A1", "東京");
+    sheet.set("A2", "abc");
+    sheet.set("A3", 42.0);
+    sheet.set("B1", "大阪");
+    sheet.set_phonetic("A1", Some("とうきょう"));
+    sheet.set_phonetic("B1", Some("おおさか"));
 
+    // Row-major: furigana where present, plain text otherwise; numbers and blanks are skipped.
     assert_eq!(
-        sheet.eval("=PHONETIC(A1:A2)"),
-        Value::Text("あびし".to_string())
+        sheet.eval("=PHONETIC(A1:B3)"),
+        Value::Text("とうきょうおおさかabc".to_string())
     );
-    assert_eq!(sheet.get("Z2"), Value::Text("def".to_string()));
+    assert_eq!(sheet.get("Z2"), Value::Blank);
+
+    assert_eq!(sheet.eval("=PHONETIC(C1:D2)"), Value::Text(String::new()));
+
+    sheet.set("B2", Value::Error(ErrorKind::NA));
+    assert_eq!(sheet.eval("=PHONETIC(A1:B3)"), Value::Error(ErrorKind::NA));
 }
 
 #[test]
@@ -1,3 +1,4 @@
+use formula_engine::calc_settings::CalculationMode;
 use formula_engine::{Engine, EngineInfo, ErrorKind, Value};
 
 #[test]
@@ -124,3 +125,74 @@ fn info_origin_is_per_sheet_and_absolute() {
         Value::Text("$B$2".to_string())
     );
 }
+
+#[test]
+fn info_and_cell_follow_workbook_metadata_changes() {
+    let mut engine = Engine::new();
+    for (addr, formula) in [
+        ("A1", r#"=INFO("numfile")"#),
+        ("A2", r#"=INFO("recalc")"#),
+        ("A3", r#"=INFO("release")"#),
+        ("A4", r#"=CELL("filename")"#),
+        ("A5", r#"=INFO("directory")"#),
+    ] {
+        engine.set_cell_formula("Sheet1", addr, formula).unwrap();
+    }
+
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(1.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A2"),
+        Value::Text("Manual".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A3"),
+        Value::Error(ErrorKind::NA)
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A4"),
+        Value::Text(String::new())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A5"),
+        Value::Error(ErrorKind::NA)
+    );
+
+    engine.ensure_sheet("Data");
+    let mut settings = engine.calc_settings().clone();
+    settings.calculation_mode = CalculationMode::Automatic;
+    engine.set_calc_settings(settings);
+    engine.set_engine_info(EngineInfo {
+        release: Some("16.0".to_string()),
+        ..EngineInfo::default()
+    });
+    engine.set_workbook_file_metadata(Some("/books"), Some("Budget.xlsx"));
+
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(2.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A2"),
+        Value::Text("Automatic".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A3"),
+        Value::Text("16.0".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A4"),
+        Value::Text("/books/[Budget.xlsx]Sheet1".to_string())
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A5"),
+        Value::Text("/books/".to_string())
+    );
+
+    assert!(engine.rename_sheet("Sheet1", "Summary"));
+    engine.delete_sheet("Data").unwrap();
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Summary", "A1"), Value::Number(1.0));
+    assert_eq!(
+        engine.get_cell_value("Summary", "A4"),
+        Value::Text("/books/[Budget.xlsx]Summary".to_string())
+    );
+}
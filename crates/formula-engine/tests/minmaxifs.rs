@@ -49,7 +49,7 @@ fn minifs_numeric_criteria_does_not_treat_text_as_zero() {
 
     engine.set_cell_value("Sheet1", "A1", "x").unwrap();
     engine.set_cell_value("Sheet1", "A2", 0.0).unwrap();
-    // A3 left unset (blank) -> treated as 0 for numeric criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     engine.set_cell_value("Sheet1", "B1", 1.0).unwrap();
     engine.set_cell_value("Sheet1", "B2", 10.0).unwrap();
@@ -72,7 +72,7 @@ fn maxifs_numeric_criteria_does_not_treat_text_as_zero() {
 
     engine.set_cell_value("Sheet1", "A1", "x").unwrap();
     engine.set_cell_value("Sheet1", "A2", 0.0).unwrap();
-    // A3 left unset (blank) -> treated as 0 for numeric criteria.
+    // A3 left unset (blank) -> not 0 either.
 
     engine.set_cell_value("Sheet1", "B1", 100.0).unwrap();
     engine.set_cell_value("Sheet1", "B2", 10.0).unwrap();
@@ -86,7 +86,7 @@ fn maxifs_numeric_criteria_does_not_treat_text_as_zero() {
         "expected MAXIFS formula to compile to bytecode for this test"
     );
     engine.recalculate();
-    assert_eq!(engine.get_cell_value("Sheet1", "Z1"), Value::Number(20.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "Z1"), Value::Number(10.0));
 }
 
 #[test]
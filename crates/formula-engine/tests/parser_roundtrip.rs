@@ -42,6 +42,45 @@ fn roundtrip_with_path_qualified_external_ref_in_quoted_sheet_prefix() {
     roundtrip("='C:\\path\\[Book.xlsx]Sheet1'!A1+1", opts, ser);
 }
 
+#[test]
+fn sheet_absolute_markers_parse_and_serialize_unchanged() {
+    use formula_engine::{Expr, SheetRef};
+
+    for formula in [
+        "=$Sheet1!A1",
+        "=SUM($Sheet1!A1:B2)",
+        "=[Book.xlsx]$Sheet1!$A$1+1",
+        "=[1]$Sheet1!A1",
+        "=[Book.xlsx]$Sheet1:$Sheet3!A1",
+        "='C:\\path\\[Book.xlsx]$Sheet1'!A1",
+    ] {
+        let ast = parse_formula(formula, ParseOptions::default()).unwrap();
+        assert_eq!(
+            ast.to_string(SerializeOptions::default()).unwrap(),
+            formula,
+            "formula `{formula}`"
+        );
+        roundtrip(
+            formula,
+            ParseOptions::default(),
+            SerializeOptions::default(),
+        );
+    }
+
+    // The marker stays on the sheet name; path-qualified refs still split off the workbook rather
+    // than being misread as a `C:...` sheet span.
+    let ast = parse_formula(
+        "='C:\\path\\[Book.xlsx]$Sheet1'!A1",
+        ParseOptions::default(),
+    )
+    .unwrap();
+    let Expr::CellRef(r) = ast.expr else {
+        panic!("expected cell ref, got {:?}", ast.expr);
+    };
+    assert_eq!(r.workbook.as_deref(), Some("C:\\path\\Book.xlsx"));
+    assert_eq!(r.sheet, Some(SheetRef::Sheet("$Sheet1".to_string())));
+}
+
 #[test]
 fn roundtrip_with_path_qualified_external_ref_with_brackets_in_path_prefix() {
     let opts = ParseOptions::default();
@@ -14,6 +14,7 @@ fn applies_date_values_as_serial_numbers_with_date_format_for_1900_and_1904_syst
             vec![PivotValue::Text("Date".to_string())],
             vec![PivotValue::Date(date)],
         ],
+        ..PivotResult::default()
     };
     let cfg = PivotConfig {
         row_fields: vec![PivotField::new("Date")],
@@ -90,6 +91,7 @@ fn applies_value_field_number_format_when_present() {
                 PivotValue::Number(250.0),
             ],
         ],
+        ..PivotResult::default()
     };
 
     let cfg = PivotConfig {
@@ -150,6 +152,7 @@ fn applies_percent_format_for_percent_show_as_when_no_explicit_format() {
                 PivotValue::Number(0.5),
             ],
         ],
+        ..PivotResult::default()
     };
 
     let cfg = PivotConfig {
@@ -329,3 +329,42 @@ fn precision_as_displayed_rounds_spilled_arrays_using_spill_origin_format() {
     assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(1.24));
     assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(2.35));
 }
+
+#[test]
+fn toggling_full_precision_changes_sum_by_rounding_residue() {
+    let mut engine = Engine::new();
+    for row in 1..=3 {
+        let addr = format!("A{row}");
+        engine
+            .set_cell_number_format("Sheet1", &addr, Some("0.00".to_string()))
+            .unwrap();
+        engine.set_cell_formula("Sheet1", &addr, "=1/3").unwrap();
+    }
+    engine
+        .set_cell_formula("Sheet1", "B1", "=SUM(A1:A3)")
+        .unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(1.0));
+    assert_eq!(engine.cell_display_text("Sheet1", "A1").unwrap(), "0.33");
+
+    let mut settings: CalcSettings = engine.calc_settings().clone();
+    settings.full_precision = false;
+    engine.set_calc_settings(settings.clone());
+    engine.recalculate();
+
+    // Each addend now stores exactly what it displays, so the total drops by the residue.
+    for row in 1..=3 {
+        let addr = format!("A{row}");
+        let displayed = engine.cell_display_text("Sheet1", &addr).unwrap();
+        assert_eq!(
+            engine.get_cell_value("Sheet1", &addr),
+            Value::Number(displayed.parse().unwrap())
+        );
+    }
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(0.99));
+
+    settings.full_precision = true;
+    engine.set_calc_settings(settings);
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(1.0));
+}
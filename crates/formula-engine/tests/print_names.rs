@@ -0,0 +1,84 @@
+use formula_engine::{Engine, EngineError, NameDefinition, NameScope};
+use formula_model::{ColRange, PrintTitles, Range, RowRange};
+
+#[test]
+fn imported_print_titles_are_read_back_as_typed_ranges() {
+    let mut engine = Engine::new();
+    engine.ensure_sheet("My Sheet");
+    // Importers store the reserved names verbatim, including Excel's casing quirks.
+    engine
+        .define_name(
+            "_XLNM.Print_Titles",
+            NameScope::Sheet("My Sheet"),
+            NameDefinition::Reference("'My Sheet'!$1:$2,'My Sheet'!$A:$B".to_string()),
+        )
+        .unwrap();
+
+    assert_eq!(
+        engine.print_titles("my sheet"),
+        Some(PrintTitles {
+            repeat_rows: Some(RowRange { start: 0, end: 1 }),
+            repeat_cols: Some(ColRange { start: 0, end: 1 }),
+        })
+    );
+    assert_eq!(engine.print_area("My Sheet"), None);
+}
+
+#[test]
+fn print_name_setters_write_canonical_definitions() {
+    let mut engine = Engine::new();
+    engine.ensure_sheet("My Sheet");
+
+    let area = [
+        Range::from_a1("A1:D20").unwrap(),
+        Range::from_a1("F5").unwrap(),
+    ];
+    engine.set_print_area("My Sheet", Some(&area)).unwrap();
+    engine
+        .set_print_titles(
+            "My Sheet",
+            Some(PrintTitles {
+                repeat_rows: Some(RowRange { start: 2, end: 0 }),
+                repeat_cols: Some(ColRange { start: 2, end: 2 }),
+            }),
+        )
+        .unwrap();
+
+    assert_eq!(
+        engine.get_name(formula_model::XLNM_PRINT_AREA, NameScope::Sheet("My Sheet")),
+        Some(&NameDefinition::Reference(
+            "'My Sheet'!$A$1:$D$20,'My Sheet'!$F$5".to_string()
+        ))
+    );
+    assert_eq!(
+        engine.get_name(
+            formula_model::XLNM_PRINT_TITLES,
+            NameScope::Sheet("My Sheet")
+        ),
+        Some(&NameDefinition::Reference(
+            "'My Sheet'!$1:$3,'My Sheet'!$C:$C".to_string()
+        ))
+    );
+    assert_eq!(engine.print_area("My Sheet"), Some(area.to_vec()));
+
+    engine.set_print_area("My Sheet", None).unwrap();
+    engine.set_print_titles("My Sheet", None).unwrap();
+    assert_eq!(engine.print_area("My Sheet"), None);
+    assert_eq!(engine.print_titles("My Sheet"), None);
+}
+
+#[test]
+fn print_names_must_be_sheet_scoped() {
+    let mut engine = Engine::new();
+    engine.ensure_sheet("Sheet1");
+
+    let err = engine
+        .define_name(
+            "_xlnm.print_area",
+            NameScope::Workbook,
+            NameDefinition::Reference("Sheet1!$A$1:$B$2".to_string()),
+        )
+        .unwrap_err();
+    assert!(matches!(err, EngineError::BuiltinNameRequiresSheetScope(_)));
+    assert_eq!(engine.print_area("Sheet1"), None);
+}
@@ -1,6 +1,6 @@
 use formula_engine::eval::CellAddr;
 use formula_engine::{Engine, ErrorKind, ExternalValueProvider, Value};
-use formula_model::Range;
+use formula_model::{CellRef, Range};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
@@ -115,3 +115,40 @@ fn get_range_values_queries_external_provider_for_missing_cells() {
     );
     assert_eq!(provider.calls.load(Ordering::SeqCst), 4);
 }
+
+#[test]
+fn get_cells_values_reads_arbitrary_cells_in_order() {
+    let mut engine = Engine::new();
+    engine.set_sheet_dimensions("Sheet1", 10, 10).unwrap(); // A1:J10
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+    engine.set_cell_value("Sheet1", "D4", "hello").unwrap();
+    engine
+        .set_cell_formula("Sheet1", "F1", "=SEQUENCE(3)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    let cells = [
+        CellRef::new(3, 3),  // D4
+        CellRef::new(0, 0),  // A1
+        CellRef::new(5, 5),  // F6 (unset)
+        CellRef::new(2, 5),  // F3 (spilled)
+        CellRef::new(0, 0),  // A1 again
+        CellRef::new(0, 12), // M1 (out of bounds)
+    ];
+    assert_eq!(
+        engine.get_cells_values("Sheet1", &cells),
+        vec![
+            Value::Text("hello".to_string()),
+            Value::Number(1.0),
+            Value::Blank,
+            Value::Number(3.0),
+            Value::Number(1.0),
+            Value::Error(ErrorKind::Ref),
+        ]
+    );
+
+    assert_eq!(
+        engine.get_cells_values("Missing", &cells[..2]),
+        vec![Value::Blank, Value::Blank]
+    );
+}
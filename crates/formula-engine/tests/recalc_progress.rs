@@ -0,0 +1,68 @@
+use formula_engine::{Engine, RecalcProgress, Value, RECALC_PROGRESS_INTERVAL};
+
+fn collect_progress(engine: &mut Engine) -> Vec<RecalcProgress> {
+    let mut reports = Vec::new();
+    engine.recalculate_with_value_changes_and_progress(&mut |p| reports.push(p));
+    reports
+}
+
+fn assert_monotonic(reports: &[RecalcProgress]) {
+    let last = reports.last().expect("final progress report");
+    assert_eq!(last.done, last.total);
+    for pair in reports.windows(2) {
+        assert!(pair[0].done <= pair[1].done, "{reports:?}");
+        assert!(pair[0].total <= pair[1].total, "{reports:?}");
+    }
+    for p in reports {
+        assert!(p.done <= p.total, "{p:?}");
+    }
+}
+
+#[test]
+fn chain_recalc_reports_periodic_progress() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+    let rows = 3 * RECALC_PROGRESS_INTERVAL + 10;
+    for row in 2..=rows {
+        engine
+            .set_cell_formula("Sheet1", &format!("A{row}"), &format!("=A{}+1", row - 1))
+            .unwrap();
+    }
+
+    let reports = collect_progress(&mut engine);
+    assert_monotonic(&reports);
+    assert!(reports.len() >= 3, "{reports:?}");
+    assert_eq!(reports.last().unwrap().total, rows - 1);
+    assert_eq!(
+        engine.get_cell_value("Sheet1", &format!("A{rows}")),
+        Value::Number(rows as f64)
+    );
+}
+
+#[test]
+fn range_formulas_report_progress_through_level_schedule() {
+    let mut engine = Engine::new();
+    for row in 1..=RECALC_PROGRESS_INTERVAL * 2 {
+        engine
+            .set_cell_formula("Sheet1", &format!("B{row}"), "=SUM(A1:A10)")
+            .unwrap();
+    }
+    engine.recalculate_single_threaded();
+    engine.set_cell_value("Sheet1", "A1", 5.0).unwrap();
+
+    let reports = collect_progress(&mut engine);
+    assert_monotonic(&reports);
+    assert_eq!(reports.last().unwrap().total, RECALC_PROGRESS_INTERVAL * 2);
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(5.0));
+}
+
+#[test]
+fn clean_workbook_reports_single_final_progress() {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+    engine.set_cell_formula("Sheet1", "B1", "=A1*2").unwrap();
+    engine.recalculate_single_threaded();
+
+    let reports = collect_progress(&mut engine);
+    assert_eq!(reports, vec![RecalcProgress { done: 0, total: 0 }]);
+}
@@ -1,6 +1,6 @@
 use formula_engine::eval::CellAddr;
 use formula_engine::{Engine, ErrorKind, NameDefinition, NameScope, PrecedentNode, Value};
-use formula_model::{EXCEL_MAX_COLS, EXCEL_MAX_ROWS};
+use formula_model::{GridLimits, EXCEL_MAX_COLS, EXCEL_MAX_ROWS, MAX_GRID_COLS};
 
 #[test]
 fn sheet_dimensions_affect_full_column_rows() {
@@ -344,3 +344,68 @@ fn precedents_clamp_external_whole_row_and_column_to_excel_dimensions() {
         }]
     );
 }
+
+#[test]
+fn excel_grid_limits_are_the_default() {
+    let mut engine = Engine::new();
+    assert_eq!(engine.grid_limits(), GridLimits::EXCEL);
+
+    assert!(engine.set_cell_value("Sheet1", "XFE1", 1.0).is_err());
+    assert!(engine
+        .set_sheet_dimensions("Sheet1", 10, EXCEL_MAX_COLS + 1)
+        .is_err());
+    assert_eq!(
+        engine.sheet_dimensions("Sheet1"),
+        Some((EXCEL_MAX_ROWS, EXCEL_MAX_COLS))
+    );
+
+    engine.set_cell_formula("Sheet1", "A1", "=XFE1").unwrap();
+    engine.recalculate();
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Error(ErrorKind::Ref)
+    );
+}
+
+#[test]
+fn big_grid_limits_allow_addressing_beyond_excel_bounds() {
+    let mut engine = Engine::new();
+    let limits = GridLimits {
+        max_rows: 4_000_000,
+        max_cols: MAX_GRID_COLS,
+    };
+    engine.set_grid_limits(limits).unwrap();
+
+    // New sheets pick up the configured grid.
+    engine.set_cell_value("Sheet1", "XFE3000000", 5.0).unwrap();
+    assert_eq!(
+        engine.sheet_dimensions("Sheet1"),
+        Some((4_000_000, MAX_GRID_COLS))
+    );
+    engine
+        .set_cell_formula("Sheet1", "A1", "=XFE3000000*2")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "A2", "=COLUMNS(1:1)")
+        .unwrap();
+    engine.recalculate();
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(10.0));
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A2"),
+        Value::Number(f64::from(MAX_GRID_COLS))
+    );
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "XFE3000000"),
+        Value::Number(5.0)
+    );
+
+    // Columns are capped at `ZZZ`, and the limit cannot shrink below existing sheets.
+    assert!(engine
+        .set_grid_limits(GridLimits {
+            max_rows: 10,
+            max_cols: MAX_GRID_COLS + 1,
+        })
+        .is_err());
+    assert!(engine.set_grid_limits(GridLimits::EXCEL).is_err());
+    assert_eq!(engine.grid_limits(), limits);
+}
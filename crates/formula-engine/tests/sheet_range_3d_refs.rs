@@ -720,6 +720,6 @@ fn bytecode_compiles_countif_over_sheet_range_area_ref_and_matches_ast() {
 
     assert_eq!(bytecode_value, ast_value);
     // `A1` on each sheet is text and should not be coerced to 0 for numeric COUNTIF criteria.
-    // Blanks match `0`, so the total is: 3 sheets x (0 + blank) = 6.
-    assert_eq!(bytecode_value, Value::Number(6.0));
+    // Blanks don't match `0` either, so the total is: 3 sheets x 1 explicit zero = 3.
+    assert_eq!(bytecode_value, Value::Number(3.0));
 }
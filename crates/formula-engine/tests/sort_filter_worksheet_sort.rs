@@ -31,6 +31,7 @@ fn worksheet_sort_moves_cells_and_rewrites_relative_formulas() {
             order: SortOrder::Ascending,
             value_type: SortValueType::Auto,
             case_sensitive: false,
+            custom_list: None,
         }],
     };
 
@@ -76,6 +77,7 @@ fn worksheet_sort_places_errors_after_booleans_and_orders_by_error_code() {
             order: SortOrder::Ascending,
             value_type: SortValueType::Auto,
             case_sensitive: false,
+            custom_list: None,
         }],
     };
 
@@ -124,6 +126,7 @@ fn worksheet_sort_with_value_locale_parses_text_numbers() {
             order: SortOrder::Ascending,
             value_type: SortValueType::Auto,
             case_sensitive: false,
+            custom_list: None,
         }],
     };
 
@@ -102,3 +102,71 @@ fn engine_evaluates_spill_operator_against_spilled_ranges() {
 
     assert_eq!(engine.get_cell_value("Sheet1", "C4"), Value::Number(4.0));
 }
+
+#[test]
+fn spill_operator_dependents_recalculate_when_source_spill_resizes() {
+    for bytecode_enabled in [true, false] {
+        let mut engine = Engine::new();
+        engine.set_bytecode_enabled(bytecode_enabled);
+
+        // The spill origin's formula never changes; only its size input does.
+        engine.set_cell_value("Sheet1", "Z1", 3.0).unwrap();
+        engine
+            .set_cell_formula("Sheet1", "A1", "=SEQUENCE(Z1)")
+            .unwrap();
+        engine
+            .set_cell_formula("Sheet1", "C1", "=SUM(A1#)")
+            .unwrap();
+        engine
+            .set_cell_formula("Sheet1", "C2", "=ROWS(A1#)")
+            .unwrap();
+        // `E1 = A1#` re-spills the source; `G1` depends on that second spill.
+        engine.set_cell_formula("Sheet1", "E1", "=A1#").unwrap();
+        engine
+            .set_cell_formula("Sheet1", "G1", "=SUM(E1#)")
+            .unwrap();
+        engine.recalculate();
+
+        let assert_extent = |engine: &Engine, rows: u32, sum: f64| {
+            let ctx = format!("rows={rows} bytecode={bytecode_enabled}");
+            for origin in ["A1", "E1"] {
+                let (start, end) = engine.spill_range("Sheet1", origin).expect("spill range");
+                assert_eq!(start, parse_a1(origin).unwrap(), "{ctx}");
+                assert_eq!(end.row + 1, rows, "{origin} {ctx}");
+            }
+            assert_eq!(
+                engine.get_cell_value("Sheet1", "C1"),
+                Value::Number(sum),
+                "{ctx}"
+            );
+            assert_eq!(
+                engine.get_cell_value("Sheet1", "C2"),
+                Value::Number(rows as f64),
+                "{ctx}"
+            );
+            assert_eq!(
+                engine.get_cell_value("Sheet1", "G1"),
+                Value::Number(sum),
+                "{ctx}"
+            );
+        };
+        assert_extent(&engine, 3, 6.0);
+
+        // Grow the spill.
+        engine.set_cell_value("Sheet1", "Z1", 5.0).unwrap();
+        engine.recalculate();
+        assert_extent(&engine, 5, 15.0);
+
+        // Shrink it below the original size; stale spill cells must not be summed.
+        engine.set_cell_value("Sheet1", "Z1", 2.0).unwrap();
+        engine.recalculate();
+        assert_extent(&engine, 2, 3.0);
+        assert_eq!(engine.get_cell_value("Sheet1", "A3"), Value::Blank);
+        assert_eq!(engine.get_cell_value("Sheet1", "E3"), Value::Blank);
+
+        // Single-threaded recalc follows the same dependency path.
+        engine.set_cell_value("Sheet1", "Z1", 4.0).unwrap();
+        engine.recalculate_single_threaded();
+        assert_extent(&engine, 4, 10.0);
+    }
+}
@@ -107,6 +107,41 @@ fn spill_resolves_after_blocker_cleared() {
     assert_eq!(engine.get_cell_value("Sheet1", "C3"), Value::Number(3.0));
 }
 
+#[test]
+fn mark_spill_origin_blocked_shows_spill_error_before_recalc() {
+    let mut engine = Engine::new();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=SEQUENCE(5)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+    // Still spilling: nothing to mark.
+    assert!(!engine.mark_spill_origin_blocked("Sheet1", "A1"));
+
+    engine.set_cell_value("Sheet1", "A3", "x").unwrap();
+    // Manual calc mode leaves the origin's old top-left value in place...
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(1.0));
+    assert!(engine.mark_spill_origin_blocked("Sheet1", "A1"));
+    // ...until the host marks it.
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Error(ErrorKind::Spill)
+    );
+    // Only formula cells can be marked.
+    assert!(!engine.mark_spill_origin_blocked("Sheet1", "A3"));
+    assert!(!engine.mark_spill_origin_blocked("Sheet1", "B1"));
+
+    engine.recalculate_single_threaded();
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "A1"),
+        Value::Error(ErrorKind::Spill)
+    );
+
+    engine.clear_cell("Sheet1", "A3").unwrap();
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(1.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "A3"), Value::Number(3.0));
+}
+
 #[test]
 fn spill_resolves_after_overlapping_spill_shrinks() {
     let mut engine = Engine::new();
@@ -3,7 +3,7 @@ use formula_engine::eval::{CellAddr, Expr, Parser};
 use formula_engine::structured_refs::{
     resolve_structured_ref, StructuredColumn, StructuredColumns, StructuredRefItem,
 };
-use formula_engine::{Engine, Value};
+use formula_engine::{Engine, NameDefinition, NameScope, Value};
 use formula_model::table::{AutoFilter, FilterColumn, SortCondition, SortState, TableColumn};
 use formula_model::{Range, Table, TableError};
 
@@ -932,6 +932,156 @@ fn rename_table_rejects_invalid_name() {
     assert_eq!(err, TableError::InvalidStartChar);
 }
 
+#[test]
+fn add_table_registers_structured_refs_and_assigns_next_id() {
+    let mut engine = Engine::new();
+    engine.set_sheet_tables("Sheet1", vec![table_fixture_single_col()]);
+    engine.set_cell_value("Sheet1", "C1", "Amount").expect("C1");
+    engine.set_cell_value("Sheet1", "C2", 4.0_f64).expect("C2");
+    engine.set_cell_value("Sheet1", "C3", 5.0_f64).expect("C3");
+    engine
+        .set_cell_formula("Sheet1", "E1", "=SUM(Sales[Amount])")
+        .expect("formula");
+    engine.recalculate_single_threaded();
+    assert_eq!(
+        engine.get_cell_value("Sheet1", "E1"),
+        Value::Error(formula_engine::ErrorKind::Name)
+    );
+
+    engine
+        .add_table(
+            "Sheet1",
+            Table {
+                name: "Sales".into(),
+                display_name: "Sales".into(),
+                range: Range::from_a1("C1:C3").unwrap(),
+                columns: vec![TableColumn {
+                    id: 1,
+                    name: "Amount".into(),
+                    formula: None,
+                    totals_formula: None,
+                }],
+                ..table_fixture_single_col()
+            },
+        )
+        .expect("add table");
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet1", "E1"), Value::Number(9.0));
+
+    let tables = engine.get_sheet_tables("Sheet1").expect("tables");
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables[1].id, 2);
+}
+
+#[test]
+fn add_table_rejects_overlap_and_duplicate_names() {
+    let mut engine = setup_engine_with_table();
+
+    let overlapping = Table {
+        name: "Other".into(),
+        display_name: "Other".into(),
+        range: Range::from_a1("D4:D6").unwrap(),
+        ..table_fixture_single_col()
+    };
+    assert_eq!(
+        engine.add_table("Sheet1", overlapping),
+        Err(TableError::OverlapsExistingTable)
+    );
+
+    let duplicate = Table {
+        name: "table1".into(),
+        display_name: "table1".into(),
+        range: Range::from_a1("F1:F3").unwrap(),
+        ..table_fixture_single_col()
+    };
+    assert_eq!(
+        engine.add_table("Sheet1", duplicate),
+        Err(TableError::DuplicateName)
+    );
+    assert_eq!(engine.get_sheet_tables("Sheet1").expect("tables").len(), 1);
+}
+
+#[test]
+fn add_table_rejects_names_taken_by_defined_names() {
+    let mut engine = setup_engine_with_table();
+    engine
+        .define_name(
+            "Totals",
+            NameScope::Sheet("Sheet2"),
+            NameDefinition::Constant(Value::Number(1.0)),
+        )
+        .expect("define name");
+
+    let clashing = Table {
+        name: "TOTALS".into(),
+        display_name: "TOTALS".into(),
+        range: Range::from_a1("F1:F3").unwrap(),
+        ..table_fixture_single_col()
+    };
+    assert_eq!(
+        engine.add_table("Sheet1", clashing),
+        Err(TableError::ConflictsWithDefinedName)
+    );
+    assert_eq!(engine.get_sheet_tables("Sheet1").expect("tables").len(), 1);
+}
+
+#[test]
+fn resize_table_updates_structured_ref_extent() {
+    let mut engine = setup_engine_with_table();
+    engine.set_cell_value("Sheet1", "A5", 4.0_f64).expect("A5");
+    engine
+        .set_cell_formula("Sheet2", "A1", "=SUM(Table1[Col1])")
+        .expect("formula");
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet2", "A1"), Value::Number(6.0));
+
+    engine
+        .resize_table("Table1", Range::from_a1("A1:E5").unwrap())
+        .expect("resize");
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet2", "A1"), Value::Number(10.0));
+
+    let table = &engine.get_sheet_tables("Sheet1").expect("tables")[0];
+    assert_eq!(table.range, Range::from_a1("A1:E5").unwrap());
+    assert_eq!(table.columns.len(), 5);
+    assert_eq!(table.columns[4].name, "Column1");
+
+    // Moving the header row is not allowed.
+    assert_eq!(
+        engine.resize_table("Table1", Range::from_a1("A2:E5").unwrap()),
+        Err(TableError::InvalidRange)
+    );
+    assert_eq!(
+        engine.resize_table("Missing", Range::from_a1("A1:B2").unwrap()),
+        Err(TableError::TableNotFound)
+    );
+}
+
+#[test]
+fn delete_table_invalidates_structured_refs() {
+    let mut engine = setup_engine_with_table();
+    engine
+        .set_cell_formula("Sheet2", "A1", "=SUM(Table1[Col1])")
+        .expect("formula");
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet2", "A1"), Value::Number(6.0));
+
+    let removed = engine.delete_table("TABLE1").expect("delete");
+    assert_eq!(removed.name, "Table1");
+    assert!(engine
+        .get_sheet_tables("Sheet1")
+        .expect("tables")
+        .is_empty());
+
+    engine.recalculate_single_threaded();
+    assert_eq!(
+        engine.get_cell_value("Sheet2", "A1"),
+        Value::Error(formula_engine::ErrorKind::Name)
+    );
+    // Cell contents stay in place.
+    assert_eq!(engine.get_cell_value("Sheet1", "A2"), Value::Number(1.0));
+}
+
 #[test]
 fn insert_cols_inside_table_updates_table_columns_and_preserves_structured_refs() {
     let mut engine = setup_engine_with_table();
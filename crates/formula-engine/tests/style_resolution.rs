@@ -0,0 +1,92 @@
+use formula_engine::metadata::FormatRun;
+use formula_engine::{Engine, StyleLayer, StyleLayerContribution};
+use formula_model::{Alignment, Font, HorizontalAlignment, Style};
+
+#[test]
+fn describe_style_resolution_lists_layers_in_precedence_order() {
+    let mut engine = Engine::new();
+    let sheet_style = engine.intern_style(Style {
+        number_format: Some("0.00".to_string()),
+        font: Some(Font {
+            italic: true,
+            ..Font::default()
+        }),
+        ..Style::default()
+    });
+    let col_style = engine.intern_style(Style {
+        alignment: Some(Alignment {
+            horizontal: Some(HorizontalAlignment::Center),
+            ..Alignment::default()
+        }),
+        ..Style::default()
+    });
+    let run_style = engine.intern_style(Style {
+        number_format: Some("0%".to_string()),
+        ..Style::default()
+    });
+    let cell_style = engine.intern_style(Style {
+        font: Some(Font {
+            bold: true,
+            ..Font::default()
+        }),
+        ..Style::default()
+    });
+
+    engine.set_sheet_default_style_id("Sheet1", Some(sheet_style));
+    engine.set_col_style_id("Sheet1", 1, Some(col_style));
+    engine
+        .set_format_runs_by_col(
+            "Sheet1",
+            1,
+            vec![FormatRun {
+                start_row: 0,
+                end_row_exclusive: 2,
+                style_id: run_style,
+            }],
+        )
+        .unwrap();
+    engine
+        .set_cell_style_id("Sheet1", "B2", cell_style)
+        .unwrap();
+
+    assert_eq!(
+        engine.describe_style_resolution("Sheet1", "B2").unwrap(),
+        vec![
+            StyleLayerContribution {
+                layer: StyleLayer::Sheet,
+                style_id: sheet_style,
+                properties: vec!["font", "numberFormat"],
+                supplied: vec![],
+            },
+            StyleLayerContribution {
+                layer: StyleLayer::Col,
+                style_id: col_style,
+                properties: vec!["alignment"],
+                supplied: vec!["alignment"],
+            },
+            StyleLayerContribution {
+                layer: StyleLayer::RangeRun,
+                style_id: run_style,
+                properties: vec!["numberFormat"],
+                supplied: vec!["numberFormat"],
+            },
+            StyleLayerContribution {
+                layer: StyleLayer::Cell,
+                style_id: cell_style,
+                properties: vec!["font"],
+                supplied: vec!["font"],
+            },
+        ]
+    );
+
+    // Outside the run and without a cell style, the sheet default still supplies everything.
+    let a5 = engine.describe_style_resolution("Sheet1", "A5").unwrap();
+    assert_eq!(a5.len(), 1);
+    assert_eq!(a5[0].layer, StyleLayer::Sheet);
+    assert_eq!(a5[0].supplied, vec!["font", "numberFormat"]);
+
+    assert!(engine
+        .describe_style_resolution("Missing", "A1")
+        .unwrap()
+        .is_empty());
+}
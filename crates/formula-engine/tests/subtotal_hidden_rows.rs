@@ -0,0 +1,111 @@
+use formula_engine::{Engine, Value};
+use formula_model::{
+    FilterColumn, FilterCriterion, FilterJoin, NumberComparison, Range, SheetAutoFilter,
+};
+
+fn setup() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", "Amount").unwrap();
+    for (row, n) in [(2, 10.0), (3, 20.0), (4, 30.0), (5, 40.0)] {
+        engine
+            .set_cell_value("Sheet1", &format!("A{row}"), n)
+            .unwrap();
+    }
+    engine
+        .set_cell_formula("Sheet1", "C1", "=SUBTOTAL(9,A2:A5)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C2", "=SUBTOTAL(109,A2:A5)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C3", "=AGGREGATE(9,5,A2:A5)")
+        .unwrap();
+    engine
+        .set_cell_formula("Sheet1", "C4", "=AGGREGATE(9,4,A2:A5)")
+        .unwrap();
+    engine
+}
+
+/// Recalculate (the engine defaults to manual mode) and read back the four totals.
+fn totals(engine: &mut Engine) -> [Value; 4] {
+    engine.recalculate_single_threaded();
+    ["C1", "C2", "C3", "C4"].map(|addr| engine.get_cell_value("Sheet1", addr))
+}
+
+fn greater_than_25(range: Range) -> SheetAutoFilter {
+    SheetAutoFilter {
+        range,
+        filter_columns: vec![FilterColumn {
+            col_id: 0,
+            join: FilterJoin::Any,
+            criteria: vec![FilterCriterion::Number(NumberComparison::GreaterThan(25.0))],
+            values: Vec::new(),
+            raw_xml: Vec::new(),
+        }],
+        sort_state: None,
+        raw_xml: Vec::new(),
+    }
+}
+
+#[test]
+fn autofilter_hidden_rows_are_excluded_by_subtotal_and_aggregate() {
+    let mut engine = setup();
+    assert_eq!(
+        totals(&mut engine),
+        [100.0, 100.0, 100.0, 100.0].map(Value::Number)
+    );
+
+    let range = Range::from_a1("A1:A5").unwrap();
+    let result = engine
+        .apply_autofilter("Sheet1", range, Some(&greater_than_25(range)))
+        .unwrap();
+    assert_eq!(result.hidden_sheet_rows, vec![1, 2]);
+    assert!(engine.is_row_filtered("Sheet1", 1));
+    assert!(!engine.is_row_hidden("Sheet1", 1));
+
+    // Both SUBTOTAL forms skip filtered rows; AGGREGATE only when asked to ignore hidden rows.
+    assert_eq!(
+        totals(&mut engine),
+        [70.0, 70.0, 70.0, 100.0].map(Value::Number)
+    );
+
+    engine.apply_autofilter("Sheet1", range, None).unwrap();
+    assert!(!engine.is_row_filtered("Sheet1", 1));
+    assert_eq!(
+        totals(&mut engine),
+        [100.0, 100.0, 100.0, 100.0].map(Value::Number)
+    );
+}
+
+#[test]
+fn manually_hidden_rows_are_excluded_only_by_the_hidden_row_forms() {
+    let mut engine = setup();
+
+    engine.set_row_hidden("Sheet1", 4, true);
+    assert_eq!(
+        totals(&mut engine),
+        [100.0, 60.0, 60.0, 100.0].map(Value::Number)
+    );
+
+    // Clearing a filter leaves manually hidden rows hidden.
+    let range = Range::from_a1("A1:A5").unwrap();
+    engine
+        .apply_autofilter("Sheet1", range, Some(&greater_than_25(range)))
+        .unwrap();
+    assert_eq!(
+        totals(&mut engine),
+        [70.0, 30.0, 30.0, 100.0].map(Value::Number)
+    );
+    engine.apply_autofilter("Sheet1", range, None).unwrap();
+    assert!(engine.is_row_hidden("Sheet1", 4));
+    assert_eq!(
+        totals(&mut engine),
+        [100.0, 60.0, 60.0, 100.0].map(Value::Number)
+    );
+
+    engine.set_row_hidden("Sheet1", 4, false);
+    assert_eq!(
+        totals(&mut engine),
+        [100.0, 100.0, 100.0, 100.0].map(Value::Number)
+    );
+}
@@ -150,3 +150,33 @@ fn sumproduct_accepts_whole_column_references() {
         Value::Number(20.0)
     );
 }
+
+#[test]
+fn sumproduct_broadcasts_across_three_factors_in_argument_order() {
+    let mut engine = Engine::new();
+    set_value(&mut engine, "A1", 2.0);
+    set_value(&mut engine, "B1", 1.0);
+    set_value(&mut engine, "B2", 2.0);
+    set_value(&mut engine, "B3", 3.0);
+    set_value(&mut engine, "C1", 10.0);
+    set_value(&mut engine, "C2", 20.0);
+    set_value(&mut engine, "C3", 30.0);
+
+    assert_eq!(
+        eval_via_ast(&engine, "=SUMPRODUCT(B1:B3,A1,C1:C3)", "Z1"),
+        Value::Number(280.0)
+    );
+
+    // For idx=0 the factors are coerced left to right, so the first erroring factor wins even
+    // when it is a broadcast single cell.
+    set_value(&mut engine, "A1", Value::Error(ErrorKind::Value));
+    set_value(&mut engine, "C1", Value::Error(ErrorKind::Div0));
+    assert_eq!(
+        eval_via_ast(&engine, "=SUMPRODUCT(B1:B3,A1,C1:C3)", "Z1"),
+        Value::Error(ErrorKind::Value)
+    );
+    assert_eq!(
+        eval_via_ast(&engine, "=SUMPRODUCT(B1:B3,C1:C3,A1)", "Z1"),
+        Value::Error(ErrorKind::Div0)
+    );
+}
@@ -0,0 +1,149 @@
+use formula_engine::locale::{TextCollation, ValueLocaleConfig};
+use formula_engine::{Engine, Value};
+
+fn text(s: &str) -> Value {
+    Value::Text(s.to_string())
+}
+
+fn engine_with_locale(value_locale: ValueLocaleConfig, bytecode: bool) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_bytecode_enabled(bytecode);
+    engine.set_value_locale(value_locale);
+    engine
+}
+
+fn eval(engine: &mut Engine, formula: &str) -> Value {
+    engine.set_cell_formula("Sheet1", "Z1", formula).unwrap();
+    engine.recalculate_single_threaded();
+    engine.get_cell_value("Sheet1", "Z1")
+}
+
+#[test]
+fn comparison_operators_sort_accents_next_to_their_base_letter() {
+    for bytecode in [true, false] {
+        let mut engine = engine_with_locale(ValueLocaleConfig::en_us(), bytecode);
+        engine.set_cell_value("Sheet1", "A1", "é").unwrap();
+        engine.set_cell_value("Sheet1", "A2", "f").unwrap();
+        engine.set_cell_value("Sheet1", "A3", "E").unwrap();
+
+        assert_eq!(
+            eval(&mut engine, "=A1<A2"),
+            Value::Bool(true),
+            "bytecode={bytecode}"
+        );
+        if bytecode {
+            assert_eq!(engine.bytecode_program_count(), 1);
+        }
+        assert_eq!(
+            eval(&mut engine, "=A3<A1"),
+            Value::Bool(true),
+            "bytecode={bytecode}"
+        );
+        assert_eq!(eval(&mut engine, "=\"ÉCOLE\"=\"école\""), Value::Bool(true));
+        assert_eq!(
+            eval(&mut engine, "=\"ecole\"=\"école\""),
+            Value::Bool(false)
+        );
+
+        // Ordinal collation keeps code-point order, which puts `é` after `z`.
+        engine.set_value_locale(ValueLocaleConfig::en_us().with_collation(TextCollation::Ordinal));
+        assert_eq!(
+            eval(&mut engine, "=A1<A2"),
+            Value::Bool(false),
+            "bytecode={bytecode}"
+        );
+        assert_eq!(
+            eval(&mut engine, "=A1>\"z\""),
+            Value::Bool(true),
+            "bytecode={bytecode}"
+        );
+    }
+}
+
+#[test]
+fn spanish_collation_sorts_n_tilde_after_n() {
+    for bytecode in [true, false] {
+        let mut engine = engine_with_locale(ValueLocaleConfig::en_us(), bytecode);
+        assert_eq!(eval(&mut engine, "=\"ñu\">\"nz\""), Value::Bool(false));
+
+        engine.set_value_locale(ValueLocaleConfig::es_es());
+        assert_eq!(eval(&mut engine, "=\"ñu\">\"nz\""), Value::Bool(true));
+        assert_eq!(eval(&mut engine, "=\"ñu\"<\"oa\""), Value::Bool(true));
+    }
+}
+
+#[test]
+fn sort_orders_accented_text_linguistically_unless_ordinal() {
+    let mut engine = engine_with_locale(ValueLocaleConfig::en_us(), true);
+    for (idx, s) in ["f", "é", "e", "z", "E"].into_iter().enumerate() {
+        engine
+            .set_cell_value("Sheet1", &format!("A{}", idx + 1), s)
+            .unwrap();
+    }
+    engine
+        .set_cell_formula("Sheet1", "B1", "=SORT(A1:A5)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+
+    let sorted: Vec<Value> = (1..=5)
+        .map(|row| engine.get_cell_value("Sheet1", &format!("B{row}")))
+        .collect();
+    assert_eq!(
+        sorted,
+        vec![text("e"), text("E"), text("é"), text("f"), text("z")]
+    );
+
+    engine.set_value_locale(ValueLocaleConfig::en_us().with_collation(TextCollation::Ordinal));
+    engine.recalculate_single_threaded();
+    let sorted: Vec<Value> = (1..=5)
+        .map(|row| engine.get_cell_value("Sheet1", &format!("B{row}")))
+        .collect();
+    assert_eq!(
+        sorted,
+        vec![text("e"), text("E"), text("f"), text("z"), text("é")]
+    );
+}
+
+#[test]
+fn match_is_case_insensitive_and_uses_collation_for_approximate_search() {
+    for bytecode in [true, false] {
+        let mut engine = engine_with_locale(ValueLocaleConfig::en_us(), bytecode);
+        for (idx, s) in ["a", "ecole", "école", "f", "g"].into_iter().enumerate() {
+            engine
+                .set_cell_value("Sheet1", &format!("A{}", idx + 1), s)
+                .unwrap();
+        }
+
+        // Exact match ignores case but not accents.
+        assert_eq!(
+            eval(&mut engine, "=MATCH(\"ÉCOLE\",A1:A5,0)"),
+            Value::Number(3.0)
+        );
+        if bytecode {
+            assert_eq!(engine.bytecode_program_count(), 1);
+        }
+        assert_eq!(
+            eval(&mut engine, "=MATCH(\"ECOLE\",A1:A5,0)"),
+            Value::Number(2.0)
+        );
+
+        // The list is sorted linguistically, so `é...` lands between `école` and `f`.
+        assert_eq!(
+            eval(&mut engine, "=MATCH(\"éz\",A1:A5,1)"),
+            Value::Number(3.0),
+            "bytecode={bytecode}"
+        );
+        assert_eq!(
+            eval(&mut engine, "=XMATCH(\"éz\",A1:A5,-1)"),
+            Value::Number(3.0),
+            "bytecode={bytecode}"
+        );
+
+        engine.set_value_locale(ValueLocaleConfig::en_us().with_collation(TextCollation::Ordinal));
+        assert_eq!(
+            eval(&mut engine, "=MATCH(\"éz\",A1:A5,1)"),
+            Value::Number(5.0),
+            "bytecode={bytecode}"
+        );
+    }
+}
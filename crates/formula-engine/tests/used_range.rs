@@ -0,0 +1,39 @@
+use formula_engine::eval::{parse_a1, CellAddr};
+use formula_engine::{Engine, Value};
+
+fn bounds(start: &str, end: &str) -> Option<(CellAddr, CellAddr)> {
+    Some((parse_a1(start).unwrap(), parse_a1(end).unwrap()))
+}
+
+#[test]
+fn used_range_covers_values_formulas_and_spills() {
+    let mut engine = Engine::new();
+    engine.ensure_sheet("Sheet1");
+    assert_eq!(engine.used_range("Sheet1"), None);
+    assert_eq!(engine.used_range("Missing"), None);
+
+    engine.set_cell_value("Sheet1", "B2", 1.0).unwrap();
+    engine.set_cell_formula("Sheet1", "C3", "=B2*2").unwrap();
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.used_range("Sheet1"), bounds("B2", "C3"));
+
+    // The spill extends the used range past every stored cell.
+    engine
+        .set_cell_formula("Sheet1", "D1", "=SEQUENCE(10,3)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.used_range("Sheet1"), bounds("B1", "F10"));
+
+    // Shrinking the spill shrinks the range again.
+    engine
+        .set_cell_formula("Sheet1", "D1", "=SEQUENCE(2)")
+        .unwrap();
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.used_range("Sheet1"), bounds("B1", "D3"));
+
+    // Cleared cells and explicit blanks do not count.
+    engine.clear_cell("Sheet1", "D1").unwrap();
+    engine.set_cell_value("Sheet1", "C3", Value::Blank).unwrap();
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.used_range("Sheet1"), bounds("B2", "B2"));
+}
@@ -4,7 +4,7 @@ use formula_engine::what_if::monte_carlo::{
 };
 use formula_engine::what_if::scenario_manager::ScenarioManager;
 use formula_engine::what_if::{CellRef, CellValue, EngineWhatIfModel, WhatIfModel};
-use formula_engine::{Engine, RecalcMode};
+use formula_engine::{Engine, EngineError, RecalcMode};
 
 #[test]
 fn goal_seek_operates_over_engine_formulas() {
@@ -121,3 +121,153 @@ fn scenario_manager_applies_scenarios_and_generates_summary_report_over_engine()
             < 1e-9
     );
 }
+
+#[test]
+fn evaluate_with_overrides_matches_set_recalc_revert() {
+    use formula_engine::Value;
+    use formula_model::CellRef as Cell;
+
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 2.0).unwrap();
+    engine.set_cell_formula("Sheet1", "A2", "=A1+1").unwrap();
+    engine
+        .set_cell_formula("Sheet1", "B1", "=A1*A2+C1")
+        .unwrap();
+    engine.set_cell_formula("Sheet1", "B2", "=B1*10").unwrap();
+    engine.recalculate_single_threaded();
+
+    // A2 is a formula and C1 is empty; both are overridden alongside the constant A1.
+    let targets = [Cell::new(0, 1), Cell::new(1, 1)];
+    let overrides = [
+        (Cell::new(0, 0), Value::Number(5.0)),
+        (Cell::new(1, 0), Value::Number(4.0)),
+        (Cell::new(0, 2), Value::Number(1.0)),
+    ];
+    let scenario = engine
+        .evaluate_with_overrides("Sheet1", &targets, &overrides)
+        .unwrap();
+
+    // The workbook is back to its original inputs and results.
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(2.0));
+    assert_eq!(engine.get_cell_formula("Sheet1", "A2"), Some("=A1+1"));
+    assert_eq!(engine.get_cell_value("Sheet1", "A2"), Value::Number(3.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "C1"), Value::Blank);
+    assert_eq!(engine.get_cell_value("Sheet1", "B2"), Value::Number(60.0));
+
+    // Compare against actually applying the overrides, recalculating, and reverting by hand.
+    engine.set_cell_value("Sheet1", "A1", 5.0).unwrap();
+    engine.set_cell_value("Sheet1", "A2", 4.0).unwrap();
+    engine.set_cell_value("Sheet1", "C1", 1.0).unwrap();
+    engine.recalculate_single_threaded();
+    let manual = engine.get_cells_values("Sheet1", &targets);
+    assert_eq!(scenario, manual);
+    assert_eq!(scenario, vec![Value::Number(21.0), Value::Number(210.0)]);
+
+    engine.set_cell_value("Sheet1", "A1", 2.0).unwrap();
+    engine.set_cell_formula("Sheet1", "A2", "=A1+1").unwrap();
+    engine.clear_cell("Sheet1", "C1").unwrap();
+    engine.recalculate_single_threaded();
+    assert_eq!(engine.get_cell_value("Sheet1", "B2"), Value::Number(60.0));
+}
+
+#[test]
+fn evaluate_with_overrides_restores_spill_outputs() {
+    use formula_engine::Value;
+    use formula_model::CellRef as Cell;
+
+    let mut engine = Engine::new();
+    engine
+        .set_cell_formula("Sheet1", "A1", "=SEQUENCE(3)")
+        .unwrap();
+    engine.set_cell_formula("Sheet1", "B1", "=A2*2").unwrap();
+    engine.recalculate_single_threaded();
+
+    let scenario = engine
+        .evaluate_with_overrides(
+            "Sheet1",
+            &[Cell::new(0, 0), Cell::new(0, 1)],
+            &[(Cell::new(1, 0), Value::Number(10.0))],
+        )
+        .unwrap();
+    assert_eq!(
+        scenario,
+        vec![
+            Value::Error(formula_engine::ErrorKind::Spill),
+            Value::Number(20.0)
+        ]
+    );
+
+    // A2 is a spill output again rather than a literal copy of the spilled value.
+    assert_eq!(engine.get_cell_formula("Sheet1", "A2"), None);
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(1.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "A2"), Value::Number(2.0));
+    assert_eq!(
+        engine
+            .spill_origin("Sheet1", "A2")
+            .map(|(_, origin)| (origin.row, origin.col)),
+        Some((0, 0))
+    );
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(4.0));
+}
+
+#[test]
+fn evaluate_with_overrides_rejects_out_of_grid_overrides_without_side_effects() {
+    use formula_engine::calc_settings::CalculationMode;
+    use formula_engine::Value;
+    use formula_model::CellRef as Cell;
+
+    let mut engine = Engine::new();
+    let mut settings = engine.calc_settings().clone();
+    settings.calculation_mode = CalculationMode::Automatic;
+    engine.set_calc_settings(settings);
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+    engine.set_cell_formula("Sheet1", "B1", "=A1*2").unwrap();
+
+    let max_cols = engine.grid_limits().max_cols;
+    let err = engine.evaluate_with_overrides(
+        "Sheet1",
+        &[Cell::new(0, 1)],
+        &[
+            (Cell::new(0, 0), Value::Number(5.0)),
+            (Cell::new(0, max_cols), Value::Number(1.0)),
+        ],
+    );
+    assert!(err.is_err());
+    assert_eq!(engine.get_cell_value("Sheet1", "A1"), Value::Number(1.0));
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(2.0));
+    assert_eq!(
+        engine.calc_settings().calculation_mode,
+        CalculationMode::Automatic
+    );
+}
+
+#[test]
+fn evaluate_with_overrides_leaves_no_records_or_sheets_behind() {
+    use formula_engine::Value;
+    use formula_model::CellRef as Cell;
+
+    let mut engine = Engine::new();
+    engine.set_cell_value("Sheet1", "A1", 1.0).unwrap();
+    engine.set_cell_formula("Sheet1", "B1", "=A1+D4").unwrap();
+    engine.recalculate_single_threaded();
+
+    // D4 was empty: overriding it must not leave an explicit blank record behind.
+    let scenario = engine
+        .evaluate_with_overrides(
+            "Sheet1",
+            &[Cell::new(0, 1)],
+            &[(Cell::new(3, 3), Value::Number(5.0))],
+        )
+        .unwrap();
+    assert_eq!(scenario, vec![Value::Number(6.0)]);
+    assert_eq!(engine.get_cell_style_id("Sheet1", "D4").unwrap(), None);
+    assert_eq!(engine.get_cell_value("Sheet1", "B1"), Value::Number(1.0));
+
+    let err = engine.evaluate_with_overrides(
+        "Missing",
+        &[Cell::new(0, 0)],
+        &[(Cell::new(0, 0), Value::Number(5.0))],
+    );
+    assert!(matches!(err, Err(EngineError::SheetNotFound(name)) if name == "Missing"));
+    assert_eq!(engine.sheet_names_in_order(), vec!["Sheet1".to_string()]);
+}
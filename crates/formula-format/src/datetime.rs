@@ -5,6 +5,9 @@ use crate::{FormatOptions, LiteralLayoutOp};
 pub enum DateSystem {
     Excel1900,
     Excel1904,
+    /// 1900 date system on the proleptic Gregorian calendar: serial 0 is `1899-12-30` and there
+    /// is no fictitious `1900-02-29`, so only serials before `1900-03-01` differ from `Excel1900`.
+    Excel1900Proleptic,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -23,6 +26,117 @@ struct DateTimeParts {
     weekday: u32,
 }
 
+/// Month and weekday names used by `mmm`/`mmmm`/`mmmmm` and `ddd`/`dddd` tokens.
+///
+/// Selected per format section from a `[$-xxxx]` locale tag; untagged sections use
+/// [`EN_DATE_NAMES`] regardless of the workbook's separator locale.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct DateNames {
+    months_short: [&'static str; 12],
+    months_long: [&'static str; 12],
+    // Indexed by weekday (0=Sunday..6=Saturday).
+    days_short: [&'static str; 7],
+    days_long: [&'static str; 7],
+}
+
+pub(crate) const EN_DATE_NAMES: DateNames = DateNames {
+    months_short: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    months_long: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    days_short: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    days_long: [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+};
+
+const DE_DATE_NAMES: DateNames = DateNames {
+    months_short: [
+        "Jan", "Feb", "Mrz", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+    ],
+    months_long: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    days_short: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+    days_long: [
+        "Sonntag",
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+    ],
+};
+
+const FR_DATE_NAMES: DateNames = DateNames {
+    months_short: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    months_long: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    days_short: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+    days_long: [
+        "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ],
+};
+
+/// Best-effort mapping from an LCID (as found in `[$-407]` tags) to localized date names.
+///
+/// Only the primary language (low 10 bits) is considered, so regional variants such as `de-AT`
+/// (`0x0C07`) share the German table and calendar/numeral flags in the upper bits are ignored.
+pub(crate) fn date_names_for_lcid(lcid: u32) -> Option<&'static DateNames> {
+    match lcid & 0x3FF {
+        0x09 => Some(&EN_DATE_NAMES),
+        0x07 => Some(&DE_DATE_NAMES),
+        0x0C => Some(&FR_DATE_NAMES),
+        _ => None,
+    }
+}
+
 /// Best-effort check for whether a number format section contains Excel date/time tokens.
 ///
 /// This is intentionally lightweight: it does *not* fully parse Excel's number format grammar.
@@ -118,7 +232,12 @@ fn parse_elapsed_bracket_token(lower: &str) -> Option<Token> {
     None
 }
 
-pub(crate) fn format_datetime(serial: f64, pattern: &str, options: &FormatOptions) -> RenderedText {
+pub(crate) fn format_datetime(
+    serial: f64,
+    pattern: &str,
+    options: &FormatOptions,
+    names: &DateNames,
+) -> RenderedText {
     let mut tokens = tokenize(pattern);
     let has_ampm = tokens
         .iter()
@@ -137,7 +256,7 @@ pub(crate) fn format_datetime(serial: f64, pattern: &str, options: &FormatOption
     let Some(parts) = serial_to_parts(serial, options.date_system, frac_digits) else {
         return RenderedText::new("#####".to_string());
     };
-    render_tokens(&tokens, &parts, has_ampm, options)
+    render_tokens(&tokens, &parts, has_ampm, options, names)
 }
 
 fn serial_to_parts(serial: f64, date_system: DateSystem, frac_second_digits: usize) -> Option<DateTimeParts> {
@@ -176,6 +295,7 @@ fn serial_to_parts(serial: f64, date_system: DateSystem, frac_second_digits: usi
     let (year, month, day, weekday) = match date_system {
         DateSystem::Excel1900 => excel_1900_days_to_ymd(days)?,
         DateSystem::Excel1904 => excel_1904_days_to_ymd(days)?,
+        DateSystem::Excel1900Proleptic => proleptic_1900_days_to_ymd(days)?,
     };
 
     Some(DateTimeParts {
@@ -220,6 +340,14 @@ fn excel_1904_days_to_ymd(days: i64) -> Option<(i32, u32, u32, u32)> {
     Some((year, month, day, weekday))
 }
 
+fn proleptic_1900_days_to_ymd(days: i64) -> Option<(i32, u32, u32, u32)> {
+    let base = days_from_civil(1899, 12, 30);
+    let abs_days = base + days;
+    let (year, month, day) = civil_from_days(abs_days);
+    let weekday = weekday_from_days(abs_days);
+    Some((year, month, day, weekday))
+}
+
 // date algorithms from Howard Hinnant (public domain).
 fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
     let mut y = year as i64;
@@ -492,7 +620,13 @@ fn next_non_literal(tokens: &[Token], idx: usize) -> Option<&Token> {
     None
 }
 
-fn render_tokens(tokens: &[Token], parts: &DateTimeParts, has_ampm: bool, options: &FormatOptions) -> RenderedText {
+fn render_tokens(
+    tokens: &[Token],
+    parts: &DateTimeParts,
+    has_ampm: bool,
+    options: &FormatOptions,
+    names: &DateNames,
+) -> RenderedText {
     let mut out = RenderedText::new(String::new());
 
     for (idx, token) in tokens.iter().enumerate() {
@@ -501,9 +635,9 @@ fn render_tokens(tokens: &[Token], parts: &DateTimeParts, has_ampm: bool, option
             Token::DateSep => out.push(options.locale.date_sep),
             Token::TimeSep => out.push(options.locale.time_sep),
             Token::Year(count) => out.push_str(&format_year(parts.year, *count)),
-            Token::Month(count) => out.push_str(&format_month(parts.month, *count)),
+            Token::Month(count) => out.push_str(&format_month(parts.month, *count, names)),
             Token::Minute(count) => out.push_str(&format_two(parts.minute, *count)),
-            Token::Day(count) => out.push_str(&format_day(parts.day, parts.weekday, *count)),
+            Token::Day(count) => out.push_str(&format_day(parts.day, parts.weekday, *count, names)),
             Token::Hour(count) => {
                 let hour = if has_ampm {
                     let mut h = (parts.hour % 12) as u32;
@@ -544,7 +678,7 @@ fn render_tokens(tokens: &[Token], parts: &DateTimeParts, has_ampm: bool, option
                 // but avoid panicking if a token stream bypasses that step.
                 let count = *count;
                 if count >= 3 {
-                    out.push_str(&format_month(parts.month, count));
+                    out.push_str(&format_month(parts.month, count, names));
                     continue;
                 }
 
@@ -555,7 +689,7 @@ fn render_tokens(tokens: &[Token], parts: &DateTimeParts, has_ampm: bool, option
                 if is_minute {
                     out.push_str(&format_two(parts.minute, count));
                 } else {
-                    out.push_str(&format_month(parts.month, count));
+                    out.push_str(&format_month(parts.month, count, names));
                 }
             }
         }
@@ -589,31 +723,14 @@ fn format_elapsed(value: i64, width: usize) -> String {
     }
 }
 
-fn format_month(month: u32, count: usize) -> String {
-    const MONTHS_SHORT: [&str; 12] = [
-        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-    ];
-    const MONTHS_LONG: [&str; 12] = [
-        "January",
-        "February",
-        "March",
-        "April",
-        "May",
-        "June",
-        "July",
-        "August",
-        "September",
-        "October",
-        "November",
-        "December",
-    ];
-
+fn format_month(month: u32, count: usize, names: &DateNames) -> String {
+    let idx = (month.saturating_sub(1)) as usize;
     match count {
         1 => month.to_string(),
         2 => format!("{:02}", month),
-        3 => MONTHS_SHORT[(month.saturating_sub(1)) as usize].to_string(),
-        4 => MONTHS_LONG[(month.saturating_sub(1)) as usize].to_string(),
-        _ => MONTHS_LONG[(month.saturating_sub(1)) as usize]
+        3 => names.months_short[idx].to_string(),
+        4 => names.months_long[idx].to_string(),
+        _ => names.months_long[idx]
             .chars()
             .next()
             .unwrap_or('?')
@@ -621,22 +738,12 @@ fn format_month(month: u32, count: usize) -> String {
     }
 }
 
-fn format_day(day: u32, weekday: u32, count: usize) -> String {
-    const DAYS_SHORT: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
-    const DAYS_LONG: [&str; 7] = [
-        "Sunday",
-        "Monday",
-        "Tuesday",
-        "Wednesday",
-        "Thursday",
-        "Friday",
-        "Saturday",
-    ];
+fn format_day(day: u32, weekday: u32, count: usize, names: &DateNames) -> String {
     match count {
         1 => day.to_string(),
         2 => format!("{:02}", day),
-        3 => DAYS_SHORT[weekday as usize].to_string(),
-        _ => DAYS_LONG[weekday as usize].to_string(),
+        3 => names.days_short[weekday as usize].to_string(),
+        _ => names.days_long[weekday as usize].to_string(),
     }
 }
 
@@ -419,7 +419,11 @@ pub fn render_value(value: Value<'_>, format_code: Option<&str>, options: &Forma
                 section_options.locale = locale;
             }
             if crate::datetime::looks_like_datetime(section.pattern) {
-                let rendered = crate::datetime::format_datetime(n, section.pattern, &section_options);
+                let names = section
+                    .date_names
+                    .unwrap_or(&crate::datetime::EN_DATE_NAMES);
+                let rendered =
+                    crate::datetime::format_datetime(n, section.pattern, &section_options, names);
                 let layout_hint = rendered.layout_hint();
                 RenderResult {
                     text: rendered.text,
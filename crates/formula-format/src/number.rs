@@ -488,9 +488,13 @@ fn format_fixed(mut value: f64, spec: &FixedSpec, options: &FormatOptions) -> St
     let rounded = round_to(value, max_frac);
 
     let (int_digits, frac_digits) = if max_frac == 0 {
-        (format!("{:.0}", rounded), String::new())
+        (
+            limit_to_15_significant_digits(format!("{:.0}", rounded), rounded, 0),
+            String::new(),
+        )
     } else {
-        let s = format!("{:.*}", max_frac, rounded);
+        let s =
+            limit_to_15_significant_digits(format!("{:.*}", max_frac, rounded), rounded, max_frac);
         let mut split = s.splitn(2, '.');
         (
             split.next().unwrap_or("").to_string(),
@@ -532,6 +536,53 @@ fn format_fixed(mut value: f64, spec: &FixedSpec, options: &FormatOptions) -> St
     out
 }
 
+/// Excel only keeps 15 significant digits, so any further digits of a fixed rendering (e.g.
+/// `0.33333333333333331483` for `1/3` with 20 decimals) display as zeros.
+///
+/// `fixed` is `value` rendered by `format!("{:.*}", max_frac, value)`.
+fn limit_to_15_significant_digits(fixed: String, value: f64, max_frac: usize) -> String {
+    let significant = fixed
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .skip_while(|&b| b == b'0')
+        .count();
+    if significant <= 15 || !value.is_finite() {
+        return fixed;
+    }
+
+    // `{:.14e}` rounds to exactly 15 significant digits in decimal.
+    let sci = format!("{:.14e}", value.abs());
+    let Some((mantissa, exp)) = sci.split_once('e') else {
+        return fixed;
+    };
+    let Ok(exp) = exp.parse::<i64>() else {
+        return fixed;
+    };
+    let digits: Vec<u8> = mantissa.bytes().filter(u8::is_ascii_digit).collect();
+    // The digit for place value `10^place` (zero outside the 15 kept digits).
+    let digit_at = |place: i64| -> char {
+        usize::try_from(exp - place)
+            .ok()
+            .and_then(|idx| digits.get(idx))
+            .map_or('0', |&d| d as char)
+    };
+
+    let mut out = String::new();
+    if fixed.starts_with('-') {
+        out.push('-');
+    }
+    if exp < 0 {
+        out.push('0');
+    } else {
+        out.extend((0..=exp).rev().map(digit_at));
+    }
+    if max_frac > 0 {
+        out.push('.');
+        out.extend((1..=max_frac as i64).map(|place| digit_at(-place)));
+    }
+    out
+}
+
 fn round_to(value: f64, decimals: usize) -> f64 {
     if decimals == 0 {
         return value.round();
@@ -1,5 +1,6 @@
 use std::fmt;
 
+use crate::datetime::DateNames;
 use crate::{ColorOverride, Locale};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,6 +51,7 @@ struct Section {
     condition: Option<Condition>,
     color: Option<ColorOverride>,
     locale_override: Option<Locale>,
+    date_names: Option<&'static DateNames>,
 }
 
 /// Parsed Excel number format code split into `;`-delimited sections.
@@ -64,6 +66,7 @@ pub(crate) struct SelectedSection<'a> {
     pub auto_negative_sign: bool,
     pub color: Option<ColorOverride>,
     pub locale_override: Option<Locale>,
+    pub date_names: Option<&'static DateNames>,
 }
 
 impl FormatCode {
@@ -74,6 +77,7 @@ impl FormatCode {
                 condition: None,
                 color: None,
                 locale_override: None,
+                date_names: None,
             }],
         }
     }
@@ -117,6 +121,7 @@ impl FormatCode {
                                 auto_negative_sign: false,
                                 color: section.color,
                                 locale_override: section.locale_override,
+                                date_names: section.date_names,
                             };
                         }
                     }
@@ -133,6 +138,7 @@ impl FormatCode {
                 auto_negative_sign: false,
                 color: section.color,
                 locale_override: section.locale_override,
+                date_names: section.date_names,
             };
         }
 
@@ -150,6 +156,7 @@ impl FormatCode {
                     auto_negative_sign: false,
                     color: self.sections[1].color,
                     locale_override: self.sections[1].locale_override,
+                    date_names: self.sections[1].date_names,
                 }
             } else {
                 SelectedSection {
@@ -157,6 +164,7 @@ impl FormatCode {
                     auto_negative_sign: true,
                     color: self.sections[0].color,
                     locale_override: self.sections[0].locale_override,
+                    date_names: self.sections[0].date_names,
                 }
             }
         } else if v == 0.0 {
@@ -166,6 +174,7 @@ impl FormatCode {
                     auto_negative_sign: false,
                     color: self.sections[2].color,
                     locale_override: self.sections[2].locale_override,
+                    date_names: self.sections[2].date_names,
                 }
             } else {
                 SelectedSection {
@@ -173,6 +182,7 @@ impl FormatCode {
                     auto_negative_sign: false,
                     color: self.sections[0].color,
                     locale_override: self.sections[0].locale_override,
+                    date_names: self.sections[0].date_names,
                 }
             }
         } else {
@@ -181,6 +191,7 @@ impl FormatCode {
                 auto_negative_sign: false,
                 color: self.sections[0].color,
                 locale_override: self.sections[0].locale_override,
+                date_names: self.sections[0].date_names,
             }
         }
     }
@@ -258,6 +269,7 @@ fn parse_section(input: &str) -> Result<Section, ParseError> {
     let mut condition: Option<Condition> = None;
     let mut color: Option<ColorOverride> = None;
     let mut locale_override: Option<Locale> = None;
+    let mut date_names: Option<&'static DateNames> = None;
 
     // Strip leading bracketed components like colors, locale tags, currencies,
     // and conditions. Conditions are of the form `[>=100]`.
@@ -286,9 +298,12 @@ fn parse_section(input: &str) -> Result<Section, ParseError> {
             }
         }
 
-        if locale_override.is_none() {
-            if let Some(locale) = parse_locale_override(content) {
-                locale_override = Some(locale);
+        if let Some(lcid) = parse_locale_lcid(content) {
+            if locale_override.is_none() {
+                locale_override = locale_for_lcid(lcid);
+            }
+            if date_names.is_none() {
+                date_names = crate::datetime::date_names_for_lcid(lcid);
             }
         }
 
@@ -307,6 +322,7 @@ fn parse_section(input: &str) -> Result<Section, ParseError> {
         condition,
         color,
         locale_override,
+        date_names,
     })
 }
 
@@ -358,12 +374,11 @@ fn parse_color_token(content: &str) -> Option<ColorOverride> {
     })
 }
 
-fn parse_locale_override(content: &str) -> Option<Locale> {
+fn parse_locale_lcid(content: &str) -> Option<u32> {
     // Locale/currency tags are encoded as `[$$-409]` where the locale is the hex LCID suffix.
     let after = content.strip_prefix('$')?;
     let (_, locale) = after.split_once('-')?;
-    let lcid = u32::from_str_radix(locale.trim(), 16).ok()?;
-    locale_for_lcid(lcid)
+    u32::from_str_radix(locale.trim(), 16).ok()
 }
 
 /// Best-effort mapping from a Windows/Excel LCID (locale identifier) to a
@@ -37,6 +37,38 @@ fn currency_and_percent() {
     );
 }
 
+#[test]
+fn fixed_formats_show_at_most_15_significant_digits() {
+    let options = FormatOptions::default();
+    let fmt = |n: f64, code: &str| format_value(Value::Number(n), Some(code), &options).text;
+
+    assert_eq!(
+        fmt(0.1 + 0.2, "0.00000000000000000000"),
+        "0.30000000000000000000"
+    );
+    assert_eq!(
+        fmt(1.0 / 3.0, "0.00000000000000000000"),
+        "0.33333333333333300000"
+    );
+    assert_eq!(
+        fmt(-2.0 / 3.0, "0.000000000000000000"),
+        "-0.666666666666667000"
+    );
+    assert_eq!(fmt(2f64.powi(70), "0"), "1180591620717410000000");
+    assert_eq!(fmt(2f64.powi(70), "#,##0"), "1,180,591,620,717,410,000,000");
+    assert_eq!(
+        fmt(123456789.12345679, "0.000000000"),
+        "123456789.123457000"
+    );
+    // Renderings within 15 digits are unchanged.
+    assert_eq!(fmt(0.1 + 0.2, "0.00"), "0.30");
+    assert_eq!(fmt(1.0 / 3.0, "0.00000"), "0.33333");
+    assert_eq!(
+        fmt(1.0 / 3.0, "0.00000000000000000000E+00"),
+        "3.33333333333333000000E-01"
+    );
+}
+
 #[test]
 fn scientific_notation() {
     let options = FormatOptions::default();
@@ -201,6 +233,44 @@ fn conditional_sections_and_text() {
     );
 }
 
+#[test]
+fn bracketed_conditions_select_sections_across_the_boundary() {
+    let options = FormatOptions::default();
+    let code = "[>=100]#,##0;[<100]0.0";
+
+    assert_eq!(format_value(Value::Number(99.94), Some(code), &options).text, "99.9");
+    assert_eq!(format_value(Value::Number(99.96), Some(code), &options).text, "100.0");
+    assert_eq!(format_value(Value::Number(100.0), Some(code), &options).text, "100");
+    assert_eq!(format_value(Value::Number(1234.4), Some(code), &options).text, "1,234");
+
+    // Conditions are tested in order; the first unconditional section is the fallback.
+    let code = r#"[<=0]"none";[=1]"one";0 "items""#;
+    assert_eq!(format_value(Value::Number(0.0), Some(code), &options).text, "none");
+    assert_eq!(format_value(Value::Number(1.0), Some(code), &options).text, "one");
+    assert_eq!(format_value(Value::Number(2.0), Some(code), &options).text, "2 items");
+}
+
+#[test]
+fn locale_tags_localize_month_and_day_names() {
+    let options = FormatOptions::default();
+    // 2024-03-05 (a Tuesday) in the 1900 date system.
+    let serial = 45356.0;
+
+    assert_eq!(format_value(Value::Number(serial), Some("[$-409]mmmm"), &options).text, "March");
+    assert_eq!(format_value(Value::Number(serial), Some("[$-407]mmmm"), &options).text, "März");
+    assert_eq!(format_value(Value::Number(serial), Some("[$-407]ddd mmm"), &options).text, "Di Mrz");
+    // Regional variants share the language's names (de-AT).
+    assert_eq!(format_value(Value::Number(serial), Some("[$-C07]dddd"), &options).text, "Dienstag");
+    assert_eq!(
+        format_value(Value::Number(serial), Some("[$-40C]dddd d mmmm"), &options).text,
+        "mardi 5 mars"
+    );
+    // `mmmmm` uses the first letter of the localized month name.
+    assert_eq!(format_value(Value::Number(serial + 300.0), Some("[$-407]mmmmm"), &options).text, "D");
+    // Unknown languages keep the English names.
+    assert_eq!(format_value(Value::Number(serial), Some("[$-411]mmmm"), &options).text, "March");
+}
+
 #[test]
 fn error_values_align_center_like_excel() {
     let options = FormatOptions::default();
@@ -19,6 +19,37 @@ fn color_tokens_are_exposed_as_hints_and_not_rendered() {
     assert_eq!(rendered.color, Some(ColorOverride::Indexed(10)));
 }
 
+#[test]
+fn four_section_formats_pick_section_and_color_by_value_kind() {
+    let options = FormatOptions::default();
+    // positive; negative; zero; text -- each section carries its own color.
+    let code = r#"[Blue]#,##0.00;[Red](#,##0.00);[Green]"zero";[Magenta]"text: "@"#;
+
+    let rendered = render_value(Value::Number(1234.5), Some(code), &options);
+    assert_eq!(rendered.text, "1,234.50");
+    assert_eq!(rendered.color, Some(ColorOverride::Argb(0xFF0000FF)));
+    assert_eq!(rendered.alignment, AlignmentHint::Right);
+
+    // The negative section has no explicit '-', so the absolute value is shown.
+    let rendered = render_value(Value::Number(-1234.5), Some(code), &options);
+    assert_eq!(rendered.text, "(1,234.50)");
+    assert_eq!(rendered.color, Some(ColorOverride::Argb(0xFFFF0000)));
+
+    let rendered = render_value(Value::Number(0.0), Some(code), &options);
+    assert_eq!(rendered.text, "zero");
+    assert_eq!(rendered.color, Some(ColorOverride::Argb(0xFF00FF00)));
+
+    let rendered = render_value(Value::Text("abc"), Some(code), &options);
+    assert_eq!(rendered.text, "text: abc");
+    assert_eq!(rendered.color, Some(ColorOverride::Argb(0xFFFF00FF)));
+    assert_eq!(rendered.alignment, AlignmentHint::Left);
+
+    // Without a color token the section renders with no override.
+    let rendered = render_value(Value::Number(1.0), Some(r#"0;[Red]-0;0;@"#), &options);
+    assert_eq!(rendered.text, "1");
+    assert_eq!(rendered.color, None);
+}
+
 #[test]
 fn bracket_currency_tokens_resolve_default_symbol_for_locale() {
     let options = FormatOptions::default();
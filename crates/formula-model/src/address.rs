@@ -383,6 +383,12 @@ pub enum A1Endpoint {
 /// - `0` row numbers are rejected
 /// - any non-ASCII letter/digit characters are rejected
 pub fn parse_a1_endpoint(s: &str) -> Result<A1Endpoint, A1ParseError> {
+    parse_a1_endpoint_with_max_cols(s, crate::cell::EXCEL_MAX_COLS)
+}
+
+/// Like [`parse_a1_endpoint`], but columns must be below `max_cols` instead of Excel's `XFD`
+/// bound (see [`crate::GridLimits`]).
+pub fn parse_a1_endpoint_with_max_cols(s: &str, max_cols: u32) -> Result<A1Endpoint, A1ParseError> {
     let s = s.trim();
     if s.is_empty() {
         return Err(A1ParseError::Empty);
@@ -432,7 +438,7 @@ pub fn parse_a1_endpoint(s: &str) -> Result<A1Endpoint, A1ParseError> {
 
     if col_len > 0 {
         let col0 = col_1_based.saturating_sub(1);
-        if col0 >= crate::cell::EXCEL_MAX_COLS {
+        if col0 >= max_cols {
             return Err(A1ParseError::InvalidColumn);
         }
 
@@ -674,8 +680,16 @@ fn name_to_col(s: &str) -> Result<u32, A1ParseError> {
 /// - Only ASCII letters are accepted.
 /// - The result must be within Excel's column bounds (`A..=XFD`).
 pub fn column_label_to_index(label: &str) -> Result<u32, A1ParseError> {
+    column_label_to_index_with_max(label, crate::cell::EXCEL_MAX_COLS)
+}
+
+/// Convert a column label into a 0-based column index that must be below `max_cols`.
+///
+/// This is the grid-aware variant of [`column_label_to_index`] for workbooks configured with
+/// non-Excel [`crate::GridLimits`].
+pub fn column_label_to_index_with_max(label: &str, max_cols: u32) -> Result<u32, A1ParseError> {
     let col = name_to_col(label)?;
-    if col >= crate::cell::EXCEL_MAX_COLS {
+    if col >= max_cols {
         return Err(A1ParseError::InvalidColumn);
     }
     Ok(col)
@@ -900,6 +914,39 @@ mod tests {
         assert!(column_label_to_index("A0").is_err());
     }
 
+    #[test]
+    fn column_label_to_index_with_max_honors_custom_grid_limits() {
+        let big = crate::GridLimits {
+            max_rows: 4_000_000,
+            max_cols: crate::MAX_GRID_COLS,
+        };
+        assert_eq!(
+            column_label_to_index_with_max("XFE", big.max_cols).unwrap(),
+            16_384
+        );
+        assert_eq!(
+            column_label_to_index_with_max("ZZZ", big.max_cols).unwrap(),
+            18_277
+        );
+        assert!(column_label_to_index_with_max("AAAA", big.max_cols).is_err());
+        assert!(column_label_to_index_with_max("C", 2).is_err());
+
+        assert_eq!(
+            parse_a1_endpoint_with_max_cols("XFE1", big.max_cols).unwrap(),
+            A1Endpoint::Cell(CellRef::new(0, 16_384))
+        );
+        assert!(parse_a1_endpoint("XFE1").is_err());
+
+        let mut out = String::new();
+        push_column_label(big.max_cols - 1, &mut out);
+        assert_eq!(out, "ZZZ");
+
+        assert!(crate::GridLimits::EXCEL.contains(1_048_575, 16_383));
+        assert!(!crate::GridLimits::EXCEL.contains(1_048_576, 0));
+        assert!(big.contains(1_048_576, 16_384));
+        assert_eq!(crate::GridLimits::default(), crate::GridLimits::EXCEL);
+    }
+
     #[test]
     fn column_label_to_index_lenient_accepts_out_of_bounds_labels() {
         assert_eq!(column_label_to_index_lenient("A").unwrap(), 0);
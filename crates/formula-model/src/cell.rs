@@ -9,6 +9,50 @@ pub const EXCEL_MAX_ROWS: u32 = 1_048_576;
 /// Excel-compatible maximum columns per worksheet (16,384).
 pub const EXCEL_MAX_COLS: u32 = 16_384;
 
+/// Upper bound on [`GridLimits::max_cols`] (18,278 columns, `A..=ZZZ`).
+///
+/// Formula A1 references are lexed with at most three column letters (names like `ABCD1` must
+/// stay valid identifiers), so this is the widest grid whose every column is addressable from a
+/// formula.
+pub const MAX_GRID_COLS: u32 = 18_278;
+
+/// Logical worksheet grid bounds used when parsing and validating addresses.
+///
+/// [`GridLimits::EXCEL`] (the default) matches Excel's fixed 1,048,576 x 16,384 grid. Non-Excel
+/// targets may opt into a larger grid, with these interop caveats:
+/// - [`CellKey`] (and therefore [`crate::Worksheet`] cell storage) packs columns into 14 bits and
+///   stays limited to [`EXCEL_MAX_COLS`].
+/// - XLSX/XLSB/XLS writers cannot represent cells beyond `XFD1048576`; Excel treats references
+///   like `XFE1` as out of range (or as defined names).
+/// - Columns are capped at [`MAX_GRID_COLS`] so every column has a 1-3 letter A1 label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GridLimits {
+    /// Number of rows in the grid (row indices `0..max_rows`).
+    pub max_rows: u32,
+    /// Number of columns in the grid (column indices `0..max_cols`).
+    pub max_cols: u32,
+}
+
+impl GridLimits {
+    /// Excel's worksheet grid (1,048,576 rows x 16,384 columns).
+    pub const EXCEL: GridLimits = GridLimits {
+        max_rows: EXCEL_MAX_ROWS,
+        max_cols: EXCEL_MAX_COLS,
+    };
+
+    /// Returns `true` when the 0-based `(row, col)` coordinate lies within these limits.
+    pub const fn contains(&self, row: u32, col: u32) -> bool {
+        row < self.max_rows && col < self.max_cols
+    }
+}
+
+impl Default for GridLimits {
+    fn default() -> Self {
+        GridLimits::EXCEL
+    }
+}
+
 const COL_BITS: u32 = 14; // 2^14 = 16,384 columns.
 const COL_MASK: u64 = (1u64 << COL_BITS) - 1;
 
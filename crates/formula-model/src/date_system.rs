@@ -31,7 +31,9 @@ impl From<DateSystem> for formula_format::DateSystem {
 impl From<formula_format::DateSystem> for DateSystem {
     fn from(value: formula_format::DateSystem) -> Self {
         match value {
-            formula_format::DateSystem::Excel1900 => DateSystem::Excel1900,
+            // Serials from 1900-03-01 onward agree, and the model has no proleptic variant.
+            formula_format::DateSystem::Excel1900
+            | formula_format::DateSystem::Excel1900Proleptic => DateSystem::Excel1900,
             formula_format::DateSystem::Excel1904 => DateSystem::Excel1904,
         }
     }
@@ -84,6 +84,8 @@ fn scan_sheet_name_token_end(src: &str, start: usize) -> Option<usize> {
     }
     match src[i..].chars().next()? {
         '\'' => scan_quoted_sheet_name_end(src, i),
+        // External-link formulas can carry a sheet-absolute marker (`[Book.xlsx]$Sheet1!A1`).
+        '$' => scan_unquoted_name_end(src, i + 1),
         _ => scan_unquoted_name_end(src, i),
     }
 }
@@ -816,6 +818,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn split_external_workbook_prefix_accepts_sheet_absolute_marker() {
+        assert_eq!(
+            split_external_workbook_prefix("C:\\path\\[Book.xlsx]$Sheet1"),
+            Some(("C:\\path\\[Book.xlsx]", "$Sheet1"))
+        );
+        assert_eq!(
+            find_external_workbook_prefix_end_if_followed_by_sheet_or_name_token(
+                "[Book.xlsx]$Sheet1!A1",
+                0
+            ),
+            Some(11)
+        );
+    }
+
     #[test]
     fn split_external_workbook_prefix_accepts_leading_bracketed_paths() {
         assert_eq!(
@@ -328,7 +328,16 @@ fn parse_unquoted_sheet_spec(formula: &str, start: usize) -> Option<(usize, &str
     let bytes = formula.as_bytes();
     let mut i = start;
 
-    let first = formula[i..].chars().next()?;
+    let mut first = formula[i..].chars().next()?;
+    // A sheet-absolute marker (`$Sheet1!A1`) belongs to the sheet token. Consuming it here keeps
+    // the scan from restarting after the `$` and matching `Sheet1` on its own.
+    if first == '$' {
+        i += 1;
+        first = formula[i..].chars().next()?;
+        if first != '_' && !first.is_alphabetic() {
+            return None;
+        }
+    }
     if first != '[' && first != '_' && !first.is_alphabetic() {
         return None;
     }
@@ -344,7 +353,7 @@ fn parse_unquoted_sheet_spec(formula: &str, start: usize) -> Option<(usize, &str
         }
 
         let next_ch = formula[i..].chars().next()?;
-        if next_ch != '_' && !next_ch.is_alphabetic() {
+        if next_ch != '_' && next_ch != '$' && !next_ch.is_alphabetic() {
             // Not a workbook+sheet reference; likely a structured reference.
             return None;
         }
@@ -983,6 +992,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rewrite_leaves_sheet_absolute_markers_intact() {
+        // `$Sheet1` is a distinct sheet token; the scan must not restart after the `$`.
+        assert_eq!(
+            rewrite_sheet_names_in_formula("=$Sheet1!A1+Sheet1!$A$1", "Sheet1", "Data"),
+            "=$Sheet1!A1+Data!$A$1"
+        );
+        // External refs with the marker are never rewritten by an internal rename.
+        for formula in [
+            "=[Book1.xlsx]$Sheet1!A1",
+            "=[1]$Sheet1!$A$1*2",
+            "='C:\\x\\[Book1.xlsx]$Sheet1'!A1",
+        ] {
+            assert_eq!(
+                rewrite_sheet_names_in_formula(formula, "Sheet1", "Data"),
+                formula
+            );
+        }
+        assert_eq!(
+            rewrite_deleted_sheet_references_in_formula(
+                "=[Book1.xlsx]$Sheet1!A1+$Sheet1!A1",
+                "Sheet1",
+                &["Sheet1".to_string()],
+            ),
+            "=[Book1.xlsx]$Sheet1!A1+$Sheet1!A1"
+        );
+    }
+
     #[test]
     fn rewrite_3d_reference() {
         assert_eq!(
@@ -46,14 +46,15 @@ pub use address::{
     push_a1_cell_ref, push_a1_cell_ref_row1, push_a1_col_range, push_a1_col_ref,
     push_a1_row_range_row1, push_a1_row_ref_row1,
     push_column_label, push_column_label_u64, A1Endpoint, A1ParseError, CellRef, Range, RangeIter, RangeParseError,
-    column_label_to_index, column_label_to_index_lenient,
+    column_label_to_index, column_label_to_index_lenient, column_label_to_index_with_max,
+    parse_a1_endpoint_with_max_cols,
 };
 pub use autofilter::{
     DateComparison, FilterCriterion, FilterJoin, FilterValue, NumberComparison, OpaqueCustomFilter,
     OpaqueDynamicFilter, SheetAutoFilter, TextMatch, TextMatchKind,
 };
 pub use calc_settings::{CalcSettings, CalculationMode, IterativeCalculationSettings};
-pub use cell::{Cell, CellId, CellKey, EXCEL_MAX_COLS, EXCEL_MAX_ROWS};
+pub use cell::{Cell, CellId, CellKey, GridLimits, EXCEL_MAX_COLS, EXCEL_MAX_ROWS, MAX_GRID_COLS};
 pub use comments::{
     Comment, CommentAuthor, CommentError, CommentKind, CommentPatch, Mention, Reply, TimestampMs,
 };
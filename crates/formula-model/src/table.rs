@@ -27,12 +27,16 @@ pub enum TableError {
     ReservedName,
     #[error("table name already exists in workbook")]
     DuplicateName,
+    #[error("table name conflicts with a defined name")]
+    ConflictsWithDefinedName,
     #[error("worksheet not found")]
     SheetNotFound,
     #[error("table not found")]
     TableNotFound,
     #[error("table range is too small for header/totals row settings")]
     InvalidRange,
+    #[error("table range overlaps an existing table")]
+    OverlapsExistingTable,
 }
 
 /// Identifier for a table within a worksheet.
@@ -924,6 +924,55 @@ impl WasmDaxDataModel {
             .map_err(|err| super::js_err(err.to_string()))
     }
 
+    /// Evaluate a scalar DAX expression against the model, optionally under a filter context.
+    ///
+    /// Supported subset: `SUM`/`AVERAGE`/`MIN`/`MAX`/`COUNTROWS` style aggregations, iterators
+    /// such as `SUMX(table, expr)`, `FILTER(table, predicate)`, and
+    /// `CALCULATE(expr, filter...)` where each filter argument is a boolean column predicate
+    /// (`Table[Column] = value`, `<>`, `<`, `>`, ...), a `FILTER(...)` table, or
+    /// `ALL(...)`/`REMOVEFILTERS(...)`. Measures may be referenced as `[Name]`. Expressions that
+    /// produce a table rather than a scalar are rejected.
+    ///
+    /// The result is returned as `null` (BLANK), a number, a string, or a boolean. Like
+    /// [`DaxModel::evaluate`], this consumes `filterContext`; use
+    /// [`evaluate_dax_with_filter`](Self::evaluate_dax_with_filter) to reuse one.
+    #[wasm_bindgen(js_name = "evaluateDax")]
+    pub fn evaluate_dax(
+        &self,
+        expression: &str,
+        filter_context: Option<DaxFilterContext>,
+    ) -> Result<JsValue, JsValue> {
+        let filter = filter_context
+            .map(|ctx| ctx.ctx)
+            .unwrap_or_else(FilterContext::empty);
+        self.evaluate_dax_value(expression, &filter)
+            .map(dax_value_to_js)
+            .map_err(|err| dax_error_to_js(*err))
+    }
+
+    /// Like [`evaluate_dax`](Self::evaluate_dax), but borrows the provided filter context instead
+    /// of consuming it.
+    #[wasm_bindgen(js_name = "evaluateDaxWithFilter")]
+    pub fn evaluate_dax_with_filter(
+        &self,
+        expression: &str,
+        filter_context: &DaxFilterContext,
+    ) -> Result<JsValue, JsValue> {
+        self.evaluate_dax_value(expression, &filter_context.ctx)
+            .map(dax_value_to_js)
+            .map_err(|err| dax_error_to_js(*err))
+    }
+
+    fn evaluate_dax_value(
+        &self,
+        expression: &str,
+        filter: &FilterContext,
+    ) -> Result<Value, Box<DaxError>> {
+        DaxEngine::new()
+            .evaluate(&self.inner, expression, filter, &RowContext::default())
+            .map_err(Box::new)
+    }
+
     #[wasm_bindgen(js_name = "pivot")]
     pub fn pivot(&self, request: JsValue) -> Result<JsValue, JsValue> {
         let request: PivotRequestDto = serde_wasm_bindgen::from_value(request)
@@ -1127,4 +1176,88 @@ mod tests {
             assert_eq!(back, value);
         }
     }
+
+    fn build_sales_model() -> WasmDaxDataModel {
+        let mut model = DataModel::new();
+
+        let mut customers = Table::new("Customers", vec!["CustomerId", "Region"]);
+        customers.push_row(vec![1.into(), "East".into()]).unwrap();
+        customers.push_row(vec![2.into(), "West".into()]).unwrap();
+        model.add_table(customers).unwrap();
+
+        let mut orders = Table::new("Orders", vec!["OrderId", "CustomerId", "Amount"]);
+        orders
+            .push_row(vec![100.into(), 1.into(), 10.0.into()])
+            .unwrap();
+        orders
+            .push_row(vec![101.into(), 1.into(), 20.0.into()])
+            .unwrap();
+        orders
+            .push_row(vec![102.into(), 2.into(), 5.0.into()])
+            .unwrap();
+        model.add_table(orders).unwrap();
+
+        model
+            .add_relationship(Relationship {
+                name: "Orders_Customers".into(),
+                from_table: "Orders".into(),
+                from_column: "CustomerId".into(),
+                to_table: "Customers".into(),
+                to_column: "CustomerId".into(),
+                cardinality: Cardinality::OneToMany,
+                cross_filter_direction: CrossFilterDirection::Single,
+                is_active: true,
+                enforce_referential_integrity: true,
+            })
+            .unwrap();
+
+        WasmDaxDataModel { inner: model }
+    }
+
+    #[test]
+    fn evaluate_dax_supports_calculate_sumx_and_filter() {
+        let model = build_sales_model();
+        let empty = FilterContext::empty();
+
+        let eval = |expr: &str, filter: &FilterContext| model.evaluate_dax_value(expr, filter);
+
+        assert_eq!(
+            eval("SUM(Orders[Amount])", &empty).unwrap(),
+            Value::from(35.0)
+        );
+        assert_eq!(
+            eval(
+                "CALCULATE(SUM(Orders[Amount]), Customers[Region] = \"East\")",
+                &empty
+            )
+            .unwrap(),
+            Value::from(30.0)
+        );
+        assert_eq!(
+            eval(
+                "SUMX(FILTER(Orders, Orders[Amount] > 8), Orders[Amount] * 2)",
+                &empty
+            )
+            .unwrap(),
+            Value::from(60.0)
+        );
+
+        // CALCULATE filter arguments replace existing filters on the same column.
+        let mut west = FilterContext::empty();
+        west.set_column_equals("Customers", "Region", "West".into());
+        assert_eq!(
+            eval("SUM(Orders[Amount])", &west).unwrap(),
+            Value::from(5.0)
+        );
+        assert_eq!(
+            eval(
+                "CALCULATE(SUM(Orders[Amount]), Customers[Region] = \"East\")",
+                &west
+            )
+            .unwrap(),
+            Value::from(30.0)
+        );
+
+        assert!(eval("SUM(Orders[Missing])", &empty).is_err());
+    }
 }
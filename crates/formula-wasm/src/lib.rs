@@ -7,6 +7,7 @@ use formula_engine::locale::{
     text_codepage_for_locale_id, FormulaLocale, ValueLocaleConfig, EN_US,
 };
 use formula_engine::pivot as pivot_engine;
+use formula_engine::sort_filter::{SortKey, SortOrder, SortValueType};
 use formula_engine::what_if::{
     goal_seek::{GoalSeek, GoalSeekParams, GoalSeekResult},
     CellRef as WhatIfCellRef, CellValue as WhatIfCellValue, WhatIfError, WhatIfModel,
@@ -14,15 +15,16 @@ use formula_engine::what_if::{
 use formula_engine::{
     metadata::FormatRun as EngineFormatRun, CellAddr, Coord, EditError as EngineEditError,
     EditOp as EngineEditOp, EditResult as EngineEditResult, Engine, EngineInfo, ErrorKind,
-    NameDefinition, NameScope, ParseOptions, Span as EngineSpan, Token, TokenKind,
-    Value as EngineValue,
+    FormulaLintKind, LintSeverity, NameDefinition, NameScope, ParseOptions, RecalcProgress,
+    Span as EngineSpan, StyleLayer, TextToColumnsSplit, Token, TokenKind, Value as EngineValue,
 };
 use formula_model::{
     display_formula_text, push_column_label, Alignment, CellRef, CellValue, Color, DateSystem,
-    DefinedNameScope, Font, HorizontalAlignment, Protection, Range, SheetVisibility, Style,
-    TabColor, VerticalAlignment, EXCEL_MAX_COLS, EXCEL_MAX_ROWS,
+    DefinedNameScope, Font, HorizontalAlignment, Hyperlink, HyperlinkTarget, Protection, Range,
+    SheetProtection, SheetVisibility, Style, TabColor, Table, VerticalAlignment, EXCEL_MAX_COLS,
+    EXCEL_MAX_ROWS,
 };
-use js_sys::{Array, Object, Reflect};
+use js_sys::{Array, Float64Array, Object, Reflect, Uint8Array};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use wasm_bindgen::prelude::*;
@@ -94,6 +96,19 @@ struct GoalSeekResponseDto {
     changes: Vec<CellChange>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+struct ScenarioOverrideDto {
+    address: String,
+    value: JsonValue,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EvaluateScenarioResponseDto {
+    values: Vec<JsonValue>,
+    changes: Vec<CellChange>,
+}
+
 #[derive(Clone, Debug, Default)]
 struct GoalSeekTuning {
     max_iterations: Option<usize>,
@@ -109,6 +124,10 @@ fn js_err(message: impl ToString) -> JsValue {
 
 const OFFICE_CRYPTO_ERROR_PREFIX: &str = "OFFICE_CRYPTO_ERROR:";
 
+/// Prefix for errors raised when editing a locked cell on a protected sheet, so callers can tell
+/// them apart from invalid-input errors.
+const SHEET_PROTECTED_ERROR_PREFIX: &str = "SHEET_PROTECTED:";
+
 fn office_crypto_kind_and_message(
     err: &formula_office_crypto::OfficeCryptoError,
 ) -> (&'static str, String) {
@@ -467,6 +486,65 @@ fn parse_font_from_js(value: &JsValue, top_level_strike: Option<bool>) -> Option
     }
 }
 
+fn text_to_columns_split_from_dto(
+    delimiter: Option<String>,
+    fixed_widths: Option<Vec<u32>>,
+) -> Result<TextToColumnsSplit, JsValue> {
+    match (delimiter, fixed_widths) {
+        (Some(delimiter), None) => {
+            let mut chars = delimiter.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => Ok(TextToColumnsSplit::Delimiter(ch)),
+                _ => Err(js_err(format!(
+                    "delimiter must be a single character, got {delimiter:?}"
+                ))),
+            }
+        }
+        (None, Some(widths)) => Ok(TextToColumnsSplit::FixedWidths(widths)),
+        _ => Err(js_err(
+            "TextToColumns requires exactly one of `delimiter` or `fixedWidths`",
+        )),
+    }
+}
+
+/// Returns the literal link location of a formula whose top-level expression is `HYPERLINK(...)`.
+fn hyperlink_formula_location(formula: &str) -> Option<String> {
+    let ast = formula_engine::parse_formula(formula, ParseOptions::default()).ok()?;
+    let formula_engine::Expr::FunctionCall(call) = ast.expr else {
+        return None;
+    };
+    if call.name.name_upper != "HYPERLINK" {
+        return None;
+    }
+    match call.args.into_iter().next()? {
+        formula_engine::Expr::String(location) if !location.is_empty() => Some(location),
+        _ => None,
+    }
+}
+
+/// Overlay the components `patch` sets (font, fill, border, ...) onto `base`.
+fn merge_style(mut base: Style, patch: &Style) -> Style {
+    if patch.font.is_some() {
+        base.font = patch.font.clone();
+    }
+    if patch.fill.is_some() {
+        base.fill = patch.fill.clone();
+    }
+    if patch.border.is_some() {
+        base.border = patch.border.clone();
+    }
+    if patch.alignment.is_some() {
+        base.alignment = patch.alignment.clone();
+    }
+    if patch.protection.is_some() {
+        base.protection = patch.protection.clone();
+    }
+    if patch.number_format.is_some() {
+        base.number_format = patch.number_format.clone();
+    }
+    base
+}
+
 fn parse_style_from_js(style: JsValue) -> Result<Style, JsValue> {
     if style.is_null() || style.is_undefined() {
         return Ok(Style::default());
@@ -850,6 +928,37 @@ pub fn get_locale_info(locale_id: &str) -> Result<JsValue, JsValue> {
     serde_wasm_bindgen::to_value(&info).map_err(|err| js_err(err.to_string()))
 }
 
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EngineFeaturesDto {
+    dax: bool,
+    xlsx_vba: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EngineInfoDto {
+    version: &'static str,
+    features: EngineFeaturesDto,
+    function_count: usize,
+    max_rows: u32,
+    max_cols: u32,
+}
+
+fn engine_info_dto() -> EngineInfoDto {
+    ensure_rust_constructors_run();
+    EngineInfoDto {
+        version: env!("CARGO_PKG_VERSION"),
+        features: EngineFeaturesDto {
+            dax: cfg!(feature = "dax"),
+            xlsx_vba: cfg!(feature = "xlsx-vba"),
+        },
+        function_count: formula_engine::functions::iter_function_specs().count(),
+        max_rows: EXCEL_MAX_ROWS,
+        max_cols: EXCEL_MAX_COLS,
+    }
+}
+
 fn require_formula_locale(locale_id: &str) -> Result<&'static FormulaLocale, JsValue> {
     get_locale(locale_id).ok_or_else(|| {
         let supported = supported_locale_ids_sorted().join(", ");
@@ -2183,17 +2292,27 @@ struct WorkbookState {
     /// This is not currently modeled by the calc engine, but we preserve it for UI/workbook
     /// metadata consumers (e.g. `WorkbookInfo.sheets[*].tabColor`).
     sheet_tab_colors: HashMap<String, TabColor>,
+    /// Worksheet protection (`<sheetProtection>`) for protected sheets, keyed by display name.
+    ///
+    /// While a sheet is protected, `setCell` & co. reject edits to locked cells. The engine only
+    /// tracks the enabled flag (see `Engine::set_sheet_protection_enabled`).
+    sheet_protection: HashMap<String, SheetProtection>,
     /// Per-sheet per-column width overrides in Excel "character" units (OOXML `col/@width`).
     ///
     /// This is separate from the calc engine's grid state today; it exists to support worksheet
     /// information functions like `CELL("width")` and to preserve imported column widths.
     col_widths_chars: BTreeMap<String, BTreeMap<u32, f32>>,
+    /// Per-sheet worksheet hyperlinks (`<hyperlinks>`), keyed by sheet display name.
+    ///
+    /// The calc engine does not model hyperlinks; they are preserved from import so the UI can
+    /// render link affordances (see `getHyperlinks`).
+    hyperlinks: BTreeMap<String, Vec<Hyperlink>>,
     /// Spill cells that were cleared by edits since the last recalc.
     ///
     /// `Engine::recalculate_with_value_changes` can only diff values across a recalc tick; when a
     /// spill is cleared as part of `setCell`/`setRange` we stash the affected cells so the next
     /// `recalculate()` call can return `CellChange[]` entries that blank out any now-stale spill
-    /// outputs in the JS cache.
+    /// outputs in the JS cache. Spill origins that an edit marked `#SPILL!` are queued here too.
     pending_spill_clears: BTreeSet<FormulaCellKey>,
     /// Formula cells that were edited since the last recalc, keyed by their previous visible value.
     ///
@@ -2315,6 +2434,15 @@ impl WhatIfModel for WorkbookGoalSeekModel<'_> {
     }
 }
 
+/// One `setCells` entry: a scalar input (value, formula string or `null`) for `sheet!address`.
+#[derive(Clone, Debug, Deserialize)]
+struct CellUpdateDto {
+    address: String,
+    value: JsonValue,
+    #[serde(default)]
+    sheet: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct FormatRunDto {
@@ -2323,6 +2451,42 @@ struct FormatRunDto {
     style_id: u32,
 }
 
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StyleLayerDto {
+    layer: &'static str,
+    style_id: u32,
+    properties: Vec<&'static str>,
+    supplied: Vec<&'static str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct HyperlinkDto {
+    address: String,
+    target: Option<String>,
+    tooltip: Option<String>,
+    location: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CellMetadataDto {
+    input: JsonValue,
+    value: JsonValue,
+    value_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    formula: Option<String>,
+    style_id: u32,
+    number_format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phonetic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hyperlink: Option<HyperlinkDto>,
+    is_spill_origin: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spill_range: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(tag = "type")]
 enum EditOpDto {
@@ -2379,6 +2543,49 @@ enum EditOpDto {
         src: String,
         dst: String,
     },
+    /// Exactly one of `delimiter` (a single character) or `fixedWidths` must be provided.
+    TextToColumns {
+        sheet: String,
+        src: String,
+        #[serde(default)]
+        delimiter: Option<String>,
+        #[serde(default, rename = "fixedWidths")]
+        fixed_widths: Option<Vec<u32>>,
+        #[serde(default, rename = "shiftRight")]
+        shift_right: bool,
+    },
+    RemoveDuplicates {
+        sheet: String,
+        range: String,
+        #[serde(rename = "keyColumns")]
+        key_columns: Vec<u32>,
+    },
+    SortRange {
+        sheet: String,
+        range: String,
+        keys: Vec<SortRangeKeyDto>,
+        #[serde(default, rename = "hasHeader")]
+        has_header: bool,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SortRangeKeyDto {
+    /// Sheet column index (0-based); must lie inside the sorted range.
+    col: u32,
+    #[serde(default)]
+    order: SortOrderDto,
+    #[serde(default)]
+    custom_list: Option<Vec<String>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum SortOrderDto {
+    #[default]
+    Ascending,
+    Descending,
 }
 
 #[derive(Clone, Debug, Serialize, PartialEq)]
@@ -2387,6 +2594,7 @@ struct EditResultDto {
     changed_cells: Vec<EditCellChangeDto>,
     moved_ranges: Vec<EditMovedRangeDto>,
     formula_rewrites: Vec<EditFormulaRewriteDto>,
+    removed_rows: u32,
 }
 
 #[derive(Clone, Debug, Serialize, PartialEq)]
@@ -2425,6 +2633,84 @@ struct EditFormulaRewriteDto {
     after: String,
 }
 
+/// Options accepted by `addTable`.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddTableOptionsDto {
+    /// Whether the first row of the range holds column headers. When omitted, headers are
+    /// detected from the first row (every cell non-empty text).
+    #[serde(default)]
+    has_headers: Option<bool>,
+    /// Table style name. Defaults to Excel's `TableStyleMedium2`.
+    #[serde(default)]
+    style_name: Option<String>,
+}
+
+/// Excel table (ListObject) metadata returned by `getSheetTables`.
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SheetTableDto {
+    name: String,
+    display_name: String,
+    /// A1 range covering the whole table, including header and totals rows.
+    range: String,
+    header_row_count: u32,
+    totals_row_count: u32,
+    columns: Vec<SheetTableColumnDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<SheetTableStyleDto>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SheetTableColumnDto {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    formula: Option<String>,
+    has_totals_formula: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    totals_formula: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SheetTableStyleDto {
+    name: String,
+    show_first_column: bool,
+    show_last_column: bool,
+    show_row_stripes: bool,
+    show_column_stripes: bool,
+}
+
+impl From<&Table> for SheetTableDto {
+    fn from(table: &Table) -> Self {
+        Self {
+            name: table.name.clone(),
+            display_name: table.display_name.clone(),
+            range: table.range.to_string(),
+            header_row_count: table.header_row_count,
+            totals_row_count: table.totals_row_count,
+            columns: table
+                .columns
+                .iter()
+                .map(|column| SheetTableColumnDto {
+                    name: column.name.clone(),
+                    formula: column.formula.clone(),
+                    has_totals_formula: column.totals_formula.is_some(),
+                    totals_formula: column.totals_formula.clone(),
+                })
+                .collect(),
+            style: table.style.as_ref().map(|style| SheetTableStyleDto {
+                name: style.name.clone(),
+                show_first_column: style.show_first_column,
+                show_last_column: style.show_last_column,
+                show_row_stripes: style.show_row_stripes,
+                show_column_stripes: style.show_column_stripes,
+            }),
+        }
+    }
+}
+
 impl WorkbookState {
     fn new_empty() -> Self {
         ensure_rust_constructors_run();
@@ -2436,7 +2722,9 @@ impl WorkbookState {
             sheet_lookup: HashMap::new(),
             sheet_visibility: HashMap::new(),
             sheet_tab_colors: HashMap::new(),
+            sheet_protection: HashMap::new(),
             col_widths_chars: BTreeMap::new(),
+            hyperlinks: BTreeMap::new(),
             pending_spill_clears: BTreeSet::new(),
             pending_formula_baselines: BTreeMap::new(),
         }
@@ -2545,6 +2833,32 @@ impl WorkbookState {
         })
     }
 
+    fn set_row_height_internal(
+        &mut self,
+        name: &str,
+        row: u32,
+        height: Option<f32>,
+    ) -> Result<(), JsValue> {
+        if row >= EXCEL_MAX_ROWS {
+            return Err(js_err(format!("row out of Excel bounds: {row}")));
+        }
+
+        if let Some(height) = height {
+            if !height.is_finite() || height < 0.0 {
+                return Err(js_err(
+                    "height must be a non-negative finite number".to_string(),
+                ));
+            }
+        }
+
+        // Preserve explicit-recalc semantics even when the workbook's calcMode is automatic.
+        self.with_manual_calc_mode(|this| {
+            let sheet = this.ensure_sheet(name);
+            this.engine.set_row_height(&sheet, row, height);
+            Ok(())
+        })
+    }
+
     fn set_workbook_file_metadata_internal(
         &mut self,
         directory: Option<&str>,
@@ -2568,6 +2882,138 @@ impl WorkbookState {
             .ok_or_else(|| js_err(format!("missing sheet: {name}")))
     }
 
+    fn get_sheet_tables_internal(&self, name: &str) -> Result<Vec<SheetTableDto>, JsValue> {
+        let sheet = self.require_sheet(name)?;
+        Ok(self
+            .engine
+            .sheet_tables(sheet)
+            .unwrap_or_default()
+            .iter()
+            .map(SheetTableDto::from)
+            .collect())
+    }
+
+    fn add_table_internal(
+        &mut self,
+        sheet: &str,
+        range_a1: &str,
+        name: &str,
+        options: AddTableOptionsDto,
+    ) -> Result<(), JsValue> {
+        let sheet = self.require_sheet(sheet)?.to_string();
+        let range = Self::parse_range(range_a1)?;
+
+        let header_values: Vec<EngineValue> = (range.start.col..=range.end.col)
+            .map(|col| {
+                let address = formula_model::cell_to_a1(range.start.row, col);
+                self.engine.get_cell_value(&sheet, &address)
+            })
+            .collect();
+        let has_headers = options.has_headers.unwrap_or_else(|| {
+            range.height() > 1
+                && header_values
+                    .iter()
+                    .all(|v| matches!(v, EngineValue::Text(s) if !s.trim().is_empty()))
+        });
+
+        let mut columns: Vec<formula_model::TableColumn> = Vec::new();
+        for (idx, value) in header_values.iter().enumerate() {
+            let position = idx as u32 + 1;
+            let header = match value {
+                _ if !has_headers => String::new(),
+                EngineValue::Text(s) => s.trim().to_string(),
+                EngineValue::Number(n) => n.to_string(),
+                EngineValue::Bool(b) => if *b { "TRUE" } else { "FALSE" }.to_string(),
+                _ => String::new(),
+            };
+            let base = if header.is_empty() {
+                format!("Column{position}")
+            } else {
+                header
+            };
+            // Column names are unique within a table (case-insensitive); Excel appends a counter.
+            let mut name = base.clone();
+            let mut suffix = 2u32;
+            while columns.iter().any(|c| c.name.eq_ignore_ascii_case(&name)) {
+                name = format!("{base}{suffix}");
+                suffix += 1;
+            }
+            columns.push(formula_model::TableColumn {
+                id: position,
+                name,
+                formula: None,
+                totals_formula: None,
+            });
+        }
+
+        let table = Table {
+            id: 0,
+            name: name.to_string(),
+            display_name: name.to_string(),
+            range,
+            header_row_count: u32::from(has_headers),
+            totals_row_count: 0,
+            columns,
+            style: Some(formula_model::table::TableStyleInfo {
+                name: options
+                    .style_name
+                    .unwrap_or_else(|| "TableStyleMedium2".to_string()),
+                show_first_column: false,
+                show_last_column: false,
+                show_row_stripes: true,
+                show_column_stripes: false,
+            }),
+            auto_filter: None,
+            relationship_id: None,
+            part_path: None,
+        };
+        self.engine
+            .add_table(&sheet, table)
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    fn resize_table_internal(&mut self, name: &str, range_a1: &str) -> Result<(), JsValue> {
+        let range = Self::parse_range(range_a1)?;
+        self.engine
+            .resize_table(name, range)
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    fn delete_table_internal(&mut self, name: &str) -> Result<(), JsValue> {
+        self.engine
+            .delete_table(name)
+            .map(|_| ())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// Grow a table by one row when `address` is the cell directly below its last row (Excel's
+    /// table auto-expansion). Tables with a totals row are never expanded.
+    fn auto_expand_table_for_edit(&mut self, sheet: &str, address: &str) -> Result<(), JsValue> {
+        let Some(sheet) = self.resolve_sheet(sheet).map(str::to_string) else {
+            return Ok(());
+        };
+        let cell_ref = Self::parse_address(address)?;
+        let Some((name, range)) = self
+            .engine
+            .sheet_tables(&sheet)
+            .unwrap_or_default()
+            .iter()
+            .find(|t| {
+                t.totals_row_count == 0
+                    && t.range.end.row.checked_add(1) == Some(cell_ref.row)
+                    && (t.range.start.col..=t.range.end.col).contains(&cell_ref.col)
+            })
+            .map(|t| (t.name.clone(), t.range))
+        else {
+            return Ok(());
+        };
+
+        let expanded = Range::new(range.start, CellRef::new(cell_ref.row, range.end.col));
+        // Expansion is best-effort: an adjacent table blocking the new row leaves both unchanged.
+        let _ = self.engine.resize_table(&name, expanded);
+        Ok(())
+    }
+
     fn set_sheet_display_name_internal(
         &mut self,
         sheet_key: &str,
@@ -2586,6 +3032,8 @@ impl WorkbookState {
         runs: Vec<EngineFormatRun>,
     ) -> Result<(), JsValue> {
         let sheet = self.ensure_sheet(sheet);
+        self.check_format_cells_allowed(&sheet)
+            .map_err(|err| js_err(format!("{SHEET_PROTECTED_ERROR_PREFIX}{err}")))?;
         self.engine
             .set_col_format_runs(&sheet, col, runs)
             .map_err(|err| js_err(err.to_string()))
@@ -2648,6 +3096,20 @@ impl WorkbookState {
         if let Some(color) = self.sheet_tab_colors.remove(&old_display) {
             self.sheet_tab_colors.insert(new_display.clone(), color);
         }
+        if let Some(protection) = self.sheet_protection.remove(&old_display) {
+            self.sheet_protection
+                .insert(new_display.clone(), protection);
+        }
+        if let Some(links) = self.hyperlinks.remove(&old_display) {
+            self.hyperlinks.insert(new_display.clone(), links);
+        }
+        for link in self.hyperlinks.values_mut().flatten() {
+            if let HyperlinkTarget::Internal { sheet, .. } = &mut link.target {
+                if formula_model::sheet_name_eq_case_insensitive(sheet, &old_display) {
+                    *sheet = new_display.clone();
+                }
+            }
+        }
 
         // Rename pending spill/formula bookkeeping entries so the next recalc tick stays coherent.
         if !self.pending_spill_clears.is_empty() {
@@ -2699,6 +3161,60 @@ impl WorkbookState {
         true
     }
 
+    fn rename_name_internal(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+        sheet: Option<&str>,
+    ) -> Result<(), JsValue> {
+        let scope_sheet = sheet
+            .map(|sheet| self.require_sheet(sheet).map(str::to_string))
+            .transpose()?;
+        let scope = match scope_sheet.as_deref() {
+            Some(sheet) => NameScope::Sheet(sheet),
+            None => NameScope::Workbook,
+        };
+
+        // Capture engine formula text for formula inputs so we can tell which cells the rename
+        // rewrote.
+        let mut formula_inputs: Vec<(String, String, Option<String>)> = Vec::new();
+        for (sheet, cells) in &self.sheets {
+            for (address, input) in cells {
+                if is_formula_input(input) {
+                    let before = self.engine.get_cell_formula(sheet, address);
+                    formula_inputs.push((
+                        sheet.clone(),
+                        address.clone(),
+                        before.map(str::to_string),
+                    ));
+                }
+            }
+        }
+
+        self.engine
+            .rename_name(old_name, new_name, scope)
+            .map_err(|err| js_err(err.to_string()))?;
+
+        // Keep stored formula inputs used by `toJson()` / `getCell.input` in sync.
+        for (sheet, address, before) in formula_inputs {
+            let after = self.engine.get_cell_formula(&sheet, &address);
+            if after == before.as_deref() {
+                continue;
+            }
+            let Some(after) = after.map(str::to_string) else {
+                continue;
+            };
+            if let Some(input) = self
+                .sheets
+                .get_mut(&sheet)
+                .and_then(|cells| cells.get_mut(&address))
+            {
+                *input = JsonValue::String(after);
+            }
+        }
+        Ok(())
+    }
+
     fn parse_address(address: &str) -> Result<CellRef, JsValue> {
         CellRef::from_a1(address).map_err(|_| js_err(format!("invalid cell address: {address}")))
     }
@@ -2770,6 +3286,8 @@ impl WorkbookState {
     ) -> Result<(), JsValue> {
         self.with_manual_calc_mode(|this| {
             let sheet = this.ensure_sheet(sheet);
+            this.check_format_cells_allowed(&sheet)
+                .map_err(|err| js_err(format!("{SHEET_PROTECTED_ERROR_PREFIX}{err}")))?;
             let cell_ref = Self::parse_address(address)?;
             let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
             this.engine
@@ -2788,142 +3306,486 @@ impl WorkbookState {
             .map_err(|err| js_err(err.to_string()))?;
         Ok(style_id.unwrap_or(0))
     }
-    fn set_cell_internal(
-        &mut self,
+
+    fn describe_style_resolution_internal(
+        &self,
         sheet: &str,
         address: &str,
-        input: JsonValue,
-    ) -> Result<(), JsValue> {
-        self.with_manual_calc_mode(|this| {
-            if !is_scalar_json(&input) {
-                return Err(js_err(format!("invalid cell value: {address}")));
-            }
+    ) -> Result<Vec<StyleLayerDto>, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        let cell_ref = Self::parse_address(address)?;
+        let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+        let layers = self
+            .engine
+            .describe_style_resolution(sheet, &address)
+            .map_err(|err| js_err(err.to_string()))?;
+        Ok(layers
+            .into_iter()
+            .map(|layer| StyleLayerDto {
+                layer: match layer.layer {
+                    StyleLayer::Sheet => "sheet",
+                    StyleLayer::Col => "col",
+                    StyleLayer::Row => "row",
+                    StyleLayer::RangeRun => "rangeRun",
+                    StyleLayer::Cell => "cell",
+                },
+                style_id: layer.style_id,
+                properties: layer.properties,
+                supplied: layer.supplied,
+            })
+            .collect())
+    }
 
-            let sheet = this.ensure_sheet(sheet);
-            let cell_ref = Self::parse_address(address)?;
-            let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+    fn get_hyperlinks_internal(&self, sheet: &str) -> Result<Vec<HyperlinkDto>, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        let stored = self.hyperlinks.get(sheet).map(Vec::as_slice).unwrap_or(&[]);
+
+        let mut out: Vec<(CellRef, HyperlinkDto)> = stored
+            .iter()
+            .map(|link| {
+                let (target, location) = match &link.target {
+                    HyperlinkTarget::ExternalUrl { uri } | HyperlinkTarget::Email { uri } => {
+                        (Some(uri.clone()), None)
+                    }
+                    HyperlinkTarget::Internal { sheet, cell } => {
+                        let mut location = String::new();
+                        formula_model::push_sheet_name_a1(&mut location, sheet);
+                        location.push('!');
+                        location.push_str(&cell.to_a1());
+                        (None, Some(location))
+                    }
+                };
+                let dto = HyperlinkDto {
+                    address: link.range.to_string(),
+                    target,
+                    tooltip: link.tooltip.clone(),
+                    location,
+                };
+                (link.range.start, dto)
+            })
+            .collect();
 
-            // Legacy scalar edits overwrite any previous rich input for this cell.
-            if let Some(rich_cells) = this.sheets_rich.get_mut(&sheet) {
-                rich_cells.remove(&address);
+        // `HYPERLINK()` formulas only surface their friendly name as the cell value, so recover the
+        // destination from the formula itself when it is a literal string. Cells that already carry
+        // a worksheet hyperlink keep that one (Excel follows the cell hyperlink on click).
+        if let Some(cells) = self.sheets.get(sheet) {
+            for address in cells.keys() {
+                let Ok(cell_ref) = Self::parse_address(address) else {
+                    continue;
+                };
+                if stored.iter().any(|link| link.range.contains(cell_ref)) {
+                    continue;
+                }
+                let Some(link_location) = self
+                    .engine
+                    .get_cell_formula(sheet, address)
+                    .and_then(hyperlink_formula_location)
+                else {
+                    continue;
+                };
+                let (target, location) = match link_location.strip_prefix('#') {
+                    Some(location) => (None, Some(location.to_string())),
+                    None => (Some(link_location), None),
+                };
+                out.push((
+                    cell_ref,
+                    HyperlinkDto {
+                        address: address.clone(),
+                        target,
+                        tooltip: None,
+                        location,
+                    },
+                ));
             }
+        }
 
-            if let Some((origin, end)) = this.engine.spill_range(&sheet, &address) {
-                let edited_row = cell_ref.row;
-                let edited_col = cell_ref.col;
-                let edited_is_formula = is_formula_input(&input);
-                for row in origin.row..=end.row {
-                    for col in origin.col..=end.col {
-                        // Skip the origin cell (top-left); we only need to clear spill outputs.
-                        if row == origin.row && col == origin.col {
-                            continue;
-                        }
-                        // If the user overwrote a spill output cell with a literal value, don't emit a
-                        // spill-clear change for that cell; the caller already knows its new input.
-                        if !edited_is_formula && row == edited_row && col == edited_col {
-                            continue;
-                        }
-                        this.pending_spill_clears
-                            .insert(FormulaCellKey::new(sheet.clone(), CellRef::new(row, col)));
+        out.sort_by_key(|(cell, _)| (cell.row, cell.col));
+        Ok(out.into_iter().map(|(_, dto)| dto).collect())
+    }
+
+    fn get_number_format_internal(&self, sheet: &str, address: &str) -> Result<String, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        let cell_ref = Self::parse_address(address)?;
+        let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+        let format = self
+            .engine
+            .effective_cell_number_format(sheet, &address)
+            .map_err(|err| js_err(err.to_string()))?;
+        Ok(format.unwrap_or_else(|| "General".to_string()))
+    }
+
+    /// Everything an inspector panel shows for one cell, assembled from the individual getters.
+    fn get_cell_metadata_internal(
+        &self,
+        sheet: &str,
+        address: &str,
+    ) -> Result<CellMetadataDto, JsValue> {
+        let CellData {
+            sheet,
+            address,
+            input,
+            value,
+        } = self.get_cell_data(sheet, address)?;
+        let cell_ref = Self::parse_address(&address)?;
+
+        let value_type = match columnar_scalar(self.engine.get_cell_value(&sheet, &address)).0 {
+            COLUMNAR_TYPE_BLANK => "blank",
+            COLUMNAR_TYPE_NUMBER => "number",
+            COLUMNAR_TYPE_BOOL => "bool",
+            COLUMNAR_TYPE_ERROR => "error",
+            _ => "text",
+        };
+        let hyperlink = self
+            .get_hyperlinks_internal(&sheet)?
+            .into_iter()
+            .find(|link| Range::from_a1(&link.address).is_ok_and(|range| range.contains(cell_ref)));
+        let spill = self.spill_range_internal(&sheet, &address)?;
+
+        Ok(CellMetadataDto {
+            input,
+            value,
+            value_type,
+            formula: self
+                .engine
+                .get_cell_formula(&sheet, &address)
+                .map(str::to_string),
+            style_id: self.get_cell_style_id_internal(&sheet, &address)?,
+            number_format: self.get_number_format_internal(&sheet, &address)?,
+            phonetic: self
+                .engine
+                .get_cell_phonetic(&sheet, &address)
+                .map(str::to_string),
+            hyperlink,
+            is_spill_origin: spill.is_some_and(|range| range.start == cell_ref),
+            spill_range: spill.map(|range| range.to_string()),
+        })
+    }
+
+    /// The full extent (origin inclusive) of the spill `address` belongs to, whether it is the
+    /// origin or one of the output cells.
+    fn spill_range_internal(&self, sheet: &str, address: &str) -> Result<Option<Range>, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        let cell_ref = Self::parse_address(address)?;
+        let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+        let spill = self.engine.spill_range(sheet, &address);
+        Ok(spill.map(|(origin, end)| {
+            Range::new(
+                CellRef::new(origin.row, origin.col),
+                CellRef::new(end.row, end.col),
+            )
+        }))
+    }
+
+    fn get_cell_display_text_internal(
+        &self,
+        sheet: &str,
+        address: &str,
+    ) -> Result<String, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        let cell_ref = Self::parse_address(address)?;
+        let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+        self.engine
+            .cell_display_text(sheet, &address)
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// Re-point every cell in `range` at a style that matches its current style except for the
+    /// number format. `"General"` (or an empty string) removes the cell-level number format.
+    ///
+    /// Whole rows/columns and large blocks are formatted through the row/col/format-run layers
+    /// (see `Engine::update_range_style`) rather than one cell at a time.
+    fn set_number_format_internal(
+        &mut self,
+        sheet: &str,
+        range: Range,
+        format: &str,
+    ) -> Result<(), JsValue> {
+        let format = format.trim();
+        let number_format = (!format.is_empty() && !format.eq_ignore_ascii_case("general"))
+            .then(|| format.to_string());
+
+        self.with_manual_calc_mode(|this| {
+            let sheet = this.ensure_sheet(sheet);
+            this.check_format_cells_allowed(&sheet)
+                .map_err(|err| js_err(format!("{SHEET_PROTECTED_ERROR_PREFIX}{err}")))?;
+            // An explicit per-cell override would shadow the style's number format.
+            this.engine
+                .clear_range_cell_number_formats(&sheet, range)
+                .map_err(|err| js_err(err.to_string()))?;
+            this.engine
+                .update_range_style(&sheet, range, |mut style| {
+                    style.number_format = number_format.clone();
+                    style
+                })
+                .map_err(|err| js_err(err.to_string()))
+        })
+    }
+
+    /// Point every cell in `range` at `style`. With `merge`, each cell keeps the parts of its
+    /// current style that `style` leaves unset. Returns the number of cells covered (saturating at
+    /// `u32::MAX`).
+    ///
+    /// Whole rows/columns and large blocks are styled through the row/col/format-run layers (see
+    /// `Engine::update_range_style`) rather than one cell at a time.
+    fn set_range_style_internal(
+        &mut self,
+        sheet: &str,
+        range: Range,
+        style: Style,
+        merge: bool,
+    ) -> Result<u32, JsValue> {
+        self.with_manual_calc_mode(|this| {
+            let sheet = this.ensure_sheet(sheet);
+            this.check_format_cells_allowed(&sheet)
+                .map_err(|err| js_err(format!("{SHEET_PROTECTED_ERROR_PREFIX}{err}")))?;
+            this.engine
+                .update_range_style(&sheet, range, |base| {
+                    if merge {
+                        merge_style(base, &style)
+                    } else {
+                        style.clone()
                     }
+                })
+                .map_err(|err| js_err(err.to_string()))?;
+            Ok(u32::try_from(range.cell_count()).unwrap_or(u32::MAX))
+        })
+    }
+
+    fn set_cell_internal(
+        &mut self,
+        sheet: &str,
+        address: &str,
+        input: JsonValue,
+    ) -> Result<(), JsValue> {
+        self.with_manual_calc_mode(|this| this.set_cell_in_manual_mode(sheet, address, input))
+    }
+
+    /// Apply a batch of `setCells` edits inside a single manual-calc scope.
+    ///
+    /// Updates are grouped by sheet (sheets in first-seen order, cells in input order within a
+    /// sheet), so the result matches calling `setCell` for each entry. The first failing entry
+    /// stops the batch with an error naming its cell; earlier entries stay applied.
+    /// Write a row-major block of inputs into `range`, growing tables like `setCell` does. Rows
+    /// are written top to bottom, so a block pasted below a table extends it one row at a time.
+    fn set_range_internal(
+        &mut self,
+        sheet: &str,
+        range: Range,
+        values: Vec<Vec<JsonValue>>,
+    ) -> Result<(), JsValue> {
+        for (r_idx, row_values) in values.into_iter().enumerate() {
+            for (c_idx, input) in row_values.into_iter().enumerate() {
+                let row = range.start.row + r_idx as u32;
+                let col = range.start.col + c_idx as u32;
+                let addr = formula_model::cell_to_a1(row, col);
+                let clears = input.is_null();
+                self.set_cell_internal(sheet, &addr, input)?;
+                if !clears {
+                    self.with_manual_calc_mode(|this| {
+                        this.auto_expand_table_for_edit(sheet, &addr)
+                    })?;
                 }
             }
+        }
+        Ok(())
+    }
 
-            let sheet_cells = this.sheets.entry(sheet.clone()).or_default();
+    fn set_cells_internal(&mut self, updates: Vec<CellUpdateDto>) -> Result<(), JsValue> {
+        let mut by_sheet: Vec<(String, String, Vec<CellUpdateDto>)> = Vec::new();
+        for update in updates {
+            let sheet = update.sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+            let key = normalize_sheet_key(sheet);
+            match by_sheet
+                .iter_mut()
+                .find(|(existing, _, _)| *existing == key)
+            {
+                Some((_, _, group)) => group.push(update),
+                None => by_sheet.push((key, sheet.to_string(), vec![update])),
+            }
+        }
 
-            // `null` represents an empty cell in the JS protocol. Preserve sparse semantics in the
-            // JSON input map by removing the stored entry instead of storing an explicit blank.
-            //
-            // In the engine, treat this as "clear contents" (value/formula -> blank) so formatting can
-            // be preserved when a cell has a non-default style.
-            if input.is_null() {
-                this.engine
+        self.with_manual_calc_mode(|this| {
+            for (_, sheet, group) in by_sheet {
+                for update in group {
+                    let clears = update.value.is_null();
+                    let address = update.address;
+                    this.set_cell_in_manual_mode(&sheet, &address, update.value)
+                        .and_then(|()| {
+                            if clears {
+                                Ok(())
+                            } else {
+                                this.auto_expand_table_for_edit(&sheet, &address)
+                            }
+                        })
+                        .map_err(|err| {
+                            let message = err.as_string().unwrap_or_default();
+                            js_err(format!("{message} (setCells: {sheet}!{address})"))
+                        })?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Body of [`Self::set_cell_internal`]; the caller must already be in manual calc mode.
+    fn set_cell_in_manual_mode(
+        &mut self,
+        sheet: &str,
+        address: &str,
+        input: JsonValue,
+    ) -> Result<(), JsValue> {
+        if !is_scalar_json(&input) {
+            return Err(js_err(format!("invalid cell value: {address}")));
+        }
+
+        let sheet = self.ensure_sheet(sheet);
+        let cell_ref = Self::parse_address(address)?;
+        let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+        self.check_cell_editable(&sheet, &address)
+            .map_err(|err| js_err(format!("{SHEET_PROTECTED_ERROR_PREFIX}{err}")))?;
+
+        // Legacy scalar edits overwrite any previous rich input for this cell.
+        if let Some(rich_cells) = self.sheets_rich.get_mut(&sheet) {
+            rich_cells.remove(&address);
+        }
+
+        // Writing content into a spill output (not the origin) blocks the spill.
+        let mut blocked_spill_origin = None;
+        if let Some((origin, end)) = self.engine.spill_range(&sheet, &address) {
+            let edited_row = cell_ref.row;
+            let edited_col = cell_ref.col;
+            let edited_is_formula = is_formula_input(&input);
+            if !input.is_null() && (origin.row, origin.col) != (edited_row, edited_col) {
+                blocked_spill_origin = Some(CellRef::new(origin.row, origin.col));
+            }
+            for row in origin.row..=end.row {
+                for col in origin.col..=end.col {
+                    // Skip the origin cell (top-left); we only need to clear spill outputs.
+                    if row == origin.row && col == origin.col {
+                        continue;
+                    }
+                    // If the user overwrote a spill output cell with a literal value, don't emit a
+                    // spill-clear change for that cell; the caller already knows its new input.
+                    if !edited_is_formula && row == edited_row && col == edited_col {
+                        continue;
+                    }
+                    self.pending_spill_clears
+                        .insert(FormulaCellKey::new(sheet.clone(), CellRef::new(row, col)));
+                }
+            }
+        }
+
+        let sheet_cells = self.sheets.entry(sheet.clone()).or_default();
+
+        // `null` represents an empty cell in the JS protocol. Preserve sparse semantics in the
+        // JSON input map by removing the stored entry instead of storing an explicit blank.
+        //
+        // In the engine, treat this as "clear contents" (value/formula -> blank) so formatting can
+        // be preserved when a cell has a non-default style.
+        if input.is_null() {
+            self.engine
+                .set_cell_value(&sheet, &address, EngineValue::Blank)
+                .map_err(|err| js_err(err.to_string()))?;
+
+            sheet_cells.remove(&address);
+            // If this cell was previously tracked as part of a spill-clear batch, drop it so we
+            // don't report direct input edits as recalc changes.
+            self.pending_spill_clears
+                .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
+            self.pending_formula_baselines
+                .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
+            return Ok(());
+        }
+
+        if is_formula_input(&input) {
+            let Some(raw) = input.as_str() else {
+                debug_assert!(
+                    false,
+                    "is_formula_input returned true but input was not a string: {input:?}"
+                );
+                return Err(js_err("invalid formula input".to_string()));
+            };
+            // Match `formula-model`'s display semantics so the worker protocol doesn't
+            // drift from other layers (trim both ends, strip a single leading '=', and
+            // treat bare '=' as empty).
+            let normalized = display_formula_text(raw);
+            if normalized.is_empty() {
+                // This should be unreachable because `is_formula_input` requires
+                // non-whitespace content after '=', but keep a defensive fallback so
+                // we never store a literal "=" formula.
+                self.engine
                     .set_cell_value(&sheet, &address, EngineValue::Blank)
                     .map_err(|err| js_err(err.to_string()))?;
-
                 sheet_cells.remove(&address);
-                // If this cell was previously tracked as part of a spill-clear batch, drop it so we
-                // don't report direct input edits as recalc changes.
-                this.pending_spill_clears
+                self.pending_spill_clears
                     .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
-                this.pending_formula_baselines
+                self.pending_formula_baselines
                     .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
                 return Ok(());
             }
 
-            if is_formula_input(&input) {
-                let Some(raw) = input.as_str() else {
-                    debug_assert!(
-                        false,
-                        "is_formula_input returned true but input was not a string: {input:?}"
-                    );
-                    return Err(js_err("invalid formula input".to_string()));
-                };
-                // Match `formula-model`'s display semantics so the worker protocol doesn't
-                // drift from other layers (trim both ends, strip a single leading '=', and
-                // treat bare '=' as empty).
-                let normalized = display_formula_text(raw);
-                if normalized.is_empty() {
-                    // This should be unreachable because `is_formula_input` requires
-                    // non-whitespace content after '=', but keep a defensive fallback so
-                    // we never store a literal "=" formula.
-                    this.engine
-                        .set_cell_value(&sheet, &address, EngineValue::Blank)
-                        .map_err(|err| js_err(err.to_string()))?;
-                    sheet_cells.remove(&address);
-                    this.pending_spill_clears
-                        .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
-                    this.pending_formula_baselines
-                        .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
-                    return Ok(());
-                }
-
-                let canonical = if this.formula_locale.id == EN_US.id {
-                    normalized
-                } else {
-                    canonicalize_formula_with_style(
-                        &normalized,
-                        this.formula_locale,
-                        formula_engine::ReferenceStyle::A1,
-                    )
-                    .map_err(|err| js_err(err.to_string()))?
-                };
+            let canonical = if self.formula_locale.id == EN_US.id {
+                normalized
+            } else {
+                canonicalize_formula_with_style(
+                    &normalized,
+                    self.formula_locale,
+                    formula_engine::ReferenceStyle::A1,
+                )
+                .map_err(|err| js_err(err.to_string()))?
+            };
 
-                let key = FormulaCellKey::new(sheet.clone(), cell_ref);
-                this.pending_formula_baselines
-                    .entry(key)
-                    .or_insert_with(|| {
-                        engine_value_to_json(this.engine.get_cell_value(&sheet, &address))
-                    });
+            let key = FormulaCellKey::new(sheet.clone(), cell_ref);
+            self.pending_formula_baselines
+                .entry(key)
+                .or_insert_with(|| {
+                    engine_value_to_json(self.engine.get_cell_value(&sheet, &address))
+                });
 
-                // Reset the stored value to blank so `getCell` returns null until the next recalc,
-                // matching the existing worker semantics.
-                this.engine
-                    .set_cell_value(&sheet, &address, EngineValue::Blank)
-                    .map_err(|err| js_err(err.to_string()))?;
-                this.engine
-                    .set_cell_formula(&sheet, &address, &canonical)
-                    .map_err(|err| js_err(err.to_string()))?;
+            // Reset the stored value to blank so `getCell` returns null until the next recalc,
+            // matching the existing worker semantics.
+            self.engine
+                .set_cell_value(&sheet, &address, EngineValue::Blank)
+                .map_err(|err| js_err(err.to_string()))?;
+            self.engine
+                .set_cell_formula(&sheet, &address, &canonical)
+                .map_err(|err| js_err(err.to_string()))?;
 
-                sheet_cells.insert(address.clone(), JsonValue::String(canonical));
-                return Ok(());
+            sheet_cells.insert(address.clone(), JsonValue::String(canonical));
+            if let Some(origin) = blocked_spill_origin {
+                self.mark_spill_origin_blocked(&sheet, origin);
             }
+            return Ok(());
+        }
 
-            // Non-formula scalar value.
-            this.engine
-                .set_cell_value(&sheet, &address, json_to_engine_value(&input))
-                .map_err(|err| js_err(err.to_string()))?;
+        // Non-formula scalar value.
+        self.engine
+            .set_cell_value(&sheet, &address, json_to_engine_value(&input))
+            .map_err(|err| js_err(err.to_string()))?;
 
-            sheet_cells.insert(address.clone(), input);
-            // If this cell was previously tracked as part of a spill-clear batch (e.g. a multi-cell
-            // paste over a spill range), drop it so we don't report direct input edits as recalc
-            // changes.
-            this.pending_spill_clears
-                .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
-            this.pending_formula_baselines
-                .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
-            Ok(())
-        })
+        sheet_cells.insert(address.clone(), input);
+        // If this cell was previously tracked as part of a spill-clear batch (e.g. a multi-cell
+        // paste over a spill range), drop it so we don't report direct input edits as recalc
+        // changes.
+        self.pending_spill_clears
+            .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
+        self.pending_formula_baselines
+            .remove(&FormulaCellKey::new(sheet.clone(), cell_ref));
+        if let Some(origin) = blocked_spill_origin {
+            self.mark_spill_origin_blocked(&sheet, origin);
+        }
+        Ok(())
+    }
+
+    /// Show `#SPILL!` on `origin` right after an edit blocked its spill, instead of leaving its
+    /// old value in place until the next recalc. The origin is queued with the spill clears so
+    /// that recalc reports its value (the engine does not, since the value changed beforehand).
+    fn mark_spill_origin_blocked(&mut self, sheet: &str, origin: CellRef) {
+        let address = formula_model::cell_to_a1(origin.row, origin.col);
+        if self.engine.mark_spill_origin_blocked(sheet, &address) {
+            self.pending_spill_clears
+                .insert(FormulaCellKey::new(sheet.to_string(), origin));
+        }
     }
 
     fn set_cell_rich_internal(
@@ -2970,6 +3832,8 @@ impl WorkbookState {
             let sheet = this.ensure_sheet(sheet);
             let cell_ref = Self::parse_address(address)?;
             let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+            this.check_cell_editable(&sheet, &address)
+                .map_err(|err| js_err(format!("{SHEET_PROTECTED_ERROR_PREFIX}{err}")))?;
 
             if let Some((origin, end)) = this.engine.spill_range(&sheet, &address) {
                 let edited_row = cell_ref.row;
@@ -3050,6 +3914,52 @@ impl WorkbookState {
         })
     }
 
+    /// Computed values for `range` (rows -> columns), shared by every range reader (`getRange`,
+    /// `getRangeCompact`, `getRangeValuesRaw`, `getRangeColumnar`).
+    ///
+    /// Like [`WorkbookState::get_cell_data`], these are the engine's last computed values: an
+    /// edit made since the last `recalculate()` shows up once it has been recalculated, and
+    /// cells without a value read as blank.
+    fn get_range_values_internal(
+        &self,
+        sheet: &str,
+        range: Range,
+    ) -> Result<Vec<Vec<EngineValue>>, JsValue> {
+        self.engine
+            .get_range_values(sheet, range)
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// Batch form of [`WorkbookState::get_cell_data`] for arbitrary (non-contiguous) cells, in the
+    /// order given.
+    fn get_cells_data(&self, sheet: &str, addresses: &[String]) -> Result<Vec<CellData>, JsValue> {
+        let sheet = self.require_sheet(sheet)?.to_string();
+        let cell_refs = addresses
+            .iter()
+            .map(|address| Self::parse_address(address))
+            .collect::<Result<Vec<_>, _>>()?;
+        let values = self.engine.get_cells_values(&sheet, &cell_refs);
+        let sheet_cells = self.sheets.get(&sheet);
+
+        Ok(cell_refs
+            .into_iter()
+            .zip(values)
+            .map(|(cell_ref, value)| {
+                let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+                let input = sheet_cells
+                    .and_then(|cells| cells.get(&address))
+                    .cloned()
+                    .unwrap_or(JsonValue::Null);
+                CellData {
+                    sheet: sheet.clone(),
+                    address,
+                    input,
+                    value: engine_value_to_json(value),
+                }
+            })
+            .collect())
+    }
+
     fn get_cell_rich_data(&self, sheet: &str, address: &str) -> Result<CellDataRich, JsValue> {
         let sheet = self.require_sheet(sheet)?.to_string();
         let cell_ref = Self::parse_address(address)?;
@@ -3081,6 +3991,14 @@ impl WorkbookState {
     }
 
     fn recalculate_internal(&mut self, sheet: Option<&str>) -> Result<Vec<CellChange>, JsValue> {
+        self.recalculate_internal_with_progress(sheet, None)
+    }
+
+    fn recalculate_internal_with_progress(
+        &mut self,
+        sheet: Option<&str>,
+        on_progress: Option<&mut dyn FnMut(RecalcProgress)>,
+    ) -> Result<Vec<CellChange>, JsValue> {
         // The JS worker protocol historically accepted a `sheet` argument for API symmetry, but
         // callers rely on `recalculate()` returning *all* value changes across the workbook so
         // client-side caches stay coherent across sheet switches.
@@ -3088,7 +4006,12 @@ impl WorkbookState {
         // Therefore we intentionally ignore `sheet` here (and do not validate it).
         let _ = sheet;
 
-        let recalc_changes = self.engine.recalculate_with_value_changes_single_threaded();
+        let recalc_changes = match on_progress {
+            Some(on_progress) => self
+                .engine
+                .recalculate_with_value_changes_and_progress(on_progress),
+            None => self.engine.recalculate_with_value_changes_single_threaded(),
+        };
         let mut by_cell: BTreeMap<FormulaCellKey, JsonValue> = BTreeMap::new();
 
         for change in recalc_changes {
@@ -3234,6 +4157,43 @@ impl WorkbookState {
         Ok((result, changes))
     }
 
+    fn evaluate_scenario_internal(
+        &mut self,
+        sheet: &str,
+        overrides: &[(String, JsonValue)],
+        targets: &[String],
+    ) -> Result<(Vec<JsonValue>, Vec<CellChange>), JsValue> {
+        let sheet = self.require_sheet(sheet)?.to_string();
+        let overrides = overrides
+            .iter()
+            .map(|(address, value)| {
+                if !is_scalar_json(value) || is_formula_input(value) {
+                    return Err(js_err(format!(
+                        "override for {address} must be a scalar value"
+                    )));
+                }
+                Ok((Self::parse_address(address)?, json_to_engine_value(value)))
+            })
+            .collect::<Result<Vec<_>, JsValue>>()?;
+        let targets = targets
+            .iter()
+            .map(|address| Self::parse_address(address))
+            .collect::<Result<Vec<_>, JsValue>>()?;
+
+        // The engine recalculates internally while evaluating the scenario. Flush any pending
+        // recalculation first so those value changes still reach the caller.
+        let changes = self.recalculate_internal(None)?;
+
+        let values = self
+            .engine
+            .evaluate_with_overrides(&sheet, &targets, &overrides)
+            .map_err(|err| js_err(err.to_string()))?;
+        Ok((
+            values.into_iter().map(engine_value_to_json).collect(),
+            changes,
+        ))
+    }
+
     fn collect_spill_output_cells(&self) -> BTreeSet<FormulaCellKey> {
         let mut out = BTreeSet::new();
         for (sheet_name, cells) in &self.sheets {
@@ -3332,6 +4292,72 @@ impl WorkbookState {
                 let dst = Self::parse_range(&dst)?;
                 Ok(EngineEditOp::Fill { sheet, src, dst })
             }
+            EditOpDto::TextToColumns {
+                sheet,
+                src,
+                delimiter,
+                fixed_widths,
+                shift_right,
+            } => {
+                let sheet = self.ensure_sheet(&sheet);
+                let src = Self::parse_range(&src)?;
+                let split = text_to_columns_split_from_dto(delimiter, fixed_widths)?;
+                Ok(EngineEditOp::TextToColumns {
+                    sheet,
+                    src,
+                    split,
+                    shift_right,
+                })
+            }
+            EditOpDto::SortRange {
+                sheet,
+                range,
+                keys,
+                has_header,
+            } => {
+                let sheet = self.ensure_sheet(&sheet);
+                let range = Self::parse_range(&range)?;
+                let keys = keys
+                    .into_iter()
+                    .map(|key| {
+                        if key.col < range.start.col || key.col > range.end.col {
+                            return Err(js_err(format!(
+                                "sort key column {} is outside the range {range}",
+                                key.col
+                            )));
+                        }
+                        Ok(SortKey {
+                            column: (key.col - range.start.col) as usize,
+                            order: match key.order {
+                                SortOrderDto::Ascending => SortOrder::Ascending,
+                                SortOrderDto::Descending => SortOrder::Descending,
+                            },
+                            value_type: SortValueType::Auto,
+                            case_sensitive: false,
+                            custom_list: key.custom_list,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, JsValue>>()?;
+                Ok(EngineEditOp::SortRange {
+                    sheet,
+                    range,
+                    keys,
+                    has_header,
+                })
+            }
+            EditOpDto::RemoveDuplicates {
+                sheet,
+                range,
+                key_columns,
+            } => {
+                let sheet = self.ensure_sheet(&sheet);
+                let range = Self::parse_range(&range)?;
+                Ok(EngineEditOp::RemoveDuplicates {
+                    sheet,
+                    range,
+                    key_columns,
+                })
+            }
         }
     }
 
@@ -3655,6 +4681,8 @@ impl WorkbookState {
         let out = (|| {
             let spill_outputs_before = self.collect_spill_output_cells();
             let op = self.edit_op_from_dto(dto)?;
+            self.check_edit_op_allowed(&op)
+                .map_err(|err| js_err(format!("{SHEET_PROTECTED_ERROR_PREFIX}{err}")))?;
             self.remap_pending_keys_for_edit(&op);
 
             let result: EngineEditResult = self
@@ -3814,6 +4842,7 @@ impl WorkbookState {
                 changed_cells,
                 moved_ranges,
                 formula_rewrites,
+                removed_rows: result.removed_rows,
             })
         })();
 
@@ -3838,12 +4867,392 @@ impl WorkbookState {
         }
         self.formula_locale = formula_locale;
         self.engine.set_locale_config(formula_locale.config.clone());
+        self.engine.set_display_locale(formula_locale);
         self.engine.set_value_locale(value_locale);
         self.engine.set_text_codepage(text_codepage);
         self.engine.set_calc_settings(previous);
         true
     }
-}
+
+    fn validate_formula_internal(&self, sheet: &str, formula: &str) -> WasmFormulaValidation {
+        // Validating against a sheet that does not exist yet is allowed (it would be created on
+        // commit); only its sheet-scoped names are unavailable.
+        let sheet = self.resolve_sheet(sheet).unwrap_or(sheet);
+        let result = if self.formula_locale.id == EN_US.id {
+            self.engine.validate_formula(sheet, formula)
+        } else {
+            self.engine
+                .validate_formula_localized(sheet, formula, self.formula_locale)
+        };
+        match result {
+            Ok(()) => WasmFormulaValidation {
+                ok: true,
+                error: None,
+                span: None,
+            },
+            Err(diagnostic) => WasmFormulaValidation {
+                ok: false,
+                error: Some(diagnostic.message),
+                span: Some(WasmSpan {
+                    start: byte_index_to_utf16_cursor(formula, diagnostic.span.start),
+                    end: byte_index_to_utf16_cursor(formula, diagnostic.span.end),
+                }),
+            },
+        }
+    }
+
+    fn analyze_formula_internal(
+        &self,
+        sheet: &str,
+        formula: &str,
+        origin: Option<&str>,
+    ) -> Result<WasmFormulaReferences, JsValue> {
+        let sheet = self.resolve_sheet(sheet).unwrap_or(sheet);
+        let origin = origin.map(Self::parse_address).transpose()?.map(|cell| {
+            formula_engine::eval::CellAddr {
+                row: cell.row,
+                col: cell.col,
+            }
+        });
+        let result = if self.formula_locale.id == EN_US.id {
+            self.engine.analyze_formula(sheet, formula, origin)
+        } else {
+            self.engine
+                .analyze_formula_localized(sheet, formula, origin, self.formula_locale)
+        };
+        let refs = result.map_err(|diagnostic| js_err(diagnostic.message))?;
+        Ok(WasmFormulaReferences {
+            ranges: refs.ranges,
+            names: refs.names,
+            tables: refs.tables,
+        })
+    }
+
+    fn lint_formula_internal(
+        &self,
+        sheet: &str,
+        formula: &str,
+        origin: Option<&str>,
+    ) -> Result<Vec<WasmFormulaLint>, JsValue> {
+        let sheet = self.resolve_sheet(sheet).unwrap_or(sheet);
+        let origin = origin.map(Self::parse_address).transpose()?.map(|cell| {
+            formula_engine::eval::CellAddr {
+                row: cell.row,
+                col: cell.col,
+            }
+        });
+        let result = if self.formula_locale.id == EN_US.id {
+            self.engine.lint_formula(sheet, formula, origin)
+        } else {
+            self.engine
+                .lint_formula_localized(sheet, formula, origin, self.formula_locale)
+        };
+        let lints = result.map_err(|diagnostic| js_err(diagnostic.message))?;
+        Ok(lints
+            .into_iter()
+            .map(|lint| WasmFormulaLint {
+                kind: match lint.kind {
+                    FormulaLintKind::EmptyRange => "emptyRange",
+                    FormulaLintKind::VolatileFunction => "volatileFunction",
+                    FormulaLintKind::InconsistentFormula => "inconsistentFormula",
+                    FormulaLintKind::BlankCellInArithmetic => "blankCellInArithmetic",
+                },
+                severity: match lint.severity {
+                    LintSeverity::Info => "info",
+                    LintSeverity::Warning => "warning",
+                },
+                message: lint.message,
+                span: WasmSpan {
+                    start: byte_index_to_utf16_cursor(formula, lint.span.start),
+                    end: byte_index_to_utf16_cursor(formula, lint.span.end),
+                },
+            })
+            .collect())
+    }
+
+    /// Protect `sheet` with `protection`, or unprotect it when `None`.
+    fn set_sheet_protection_internal(
+        &mut self,
+        sheet: &str,
+        protection: Option<SheetProtection>,
+    ) -> Result<(), JsValue> {
+        let sheet = self.require_sheet(sheet)?.to_string();
+        let enabled = match protection {
+            Some(protection) => {
+                self.sheet_protection.insert(
+                    sheet.clone(),
+                    SheetProtection {
+                        enabled: true,
+                        ..protection
+                    },
+                );
+                true
+            }
+            None => {
+                self.sheet_protection.remove(&sheet);
+                false
+            }
+        };
+        self.engine.set_sheet_protection_enabled(&sheet, enabled);
+        Ok(())
+    }
+
+    fn is_sheet_protected_internal(&self, sheet: &str) -> Result<bool, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        Ok(self.sheet_protection.contains_key(sheet))
+    }
+
+    /// Print area for `sheet` as comma-separated A1 ranges without a sheet prefix.
+    ///
+    /// Excel stores the print area as the sheet-scoped `_xlnm.Print_Area` defined name; see
+    /// `Engine::print_area`. Definitions that are not plain ranges on `sheet` are reported as
+    /// `None`.
+    fn get_print_area_internal(&self, sheet: &str) -> Result<Option<String>, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        let Some(ranges) = self.engine.print_area(sheet) else {
+            return Ok(None);
+        };
+        let ranges: Vec<String> = ranges.iter().map(ToString::to_string).collect();
+        Ok((!ranges.is_empty()).then(|| ranges.join(",")))
+    }
+
+    /// Set (or clear, with `None`) the print area for `sheet` from comma-separated A1 ranges.
+    fn set_print_area_internal(&mut self, sheet: &str, range: Option<&str>) -> Result<(), JsValue> {
+        let sheet = self.require_sheet(sheet)?.to_string();
+        let ranges = match range.map(str::trim).filter(|r| !r.is_empty()) {
+            Some(range) => Some(
+                range
+                    .split(',')
+                    .map(|part| Self::parse_range(part.trim()))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            None => None,
+        };
+        self.engine
+            .set_print_area(&sheet, ranges.as_deref())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// Reject edits to locked cells (the Excel default; see `Protection.locked`) on protected
+    /// sheets. `sheet` must be a display name and `address` a normalized A1 address.
+    fn check_cell_editable(&self, sheet: &str, address: &str) -> Result<(), String> {
+        if !self.sheet_protection.contains_key(sheet) {
+            return Ok(());
+        }
+        let locked = self
+            .engine
+            .effective_cell_style(sheet, address)
+            .is_none_or(|style| style.locked);
+        if locked {
+            return Err(format!(
+                "cell {sheet}!{address} is locked on a protected sheet"
+            ));
+        }
+        Ok(())
+    }
+
+    /// [`Self::check_cell_editable`] for a whole range, reporting the first locked cell. The
+    /// engine resolves locked state per style layer, so whole-column ranges stay cheap.
+    fn check_range_editable(&self, sheet: &str, range: Range) -> Result<(), String> {
+        if !self.sheet_protection.contains_key(sheet) {
+            return Ok(());
+        }
+        match self.engine.first_locked_cell(sheet, range) {
+            Some(cell) => Err(format!(
+                "cell {sheet}!{} is locked on a protected sheet",
+                formula_model::cell_to_a1(cell.row, cell.col)
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Reject style and number-format changes on protected sheets that do not allow `formatCells`.
+    fn check_format_cells_allowed(&self, sheet: &str) -> Result<(), String> {
+        match self.sheet_protection.get(sheet) {
+            Some(protection) if !protection.format_cells => Err(format!(
+                "formatting cells is not allowed on protected sheet {sheet}"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reject structural edits on protected sheets, following Excel: row/column inserts and
+    /// deletes and sorting need their allowed-action flag, ops that overwrite cells need every
+    /// target cell unlocked, and cell shifts, Text to Columns and Remove Duplicates are
+    /// unavailable.
+    fn check_edit_op_allowed(&self, op: &EngineEditOp) -> Result<(), String> {
+        let sheet = match op {
+            EngineEditOp::InsertRows { sheet, .. }
+            | EngineEditOp::DeleteRows { sheet, .. }
+            | EngineEditOp::InsertCols { sheet, .. }
+            | EngineEditOp::DeleteCols { sheet, .. }
+            | EngineEditOp::InsertCellsShiftRight { sheet, .. }
+            | EngineEditOp::InsertCellsShiftDown { sheet, .. }
+            | EngineEditOp::DeleteCellsShiftLeft { sheet, .. }
+            | EngineEditOp::DeleteCellsShiftUp { sheet, .. }
+            | EngineEditOp::MoveRange { sheet, .. }
+            | EngineEditOp::CopyRange { sheet, .. }
+            | EngineEditOp::Fill { sheet, .. }
+            | EngineEditOp::TextToColumns { sheet, .. }
+            | EngineEditOp::RemoveDuplicates { sheet, .. }
+            | EngineEditOp::SortRange { sheet, .. } => sheet.as_str(),
+        };
+        let Some(protection) = self.sheet_protection.get(sheet) else {
+            return Ok(());
+        };
+        let require = |allowed: bool, action: &str| {
+            if allowed {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{action} is not allowed on protected sheet {sheet}"
+                ))
+            }
+        };
+        // Destination of a move/copy: `src`'s shape anchored at `dst_top_left`.
+        let destination = |src: &Range, dst_top_left: &CellRef| {
+            Range::new(
+                *dst_top_left,
+                CellRef::new(
+                    dst_top_left.row.saturating_add(src.height() - 1),
+                    dst_top_left.col.saturating_add(src.width() - 1),
+                ),
+            )
+        };
+
+        match op {
+            EngineEditOp::InsertRows { .. } => require(protection.insert_rows, "inserting rows"),
+            EngineEditOp::DeleteRows { .. } => require(protection.delete_rows, "deleting rows"),
+            EngineEditOp::InsertCols { .. } => {
+                require(protection.insert_columns, "inserting columns")
+            }
+            EngineEditOp::DeleteCols { .. } => {
+                require(protection.delete_columns, "deleting columns")
+            }
+            EngineEditOp::SortRange { range, .. } => {
+                require(protection.sort, "sorting")?;
+                self.check_range_editable(sheet, *range)
+            }
+            EngineEditOp::MoveRange {
+                src, dst_top_left, ..
+            } => {
+                self.check_range_editable(sheet, *src)?;
+                self.check_range_editable(sheet, destination(src, dst_top_left))
+            }
+            EngineEditOp::CopyRange {
+                src, dst_top_left, ..
+            } => self.check_range_editable(sheet, destination(src, dst_top_left)),
+            EngineEditOp::Fill { dst, .. } => self.check_range_editable(sheet, *dst),
+            EngineEditOp::InsertCellsShiftRight { .. }
+            | EngineEditOp::InsertCellsShiftDown { .. } => require(false, "inserting cells"),
+            EngineEditOp::DeleteCellsShiftLeft { .. } | EngineEditOp::DeleteCellsShiftUp { .. } => {
+                require(false, "deleting cells")
+            }
+            EngineEditOp::TextToColumns { .. } => require(false, "Text to Columns"),
+            EngineEditOp::RemoveDuplicates { .. } => require(false, "Remove Duplicates"),
+        }
+    }
+
+    fn get_used_range_internal(&self, sheet: &str) -> Result<Option<WasmUsedRange>, JsValue> {
+        let sheet = self.require_sheet(sheet)?;
+        Ok(self
+            .engine
+            .used_range(sheet)
+            .map(|(start, end)| WasmUsedRange {
+                start_row: start.row,
+                start_col: start.col,
+                end_row: end.row,
+                end_col: end.col,
+            }))
+    }
+
+    /// Compare `self` (before) against `other` (after).
+    ///
+    /// Sheets are matched by name (case-insensitive). On sheets present in both workbooks the
+    /// candidate cells are every key of either sparse input map plus any spill ranges anchored
+    /// there; a cell is reported when its input or engine value differs. Values are read as-is,
+    /// so callers should recalculate both workbooks first. At most `max_cells` cells are
+    /// reported; `truncated` is set when more differences were found.
+    fn diff_internal(&self, other: &WorkbookState, max_cells: Option<usize>) -> WorkbookDiff {
+        let before_sheets = self.engine.sheet_keys_in_order();
+        let after_sheets = other.engine.sheet_keys_in_order();
+
+        let mut diff = WorkbookDiff {
+            added_sheets: after_sheets
+                .iter()
+                .filter(|sheet| self.resolve_sheet(sheet).is_none())
+                .cloned()
+                .collect(),
+            removed_sheets: before_sheets
+                .iter()
+                .filter(|sheet| other.resolve_sheet(sheet).is_none())
+                .cloned()
+                .collect(),
+            cells: Vec::new(),
+            truncated: false,
+        };
+
+        for before_sheet in &before_sheets {
+            let Some(after_sheet) = other.resolve_sheet(before_sheet) else {
+                continue;
+            };
+
+            let mut candidates: BTreeSet<(u32, u32)> = BTreeSet::new();
+            for (state, sheet) in [(self, before_sheet.as_str()), (other, after_sheet)] {
+                let Some(cells) = state.sheets.get(sheet) else {
+                    continue;
+                };
+                for address in cells.keys() {
+                    let Ok(cell_ref) = Self::parse_address(address) else {
+                        continue;
+                    };
+                    candidates.insert((cell_ref.row, cell_ref.col));
+                    if let Some((start, end)) = state.engine.spill_range(sheet, address) {
+                        for row in start.row..=end.row {
+                            for col in start.col..=end.col {
+                                candidates.insert((row, col));
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (row, col) in candidates {
+                let address = formula_model::cell_to_a1(row, col);
+                let before = self.cell_snapshot(before_sheet, &address);
+                let after = other.cell_snapshot(after_sheet, &address);
+                if before == after {
+                    continue;
+                }
+                if max_cells.is_some_and(|max| diff.cells.len() >= max) {
+                    diff.truncated = true;
+                    return diff;
+                }
+                diff.cells.push(CellDiff {
+                    sheet: before_sheet.clone(),
+                    address,
+                    before,
+                    after,
+                });
+            }
+        }
+
+        diff
+    }
+
+    /// Input and value of a cell for [`WorkbookState::diff_internal`]. `sheet` must be a sheet key
+    /// and `address` a normalized A1 address.
+    fn cell_snapshot(&self, sheet: &str, address: &str) -> CellSnapshot {
+        let input = self
+            .sheets
+            .get(sheet)
+            .and_then(|cells| cells.get(address))
+            .cloned()
+            .unwrap_or(JsonValue::Null);
+        let value = engine_value_to_json(self.engine.get_cell_value(sheet, address));
+        CellSnapshot { input, value }
+    }
+}
 
 fn json_scalar_to_js(value: &JsonValue) -> JsValue {
     match value {
@@ -3885,6 +5294,138 @@ fn engine_value_to_js_scalar(value: EngineValue) -> JsValue {
     }
 }
 
+/// Per-cell type tags reported in `getRangeColumnar` column `types` masks.
+const COLUMNAR_TYPE_BLANK: u8 = 0;
+const COLUMNAR_TYPE_NUMBER: u8 = 1;
+const COLUMNAR_TYPE_TEXT: u8 = 2;
+const COLUMNAR_TYPE_BOOL: u8 = 3;
+const COLUMNAR_TYPE_ERROR: u8 = 4;
+
+/// Column payload for `getRangeColumnar`.
+#[derive(Debug, Clone, PartialEq)]
+enum ColumnarValues {
+    /// Numbers and booleans (as `0`/`1`), with `NaN` for blanks. The type mask tells them apart.
+    Number(Vec<f64>),
+    /// Text only; blanks are `None`.
+    Text(Vec<Option<String>>),
+    /// Anything else, as the same tagged scalars `getRangeValuesRaw` returns.
+    Mixed(Vec<EngineValue>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ColumnarColumn {
+    types: Vec<u8>,
+    values: ColumnarValues,
+}
+
+/// Degrade an engine value to the scalar the JS protocol would see (mirrors
+/// [`engine_value_to_js_scalar`]) and tag it.
+fn columnar_scalar(value: EngineValue) -> (u8, EngineValue) {
+    match value {
+        EngineValue::Blank => (COLUMNAR_TYPE_BLANK, EngineValue::Blank),
+        EngineValue::Number(n) if n.is_finite() => (COLUMNAR_TYPE_NUMBER, EngineValue::Number(n)),
+        EngineValue::Number(_) => (COLUMNAR_TYPE_ERROR, EngineValue::Error(ErrorKind::Num)),
+        EngineValue::Bool(b) => (COLUMNAR_TYPE_BOOL, EngineValue::Bool(b)),
+        EngineValue::Text(s) => (COLUMNAR_TYPE_TEXT, EngineValue::Text(s)),
+        EngineValue::Entity(entity) => (COLUMNAR_TYPE_TEXT, EngineValue::Text(entity.display)),
+        EngineValue::Record(record) => (COLUMNAR_TYPE_TEXT, EngineValue::Text(record.display)),
+        EngineValue::Error(kind) => (COLUMNAR_TYPE_ERROR, EngineValue::Error(kind)),
+        EngineValue::Array(arr) => columnar_scalar(arr.top_left()),
+        other => (COLUMNAR_TYPE_TEXT, EngineValue::Text(other.to_string())),
+    }
+}
+
+impl ColumnarColumn {
+    fn from_values(values: Vec<EngineValue>) -> Self {
+        let (types, values): (Vec<u8>, Vec<EngineValue>) =
+            values.into_iter().map(columnar_scalar).unzip();
+
+        let numeric = types.iter().all(|t| {
+            matches!(
+                *t,
+                COLUMNAR_TYPE_BLANK | COLUMNAR_TYPE_NUMBER | COLUMNAR_TYPE_BOOL
+            )
+        });
+        let text = types
+            .iter()
+            .all(|t| matches!(*t, COLUMNAR_TYPE_BLANK | COLUMNAR_TYPE_TEXT));
+
+        let values = if numeric {
+            ColumnarValues::Number(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        EngineValue::Number(n) => n,
+                        EngineValue::Bool(b) => f64::from(u8::from(b)),
+                        _ => f64::NAN,
+                    })
+                    .collect(),
+            )
+        } else if text {
+            ColumnarValues::Text(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        EngineValue::Text(s) => Some(s),
+                        _ => None,
+                    })
+                    .collect(),
+            )
+        } else {
+            ColumnarValues::Mixed(values)
+        };
+        Self { types, values }
+    }
+
+    fn to_js(&self) -> Result<JsValue, JsValue> {
+        let obj = Object::new();
+        let (kind, values): (&str, JsValue) = match &self.values {
+            ColumnarValues::Number(values) => {
+                ("number", Float64Array::from(values.as_slice()).into())
+            }
+            ColumnarValues::Text(values) => {
+                let out = Array::new_with_length(values.len() as u32);
+                for (idx, value) in values.iter().enumerate() {
+                    let value = value.as_deref().map_or(JsValue::NULL, JsValue::from_str);
+                    out.set(idx as u32, value);
+                }
+                ("text", out.into())
+            }
+            ColumnarValues::Mixed(values) => {
+                let out = Array::new_with_length(values.len() as u32);
+                for (idx, value) in values.iter().enumerate() {
+                    out.set(idx as u32, engine_value_to_js_scalar(value.clone()));
+                }
+                ("mixed", out.into())
+            }
+        };
+        Reflect::set(&obj, &JsValue::from_str("kind"), &JsValue::from_str(kind))?;
+        Reflect::set(
+            &obj,
+            &JsValue::from_str("types"),
+            &Uint8Array::from(self.types.as_slice()),
+        )?;
+        Reflect::set(&obj, &JsValue::from_str("values"), &values)?;
+        Ok(obj.into())
+    }
+}
+
+/// Transpose row-major range values into typed columns.
+fn range_values_to_columns(rows: Vec<Vec<EngineValue>>, col_count: usize) -> Vec<ColumnarColumn> {
+    let mut columns: Vec<Vec<EngineValue>> = (0..col_count)
+        .map(|_| Vec::with_capacity(rows.len()))
+        .collect();
+    for row in rows {
+        for (column, value) in columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+    columns
+        .into_iter()
+        .map(ColumnarColumn::from_values)
+        .collect()
+}
+
 fn push_u64_decimal(mut n: u64, out: &mut String) {
     let mut buf = [0u8; 20];
     let mut len = 0usize;
@@ -4229,6 +5770,205 @@ struct WasmParseError {
     span: WasmSpan,
 }
 
+/// Worksheet protection options in `setSheetProtection` and `toJson().sheets[*].protection`.
+///
+/// Each flag allows an action while the sheet is protected; omitted flags take Excel's defaults
+/// (selecting cells is allowed, everything else is not).
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SheetProtectionOptionsDto {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    select_locked_cells: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    select_unlocked_cells: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format_cells: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format_columns: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format_rows: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    insert_columns: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    insert_rows: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    insert_hyperlinks: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    delete_columns: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    delete_rows: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sort: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auto_filter: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pivot_tables: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    edit_objects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    edit_scenarios: Option<bool>,
+    /// Legacy 16-bit password hash (OOXML `sheetProtection/@password`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    password_hash: Option<u16>,
+    /// Plain-text password, hashed into `password_hash` on input. Never serialized.
+    #[serde(default, skip_serializing)]
+    password: Option<String>,
+}
+
+impl SheetProtectionOptionsDto {
+    fn to_protection(&self) -> SheetProtection {
+        let password_hash = self
+            .password
+            .as_deref()
+            .filter(|password| !password.is_empty())
+            .map(formula_model::hash_legacy_password)
+            .or(self.password_hash);
+        let mut protection = SheetProtection {
+            enabled: true,
+            password_hash,
+            ..SheetProtection::default()
+        };
+        if let Some(v) = self.select_locked_cells {
+            protection.select_locked_cells = v;
+        }
+        if let Some(v) = self.select_unlocked_cells {
+            protection.select_unlocked_cells = v;
+        }
+        if let Some(v) = self.format_cells {
+            protection.format_cells = v;
+        }
+        if let Some(v) = self.format_columns {
+            protection.format_columns = v;
+        }
+        if let Some(v) = self.format_rows {
+            protection.format_rows = v;
+        }
+        if let Some(v) = self.insert_columns {
+            protection.insert_columns = v;
+        }
+        if let Some(v) = self.insert_rows {
+            protection.insert_rows = v;
+        }
+        if let Some(v) = self.insert_hyperlinks {
+            protection.insert_hyperlinks = v;
+        }
+        if let Some(v) = self.delete_columns {
+            protection.delete_columns = v;
+        }
+        if let Some(v) = self.delete_rows {
+            protection.delete_rows = v;
+        }
+        if let Some(v) = self.sort {
+            protection.sort = v;
+        }
+        if let Some(v) = self.auto_filter {
+            protection.auto_filter = v;
+        }
+        if let Some(v) = self.pivot_tables {
+            protection.pivot_tables = v;
+        }
+        if let Some(v) = self.edit_objects {
+            protection.edit_objects = v;
+        }
+        if let Some(v) = self.edit_scenarios {
+            protection.edit_scenarios = v;
+        }
+        protection
+    }
+
+    fn from_protection(protection: &SheetProtection) -> Self {
+        let defaults = SheetProtection::default();
+        let changed = |value: bool, default: bool| (value != default).then_some(value);
+        Self {
+            select_locked_cells: changed(
+                protection.select_locked_cells,
+                defaults.select_locked_cells,
+            ),
+            select_unlocked_cells: changed(
+                protection.select_unlocked_cells,
+                defaults.select_unlocked_cells,
+            ),
+            format_cells: changed(protection.format_cells, defaults.format_cells),
+            format_columns: changed(protection.format_columns, defaults.format_columns),
+            format_rows: changed(protection.format_rows, defaults.format_rows),
+            insert_columns: changed(protection.insert_columns, defaults.insert_columns),
+            insert_rows: changed(protection.insert_rows, defaults.insert_rows),
+            insert_hyperlinks: changed(protection.insert_hyperlinks, defaults.insert_hyperlinks),
+            delete_columns: changed(protection.delete_columns, defaults.delete_columns),
+            delete_rows: changed(protection.delete_rows, defaults.delete_rows),
+            sort: changed(protection.sort, defaults.sort),
+            auto_filter: changed(protection.auto_filter, defaults.auto_filter),
+            pivot_tables: changed(protection.pivot_tables, defaults.pivot_tables),
+            edit_objects: changed(protection.edit_objects, defaults.edit_objects),
+            edit_scenarios: changed(protection.edit_scenarios, defaults.edit_scenarios),
+            password_hash: protection.password_hash,
+            password: None,
+        }
+    }
+}
+
+/// Result of `validateFormula`: `{ ok: true }` or `{ ok: false, error, span }`.
+#[derive(Debug, Serialize)]
+struct WasmFormulaValidation {
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    span: Option<WasmSpan>,
+}
+
+/// Result of `analyzeFormula`: the ranges (A1 strings), defined names and tables a formula reads.
+#[derive(Debug, Serialize)]
+struct WasmFormulaReferences {
+    ranges: Vec<String>,
+    names: Vec<String>,
+    tables: Vec<String>,
+}
+
+/// One `lintFormula` finding. `kind` is `emptyRange`, `volatileFunction`, `inconsistentFormula` or
+/// `blankCellInArithmetic`; `severity` is `info` or `warning`.
+#[derive(Debug, Serialize)]
+struct WasmFormulaLint {
+    kind: &'static str,
+    severity: &'static str,
+    message: String,
+    span: WasmSpan,
+}
+
+/// Result of `getUsedRange`: 0-based, inclusive cell bounds.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WasmUsedRange {
+    start_row: u32,
+    start_col: u32,
+    end_row: u32,
+    end_col: u32,
+}
+
+/// Result of `WasmWorkbook.diff`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkbookDiff {
+    added_sheets: Vec<String>,
+    removed_sheets: Vec<String>,
+    cells: Vec<CellDiff>,
+    truncated: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct CellDiff {
+    sheet: String,
+    address: String,
+    before: CellSnapshot,
+    after: CellSnapshot,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct CellSnapshot {
+    input: JsonValue,
+    value: JsonValue,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct WasmFunctionContext {
@@ -4831,6 +6571,8 @@ impl WasmWorkbook {
             visibility: Option<SheetVisibilityJson>,
             #[serde(default, rename = "tabColor")]
             tab_color: Option<TabColorJson>,
+            #[serde(default)]
+            protection: Option<SheetProtectionOptionsDto>,
             #[serde(default, rename = "cellPhonetics")]
             cell_phonetics: Option<BTreeMap<String, JsonValue>>,
             cells: BTreeMap<String, JsonValue>,
@@ -4911,6 +6653,7 @@ impl WasmWorkbook {
                 col_count,
                 visibility,
                 tab_color,
+                protection,
                 cell_phonetics,
                 cells,
                 default_style_id,
@@ -5068,6 +6811,11 @@ impl WasmWorkbook {
                     Ok(())
                 });
             }
+
+            // Protect the sheet last so importing `cells` isn't rejected for locked cells.
+            if let Some(protection) = protection {
+                wb.set_sheet_protection_internal(&display_name, Some(protection.to_protection()))?;
+            }
         }
 
         // Ensure the workbook locale is applied for subsequent edits/value coercion.
@@ -5149,10 +6897,20 @@ impl WasmWorkbook {
         // Create all sheets up-front so formulas can resolve cross-sheet references.
         for sheet in &model.sheets {
             let sheet_name = wb.ensure_sheet(&sheet.name);
+            if !sheet.hyperlinks.is_empty() {
+                wb.hyperlinks
+                    .insert(sheet_name.clone(), sheet.hyperlinks.clone());
+            }
             if sheet.visibility != SheetVisibility::Visible {
                 wb.sheet_visibility
                     .insert(sheet_name.clone(), sheet.visibility);
             }
+            if sheet.sheet_protection.enabled {
+                wb.set_sheet_protection_internal(
+                    &sheet_name,
+                    Some(sheet.sheet_protection.clone()),
+                )?;
+            }
             if let Some(color) = sheet.tab_color.as_ref() {
                 let is_empty = color.rgb.is_none()
                     && color.theme.is_none()
@@ -5201,12 +6959,12 @@ impl WasmWorkbook {
             let _ = wb.engine.set_sheet_origin(&sheet_name, Some(&origin));
         }
 
-        // Import worksheet column/row properties (width/hidden/default style) and default column
-        // width.
+        // Import worksheet column/row properties (width/height/hidden/default style) and default
+        // column width.
         //
-        // These are persisted in OOXML (`col/@width`, `col/@hidden`, `col/@style`, `row/@s`, and
-        // `<sheetFormatPr defaultColWidth="...">`) and are needed for workbook info functions like
-        // `CELL("format")` and `CELL("width")`.
+        // These are persisted in OOXML (`col/@width`, `col/@hidden`, `col/@style`, `row/@ht`,
+        // `row/@hidden`, `row/@s`, and `<sheetFormatPr defaultColWidth="...">`) and are needed for
+        // layout and workbook info functions like `CELL("format")` and `CELL("width")`.
         for sheet in &model.sheets {
             let sheet_name = wb.require_sheet(&sheet.name)?.to_string();
 
@@ -5244,6 +7002,12 @@ impl WasmWorkbook {
             }
 
             for (&row, props) in &sheet.row_properties {
+                if let Some(height) = props.height {
+                    wb.engine.set_row_height(&sheet_name, row, Some(height));
+                }
+                if props.hidden {
+                    wb.engine.set_row_hidden(&sheet_name, row, true);
+                }
                 let Some(style_id) = props.style_id else {
                     continue;
                 };
@@ -5252,6 +7016,13 @@ impl WasmWorkbook {
                     wb.engine.set_row_style_id(&sheet_name, row, Some(mapped));
                 }
             }
+
+            // Like columns above, also honor outline-based hidden state for rows.
+            for (index, entry) in sheet.outline.rows.iter() {
+                if entry.hidden.is_hidden() && index > 0 {
+                    wb.engine.set_row_hidden(&sheet_name, index - 1, true);
+                }
+            }
         }
 
         // Import Excel tables (structured reference metadata) before formulas are compiled so
@@ -5302,13 +7073,31 @@ impl WasmWorkbook {
             let _ = wb.engine.define_name(&name.name, scope, definition);
         }
 
-        for sheet in &model.sheets {
-            let sheet_name = wb.require_sheet(&sheet.name)?.to_string();
-
-            for (cell_ref, cell) in sheet.iter_cells() {
-                let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
-                let phonetic = cell.phonetic.as_deref().map(|s| s.to_string());
-
+        // Models built outside the XLSX reader may carry print settings only in
+        // `print_settings`; mirror them into the `_xlnm.*` names the engine reads them from.
+        for settings in &model.print_settings.sheets {
+            let Ok(sheet_name) = wb.require_sheet(&settings.sheet_name).map(str::to_string) else {
+                continue;
+            };
+            if let Some(print_area) = settings.print_area.as_deref() {
+                if wb.engine.print_area(&sheet_name).is_none() {
+                    let _ = wb.engine.set_print_area(&sheet_name, Some(print_area));
+                }
+            }
+            if let Some(titles) = settings.print_titles {
+                if wb.engine.print_titles(&sheet_name).is_none() {
+                    let _ = wb.engine.set_print_titles(&sheet_name, Some(titles));
+                }
+            }
+        }
+
+        for sheet in &model.sheets {
+            let sheet_name = wb.require_sheet(&sheet.name)?.to_string();
+
+            for (cell_ref, cell) in sheet.iter_cells() {
+                let address = formula_model::cell_to_a1(cell_ref.row, cell_ref.col);
+                let phonetic = cell.phonetic.as_deref().map(|s| s.to_string());
+
                 // Apply formatting metadata first (including style-only cells) so cached values can
                 // be rounded correctly when the workbook uses "precision as displayed".
                 if cell.style_id != 0 {
@@ -5484,6 +7273,54 @@ impl WasmWorkbook {
         Ok(obj.into())
     }
 
+    /// Return the Excel tables defined on a sheet (name, range, column headers, totals row state,
+    /// and table style), in workbook order.
+    #[wasm_bindgen(js_name = "getSheetTables")]
+    pub fn get_sheet_tables(&self, sheet_name: String) -> Result<JsValue, JsValue> {
+        let tables = self.inner.get_sheet_tables_internal(&sheet_name)?;
+        use serde::ser::Serialize as _;
+        tables
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// Create an Excel table over `range` on `sheet_name`.
+    ///
+    /// `options` may be `{ hasHeaders?: boolean, styleName?: string }`. Errors when the name is
+    /// invalid or already used, or when the range overlaps another table.
+    #[wasm_bindgen(js_name = "addTable")]
+    pub fn add_table(
+        &mut self,
+        sheet_name: String,
+        range: String,
+        name: String,
+        options: JsValue,
+    ) -> Result<(), JsValue> {
+        let options: AddTableOptionsDto = if options.is_null() || options.is_undefined() {
+            AddTableOptionsDto::default()
+        } else {
+            serde_wasm_bindgen::from_value(options).map_err(|err| js_err(err.to_string()))?
+        };
+        // Preserve explicit-recalc semantics even when the workbook's calcMode is automatic.
+        self.inner.with_manual_calc_mode(|this| {
+            this.add_table_internal(&sheet_name, &range, &name, options)
+        })
+    }
+
+    /// Resize an existing table; the header row must stay on the same row.
+    #[wasm_bindgen(js_name = "resizeTable")]
+    pub fn resize_table(&mut self, name: String, new_range: String) -> Result<(), JsValue> {
+        self.inner
+            .with_manual_calc_mode(|this| this.resize_table_internal(&name, &new_range))
+    }
+
+    /// Delete a table, leaving its cell contents in place.
+    #[wasm_bindgen(js_name = "deleteTable")]
+    pub fn delete_table(&mut self, name: String) -> Result<(), JsValue> {
+        self.inner
+            .with_manual_calc_mode(|this| this.delete_table_internal(&name))
+    }
+
     /// Rename a worksheet and rewrite formulas that reference it (Excel-like).
     ///
     /// Returns `false` when `old_name` does not exist or `new_name` conflicts with another sheet.
@@ -5495,6 +7332,23 @@ impl WasmWorkbook {
             .unwrap_or(false)
     }
 
+    /// Rename a defined name and rewrite formulas that reference it.
+    ///
+    /// `sheet` selects a sheet-scoped name; omit it for a workbook-scoped name. Throws when the
+    /// name does not exist, `newName` is invalid, or `newName` already exists in the same scope.
+    #[wasm_bindgen(js_name = "renameName")]
+    pub fn rename_name(
+        &mut self,
+        old_name: String,
+        new_name: String,
+        sheet: Option<String>,
+    ) -> Result<(), JsValue> {
+        // Preserve explicit-recalc semantics even when the workbook's calcMode is automatic.
+        self.inner.with_manual_calc_mode(|this| {
+            this.rename_name_internal(&old_name, &new_name, sheet.as_deref())
+        })
+    }
+
     #[wasm_bindgen(js_name = "setSheetDisplayName")]
     pub fn set_sheet_display_name(
         &mut self,
@@ -5596,6 +7450,53 @@ impl WasmWorkbook {
         })
     }
 
+    /// Set (or clear) a per-row height override for a sheet.
+    ///
+    /// `height` is expressed in points (OOXML `row/@ht`), **not pixels**. `row` is 0-based.
+    ///
+    /// A height of `0` hides the row (Excel semantics). Pass `null`/`undefined` to clear the
+    /// override.
+    #[wasm_bindgen(js_name = "setRowHeight")]
+    pub fn set_row_height(
+        &mut self,
+        sheet_name: String,
+        row: u32,
+        height: JsValue,
+    ) -> Result<(), JsValue> {
+        let height = if height.is_null() || height.is_undefined() {
+            None
+        } else {
+            let raw = height
+                .as_f64()
+                .ok_or_else(|| js_err("height must be a number or null".to_string()))?;
+            // Range checks live in `set_row_height_internal`; an out-of-range f64 becomes a
+            // non-finite f32 there.
+            Some(raw as f32)
+        };
+
+        let sheet_name = sheet_name.trim();
+        let sheet_name = if sheet_name.is_empty() {
+            DEFAULT_SHEET
+        } else {
+            sheet_name
+        };
+
+        self.inner.set_row_height_internal(sheet_name, row, height)
+    }
+
+    /// Get the per-row height override for a sheet in points.
+    ///
+    /// Returns `0` for hidden rows and `null` when the row uses the sheet's default height.
+    #[wasm_bindgen(js_name = "getRowHeight")]
+    pub fn get_row_height(&self, sheet_name: String, row: u32) -> Result<Option<f64>, JsValue> {
+        let sheet = self.inner.require_sheet(&sheet_name)?;
+        Ok(self
+            .inner
+            .engine
+            .row_height(sheet, row)
+            .map(|height| height as f64))
+    }
+
     /// Set (or clear) the sheet's default column width in Excel "character" units.
     ///
     /// This corresponds to the worksheet's OOXML `<sheetFormatPr defaultColWidth="...">` attribute.
@@ -5749,6 +7650,8 @@ impl WasmWorkbook {
             visibility: Option<&'static str>,
             #[serde(default, skip_serializing_if = "Option::is_none", rename = "tabColor")]
             tab_color: Option<TabColor>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            protection: Option<SheetProtectionOptionsDto>,
             #[serde(
                 default,
                 skip_serializing_if = "BTreeMap::is_empty",
@@ -5794,6 +7697,11 @@ impl WasmWorkbook {
                     SheetVisibility::Visible => None,
                 });
             let tab_color = self.inner.sheet_tab_colors.get(sheet_name).cloned();
+            let protection = self
+                .inner
+                .sheet_protection
+                .get(sheet_name)
+                .map(SheetProtectionOptionsDto::from_protection);
             sheets.insert(
                 sheet_name.clone(),
                 SheetJson {
@@ -5801,6 +7709,7 @@ impl WasmWorkbook {
                     col_count,
                     visibility,
                     tab_color,
+                    protection,
                     cell_phonetics,
                     cells: out_cells,
                 },
@@ -5842,6 +7751,26 @@ impl WasmWorkbook {
         .map_err(|err| js_err(format!("invalid workbook json: {err}")))
     }
 
+    /// Diff the cell inputs and values of two workbooks, e.g. to compare engine versions.
+    ///
+    /// Returns `{ addedSheets, removedSheets, cells, truncated }` where each `cells` entry is
+    /// `{ sheet, address, before: { input, value }, after: { input, value } }` in sheet tab then
+    /// row-major order. Cells on added/removed sheets are not listed. `maxCells` caps the number
+    /// of cell entries; `truncated` reports whether any were dropped.
+    #[wasm_bindgen(js_name = "diff")]
+    pub fn diff(
+        a: &WasmWorkbook,
+        b: &WasmWorkbook,
+        max_cells: Option<u32>,
+    ) -> Result<JsValue, JsValue> {
+        let diff = a
+            .inner
+            .diff_internal(&b.inner, max_cells.map(|max| max as usize));
+        use serde::ser::Serialize as _;
+        diff.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
     /// Return a lightweight workbook metadata payload (sheet list + dimensions + best-effort used ranges)
     /// without materializing the full workbook JSON string returned by `toJson()`.
     ///
@@ -6001,6 +7930,20 @@ impl WasmWorkbook {
         Ok(obj.into())
     }
 
+    /// Return the bounding box of the non-empty cells on `sheet` as
+    /// `{ startRow, startCol, endRow, endCol }` (0-based, inclusive), or `null` for an empty sheet.
+    ///
+    /// Unlike the `usedRange` reported by `getWorkbookInfo`, this comes from the engine's own cell
+    /// store, so formula results and spilled array cells are included.
+    #[wasm_bindgen(js_name = "getUsedRange")]
+    pub fn get_used_range(&self, sheet: Option<String>) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let out = self.inner.get_used_range_internal(sheet)?;
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
     #[wasm_bindgen(js_name = "getCell")]
     pub fn get_cell(&self, address: String, sheet: Option<String>) -> Result<JsValue, JsValue> {
         let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
@@ -6008,6 +7951,157 @@ impl WasmWorkbook {
         cell_data_to_js(&cell)
     }
 
+    /// Read several cells at once (e.g. a non-contiguous multi-selection).
+    ///
+    /// `addresses` is an array of A1 strings; the result is an array of `getCell`-shaped objects
+    /// in the same order.
+    #[wasm_bindgen(js_name = "getCells")]
+    pub fn get_cells(&self, addresses: JsValue, sheet: Option<String>) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let addresses: Vec<String> =
+            serde_wasm_bindgen::from_value(addresses).map_err(|err| js_err(err.to_string()))?;
+        let cells = self.inner.get_cells_data(sheet, &addresses)?;
+        let out = Array::new_with_length(cells.len() as u32);
+        for (idx, cell) in cells.iter().enumerate() {
+            out.set(idx as u32, cell_data_to_js(cell)?);
+        }
+        Ok(out.into())
+    }
+
+    /// Read everything an inspector panel needs about one cell in a single call.
+    ///
+    /// Returns `{ input, value, valueType, formula, styleId, numberFormat, phonetic, hyperlink,
+    /// isSpillOrigin, spillRange }`. `valueType` is one of `"blank"`, `"number"`, `"text"`,
+    /// `"bool"` or `"error"`; `hyperlink` has the `getHyperlinks` entry shape. `formula`,
+    /// `phonetic`, `hyperlink` and `spillRange` are omitted when the cell has none. Cell comments
+    /// are not retained by the workbook bridge, so they are not reported here.
+    #[wasm_bindgen(js_name = "getCellMetadata")]
+    pub fn get_cell_metadata(
+        &self,
+        address: String,
+        sheet: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let out = self.inner.get_cell_metadata_internal(sheet, &address)?;
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// The A1 range (e.g. `"A1:A3"`) currently occupied by the spill `address` belongs to, or
+    /// `null` when the cell is not part of a spill. Works for the origin and its output cells.
+    #[wasm_bindgen(js_name = "getSpillRange")]
+    pub fn get_spill_range(
+        &self,
+        address: String,
+        sheet: Option<String>,
+    ) -> Result<Option<String>, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        Ok(self
+            .inner
+            .spill_range_internal(sheet, &address)?
+            .map(|range| range.to_string()))
+    }
+
+    /// The A1 address of the formula cell whose spill covers `address`, or `null` when the cell
+    /// is not part of a spill. A spill origin returns its own address.
+    #[wasm_bindgen(js_name = "getSpillOrigin")]
+    pub fn get_spill_origin(
+        &self,
+        address: String,
+        sheet: Option<String>,
+    ) -> Result<Option<String>, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        Ok(self
+            .inner
+            .spill_range_internal(sheet, &address)?
+            .map(|range| range.start.to_a1()))
+    }
+
+    /// Whether `address` holds a formula that is currently spilling.
+    #[wasm_bindgen(js_name = "isSpillOrigin")]
+    pub fn is_spill_origin(&self, address: String, sheet: Option<String>) -> Result<bool, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let cell_ref = WorkbookState::parse_address(&address)?;
+        Ok(self
+            .inner
+            .spill_range_internal(sheet, &address)?
+            .is_some_and(|range| range.start == cell_ref))
+    }
+
+    /// Whether `address` displays a value spilled from another cell. Editing such a cell blocks
+    /// the spill with `#SPILL!`, so UIs typically treat output cells as read-only.
+    #[wasm_bindgen(js_name = "isSpillOutput")]
+    pub fn is_spill_output(&self, address: String, sheet: Option<String>) -> Result<bool, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let cell_ref = WorkbookState::parse_address(&address)?;
+        Ok(self
+            .inner
+            .spill_range_internal(sheet, &address)?
+            .is_some_and(|range| range.start != cell_ref))
+    }
+
+    /// Check formula text as if it were committed to `sheet` with `setCell`, without mutating the
+    /// workbook.
+    ///
+    /// Returns `{ ok: true }`, or `{ ok: false, error, span }` for the first parse error or
+    /// unknown function/name/table reference. `span` is in UTF-16 code units of `formula`.
+    #[wasm_bindgen(js_name = "validateFormula")]
+    pub fn validate_formula(
+        &self,
+        formula: String,
+        sheet: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let out = self.inner.validate_formula_internal(sheet, &formula);
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// List the cells/ranges, defined names and tables `formula` refers to if entered on `sheet`
+    /// (default `Sheet1`), without committing it.
+    ///
+    /// `origin` is the A1 address of the cell being edited; relative references and `[@Column]`
+    /// references resolve against it. Returns `{ ranges, names, tables }`; throws on parse errors.
+    #[wasm_bindgen(js_name = "analyzeFormula")]
+    pub fn analyze_formula(
+        &self,
+        formula: String,
+        sheet: Option<String>,
+        origin: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let out = self
+            .inner
+            .analyze_formula_internal(sheet, &formula, origin.as_deref())?;
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// Lint `formula` as if entered on `sheet` (default `Sheet1`), without committing it.
+    ///
+    /// Returns advisory findings as `[{ kind, severity, message, span }]`: ranges over empty
+    /// areas, volatile functions, blank cells used in arithmetic and, when `origin` (A1 address
+    /// of the edited cell) is given, formulas inconsistent with their neighbors. Throws on parse
+    /// errors.
+    #[wasm_bindgen(js_name = "lintFormula")]
+    pub fn lint_formula(
+        &self,
+        formula: String,
+        sheet: Option<String>,
+        origin: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let out = self
+            .inner
+            .lint_formula_internal(sheet, &formula, origin.as_deref())?;
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
     /// Returns the per-cell style id, or `0` if the cell has the default style.
     ///
     /// Note: This is currently a narrow interop hook so JS callers can preserve formatting when
@@ -6022,6 +8116,119 @@ impl WasmWorkbook {
         self.inner.get_cell_style_id_internal(sheet, &address)
     }
 
+    /// Explain which style layers contribute to a cell, lowest precedence first.
+    ///
+    /// Returns `[{ layer, styleId, properties, supplied }]`, where `layer` is one of `"sheet"`,
+    /// `"col"`, `"row"`, `"rangeRun"` or `"cell"`, `properties` lists what that layer's style sets
+    /// and `supplied` lists what it still contributes after higher layers apply. Layers with the
+    /// default style (`0`) are omitted.
+    #[wasm_bindgen(js_name = "describeStyleResolution")]
+    pub fn describe_style_resolution(
+        &self,
+        address: String,
+        sheet: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let out = self
+            .inner
+            .describe_style_resolution_internal(sheet, &address)?;
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// List every hyperlink on a sheet, ordered by address (row-major).
+    ///
+    /// Returns `[{ address, target, tooltip, location }]`. Worksheet hyperlinks report external
+    /// URLs/emails as `target` and in-workbook destinations as `location` (e.g. `"Sheet2!B3"`).
+    /// Cells whose formula is `HYPERLINK("...")` with a literal link location are included too;
+    /// a leading `#` marks an in-workbook `location`. Missing fields are `null`.
+    #[wasm_bindgen(js_name = "getHyperlinks")]
+    pub fn get_hyperlinks(&self, sheet: Option<String>) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let out = self.inner.get_hyperlinks_internal(sheet)?;
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
+    /// Returns the effective number format for a cell, resolved across the cell, range-run, row,
+    /// column and sheet style layers. Cells with no number format report `"General"`.
+    #[wasm_bindgen(js_name = "getNumberFormat")]
+    pub fn get_number_format(
+        &self,
+        address: String,
+        sheet: Option<String>,
+    ) -> Result<String, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        self.inner.get_number_format_internal(sheet, &address)
+    }
+
+    /// Returns a cell's value formatted with its effective number format.
+    ///
+    /// With "precision as displayed" enabled (`fullPrecision: false`), numbers are stored rounded
+    /// to exactly this text.
+    #[wasm_bindgen(js_name = "getCellDisplayText")]
+    pub fn get_cell_display_text(
+        &self,
+        address: String,
+        sheet: Option<String>,
+    ) -> Result<String, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        self.inner.get_cell_display_text_internal(sheet, &address)
+    }
+
+    /// Set a cell's number format without building a full style object.
+    ///
+    /// The cell keeps the rest of its style (fonts, fills, borders, ...); only the number format
+    /// changes. `"General"` clears the cell-level number format so lower layers apply again.
+    #[wasm_bindgen(js_name = "setNumberFormat")]
+    pub fn set_number_format(
+        &mut self,
+        address: String,
+        format: String,
+        sheet: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let cell_ref = WorkbookState::parse_address(&address)?;
+        self.inner
+            .set_number_format_internal(sheet, Range::new(cell_ref, cell_ref), &format)
+    }
+
+    /// Range variant of `setNumberFormat` (e.g. `"A1:C10"`).
+    #[wasm_bindgen(js_name = "setRangeNumberFormat")]
+    pub fn set_range_number_format(
+        &mut self,
+        range: String,
+        format: String,
+        sheet: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let range = WorkbookState::parse_range(&range)?;
+        self.inner.set_number_format_internal(sheet, range, &format)
+    }
+
+    /// Apply one style object to every cell in a range (e.g. `"A1:C10"`).
+    ///
+    /// The style is interned once. By default it replaces each cell's style; with `merge=true`
+    /// only the components present in `style` are overwritten. Returns the number of cells touched.
+    /// Whole rows, whole columns and large ranges are styled through the row/column/format-run
+    /// layers instead of per cell.
+    #[wasm_bindgen(js_name = "setRangeStyle")]
+    pub fn set_range_style(
+        &mut self,
+        range: String,
+        style: JsValue,
+        sheet: Option<String>,
+        merge: Option<bool>,
+    ) -> Result<u32, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let range = WorkbookState::parse_range(&range)?;
+        let style = parse_style_from_js(style)?;
+        self.inner
+            .set_range_style_internal(sheet, range, style, merge.unwrap_or(false))
+    }
+
     #[wasm_bindgen(js_name = "setCell")]
     pub fn set_cell(
         &mut self,
@@ -6037,7 +8244,9 @@ impl WasmWorkbook {
         }
         let input: JsonValue =
             serde_wasm_bindgen::from_value(input).map_err(|err| js_err(err.to_string()))?;
-        self.inner.set_cell_internal(sheet, &address, input)
+        self.inner.set_cell_internal(sheet, &address, input)?;
+        self.inner
+            .with_manual_calc_mode(|this| this.auto_expand_table_for_edit(sheet, &address))
     }
 
     #[wasm_bindgen(js_name = "setCellPhonetic")]
@@ -6109,38 +8318,93 @@ impl WasmWorkbook {
 
     #[wasm_bindgen(js_name = "setCells")]
     pub fn set_cells(&mut self, updates: JsValue) -> Result<(), JsValue> {
+        let updates: Vec<CellUpdateDto> =
+            serde_wasm_bindgen::from_value(updates).map_err(|err| js_err(err.to_string()))?;
+        self.inner.set_cells_internal(updates)
+    }
+
+    /// Protect `sheet` (`<sheetProtection>`), or unprotect it when `protection` is null/undefined.
+    ///
+    /// `protection` is `{ password?, options? }`, where `options` holds the allowed-action flags
+    /// (`formatCells`, `insertRows`, `selectLockedCells`, ...) and may also carry the `password`.
+    /// While protected, mutating methods fail with a `SHEET_PROTECTED:` error: `setCell`,
+    /// `setCells`, `setRange` and `applyOperation` when they touch locked cells, `applyOperation`
+    /// for structural edits the flags do not allow, and the style/number-format setters unless
+    /// `formatCells` is set. The password is only stored as Excel's legacy hash for export; it is
+    /// not checked when unprotecting.
+    #[wasm_bindgen(js_name = "setSheetProtection")]
+    pub fn set_sheet_protection(
+        &mut self,
+        sheet: String,
+        protection: JsValue,
+    ) -> Result<(), JsValue> {
         #[derive(Deserialize)]
-        struct CellUpdate {
-            address: String,
-            value: JsonValue,
-            sheet: Option<String>,
+        struct ProtectionInput {
+            #[serde(default)]
+            password: Option<String>,
+            #[serde(default)]
+            options: SheetProtectionOptionsDto,
         }
 
-        let updates: Vec<CellUpdate> =
-            serde_wasm_bindgen::from_value(updates).map_err(|err| js_err(err.to_string()))?;
-
-        for update in updates {
-            let sheet = update.sheet.as_deref().unwrap_or(DEFAULT_SHEET);
-            self.inner
-                .set_cell_internal(sheet, &update.address, update.value)?;
+        if protection.is_null() || protection.is_undefined() {
+            return self.inner.set_sheet_protection_internal(&sheet, None);
         }
+        let input: ProtectionInput =
+            serde_wasm_bindgen::from_value(protection).map_err(|err| js_err(err.to_string()))?;
+        let mut options = input.options;
+        if input.password.is_some() {
+            options.password = input.password;
+        }
+        let protection = options.to_protection();
+        self.inner
+            .set_sheet_protection_internal(&sheet, Some(protection))
+    }
 
-        Ok(())
+    #[wasm_bindgen(js_name = "isSheetProtected")]
+    pub fn is_sheet_protected(&self, sheet: String) -> Result<bool, JsValue> {
+        self.inner.is_sheet_protected_internal(&sheet)
+    }
+
+    /// Return the sheet's print area as comma-separated A1 ranges (e.g. `"A1:D20"`), or `null`.
+    #[wasm_bindgen(js_name = "getPrintArea")]
+    pub fn get_print_area(&self, sheet: String) -> Result<Option<String>, JsValue> {
+        self.inner.get_print_area_internal(&sheet)
+    }
+
+    /// Set the sheet's print area from comma-separated A1 ranges; `null` clears it.
+    ///
+    /// The print area is stored as the sheet-scoped `_xlnm.Print_Area` defined name.
+    #[wasm_bindgen(js_name = "setPrintArea")]
+    pub fn set_print_area(&mut self, sheet: String, range: Option<String>) -> Result<(), JsValue> {
+        // Preserve explicit-recalc semantics even when the workbook's calcMode is automatic.
+        self.inner
+            .with_manual_calc_mode(|this| this.set_print_area_internal(&sheet, range.as_deref()))
     }
 
+    /// Return `{ sheet, address, input, value }` objects for `range` as nested JS arrays
+    /// (rows -> columns).
+    ///
+    /// When `valuesOnly` is `true`, the sparse input lookup and the `sheet`/`address` fields
+    /// are skipped and each cell is just its computed value scalar (the same payload as
+    /// `getRangeValuesRaw`). Defaults to `false`.
     #[wasm_bindgen(js_name = "getRange")]
-    pub fn get_range(&self, range: String, sheet: Option<String>) -> Result<JsValue, JsValue> {
+    pub fn get_range(
+        &self,
+        range: String,
+        sheet: Option<String>,
+        values_only: Option<bool>,
+    ) -> Result<JsValue, JsValue> {
+        if values_only.unwrap_or(false) {
+            return self.get_range_values_raw(range, sheet);
+        }
+
         let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
         let sheet = self.inner.require_sheet(sheet)?.to_string();
         let range = WorkbookState::parse_range(&range)?;
         let start_row = range.start.row;
         let start_col = range.start.col;
 
-        let values = self
-            .inner
-            .engine
-            .get_range_values(&sheet, range)
-            .map_err(|err| js_err(err.to_string()))?;
+        let values = self.inner.get_range_values_internal(&sheet, range)?;
 
         let sheet_cells = self.inner.sheets.get(&sheet);
         let sheet_js = JsValue::from_str(&sheet);
@@ -6207,11 +8471,7 @@ impl WasmWorkbook {
         // This avoids allocating redundant `{sheet,address}` strings per cell, which the
         // TS backend discards anyway.
         let sheet_cells = self.inner.sheets.get(sheet);
-        let values = self
-            .inner
-            .engine
-            .get_range_values(sheet, range)
-            .map_err(|err| js_err(err.to_string()))?;
+        let values = self.inner.get_range_values_internal(sheet, range)?;
 
         let outer = Array::new_with_length(values.len() as u32);
         // Reuse buffers to avoid per-cell string allocations while looking up sparse inputs.
@@ -6250,6 +8510,79 @@ impl WasmWorkbook {
         Ok(outer.into())
     }
 
+    /// Return only the computed values for `range` as nested JS arrays (rows -> columns).
+    ///
+    /// Unlike `getRange`/`getRangeCompact`, this never consults the sparse input map, so it
+    /// avoids per-cell address formatting and lookups. Intended for bulk value exports (e.g.
+    /// feeding charts) where the user-entered inputs are not needed. Values are resolved exactly
+    /// as `getRange` and `getCell` resolve them, including before the next `recalculate()`.
+    #[wasm_bindgen(js_name = "getRangeValuesRaw")]
+    pub fn get_range_values_raw(
+        &self,
+        range: String,
+        sheet: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let sheet = self.inner.require_sheet(sheet)?;
+        let range = WorkbookState::parse_range(&range)?;
+
+        let values = self.inner.get_range_values_internal(sheet, range)?;
+
+        let outer = Array::new_with_length(values.len() as u32);
+        for (row_off, row_values) in values.into_iter().enumerate() {
+            let inner = Array::new_with_length(row_values.len() as u32);
+            for (col_off, engine_value) in row_values.into_iter().enumerate() {
+                inner.set(col_off as u32, engine_value_to_js_scalar(engine_value));
+            }
+            outer.set(row_off as u32, inner.into());
+        }
+
+        Ok(outer.into())
+    }
+
+    /// Return the computed values for `range` column by column, as typed buffers.
+    ///
+    /// Result shape: `{ rows, cols, columns: [{ kind, types, values }] }`, where `types` is a
+    /// `Uint8Array` of per-cell tags (`0` blank, `1` number, `2` text, `3` boolean, `4` error) and
+    /// `values` depends on `kind`:
+    /// - `"number"`: `Float64Array` (booleans as `0`/`1`, blanks as `NaN`)
+    /// - `"text"`: array of strings (`null` for blanks)
+    /// - `"mixed"`: array of the same scalars `getRangeValuesRaw` returns
+    ///
+    /// Numeric columns avoid boxing one JS value per cell, which keeps GC pressure down for large
+    /// analytics reads.
+    #[wasm_bindgen(js_name = "getRangeColumnar")]
+    pub fn get_range_columnar(
+        &self,
+        range: String,
+        sheet: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+        let sheet = self.inner.require_sheet(sheet)?;
+        let range = WorkbookState::parse_range(&range)?;
+
+        let values = self.inner.get_range_values_internal(sheet, range)?;
+        let columns = range_values_to_columns(values, range.width() as usize);
+
+        let columns_js = Array::new_with_length(columns.len() as u32);
+        for (idx, column) in columns.iter().enumerate() {
+            columns_js.set(idx as u32, column.to_js()?);
+        }
+        let out = Object::new();
+        Reflect::set(
+            &out,
+            &JsValue::from_str("rows"),
+            &JsValue::from_f64(f64::from(range.height())),
+        )?;
+        Reflect::set(
+            &out,
+            &JsValue::from_str("cols"),
+            &JsValue::from_f64(f64::from(range.width())),
+        )?;
+        Reflect::set(&out, &JsValue::from_str("columns"), &columns_js)?;
+        Ok(out.into())
+    }
+
     #[wasm_bindgen(js_name = "setRange")]
     pub fn set_range(
         &mut self,
@@ -6271,16 +8604,7 @@ impl WasmWorkbook {
             )));
         }
 
-        for (r_idx, row_values) in values.into_iter().enumerate() {
-            for (c_idx, input) in row_values.into_iter().enumerate() {
-                let row = range_parsed.start.row + r_idx as u32;
-                let col = range_parsed.start.col + c_idx as u32;
-                let addr = formula_model::cell_to_a1(row, col);
-                self.inner.set_cell_internal(sheet, &addr, input)?;
-            }
-        }
-
-        Ok(())
+        self.inner.set_range_internal(sheet, range_parsed, values)
     }
 
     #[wasm_bindgen(js_name = "goalSeek")]
@@ -6364,6 +8688,39 @@ impl WasmWorkbook {
         serde_wasm_bindgen::to_value(&out).map_err(|err| js_err(err.to_string()))
     }
 
+    /// Evaluate `targets` as if each override cell held the given value, without changing the
+    /// workbook.
+    ///
+    /// `overrides` is an array of `{ address, value }` scalar inputs; `targets` is an array of A1
+    /// addresses. Returns `{ values, changes }`, where `values` follows the order of `targets` and
+    /// `changes` holds any pending recalculation results flushed before the scenario ran.
+    #[wasm_bindgen(js_name = "evaluateScenario")]
+    pub fn evaluate_scenario(
+        &mut self,
+        overrides: JsValue,
+        targets: JsValue,
+        sheet: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        ensure_rust_constructors_run();
+        let overrides: Vec<ScenarioOverrideDto> =
+            serde_wasm_bindgen::from_value(overrides).map_err(|err| js_err(err.to_string()))?;
+        let targets: Vec<String> =
+            serde_wasm_bindgen::from_value(targets).map_err(|err| js_err(err.to_string()))?;
+        let overrides: Vec<(String, JsonValue)> = overrides
+            .into_iter()
+            .map(|entry| (entry.address, entry.value))
+            .collect();
+        let sheet = sheet.as_deref().unwrap_or(DEFAULT_SHEET);
+
+        let (values, changes) = self
+            .inner
+            .evaluate_scenario_internal(sheet, &overrides, &targets)?;
+        let out = EvaluateScenarioResponseDto { values, changes };
+        use serde::ser::Serialize as _;
+        out.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|err| js_err(err.to_string()))
+    }
+
     #[wasm_bindgen(js_name = "getPivotSchema")]
     pub fn get_pivot_schema(
         &self,
@@ -6480,6 +8837,50 @@ impl WasmWorkbook {
         Ok(out.into())
     }
 
+    /// Like `recalculate`, but calls `callback(done, total)` while evaluating.
+    ///
+    /// Recalc is synchronous, so the callback runs inline on the calling thread (about every
+    /// 256 evaluated formula cells, plus once at the end with `done === total`). A page cannot
+    /// repaint in between; run the workbook in a Worker and forward progress with `postMessage`
+    /// to drive a progress bar. `total` can grow mid-recalc when spills dirty extra cells.
+    ///
+    /// A throwing callback does not fail the call: the recalc has already been committed, so the
+    /// value changes are always returned to keep caches in sync. The callback is not called again
+    /// after it throws, and the first error is passed to `onError(err)` once recalc finishes.
+    #[wasm_bindgen(js_name = "recalculateWithProgress")]
+    pub fn recalculate_with_progress(
+        &mut self,
+        callback: js_sys::Function,
+        sheet: Option<String>,
+        on_error: Option<js_sys::Function>,
+    ) -> Result<JsValue, JsValue> {
+        let mut callback_err: Option<JsValue> = None;
+        let mut on_progress = |progress: RecalcProgress| {
+            if callback_err.is_some() {
+                return;
+            }
+            if let Err(err) = callback.call2(
+                &JsValue::NULL,
+                &JsValue::from_f64(progress.done as f64),
+                &JsValue::from_f64(progress.total as f64),
+            ) {
+                callback_err = Some(err);
+            }
+        };
+        let changes = self
+            .inner
+            .recalculate_internal_with_progress(sheet.as_deref(), Some(&mut on_progress))?;
+        if let (Some(err), Some(on_error)) = (callback_err, on_error) {
+            // Best-effort: a throwing `onError` must not drop the changes either.
+            let _ = on_error.call1(&JsValue::NULL, &err);
+        }
+        let out = Array::new();
+        for change in changes {
+            out.push(&cell_change_to_js(&change)?);
+        }
+        Ok(out.into())
+    }
+
     #[wasm_bindgen(js_name = "applyOperation")]
     pub fn apply_operation(&mut self, op: JsValue) -> Result<JsValue, JsValue> {
         let op: EditOpDto =
@@ -6492,6 +8893,13 @@ impl WasmWorkbook {
     pub fn default_sheet_name() -> String {
         DEFAULT_SHEET.to_string()
     }
+
+    /// Describe the compiled engine: crate version, optional features, the number of registered
+    /// functions, and the grid limits. Does not touch any workbook state.
+    #[wasm_bindgen(js_name = "engineInfo")]
+    pub fn engine_info() -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(&engine_info_dto()).map_err(|err| js_err(err.to_string()))
+    }
 }
 
 fn xlsb_error_code_to_model_error(code: u8) -> formula_model::ErrorValue {
@@ -6735,6 +9143,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn engine_info_reports_version_features_and_limits() {
+        let info = serde_json::to_value(engine_info_dto()).unwrap();
+
+        assert_eq!(info["version"], json!(env!("CARGO_PKG_VERSION")));
+        assert_eq!(info["features"]["dax"], json!(cfg!(feature = "dax")));
+        assert_eq!(
+            info["features"]["xlsxVba"],
+            json!(cfg!(feature = "xlsx-vba"))
+        );
+        assert_eq!(info["maxRows"], json!(1_048_576));
+        assert_eq!(info["maxCols"], json!(16_384));
+
+        let count = info["functionCount"].as_u64().unwrap();
+        assert!(
+            count > 400,
+            "expected a full function registry, got {count}"
+        );
+    }
+
     #[test]
     fn from_json_sheet_order_controls_3d_reference_semantics() {
         // 3D references (`Sheet1:Sheet3!A1`) depend on sheet tab order. The JSON workbook schema is
@@ -6775,6 +9203,71 @@ mod tests {
         assert_eq!(value_ordered.as_f64().unwrap(), 111.0);
     }
 
+    #[test]
+    fn set_cells_batch_matches_per_cell_set_cell() {
+        let updates: Vec<(Option<&str>, &str, JsonValue)> = vec![
+            (None, "A1", json!(1)),
+            (Some("Other"), "A1", json!("=Sheet1!A1*10")),
+            (None, "A2", json!("=A1+1")),
+            (Some("Other"), "B1", json!("text")),
+            (None, "A1", json!(5)),
+            (None, "A3", json!("=SUM(A1:A2)")),
+            (Some("Other"), "B1", JsonValue::Null),
+        ];
+
+        let mut batched = WorkbookState::new_with_default_sheet();
+        batched.ensure_sheet("Other");
+        batched
+            .set_cells_internal(
+                updates
+                    .iter()
+                    .map(|(sheet, address, value)| CellUpdateDto {
+                        address: address.to_string(),
+                        value: value.clone(),
+                        sheet: sheet.map(str::to_string),
+                    })
+                    .collect(),
+            )
+            .unwrap();
+
+        let mut per_cell = WorkbookState::new_with_default_sheet();
+        per_cell.ensure_sheet("Other");
+        for (sheet, address, value) in &updates {
+            let sheet = sheet.unwrap_or(DEFAULT_SHEET);
+            let clears = value.is_null();
+            per_cell
+                .set_cell_internal(sheet, address, value.clone())
+                .unwrap();
+            if !clears {
+                per_cell
+                    .with_manual_calc_mode(|this| this.auto_expand_table_for_edit(sheet, address))
+                    .unwrap();
+            }
+        }
+
+        assert_eq!(
+            batched.recalculate_internal(None).unwrap(),
+            per_cell.recalculate_internal(None).unwrap()
+        );
+        for (sheet, address) in [
+            (DEFAULT_SHEET, "A1"),
+            (DEFAULT_SHEET, "A2"),
+            (DEFAULT_SHEET, "A3"),
+            ("Other", "A1"),
+            ("Other", "B1"),
+        ] {
+            assert_eq!(
+                batched.get_cell_data(sheet, address).unwrap(),
+                per_cell.get_cell_data(sheet, address).unwrap(),
+                "{sheet}!{address}"
+            );
+        }
+        assert_eq!(
+            batched.get_cell_data("Other", "A1").unwrap().value,
+            json!(50.0)
+        );
+    }
+
     #[test]
     fn set_cell_rich_entity_roundtrips_and_degrades_in_get_cell() {
         let mut wb = WorkbookState::new_with_default_sheet();
@@ -7989,82 +10482,365 @@ mod tests {
         );
     }
 
-    fn build_inline_string_phonetic_fixture_xlsx() -> Vec<u8> {
-        use std::io::{Cursor, Write};
-        use zip::write::FileOptions;
-        use zip::{CompressionMethod, ZipWriter};
-
-        let workbook_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"
- xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
-  <sheets>
-    <sheet name="Sheet1" sheetId="1" r:id="rId1"/>
-  </sheets>
-</workbook>"#;
-
-        let workbook_rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
-  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>
-</Relationships>"#;
-
-        let root_rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
-  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>
-</Relationships>"#;
-
-        let content_types = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
-  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
-  <Default Extension="xml" ContentType="application/xml"/>
-  <Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
-  <Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>
-</Types>"#;
+    #[test]
+    fn get_sheet_tables_reports_imported_table_metadata() {
+        let bytes = include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../formula-xlsx/tests/fixtures/table.xlsx"
+        ));
 
-        let worksheet_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
-  <sheetData>
-    <row r="1">
-      <c r="A1" t="inlineStr">
-        <is>
-          <t>Base</t>
-          <phoneticPr fontId="0" type="noConversion"/>
-          <rPh sb="0" eb="4"><t>PHONETIC</t></rPh>
-        </is>
-      </c>
-      <c r="B1">
-        <f>PHONETIC(A1)</f>
-      </c>
-    </row>
-  </sheetData>
-</worksheet>"#;
+        let wb = WasmWorkbook::from_xlsx_bytes(bytes).unwrap();
+        let tables = wb.inner.get_sheet_tables_internal(DEFAULT_SHEET).unwrap();
+        assert_eq!(tables.len(), 1);
+
+        let table = &tables[0];
+        assert_eq!(table.name, "Table1");
+        assert_eq!(table.display_name, "Table1");
+        assert_eq!(table.range, "A1:D4");
+        assert_eq!(table.header_row_count, 1);
+        assert_eq!(table.totals_row_count, 0);
+        let names: Vec<&str> = table.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Item", "Qty", "Price", "Total"]);
+        assert_eq!(table.columns[3].formula.as_deref(), Some("[@Qty]*[@Price]"));
+        assert!(table.columns.iter().all(|c| !c.has_totals_formula));
+
+        let style = table.style.as_ref().expect("table style");
+        assert_eq!(style.name, "TableStyleMedium2");
+        assert!(style.show_row_stripes);
+        assert!(!style.show_column_stripes);
+    }
 
-        let cursor = Cursor::new(Vec::new());
-        let mut zip = ZipWriter::new(cursor);
-        let options = FileOptions::<()>::default().compression_method(CompressionMethod::Deflated);
+    #[test]
+    fn get_sheet_tables_reports_totals_row_formulas() {
+        let mut wb = WasmWorkbook::new();
+        let table = formula_model::Table {
+            id: 1,
+            name: "Sales".to_string(),
+            display_name: "Sales".to_string(),
+            range: Range::from_a1("A1:B4").unwrap(),
+            header_row_count: 1,
+            totals_row_count: 1,
+            columns: vec![
+                formula_model::TableColumn {
+                    id: 1,
+                    name: "Region".to_string(),
+                    formula: None,
+                    totals_formula: None,
+                },
+                formula_model::TableColumn {
+                    id: 2,
+                    name: "Amount".to_string(),
+                    formula: None,
+                    totals_formula: Some("SUBTOTAL(109,[Amount])".to_string()),
+                },
+            ],
+            style: None,
+            auto_filter: None,
+            relationship_id: None,
+            part_path: None,
+        };
+        wb.inner.engine.set_sheet_tables(DEFAULT_SHEET, vec![table]);
+
+        let tables = wb.inner.get_sheet_tables_internal(DEFAULT_SHEET).unwrap();
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].totals_row_count, 1);
+        assert!(tables[0].style.is_none());
+        assert!(!tables[0].columns[0].has_totals_formula);
+        assert!(tables[0].columns[1].has_totals_formula);
+        assert_eq!(
+            tables[0].columns[1].totals_formula.as_deref(),
+            Some("SUBTOTAL(109,[Amount])")
+        );
+    }
 
-        zip.start_file("_rels/.rels", options).unwrap();
-        zip.write_all(root_rels.as_bytes()).unwrap();
+    #[test]
+    fn add_table_detects_headers_and_resolves_structured_refs() {
+        let mut wb = WasmWorkbook::new();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "A1", json!("Item"))
+            .unwrap();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "B1", json!("Qty"))
+            .unwrap();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "A2", json!("Apple"))
+            .unwrap();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "B2", json!(3.0))
+            .unwrap();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "B3", json!(4.0))
+            .unwrap();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "D1", json!("=SUM(Fruit[Qty])"))
+            .unwrap();
 
-        zip.start_file("[Content_Types].xml", options).unwrap();
-        zip.write_all(content_types.as_bytes()).unwrap();
+        wb.inner
+            .add_table_internal(
+                DEFAULT_SHEET,
+                "A1:B3",
+                "Fruit",
+                AddTableOptionsDto::default(),
+            )
+            .unwrap();
+        wb.inner.recalculate_internal(None).unwrap();
+        assert_eq!(
+            wb.inner.engine.get_cell_value(DEFAULT_SHEET, "D1"),
+            EngineValue::Number(7.0)
+        );
 
-        zip.start_file("xl/workbook.xml", options).unwrap();
-        zip.write_all(workbook_xml.as_bytes()).unwrap();
+        let tables = wb.inner.get_sheet_tables_internal(DEFAULT_SHEET).unwrap();
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].range, "A1:B3");
+        assert_eq!(tables[0].header_row_count, 1);
+        let names: Vec<&str> = tables[0].columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Item", "Qty"]);
+        assert_eq!(
+            tables[0].style.as_ref().map(|s| s.name.as_str()),
+            Some("TableStyleMedium2")
+        );
+    }
 
-        zip.start_file("xl/_rels/workbook.xml.rels", options)
+    #[test]
+    fn add_table_without_text_headers_uses_default_column_names() {
+        let mut wb = WasmWorkbook::new();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "A1", json!(1.0))
+            .unwrap();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "B1", json!("Name"))
             .unwrap();
-        zip.write_all(workbook_rels.as_bytes()).unwrap();
 
-        zip.start_file("xl/worksheets/sheet1.xml", options).unwrap();
-        zip.write_all(worksheet_xml.as_bytes()).unwrap();
+        wb.inner
+            .add_table_internal(
+                DEFAULT_SHEET,
+                "A1:B2",
+                "Data",
+                AddTableOptionsDto::default(),
+            )
+            .unwrap();
+        let tables = wb.inner.get_sheet_tables_internal(DEFAULT_SHEET).unwrap();
+        assert_eq!(tables[0].header_row_count, 0);
+        let names: Vec<&str> = tables[0].columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Column1", "Column2"]);
 
-        zip.finish().unwrap().into_inner()
+        // Explicit headers are deduplicated like Excel.
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "D1", json!("Qty"))
+            .unwrap();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "E1", json!("qty"))
+            .unwrap();
+        wb.inner
+            .add_table_internal(
+                DEFAULT_SHEET,
+                "D1:F2",
+                "Other",
+                AddTableOptionsDto {
+                    has_headers: Some(true),
+                    style_name: Some("TableStyleLight1".to_string()),
+                },
+            )
+            .unwrap();
+        let tables = wb.inner.get_sheet_tables_internal(DEFAULT_SHEET).unwrap();
+        let names: Vec<&str> = tables[1].columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Qty", "qty2", "Column3"]);
     }
 
-    fn build_shared_strings_phonetic_fixture_xlsx() -> Vec<u8> {
-        use std::io::{Cursor, Write};
-        use zip::write::FileOptions;
-        use zip::{CompressionMethod, ZipWriter};
+    #[test]
+    fn resize_and_delete_table_update_structured_refs() {
+        let mut wb = WasmWorkbook::new();
+        for (address, value) in [("A1", json!("Qty")), ("A2", json!(1.0)), ("A3", json!(2.0))] {
+            wb.inner
+                .set_cell_internal(DEFAULT_SHEET, address, value)
+                .unwrap();
+        }
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "C1", json!("=SUM(T[Qty])"))
+            .unwrap();
+        wb.inner
+            .add_table_internal(DEFAULT_SHEET, "A1:A2", "T", AddTableOptionsDto::default())
+            .unwrap();
+        wb.inner.recalculate_internal(None).unwrap();
+        assert_eq!(
+            wb.inner.engine.get_cell_value(DEFAULT_SHEET, "C1"),
+            EngineValue::Number(1.0)
+        );
+
+        wb.inner.resize_table_internal("T", "A1:A3").unwrap();
+        wb.inner.recalculate_internal(None).unwrap();
+        assert_eq!(
+            wb.inner.engine.get_cell_value(DEFAULT_SHEET, "C1"),
+            EngineValue::Number(3.0)
+        );
+
+        wb.inner.delete_table_internal("T").unwrap();
+        assert!(wb
+            .inner
+            .get_sheet_tables_internal(DEFAULT_SHEET)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn set_range_below_a_table_expands_it() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_range_internal(
+            DEFAULT_SHEET,
+            Range::from_a1("A1:B2").unwrap(),
+            vec![
+                vec![json!("Qty"), json!("Price")],
+                vec![json!(1.0), json!(2.0)],
+            ],
+        )
+        .unwrap();
+        wb.add_table_internal(DEFAULT_SHEET, "A1:B2", "T", AddTableOptionsDto::default())
+            .unwrap();
+
+        wb.set_range_internal(
+            DEFAULT_SHEET,
+            Range::from_a1("A3:B4").unwrap(),
+            vec![vec![json!(3.0), json!(4.0)], vec![json!(5.0), json!(6.0)]],
+        )
+        .unwrap();
+        assert_eq!(
+            wb.get_sheet_tables_internal(DEFAULT_SHEET).unwrap()[0].range,
+            "A1:B4"
+        );
+
+        // Clearing cells below the table never grows it.
+        wb.set_range_internal(
+            DEFAULT_SHEET,
+            Range::from_a1("A5").unwrap(),
+            vec![vec![JsonValue::Null]],
+        )
+        .unwrap();
+        assert_eq!(
+            wb.get_sheet_tables_internal(DEFAULT_SHEET).unwrap()[0].range,
+            "A1:B4"
+        );
+    }
+
+    #[test]
+    fn editing_the_row_below_a_table_expands_it() {
+        let mut wb = WasmWorkbook::new();
+        for (address, value) in [("A1", json!("Qty")), ("A2", json!(1.0))] {
+            wb.inner
+                .set_cell_internal(DEFAULT_SHEET, address, value)
+                .unwrap();
+        }
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "C1", json!("=SUM(T[Qty])"))
+            .unwrap();
+        wb.inner
+            .add_table_internal(DEFAULT_SHEET, "A1:A2", "T", AddTableOptionsDto::default())
+            .unwrap();
+
+        // Not adjacent: no expansion.
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "B3", json!(5.0))
+            .unwrap();
+        wb.inner
+            .auto_expand_table_for_edit(DEFAULT_SHEET, "B3")
+            .unwrap();
+        assert_eq!(
+            wb.inner.get_sheet_tables_internal(DEFAULT_SHEET).unwrap()[0].range,
+            "A1:A2"
+        );
+
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "A3", json!(2.0))
+            .unwrap();
+        wb.inner
+            .auto_expand_table_for_edit(DEFAULT_SHEET, "A3")
+            .unwrap();
+        assert_eq!(
+            wb.inner.get_sheet_tables_internal(DEFAULT_SHEET).unwrap()[0].range,
+            "A1:A3"
+        );
+        wb.inner.recalculate_internal(None).unwrap();
+        assert_eq!(
+            wb.inner.engine.get_cell_value(DEFAULT_SHEET, "C1"),
+            EngineValue::Number(3.0)
+        );
+    }
+
+    fn build_inline_string_phonetic_fixture_xlsx() -> Vec<u8> {
+        use std::io::{Cursor, Write};
+        use zip::write::FileOptions;
+        use zip::{CompressionMethod, ZipWriter};
+
+        let workbook_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"
+ xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <sheets>
+    <sheet name="Sheet1" sheetId="1" r:id="rId1"/>
+  </sheets>
+</workbook>"#;
+
+        let workbook_rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>
+</Relationships>"#;
+
+        let root_rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>
+</Relationships>"#;
+
+        let content_types = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="xml" ContentType="application/xml"/>
+  <Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
+  <Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>
+</Types>"#;
+
+        let worksheet_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+  <sheetData>
+    <row r="1">
+      <c r="A1" t="inlineStr">
+        <is>
+          <t>Base</t>
+          <phoneticPr fontId="0" type="noConversion"/>
+          <rPh sb="0" eb="4"><t>PHONETIC</t></rPh>
+        </is>
+      </c>
+      <c r="B1">
+        <f>PHONETIC(A1)</f>
+      </c>
+    </row>
+  </sheetData>
+</worksheet>"#;
+
+        let cursor = Cursor::new(Vec::new());
+        let mut zip = ZipWriter::new(cursor);
+        let options = FileOptions::<()>::default().compression_method(CompressionMethod::Deflated);
+
+        zip.start_file("_rels/.rels", options).unwrap();
+        zip.write_all(root_rels.as_bytes()).unwrap();
+
+        zip.start_file("[Content_Types].xml", options).unwrap();
+        zip.write_all(content_types.as_bytes()).unwrap();
+
+        zip.start_file("xl/workbook.xml", options).unwrap();
+        zip.write_all(workbook_xml.as_bytes()).unwrap();
+
+        zip.start_file("xl/_rels/workbook.xml.rels", options)
+            .unwrap();
+        zip.write_all(workbook_rels.as_bytes()).unwrap();
+
+        zip.start_file("xl/worksheets/sheet1.xml", options).unwrap();
+        zip.write_all(worksheet_xml.as_bytes()).unwrap();
+
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn build_shared_strings_phonetic_fixture_xlsx() -> Vec<u8> {
+        use std::io::{Cursor, Write};
+        use zip::write::FileOptions;
+        use zip::{CompressionMethod, ZipWriter};
 
         let workbook_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"
@@ -8321,6 +11097,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_xlsx_bytes_imports_row_heights_and_hidden_rows() {
+        let bytes = include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../fixtures/xlsx/basic/row-col-attrs.xlsx"
+        ));
+
+        let wb = WasmWorkbook::from_xlsx_bytes(bytes).unwrap();
+
+        // Row 2 (1-based) has a custom height, and row 3 is hidden.
+        assert_eq!(
+            wb.get_row_height(DEFAULT_SHEET.to_string(), 1).unwrap(),
+            Some(20.0)
+        );
+        assert_eq!(
+            wb.get_row_height(DEFAULT_SHEET.to_string(), 2).unwrap(),
+            Some(0.0)
+        );
+        assert!(wb.inner.engine.is_row_hidden(DEFAULT_SHEET, 2));
+        assert_eq!(
+            wb.get_row_height(DEFAULT_SHEET.to_string(), 0).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn set_row_height_roundtrips_and_zero_height_hides_row() {
+        let mut wb = WasmWorkbook::new();
+        wb.inner
+            .set_row_height_internal(DEFAULT_SHEET, 4, Some(30.0))
+            .unwrap();
+        assert_eq!(
+            wb.get_row_height(DEFAULT_SHEET.to_string(), 4).unwrap(),
+            Some(30.0)
+        );
+
+        wb.inner
+            .set_row_height_internal(DEFAULT_SHEET, 4, Some(0.0))
+            .unwrap();
+        assert!(wb.inner.engine.is_row_hidden(DEFAULT_SHEET, 4));
+        assert_eq!(
+            wb.get_row_height(DEFAULT_SHEET.to_string(), 4).unwrap(),
+            Some(0.0)
+        );
+
+        wb.inner
+            .set_row_height_internal(DEFAULT_SHEET, 4, None)
+            .unwrap();
+        assert_eq!(
+            wb.get_row_height(DEFAULT_SHEET.to_string(), 4).unwrap(),
+            Some(0.0),
+            "clearing the height override should keep the row hidden"
+        );
+    }
+
     #[test]
     fn from_model_json_propagates_codepage_and_cell_phonetic_metadata() {
         let mut model = formula_model::Workbook::new();
@@ -9708,6 +12539,75 @@ mod tests {
         assert_eq!(rich_cells.get("A2"), Some(&entity));
     }
 
+    #[test]
+    fn apply_operation_text_to_columns_splits_cells_and_updates_inputs() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!("x;1.5"))
+            .unwrap();
+
+        wb.apply_operation_internal(EditOpDto::TextToColumns {
+            sheet: DEFAULT_SHEET.to_string(),
+            src: "A1:A2".to_string(),
+            delimiter: Some(";".to_string()),
+            fixed_widths: None,
+            shift_right: false,
+        })
+        .unwrap();
+
+        let sheet_cells = wb.sheets.get(DEFAULT_SHEET).unwrap();
+        assert_eq!(sheet_cells.get("A1"), Some(&json!("x")));
+        assert_eq!(sheet_cells.get("B1"), Some(&json!(1.5)));
+    }
+
+    #[test]
+    fn apply_operation_remove_duplicates_reports_removed_rows() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        for (address, value) in [("A1", "a"), ("A2", "A"), ("A3", "b")] {
+            wb.set_cell_internal(DEFAULT_SHEET, address, json!(value))
+                .unwrap();
+        }
+
+        let result = wb
+            .apply_operation_internal(EditOpDto::RemoveDuplicates {
+                sheet: DEFAULT_SHEET.to_string(),
+                range: "A1:A3".to_string(),
+                key_columns: vec![0],
+            })
+            .unwrap();
+
+        assert_eq!(result.removed_rows, 1);
+        let sheet_cells = wb.sheets.get(DEFAULT_SHEET).unwrap();
+        assert_eq!(sheet_cells.get("A2"), Some(&json!("b")));
+        assert_eq!(sheet_cells.get("A3"), None);
+    }
+
+    #[test]
+    fn apply_operation_sort_range_maps_sheet_columns_to_sort_keys() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        for (address, value) in [
+            ("B1", json!("Feb")),
+            ("C1", json!(2)),
+            ("B2", json!("Jan")),
+            ("C2", json!(1)),
+        ] {
+            wb.set_cell_internal(DEFAULT_SHEET, address, value).unwrap();
+        }
+
+        let op: EditOpDto = serde_json::from_value(json!({
+            "type": "SortRange",
+            "sheet": DEFAULT_SHEET,
+            "range": "B1:C2",
+            "keys": [{ "col": 1, "customList": ["Jan", "Feb"] }],
+        }))
+        .unwrap();
+        wb.apply_operation_internal(op).unwrap();
+
+        let sheet_cells = wb.sheets.get(DEFAULT_SHEET).unwrap();
+        assert_eq!(sheet_cells.get("B1"), Some(&json!("Jan")));
+        assert_eq!(sheet_cells.get("C1"), Some(&json!(1.0)));
+        assert_eq!(sheet_cells.get("B2"), Some(&json!("Feb")));
+    }
+
     #[test]
     fn apply_operation_fill_repeats_formulas_and_updates_relative_references() {
         let mut wb = WorkbookState::new_with_default_sheet();
@@ -10142,6 +13042,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn evaluate_scenario_reads_targets_and_leaves_workbook_unchanged() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(2.0))
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "B1", json!("=A1*10"))
+            .unwrap();
+        let changes = wb.recalculate_internal(None).unwrap();
+        assert!(!changes.is_empty());
+
+        let (values, changes) = wb
+            .evaluate_scenario_internal(
+                DEFAULT_SHEET,
+                &[("A1".to_string(), json!(7.0))],
+                &["B1".to_string(), "A1".to_string()],
+            )
+            .unwrap();
+        assert_eq!(values, vec![json!(70.0), json!(7.0)]);
+        assert!(changes.is_empty());
+
+        assert_eq!(
+            wb.engine.get_cell_value(DEFAULT_SHEET, "A1"),
+            EngineValue::Number(2.0)
+        );
+        assert_eq!(
+            wb.engine.get_cell_value(DEFAULT_SHEET, "B1"),
+            EngineValue::Number(20.0)
+        );
+        assert!(wb.recalculate_internal(None).unwrap().is_empty());
+    }
+
     #[test]
     fn goal_seek_converges_and_returns_changes() {
         use formula_engine::what_if::goal_seek::GoalSeekStatus;
@@ -10184,23 +13115,807 @@ mod tests {
     }
 
     #[test]
-    fn style_json_to_model_style_accepts_ui_camel_case_number_format() {
-        let style = style_json_to_model_style(&json!({ "numberFormat": "0.00" }));
-        assert_eq!(style.number_format.as_deref(), Some("0.00"));
-    }
+    fn range_values_match_cell_values_before_recalculation() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        for (address, input) in [
+            ("A1", json!(1.0)),
+            ("A2", json!("text")),
+            ("B1", json!("=A1*2")),
+            ("C1", json!("=SEQUENCE(2)")),
+        ] {
+            wb.set_cell_internal(DEFAULT_SHEET, address, input).unwrap();
+        }
+        wb.recalculate_internal(None).unwrap();
 
-    #[test]
-    fn style_json_to_model_style_treats_null_number_format_as_explicit_general_override() {
-        let style = style_json_to_model_style(&json!({ "numberFormat": null }));
-        assert_eq!(style.number_format.as_deref(), Some("General"));
+        // Pending edits: a changed precedent, a new formula, and a cleared input.
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(5.0))
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "D1", json!("=A1+1"))
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "A2", JsonValue::Null)
+            .unwrap();
+
+        let range = Range::from_a1("A1:D3").unwrap();
+        let values = wb.get_range_values_internal(DEFAULT_SHEET, range).unwrap();
+        for (row_off, row_values) in values.into_iter().enumerate() {
+            for (col_off, value) in row_values.into_iter().enumerate() {
+                let address = formula_model::cell_to_a1(row_off as u32, col_off as u32);
+                let cell = wb.get_cell_data(DEFAULT_SHEET, &address).unwrap();
+                assert_eq!(engine_value_to_json(value), cell.value, "{address}");
+            }
+        }
     }
 
     #[test]
-    fn style_json_to_model_style_prefers_null_number_format_over_imported_snake_case() {
-        let style = style_json_to_model_style(&json!({
-            "number_format": "0.00",
-            "numberFormat": null,
-        }));
+    fn range_values_to_columns_picks_typed_payloads_per_column() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        for (address, input) in [
+            ("A1", json!(1.5)),
+            ("A3", json!(true)),
+            ("B1", json!("x")),
+            ("B2", json!("y")),
+            ("C1", json!(2)),
+            ("C2", json!("two")),
+            ("C3", json!("=1/0")),
+        ] {
+            wb.set_cell_internal(DEFAULT_SHEET, address, input).unwrap();
+        }
+        wb.recalculate_internal(None).unwrap();
+
+        let range = Range::from_a1("A1:D3").unwrap();
+        let values = wb.engine.get_range_values(DEFAULT_SHEET, range).unwrap();
+        let columns = range_values_to_columns(values, range.width() as usize);
+        assert_eq!(columns.len(), 4);
+
+        assert_eq!(
+            columns[0].types,
+            vec![
+                COLUMNAR_TYPE_NUMBER,
+                COLUMNAR_TYPE_BLANK,
+                COLUMNAR_TYPE_BOOL
+            ]
+        );
+        let ColumnarValues::Number(numbers) = &columns[0].values else {
+            panic!("expected numeric column, got {:?}", columns[0].values);
+        };
+        assert_eq!(numbers[0], 1.5);
+        assert!(numbers[1].is_nan());
+        assert_eq!(numbers[2], 1.0);
+
+        assert_eq!(
+            columns[1].values,
+            ColumnarValues::Text(vec![Some("x".to_string()), Some("y".to_string()), None])
+        );
+        assert_eq!(
+            columns[2].types,
+            vec![
+                COLUMNAR_TYPE_NUMBER,
+                COLUMNAR_TYPE_TEXT,
+                COLUMNAR_TYPE_ERROR
+            ]
+        );
+        assert_eq!(
+            columns[2].values,
+            ColumnarValues::Mixed(vec![
+                EngineValue::Number(2.0),
+                EngineValue::Text("two".to_string()),
+                EngineValue::Error(ErrorKind::Div0),
+            ])
+        );
+        // An all-blank column is numeric (all `NaN`).
+        assert_eq!(columns[3].types, vec![COLUMNAR_TYPE_BLANK; 3]);
+        assert!(matches!(columns[3].values, ColumnarValues::Number(_)));
+    }
+
+    #[test]
+    fn set_number_format_keeps_other_style_fields_and_general_clears() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        let bold = wb.engine.intern_style(Style {
+            font: Some(Font {
+                bold: true,
+                ..Default::default()
+            }),
+            number_format: Some("0.00".to_string()),
+            ..Default::default()
+        });
+        wb.set_cell_style_id_internal(DEFAULT_SHEET, "A1", bold)
+            .unwrap();
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "A1").unwrap(),
+            "0.00"
+        );
+
+        let range = Range::from_a1("A1").unwrap();
+        wb.set_number_format_internal(DEFAULT_SHEET, range, "0%")
+            .unwrap();
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "A1").unwrap(),
+            "0%"
+        );
+        let style_id = wb.get_cell_style_id_internal(DEFAULT_SHEET, "A1").unwrap();
+        let style = wb.engine.style_table().get(style_id).unwrap();
+        assert!(style.font.as_ref().is_some_and(|font| font.bold));
+
+        // "General" drops the cell-level format, so a column format shows through again.
+        let col_style = wb.engine.intern_style(Style {
+            number_format: Some("0.0".to_string()),
+            ..Default::default()
+        });
+        wb.engine
+            .set_col_style_id(DEFAULT_SHEET, 0, Some(col_style));
+        wb.set_number_format_internal(DEFAULT_SHEET, range, "General")
+            .unwrap();
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "A1").unwrap(),
+            "0.0"
+        );
+        let style_id = wb.get_cell_style_id_internal(DEFAULT_SHEET, "A1").unwrap();
+        let style = wb.engine.style_table().get(style_id).unwrap();
+        assert_eq!(style.number_format, None);
+        assert!(style.font.as_ref().is_some_and(|font| font.bold));
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "B1").unwrap(),
+            "General"
+        );
+    }
+
+    #[test]
+    fn set_number_format_uses_style_layers_for_whole_columns_rows_and_large_blocks() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        let limits = wb.engine.grid_limits();
+        let bold = wb.engine.intern_style(Style {
+            font: Some(Font {
+                bold: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        wb.set_cell_style_id_internal(DEFAULT_SHEET, "B5", bold)
+            .unwrap();
+        let row_style = wb.engine.intern_style(Style {
+            number_format: Some("0.0".to_string()),
+            ..Default::default()
+        });
+        wb.engine
+            .set_row_style_id(DEFAULT_SHEET, 2, Some(row_style));
+
+        // Column B: the column layer takes the format; the styled row and the bold cell are
+        // updated in place so they do not shadow it.
+        let col_b = Range::new(CellRef::new(0, 1), CellRef::new(limits.max_rows - 1, 1));
+        wb.set_number_format_internal(DEFAULT_SHEET, col_b, "0%")
+            .unwrap();
+        for address in ["B1", "B3", "B5", "B1048576"] {
+            assert_eq!(
+                wb.get_number_format_internal(DEFAULT_SHEET, address)
+                    .unwrap(),
+                "0%",
+                "{address}"
+            );
+        }
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "A3").unwrap(),
+            "0.0"
+        );
+        let style_id = wb.get_cell_style_id_internal(DEFAULT_SHEET, "B5").unwrap();
+        let style = wb.engine.style_table().get(style_id).unwrap();
+        assert!(style.font.as_ref().is_some_and(|font| font.bold));
+        assert_eq!(
+            wb.get_cell_style_id_internal(DEFAULT_SHEET, "B1000")
+                .unwrap(),
+            0
+        );
+
+        // Whole rows sit above the column layer.
+        let rows = Range::new(CellRef::new(9, 0), CellRef::new(10, limits.max_cols - 1));
+        wb.set_number_format_internal(DEFAULT_SHEET, rows, "#,##0")
+            .unwrap();
+        for address in ["A10", "B11", "XFD10"] {
+            assert_eq!(
+                wb.get_number_format_internal(DEFAULT_SHEET, address)
+                    .unwrap(),
+                "#,##0",
+                "{address}"
+            );
+        }
+
+        // A large block goes through per-column format runs.
+        let block = Range::from_a1("C1:D100000").unwrap();
+        wb.set_number_format_internal(DEFAULT_SHEET, block, "0.00")
+            .unwrap();
+        for address in ["C1", "D10", "D100000"] {
+            assert_eq!(
+                wb.get_number_format_internal(DEFAULT_SHEET, address)
+                    .unwrap(),
+                "0.00",
+                "{address}"
+            );
+        }
+        assert_eq!(
+            wb.get_cell_style_id_internal(DEFAULT_SHEET, "C500")
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "C100001")
+                .unwrap(),
+            "General"
+        );
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "E1").unwrap(),
+            "General"
+        );
+    }
+
+    #[test]
+    fn cell_display_text_matches_precision_as_displayed_storage() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        let mut settings = wb.engine.calc_settings().clone();
+        settings.full_precision = false;
+        wb.engine.set_calc_settings(settings);
+
+        wb.set_number_format_internal(DEFAULT_SHEET, Range::from_a1("A1").unwrap(), "0.0%")
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(0.12345))
+            .unwrap();
+        assert_eq!(
+            wb.get_cell_display_text_internal(DEFAULT_SHEET, "A1")
+                .unwrap(),
+            "12.3%"
+        );
+        let EngineValue::Number(stored) = wb.engine.get_cell_value(DEFAULT_SHEET, "A1") else {
+            panic!("expected a number in A1");
+        };
+        assert!((stored - 0.123).abs() < 1e-12, "{stored}");
+    }
+
+    #[test]
+    fn cell_display_text_localizes_error_codes_for_workbook_locale() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        assert!(wb.set_locale_id("de-DE"));
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!("=WENN(WAHR;#BEZUG!)"))
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "A2", json!("=TEXT(NV();\"0\")"))
+            .unwrap();
+        wb.recalculate_internal(None).unwrap();
+
+        assert_eq!(
+            wb.get_cell_display_text_internal(DEFAULT_SHEET, "A1")
+                .unwrap(),
+            "#BEZUG!"
+        );
+        assert_eq!(
+            wb.get_cell_display_text_internal(DEFAULT_SHEET, "A2")
+                .unwrap(),
+            "#NV"
+        );
+        // The stored value stays canonical.
+        assert_eq!(
+            wb.engine.get_cell_value(DEFAULT_SHEET, "A1"),
+            EngineValue::Error(ErrorKind::Ref)
+        );
+
+        assert!(wb.set_locale_id("en-US"));
+        assert_eq!(
+            wb.get_cell_display_text_internal(DEFAULT_SHEET, "A1")
+                .unwrap(),
+            "#REF!"
+        );
+    }
+
+    #[test]
+    fn rename_name_rewrites_formula_inputs_on_every_sheet() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.ensure_sheet("Sheet2");
+        wb.engine
+            .define_name(
+                "Rate",
+                NameScope::Workbook,
+                NameDefinition::Constant(EngineValue::Number(0.5)),
+            )
+            .unwrap();
+        wb.engine
+            .define_name(
+                "Other",
+                NameScope::Workbook,
+                NameDefinition::Constant(EngineValue::Number(1.0)),
+            )
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!("=Rate*2"))
+            .unwrap();
+        wb.set_cell_internal("Sheet2", "A1", json!("=Rate+Other"))
+            .unwrap();
+        wb.set_cell_internal("Sheet2", "A2", json!("=Other"))
+            .unwrap();
+
+        wb.rename_name_internal("Rate", "Discount", None).unwrap();
+        wb.recalculate_internal(None).unwrap();
+
+        let cell = wb.get_cell_data(DEFAULT_SHEET, "A1").unwrap();
+        assert_eq!(cell.input, json!("=Discount*2"));
+        assert_eq!(cell.value, json!(1.0));
+        let cell = wb.get_cell_data("Sheet2", "A1").unwrap();
+        assert_eq!(cell.input, json!("=Discount+Other"));
+        assert_eq!(cell.value, json!(1.5));
+        assert_eq!(
+            wb.get_cell_data("Sheet2", "A2").unwrap().input,
+            json!("=Other")
+        );
+    }
+
+    #[test]
+    fn set_range_number_format_applies_to_every_cell() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(0.25))
+            .unwrap();
+        wb.engine
+            .set_cell_number_format(DEFAULT_SHEET, "B2", Some("0.000".to_string()))
+            .unwrap();
+
+        let range = Range::from_a1("A1:B2").unwrap();
+        wb.set_number_format_internal(DEFAULT_SHEET, range, "0.0%")
+            .unwrap();
+        for address in ["A1", "A2", "B1", "B2"] {
+            assert_eq!(
+                wb.get_number_format_internal(DEFAULT_SHEET, address)
+                    .unwrap(),
+                "0.0%",
+                "{address}"
+            );
+        }
+        // Explicit per-cell overrides are dropped so they cannot shadow the new style.
+        assert_eq!(
+            wb.engine.cell_number_format(DEFAULT_SHEET, "B2").unwrap(),
+            None
+        );
+        assert_eq!(
+            wb.get_cell_style_id_internal(DEFAULT_SHEET, "A1").unwrap(),
+            wb.get_cell_style_id_internal(DEFAULT_SHEET, "B2").unwrap()
+        );
+        assert_eq!(
+            wb.get_number_format_internal(DEFAULT_SHEET, "C3").unwrap(),
+            "General"
+        );
+    }
+
+    #[test]
+    fn set_range_style_replaces_or_merges_cell_styles() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        let percent = wb.engine.intern_style(Style {
+            number_format: Some("0%".to_string()),
+            ..Default::default()
+        });
+        wb.set_cell_style_id_internal(DEFAULT_SHEET, "B2", percent)
+            .unwrap();
+        let bold = Style {
+            font: Some(Font {
+                bold: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let range = Range::from_a1("A1:B2").unwrap();
+        let touched = wb
+            .set_range_style_internal(DEFAULT_SHEET, range, bold.clone(), true)
+            .unwrap();
+        assert_eq!(touched, 4);
+        let a1 = wb.get_cell_style_id_internal(DEFAULT_SHEET, "A1").unwrap();
+        assert_eq!(wb.engine.style_table().get(a1), Some(&bold));
+        let b2 = wb.get_cell_style_id_internal(DEFAULT_SHEET, "B2").unwrap();
+        let merged = wb.engine.style_table().get(b2).unwrap();
+        assert!(merged.font.as_ref().is_some_and(|font| font.bold));
+        assert_eq!(merged.number_format.as_deref(), Some("0%"));
+
+        // Without `merge`, every cell ends up on the exact same style id.
+        wb.set_range_style_internal(DEFAULT_SHEET, range, bold, false)
+            .unwrap();
+        for address in ["A2", "B1", "B2"] {
+            assert_eq!(
+                wb.get_cell_style_id_internal(DEFAULT_SHEET, address)
+                    .unwrap(),
+                a1,
+                "{address}"
+            );
+        }
+        assert_eq!(
+            wb.get_cell_style_id_internal(DEFAULT_SHEET, "C3").unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn set_range_style_styles_whole_sheet_through_layers() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        let limits = wb.engine.grid_limits();
+        let italic = wb.engine.intern_style(Style {
+            font: Some(Font {
+                italic: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        wb.set_cell_style_id_internal(DEFAULT_SHEET, "B2", italic)
+            .unwrap();
+
+        let centered = Style {
+            alignment: Some(Alignment {
+                horizontal: Some(HorizontalAlignment::Center),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let sheet = Range::new(
+            CellRef::new(0, 0),
+            CellRef::new(limits.max_rows - 1, limits.max_cols - 1),
+        );
+        let touched = wb
+            .set_range_style_internal(DEFAULT_SHEET, sheet, centered.clone(), true)
+            .unwrap();
+        assert_eq!(touched, u32::MAX);
+
+        // Untouched cells pick the style up from the sheet default instead of a cell entry.
+        let layers = wb
+            .describe_style_resolution_internal(DEFAULT_SHEET, "Z999")
+            .unwrap();
+        assert_eq!(layers.len(), 1);
+        assert_eq!(layers[0].layer, "sheet");
+        assert_eq!(
+            wb.engine.style_table().get(layers[0].style_id),
+            Some(&centered)
+        );
+        assert_eq!(
+            wb.get_cell_style_id_internal(DEFAULT_SHEET, "Z999")
+                .unwrap(),
+            0
+        );
+
+        // Explicit cell styles are merged so they keep their own properties.
+        let b2 = wb.get_cell_style_id_internal(DEFAULT_SHEET, "B2").unwrap();
+        let merged = wb.engine.style_table().get(b2).unwrap();
+        assert!(merged.font.as_ref().is_some_and(|font| font.italic));
+        assert_eq!(merged.alignment, centered.alignment);
+    }
+
+    #[test]
+    fn describe_style_resolution_reports_layers_and_supplied_properties() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        let percent = wb.engine.intern_style(Style {
+            number_format: Some("0%".to_string()),
+            ..Default::default()
+        });
+        let bold_percent = wb.engine.intern_style(Style {
+            font: Some(Font {
+                bold: true,
+                ..Default::default()
+            }),
+            number_format: Some("0.0%".to_string()),
+            ..Default::default()
+        });
+        wb.engine.set_row_style_id(DEFAULT_SHEET, 0, Some(percent));
+        wb.set_cell_style_id_internal(DEFAULT_SHEET, "A1", bold_percent)
+            .unwrap();
+
+        let layers = wb
+            .describe_style_resolution_internal(DEFAULT_SHEET, "A1")
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&layers).unwrap(),
+            json!([
+                {
+                    "layer": "row",
+                    "styleId": percent,
+                    "properties": ["numberFormat"],
+                    "supplied": [],
+                },
+                {
+                    "layer": "cell",
+                    "styleId": bold_percent,
+                    "properties": ["font", "numberFormat"],
+                    "supplied": ["font", "numberFormat"],
+                },
+            ])
+        );
+        assert!(wb
+            .describe_style_resolution_internal(DEFAULT_SHEET, "B2")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn get_hyperlinks_lists_worksheet_and_formula_links_by_address() {
+        let mut model = formula_model::Workbook::new();
+        let sheet_id = model.add_sheet("Sheet1").unwrap();
+        model.add_sheet("Other Sheet").unwrap();
+        let sheet = model.sheet_mut(sheet_id).unwrap();
+        sheet.hyperlinks = vec![
+            Hyperlink {
+                tooltip: Some("Docs".to_string()),
+                ..Hyperlink::for_cell(
+                    CellRef::from_a1("B2").unwrap(),
+                    HyperlinkTarget::ExternalUrl {
+                        uri: "https://example.com".to_string(),
+                    },
+                )
+            },
+            Hyperlink {
+                range: Range::from_a1("A1:A2").unwrap(),
+                ..Hyperlink::for_cell(
+                    CellRef::from_a1("A1").unwrap(),
+                    HyperlinkTarget::Internal {
+                        sheet: "Other Sheet".to_string(),
+                        cell: CellRef::from_a1("C3").unwrap(),
+                    },
+                )
+            },
+        ];
+        sheet
+            .set_formula_a1(
+                "A2",
+                Some("HYPERLINK(\"https://ignored.example\")".to_string()),
+            )
+            .unwrap();
+        sheet
+            .set_formula_a1(
+                "C1",
+                Some("HYPERLINK(\"mailto:a@example.com\",\"Mail\")".to_string()),
+            )
+            .unwrap();
+        sheet
+            .set_formula_a1("A3", Some("HYPERLINK(\"#Sheet1!Z9\")".to_string()))
+            .unwrap();
+        sheet
+            .set_formula_a1("B3", Some("UPPER(\"x\")".to_string()))
+            .unwrap();
+
+        let json = serde_json::to_string(&model).unwrap();
+        let mut wb = WasmWorkbook::from_model_json(json).unwrap();
+        fn expected(
+            address: &str,
+            target: Option<&str>,
+            tooltip: Option<&str>,
+            location: Option<&str>,
+        ) -> HyperlinkDto {
+            HyperlinkDto {
+                address: address.to_string(),
+                target: target.map(str::to_string),
+                tooltip: tooltip.map(str::to_string),
+                location: location.map(str::to_string),
+            }
+        }
+        assert_eq!(
+            wb.inner.get_hyperlinks_internal(DEFAULT_SHEET).unwrap(),
+            vec![
+                expected("A1:A2", None, None, Some("'Other Sheet'!C3")),
+                expected("C1", Some("mailto:a@example.com"), None, None),
+                expected("B2", Some("https://example.com"), Some("Docs"), None),
+                expected("A3", None, None, Some("Sheet1!Z9")),
+            ]
+        );
+
+        // Renaming the destination sheet keeps in-workbook links pointing at it.
+        assert!(wb.inner.rename_sheet_internal("Other Sheet", "Data"));
+        assert_eq!(
+            wb.inner.get_hyperlinks_internal(DEFAULT_SHEET).unwrap()[0].location,
+            Some("Data!C3".to_string())
+        );
+        assert!(wb.inner.get_hyperlinks_internal("Data").unwrap().is_empty());
+    }
+
+    #[test]
+    fn get_cell_metadata_assembles_cell_details_and_omits_absent_fields() {
+        let mut model = formula_model::Workbook::new();
+        let sheet_id = model.add_sheet("Sheet1").unwrap();
+        model.sheet_mut(sheet_id).unwrap().hyperlinks = vec![Hyperlink {
+            tooltip: Some("Docs".to_string()),
+            ..Hyperlink::for_cell(
+                CellRef::from_a1("C1").unwrap(),
+                HyperlinkTarget::ExternalUrl {
+                    uri: "https://example.com".to_string(),
+                },
+            )
+        }];
+        let json = serde_json::to_string(&model).unwrap();
+        let mut wb = WasmWorkbook::from_model_json(json).unwrap();
+        let state = &mut wb.inner;
+        state
+            .set_cell_internal(DEFAULT_SHEET, "A1", json!("=SEQUENCE(3)"))
+            .unwrap();
+        state
+            .set_cell_internal(DEFAULT_SHEET, "C1", json!("東京"))
+            .unwrap();
+        state
+            .engine
+            .set_cell_phonetic(DEFAULT_SHEET, "C1", Some("トウキョウ".to_string()))
+            .unwrap();
+        state
+            .set_number_format_internal(DEFAULT_SHEET, Range::from_a1("A1").unwrap(), "0.00")
+            .unwrap();
+        state.recalculate_internal(None).unwrap();
+
+        let origin = state
+            .get_cell_metadata_internal(DEFAULT_SHEET, "a1")
+            .unwrap();
+        assert_eq!(origin.input, json!("=SEQUENCE(3)"));
+        assert_eq!(origin.value, json!(1.0));
+        assert_eq!(origin.value_type, "number");
+        assert_eq!(origin.formula.as_deref(), Some("=SEQUENCE(3)"));
+        assert_ne!(origin.style_id, 0);
+        assert_eq!(origin.number_format, "0.00");
+        assert!(origin.is_spill_origin);
+        assert_eq!(origin.spill_range.as_deref(), Some("A1:A3"));
+
+        let spilled = state
+            .get_cell_metadata_internal(DEFAULT_SHEET, "A2")
+            .unwrap();
+        assert_eq!(spilled.value, json!(2.0));
+        assert_eq!(spilled.formula, None);
+        // Spilled outputs display with the origin's number format.
+        assert_eq!(spilled.style_id, 0);
+        assert_eq!(spilled.number_format, "0.00");
+        assert!(!spilled.is_spill_origin);
+        assert_eq!(spilled.spill_range.as_deref(), Some("A1:A3"));
+
+        let text = state
+            .get_cell_metadata_internal(DEFAULT_SHEET, "C1")
+            .unwrap();
+        assert_eq!(text.value_type, "text");
+        assert_eq!(text.phonetic.as_deref(), Some("トウキョウ"));
+        assert_eq!(
+            text.hyperlink.as_ref().map(|link| link.target.as_deref()),
+            Some(Some("https://example.com"))
+        );
+
+        // Absent optional fields are omitted from the serialized object entirely.
+        let blank = state
+            .get_cell_metadata_internal(DEFAULT_SHEET, "D9")
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&blank).unwrap(),
+            json!({
+                "input": null,
+                "value": null,
+                "valueType": "blank",
+                "styleId": 0,
+                "numberFormat": "General",
+                "isSpillOrigin": false,
+            })
+        );
+    }
+
+    #[test]
+    fn spill_helpers_report_sequence_extent_origin_and_outputs() {
+        let mut wb = WasmWorkbook::new();
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "B2", json!("=SEQUENCE(3,2)"))
+            .unwrap();
+        wb.inner.recalculate_internal(None).unwrap();
+
+        let spill_range =
+            |wb: &WasmWorkbook, addr: &str| wb.get_spill_range(addr.to_string(), None).unwrap();
+        let spill_origin =
+            |wb: &WasmWorkbook, addr: &str| wb.get_spill_origin(addr.to_string(), None).unwrap();
+
+        for addr in ["B2", "c2", "B4", "C4"] {
+            assert_eq!(spill_range(&wb, addr).as_deref(), Some("B2:C4"), "{addr}");
+            assert_eq!(spill_origin(&wb, addr).as_deref(), Some("B2"), "{addr}");
+        }
+        assert!(wb.is_spill_origin("B2".to_string(), None).unwrap());
+        assert!(!wb.is_spill_output("B2".to_string(), None).unwrap());
+        assert!(!wb.is_spill_origin("C3".to_string(), None).unwrap());
+        assert!(wb
+            .is_spill_output("C3".to_string(), Some(DEFAULT_SHEET.to_string()))
+            .unwrap());
+
+        // Cells outside the spill report nothing.
+        for addr in ["A1", "D2", "B5"] {
+            assert_eq!(spill_range(&wb, addr), None, "{addr}");
+            assert_eq!(spill_origin(&wb, addr), None, "{addr}");
+            assert!(!wb.is_spill_origin(addr.to_string(), None).unwrap());
+            assert!(!wb.is_spill_output(addr.to_string(), None).unwrap());
+        }
+
+        // The helpers track the spill's current extent after it shrinks.
+        wb.inner
+            .set_cell_internal(DEFAULT_SHEET, "B2", json!("=SEQUENCE(2)"))
+            .unwrap();
+        wb.inner.recalculate_internal(None).unwrap();
+        assert_eq!(spill_range(&wb, "B3").as_deref(), Some("B2:B3"));
+        assert_eq!(spill_range(&wb, "C4"), None);
+        assert!(!wb.is_spill_output("C2".to_string(), None).unwrap());
+    }
+
+    #[test]
+    fn writing_into_spill_output_marks_origin_spill_error_until_unblocked() {
+        let mut wb = WasmWorkbook::new();
+        let state = &mut wb.inner;
+        state
+            .set_cell_internal(DEFAULT_SHEET, "A1", json!("=SEQUENCE(5)"))
+            .unwrap();
+        state.recalculate_internal(None).unwrap();
+        assert_eq!(
+            state.get_cell_data(DEFAULT_SHEET, "A3").unwrap().value,
+            json!(3.0)
+        );
+
+        // Overwriting a non-origin output blocks the spill immediately, before any recalc.
+        state
+            .set_cell_internal(DEFAULT_SHEET, "A3", json!("x"))
+            .unwrap();
+        let value = |state: &WorkbookState, addr: &str| {
+            state.get_cell_data(DEFAULT_SHEET, addr).unwrap().value
+        };
+        assert_eq!(value(state, "A1"), json!("#SPILL!"));
+        assert_eq!(value(state, "A2"), JsonValue::Null);
+        assert_eq!(value(state, "A3"), json!("x"));
+
+        // Recalc confirms the blockage and reports the origin and the cleared outputs.
+        let changes = state.recalculate_internal(None).unwrap();
+        let changed = |changes: &[CellChange]| -> Vec<(String, JsonValue)> {
+            changes
+                .iter()
+                .map(|change| (change.address.clone(), change.value.clone()))
+                .collect()
+        };
+        assert_eq!(
+            changed(&changes),
+            vec![
+                ("A1".to_string(), json!("#SPILL!")),
+                ("A2".to_string(), JsonValue::Null),
+                ("A4".to_string(), JsonValue::Null),
+                ("A5".to_string(), JsonValue::Null),
+            ]
+        );
+        assert_eq!(value(state, "A1"), json!("#SPILL!"));
+        assert_eq!(state.engine.spill_range(DEFAULT_SHEET, "A1"), None);
+
+        // Clearing the blocker lets the origin spill again on the next recalc.
+        state
+            .set_cell_internal(DEFAULT_SHEET, "A3", JsonValue::Null)
+            .unwrap();
+        let changes = state.recalculate_internal(None).unwrap();
+        assert_eq!(
+            changed(&changes),
+            (1..=5)
+                .map(|n| (format!("A{n}"), json!(n as f64)))
+                .collect::<Vec<_>>()
+        );
+
+        // Clearing an output cell (null) or rewriting the origin does not block the spill.
+        state
+            .set_cell_internal(DEFAULT_SHEET, "A4", JsonValue::Null)
+            .unwrap();
+        assert_eq!(value(state, "A1"), json!(1.0));
+        state.recalculate_internal(None).unwrap();
+        assert_eq!(value(state, "A4"), json!(4.0));
+
+        // A formula typed into an output cell blocks the spill as well.
+        state
+            .set_cell_internal(DEFAULT_SHEET, "A5", json!("=1+1"))
+            .unwrap();
+        assert_eq!(value(state, "A1"), json!("#SPILL!"));
+        state.recalculate_internal(None).unwrap();
+        assert_eq!(value(state, "A1"), json!("#SPILL!"));
+        assert_eq!(value(state, "A5"), json!(2.0));
+    }
+
+    #[test]
+    fn style_json_to_model_style_accepts_ui_camel_case_number_format() {
+        let style = style_json_to_model_style(&json!({ "numberFormat": "0.00" }));
+        assert_eq!(style.number_format.as_deref(), Some("0.00"));
+    }
+
+    #[test]
+    fn style_json_to_model_style_treats_null_number_format_as_explicit_general_override() {
+        let style = style_json_to_model_style(&json!({ "numberFormat": null }));
+        assert_eq!(style.number_format.as_deref(), Some("General"));
+    }
+
+    #[test]
+    fn style_json_to_model_style_prefers_null_number_format_over_imported_snake_case() {
+        let style = style_json_to_model_style(&json!({
+            "number_format": "0.00",
+            "numberFormat": null,
+        }));
         assert_eq!(style.number_format.as_deref(), Some("General"));
     }
 
@@ -10400,4 +14115,454 @@ mod tests {
             EngineValue::Number(1.0)
         );
     }
+
+    #[test]
+    fn validate_formula_reports_utf16_spans_without_mutating_the_workbook() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(1.0))
+            .unwrap();
+
+        let ok = wb.validate_formula_internal(DEFAULT_SHEET, "=SUM(A1:A3)");
+        assert!(ok.ok);
+        assert_eq!((ok.error, ok.span.is_none()), (None, true));
+
+        // The emoji is 4 UTF-8 bytes but 2 UTF-16 code units.
+        let err = wb.validate_formula_internal(DEFAULT_SHEET, "=\"😀\"&SUMM(A1)");
+        assert!(!err.ok);
+        assert_eq!(err.error.as_deref(), Some("Unknown function: SUMM"));
+        let span = err.span.expect("span");
+        assert_eq!((span.start, span.end), (6, 10));
+
+        let err = wb.validate_formula_internal("Other", "=SUM(1,");
+        assert!(!err.ok);
+        assert!(wb.resolve_sheet("Other").is_none());
+        assert_eq!(wb.engine.get_cell_formula(DEFAULT_SHEET, "A1"), None);
+
+        assert!(wb.set_locale_id("de-DE"));
+        assert!(
+            wb.validate_formula_internal(DEFAULT_SHEET, "=SUMME(A1;1,5)")
+                .ok
+        );
+        let err = wb.validate_formula_internal(DEFAULT_SHEET, "=SUMME(A1;Rabatt)");
+        assert_eq!(err.error.as_deref(), Some("Unknown name: Rabatt"));
+        let span = err.span.expect("span");
+        assert_eq!((span.start, span.end), (10, 16));
+    }
+
+    #[test]
+    fn analyze_formula_lists_references_without_mutating_the_workbook() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.ensure_sheet("Data");
+
+        let refs = wb
+            .analyze_formula_internal(DEFAULT_SHEET, "=SUM($A$1:B2, Data!C3) + Rate", None)
+            .unwrap();
+        assert_eq!(refs.ranges, vec!["A1:B2", "Data!C3"]);
+        assert_eq!(refs.names, vec!["Rate"]);
+        assert!(refs.tables.is_empty());
+        assert_eq!(wb.engine.get_cell_formula(DEFAULT_SHEET, "A1"), None);
+
+        assert!(wb.set_locale_id("de-DE"));
+        let refs = wb
+            .analyze_formula_internal(DEFAULT_SHEET, "=SUMME(A1;B2:C3)", Some("D4"))
+            .unwrap();
+        assert_eq!(refs.ranges, vec!["A1", "B2:C3"]);
+    }
+
+    #[test]
+    fn lint_formula_reports_kinds_and_utf16_spans() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(1.0))
+            .unwrap();
+
+        let lints = wb
+            .lint_formula_internal(DEFAULT_SHEET, "=\"é\"&NOW()+B1*A1", None)
+            .unwrap();
+        let summary: Vec<_> = lints
+            .iter()
+            .map(|lint| (lint.kind, lint.severity, lint.span.start, lint.span.end))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("volatileFunction", "info", 5, 8),
+                ("blankCellInArithmetic", "warning", 11, 13),
+            ]
+        );
+    }
+
+    #[test]
+    fn sheet_protection_rejects_locked_cells_and_round_trips_through_json() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(1.0))
+            .unwrap();
+        let unlocked = wb.engine.intern_style(Style {
+            protection: Some(Protection {
+                locked: false,
+                hidden: false,
+            }),
+            ..Style::default()
+        });
+        wb.engine
+            .set_cell_style_id(DEFAULT_SHEET, "B1", unlocked)
+            .unwrap();
+        assert!(!wb.is_sheet_protected_internal(DEFAULT_SHEET).unwrap());
+        assert!(wb.check_cell_editable(DEFAULT_SHEET, "A1").is_ok());
+
+        let protection = SheetProtectionOptionsDto {
+            format_cells: Some(true),
+            ..SheetProtectionOptionsDto::default()
+        }
+        .to_protection();
+        wb.set_sheet_protection_internal(DEFAULT_SHEET, Some(protection))
+            .unwrap();
+        assert!(wb.is_sheet_protected_internal(DEFAULT_SHEET).unwrap());
+        assert_eq!(
+            wb.engine.sheet_protection_enabled(DEFAULT_SHEET),
+            Some(true)
+        );
+
+        // Cells are locked unless their style says otherwise, including never-written cells.
+        let err = wb.check_cell_editable(DEFAULT_SHEET, "A1").unwrap_err();
+        assert_eq!(err, "cell Sheet1!A1 is locked on a protected sheet");
+        assert!(wb.check_cell_editable(DEFAULT_SHEET, "Z99").is_err());
+        assert!(wb.check_cell_editable(DEFAULT_SHEET, "B1").is_ok());
+        wb.set_cell_internal(DEFAULT_SHEET, "B1", json!("edited"))
+            .unwrap();
+
+        let json = WasmWorkbook { inner: wb }.to_json().unwrap();
+        let parsed: JsonValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed["sheets"][DEFAULT_SHEET]["protection"],
+            json!({ "formatCells": true })
+        );
+        let mut wb = WasmWorkbook::from_json(&json).unwrap().inner;
+        assert!(wb.is_sheet_protected_internal(DEFAULT_SHEET).unwrap());
+        assert!(wb.check_cell_editable(DEFAULT_SHEET, "A1").is_err());
+
+        wb.set_sheet_protection_internal(DEFAULT_SHEET, None)
+            .unwrap();
+        assert!(!wb.is_sheet_protected_internal(DEFAULT_SHEET).unwrap());
+        assert_eq!(
+            wb.engine.sheet_protection_enabled(DEFAULT_SHEET),
+            Some(false)
+        );
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(2.0))
+            .unwrap();
+    }
+
+    #[test]
+    fn sheet_protection_guards_operations_and_formatting() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        let sheet = DEFAULT_SHEET.to_string();
+        let range = Range::from_a1("A1:B3").unwrap();
+        let sort = EngineEditOp::SortRange {
+            sheet: sheet.clone(),
+            range,
+            keys: Vec::new(),
+            has_header: false,
+        };
+        let insert_rows = EngineEditOp::InsertRows {
+            sheet: sheet.clone(),
+            row: 0,
+            count: 1,
+        };
+        let text_to_columns = EngineEditOp::TextToColumns {
+            sheet: sheet.clone(),
+            src: range,
+            split: TextToColumnsSplit::Delimiter(','),
+            shift_right: false,
+        };
+        assert!(wb.check_edit_op_allowed(&sort).is_ok());
+        assert!(wb.check_format_cells_allowed(DEFAULT_SHEET).is_ok());
+
+        let protection: SheetProtectionOptionsDto =
+            serde_json::from_value(json!({ "password": "secret" })).unwrap();
+        let protection = protection.to_protection();
+        assert_eq!(
+            protection.password_hash,
+            Some(formula_model::hash_legacy_password("secret"))
+        );
+        wb.set_sheet_protection_internal(DEFAULT_SHEET, Some(protection))
+            .unwrap();
+        assert_eq!(
+            wb.check_edit_op_allowed(&sort).unwrap_err(),
+            "sorting is not allowed on protected sheet Sheet1"
+        );
+        assert!(wb.check_edit_op_allowed(&insert_rows).is_err());
+        assert!(wb.check_edit_op_allowed(&text_to_columns).is_err());
+        assert_eq!(
+            wb.check_format_cells_allowed(DEFAULT_SHEET).unwrap_err(),
+            "formatting cells is not allowed on protected sheet Sheet1"
+        );
+
+        // Allowed actions still need the cells they rewrite to be unlocked.
+        let protection = SheetProtectionOptionsDto {
+            sort: Some(true),
+            insert_rows: Some(true),
+            format_cells: Some(true),
+            ..SheetProtectionOptionsDto::default()
+        }
+        .to_protection();
+        wb.set_sheet_protection_internal(DEFAULT_SHEET, Some(protection))
+            .unwrap();
+        assert!(wb.check_edit_op_allowed(&insert_rows).is_ok());
+        assert!(wb.check_format_cells_allowed(DEFAULT_SHEET).is_ok());
+        assert_eq!(
+            wb.check_edit_op_allowed(&sort).unwrap_err(),
+            "cell Sheet1!A1 is locked on a protected sheet"
+        );
+        wb.engine
+            .update_range_style(DEFAULT_SHEET, range, |_| Style {
+                protection: Some(Protection {
+                    locked: false,
+                    hidden: false,
+                }),
+                ..Style::default()
+            })
+            .unwrap();
+        assert!(wb.check_edit_op_allowed(&sort).is_ok());
+    }
+
+    #[test]
+    fn diff_reports_changed_cells_spills_and_sheet_changes() {
+        let mut before = WorkbookState::new_with_default_sheet();
+        before
+            .set_cell_internal(DEFAULT_SHEET, "A1", json!(1.0))
+            .unwrap();
+        before
+            .set_cell_internal(DEFAULT_SHEET, "A2", json!("same"))
+            .unwrap();
+        before
+            .set_cell_internal(DEFAULT_SHEET, "B1", json!("=SEQUENCE(2)"))
+            .unwrap();
+        before
+            .set_cell_internal(DEFAULT_SHEET, "C1", json!(5.0))
+            .unwrap();
+        before.ensure_sheet("Old");
+        before.recalculate_internal(None).unwrap();
+
+        let mut after = WorkbookState::new_with_default_sheet();
+        after
+            .set_cell_internal(DEFAULT_SHEET, "A1", json!(2.0))
+            .unwrap();
+        after
+            .set_cell_internal(DEFAULT_SHEET, "A2", json!("same"))
+            .unwrap();
+        after
+            .set_cell_internal(DEFAULT_SHEET, "B1", json!("=SEQUENCE(3)"))
+            .unwrap();
+        after.ensure_sheet("New");
+        after.recalculate_internal(None).unwrap();
+
+        let diff = before.diff_internal(&after, None);
+        assert_eq!(diff.added_sheets, vec!["New".to_string()]);
+        assert_eq!(diff.removed_sheets, vec!["Old".to_string()]);
+        assert!(!diff.truncated);
+
+        let cells: Vec<_> = diff
+            .cells
+            .iter()
+            .map(|cell| {
+                (
+                    cell.address.as_str(),
+                    &cell.before.input,
+                    &cell.before.value,
+                    &cell.after.input,
+                    &cell.after.value,
+                )
+            })
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                ("A1", &json!(1.0), &json!(1.0), &json!(2.0), &json!(2.0)),
+                (
+                    "B1",
+                    &json!("=SEQUENCE(2)"),
+                    &json!(1.0),
+                    &json!("=SEQUENCE(3)"),
+                    &json!(1.0),
+                ),
+                (
+                    "C1",
+                    &json!(5.0),
+                    &json!(5.0),
+                    &JsonValue::Null,
+                    &JsonValue::Null
+                ),
+                // Spill output with no input on either side.
+                (
+                    "B3",
+                    &JsonValue::Null,
+                    &JsonValue::Null,
+                    &JsonValue::Null,
+                    &json!(3.0)
+                ),
+            ]
+        );
+
+        let capped = before.diff_internal(&after, Some(2));
+        assert_eq!(capped.cells.len(), 2);
+        assert!(capped.truncated);
+        assert!(!before.diff_internal(&after, Some(4)).truncated);
+        assert!(before.diff_internal(&before, None).cells.is_empty());
+    }
+
+    #[test]
+    fn from_workbook_model_imports_sheet_protection() {
+        let mut model = formula_model::Workbook::new();
+        let sheet_id = model.add_sheet("Locked").unwrap();
+        let sheet = model.sheet_mut(sheet_id).unwrap();
+        sheet.sheet_protection = SheetProtection {
+            enabled: true,
+            sort: true,
+            password_hash: Some(formula_model::hash_legacy_password("secret")),
+            ..SheetProtection::default()
+        };
+
+        let wb = WasmWorkbook::from_workbook_model(model).unwrap().inner;
+        assert!(wb.is_sheet_protected_internal("Locked").unwrap());
+        assert!(wb.check_cell_editable("Locked", "A1").is_err());
+        let stored = &wb.sheet_protection["Locked"];
+        assert!(stored.sort);
+        assert_eq!(
+            stored.password_hash,
+            Some(formula_model::hash_legacy_password("secret"))
+        );
+    }
+
+    #[test]
+    fn print_area_round_trips_through_xlsx_and_can_be_updated() {
+        use std::io::Cursor;
+
+        let mut workbook = formula_model::Workbook::new();
+        let sheet_id = workbook.add_sheet("Report").unwrap();
+        assert!(
+            workbook.set_sheet_print_area(sheet_id, Some(vec![Range::from_a1("A1:D20").unwrap()]))
+        );
+        let margins = formula_model::PageMargins {
+            left: 0.25,
+            right: 0.5,
+            ..formula_model::PageMargins::default()
+        };
+        assert!(workbook.set_sheet_page_setup(
+            sheet_id,
+            formula_model::PageSetup {
+                margins,
+                ..formula_model::PageSetup::default()
+            },
+        ));
+
+        let mut cursor = Cursor::new(Vec::new());
+        formula_xlsx::write_workbook_to_writer(&workbook, &mut cursor).unwrap();
+        let bytes = cursor.into_inner();
+
+        let model = formula_xlsx::read_workbook_model_from_bytes(&bytes).unwrap();
+        assert_eq!(
+            model.sheet_print_settings(sheet_id).page_setup.margins,
+            margins
+        );
+
+        let mut wb = WasmWorkbook::from_xlsx_bytes(&bytes).unwrap().inner;
+        assert_eq!(
+            wb.get_print_area_internal("report").unwrap().as_deref(),
+            Some("A1:D20")
+        );
+
+        wb.set_print_area_internal("Report", Some("$B$2:C3, E5"))
+            .unwrap();
+        assert_eq!(
+            wb.get_print_area_internal("Report").unwrap().as_deref(),
+            Some("B2:C3,E5")
+        );
+        assert_eq!(
+            wb.engine
+                .get_name(formula_model::XLNM_PRINT_AREA, NameScope::Sheet("Report")),
+            Some(&NameDefinition::Reference(
+                "Report!$B$2:$C$3,Report!$E$5".to_string()
+            ))
+        );
+
+        wb.set_print_area_internal("Report", None).unwrap();
+        assert_eq!(wb.get_print_area_internal("Report").unwrap(), None);
+    }
+
+    #[test]
+    fn print_titles_are_imported_from_xlsx() {
+        use std::io::Cursor;
+
+        let titles = formula_model::PrintTitles {
+            repeat_rows: Some(formula_model::RowRange { start: 0, end: 1 }),
+            repeat_cols: Some(formula_model::ColRange { start: 0, end: 0 }),
+        };
+        let mut workbook = formula_model::Workbook::new();
+        let sheet_id = workbook.add_sheet("Q1 Sales").unwrap();
+        assert!(workbook.set_sheet_print_titles(sheet_id, Some(titles)));
+
+        let mut cursor = Cursor::new(Vec::new());
+        formula_xlsx::write_workbook_to_writer(&workbook, &mut cursor).unwrap();
+        let bytes = cursor.into_inner();
+
+        let wb = WasmWorkbook::from_xlsx_bytes(&bytes).unwrap().inner;
+        assert_eq!(wb.engine.print_titles("Q1 Sales"), Some(titles));
+        assert_eq!(wb.get_print_area_internal("Q1 Sales").unwrap(), None);
+    }
+
+    #[test]
+    fn used_range_includes_spilled_cells_beyond_the_input_map() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        assert!(wb.get_used_range_internal(DEFAULT_SHEET).unwrap().is_none());
+
+        wb.set_cell_internal(DEFAULT_SHEET, "B2", json!(5.0))
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "C2", json!("=SEQUENCE(4,3)"))
+            .unwrap();
+        wb.recalculate_internal(None).unwrap();
+
+        // The input map only covers B2:C2; the spill reaches E5.
+        let used = wb
+            .get_used_range_internal(DEFAULT_SHEET)
+            .unwrap()
+            .expect("used range");
+        assert_eq!(
+            (used.start_row, used.start_col, used.end_row, used.end_col),
+            (1, 1, 4, 4)
+        );
+    }
+
+    #[test]
+    fn get_cells_data_reads_non_contiguous_cells_in_order() {
+        let mut wb = WorkbookState::new_with_default_sheet();
+        wb.set_cell_internal(DEFAULT_SHEET, "A1", json!(1.0))
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "C3", json!("=A1*2"))
+            .unwrap();
+        wb.set_cell_internal(DEFAULT_SHEET, "E1", json!("=SEQUENCE(3)"))
+            .unwrap();
+        wb.recalculate_internal(None).unwrap();
+
+        let addresses = ["C3", "a1", "Z9", "E3"].map(String::from);
+        let cells = wb.get_cells_data(DEFAULT_SHEET, &addresses).unwrap();
+        let summary: Vec<_> = cells
+            .iter()
+            .map(|cell| {
+                (
+                    cell.address.as_str(),
+                    cell.input.clone(),
+                    cell.value.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("C3", json!("=A1*2"), json!(2.0)),
+                ("A1", json!(1.0), json!(1.0)),
+                ("Z9", JsonValue::Null, JsonValue::Null),
+                // Spilled cells have a value but no input.
+                ("E3", JsonValue::Null, json!(3.0)),
+            ]
+        );
+    }
 }
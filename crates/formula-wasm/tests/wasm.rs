@@ -3246,3 +3246,95 @@ fn cell_width_reflects_set_col_width_chars() {
         "expected cleared width to revert to default; got {cleared_width}"
     );
 }
+
+#[wasm_bindgen_test]
+fn set_row_height_roundtrips_and_rejects_invalid_heights() {
+    let mut wb = WasmWorkbook::new();
+    assert_eq!(
+        wb.get_row_height(DEFAULT_SHEET.to_string(), 1).unwrap(),
+        None
+    );
+
+    wb.set_row_height(DEFAULT_SHEET.to_string(), 1, JsValue::from_f64(24.5))
+        .unwrap();
+    assert_eq!(
+        wb.get_row_height(DEFAULT_SHEET.to_string(), 1).unwrap(),
+        Some(24.5)
+    );
+
+    assert!(wb
+        .set_row_height(DEFAULT_SHEET.to_string(), 1, JsValue::from_f64(-1.0))
+        .is_err());
+    assert!(wb
+        .set_row_height(DEFAULT_SHEET.to_string(), 1, JsValue::from_str("tall"))
+        .is_err());
+
+    wb.set_row_height(DEFAULT_SHEET.to_string(), 1, JsValue::NULL)
+        .unwrap();
+    assert_eq!(
+        wb.get_row_height(DEFAULT_SHEET.to_string(), 1).unwrap(),
+        None
+    );
+}
+
+#[wasm_bindgen_test]
+fn add_table_rejects_overlap_and_auto_expands_on_set_cell() {
+    let mut wb = WasmWorkbook::new();
+    wb.set_cell("A1".to_string(), JsValue::from_str("Qty"), None)
+        .unwrap();
+    wb.set_cell("A2".to_string(), JsValue::from_f64(1.0), None)
+        .unwrap();
+    wb.add_table(
+        DEFAULT_SHEET.to_string(),
+        "A1:A2".to_string(),
+        "T".to_string(),
+        JsValue::UNDEFINED,
+    )
+    .unwrap();
+
+    assert!(wb
+        .add_table(
+            DEFAULT_SHEET.to_string(),
+            "A2:B3".to_string(),
+            "Other".to_string(),
+            JsValue::UNDEFINED,
+        )
+        .is_err());
+
+    wb.set_cell("A3".to_string(), JsValue::from_f64(2.0), None)
+        .unwrap();
+    let tables: JsonValue =
+        serde_wasm_bindgen::from_value(wb.get_sheet_tables(DEFAULT_SHEET.to_string()).unwrap())
+            .unwrap();
+    assert_eq!(tables[0]["range"], json!("A1:A3"));
+    assert_eq!(tables[0]["columns"][0]["name"], json!("Qty"));
+    assert_eq!(tables[0]["columns"][0]["hasTotalsFormula"], json!(false));
+
+    wb.delete_table("T".to_string()).unwrap();
+    assert!(wb
+        .resize_table("T".to_string(), "A1:A4".to_string())
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn get_range_values_only_returns_plain_value_rows() {
+    let mut wb = WasmWorkbook::new();
+    wb.set_cell("A1".to_string(), JsValue::from_f64(2.0), None)
+        .unwrap();
+    wb.set_cell("B1".to_string(), JsValue::from_str("=A1*3"), None)
+        .unwrap();
+    wb.recalculate(None).unwrap();
+
+    let full: JsonValue =
+        serde_wasm_bindgen::from_value(wb.get_range("A1:B1".to_string(), None, None).unwrap())
+            .unwrap();
+    assert_eq!(full[0][1]["address"], json!("B1"));
+    assert_eq!(full[0][1]["input"], json!("=A1*3"));
+    assert_eq!(full[0][1]["value"], json!(6.0));
+
+    let values: JsonValue = serde_wasm_bindgen::from_value(
+        wb.get_range("A1:B2".to_string(), None, Some(true)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(values, json!([[2.0, 6.0], [null, null]]));
+}
@@ -1001,7 +1001,8 @@ fn rgce_contains_ptgarray(rgce_bytes: &[u8]) -> bool {
 
                 const T_ATTR_CHOOSE: u8 = 0x04;
                 if grbit & T_ATTR_CHOOSE != 0 {
-                    let needed = w_attr * 2;
+                    // Jump table: `wAttr + 1` u16 offsets.
+                    let needed = (w_attr + 1) * 2;
                     if !advance(&mut i, needed) {
                         return false;
                     }
@@ -1154,7 +1155,8 @@ fn rgce_contains_area3d_relative_flags(rgce_bytes: &[u8]) -> bool {
 
                 const T_ATTR_CHOOSE: u8 = 0x04;
                 if grbit & T_ATTR_CHOOSE != 0 {
-                    let needed = w_attr * 2;
+                    // Jump table: `wAttr + 1` u16 offsets.
+                    let needed = (w_attr + 1) * 2;
                     if !advance(&mut i, needed) {
                         return false;
                     }
@@ -1474,7 +1476,8 @@ pub(crate) fn materialize_biff8_rgce(
 
                 const T_ATTR_CHOOSE: u8 = 0x04;
                 if grbit & T_ATTR_CHOOSE != 0 {
-                    let needed = (w_attr as usize).checked_mul(2)?;
+                    // Jump table: `wAttr + 1` u16 offsets.
+                    let needed = (w_attr as usize + 1).checked_mul(2)?;
                     let end = i.checked_add(needed)?;
                     out.extend_from_slice(base.get(i..end)?);
                     i = end;
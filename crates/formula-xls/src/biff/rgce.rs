@@ -299,7 +299,8 @@ fn namex_is_udf_call(remaining: &[u8]) -> bool {
                 input = &input[4..];
 
                 if grbit & T_ATTR_CHOOSE != 0 {
-                    let needed = (w_attr as usize) * 2;
+                    // Jump table: `wAttr + 1` u16 offsets.
+                    let needed = (w_attr as usize + 1) * 2;
                     if input.len() < needed {
                         return false;
                     }
@@ -725,8 +726,8 @@ fn decode_biff8_rgce_with_base_and_rgcb_opt(
                 const T_ATTR_SUM: u8 = 0x10;
 
                 if grbit & T_ATTR_CHOOSE != 0 {
-                    // tAttrChoose is followed by a jump table of u16 offsets; consume it.
-                    let needed = (w_attr as usize) * 2;
+                    // tAttrChoose is followed by a jump table of `wAttr + 1` u16 offsets; consume it.
+                    let needed = (w_attr as usize + 1) * 2;
                     if input.len() < needed {
                         push_warning(
                             &mut warnings,
@@ -1959,7 +1960,8 @@ fn consume_rgcb_arrays_in_subexpression(
                         .ok_or_else(|| "rgce offset overflow".to_string())?;
                     const T_ATTR_CHOOSE: u8 = 0x04;
                     if grbit & T_ATTR_CHOOSE != 0 {
-                        let needed = (w_attr as usize)
+                        // Jump table: `wAttr + 1` u16 offsets.
+                        let needed = (w_attr as usize + 1)
                             .checked_mul(2)
                             .ok_or_else(|| "PtgAttr jump table length overflow".to_string())?;
                         let end = i
@@ -2376,7 +2378,8 @@ pub(crate) fn analyze_biff8_shared_formula_rgce(
                         .ok_or_else(|| "rgce offset overflow".to_string())?;
                     const T_ATTR_CHOOSE: u8 = 0x04;
                     if grbit & T_ATTR_CHOOSE != 0 {
-                        let needed = (w_attr as usize)
+                        // Jump table: `wAttr + 1` u16 offsets.
+                        let needed = (w_attr as usize + 1)
                             .checked_mul(2)
                             .ok_or_else(|| "PtgAttr jump table length overflow".to_string())?;
                         let end = i
@@ -2661,7 +2664,8 @@ pub(crate) fn materialize_biff8_shared_formula_rgce(
 
                     const T_ATTR_CHOOSE: u8 = 0x04;
                     if grbit & T_ATTR_CHOOSE != 0 {
-                        let needed = (w_attr as usize)
+                        // Jump table: `wAttr + 1` u16 offsets.
+                        let needed = (w_attr as usize + 1)
                             .checked_mul(2)
                             .ok_or_else(|| "PtgAttr jump table length overflow".to_string())?;
                         let end = i
@@ -6765,6 +6769,44 @@ mod tests {
         assert_parseable(&decoded.text);
     }
 
+    #[test]
+    fn decodes_choose_and_nested_if_with_attr_control_tokens() {
+        let sheet_names: Vec<String> = Vec::new();
+        let externsheet: Vec<ExternSheetEntry> = Vec::new();
+        let defined_names: Vec<DefinedNameMeta> = Vec::new();
+        let ctx = empty_ctx(&sheet_names, &externsheet, &defined_names);
+
+        // CHOOSE(2,10,IF(TRUE,20,30))+1 as Excel writes it, with the tAttrChoose jump table
+        // (wAttr + 1 entries) and tAttrIf/tAttrSkip tokens around each branch.
+        let rgce = vec![
+            0x1E, 0x02, 0x00, // 2
+            0x19, 0x04, 0x02, 0x00, // PtgAttr(tAttrChoose, wAttr=2)
+            0x06, 0x00, 0x0D, 0x00, 0x28, 0x00, // jump table (3 entries)
+            0x1E, 0x0A, 0x00, // 10
+            0x19, 0x08, 0x1F, 0x00, // PtgAttr(tAttrSkip)
+            0x1D, 0x01, // TRUE
+            0x19, 0x02, 0x07, 0x00, // PtgAttr(tAttrIf)
+            0x1E, 0x14, 0x00, // 20
+            0x19, 0x08, 0x0A, 0x00, // PtgAttr(tAttrSkip)
+            0x1E, 0x1E, 0x00, // 30
+            0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrSkip)
+            0x22, 0x03, 0x01, 0x00, // IF(argc=3)
+            0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrSkip)
+            0x22, 0x03, 0x64, 0x00, // CHOOSE(argc=3)
+            0x1E, 0x01, 0x00, // 1
+            0x03, // PtgAdd
+        ];
+
+        let decoded = decode_biff8_rgce(&rgce, &ctx);
+        assert_eq!(decoded.text, "CHOOSE(2,10,IF(TRUE,20,30))+1");
+        assert!(
+            decoded.warnings.is_empty(),
+            "warnings={:?}",
+            decoded.warnings
+        );
+        assert_parseable(&decoded.text);
+    }
+
     #[test]
     fn decodes_abs_neg1_from_ptg_func() {
         let sheet_names: Vec<String> = Vec::new();
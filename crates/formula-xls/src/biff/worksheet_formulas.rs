@@ -1494,10 +1494,10 @@ impl<'a> FragmentCursor<'a> {
                     out.push(grbit);
                     out.extend_from_slice(&w_attr.to_le_bytes());
 
-                    // tAttrChoose includes a jump table of `u16` offsets (wAttr entries).
+                    // tAttrChoose includes a jump table of `u16` offsets (wAttr + 1 entries).
                     const T_ATTR_CHOOSE: u8 = 0x04;
                     if (grbit & T_ATTR_CHOOSE) != 0 {
-                        let entries = w_attr as usize;
+                        let entries = w_attr as usize + 1;
                         let bytes = entries
                             .checked_mul(2)
                             .ok_or_else(|| "tAttrChoose jump table length overflow".to_string())?;
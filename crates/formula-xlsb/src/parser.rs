@@ -2709,7 +2709,8 @@ fn materialize_rgce(
 
                 const T_ATTR_CHOOSE: u8 = 0x04;
                 if grbit & T_ATTR_CHOOSE != 0 {
-                    let needed = (w_attr as usize).checked_mul(2)?;
+                    // Jump table: `wAttr + 1` u16 offsets.
+                    let needed = (w_attr as usize + 1).checked_mul(2)?;
                     let end = i.checked_add(needed)?;
                     out.extend_from_slice(base.get(i..end)?);
                     i = end;
@@ -3184,7 +3184,8 @@ pub fn rgce_references_rgcb(rgce: &[u8]) -> bool {
 
                     const T_ATTR_CHOOSE: u8 = 0x04;
                     if grbit & T_ATTR_CHOOSE != 0 {
-                        let needed = match w_attr.checked_mul(2) {
+                        // Jump table: `wAttr + 1` u16 offsets.
+                        let needed = match (w_attr + 1).checked_mul(2) {
                             Some(v) => v,
                             None => return false,
                         };
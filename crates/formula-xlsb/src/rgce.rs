@@ -970,9 +970,10 @@ fn decode_rgce_impl(
                     // short-circuit evaluation.
                     //
                     // We don't need it for printing, but we must consume it so subsequent tokens
-                    // stay aligned.
-                    // `w_attr` is a u16 widened to usize, so `* 2` cannot overflow.
-                    let needed = (w_attr as usize) * 2;
+                    // stay aligned. The table has `wAttr + 1` entries: one per CHOOSE value plus
+                    // the jump past the last value.
+                    // `w_attr` is a u16 widened to usize, so this cannot overflow.
+                    let needed = (w_attr as usize + 1) * 2;
                     let remaining = remaining_len(rgce, i);
                     if remaining < needed {
                         return Err(DecodeError::UnexpectedEof {
@@ -2329,8 +2330,9 @@ fn consume_rgcb_arrays_in_subexpression(
 
                 const T_ATTR_CHOOSE: u8 = 0x04;
                 if grbit & T_ATTR_CHOOSE != 0 {
-                    // `w_attr` is a u16 widened to usize, so `* 2` cannot overflow.
-                    let needed = w_attr * 2;
+                    // The jump table has `wAttr + 1` entries. `w_attr` is a u16 widened to usize,
+                    // so this cannot overflow.
+                    let needed = (w_attr + 1) * 2;
                     if !has_remaining(rgce, i, needed) {
                         return Err(DecodeError::UnexpectedEof {
                             offset: ptg_offset,
@@ -276,6 +276,34 @@ fn ignores_tattrif_and_tattrskip_without_breaking_offsets() {
     assert_parses_and_roundtrips(&text);
 }
 
+#[test]
+fn decodes_choose_with_tattrchoose_jump_table_and_nested_if() {
+    // `=CHOOSE(A1,10,IF(B1,20,30))+1`. The `tAttrChoose` jump table has wAttr + 1 entries; the
+    // nested IF carries its own tAttrIf/tAttrSkip tokens.
+    let rgce = [
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, // PtgRef A1
+        0x19, 0x04, 0x02, 0x00, // PtgAttr(tAttrChoose, wAttr=2)
+        0x06, 0x00, 0x0D, 0x00, 0x34, 0x00, // jump table (3 entries)
+        0x1E, 0x0A, 0x00, // PtgInt(10)
+        0x19, 0x08, 0x2B, 0x00, // PtgAttr(tAttrSkip)
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, // PtgRef B1
+        0x19, 0x02, 0x07, 0x00, // PtgAttr(tAttrIf)
+        0x1E, 0x14, 0x00, // PtgInt(20)
+        0x19, 0x08, 0x0A, 0x00, // PtgAttr(tAttrSkip)
+        0x1E, 0x1E, 0x00, // PtgInt(30)
+        0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrSkip)
+        0x22, 0x03, 0x01, 0x00, // PtgFuncVar(argc=3, IF)
+        0x19, 0x08, 0x03, 0x00, // PtgAttr(tAttrSkip)
+        0x22, 0x03, 0x64, 0x00, // PtgFuncVar(argc=3, CHOOSE)
+        0x1E, 0x01, 0x00, // PtgInt(1)
+        0x03, // PtgAdd
+    ];
+
+    let text = decode_rgce(&rgce).expect("decode");
+    assert_eq!(text, "CHOOSE(A1,10,IF(B1,20,30))+1");
+    assert_parses_and_roundtrips(&text);
+}
+
 #[test]
 fn quotes_sheet_names_in_ptgref3d() {
     let mut ctx = WorkbookContext::default();
@@ -84,14 +84,14 @@ fn rgce_attr_choose_with_ptgarray_bytes_in_jump_table() -> Vec<u8> {
     // PtgAttrChoose:
     //   [ptg=0x19]
     //   [grbit=0x04 (tAttrChoose)]
-    //   [wAttr: u16] (number of u16 offsets in the jump table)
-    //   [jump table: wAttr * u16]
+    //   [wAttr: u16] (number of CHOOSE values)
+    //   [jump table: (wAttr + 1) * u16]
     //
     // Ensure the jump table contains `0x20` so a naive raw-byte scan would incorrectly
     // interpret it as `PtgArray`.
     let mut rgce = vec![0x19, 0x04];
     rgce.extend_from_slice(&1u16.to_le_bytes());
-    rgce.extend_from_slice(&[0x20, 0x00]);
+    rgce.extend_from_slice(&[0x20, 0x00, 0x20, 0x00]);
     rgce
 }
 
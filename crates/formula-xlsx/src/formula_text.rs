@@ -54,6 +54,7 @@ const XL_FN_REQUIRED_FUNCTIONS: &[&str] = &[
     "F.INV",
     "F.INV.RT",
     "F.TEST",
+    "FIELDVALUE",
     "FILTER",
     "FLOOR.MATH",
     "FLOOR.PRECISE",
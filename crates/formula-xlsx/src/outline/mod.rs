@@ -84,6 +84,8 @@ fn parse_outline_pr(pr: &mut OutlinePr, e: &BytesStart<'_>) -> Result<(), Outlin
 fn parse_row_outline(outline: &mut Outline, e: &BytesStart<'_>) -> Result<(), OutlineXlsxError> {
     let mut row_index: Option<u32> = None;
     let mut entry = OutlineEntry::default();
+    let mut zero_height = false;
+    let mut custom_height: Option<bool> = None;
     for attr in e.attributes() {
         let attr = attr?;
         match attr.key.as_ref() {
@@ -91,9 +93,21 @@ fn parse_row_outline(outline: &mut Outline, e: &BytesStart<'_>) -> Result<(), Ou
             b"outlineLevel" => entry.level = parse_u8(attr.value.as_ref(), "outlineLevel")?,
             b"hidden" => entry.hidden.user = parse_bool(attr.value.as_ref())?,
             b"collapsed" => entry.collapsed = parse_bool(attr.value.as_ref())?,
+            b"ht" => {
+                zero_height = std::str::from_utf8(attr.value.as_ref())
+                    .ok()
+                    .and_then(|v| v.trim().parse::<f32>().ok())
+                    == Some(0.0)
+            }
+            b"customHeight" => custom_height = Some(parse_bool(attr.value.as_ref())?),
             _ => {}
         }
     }
+    // Excel treats a zero-height row as hidden even when `hidden` is omitted (matching the
+    // worksheet reader's row properties).
+    if zero_height && custom_height != Some(false) {
+        entry.hidden.user = true;
+    }
     if let Some(index) = row_index {
         // Only store non-default entries so `Outline` stays compact (and so sheets without any
         // outline metadata keep `Outline::default()`).
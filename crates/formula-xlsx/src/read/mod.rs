@@ -3256,7 +3256,8 @@ fn parse_worksheet_into_model(
                                 worksheet.set_row_height(row, Some(height));
                             }
                         }
-                        if hidden {
+                        // Excel treats a zero-height row as hidden even when `hidden` is omitted.
+                        if hidden || (custom_height != Some(false) && height == Some(0.0)) {
                             worksheet.set_row_hidden(row, true);
                         }
                         // Row default formatting (`row/@s`) is nominally gated by `customFormat`,
@@ -3,6 +3,7 @@ use crate::tables::{write_table_xml, TABLE_REL_TYPE};
 use crate::ConditionalFormattingDxfAggregation;
 use crate::WorkbookKind;
 use formula_columnar::{ColumnType as ColumnarType, Value as ColumnarValue};
+use formula_engine::{parse_formula, CellAddr, ParseOptions};
 use formula_fs::{atomic_write_with_path, AtomicWriteError};
 use formula_model::rich_text::{RichText, Underline};
 use formula_model::{
@@ -1294,6 +1295,7 @@ fn sheet_xml(
         row_cells.sort_by_key(|(col, _, _)| *col);
     }
     let overlay_rows: Vec<u32> = overlay_by_row.keys().copied().collect();
+    let shared_formulas = shared_formula_roles(sheet);
 
     let row_props_rows: Vec<u32> = sheet
         .row_properties
@@ -1389,7 +1391,13 @@ fn sheet_xml(
                     && overlay_cells[overlay_cell_idx].0 < columnar.origin.col
                 {
                     let (_col, cell_ref, cell) = overlay_cells[overlay_cell_idx];
-                    row_cells_xml.push_str(&cell_xml(&cell_ref, cell, shared_strings, style_to_xf));
+                    row_cells_xml.push_str(&cell_xml(
+                        &cell_ref,
+                        cell,
+                        shared_strings,
+                        &shared_formulas,
+                        style_to_xf,
+                    ));
                     overlay_cell_idx += 1;
                     wrote_any_cell = true;
                 }
@@ -1405,6 +1413,7 @@ fn sheet_xml(
                             &cell_ref,
                             cell,
                             shared_strings,
+                            &shared_formulas,
                             style_to_xf,
                         ));
                         overlay_cell_idx += 1;
@@ -1438,21 +1447,39 @@ fn sheet_xml(
                 // Overlay cells right of the table.
                 while overlay_cell_idx < overlay_cells.len() {
                     let (_col, cell_ref, cell) = overlay_cells[overlay_cell_idx];
-                    row_cells_xml.push_str(&cell_xml(&cell_ref, cell, shared_strings, style_to_xf));
+                    row_cells_xml.push_str(&cell_xml(
+                        &cell_ref,
+                        cell,
+                        shared_strings,
+                        &shared_formulas,
+                        style_to_xf,
+                    ));
                     overlay_cell_idx += 1;
                     wrote_any_cell = true;
                 }
             } else {
                 // Row outside the columnar table; only overlay cells apply.
                 for (_col, cell_ref, cell) in overlay_cells {
-                    row_cells_xml.push_str(&cell_xml(cell_ref, cell, shared_strings, style_to_xf));
+                    row_cells_xml.push_str(&cell_xml(
+                        cell_ref,
+                        cell,
+                        shared_strings,
+                        &shared_formulas,
+                        style_to_xf,
+                    ));
                     wrote_any_cell = true;
                 }
             }
         } else {
             // No columnar table; only overlay cells apply.
             for (_col, cell_ref, cell) in overlay_cells {
-                row_cells_xml.push_str(&cell_xml(cell_ref, cell, shared_strings, style_to_xf));
+                row_cells_xml.push_str(&cell_xml(
+                    cell_ref,
+                    cell,
+                    shared_strings,
+                    &shared_formulas,
+                    style_to_xf,
+                ));
                 wrote_any_cell = true;
             }
         }
@@ -2029,10 +2056,107 @@ fn sheet_protection_xml(sheet: &Worksheet) -> String {
     format!(r#"<sheetProtection{attrs}/>"#)
 }
 
+/// How a formula cell participates in a shared-formula group (`<f t="shared">`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SharedFormulaRole {
+    /// Carries the formula text and the `ref` covering the whole group.
+    Master { index: u32, range: Range },
+    /// Textless member whose formula is derived from the master by shifting relative refs.
+    Follower { index: u32 },
+}
+
+/// Detect fill-down / fill-right formula families so they can be written as shared formulas.
+///
+/// Two formulas are copy-related when their ASTs match after normalizing relative references
+/// against their own cell. Vertical runs are grouped first (the common fill-down case), then
+/// horizontal runs among the remaining cells. Formulas the engine parser rejects are never
+/// shared and keep their full text.
+fn shared_formula_roles(sheet: &Worksheet) -> HashMap<CellRef, SharedFormulaRole> {
+    let mut normalized: HashMap<CellRef, formula_engine::Ast> = HashMap::new();
+    for (cell_ref, cell) in sheet.iter_cells() {
+        let Some(formula) = cell.formula.as_deref().and_then(normalize_formula_text) else {
+            continue;
+        };
+        let opts = ParseOptions {
+            normalize_relative_to: Some(CellAddr::new(cell_ref.row, cell_ref.col)),
+            ..ParseOptions::default()
+        };
+        if let Ok(ast) = parse_formula(&formula, opts) {
+            normalized.insert(cell_ref, ast);
+        }
+    }
+
+    let mut groups: Vec<Range> = Vec::new();
+    let mut grouped: HashSet<CellRef> = HashSet::new();
+    let mut cells: Vec<CellRef> = normalized.keys().copied().collect();
+
+    // Fill-down runs: consecutive rows in the same column.
+    cells.sort_by_key(|c| (c.col, c.row));
+    collect_shared_formula_runs(
+        &cells,
+        &normalized,
+        &mut grouped,
+        &mut groups,
+        |prev, next| prev.col == next.col && prev.row.checked_add(1) == Some(next.row),
+    );
+
+    // Fill-right runs among cells not already in a vertical group.
+    cells.retain(|c| !grouped.contains(c));
+    cells.sort_by_key(|c| (c.row, c.col));
+    collect_shared_formula_runs(
+        &cells,
+        &normalized,
+        &mut grouped,
+        &mut groups,
+        |prev, next| prev.row == next.row && prev.col.checked_add(1) == Some(next.col),
+    );
+
+    groups.sort_by_key(|range| (range.start.row, range.start.col));
+    let mut roles = HashMap::new();
+    for (index, range) in groups.into_iter().enumerate() {
+        let index = index as u32;
+        for row in range.start.row..=range.end.row {
+            for col in range.start.col..=range.end.col {
+                roles.insert(
+                    CellRef::new(row, col),
+                    SharedFormulaRole::Follower { index },
+                );
+            }
+        }
+        roles.insert(range.start, SharedFormulaRole::Master { index, range });
+    }
+    roles
+}
+
+fn collect_shared_formula_runs(
+    cells: &[CellRef],
+    normalized: &HashMap<CellRef, formula_engine::Ast>,
+    grouped: &mut HashSet<CellRef>,
+    groups: &mut Vec<Range>,
+    adjacent: impl Fn(CellRef, CellRef) -> bool,
+) {
+    let mut start = 0;
+    while start < cells.len() {
+        let mut end = start;
+        while end + 1 < cells.len()
+            && adjacent(cells[end], cells[end + 1])
+            && normalized.get(&cells[start]) == normalized.get(&cells[end + 1])
+        {
+            end += 1;
+        }
+        if end > start {
+            grouped.extend(&cells[start..=end]);
+            groups.push(Range::new(cells[start], cells[end]));
+        }
+        start = end + 1;
+    }
+}
+
 fn cell_xml(
     cell_ref: &CellRef,
     cell: &Cell,
     shared_strings: &SharedStrings,
+    shared_formulas: &HashMap<CellRef, SharedFormulaRole>,
     style_to_xf: &HashMap<u32, u32>,
 ) -> String {
     let mut attrs = String::new();
@@ -2047,10 +2171,26 @@ fn cell_xml(
         }
     }
 
+    let mut has_formula = false;
     if let Some(formula) = &cell.formula {
         if let Some(formula) = normalize_formula_text(formula) {
-            let file_formula = crate::formula_text::add_xlfn_prefixes(&formula);
-            value_xml.push_str(&format!(r#"<f>{}</f>"#, escape_xml(&file_formula)));
+            has_formula = true;
+            match shared_formulas.get(cell_ref) {
+                Some(SharedFormulaRole::Follower { index }) => {
+                    value_xml.push_str(&format!(r#"<f t="shared" si="{index}"/>"#));
+                }
+                Some(SharedFormulaRole::Master { index, range }) => {
+                    let file_formula = crate::formula_text::add_xlfn_prefixes(&formula);
+                    value_xml.push_str(&format!(
+                        r#"<f t="shared" ref="{range}" si="{index}">{}</f>"#,
+                        escape_xml(&file_formula)
+                    ));
+                }
+                None => {
+                    let file_formula = crate::formula_text::add_xlfn_prefixes(&formula);
+                    value_xml.push_str(&format!(r#"<f>{}</f>"#, escape_xml(&file_formula)));
+                }
+            }
         }
     }
 
@@ -2060,26 +2200,23 @@ fn cell_xml(
     // exporter does not attempt to preserve full sharedStrings.xml structure, so emit inlineStr
     // when `Cell.phonetic` is present.
     if let Some(phonetic) = cell.phonetic.as_deref() {
-        let base_text: Option<String> = match &cell.value {
-            CellValue::String(s) => Some(s.clone()),
-            CellValue::Entity(entity) => Some(entity.display_value.clone()),
-            CellValue::Record(record) => Some(record_display_string(record)),
-            CellValue::RichText(r) => Some(r.text.clone()),
-            CellValue::Image(image) => image
-                .alt_text
-                .as_deref()
-                .filter(|s| !s.is_empty())
-                .map(|s| s.to_string()),
-            _ => None,
-        };
-
-        if let Some(base_text) = base_text {
+        if let Some(base_text) = cell_value_text(&cell.value) {
             attrs.push_str(r#" t="inlineStr""#);
             value_xml.push_str(&inline_string_with_phonetic_xml(&base_text, phonetic));
             return format!(r#"<c{}>{}</c>"#, attrs, value_xml);
         }
     }
 
+    // Formula results are cached as `t="str"` with inline text (as Excel writes them) rather than
+    // as shared strings, so the cached value survives without touching `sharedStrings.xml`.
+    if has_formula {
+        if let Some(text) = cell_value_text(&cell.value) {
+            attrs.push_str(r#" t="str""#);
+            value_xml.push_str(&format!(r#"<v>{}</v>"#, escape_xml(&text)));
+            return format!(r#"<c{}>{}</c>"#, attrs, value_xml);
+        }
+    }
+
     match &cell.value {
         CellValue::Empty => {}
         CellValue::Number(n) => {
@@ -2134,6 +2271,22 @@ fn cell_xml(
     format!(r#"<c{}>{}</c>"#, attrs, value_xml)
 }
 
+/// Plain text for text-like values (used for inline strings and cached formula text).
+fn cell_value_text(value: &CellValue) -> Option<String> {
+    match value {
+        CellValue::String(s) => Some(s.clone()),
+        CellValue::Entity(entity) => Some(entity.display_value.clone()),
+        CellValue::Record(record) => Some(record_display_string(record)),
+        CellValue::RichText(r) => Some(r.text.clone()),
+        CellValue::Image(image) => image
+            .alt_text
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string()),
+        _ => None,
+    }
+}
+
 fn columnar_cell_xml(
     cell_ref: &CellRef,
     value: ColumnarValue,
@@ -2205,6 +2358,15 @@ fn build_shared_strings(workbook: &Workbook) -> SharedStrings {
 
     for sheet in &workbook.sheets {
         for (_cell_ref, cell) in sheet.iter_cells() {
+            // Formula results are written inline as `t="str"`; see `cell_xml`.
+            let has_formula = cell
+                .formula
+                .as_deref()
+                .and_then(normalize_formula_text)
+                .is_some();
+            if has_formula {
+                continue;
+            }
             match &cell.value {
                 CellValue::String(s) => {
                     let key = SharedStringKey::plain(s);
@@ -62,3 +62,27 @@ fn reader_applies_row_properties_beyond_excel_max_rows() -> Result<(), Box<dyn s
     Ok(())
 }
 
+#[test]
+fn reader_treats_zero_height_rows_as_hidden() -> Result<(), Box<dyn std::error::Error>> {
+    let sheet_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+  <sheetData>
+    <row r="2" ht="0" customHeight="1"/>
+    <row r="3" ht="18" customHeight="1"/>
+  </sheetData>
+</worksheet>"#;
+
+    let bytes = build_minimal_xlsx(sheet_xml);
+    let workbook = read_workbook_model_from_bytes(&bytes)?;
+    let sheet = &workbook.sheets[0];
+
+    let hidden = sheet.row_properties(1).expect("row 2 properties");
+    assert!(hidden.hidden);
+    assert!(sheet.is_row_hidden_effective(2));
+
+    let visible = sheet.row_properties(2).expect("row 3 properties");
+    assert_eq!(visible.height, Some(18.0));
+    assert!(!visible.hidden);
+
+    Ok(())
+}
@@ -51,3 +51,92 @@ fn roundtrip_preserves_textless_shared_formula_xml() -> Result<(), Box<dyn std::
     Ok(())
 }
 
+
+fn write_formula_column(formula_for_row: impl Fn(u32) -> String) -> Vec<u8> {
+    let mut workbook = formula_model::Workbook::new();
+    let sheet_id = workbook.add_sheet("Sheet1").unwrap();
+    let sheet = workbook.sheet_mut(sheet_id).unwrap();
+    for row in 1..=500u32 {
+        sheet.set_formula(CellRef::new(row - 1, 0), Some(formula_for_row(row)));
+    }
+
+    let mut cursor = Cursor::new(Vec::new());
+    formula_xlsx::write_workbook_to_writer(&workbook, &mut cursor).unwrap();
+    cursor.into_inner()
+}
+
+fn sheet1_xml(bytes: &[u8]) -> String {
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut sheet_xml = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut sheet_xml)
+        .unwrap();
+    sheet_xml
+}
+
+#[test]
+fn writer_emits_shared_formulas_for_filled_down_families() -> Result<(), Box<dyn std::error::Error>>
+{
+    let formula = |row: u32| format!("IF(B{row}>100,B{row}*2+SUM($B$1:$B$10),B{row}/2)");
+    let filled = write_formula_column(formula);
+    // Same text lengths, but every row points somewhere else so nothing is copy-related.
+    let scattered = write_formula_column(|row| formula(row * 7 % 500 + 1));
+
+    let filled_xml = sheet1_xml(&filled);
+    let scattered_xml = sheet1_xml(&scattered);
+    assert!(filled_xml.contains(
+        r#"<f t="shared" ref="A1:A500" si="0">IF(B1&gt;100,B1*2+SUM($B$1:$B$10),B1/2)</f>"#
+    ));
+    assert_eq!(filled_xml.matches(r#"<f t="shared" si="0"/>"#).count(), 499);
+    assert!(!scattered_xml.contains(r#"t="shared""#));
+    assert!(
+        filled_xml.len() * 3 < scattered_xml.len() * 2,
+        "expected shared formulas to shrink sheet XML: {} vs {}",
+        filled_xml.len(),
+        scattered_xml.len()
+    );
+
+    let doc = formula_xlsx::load_from_bytes(&filled)?;
+    let sheet = &doc.workbook.sheets[0];
+    for row in [1, 250, 500] {
+        let cell = CellRef::new(row - 1, 0);
+        assert_eq!(sheet.formula(cell), Some(formula(row).as_str()));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn writer_shares_fill_right_runs_and_keeps_unrelated_formulas_explicit(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut workbook = formula_model::Workbook::new();
+    let sheet_id = workbook.add_sheet("Sheet1")?;
+    let sheet = workbook.sheet_mut(sheet_id).unwrap();
+    for col in 0..4 {
+        let cell = CellRef::new(1, col);
+        let above = CellRef::new(0, col);
+        sheet.set_value(above, formula_model::CellValue::Number(col as f64));
+        sheet.set_formula(cell, Some(format!("{above}+1")));
+    }
+    sheet.set_formula(CellRef::from_a1("A3")?, Some("SUM(A1:D1)".to_string()));
+    sheet.set_formula(CellRef::from_a1("B3")?, Some("A3*2".to_string()));
+
+    let mut cursor = Cursor::new(Vec::new());
+    formula_xlsx::write_workbook_to_writer(&workbook, &mut cursor)?;
+    let bytes = cursor.into_inner();
+
+    let sheet_xml = sheet1_xml(&bytes);
+    assert!(sheet_xml.contains(r#"<f t="shared" ref="A2:D2" si="0">A1+1</f>"#));
+    assert_eq!(sheet_xml.matches(r#"<f t="shared" si="0"/>"#).count(), 3);
+    assert!(sheet_xml.contains("<f>SUM(A1:D1)</f>"));
+    assert!(sheet_xml.contains("<f>A3*2</f>"));
+
+    let doc = formula_xlsx::load_from_bytes(&bytes)?;
+    let sheet = &doc.workbook.sheets[0];
+    assert_eq!(sheet.formula(CellRef::from_a1("D2")?), Some("D1+1"));
+    assert_eq!(sheet.formula(CellRef::from_a1("B3")?), Some("A3*2"));
+
+    Ok(())
+}
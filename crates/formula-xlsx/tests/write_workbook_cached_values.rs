@@ -0,0 +1,95 @@
+use std::io::{Cursor, Read};
+
+use formula_model::{Cell, CellRef, CellValue, ErrorValue, Workbook};
+use zip::ZipArchive;
+
+fn zip_part(zip_bytes: &[u8], name: &str) -> Option<String> {
+    let mut archive = ZipArchive::new(Cursor::new(zip_bytes)).expect("open zip");
+    let mut file = archive.by_name(name).ok()?;
+    let mut out = String::new();
+    file.read_to_string(&mut out).expect("read part");
+    Some(out)
+}
+
+fn formula_cell(formula: &str, cached: CellValue) -> Cell {
+    let mut cell = Cell::new(cached);
+    cell.formula = Some(formula.to_string());
+    cell
+}
+
+#[test]
+fn write_workbook_caches_formula_results_with_matching_cell_types(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut workbook = Workbook::new();
+    let sheet_id = workbook.add_sheet("Sheet1")?;
+    let sheet = workbook.sheet_mut(sheet_id).expect("sheet exists");
+
+    let cells = [
+        ("A1", formula_cell("1+2", CellValue::Number(3.0))),
+        (
+            "A2",
+            formula_cell(r#""a"&"b""#, CellValue::String("ab".to_string())),
+        ),
+        ("A3", formula_cell("1=1", CellValue::Boolean(true))),
+        (
+            "A4",
+            formula_cell("1/0", CellValue::Error(ErrorValue::Div0)),
+        ),
+        (
+            "A5",
+            formula_cell(r#"IF(TRUE,"")"#, CellValue::String(String::new())),
+        ),
+        ("B1", Cell::new(CellValue::String("plain".to_string()))),
+    ];
+    for (a1, cell) in cells.clone() {
+        sheet.set_cell(CellRef::from_a1(a1)?, cell);
+    }
+
+    let mut buffer = Cursor::new(Vec::new());
+    formula_xlsx::write_workbook_to_writer(&workbook, &mut buffer)?;
+    let bytes = buffer.into_inner();
+
+    let sheet_xml = zip_part(&bytes, "xl/worksheets/sheet1.xml").expect("sheet1.xml");
+    let doc = roxmltree::Document::parse(&sheet_xml)?;
+    let cell_xml = |a1: &str| {
+        let c = doc
+            .descendants()
+            .find(|n| n.has_tag_name("c") && n.attribute("r") == Some(a1))
+            .unwrap_or_else(|| panic!("missing <c r=\"{a1}\">: {sheet_xml}"));
+        let v = c
+            .children()
+            .find(|n| n.has_tag_name("v"))
+            .map(|v| v.text().unwrap_or_default().to_string());
+        (c.attribute("t"), v)
+    };
+
+    assert_eq!(cell_xml("A1"), (None, Some("3".to_string())));
+    assert_eq!(cell_xml("A2"), (Some("str"), Some("ab".to_string())));
+    assert_eq!(cell_xml("A3"), (Some("b"), Some("1".to_string())));
+    assert_eq!(cell_xml("A4"), (Some("e"), Some("#DIV/0!".to_string())));
+    assert_eq!(cell_xml("A5"), (Some("str"), Some(String::new())));
+    assert_eq!(cell_xml("B1"), (Some("s"), Some("0".to_string())));
+
+    // Formula results are cached inline, so only the literal string lands in sharedStrings.xml.
+    let shared_strings = zip_part(&bytes, "xl/sharedStrings.xml").expect("sharedStrings.xml");
+    assert!(shared_strings.contains("plain"));
+    assert!(!shared_strings.contains("ab"));
+
+    // No calc chain is written; Excel rebuilds it from the cached formulas.
+    assert!(zip_part(&bytes, "xl/calcChain.xml").is_none());
+    let workbook_xml = zip_part(&bytes, "xl/workbook.xml").expect("workbook.xml");
+    assert!(workbook_xml.contains(r#"fullCalcOnLoad="0""#));
+
+    let reopened = formula_xlsx::load_from_bytes(&bytes)?;
+    let sheet = reopened
+        .workbook
+        .sheet_by_name("Sheet1")
+        .expect("Sheet1 exists");
+    for (a1, expected) in cells {
+        let cell = sheet.cell(CellRef::from_a1(a1)?).expect("cell exists");
+        assert_eq!(cell.value, expected.value, "{a1}");
+        assert_eq!(cell.formula, expected.formula, "{a1}");
+    }
+
+    Ok(())
+}
@@ -3360,6 +3360,34 @@ const CURATED_FUNCTIONS = [
       { name: "text", type: "range" },
     ],
   },
+  {
+    name: "CHAR",
+    description: "Returns the character specified by the code number.",
+    args: [
+      { name: "number", type: "number" },
+    ],
+  },
+  {
+    name: "CODE",
+    description: "Returns a numeric code for the first character in a text string.",
+    args: [
+      { name: "text", type: "range" },
+    ],
+  },
+  {
+    name: "UNICHAR",
+    description: "Returns the Unicode character that is referenced by the given numeric value.",
+    args: [
+      { name: "number", type: "number" },
+    ],
+  },
+  {
+    name: "UNICODE",
+    description: "Returns the number (code point) that corresponds to the first character of the text.",
+    args: [
+      { name: "text", type: "range" },
+    ],
+  },
   {
     name: "LOWER",
     description: "Converts text to lowercase.",
@@ -4239,6 +4267,14 @@ const CURATED_FUNCTIONS = [
       { name: "width", type: "value", optional: true },
     ],
   },
+  {
+    name: "FIELDVALUE",
+    description: "Returns the value of a named field from a linked data type or record.",
+    args: [
+      { name: "value", type: "range" },
+      { name: "field_name", type: "string" },
+    ],
+  },
   {
     name: "NUMBERVALUE",
     description: "Converts text to a number in a locale-independent way, using custom separators.",
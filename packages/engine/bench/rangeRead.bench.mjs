@@ -0,0 +1,72 @@
+import { performance } from "node:perf_hooks";
+
+import { ensureFormulaWasmNodeBuild, formulaWasmNodeEntryUrl } from "../../../scripts/build-formula-wasm-node.mjs";
+
+// Compare bulk range reads from the wasm workbook:
+// - `getRangeCompact`: `[input, value]` tuples (joins engine values with the sparse input map)
+// - `getRangeValuesRaw`: computed values only (no input lookups, no per-cell tuple allocation)
+// - `getRangeColumnar`: computed values as per-column typed arrays (no per-cell JS values for
+//   numeric columns)
+
+const ROWS = 1000;
+const COLS = 100;
+const ITERATIONS = Number(process.env.BENCH_ITERATIONS ?? 10);
+
+function fmtMs(ms) {
+  return `${ms.toFixed(1)}ms`;
+}
+
+function colLabel(col) {
+  let label = "";
+  let n = col + 1;
+  while (n > 0) {
+    const rem = (n - 1) % 26;
+    label = String.fromCharCode(65 + rem) + label;
+    n = Math.floor((n - 1) / 26);
+  }
+  return label;
+}
+
+/**
+ * @param {string} label
+ * @param {() => unknown} fn
+ */
+function bench(label, fn) {
+  // Warm up once so JIT/allocation effects don't dominate the first sample.
+  fn();
+  const samples = [];
+  let checksum = 0;
+  for (let i = 0; i < ITERATIONS; i++) {
+    const start = performance.now();
+    const out = fn();
+    samples.push(performance.now() - start);
+    // Touch the result so work isn't optimized away.
+    checksum += Array.isArray(out) ? out.length : (out?.columns?.length ?? 0);
+  }
+  samples.sort((a, b) => a - b);
+  const median = samples[Math.floor(samples.length / 2)];
+  console.log(`${label.padEnd(20)} median=${fmtMs(median)} min=${fmtMs(samples[0])} (checksum ${checksum})`);
+  return median;
+}
+
+ensureFormulaWasmNodeBuild();
+const mod = await import(formulaWasmNodeEntryUrl());
+const wasm = mod.default ?? mod;
+
+const wb = new wasm.WasmWorkbook();
+const range = `A1:${colLabel(COLS - 1)}${ROWS}`;
+const values = [];
+for (let r = 0; r < ROWS; r++) {
+  const row = [];
+  for (let c = 0; c < COLS; c++) row.push(r * COLS + c);
+  values.push(row);
+}
+wb.setRange(range, values);
+wb.recalculate();
+
+console.log(`range read benchmark (${ROWS}x${COLS}, ${ITERATIONS} iterations)`);
+const compact = bench("getRangeCompact", () => wb.getRangeCompact(range));
+const raw = bench("getRangeValuesRaw", () => wb.getRangeValuesRaw(range));
+const columnar = bench("getRangeColumnar", () => wb.getRangeColumnar(range));
+console.log(`speedup (raw vs compact): ${(compact / raw).toFixed(2)}x`);
+console.log(`speedup (columnar vs compact): ${(compact / columnar).toFixed(2)}x`);
@@ -48,3 +48,62 @@ describeWasm("WasmWorkbook.getRangeCompact", () => {
     expect(compact[0][0]).toEqual([1, 1]);
   });
 });
+
+describeWasm("WasmWorkbook.getRangeValuesRaw", () => {
+  it("returns only computed values, matching getRangeCompact", async () => {
+    const wasm = await loadFormulaWasm();
+    const wb = new (wasm as any).WasmWorkbook();
+
+    wb.setCell("A1", 1);
+    wb.setCell("B1", "=A1*2");
+    wb.setCell("A2", "text");
+    wb.setCell("B2", "=1/0");
+    wb.setCell("A1", 1, "Sheet2");
+    wb.recalculate();
+
+    const compact = wb.getRangeCompact("A1:C2");
+    const raw = wb.getRangeValuesRaw("A1:C2");
+
+    expect(raw).toEqual(compact.map((row: any[]) => row.map((cell: any[]) => cell[1])));
+    expect(raw).toEqual([
+      [1, 2, null],
+      ["text", "#DIV/0!", null],
+    ]);
+    expect(wb.getRangeValuesRaw("A1", "Sheet2")).toEqual([[1]]);
+  });
+});
+
+describeWasm("WasmWorkbook.getRangeColumnar", () => {
+  it("returns typed column buffers matching getRangeValuesRaw", async () => {
+    const wasm = await loadFormulaWasm();
+    const wb = new (wasm as any).WasmWorkbook();
+
+    wb.setCell("A1", 1.5);
+    wb.setCell("A2", "=A1*2");
+    wb.setCell("B1", "x");
+    wb.setCell("C1", 2);
+    wb.setCell("C2", "=1/0");
+    wb.recalculate();
+
+    const columnar = wb.getRangeColumnar("A1:C2");
+    expect(columnar.rows).toBe(2);
+    expect(columnar.cols).toBe(3);
+
+    const [a, b, c] = columnar.columns;
+    expect(a.kind).toBe("number");
+    expect(a.values).toBeInstanceOf(Float64Array);
+    expect(Array.from(a.values)).toEqual([1.5, 3]);
+    expect(Array.from(a.types)).toEqual([1, 1]);
+
+    expect(b.kind).toBe("text");
+    expect(b.values).toEqual(["x", null]);
+    expect(Array.from(b.types)).toEqual([2, 0]);
+
+    expect(c.kind).toBe("mixed");
+    expect(c.values).toEqual([2, "#DIV/0!"]);
+    expect(Array.from(c.types)).toEqual([1, 4]);
+
+    const raw = wb.getRangeValuesRaw("A1:C2");
+    expect(raw.map((row: unknown[]) => row[2])).toEqual(c.values);
+  });
+});
@@ -431,7 +431,25 @@ export type EditOp =
   | { type: "DeleteCellsShiftUp"; sheet: string; range: string }
   | { type: "MoveRange"; sheet: string; src: string; dstTopLeft: string }
   | { type: "CopyRange"; sheet: string; src: string; dstTopLeft: string }
-  | { type: "Fill"; sheet: string; src: string; dst: string };
+  | { type: "Fill"; sheet: string; src: string; dst: string }
+  | {
+      type: "TextToColumns";
+      sheet: string;
+      src: string;
+      /** Single-character delimiter. Provide exactly one of `delimiter` / `fixedWidths`. */
+      delimiter?: string;
+      fixedWidths?: number[];
+      shiftRight?: boolean;
+    }
+  | { type: "RemoveDuplicates"; sheet: string; range: string; keyColumns: number[] }
+  | {
+      type: "SortRange";
+      sheet: string;
+      range: string;
+      /** `col` is a 0-based sheet column inside `range`; `customList` orders matching text first. */
+      keys: { col: number; order?: "ascending" | "descending"; customList?: string[] }[];
+      hasHeader?: boolean;
+    };
 
 export interface EditCellSnapshot {
   value: CellScalar;
@@ -462,6 +480,8 @@ export interface EditResult {
   changedCells: EditCellChange[];
   movedRanges: EditMovedRange[];
   formulaRewrites: EditFormulaRewrite[];
+  /** Rows deleted by `RemoveDuplicates` (0 for other operations). */
+  removedRows?: number;
 }
 
 /**
@@ -611,6 +611,16 @@ export default {
         "any"
       ]
     },
+    {
+      "name": "CHAR",
+      "min_args": 1,
+      "max_args": 1,
+      "volatility": "non_volatile",
+      "return_type": "text",
+      "arg_types": [
+        "number"
+      ]
+    },
     {
       "name": "CHIDIST",
       "min_args": 2,
@@ -743,6 +753,16 @@ export default {
         "text"
       ]
     },
+    {
+      "name": "CODE",
+      "min_args": 1,
+      "max_args": 1,
+      "volatility": "non_volatile",
+      "return_type": "number",
+      "arg_types": [
+        "text"
+      ]
+    },
     {
       "name": "COLUMN",
       "min_args": 0,
@@ -1834,6 +1854,17 @@ export default {
         "number"
       ]
     },
+    {
+      "name": "FIELDVALUE",
+      "min_args": 2,
+      "max_args": 2,
+      "volatility": "non_volatile",
+      "return_type": "any",
+      "arg_types": [
+        "any",
+        "text"
+      ]
+    },
     {
       "name": "FILTER",
       "min_args": 2,
@@ -4669,12 +4700,11 @@ export default {
     },
     {
       "name": "SUMPRODUCT",
-      "min_args": 2,
-      "max_args": 2,
+      "min_args": 1,
+      "max_args": 255,
       "volatility": "non_volatile",
       "return_type": "number",
       "arg_types": [
-        "any",
         "any"
       ]
     },
@@ -5159,6 +5189,26 @@ export default {
         "any"
       ]
     },
+    {
+      "name": "UNICHAR",
+      "min_args": 1,
+      "max_args": 1,
+      "volatility": "non_volatile",
+      "return_type": "text",
+      "arg_types": [
+        "number"
+      ]
+    },
+    {
+      "name": "UNICODE",
+      "min_args": 1,
+      "max_args": 1,
+      "volatility": "non_volatile",
+      "return_type": "number",
+      "arg_types": [
+        "text"
+      ]
+    },
     {
       "name": "UNIQUE",
       "min_args": 1,
@@ -53,6 +53,7 @@ export default [
   "CEILING.MATH",
   "CEILING.PRECISE",
   "CELL",
+  "CHAR",
   "CHIDIST",
   "CHIINV",
   "CHISQ.DIST",
@@ -65,6 +66,7 @@ export default [
   "CHOOSECOLS",
   "CHOOSEROWS",
   "CLEAN",
+  "CODE",
   "COLUMN",
   "COLUMNS",
   "COMBIN",
@@ -162,6 +164,7 @@ export default [
   "FACTDOUBLE",
   "FALSE",
   "FDIST",
+  "FIELDVALUE",
   "FILTER",
   "FIND",
   "FINDB",
@@ -463,6 +466,8 @@ export default [
   "TRUNC",
   "TTEST",
   "TYPE",
+  "UNICHAR",
+  "UNICODE",
   "UNIQUE",
   "UPPER",
   "VALUE",
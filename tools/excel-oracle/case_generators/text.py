@@ -97,6 +97,35 @@ def generate(
         inputs=[CellInput("A1", "a\u0000\u0009b\u001Fc\u007Fd")],
         description="CLEAN strips non-printable ASCII control codes",
     )
+    add_case(
+        cases,
+        prefix="char",
+        tags=["text", "CHAR"],
+        formula="=CHAR(A1)",
+        inputs=[CellInput("A1", 65)],
+    )
+    add_case(
+        cases,
+        prefix="code",
+        tags=["text", "CODE"],
+        formula="=CODE(A1)",
+        inputs=[CellInput("A1", "Apple")],
+    )
+    add_case(
+        cases,
+        prefix="unichar",
+        tags=["text", "UNICHAR"],
+        formula="=UNICHAR(A1)",
+        inputs=[CellInput("A1", 128512)],
+        description="UNICHAR returns non-BMP code points as a single character",
+    )
+    add_case(
+        cases,
+        prefix="unicode",
+        tags=["text", "UNICODE"],
+        formula="=UNICODE(A1)",
+        inputs=[CellInput("A1", "\u20ac")],
+    )
     add_case(
         cases,
         prefix="trim",